@@ -212,6 +212,28 @@ impl ObjectIdentifier {
         &self.bytes[..self.length as usize]
     }
 
+    /// Get the length of the BER/DER serialization of this OID in bytes.
+    pub const fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    /// Is the BER/DER serialization of this OID empty?
+    ///
+    /// Always `false`, as every OID has at least two arcs, which are
+    /// serialized in at least one byte.
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Get the BER/DER serialization of this OID as a fixed-size array,
+    /// padded with trailing zeroes.
+    ///
+    /// Only the first [`ObjectIdentifier::len`] bytes are significant.
+    /// Unlike [`ObjectIdentifier::as_bytes`], this method is a `const fn`.
+    pub const fn as_padded_bytes(&self) -> &[u8; Self::MAX_LENGTH] {
+        &self.bytes
+    }
+
     /// Return the arc with the given index, if it exists.
     pub fn arc(&self, index: usize) -> Option<Arc> {
         self.arcs().nth(index)
@@ -134,3 +134,12 @@ fn parse_invalid_first_arc() {
 fn parse_invalid_second_arc() {
     ObjectIdentifier::new("1.40.840.10045.3.1.7");
 }
+
+#[test]
+fn padded_bytes() {
+    const PADDED: &[u8; ObjectIdentifier::MAX_LENGTH] = EXAMPLE_OID_1.as_padded_bytes();
+    assert_eq!(EXAMPLE_OID_1.len(), EXAMPLE_OID_1_BER.len());
+    assert!(!EXAMPLE_OID_1.is_empty());
+    assert_eq!(&PADDED[..EXAMPLE_OID_1.len()], EXAMPLE_OID_1_BER);
+    assert!(PADDED[EXAMPLE_OID_1.len()..].iter().all(|&b| b == 0));
+}
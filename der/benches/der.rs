@@ -0,0 +1,53 @@
+//! Decoding and encoding benchmarks for representative documents.
+//!
+//! Run with `cargo bench --features dev`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use der::{
+    asn1::UIntBytes,
+    dev::samples::{self, P256PrivateKey},
+    Decodable, Encodable,
+};
+
+fn sequence_of_integers(c: &mut Criterion) {
+    let documents = [
+        ("rsa4096", samples::rsa4096_private_key()),
+        ("10k", samples::sequence_of_integers(10_000)),
+    ];
+
+    let mut group = c.benchmark_group("SEQUENCE OF INTEGER");
+
+    for (name, der) in &documents {
+        let decoded = Vec::<UIntBytes<'_>>::from_der(der).unwrap();
+        group.throughput(Throughput::Bytes(der.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("decode", name), der, |b, der| {
+            b.iter(|| Vec::<UIntBytes<'_>>::from_der(der).unwrap())
+        });
+
+        group.bench_with_input(BenchmarkId::new("encode", name), &decoded, |b, decoded| {
+            b.iter(|| decoded.to_vec().unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+fn ec_private_key(c: &mut Criterion) {
+    let der = samples::p256_private_key();
+    let decoded = P256PrivateKey::from_der(&der).unwrap();
+
+    let mut group = c.benchmark_group("ECPrivateKey");
+    group.throughput(Throughput::Bytes(der.len() as u64));
+
+    group.bench_function("decode/p256", |b| {
+        b.iter(|| P256PrivateKey::from_der(&der).unwrap())
+    });
+
+    group.bench_function("encode/p256", |b| b.iter(|| decoded.to_vec().unwrap()));
+
+    group.finish();
+}
+
+criterion_group!(benches, sequence_of_integers, ec_private_key);
+criterion_main!(benches);
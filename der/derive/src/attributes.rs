@@ -1,50 +1,198 @@
 //! Attribute-related types used by the proc macro
 
 use crate::Asn1Type;
-use syn::{Attribute, Lit, Meta, MetaList, MetaNameValue, NestedMeta};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Lit, LitStr, Meta, MetaList, MetaNameValue, NestedMeta, Path};
 
 #[derive(Debug)]
 pub(crate) struct Asn1Attrs {
     /// Value of the `#[asn1(type = "...")]` attribute if provided
     pub asn1_type: Option<Asn1Type>,
+
+    /// Value of the `#[asn1(order = N)]` attribute if provided
+    pub order: Option<usize>,
+
+    /// Value of the `#[asn1(context_specific = "...")]` attribute if provided
+    pub context_specific: Option<u32>,
+
+    /// Value of the `#[asn1(tag_mode = "...")]` attribute if provided
+    pub tag_mode: Option<TagMode>,
+
+    /// Value of the `#[asn1(default = "...")]` attribute if provided
+    pub default: Option<Path>,
+
+    /// Value of the `#[asn1(optional = "...")]` attribute if provided
+    pub optional: bool,
 }
 
 impl Asn1Attrs {
     /// Parse attributes from a field or enum variant
     pub fn new(attrs: &[Attribute]) -> Self {
         let mut asn1_type = None;
+        let mut order = None;
+        let mut context_specific = None;
+        let mut tag_mode = None;
+        let mut default = None;
+        let mut optional = None;
 
         for attr in attrs {
             if !attr.path.is_ident("asn1") {
                 continue;
             }
 
-            match attr.parse_meta().expect("error parsing `asn1` attribute") {
-                Meta::List(MetaList { nested, .. }) if nested.len() == 1 => {
-                    match nested.first() {
-                        Some(NestedMeta::Meta(Meta::NameValue(MetaNameValue {
-                            path,
-                            lit: Lit::Str(lit_str),
-                            ..
-                        }))) => {
-                            // Parse the `type = "..."` attribute
-                            if !path.is_ident("type") {
-                                panic!("unknown `asn1` attribute: {:?}", path);
-                            }
-
-                            if let Some(ty) = asn1_type {
-                                panic!("duplicate ASN.1 `type` attribute: {:?}", ty);
-                            }
-
-                            asn1_type = Some(Asn1Type::new(&lit_str.value()));
+            let nested = match attr.parse_meta().expect("error parsing `asn1` attribute") {
+                Meta::List(MetaList { nested, .. }) if !nested.is_empty() => nested,
+                other => panic!("malformed `asn1` attribute: {:?}", other),
+            };
+
+            for meta in &nested {
+                match meta {
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("type") => {
+                        // Parse the `type = "..."` attribute
+                        if let Some(ty) = asn1_type {
+                            panic!("duplicate ASN.1 `type` attribute: {:?}", ty);
                         }
-                        other => panic!("malformed `asn1` attribute: {:?}", other),
+
+                        asn1_type = Some(Asn1Type::new(&lit_str.value()));
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Int(lit_int),
+                        ..
+                    })) if path.is_ident("order") => {
+                        // Parse the `order = N` attribute
+                        if let Some(n) = order {
+                            panic!("duplicate ASN.1 `order` attribute: {}", n);
+                        }
+
+                        order = Some(
+                            lit_int
+                                .base10_parse()
+                                .expect("error parsing ASN.1 `order` attribute"),
+                        );
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("context_specific") => {
+                        // Parse the `context_specific = "..."` attribute
+                        if let Some(n) = context_specific {
+                            panic!("duplicate ASN.1 `context_specific` attribute: {}", n);
+                        }
+
+                        let n = lit_str.value().parse().unwrap_or_else(|_| {
+                            panic!("invalid ASN.1 tag number: {:?}", lit_str.value())
+                        });
+
+                        context_specific = Some(n);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("tag_mode") => {
+                        // Parse the `tag_mode = "..."` attribute
+                        if let Some(mode) = tag_mode {
+                            panic!("duplicate ASN.1 `tag_mode` attribute: {:?}", mode);
+                        }
+
+                        tag_mode = Some(TagMode::new(&lit_str.value()));
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("default") => {
+                        // Parse the `default = "..."` attribute
+                        if default.is_some() {
+                            panic!("duplicate ASN.1 `default` attribute");
+                        }
+
+                        default = Some(parse_path(lit_str));
                     }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("optional") => {
+                        // Parse the `optional = "..."` attribute
+                        if let Some(value) = optional {
+                            panic!("duplicate ASN.1 `optional` attribute: {}", value);
+                        }
+
+                        optional = Some(match lit_str.value().as_str() {
+                            "true" => true,
+                            "false" => false,
+                            other => panic!("invalid ASN.1 `optional` attribute: {:?}", other),
+                        });
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, .. })) => {
+                        panic!("unknown `asn1` attribute: {:?}", path)
+                    }
+                    other => panic!("malformed `asn1` attribute: {:?}", other),
                 }
-                other => panic!("malformed `asn1` attribute: {:?}", other),
             }
         }
 
-        Self { asn1_type }
+        Self {
+            asn1_type,
+            order,
+            context_specific,
+            tag_mode,
+            default,
+            optional: optional.unwrap_or(false),
+        }
+    }
+
+    /// Are any of the attributes which only apply to `CONTEXT-SPECIFIC`
+    /// fields present?
+    pub fn has_context_specific_attrs(&self) -> bool {
+        self.context_specific.is_some()
+            || self.tag_mode.is_some()
+            || self.default.is_some()
+            || self.optional
+    }
+}
+
+/// Tag mode of a `CONTEXT-SPECIFIC` field, i.e. the value of the
+/// `#[asn1(tag_mode = "...")]` attribute
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum TagMode {
+    /// `EXPLICIT` tagging
+    Explicit,
+
+    /// `IMPLICIT` tagging
+    Implicit,
+}
+
+impl TagMode {
+    /// Parse tag mode
+    pub fn new(s: &str) -> Self {
+        match s {
+            "EXPLICIT" | "explicit" => Self::Explicit,
+            "IMPLICIT" | "implicit" => Self::Implicit,
+            _ => panic!("unrecognized ASN.1 tag mode: {}", s),
+        }
+    }
+
+    /// Get the `::der::TagMode` for this tag mode
+    pub fn to_tokens(self) -> TokenStream {
+        match self {
+            TagMode::Explicit => quote!(::der::TagMode::Explicit),
+            TagMode::Implicit => quote!(::der::TagMode::Implicit),
+        }
     }
 }
+
+/// Parse the path of a function from a string literal
+fn parse_path(lit_str: &LitStr) -> Path {
+    lit_str
+        .parse()
+        .unwrap_or_else(|_| panic!("invalid path: {:?}", lit_str.value()))
+}
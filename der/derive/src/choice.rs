@@ -47,7 +47,23 @@ impl DeriveChoice {
         };
 
         for (variant_info, variant) in s.variants().iter().zip(&data.variants) {
-            let asn1_type = Asn1Attrs::new(&variant.attrs).asn1_type.unwrap_or_else(|| {
+            let attrs = Asn1Attrs::new(&variant.attrs);
+
+            if attrs.order.is_some() {
+                panic!(
+                    "#[asn1(order=...)] is not supported on enum variants: {}",
+                    variant.ident
+                );
+            }
+
+            if attrs.has_context_specific_attrs() {
+                panic!(
+                    "context-specific attributes are not supported on enum variants: {}",
+                    variant.ident
+                );
+            }
+
+            let asn1_type = attrs.asn1_type.unwrap_or_else(|| {
                 panic!(
                     "no #[asn1(type=...)] specified for enum variant: {}",
                     variant.ident
@@ -177,11 +193,10 @@ impl DeriveChoice {
                     #[allow(unused_imports)]
                     use core::convert::{TryFrom, TryInto};
 
-                    let octet = decoder.peek().ok_or_else(|| {
-                        decoder.error(::der::ErrorKind::Truncated)
-                    })?;
-
-                    let tag = ::der::Tag::try_from(octet).map_err(|e| decoder.error(e.kind()))?;
+                    let tag = decoder
+                        .peek_tag()
+                        .map_err(|e| decoder.error(e.kind()))?
+                        .ok_or_else(|| decoder.error(::der::ErrorKind::Truncated))?;
 
                     match tag {
                         #decode_body
@@ -48,6 +48,58 @@
 //! Note: please open a GitHub Issue if you would like to request support
 //! for additional ASN.1 types.
 //!
+//! # `#[asn1(order = N)]` attribute
+//!
+//! This attribute can be placed on the fields of a struct deriving
+//! `Sequence` to declare the (zero-based) position of the field within the
+//! ASN.1 `SEQUENCE`.
+//!
+//! Since fields are encoded in the order they're declared in the struct,
+//! reordering them changes the wire format. When this attribute is present,
+//! the custom derive checks it against the field's actual position and fails
+//! to compile if they don't match, catching accidental reordering:
+//!
+//! ```ignore
+//! #[derive(Sequence)]
+//! pub struct AlgorithmIdentifier<'a> {
+//!     #[asn1(order = 0)]
+//!     pub algorithm: ObjectIdentifier,
+//!
+//!     #[asn1(order = 1)]
+//!     pub parameters: Option<Any<'a>>,
+//! }
+//! ```
+//!
+//! It can be combined with the `type` attribute, e.g.
+//! `#[asn1(type = "OCTET STRING", order = 2)]`.
+//!
+//! # `#[asn1(context_specific = "...")]` attribute
+//!
+//! This attribute can be placed on the fields of a struct deriving
+//! `Sequence` to declare them as `CONTEXT-SPECIFIC` fields with the given
+//! tag number, which are `EXPLICIT` unless `tag_mode = "IMPLICIT"` is
+//! specified.
+//!
+//! Such fields must either be `OPTIONAL`, which is declared with
+//! `optional = "true"` on a field of type `Option<T>`, or have a `DEFAULT`
+//! value, which is declared with `default = "..."` naming a function that
+//! returns it:
+//!
+//! ```ignore
+//! /// `version [0] EXPLICIT Version DEFAULT v1` of an X.509 `TBSCertificate`
+//! #[asn1(context_specific = "0", default = "Default::default")]
+//! pub version: u8,
+//!
+//! /// `issuerUniqueID [1] IMPLICIT UniqueIdentifier OPTIONAL`
+//! #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+//! pub issuer_unique_id: Option<BitString<'a>>,
+//! ```
+//!
+//! As DER requires, fields equal to their default value are omitted when
+//! encoding and rejected as non-canonical when decoding. The field type
+//! must impl `Clone` and `PartialEq` as well as the `DecodeValue`,
+//! `EncodeValue` and `Tagged` traits.
+//!
 //! [`der`]: https://docs.rs/der/
 //! [`der::asn1::BitString`]: https://docs.rs/der/latest/der/asn1/struct.BitString.html
 //! [`der::asn1::GeneralizedTime`]: https://docs.rs/der/latest/der/asn1/struct.GeneralizedTime.html
@@ -143,7 +195,7 @@ decl_derive!(
     /// }
     /// ```
     ///
-    /// # `#[asn1(type = "...")]` attribute
+    /// # `#[asn1(...)]` attributes
     ///
     /// See [toplevel documentation for the `der_derive` crate][2] for more
     /// information about the `#[asn1]` attribute.
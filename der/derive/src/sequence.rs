@@ -1,7 +1,7 @@
 //! Support for deriving the `Sequence` trait on structs for the purposes of
 //! decoding/encoding ASN.1 `SEQUENCE` types as mapped to struct fields.
 
-use crate::{Asn1Attrs, Asn1Type};
+use crate::{attributes::TagMode, Asn1Attrs, Asn1Type};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{DataStruct, Field, Ident, Lifetime};
@@ -27,24 +27,113 @@ impl DeriveSequence {
             encode_fields: TokenStream::new(),
         };
 
-        for field in &data.fields {
-            state.derive_field(field);
+        for (index, field) in data.fields.iter().enumerate() {
+            state.derive_field(index, field);
         }
 
         state.finish(&s, lifetime)
     }
 
     /// Derive handling for a particular `#[field(...)]`
-    fn derive_field(&mut self, field: &Field) {
+    fn derive_field(&mut self, index: usize, field: &Field) {
         let name = field
             .ident
             .as_ref()
             .cloned()
             .expect("no name on struct field i.e. tuple structs unsupported");
 
-        let asn1_type = Asn1Attrs::new(&field.attrs).asn1_type;
-        self.derive_field_decoder(&name, asn1_type);
-        self.derive_field_encoder(&name, asn1_type);
+        let attrs = Asn1Attrs::new(&field.attrs);
+
+        // Guard against struct fields being reordered, which would silently
+        // change the order of fields in the encoded `SEQUENCE`
+        if let Some(order) = attrs.order {
+            if order != index {
+                panic!(
+                    "field `{}` has #[asn1(order = {})] but is at position {} in the struct",
+                    name, order, index
+                );
+            }
+        }
+
+        if attrs.context_specific.is_some() {
+            self.derive_context_specific_field(&name, &attrs);
+        } else if attrs.has_context_specific_attrs() {
+            panic!(
+                "field `{}` has `tag_mode`, `default` or `optional` attributes, which require \
+                 #[asn1(context_specific = \"...\")]",
+                name
+            );
+        } else {
+            let asn1_type = attrs.asn1_type;
+            self.derive_field_decoder(&name, asn1_type);
+            self.derive_field_encoder(&name, asn1_type);
+        }
+    }
+
+    /// Derive handling for a `CONTEXT-SPECIFIC` field, which must either be
+    /// `OPTIONAL` or have a `DEFAULT` value.
+    ///
+    /// Fields with a `DEFAULT` value are decoded as the default when absent,
+    /// and omitted when encoding if equal to it, as DER requires.
+    fn derive_context_specific_field(&mut self, name: &Ident, attrs: &Asn1Attrs) {
+        if let Some(ty) = attrs.asn1_type {
+            panic!(
+                "field `{}` can't have both #[asn1(type = \"{}\")] and `context_specific`",
+                name, ty
+            );
+        }
+
+        let tag_number = attrs
+            .context_specific
+            .expect("not a context-specific field");
+        let tag_number = quote!(::der::TagNumber::new(#tag_number));
+        let tag_mode = attrs.tag_mode.unwrap_or(TagMode::Explicit).to_tokens();
+
+        let (field_decoder, field_encoder) = match (&attrs.default, attrs.optional) {
+            (Some(default), false) => (
+                quote! {
+                    let #name = ::der::asn1::ContextSpecific::decode_with_default(
+                        decoder,
+                        #tag_number,
+                        #tag_mode,
+                        #default(),
+                    )?
+                    .value;
+                },
+                quote! {
+                    &::der::asn1::ContextSpecific {
+                        tag_number: #tag_number,
+                        tag_mode: #tag_mode,
+                        value: ::core::clone::Clone::clone(&self.#name),
+                    }
+                    .skip_default(&#default()),
+                },
+            ),
+            (None, true) => (
+                quote! {
+                    let #name = decoder.context_specific(#tag_number, #tag_mode)?;
+                },
+                quote! {
+                    &self.#name.as_ref().map(|value| ::der::asn1::ContextSpecific {
+                        tag_number: #tag_number,
+                        tag_mode: #tag_mode,
+                        value: ::core::clone::Clone::clone(value),
+                    }),
+                },
+            ),
+            (Some(_), true) => panic!(
+                "field `{}` can't be both `optional` and have a `default`",
+                name
+            ),
+            (None, false) => panic!(
+                "context-specific field `{}` must be `optional` or have a `default`",
+                name
+            ),
+        };
+
+        field_decoder.to_tokens(&mut self.decode_fields);
+        quote!(#name,).to_tokens(&mut self.decode_result);
+        field_encoder.to_tokens(&mut self.encode_fields);
     }
 
     /// Derive code for decoding a field of a sequence
@@ -126,3 +215,48 @@ impl DeriveSequence {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::DeriveSequence;
+    use syn::{parse_quote, Data, DeriveInput};
+    use synstructure::Structure;
+
+    /// Run the `Sequence` custom derive on the given struct.
+    fn derive(input: DeriveInput) {
+        let data = match &input.data {
+            Data::Struct(data) => data,
+            _ => panic!("not a struct"),
+        };
+
+        DeriveSequence::derive(Structure::new(&input), data, None);
+    }
+
+    #[test]
+    fn fields_in_order() {
+        derive(parse_quote! {
+            struct AlgorithmIdentifier<'a> {
+                #[asn1(order = 0)]
+                algorithm: ObjectIdentifier,
+
+                #[asn1(order = 1)]
+                parameters: Option<Any<'a>>,
+            }
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "field `parameters` has #[asn1(order = 0)] but is at position 1 in the struct"
+    )]
+    fn fields_out_of_order() {
+        derive(parse_quote! {
+            struct AlgorithmIdentifier<'a> {
+                algorithm: ObjectIdentifier,
+
+                #[asn1(order = 0)]
+                parameters: Option<Any<'a>>,
+            }
+        });
+    }
+}
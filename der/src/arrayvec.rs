@@ -24,13 +24,22 @@ impl<T, const N: usize> ArrayVec<T, N> {
         }
     }
 
+    /// Create a new [`ArrayVec`] containing all of the elements of the given
+    /// array.
+    pub fn from_array(array: [T; N]) -> Self {
+        Self {
+            elements: array.map(Some),
+            length: N,
+        }
+    }
+
     /// Add an element to this [`ArrayVec`].
     ///
     /// Items MUST be added in lexicographical order according to the `Ord`
     /// impl on `T`.
     pub fn add(&mut self, element: T) -> Result<()> {
         match self.length.checked_add(1) {
-            Some(n) if n < N => {
+            Some(n) if n <= N => {
                 self.elements[self.length] = Some(element);
                 self.length = n;
                 Ok(())
@@ -39,6 +48,24 @@ impl<T, const N: usize> ArrayVec<T, N> {
         }
     }
 
+    /// Insert an element at the given index, shifting all elements after it
+    /// to the right.
+    pub fn insert(&mut self, index: usize, element: T) -> Result<()> {
+        if index > self.length {
+            return Err(ErrorKind::Overflow.into());
+        }
+
+        match self.length.checked_add(1) {
+            Some(n) if n <= N => {
+                self.elements[index..n].rotate_right(1);
+                self.elements[index] = Some(element);
+                self.length = n;
+                Ok(())
+            }
+            _ => Err(ErrorKind::Overlength.into()),
+        }
+    }
+
     /// Get an element from this [`ArrayVec`].
     pub fn get(&self, index: usize) -> Option<&T> {
         match self.elements.get(index) {
@@ -47,6 +74,16 @@ impl<T, const N: usize> ArrayVec<T, N> {
         }
     }
 
+    /// Get the number of elements in this [`ArrayVec`].
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Is this [`ArrayVec`] empty?
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
     /// Get the last item from this [`ArrayVec`].
     pub fn last(&self) -> Option<&T> {
         self.length.checked_sub(1).and_then(|n| self.get(n))
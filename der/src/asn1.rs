@@ -2,42 +2,79 @@
 
 mod any;
 mod bit_string;
+mod bmp_string;
 mod boolean;
 mod choice;
 mod context_specific;
+mod date;
+mod date_and_time;
+mod embedded_pdv;
+mod external;
+mod general_string;
 mod generalized_time;
 mod ia5_string;
 mod integer;
 mod null;
+mod numeric_string;
 mod octet_string;
 #[cfg(feature = "oid")]
 mod oid;
+#[cfg(feature = "oid")]
+mod open_type;
 mod optional;
 mod printable_string;
+mod relative_oid;
 mod sequence;
 mod sequence_of;
 mod set_of;
+mod teletex_string;
+mod time;
+mod time_of_day;
+mod universal_string;
 mod utc_time;
 mod utf8_string;
+mod visible_string;
 
 pub use self::{
     any::Any,
     bit_string::BitString,
+    bmp_string::BmpString,
     choice::Choice,
-    context_specific::ContextSpecific,
-    generalized_time::GeneralizedTime,
+    context_specific::{ContextSpecific, ContextSpecificExplicit, ContextSpecificImplicit},
+    date::Date,
+    date_and_time::DateAndTime,
+    embedded_pdv::EmbeddedPdv,
+    external::External,
+    general_string::GeneralString,
+    generalized_time::{GeneralizedTime, GeneralizedTimeLenient},
     ia5_string::Ia5String,
-    integer::bigint::UIntBytes,
+    integer::bigint::{IntBytes, UIntBytes},
     null::Null,
+    numeric_string::NumericString,
     octet_string::OctetString,
     printable_string::PrintableString,
+    relative_oid::RelativeOid,
     sequence::Sequence,
-    sequence_of::{SequenceOf, SequenceOfIter},
+    sequence_of::{
+        LazySequenceOf, LazySequenceOfIter, SequenceOf, SequenceOfDecodeError, SequenceOfIter,
+    },
     set_of::{SetOf, SetOfIter},
+    teletex_string::TeletexString,
+    time::Time,
+    time_of_day::TimeOfDay,
+    universal_string::UniversalString,
     utc_time::UtcTime,
     utf8_string::Utf8String,
+    visible_string::VisibleString,
 };
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::set_of::SetOfVec;
+
 #[cfg(feature = "oid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
-pub use const_oid::ObjectIdentifier;
+pub use {
+    self::open_type::{OidValuePair, OpenTypeDecoder, OpenTypeRegistry},
+    const_oid::ObjectIdentifier,
+};
@@ -30,7 +30,7 @@ pub struct Any<'a> {
 impl<'a> Any<'a> {
     /// Create a new [`Any`] from the provided [`Tag`] and byte slice.
     pub fn new(tag: Tag, bytes: &'a [u8]) -> Result<Self> {
-        let value = ByteSlice::new(bytes).map_err(|_| ErrorKind::Length { tag })?;
+        let value = ByteSlice::new(bytes).map_err(|_| ErrorKind::Length { tag, length: None })?;
         Ok(Self { tag, value })
     }
 
@@ -78,6 +78,26 @@ impl<'a> Any<'a> {
         self.try_into()
     }
 
+    /// Attempt to decode an ASN.1 `BMPString`.
+    pub fn bmp_string(self) -> Result<BmpString<'a>> {
+        self.try_into()
+    }
+
+    /// Attempt to decode an ASN.1 `DATE`.
+    pub fn date(self) -> Result<Date> {
+        self.try_into()
+    }
+
+    /// Attempt to decode an ASN.1 `DATE-TIME`.
+    pub fn date_and_time(self) -> Result<DateAndTime> {
+        self.try_into()
+    }
+
+    /// Attempt to decode an ASN.1 `GeneralString`.
+    pub fn general_string(self) -> Result<GeneralString<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode an ASN.1 `GeneralizedTime`.
     pub fn generalized_time(self) -> Result<GeneralizedTime> {
         self.try_into()
@@ -88,6 +108,11 @@ impl<'a> Any<'a> {
         self.try_into()
     }
 
+    /// Attempt to decode an ASN.1 `NumericString`.
+    pub fn numeric_string(self) -> Result<NumericString<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode an ASN.1 `OCTET STRING`.
     pub fn octet_string(self) -> Result<OctetString<'a>> {
         self.try_into()
@@ -117,6 +142,11 @@ impl<'a> Any<'a> {
         self.try_into()
     }
 
+    /// Attempt to decode an ASN.1 `RELATIVE-OID`.
+    pub fn relative_oid(self) -> Result<RelativeOid<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode this value an ASN.1 `SEQUENCE`, creating a new
     /// nested [`Decoder`] and calling the provided argument with it.
     pub fn sequence<F, T>(self, f: F) -> Result<T>
@@ -129,15 +159,40 @@ impl<'a> Any<'a> {
         seq_decoder.finish(result)
     }
 
+    /// Attempt to decode an ASN.1 `TeletexString`.
+    pub fn teletex_string(self) -> Result<TeletexString<'a>> {
+        self.try_into()
+    }
+
+    /// Attempt to decode an ASN.1 `TIME`.
+    pub fn time(self) -> Result<Time<'a>> {
+        self.try_into()
+    }
+
+    /// Attempt to decode an ASN.1 `TIME-OF-DAY`.
+    pub fn time_of_day(self) -> Result<TimeOfDay> {
+        self.try_into()
+    }
+
     /// Attempt to decode an ASN.1 `UTCTime`.
     pub fn utc_time(self) -> Result<UtcTime> {
         self.try_into()
     }
 
+    /// Attempt to decode an ASN.1 `UniversalString`.
+    pub fn universal_string(self) -> Result<UniversalString<'a>> {
+        self.try_into()
+    }
+
     /// Attempt to decode an ASN.1 `UTF8String`.
     pub fn utf8_string(self) -> Result<Utf8String<'a>> {
         self.try_into()
     }
+
+    /// Attempt to decode an ASN.1 `VisibleString`.
+    pub fn visible_string(self) -> Result<VisibleString<'a>> {
+        self.try_into()
+    }
 }
 
 impl<'a> Choice<'a> for Any<'a> {
@@ -148,16 +203,19 @@ impl<'a> Choice<'a> for Any<'a> {
 
 impl<'a> Decodable<'a> for Any<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> Result<Any<'a>> {
-        let header = Header::decode(decoder)?;
-        let tag = header.tag;
-        let value = ByteSlice::decode_value(decoder, header.length)?;
-        Ok(Self { tag, value })
+        decoder.tlv(|decoder, header| {
+            let value = ByteSlice::decode_value(decoder, header.length)?;
+            Ok(Self {
+                tag: header.tag,
+                value,
+            })
+        })
     }
 }
 
 impl<'a> Encodable for Any<'a> {
     fn encoded_len(&self) -> Result<Length> {
-        self.value.len().for_tlv()
+        self.value.len().tlv_len(self.tag)
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
@@ -4,7 +4,13 @@ use crate::{
     asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, Length,
     Result, Tag, Tagged,
 };
-use core::convert::TryFrom;
+use core::{convert::TryFrom, slice, slice::SliceIndex};
+
+#[cfg(feature = "flagset")]
+use {
+    core::{mem::size_of, ops::Shl},
+    flagset::FlagSet,
+};
 
 /// ASN.1 `BIT STRING` type.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -19,8 +25,14 @@ pub struct BitString<'a> {
 impl<'a> BitString<'a> {
     /// Create a new ASN.1 `BIT STRING` from a byte slice.
     pub fn new(bytes: &'a [u8]) -> Result<Self> {
-        let inner = ByteSlice::new(bytes).map_err(|_| ErrorKind::Length { tag: Self::TAG })?;
-        let encoded_len = (inner.len() + 1u8).map_err(|_| ErrorKind::Length { tag: Self::TAG })?;
+        let inner = ByteSlice::new(bytes).map_err(|_| ErrorKind::Length {
+            tag: Self::TAG,
+            length: None,
+        })?;
+        let encoded_len = (inner.len() + 1u8).map_err(|_| ErrorKind::Length {
+            tag: Self::TAG,
+            length: None,
+        })?;
         Ok(Self { inner, encoded_len })
     }
 
@@ -38,6 +50,37 @@ impl<'a> BitString<'a> {
     pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
+
+    /// Iterate over the bytes of this `BIT STRING`.
+    pub fn iter(&self) -> slice::Iter<'a, u8> {
+        self.as_bytes().iter()
+    }
+
+    /// Get a subslice of this `BIT STRING` as a new `BIT STRING` which
+    /// borrows from the same data, or `None` if `index` is out of bounds.
+    ///
+    /// Indexes into the inner byte slice (sans leading `0` byte).
+    pub fn get<I>(&self, index: I) -> Option<Self>
+    where
+        I: SliceIndex<[u8], Output = [u8]>,
+    {
+        self.inner
+            .get(index)
+            .and_then(|inner| Self::new(inner.as_bytes()).ok())
+    }
+
+    /// Split this `BIT STRING` in two at the given offset within the inner
+    /// byte slice (sans leading `0` byte).
+    ///
+    /// Returns an error if `mid` is greater than [`BitString::len`].
+    pub fn split_at(&self, mid: Length) -> Result<(Self, Self)> {
+        let (head, tail) = self.inner.split_at(mid).ok_or(ErrorKind::Length {
+            tag: Self::TAG,
+            length: Some(mid),
+        })?;
+
+        Ok((Self::new(head.as_bytes())?, Self::new(tail.as_bytes())?))
+    }
 }
 
 impl AsRef<[u8]> for BitString<'_> {
@@ -46,6 +89,15 @@ impl AsRef<[u8]> for BitString<'_> {
     }
 }
 
+impl<'a> IntoIterator for BitString<'a> {
+    type Item = &'a u8;
+    type IntoIter = slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> slice::Iter<'a, u8> {
+        self.iter()
+    }
+}
+
 impl<'a> DecodeValue<'a> for BitString<'a> {
     fn decode_value(decoder: &mut Decoder<'a>, encoded_len: Length) -> Result<Self> {
         // The prefix octet indicates the the number of bits which are
@@ -96,9 +148,124 @@ impl<'a> Tagged for BitString<'a> {
     const TAG: Tag = Tag::BitString;
 }
 
+/// ASN.1 `BIT STRING` with named bits, i.e. `BIT STRING { a(0), b(1), ... }`.
+///
+/// Bit `n` of the `BIT STRING` corresponds to the flag whose value is
+/// `1 << n`. As DER requires for named bit lists, trailing zero bits are
+/// removed when encoding and rejected when decoding. Bits without a
+/// corresponding flag are ignored when decoding.
+#[cfg(feature = "flagset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flagset")))]
+impl<'a, T> DecodeValue<'a> for FlagSet<T>
+where
+    T: flagset::Flags,
+    T::Type: From<bool> + Shl<usize, Output = T::Type>,
+{
+    fn decode_value(decoder: &mut Decoder<'a>, encoded_len: Length) -> Result<Self> {
+        // The prefix octet indicates the number of unused bits in the final
+        // byte, which must be zero, and the last bit must be set.
+        let unused_bits = decoder.byte()?;
+        let bytes = ByteSlice::decode_value(decoder, (encoded_len - Length::ONE)?)?.as_bytes();
+
+        let canonical = match bytes.last() {
+            Some(&last) => {
+                unused_bits < 8 && last & (0xFF >> (7 - unused_bits)) == 1 << unused_bits
+            }
+            None => unused_bits == 0,
+        };
+
+        if !canonical {
+            return Err(Self::TAG.non_canonical_error());
+        }
+
+        let bit_len = bytes.len() * 8 - usize::from(unused_bits);
+
+        if bit_len > flag_bits::<T>() {
+            return Err(Self::TAG.value_error());
+        }
+
+        let mut bits = T::Type::default();
+
+        for n in 0..bit_len {
+            if bytes[n / 8] & (0x80 >> (n % 8)) != 0 {
+                bits |= T::Type::from(true) << n;
+            }
+        }
+
+        Ok(Self::new_truncated(bits))
+    }
+}
+
+#[cfg(feature = "flagset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flagset")))]
+impl<T> EncodeValue for FlagSet<T>
+where
+    T: flagset::Flags,
+    T::Type: From<bool> + Shl<usize, Output = T::Type>,
+{
+    fn value_len(&self) -> Result<Length> {
+        Length::try_from((named_bit_len(*self) + 7) / 8)? + Length::ONE
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        let bit_len = named_bit_len(*self);
+        let unused_bits = (8 - bit_len % 8) % 8;
+        encoder.byte(unused_bits as u8)?;
+
+        for i in 0..(bit_len + 7) / 8 {
+            let mut byte = 0;
+
+            for j in 0..8 {
+                if is_set(*self, i * 8 + j) {
+                    byte |= 0x80 >> j;
+                }
+            }
+
+            encoder.byte(byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "flagset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flagset")))]
+impl<T: flagset::Flags> Tagged for FlagSet<T> {
+    const TAG: Tag = Tag::BitString;
+}
+
+/// Number of bits which can be represented by the flags of type `T`.
+#[cfg(feature = "flagset")]
+fn flag_bits<T: flagset::Flags>() -> usize {
+    size_of::<T::Type>() * 8
+}
+
+/// Is the flag with value `1 << n` set?
+#[cfg(feature = "flagset")]
+fn is_set<T>(flags: FlagSet<T>, n: usize) -> bool
+where
+    T: flagset::Flags,
+    T::Type: From<bool> + Shl<usize, Output = T::Type>,
+{
+    n < flag_bits::<T>() && flags.bits() & (T::Type::from(true) << n) != T::Type::default()
+}
+
+/// Length in bits of the DER encoding of `flags`, i.e. sans trailing zeros.
+#[cfg(feature = "flagset")]
+fn named_bit_len<T>(flags: FlagSet<T>) -> usize
+where
+    T: flagset::Flags,
+    T::Type: From<bool> + Shl<usize, Output = T::Type>,
+{
+    (0..flag_bits::<T>())
+        .rev()
+        .find(|&n| is_set(flags, n))
+        .map_or(0, |n| n + 1)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{BitString, Result, Tag};
+    use super::{BitString, Length, Result, Tag};
     use crate::asn1::Any;
     use core::convert::TryInto;
 
@@ -118,4 +285,88 @@ mod tests {
         let bs = parse_bitstring_from_any(&[0, 1, 2, 3]).unwrap();
         assert_eq!(bs.as_ref(), &[1, 2, 3]);
     }
+
+    #[test]
+    fn subslice_bitstring() {
+        let bs = parse_bitstring_from_any(&[0, 1, 2, 3]).unwrap();
+        let sub = bs.get(1..).unwrap();
+        assert_eq!(sub.as_bytes(), &[2, 3]);
+        assert_eq!(sub.encoded_len, Length::from(3u8));
+        assert!(bs.get(..4).is_none());
+
+        let (head, tail) = bs.split_at(Length::ONE).unwrap();
+        assert_eq!(head.as_bytes(), &[1]);
+        assert_eq!(tail.as_bytes(), &[2, 3]);
+        assert!(bs.split_at(Length::from(4u8)).is_err());
+
+        assert_eq!(bs.iter().copied().sum::<u8>(), 6);
+    }
+
+    #[cfg(feature = "flagset")]
+    mod flagset {
+        use crate::{Decodable, Encodable};
+        use flagset::{flags, FlagSet};
+        use hex_literal::hex;
+
+        flags! {
+            /// `KeyUsage` as defined in RFC 5280 Section 4.2.1.3.
+            enum KeyUsage: u16 {
+                DigitalSignature = 1 << 0,
+                NonRepudiation = 1 << 1,
+                KeyEncipherment = 1 << 2,
+                DataEncipherment = 1 << 3,
+                KeyAgreement = 1 << 4,
+                KeyCertSign = 1 << 5,
+                CrlSign = 1 << 6,
+                EncipherOnly = 1 << 7,
+                DecipherOnly = 1 << 8,
+            }
+        }
+
+        #[test]
+        fn encode_decode_named_bits() {
+            for (flags, der) in &[
+                (FlagSet::default(), &hex!("030100")[..]),
+                (KeyUsage::DigitalSignature.into(), &hex!("03020780")),
+                (KeyUsage::KeyCertSign | KeyUsage::CrlSign, &hex!("03020106")),
+                (KeyUsage::DecipherOnly.into(), &hex!("0303070080")),
+                (
+                    KeyUsage::DigitalSignature | KeyUsage::EncipherOnly | KeyUsage::DecipherOnly,
+                    &hex!("0303078180"),
+                ),
+            ] {
+                let flags: FlagSet<KeyUsage> = *flags;
+                let mut buffer = [0u8; 8];
+                assert_eq!(flags.encode_to_slice(&mut buffer).unwrap(), *der);
+                assert_eq!(FlagSet::<KeyUsage>::from_der(der).unwrap(), flags);
+            }
+        }
+
+        #[test]
+        fn reject_non_canonical_named_bits() {
+            for der in &[
+                // Trailing zero bits
+                &hex!("03020080")[..],
+                &hex!("0303078000"),
+                // Non-zero unused bits
+                &hex!("03020781"),
+                // Unused bits without any bytes
+                &hex!("030101"),
+                // More than 7 unused bits
+                &hex!("03020880"),
+            ] {
+                assert!(FlagSet::<KeyUsage>::from_der(der).is_err());
+            }
+        }
+
+        #[test]
+        fn named_bits_overflow() {
+            // Bit 16 doesn't fit in `u16`
+            assert!(FlagSet::<KeyUsage>::from_der(&hex!("030407000080")).is_err());
+
+            // Bits without a corresponding flag are ignored
+            let flags = FlagSet::<KeyUsage>::from_der(&hex!("0303068040")).unwrap();
+            assert_eq!(flags, KeyUsage::DigitalSignature);
+        }
+    }
 }
@@ -0,0 +1,241 @@
+//! ASN.1 `BMPString` support.
+
+use crate::{
+    asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, Length,
+    Result, Tag, Tagged,
+};
+use core::{
+    char,
+    convert::TryFrom,
+    fmt::{self, Write},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// ASN.1 `BMPString` type.
+///
+/// Supports characters of the Basic Multilingual Plane (BMP) of the
+/// Universal Character Set, encoded as big endian 16-bit code units. Like
+/// many other implementations, this type accepts any valid UTF-16BE input,
+/// i.e. surrogate pairs are permitted as long as they are well-formed.
+///
+/// Commonly found in X.509 `DirectoryString` values and in the
+/// `friendlyName` attribute of PKCS#12 bags.
+///
+/// For UTF-8, use [`Utf8String`][`crate::asn1::Utf8String`].
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct BmpString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> BmpString<'a> {
+    /// Create a new `BMPString` from its UTF-16BE encoding.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+        Self::validate(input)?;
+
+        ByteSlice::new(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Validate the input is well-formed UTF-16BE.
+    fn validate(input: &[u8]) -> Result<()> {
+        if input.len() % 2 != 0 {
+            return Err(ErrorKind::Length {
+                tag: Self::TAG,
+                length: Length::try_from(input.len()).ok(),
+            }
+            .into());
+        }
+
+        if char::decode_utf16(code_units(input)).any(|c| c.is_err()) {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(())
+    }
+
+    /// Iterate over the characters of this string.
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        // Input is validated on construction, so decoding can't fail
+        char::decode_utf16(code_units(self.as_bytes()))
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Borrow the UTF-16BE encoding of this string.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for BmpString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for BmpString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, length)?.as_bytes())
+    }
+}
+
+impl<'a> EncodeValue for BmpString<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> From<&BmpString<'a>> for BmpString<'a> {
+    fn from(value: &BmpString<'a>) -> BmpString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for BmpString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<BmpString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<BmpString<'a>> for Any<'a> {
+    fn from(bmp_string: BmpString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::BmpString, bmp_string.inner)
+    }
+}
+
+impl<'a> From<BmpString<'a>> for &'a [u8] {
+    fn from(bmp_string: BmpString<'a>) -> &'a [u8] {
+        bmp_string.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> From<BmpString<'a>> for String {
+    fn from(bmp_string: BmpString<'a>) -> String {
+        bmp_string.chars().collect()
+    }
+}
+
+impl<'a> Tagged for BmpString<'a> {
+    const TAG: Tag = Tag::BmpString;
+}
+
+impl<'a> fmt::Display for BmpString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|c| f.write_char(c))
+    }
+}
+
+impl<'a> fmt::Debug for BmpString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BmpString(\"")?;
+        self.chars()
+            .flat_map(|c| c.escape_debug())
+            .try_for_each(|c| f.write_char(c))?;
+        f.write_str("\")")
+    }
+}
+
+/// Iterate over the big endian 16-bit code units of the given bytes.
+fn code_units(bytes: &[u8]) -> impl Iterator<Item = u16> + '_ {
+    bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BmpString;
+    use crate::{Decodable, Encodable, ErrorKind, Length, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() {
+        // BMPString "Hello" as generated by `openssl asn1parse -genstr 'BMPSTRING:Hello'`
+        let example_bytes = hex!("1e 0a 00 48 00 65 00 6c 00 6c 00 6f");
+        let bmp_string = BmpString::from_der(&example_bytes).unwrap();
+        assert!(bmp_string.chars().eq("Hello".chars()));
+        assert_eq!(bmp_string.len(), 10u8.into());
+
+        let mut buf = [0u8; 12];
+        assert_eq!(bmp_string.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn surrogate_pairs() {
+        // "€𝄞": U+20AC followed by U+1D11E as a surrogate pair
+        let bmp_string = BmpString::new(&hex!("20ac d834 dd1e")).unwrap();
+        assert!(bmp_string.chars().eq("€𝄞".chars()));
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // Odd length
+        let err = BmpString::new(&hex!("0048 00")).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::BmpString,
+                length: Some(Length::new(3))
+            }
+        );
+
+        // Unpaired surrogate
+        let err = BmpString::new(&hex!("0048 d834")).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Value {
+                tag: Tag::BmpString
+            }
+        );
+
+        let err = BmpString::from_der(&hex!("1e 04 dd1e 0048")).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Value {
+                tag: Tag::BmpString
+            }
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fmt() {
+        use alloc::format;
+
+        let bmp_string = BmpString::new(&hex!("0022 00e9 0022")).unwrap();
+        assert_eq!(format!("{}", bmp_string), "\"é\"");
+        assert_eq!(format!("{:?}", bmp_string), "BmpString(\"\\\"é\\\"\")");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string() {
+        let bmp_string = BmpString::new(&hex!("004b 00e4 0073 0065")).unwrap();
+        assert_eq!(alloc::string::String::from(bmp_string), "Käse");
+    }
+}
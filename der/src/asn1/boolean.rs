@@ -18,7 +18,10 @@ const FALSE_OCTET: u8 = 0b00000000;
 impl<'a> DecodeValue<'a> for bool {
     fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
         if length != Length::ONE {
-            return Err(decoder.error(ErrorKind::Length { tag: Self::TAG }));
+            return Err(decoder.error(ErrorKind::Length {
+                tag: Self::TAG,
+                length: Some(length),
+            }));
         }
 
         match decoder.byte()? {
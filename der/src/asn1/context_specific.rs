@@ -2,9 +2,9 @@
 
 use crate::{
     asn1::Any, Choice, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error,
-    Header, Length, Result, Tag, TagMode, TagNumber, Tagged,
+    Header, Length, Result, ReverseEncoder, Tag, TagMode, TagNumber, Tagged,
 };
-use core::convert::{TryFrom, TryInto};
+use core::convert::TryFrom;
 
 /// Context-specific field.
 ///
@@ -47,13 +47,13 @@ impl<T> ContextSpecific<T> {
         T: Decodable<'a>,
     {
         Self::decode_with(decoder, tag_number, |decoder| {
-            let any = Any::decode(decoder)?;
+            decoder.tlv(|decoder, header| {
+                if !header.tag.is_constructed() {
+                    return Err(header.tag.non_canonical_error());
+                }
 
-            if !any.tag().is_constructed() {
-                return Err(any.tag().non_canonical_error());
-            }
-
-            Self::try_from(any)
+                Self::decode_explicit_value(decoder, header)
+            })
         })
     }
 
@@ -71,20 +71,90 @@ impl<T> ContextSpecific<T> {
         T: DecodeValue<'a> + Tagged,
     {
         Self::decode_with(decoder, tag_number, |decoder| {
-            let header = Header::decode(decoder)?;
+            decoder.tlv(|decoder, header| {
+                if header.tag.is_constructed() != T::TAG.is_constructed() {
+                    return Err(header.tag.non_canonical_error());
+                }
+
+                let value = T::decode_value(decoder, header.length)?;
+
+                Ok(Self {
+                    tag_number,
+                    tag_mode: TagMode::Implicit,
+                    value,
+                })
+            })
+        })
+    }
+
+    /// Attempt to decode an ASN.1 `CONTEXT-SPECIFIC` field with the provided
+    /// [`TagNumber`] and [`TagMode`] which is declared with a `DEFAULT`
+    /// value in the ASN.1 schema, e.g. `[0] EXPLICIT INTEGER DEFAULT 0`.
+    ///
+    /// Returns a field containing `default` if the field is absent, with the
+    /// same skipping behavior as `decode_explicit`. DER requires fields
+    /// equal to their default value to be omitted, so an encoded field
+    /// containing `default` is rejected as non-canonical.
+    pub fn decode_with_default<'a>(
+        decoder: &mut Decoder<'a>,
+        tag_number: TagNumber,
+        tag_mode: TagMode,
+        default: T,
+    ) -> Result<Self>
+    where
+        T: DecodeValue<'a> + Tagged + PartialEq,
+    {
+        let field = match tag_mode {
+            TagMode::Explicit => Self::decode_explicit(decoder, tag_number)?,
+            TagMode::Implicit => Self::decode_implicit(decoder, tag_number)?,
+        };
 
-            if header.tag.is_constructed() != T::TAG.is_constructed() {
-                return Err(header.tag.non_canonical_error());
+        match field {
+            Some(field) if field.value == default => {
+                Err(decoder.error(field.tag().non_canonical_error().kind()))
             }
+            Some(field) => Ok(field),
+            None => Ok(Self {
+                tag_number,
+                tag_mode,
+                value: default,
+            }),
+        }
+    }
 
-            let value = T::decode_value(decoder, header.length)?;
+    /// Get this field unless its value is `default`, in which case DER
+    /// requires it to be omitted from the encoding.
+    ///
+    /// The result can be encoded like an `OPTIONAL` field, i.e. nothing is
+    /// encoded for `None`.
+    pub fn skip_default(self, default: &T) -> Option<Self>
+    where
+        T: PartialEq,
+    {
+        if self.value == *default {
+            None
+        } else {
+            Some(self)
+        }
+    }
 
-            Ok(Self {
-                tag_number,
-                tag_mode: TagMode::Implicit,
-                value,
-            })
-        })
+    /// Decode the value of an `EXPLICIT` field with the given [`Header`],
+    /// using a nested decoder so it follows the same encoding rules.
+    fn decode_explicit_value<'a>(decoder: &mut Decoder<'a>, header: Header) -> Result<Self>
+    where
+        T: Decodable<'a>,
+    {
+        match header.tag {
+            Tag::ContextSpecific {
+                number,
+                constructed: true,
+            } => Ok(Self {
+                tag_number: number,
+                tag_mode: TagMode::default(),
+                value: decoder.decode_nested(header.length, T::decode)?,
+            }),
+            tag => Err(tag.unexpected_error(None)),
+        }
     }
 
     /// Attempt to decode a context-specific field as an [`Any`] type.
@@ -96,9 +166,7 @@ impl<T> ContextSpecific<T> {
     where
         F: FnOnce(&mut Decoder<'a>) -> Result<Self>,
     {
-        while let Some(octet) = decoder.peek() {
-            let tag = Tag::try_from(octet)?;
-
+        while let Some(tag) = decoder.peek_tag()? {
             if !tag.is_context_specific() || tag.number() > tag_number {
                 break;
             } else if tag.number() == tag_number {
@@ -142,7 +210,7 @@ where
     T: Decodable<'a>,
 {
     fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
-        Any::decode(decoder)?.try_into()
+        decoder.tlv(Self::decode_explicit_value)
     }
 }
 
@@ -151,13 +219,17 @@ where
     T: EncodeValue + Tagged,
 {
     fn encoded_len(&self) -> Result<Length> {
-        self.value_len()?.for_tlv()
+        self.value_len()?.tlv_len(self.tag())
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
         Header::new(self.tag(), self.value_len()?)?.encode(encoder)?;
         self.encode_value(encoder)
     }
+
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        encoder.tlv(self.tag(), |encoder| self.encode_value_reverse(encoder))
+    }
 }
 
 impl<T> EncodeValue for ContextSpecific<T>
@@ -177,6 +249,13 @@ where
             TagMode::Implicit => self.value.encode_value(encoder),
         }
     }
+
+    fn encode_value_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        match self.tag_mode {
+            TagMode::Explicit => encoder.encode(&self.value),
+            TagMode::Implicit => self.value.encode_value_reverse(encoder),
+        }
+    }
 }
 
 impl<'a, T> TryFrom<Any<'a>> for ContextSpecific<T>
@@ -200,10 +279,140 @@ where
     }
 }
 
+/// `EXPLICIT` context-specific field with the tag number `N`.
+///
+/// Unlike [`ContextSpecific`], the tag number and tag mode are part of the
+/// type, so this can be used as the type of a field in a struct which
+/// derives or implements [`Sequence`][`crate::Sequence`] and decoded or
+/// encoded like any other type. Wrap it in an [`Option`] for `OPTIONAL`
+/// fields, e.g. `[3] EXPLICIT Extensions OPTIONAL` maps to
+/// `Option<ContextSpecificExplicit<3, Extensions>>`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ContextSpecificExplicit<const N: u32, T> {
+    /// Value of the field.
+    pub value: T,
+}
+
+impl<const N: u32, T> From<T> for ContextSpecificExplicit<N, T> {
+    fn from(value: T) -> Self {
+        Self { value }
+    }
+}
+
+impl<'a, const N: u32, T> DecodeValue<'a> for ContextSpecificExplicit<N, T>
+where
+    T: Decodable<'a>,
+{
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        decoder.decode_nested(length, T::decode).map(Self::from)
+    }
+}
+
+impl<const N: u32, T> EncodeValue for ContextSpecificExplicit<N, T>
+where
+    T: Encodable,
+{
+    fn value_len(&self) -> Result<Length> {
+        self.value.encoded_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.value.encode(encoder)
+    }
+
+    fn encode_value_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        encoder.encode(&self.value)
+    }
+}
+
+impl<const N: u32, T> Tagged for ContextSpecificExplicit<N, T> {
+    const TAG: Tag = Tag::ContextSpecific {
+        number: TagNumber::new(N),
+        constructed: true,
+    };
+}
+
+impl<const N: u32, T> From<ContextSpecificExplicit<N, T>> for ContextSpecific<T> {
+    fn from(field: ContextSpecificExplicit<N, T>) -> Self {
+        Self {
+            tag_number: TagNumber::new(N),
+            tag_mode: TagMode::Explicit,
+            value: field.value,
+        }
+    }
+}
+
+/// `IMPLICIT` context-specific field with the tag number `N`.
+///
+/// This is the `IMPLICIT` counterpart of [`ContextSpecificExplicit`], e.g.
+/// `[1] IMPLICIT BIT STRING OPTIONAL` maps to
+/// `Option<ContextSpecificImplicit<1, BitString>>`. The field is
+/// constructed if and only if `T` is.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ContextSpecificImplicit<const N: u32, T> {
+    /// Value of the field.
+    pub value: T,
+}
+
+impl<const N: u32, T> From<T> for ContextSpecificImplicit<N, T> {
+    fn from(value: T) -> Self {
+        Self { value }
+    }
+}
+
+impl<'a, const N: u32, T> DecodeValue<'a> for ContextSpecificImplicit<N, T>
+where
+    T: DecodeValue<'a> + Tagged,
+{
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        T::decode_value(decoder, length).map(Self::from)
+    }
+}
+
+impl<const N: u32, T> EncodeValue for ContextSpecificImplicit<N, T>
+where
+    T: EncodeValue + Tagged,
+{
+    fn value_len(&self) -> Result<Length> {
+        self.value.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.value.encode_value(encoder)
+    }
+
+    fn encode_value_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        self.value.encode_value_reverse(encoder)
+    }
+}
+
+impl<const N: u32, T> Tagged for ContextSpecificImplicit<N, T>
+where
+    T: Tagged,
+{
+    const TAG: Tag = Tag::ContextSpecific {
+        number: TagNumber::new(N),
+        constructed: T::TAG.is_constructed(),
+    };
+}
+
+impl<const N: u32, T> From<ContextSpecificImplicit<N, T>> for ContextSpecific<T> {
+    fn from(field: ContextSpecificImplicit<N, T>) -> Self {
+        Self {
+            tag_number: TagNumber::new(N),
+            tag_mode: TagMode::Implicit,
+            value: field.value,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::ContextSpecific;
-    use crate::{asn1::BitString, Decodable, Decoder, Encodable, TagMode, TagNumber};
+    use super::{ContextSpecific, ContextSpecificExplicit, ContextSpecificImplicit};
+    use crate::{
+        asn1::BitString, Decodable, Decoder, Encodable, ErrorKind, Length, Tag, TagMode, TagNumber,
+        Tagged,
+    };
     use hex_literal::hex;
 
     // Public key data from `pkcs8` crate's `ed25519-pkcs8-v2.der`
@@ -295,4 +504,128 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn context_specific_with_default() {
+        let tag_number = TagNumber::new(0);
+
+        // Absent field
+        let mut decoder = Decoder::new(&hex!("020101"));
+        let field =
+            ContextSpecific::decode_with_default(&mut decoder, tag_number, TagMode::Explicit, 0u8)
+                .unwrap();
+        assert_eq!(field.tag_mode, TagMode::Explicit);
+        assert_eq!(field.value, 0);
+        assert_eq!(field.skip_default(&0), None);
+
+        // Present field
+        let mut decoder = Decoder::new(&hex!("A003020102"));
+        let field =
+            ContextSpecific::decode_with_default(&mut decoder, tag_number, TagMode::Explicit, 0u8)
+                .unwrap();
+        assert_eq!(field.value, 2);
+        assert_eq!(field.skip_default(&0), Some(field));
+
+        let mut decoder = Decoder::new(&hex!("800102"));
+        let field =
+            ContextSpecific::decode_with_default(&mut decoder, tag_number, TagMode::Implicit, 0u8)
+                .unwrap();
+        assert_eq!(field.tag_mode, TagMode::Implicit);
+        assert_eq!(field.value, 2);
+
+        // Field containing the default value
+        let mut decoder = Decoder::new(&hex!("A003020100"));
+        let err =
+            ContextSpecific::decode_with_default(&mut decoder, tag_number, TagMode::Explicit, 0u8)
+                .err()
+                .unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Noncanonical {
+                tag: Tag::ContextSpecific {
+                    constructed: true,
+                    number: tag_number
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn typed_explicit_field() {
+        type Field<'a> = ContextSpecificExplicit<1, BitString<'a>>;
+        assert_eq!(Field::TAG, TagNumber::new(1).context_specific(true));
+
+        let field = Field::from_der(EXAMPLE_BYTES).unwrap();
+        assert_eq!(field.value, BitString::new(&EXAMPLE_BYTES[5..]).unwrap());
+
+        let mut buf = [0u8; 128];
+        let encoded = field.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(encoded, EXAMPLE_BYTES);
+        assert_eq!(
+            ContextSpecific::from(field),
+            ContextSpecific::<BitString<'_>>::from_der(EXAMPLE_BYTES).unwrap()
+        );
+
+        // Wrong tag number or tag mode
+        assert!(ContextSpecificExplicit::<2, BitString<'_>>::from_der(EXAMPLE_BYTES).is_err());
+        assert!(ContextSpecificImplicit::<1, BitString<'_>>::from_der(EXAMPLE_BYTES).is_err());
+    }
+
+    #[test]
+    fn typed_implicit_field() {
+        let bytes = hex!("81210019BF44096984CDFE8541BAC167DC3B96C85086AA30B6B6CB0C5C38AD703166E1");
+
+        type Field<'a> = ContextSpecificImplicit<1, BitString<'a>>;
+        assert_eq!(Field::TAG, TagNumber::new(1).context_specific(false));
+
+        let field = Field::from_der(&bytes).unwrap();
+        assert_eq!(field.value.as_bytes(), &bytes[3..]);
+
+        let mut buf = [0u8; 128];
+        let encoded = field.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(encoded, bytes);
+    }
+
+    #[test]
+    fn high_tag_number_field() {
+        let bytes = hex!("BF8100030201059F817F0107");
+
+        let mut decoder = Decoder::new(&bytes);
+        let field = ContextSpecific::<u8>::decode_explicit(&mut decoder, TagNumber::new(128))
+            .unwrap()
+            .unwrap();
+        assert_eq!(field.value, 5);
+
+        let field: ContextSpecificImplicit<255, u8> = decoder.decode().unwrap();
+        assert_eq!(field.value, 7);
+        assert!(decoder.is_finished());
+
+        let mut buf = [0u8; 16];
+        let explicit = ContextSpecificExplicit::<128, u8>::from(5);
+        assert_eq!(explicit.encoded_len().unwrap(), Length::new(7));
+        assert_eq!(explicit.encode_to_slice(&mut buf).unwrap(), &bytes[..7]);
+        assert_eq!(field.encoded_len().unwrap(), Length::new(5));
+        assert_eq!(field.encode_to_slice(&mut buf).unwrap(), &bytes[7..]);
+    }
+
+    #[test]
+    fn typed_optional_fields() {
+        type First = Option<ContextSpecificExplicit<0, u8>>;
+        type Second = Option<ContextSpecificImplicit<1, u8>>;
+
+        let mut decoder = Decoder::new(&hex!("810105"));
+        assert_eq!(First::decode(&mut decoder).unwrap(), None);
+        assert_eq!(
+            Second::decode(&mut decoder).unwrap(),
+            Some(ContextSpecificImplicit::from(5))
+        );
+        assert!(decoder.is_finished());
+
+        let mut buf = [0u8; 16];
+        let first: First = Some(ContextSpecificExplicit::from(1));
+        assert_eq!(first.encode_to_slice(&mut buf).unwrap(), hex!("A003020101"));
+
+        let second: Second = None;
+        assert_eq!(second.encoded_len().unwrap(), Length::ZERO);
+    }
 }
@@ -0,0 +1,172 @@
+//! ASN.1 `DATE` support.
+
+use crate::{
+    asn1::Any,
+    datetime::{self, DateTime},
+    ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result, Tag, Tagged,
+};
+use core::convert::TryFrom;
+
+/// ASN.1 `DATE` type.
+///
+/// Introduced in the 2008 revision of X.680 as a subtype of `TIME`
+/// representing a calendar date:
+///
+/// ```text
+/// DATE ::= [UNIVERSAL 31] IMPLICIT TIME
+///     (SETTINGS "Basic=Date Date=YMD Year=Basic")
+/// ```
+///
+/// Encoded as `YYYYMMDD` (X.690 Section 8.26). Its universal tag number is
+/// greater than 30, so it uses a two octet tag (see [`Tag::Date`]).
+///
+/// The range of supported dates is the same as [`DateTime`], i.e. years
+/// 1970 through 9999.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Date(DateTime);
+
+impl Date {
+    /// Length of an ASN.1 DER-encoded [`Date`].
+    pub const LENGTH: Length = Length::new(8);
+
+    /// Create a new [`Date`] from its components.
+    pub fn new(year: u16, month: u16, day: u16) -> Result<Self> {
+        DateTime::new(year, month, day, 0, 0, 0)
+            .map(Self)
+            .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Create a [`Date`] from the date of a [`DateTime`], discarding the
+    /// time of day.
+    pub fn from_date_time(datetime: DateTime) -> Self {
+        Self(
+            DateTime::new(datetime.year(), datetime.month(), datetime.day(), 0, 0, 0)
+                .expect("DateTime invariant violated"),
+        )
+    }
+
+    /// Convert this [`Date`] into a [`DateTime`] at midnight of that day.
+    pub fn to_date_time(&self) -> DateTime {
+        self.0
+    }
+
+    /// Get the year.
+    pub fn year(&self) -> u16 {
+        self.0.year()
+    }
+
+    /// Get the month (1-12).
+    pub fn month(&self) -> u16 {
+        self.0.month()
+    }
+
+    /// Get the day of the month (1-31).
+    pub fn day(&self) -> u16 {
+        self.0.day()
+    }
+}
+
+impl DecodeValue<'_> for Date {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        match *ByteSlice::decode_value(decoder, length)?.as_bytes() {
+            [y1, y2, y3, y4, mon1, mon2, day1, day2] => {
+                let year = datetime::decode_decimal(Self::TAG, y1, y2)? * 100
+                    + datetime::decode_decimal(Self::TAG, y3, y4)?;
+                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
+                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
+                Self::new(year, month, day)
+            }
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+}
+
+impl EncodeValue for Date {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Self::LENGTH)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        datetime::encode_decimal(encoder, Self::TAG, self.year() / 100)?;
+        datetime::encode_decimal(encoder, Self::TAG, self.year() % 100)?;
+        datetime::encode_decimal(encoder, Self::TAG, self.month())?;
+        datetime::encode_decimal(encoder, Self::TAG, self.day())
+    }
+}
+
+impl From<&Date> for Date {
+    fn from(value: &Date) -> Date {
+        *value
+    }
+}
+
+impl From<Date> for DateTime {
+    fn from(date: Date) -> DateTime {
+        date.to_date_time()
+    }
+}
+
+impl From<DateTime> for Date {
+    fn from(datetime: DateTime) -> Self {
+        Self::from_date_time(datetime)
+    }
+}
+
+impl TryFrom<Any<'_>> for Date {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<Date> {
+        any.decode_into()
+    }
+}
+
+impl Tagged for Date {
+    const TAG: Tag = Tag::Date;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Date;
+    use crate::{asn1::Any, DateTime, Decodable, Encodable, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        // DATE "2012-12-21"
+        let example_bytes = hex!("1f1f 08 3230313231323231");
+        let date = Date::from_der(&example_bytes).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2012, 12, 21));
+        assert_eq!(date.to_date_time().unix_duration().as_secs(), 1356048000);
+
+        let mut buf = [0u8; 11];
+        assert_eq!(date.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn from_date_time() {
+        let datetime = DateTime::new(2012, 12, 21, 11, 12, 13).unwrap();
+        assert_eq!(Date::from(datetime), Date::new(2012, 12, 21).unwrap());
+    }
+
+    #[test]
+    fn optional() {
+        // SEQUENCE { DATE "2012-12-21" }
+        let der = hex!("300b 1f1f 08 3230313231323231");
+        let any = Any::from_der(&der).unwrap();
+        let date: Option<Date> = any.sequence(|decoder| decoder.decode()).unwrap();
+        assert_eq!(date, Some(Date::new(2012, 12, 21).unwrap()));
+        assert_eq!(Any::from_der(&der[2..]).unwrap().tag(), Tag::Date);
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // 2012-12-32
+        assert!(Date::from_der(&hex!("1f1f 08 3230313231323332")).is_err());
+        // Truncated
+        assert!(Date::from_der(&hex!("1f1f 07 32303132313232")).is_err());
+        // Extended format
+        assert!(Date::from_der(&hex!("1f1f 0a 323031322d31322d3231")).is_err());
+        // Before 1970
+        assert!(Date::from_der(&hex!("1f1f 08 3139363931323331")).is_err());
+    }
+}
@@ -0,0 +1,187 @@
+//! ASN.1 `DATE-TIME` support.
+
+use crate::{
+    asn1::{Any, Date, TimeOfDay},
+    datetime::{self, DateTime},
+    ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result, Tag, Tagged,
+};
+use core::convert::TryFrom;
+
+/// ASN.1 `DATE-TIME` type.
+///
+/// Introduced in the 2008 revision of X.680 as a subtype of `TIME`
+/// representing a calendar date and local time of day with a precision of
+/// one second:
+///
+/// ```text
+/// DATE-TIME ::= [UNIVERSAL 33] IMPLICIT TIME
+///     (SETTINGS "Basic=Date-Time Date=YMD Year=Basic Time=HMS Local-or-UTC=L")
+/// ```
+///
+/// Encoded as `YYYYMMDDHHMMSS` (X.690 Section 8.26). Its universal tag
+/// number is greater than 30, so it uses a two octet tag (see
+/// [`Tag::DateTime`]). It's named `DateAndTime` to avoid confusion with
+/// [`DateTime`].
+///
+/// Values carry no time zone: conversions to and from [`DateTime`], which
+/// is Z-normalized, keep the components as-is, so it's up to the protocol
+/// to define which time zone applies.
+///
+/// The range of supported dates is the same as [`DateTime`], i.e. years
+/// 1970 through 9999.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct DateAndTime(DateTime);
+
+impl DateAndTime {
+    /// Length of an ASN.1 DER-encoded [`DateAndTime`].
+    pub const LENGTH: Length = Length::new(14);
+
+    /// Create a new [`DateAndTime`] from a [`Date`] and a [`TimeOfDay`].
+    pub fn new(date: Date, time_of_day: TimeOfDay) -> Result<Self> {
+        DateTime::new(
+            date.year(),
+            date.month(),
+            date.day(),
+            time_of_day.hour(),
+            time_of_day.minutes(),
+            time_of_day.seconds(),
+        )
+        .map(Self)
+        .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Create a [`DateAndTime`] from a [`DateTime`].
+    pub fn from_date_time(datetime: DateTime) -> Self {
+        Self(datetime)
+    }
+
+    /// Convert this [`DateAndTime`] into a [`DateTime`].
+    pub fn to_date_time(&self) -> DateTime {
+        self.0
+    }
+
+    /// Get the [`Date`] component.
+    pub fn date(&self) -> Date {
+        Date::from_date_time(self.0)
+    }
+
+    /// Get the [`TimeOfDay`] component.
+    pub fn time_of_day(&self) -> TimeOfDay {
+        TimeOfDay::from_date_time(self.0)
+    }
+}
+
+impl DecodeValue<'_> for DateAndTime {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        match *ByteSlice::decode_value(decoder, length)?.as_bytes() {
+            [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2] => {
+                let year = datetime::decode_decimal(Self::TAG, y1, y2)? * 100
+                    + datetime::decode_decimal(Self::TAG, y3, y4)?;
+                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
+                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
+                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
+                let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
+                let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
+
+                DateTime::new(year, month, day, hour, minute, second)
+                    .map(Self)
+                    .map_err(|_| Self::TAG.value_error())
+            }
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+}
+
+impl EncodeValue for DateAndTime {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Self::LENGTH)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.date().encode_value(encoder)?;
+        self.time_of_day().encode_value(encoder)
+    }
+}
+
+impl From<&DateAndTime> for DateAndTime {
+    fn from(value: &DateAndTime) -> DateAndTime {
+        *value
+    }
+}
+
+impl From<DateAndTime> for DateTime {
+    fn from(date_and_time: DateAndTime) -> DateTime {
+        date_and_time.0
+    }
+}
+
+impl From<DateTime> for DateAndTime {
+    fn from(datetime: DateTime) -> Self {
+        Self::from_date_time(datetime)
+    }
+}
+
+impl TryFrom<Any<'_>> for DateAndTime {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<DateAndTime> {
+        any.decode_into()
+    }
+}
+
+impl Tagged for DateAndTime {
+    const TAG: Tag = Tag::DateTime;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateAndTime;
+    use crate::{
+        asn1::{Date, TimeOfDay},
+        DateTime, Decodable, Encodable,
+    };
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        // DATE-TIME "2012-12-21T11:12:13"
+        let example_bytes = hex!("1f21 0e 3230313231323231313131323133");
+        let date_and_time = DateAndTime::from_der(&example_bytes).unwrap();
+        assert_eq!(
+            date_and_time.to_date_time(),
+            DateTime::new(2012, 12, 21, 11, 12, 13).unwrap()
+        );
+        assert_eq!(date_and_time.date(), Date::new(2012, 12, 21).unwrap());
+        assert_eq!(
+            date_and_time.time_of_day(),
+            TimeOfDay::new(11, 12, 13).unwrap()
+        );
+
+        let mut buf = [0u8; 17];
+        assert_eq!(
+            date_and_time.encode_to_slice(&mut buf).unwrap(),
+            example_bytes
+        );
+    }
+
+    #[test]
+    fn new() {
+        let date_and_time = DateAndTime::new(
+            Date::new(2012, 12, 21).unwrap(),
+            TimeOfDay::new(11, 12, 13).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            DateTime::from(date_and_time),
+            DateTime::new(2012, 12, 21, 11, 12, 13).unwrap()
+        );
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // 'T' separator
+        assert!(DateAndTime::from_der(&hex!("1f21 0f 3230313231323231 54 313131323133")).is_err());
+        // 2012-02-30
+        assert!(DateAndTime::from_der(&hex!("1f21 0e 3230313230323330313131323133")).is_err());
+    }
+}
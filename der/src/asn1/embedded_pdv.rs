@@ -0,0 +1,166 @@
+//! ASN.1 `EMBEDDED PDV` support.
+
+use crate::{
+    asn1::{Any, ContextSpecific, OctetString},
+    DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, ErrorKind, Header, Length,
+    Result, Tag, TagMode, TagNumber, Tagged,
+};
+use core::convert::TryFrom;
+
+/// ASN.1 `EMBEDDED PDV` type.
+///
+/// Encoded as the following structure, as described in X.690 Section 8.19:
+///
+/// ```text
+/// EMBEDDED PDV ::= [UNIVERSAL 11] IMPLICIT SEQUENCE {
+///     identification [0] EXPLICIT CHOICE {
+///         syntaxes                [0] ...,
+///         syntax                  [1] ...,
+///         presentation-context-id [2] ...,
+///         context-negotiation     [3] ...,
+///         transfer-syntax         [4] ...,
+///         fixed                   [5] ... },
+///     data-value     [2] IMPLICIT OCTET STRING }
+/// ```
+///
+/// The `identification` alternative is stored as an [`Any`] so it can be
+/// round-tripped without loss.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct EmbeddedPdv<'a> {
+    /// `identification`: `CONTEXT-SPECIFIC` field containing the selected
+    /// `CHOICE` alternative which identifies the abstract and transfer syntaxes.
+    pub identification: Any<'a>,
+
+    /// `data-value`: the encoded value.
+    pub data_value: OctetString<'a>,
+}
+
+impl<'a> EmbeddedPdv<'a> {
+    /// Tag number of the `identification` field.
+    const IDENTIFICATION_TAG: TagNumber = TagNumber::new(0);
+
+    /// Tag number of the `data-value` field.
+    const DATA_VALUE_TAG: TagNumber = TagNumber::new(2);
+
+    /// Highest tag number of the `identification` field's alternatives.
+    const MAX_IDENTIFICATION_TAG_NUMBER: TagNumber = TagNumber::new(5);
+
+    /// Decode the fields of an [`EmbeddedPdv`] from the provided decoder.
+    fn decode_fields(decoder: &mut Decoder<'a>) -> Result<Self> {
+        let identification =
+            ContextSpecific::<Any<'a>>::decode_explicit(decoder, Self::IDENTIFICATION_TAG)?
+                .ok_or_else(|| decoder.error(ErrorKind::Truncated))?
+                .value;
+
+        if !identification.tag().is_context_specific()
+            || identification.tag().number() > Self::MAX_IDENTIFICATION_TAG_NUMBER
+        {
+            return Err(identification.tag().unexpected_error(None));
+        }
+
+        let data_value =
+            ContextSpecific::<OctetString<'a>>::decode_implicit(decoder, Self::DATA_VALUE_TAG)?
+                .ok_or_else(|| decoder.error(ErrorKind::Truncated))?
+                .value;
+
+        Ok(Self {
+            identification,
+            data_value,
+        })
+    }
+
+    /// Get the header of the `EXPLICIT` `identification` field.
+    fn identification_header(&self) -> Result<Header> {
+        Header::new(
+            Self::IDENTIFICATION_TAG.context_specific(true),
+            self.identification.encoded_len()?,
+        )
+    }
+
+    /// Get the `data-value` field as a [`ContextSpecific`] wrapper.
+    fn data_value_field(&self) -> ContextSpecific<OctetString<'a>> {
+        ContextSpecific {
+            tag_number: Self::DATA_VALUE_TAG,
+            tag_mode: TagMode::Implicit,
+            value: self.data_value,
+        }
+    }
+}
+
+impl<'a> DecodeValue<'a> for EmbeddedPdv<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        let mut nested_decoder = Decoder::new(decoder.bytes(length)?);
+        let result = Self::decode_fields(&mut nested_decoder)?;
+        nested_decoder.finish(result)
+    }
+}
+
+impl<'a> EncodeValue for EmbeddedPdv<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.identification.encoded_len()?.for_tlv()? + self.data_value_field().encoded_len()?
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.encode(&self.identification_header()?)?;
+        encoder.encode(&self.identification)?;
+        encoder.encode(&self.data_value_field())
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for EmbeddedPdv<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<EmbeddedPdv<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> Tagged for EmbeddedPdv<'a> {
+    const TAG: Tag = Tag::EmbeddedPdv;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmbeddedPdv;
+    use crate::{Decodable, Encodable, ErrorKind, Tag, TagNumber};
+    use hex_literal::hex;
+
+    /// `EMBEDDED PDV` with an `identification` of `fixed` (`[5] NULL`) and
+    /// a `data-value` of `0x01 0x02 0x03`.
+    const EXAMPLE_BYTES: &[u8] = &hex!("2b09a00285008203010203");
+
+    #[test]
+    fn round_trip() {
+        let pdv = EmbeddedPdv::from_der(EXAMPLE_BYTES).unwrap();
+        assert_eq!(
+            pdv.identification.tag(),
+            Tag::ContextSpecific {
+                constructed: false,
+                number: TagNumber::new(5)
+            }
+        );
+        assert_eq!(pdv.data_value.as_bytes(), &[1, 2, 3]);
+
+        let mut buf = [0u8; 32];
+        assert_eq!(pdv.encode_to_slice(&mut buf).unwrap(), EXAMPLE_BYTES);
+    }
+
+    #[test]
+    fn unknown_identification_alternative() {
+        // `identification` of `[6] NULL`, which isn't a `CHOICE` alternative
+        let err = EmbeddedPdv::from_der(&hex!("2b09a00286008203010203"))
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            err.kind(),
+            ErrorKind::UnexpectedTag {
+                expected: None,
+                actual: Tag::ContextSpecific {
+                    constructed: false,
+                    number: TagNumber::new(6)
+                }
+            }
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! ASN.1 `EXTERNAL` support.
+
+use crate::{
+    asn1::Any, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, Length, Result, Tag,
+    TagNumber, Tagged,
+};
+use core::convert::TryFrom;
+
+/// ASN.1 `EXTERNAL` type.
+///
+/// Encoded as the following structure, as described in X.690 Section 8.18:
+///
+/// ```text
+/// EXTERNAL ::= [UNIVERSAL 8] IMPLICIT SEQUENCE {
+///     direct-reference      OBJECT IDENTIFIER OPTIONAL,
+///     indirect-reference    INTEGER OPTIONAL,
+///     data-value-descriptor ObjectDescriptor OPTIONAL,
+///     encoding CHOICE {
+///         single-ASN1-type  [0] ABSTRACT-SYNTAX.&Type,
+///         octet-aligned     [1] IMPLICIT OCTET STRING,
+///         arbitrary         [2] IMPLICIT BIT STRING } }
+/// ```
+///
+/// All fields are stored as [`Any`] values so they can be round-tripped
+/// without loss.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct External<'a> {
+    /// `direct-reference`: ASN.1 `OBJECT IDENTIFIER` identifying the
+    /// abstract and transfer syntaxes of the encoded value.
+    pub direct_reference: Option<Any<'a>>,
+
+    /// `indirect-reference`: ASN.1 `INTEGER` presentation context identifier.
+    pub indirect_reference: Option<Any<'a>>,
+
+    /// `data-value-descriptor`: ASN.1 `ObjectDescriptor` describing the
+    /// encoded value.
+    pub data_value_descriptor: Option<Any<'a>>,
+
+    /// `encoding`: `CONTEXT-SPECIFIC` field containing the encoded value.
+    pub encoding: Any<'a>,
+}
+
+impl<'a> External<'a> {
+    /// Highest tag number of the `encoding` field's alternatives.
+    const MAX_ENCODING_TAG_NUMBER: TagNumber = TagNumber::new(2);
+
+    /// Decode the fields of an [`External`] from the provided decoder.
+    fn decode_fields(decoder: &mut Decoder<'a>) -> Result<Self> {
+        let direct_reference = decode_optional(decoder, Tag::ObjectIdentifier)?;
+        let indirect_reference = decode_optional(decoder, Tag::Integer)?;
+        let data_value_descriptor = decode_optional(decoder, Tag::ObjectDescriptor)?;
+        let encoding = decoder.any()?;
+
+        if !encoding.tag().is_context_specific()
+            || encoding.tag().number() > Self::MAX_ENCODING_TAG_NUMBER
+        {
+            return Err(encoding.tag().unexpected_error(None));
+        }
+
+        Ok(Self {
+            direct_reference,
+            indirect_reference,
+            data_value_descriptor,
+            encoding,
+        })
+    }
+}
+
+impl<'a> DecodeValue<'a> for External<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        let mut nested_decoder = Decoder::new(decoder.bytes(length)?);
+        let result = Self::decode_fields(&mut nested_decoder)?;
+        nested_decoder.finish(result)
+    }
+}
+
+impl<'a> EncodeValue for External<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.direct_reference.encoded_len()?
+            + self.indirect_reference.encoded_len()?
+            + self.data_value_descriptor.encoded_len()?
+            + self.encoding.encoded_len()?
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.encode(&self.direct_reference)?;
+        encoder.encode(&self.indirect_reference)?;
+        encoder.encode(&self.data_value_descriptor)?;
+        encoder.encode(&self.encoding)
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for External<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<External<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> Tagged for External<'a> {
+    const TAG: Tag = Tag::External;
+}
+
+/// Decode an `OPTIONAL` field with the given [`Tag`] as an [`Any`].
+fn decode_optional<'a>(decoder: &mut Decoder<'a>, tag: Tag) -> Result<Option<Any<'a>>> {
+    match decoder.peek_tag()? {
+        Some(t) if t == tag => decoder.any().map(Some),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::External;
+    use crate::{asn1::Any, Decodable, Encodable, Tag};
+    use hex_literal::hex;
+
+    /// `EXTERNAL` with a `direct-reference` of `2.1.1` and an `octet-aligned`
+    /// encoding of `0x01 0x02 0x03`.
+    const EXAMPLE_BYTES: &[u8] = &hex!("2809060251018103010203");
+
+    #[test]
+    fn round_trip() {
+        let external = External::from_der(EXAMPLE_BYTES).unwrap();
+        assert_eq!(
+            external.direct_reference,
+            Some(Any::new(Tag::ObjectIdentifier, &[0x51, 0x01]).unwrap())
+        );
+        assert!(external.indirect_reference.is_none());
+        assert!(external.data_value_descriptor.is_none());
+        assert_eq!(external.encoding.value(), &[1, 2, 3]);
+
+        let mut buf = [0u8; 32];
+        assert_eq!(external.encode_to_slice(&mut buf).unwrap(), EXAMPLE_BYTES);
+    }
+
+    #[test]
+    fn reject_non_context_specific_encoding() {
+        assert!(External::from_der(&hex!("2806060251010500")).is_err());
+    }
+}
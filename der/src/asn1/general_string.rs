@@ -0,0 +1,201 @@
+//! ASN.1 `GeneralString` support.
+
+use crate::{
+    asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result, Tag,
+    Tagged,
+};
+use core::{
+    ascii,
+    convert::TryFrom,
+    fmt::{self, Write},
+    str,
+};
+
+/// ASN.1 `GeneralString` type.
+///
+/// Used pervasively by Kerberos (RFC 4120) and GSS-API structures.
+/// Nominally it may contain any character set registered for use with
+/// ISO 2022 escape sequences, which is almost never implemented: RFC 4120
+/// restricts `KerberosString` to IA5 (i.e. ASCII) characters, though some
+/// implementations emit other 8-bit encodings such as UTF-8.
+///
+/// This type therefore stores the contents as opaque bytes and accepts any
+/// byte sequence, with [`GeneralString::as_str`] providing access to values
+/// which only contain ASCII characters. Its [`Display`][`fmt::Display`]
+/// impl writes other bytes as `\xNN` escapes.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GeneralString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> GeneralString<'a> {
+    /// Create a new `GeneralString`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        ByteSlice::new(input.as_ref())
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Borrow the string as a `str`, if it only contains ASCII characters.
+    ///
+    /// Returns an error otherwise: other encodings have to be handled
+    /// using [`GeneralString::as_bytes`].
+    pub fn as_str(&self) -> Result<&'a str> {
+        let bytes = self.as_bytes();
+
+        if bytes.is_ascii() {
+            str::from_utf8(bytes).map_err(|_| Self::TAG.value_error())
+        } else {
+            Err(Self::TAG.value_error())
+        }
+    }
+
+    /// Borrow the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for GeneralString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for GeneralString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, length)?.as_bytes())
+    }
+}
+
+impl<'a> EncodeValue for GeneralString<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> From<&GeneralString<'a>> for GeneralString<'a> {
+    fn from(value: &GeneralString<'a>) -> GeneralString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for GeneralString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<GeneralString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<GeneralString<'a>> for Any<'a> {
+    fn from(general_string: GeneralString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::GeneralString, general_string.inner)
+    }
+}
+
+impl<'a> From<GeneralString<'a>> for &'a [u8] {
+    fn from(general_string: GeneralString<'a>) -> &'a [u8] {
+        general_string.as_bytes()
+    }
+}
+
+impl<'a> Tagged for GeneralString<'a> {
+    const TAG: Tag = Tag::GeneralString;
+}
+
+impl<'a> fmt::Display for GeneralString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_bytes().iter().try_for_each(|&byte| {
+            if byte.is_ascii() {
+                f.write_char(char::from(byte))
+            } else {
+                write!(f, "\\x{:02x}", byte)
+            }
+        })
+    }
+}
+
+impl<'a> fmt::Debug for GeneralString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("GeneralString(\"")?;
+        self.as_bytes()
+            .iter()
+            .flat_map(|&byte| ascii::escape_default(byte))
+            .try_for_each(|byte| f.write_char(char::from(byte)))?;
+        f.write_str("\")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeneralString;
+    use crate::{asn1::Any, Decodable, Encodable, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() {
+        // GeneralString "EXAMPLE.COM" (a Kerberos realm)
+        let example_bytes = hex!("1b 0b 4558414d504c452e434f4d");
+        let general_string = GeneralString::from_der(&example_bytes).unwrap();
+        assert_eq!(general_string.as_str().unwrap(), "EXAMPLE.COM");
+        assert_eq!(general_string.len(), 11u8.into());
+
+        let mut buf = [0u8; 13];
+        assert_eq!(
+            general_string.encode_to_slice(&mut buf).unwrap(),
+            example_bytes
+        );
+    }
+
+    #[test]
+    fn non_ascii() {
+        // "Jürgen" encoded as UTF-8
+        let general_string = GeneralString::new(&hex!("4ac3bc7267656e")).unwrap();
+        assert_eq!(
+            general_string.as_str().err().unwrap().kind(),
+            ErrorKind::Value {
+                tag: Tag::GeneralString
+            }
+        );
+        assert_eq!(general_string.as_bytes(), "Jürgen".as_bytes());
+    }
+
+    #[test]
+    fn any() {
+        let any = Any::from_der(&hex!("1b 04 686f7374")).unwrap();
+        assert_eq!(any.tag(), Tag::GeneralString);
+        assert_eq!(any.general_string().unwrap().as_str().unwrap(), "host");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fmt() {
+        use alloc::format;
+
+        let general_string = GeneralString::new(&hex!("22 4a c3bc 22")).unwrap();
+        assert_eq!(format!("{}", general_string), "\"J\\xc3\\xbc\"");
+        assert_eq!(
+            format!("{:?}", general_string),
+            "GeneralString(\"\\\"J\\xc3\\xbc\\\"\")"
+        );
+    }
+}
@@ -10,8 +10,14 @@ use core::{convert::TryFrom, time::Duration};
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "chrono")]
+use chrono::Utc;
+
 #[cfg(feature = "time")]
-use {core::convert::TryInto, time::PrimitiveDateTime};
+use {
+    core::convert::TryInto,
+    time::{OffsetDateTime, PrimitiveDateTime},
+};
 
 /// ASN.1 `GeneralizedTime` type.
 ///
@@ -75,18 +81,8 @@ impl DecodeValue<'_> for GeneralizedTime {
     fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
         match *ByteSlice::decode_value(decoder, length)?.as_bytes() {
             // RFC 5280 requires mandatory seconds and Z-normalized time zone
-            [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
-                let year = datetime::decode_decimal(Self::TAG, y1, y2)? * 100
-                    + datetime::decode_decimal(Self::TAG, y3, y4)?;
-                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
-                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
-                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
-                let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
-                let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
-
-                DateTime::new(year, month, day, hour, minute, second)
-                    .map_err(|_| Self::TAG.value_error())
-                    .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
+            [ref datetime @ .., b'Z'] if datetime.len() == 14 => {
+                decode_date_time(datetime).map(Self)
             }
             _ => Err(Self::TAG.value_error()),
         }
@@ -155,6 +151,160 @@ impl Tagged for GeneralizedTime {
     const TAG: Tag = Tag::GeneralizedTime;
 }
 
+/// ASN.1 `GeneralizedTime` type which accepts the forms commonly produced
+/// by non-conforming encoders.
+///
+/// [`GeneralizedTime`] only accepts the `YYYYMMDDHHMMSSZ` form required by
+/// DER and RFC 5280. This type additionally accepts:
+///
+/// - fractional seconds, using either `.` or `,` as the decimal separator,
+///   e.g. `YYYYMMDDHHMMSS.fffZ`
+/// - offsets from UTC in place of `Z`, i.e. `YYYYMMDDHHMMSS+hhmm`,
+///   `YYYYMMDDHHMMSS-hhmm` or the hours-only `YYYYMMDDHHMMSS+hh`
+///
+/// Decoded values are normalized to a [`GeneralizedTime`]: offsets are
+/// applied to obtain UTC and fractional seconds are truncated. Local times
+/// without a `Z` or offset are still rejected, as there's no way to
+/// normalize them.
+///
+/// Values are always encoded in the strict DER form, so re-encoding a
+/// document decoded with this type won't necessarily reproduce it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GeneralizedTimeLenient(GeneralizedTime);
+
+impl GeneralizedTimeLenient {
+    /// Get the normalized [`GeneralizedTime`].
+    pub fn to_generalized_time(&self) -> GeneralizedTime {
+        self.0
+    }
+
+    /// Convert this [`GeneralizedTimeLenient`] into a [`DateTime`] in UTC.
+    pub fn to_date_time(&self) -> DateTime {
+        self.0.to_date_time()
+    }
+}
+
+impl DecodeValue<'_> for GeneralizedTimeLenient {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        let bytes = ByteSlice::decode_value(decoder, length)?.as_bytes();
+
+        if bytes.len() < 14 {
+            return Err(Self::TAG.value_error());
+        }
+
+        let (datetime, mut remaining) = bytes.split_at(14);
+        let datetime = decode_date_time(datetime)?;
+
+        // Fractional seconds are truncated
+        if let [b'.' | b',', ref fraction @ ..] = *remaining {
+            let digits = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+
+            if digits == 0 {
+                return Err(Self::TAG.value_error());
+            }
+
+            remaining = &fraction[digits..];
+        }
+
+        let offset = match *remaining {
+            [b'Z'] => return Ok(Self(GeneralizedTime(datetime))),
+            [sign @ (b'+' | b'-'), h1, h2] => decode_offset(sign, h1, h2, b'0', b'0')?,
+            [sign @ (b'+' | b'-'), h1, h2, m1, m2] => decode_offset(sign, h1, h2, m1, m2)?,
+            _ => return Err(Self::TAG.value_error()),
+        };
+
+        // Local time minus its offset from UTC
+        let secs = i64::try_from(datetime.unix_duration().as_secs())
+            .ok()
+            .and_then(|secs| secs.checked_sub(offset))
+            .and_then(|secs| u64::try_from(secs).ok())
+            .ok_or_else(|| Self::TAG.value_error())?;
+
+        GeneralizedTime::from_unix_duration(Duration::from_secs(secs)).map(Self)
+    }
+}
+
+impl EncodeValue for GeneralizedTimeLenient {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl From<&GeneralizedTimeLenient> for GeneralizedTimeLenient {
+    fn from(value: &GeneralizedTimeLenient) -> GeneralizedTimeLenient {
+        *value
+    }
+}
+
+impl From<GeneralizedTime> for GeneralizedTimeLenient {
+    fn from(time: GeneralizedTime) -> GeneralizedTimeLenient {
+        Self(time)
+    }
+}
+
+impl From<GeneralizedTimeLenient> for GeneralizedTime {
+    fn from(time: GeneralizedTimeLenient) -> GeneralizedTime {
+        time.0
+    }
+}
+
+impl From<GeneralizedTimeLenient> for DateTime {
+    fn from(time: GeneralizedTimeLenient) -> DateTime {
+        time.to_date_time()
+    }
+}
+
+impl TryFrom<Any<'_>> for GeneralizedTimeLenient {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<GeneralizedTimeLenient> {
+        any.decode_into()
+    }
+}
+
+impl Tagged for GeneralizedTimeLenient {
+    const TAG: Tag = Tag::GeneralizedTime;
+}
+
+/// Decode the `YYYYMMDDHHMMSS` portion of a `GeneralizedTime`.
+fn decode_date_time(bytes: &[u8]) -> Result<DateTime> {
+    const TAG: Tag = Tag::GeneralizedTime;
+
+    match *bytes {
+        [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2] => {
+            let year = datetime::decode_decimal(TAG, y1, y2)? * 100
+                + datetime::decode_decimal(TAG, y3, y4)?;
+            let month = datetime::decode_decimal(TAG, mon1, mon2)?;
+            let day = datetime::decode_decimal(TAG, day1, day2)?;
+            let hour = datetime::decode_decimal(TAG, hour1, hour2)?;
+            let minute = datetime::decode_decimal(TAG, min1, min2)?;
+            let second = datetime::decode_decimal(TAG, sec1, sec2)?;
+
+            DateTime::new(year, month, day, hour, minute, second).map_err(|_| TAG.value_error())
+        }
+        _ => Err(TAG.value_error()),
+    }
+}
+
+/// Decode a `+hhmm` or `-hhmm` offset from UTC, returning it in seconds.
+fn decode_offset(sign: u8, h1: u8, h2: u8, m1: u8, m2: u8) -> Result<i64> {
+    const TAG: Tag = Tag::GeneralizedTime;
+
+    let hours = datetime::decode_decimal(TAG, h1, h2)?;
+    let minutes = datetime::decode_decimal(TAG, m1, m2)?;
+
+    if hours > 23 || minutes > 59 {
+        return Err(TAG.value_error());
+    }
+
+    let offset = i64::from(hours) * 3600 + i64::from(minutes) * 60;
+    Ok(if sign == b'-' { -offset } else { offset })
+}
+
 impl DecodeValue<'_> for DateTime {
     fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
         Ok(GeneralizedTime::decode_value(decoder, length)?.into())
@@ -303,9 +453,119 @@ impl Tagged for PrimitiveDateTime {
     const TAG: Tag = Tag::GeneralizedTime;
 }
 
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl DecodeValue<'_> for OffsetDateTime {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        GeneralizedTime::decode_value(decoder, length)?.try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl EncodeValue for OffsetDateTime {
+    fn value_len(&self) -> Result<Length> {
+        GeneralizedTime::try_from(self)?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        GeneralizedTime::try_from(self)?.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<OffsetDateTime> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<GeneralizedTime> {
+        Ok(GeneralizedTime::from_date_time(DateTime::try_from(time)?))
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<&OffsetDateTime> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: &OffsetDateTime) -> Result<GeneralizedTime> {
+        Self::try_from(*time)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<GeneralizedTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: GeneralizedTime) -> Result<OffsetDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl Tagged for OffsetDateTime {
+    const TAG: Tag = Tag::GeneralizedTime;
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl DecodeValue<'_> for chrono::DateTime<Utc> {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        GeneralizedTime::decode_value(decoder, length).map(Into::into)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl EncodeValue for chrono::DateTime<Utc> {
+    fn value_len(&self) -> Result<Length> {
+        GeneralizedTime::try_from(self)?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        GeneralizedTime::try_from(self)?.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<chrono::DateTime<Utc>> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: chrono::DateTime<Utc>) -> Result<GeneralizedTime> {
+        Ok(GeneralizedTime::from_date_time(DateTime::try_from(time)?))
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<&chrono::DateTime<Utc>> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: &chrono::DateTime<Utc>) -> Result<GeneralizedTime> {
+        Self::try_from(*time)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl From<GeneralizedTime> for chrono::DateTime<Utc> {
+    fn from(time: GeneralizedTime) -> chrono::DateTime<Utc> {
+        time.to_date_time().into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl Tagged for chrono::DateTime<Utc> {
+    const TAG: Tag = Tag::GeneralizedTime;
+}
+
 #[cfg(test)]
 mod tests {
-    use super::GeneralizedTime;
+    use super::{GeneralizedTime, GeneralizedTimeLenient};
     use crate::{Decodable, Encodable, Encoder};
     use hex_literal::hex;
 
@@ -320,4 +580,128 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn reject_non_der_forms() {
+        // 19910506234540.5Z
+        assert!(
+            GeneralizedTime::from_der(&hex!("18 11 3139393130353036323334353430 2e35 5a")).is_err()
+        );
+        // 19910506234540+0100
+        assert!(
+            GeneralizedTime::from_der(&hex!("18 13 3139393130353036323334353430 2b30313030"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn lenient() {
+        let expected =
+            GeneralizedTime::from_der(&hex!("18 0f 3139393130353036323334353430 5a")).unwrap();
+
+        for example_bytes in &[
+            // 19910506234540Z
+            &hex!("18 0f 3139393130353036323334353430 5a")[..],
+            // 19910506234540.123Z
+            &hex!("18 13 3139393130353036323334353430 2e313233 5a"),
+            // 19910506234540,9Z
+            &hex!("18 11 3139393130353036323334353430 2c39 5a"),
+            // 19910507013540+0150
+            &hex!("18 13 3139393130353037303133353430 2b30313530"),
+            // 19910506204540-03
+            &hex!("18 11 3139393130353036323034353430 2d3033"),
+            // 19910507004540.25+01
+            &hex!("18 14 3139393130353037303034353430 2e3235 2b3031"),
+        ] {
+            let time = GeneralizedTimeLenient::from_der(example_bytes).unwrap();
+            assert_eq!(time.to_generalized_time(), expected);
+
+            // Always encoded in DER form
+            let mut buf = [0u8; 17];
+            assert_eq!(
+                time.encode_to_slice(&mut buf).unwrap(),
+                hex!("18 0f 3139393130353036323334353430 5a")
+            );
+        }
+    }
+
+    #[test]
+    fn lenient_reject_invalid() {
+        for example_bytes in &[
+            // Local time: 19910506234540
+            &hex!("18 0e 3139393130353036323334353430")[..],
+            // Empty fraction: 19910506234540.Z
+            &hex!("18 10 3139393130353036323334353430 2e 5a"),
+            // Invalid offset: 19910506234540+2400
+            &hex!("18 13 3139393130353036323334353430 2b32343030"),
+            // Truncated offset: 19910506234540+1
+            &hex!("18 10 3139393130353036323334353430 2b31"),
+            // Before the Unix epoch once normalized: 19700101000000+0100
+            &hex!("18 13 3139373030313031303030303030 2b30313030"),
+        ] {
+            assert!(GeneralizedTimeLenient::from_der(example_bytes).is_err());
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_crate() {
+        use chrono::{TimeZone, Utc};
+        use core::convert::TryFrom;
+
+        let example_bytes = hex!("18 0f 3139393130353036323334353430 5a");
+        let generalized_time = GeneralizedTime::from_der(&example_bytes).unwrap();
+
+        let chrono_date_time = chrono::DateTime::<Utc>::from(generalized_time);
+        assert_eq!(chrono_date_time.timestamp(), 673573540);
+        assert_eq!(
+            GeneralizedTime::try_from(chrono_date_time).unwrap(),
+            generalized_time
+        );
+
+        // Decodable and encodable as `GeneralizedTime`
+        assert_eq!(
+            chrono::DateTime::<Utc>::from_der(&example_bytes).unwrap(),
+            chrono_date_time
+        );
+        let mut buf = [0u8; 128];
+        let mut encoder = Encoder::new(&mut buf);
+        chrono_date_time.encode(&mut encoder).unwrap();
+        assert_eq!(example_bytes, encoder.finish().unwrap());
+
+        // Out of range for `GeneralizedTime`
+        let y10000 = Utc.timestamp_opt(253402300800, 0).unwrap();
+        assert!(GeneralizedTime::try_from(y10000).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_crate() {
+        use core::convert::TryFrom;
+        use time::{OffsetDateTime, PrimitiveDateTime};
+
+        let example_bytes = hex!("18 0f 3139393130353036323334353430 5a");
+        let generalized_time = GeneralizedTime::from_der(&example_bytes).unwrap();
+
+        let offset_date_time = OffsetDateTime::try_from(generalized_time).unwrap();
+        assert_eq!(offset_date_time.unix_timestamp(), 673573540);
+        assert_eq!(
+            GeneralizedTime::try_from(offset_date_time).unwrap(),
+            generalized_time
+        );
+
+        let primitive_date_time = PrimitiveDateTime::try_from(generalized_time).unwrap();
+        assert_eq!(primitive_date_time.assume_utc(), offset_date_time);
+
+        // Decodable and encodable as `GeneralizedTime`
+        assert_eq!(
+            OffsetDateTime::from_der(&example_bytes).unwrap(),
+            offset_date_time
+        );
+        let mut buf = [0u8; 17];
+        assert_eq!(
+            offset_date_time.encode_to_slice(&mut buf).unwrap(),
+            example_bytes
+        );
+    }
 }
@@ -29,15 +29,20 @@ impl<'a> Ia5String<'a> {
         T: AsRef<[u8]> + ?Sized,
     {
         let input = input.as_ref();
+        Self::validate(input)?;
 
-        // Validate all characters are within IA5String's allowed set
+        StrSlice::from_bytes(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Validate all characters are within IA5String's allowed set.
+    fn validate(input: &[u8]) -> Result<()> {
         if input.iter().any(|&c| c > 0x7F) {
             return Err(Self::TAG.value_error());
         }
 
-        StrSlice::from_bytes(input)
-            .map(|inner| Self { inner })
-            .map_err(|_| Self::TAG.value_error())
+        Ok(())
     }
 
     /// Borrow the string as a `str`.
@@ -95,6 +100,15 @@ impl<'a> From<&Ia5String<'a>> for Ia5String<'a> {
     }
 }
 
+impl<'a> TryFrom<StrSlice<'a>> for Ia5String<'a> {
+    type Error = Error;
+
+    fn try_from(inner: StrSlice<'a>) -> Result<Ia5String<'a>> {
+        Self::validate(inner.as_bytes())?;
+        Ok(Self { inner })
+    }
+}
+
 impl<'a> TryFrom<Any<'a>> for Ia5String<'a> {
     type Error = Error;
 
@@ -134,7 +148,8 @@ impl<'a> fmt::Debug for Ia5String<'a> {
 #[cfg(test)]
 mod tests {
     use super::Ia5String;
-    use crate::Decodable;
+    use crate::{Decodable, StrSlice};
+    use core::convert::TryFrom;
     use hex_literal::hex;
 
     #[test]
@@ -143,4 +158,13 @@ mod tests {
         let printable_string = Ia5String::from_der(&example_bytes).unwrap();
         assert_eq!(printable_string.as_str(), "test1@rsa.com");
     }
+
+    #[test]
+    fn from_str_slice() {
+        let s = StrSlice::new("test1@rsa.com").unwrap();
+        assert_eq!(Ia5String::try_from(s).unwrap().as_str(), "test1@rsa.com");
+
+        let s = StrSlice::new("Camille Saint-Saëns").unwrap();
+        assert!(Ia5String::try_from(s).is_err());
+    }
 }
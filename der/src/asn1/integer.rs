@@ -17,18 +17,13 @@ macro_rules! impl_int_encoding {
                 fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
                     let bytes = ByteSlice::decode_value(decoder, length)?.as_bytes();
 
-                    let result = if is_highest_bit_set(bytes) {
-                        <$uint>::from_be_bytes(int::decode_to_array(bytes)?) as $int
+                    if is_highest_bit_set(bytes) {
+                        Ok(<$uint>::from_be_bytes(int::decode_to_array(bytes)?) as $int)
                     } else {
-                        Self::from_be_bytes(uint::decode_to_array(bytes)?)
-                    };
-
-                    // Ensure we compute the same encoded length as the original any value
-                    // if any.encoded_len()? != result.encoded_len()? {
-                    //     return Err(Self::TAG.non_canonical_error());
-                    // }
-
-                    Ok(result)
+                        // Positive values must fit without setting the sign bit
+                        Self::try_from(<$uint>::from_be_bytes(uint::decode_to_array(bytes)?))
+                            .map_err(|_| Self::TAG.value_error())
+                    }
                 }
             }
 
@@ -71,14 +66,7 @@ macro_rules! impl_uint_encoding {
             impl<'a> DecodeValue<'a> for $uint {
                 fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
                     let bytes = ByteSlice::decode_value(decoder, length)?.as_bytes();
-                    let result = Self::from_be_bytes(uint::decode_to_array(bytes)?);
-
-                    // Ensure we compute the same encoded length as the original any value
-                    // if any.encoded_len()? != result.encoded_len()? {
-                    //     return Err(Self::TAG.non_canonical_error());
-                    // }
-
-                    Ok(result)
+                    Ok(Self::from_be_bytes(uint::decode_to_array(bytes)?))
                 }
             }
 
@@ -137,6 +125,18 @@ pub(crate) mod tests {
     pub(crate) const I32767_BYTES: &[u8] = &[0x02, 0x02, 0x7F, 0xFF];
     pub(crate) const I65535_BYTES: &[u8] = &[0x02, 0x03, 0x00, 0xFF, 0xFF];
     pub(crate) const INEG32768_BYTES: &[u8] = &[0x02, 0x02, 0x80, 0x00];
+    pub(crate) const U32_MAX_BYTES: &[u8] = &[0x02, 0x05, 0x00, 0xFF, 0xFF, 0xFF, 0xFF];
+    pub(crate) const U64_MAX_BYTES: &[u8] = &[
+        0x02, 0x09, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    ];
+    pub(crate) const U128_MAX_BYTES: &[u8] = &[
+        0x02, 0x11, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFF, 0xFF, 0xFF, 0xFF,
+    ];
+    pub(crate) const I64_MAX_BYTES: &[u8] =
+        &[0x02, 0x08, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    pub(crate) const I64_MIN_BYTES: &[u8] =
+        &[0x02, 0x08, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
 
     #[test]
     fn decode_i8() {
@@ -234,6 +234,60 @@ pub(crate) mod tests {
         assert_eq!(I65535_BYTES, 65535u16.encode_to_slice(&mut buffer).unwrap());
     }
 
+    #[test]
+    fn round_trip_wide_integers() {
+        let mut buffer = [0u8; 19];
+
+        for &(value, der) in &[
+            (0u64, I0_BYTES),
+            (u64::from(u32::MAX), U32_MAX_BYTES),
+            (u64::MAX, U64_MAX_BYTES),
+        ] {
+            assert_eq!(value, u64::from_der(der).unwrap());
+            assert_eq!(der, value.encode_to_slice(&mut buffer).unwrap());
+            assert_eq!(u128::from(value), u128::from_der(der).unwrap());
+            assert_eq!(der, u128::from(value).encode_to_slice(&mut buffer).unwrap());
+        }
+
+        assert_eq!(u32::MAX, u32::from_der(U32_MAX_BYTES).unwrap());
+        assert_eq!(
+            U32_MAX_BYTES,
+            u32::MAX.encode_to_slice(&mut buffer).unwrap()
+        );
+        assert_eq!(u128::MAX, u128::from_der(U128_MAX_BYTES).unwrap());
+        assert_eq!(
+            U128_MAX_BYTES,
+            u128::MAX.encode_to_slice(&mut buffer).unwrap()
+        );
+
+        for &(value, der) in &[
+            (i64::MAX, I64_MAX_BYTES),
+            (i64::MIN, I64_MIN_BYTES),
+            (-129, INEG129_BYTES),
+        ] {
+            assert_eq!(value, i64::from_der(der).unwrap());
+            assert_eq!(der, value.encode_to_slice(&mut buffer).unwrap());
+            assert_eq!(i128::from(value), i128::from_der(der).unwrap());
+            assert_eq!(der, i128::from(value).encode_to_slice(&mut buffer).unwrap());
+        }
+    }
+
+    /// Values which don't fit into the type are rejected rather than truncated
+    #[test]
+    fn reject_out_of_range() {
+        assert!(u8::from_der(I256_BYTES).is_err());
+        assert!(i8::from_der(I128_BYTES).is_err());
+        assert!(i8::from_der(INEG129_BYTES).is_err());
+        assert!(u32::from_der(U64_MAX_BYTES).is_err());
+        assert!(i64::from_der(U64_MAX_BYTES).is_err());
+        assert!(u64::from_der(U128_MAX_BYTES).is_err());
+        assert!(i128::from_der(U128_MAX_BYTES).is_err());
+
+        // Negative values can't be decoded as unsigned integers
+        assert!(u64::from_der(INEG128_BYTES).is_err());
+        assert!(u128::from_der(I64_MIN_BYTES).is_err());
+    }
+
     /// Integers must be encoded with a minimum number of octets
     #[test]
     fn reject_non_canonical() {
@@ -241,5 +295,10 @@ pub(crate) mod tests {
         assert!(i16::from_der(&[0x02, 0x02, 0x00, 0x00]).is_err());
         assert!(u8::from_der(&[0x02, 0x02, 0x00, 0x00]).is_err());
         assert!(u16::from_der(&[0x02, 0x02, 0x00, 0x00]).is_err());
+        assert!(u64::from_der(&[0x02, 0x02, 0x00, 0x7F]).is_err());
+        assert!(i64::from_der(&[0x02, 0x02, 0xFF, 0x80]).is_err());
+        assert!(i128::from_der(&[0x02, 0x03, 0xFF, 0xFF, 0x7F]).is_err());
+        assert!(u32::from_der(&[0x02, 0x00]).is_err());
+        assert!(i32::from_der(&[0x02, 0x00]).is_err());
     }
 }
@@ -1,12 +1,15 @@
 //! "Big" ASN.1 `INTEGER` types.
 
-use super::uint;
+use super::{int, is_highest_bit_set, uint};
 use crate::{
     asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, Length,
     Result, Tag, Tagged,
 };
 use core::convert::TryFrom;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "bigint")]
 use {
     core::convert::TryInto,
@@ -29,12 +32,76 @@ pub struct UIntBytes<'a> {
 impl<'a> UIntBytes<'a> {
     /// Create a new [`UIntBytes`] from a byte slice.
     pub fn new(bytes: &'a [u8]) -> Result<Self> {
-        let inner = ByteSlice::new(uint::strip_leading_zeroes(bytes))
-            .map_err(|_| ErrorKind::Length { tag: Self::TAG })?;
+        let inner =
+            ByteSlice::new(uint::strip_leading_zeroes(bytes)).map_err(|_| ErrorKind::Length {
+                tag: Self::TAG,
+                length: None,
+            })?;
 
         Ok(Self { inner })
     }
 
+    /// Create a new [`UIntBytes`] from a fixed-size big endian byte array,
+    /// trimming any leading zeroes (e.g. padding to a fixed width required
+    /// by a hardware API).
+    pub fn from_be_array_trimmed<const N: usize>(array: &'a [u8; N]) -> Result<Self> {
+        Self::new(array)
+    }
+
+    /// Convert this [`UIntBytes`] into a fixed-size big endian byte array,
+    /// left-padding it with zeroes.
+    ///
+    /// Returns an error if the integer value is too large to fit in `N` bytes.
+    pub fn to_be_array<const N: usize>(&self) -> Result<[u8; N]> {
+        let mut array = [0u8; N];
+        self.to_fixed_bytes(&mut array)?;
+        Ok(array)
+    }
+
+    /// Write this [`UIntBytes`] into `buffer` as a big endian integer
+    /// which fills it entirely, left-padding it with zeroes, e.g. to move
+    /// it into a constant-size limb buffer whose size isn't known at
+    /// compile time.
+    ///
+    /// Returns an error if the integer value is too large to fit in
+    /// `buffer`, which is left unmodified in that case.
+    pub fn to_fixed_bytes(&self, buffer: &mut [u8]) -> Result<()> {
+        let bytes = self.as_bytes();
+        let offset = buffer
+            .len()
+            .checked_sub(bytes.len())
+            .ok_or_else(|| Self::TAG.length_error())?;
+
+        let (padding, value) = buffer.split_at_mut(offset);
+        padding.iter_mut().for_each(|byte| *byte = 0);
+        value.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Create a new [`UIntBytes`] from a little endian byte slice (e.g. an
+    /// RSA or EC key component returned by a TPM), writing its big endian
+    /// form into `buffer`.
+    ///
+    /// Any trailing zeroes of the little endian slice are trimmed. Returns an
+    /// error if `buffer` is shorter than `bytes`.
+    pub fn from_le_slice(bytes: &[u8], buffer: &'a mut [u8]) -> Result<Self> {
+        let buffer = buffer
+            .get_mut(..bytes.len())
+            .ok_or_else(|| Self::TAG.length_error())?;
+
+        buffer.copy_from_slice(bytes);
+        buffer.reverse();
+        Self::new(buffer)
+    }
+
+    /// Convert this [`UIntBytes`] into a little endian byte vector without
+    /// any trailing zeroes, e.g. for hardware APIs which expect one.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_le_vec(&self) -> Vec<u8> {
+        self.as_bytes().iter().rev().copied().collect()
+    }
+
     /// Borrow the inner byte slice which contains the least significant bytes
     /// of a big endian integer value with all leading zeros stripped.
     pub fn as_bytes(&self) -> &'a [u8] {
@@ -99,6 +166,118 @@ impl<'a> Tagged for UIntBytes<'a> {
     const TAG: Tag = Tag::Integer;
 }
 
+/// "Big" signed ASN.1 `INTEGER` type.
+///
+/// Provides direct access to the underlying big endian two's complement bytes
+/// which comprise a signed integer value.
+///
+/// Intended for use cases like large integers which may be negative, e.g.
+/// X.509 serial numbers produced by non-conforming CAs.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct IntBytes<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> IntBytes<'a> {
+    /// Create a new [`IntBytes`] from a big endian two's complement byte
+    /// slice, stripping any redundant leading `0x00` or `0xFF` bytes.
+    ///
+    /// Returns an error if the slice is empty.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.is_empty() {
+            return Err(Self::TAG.value_error());
+        }
+
+        let inner = ByteSlice::new(int::strip_leading_sign_bytes(bytes)).map_err(|_| {
+            ErrorKind::Length {
+                tag: Self::TAG,
+                length: None,
+            }
+        })?;
+
+        Ok(Self { inner })
+    }
+
+    /// Borrow the inner byte slice which contains the big endian two's
+    /// complement integer value with all redundant leading bytes stripped.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of this [`IntBytes`] in bytes.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner byte slice empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Is this integer negative?
+    pub fn is_negative(&self) -> bool {
+        is_highest_bit_set(self.as_bytes())
+    }
+
+    /// Is this integer zero?
+    pub fn is_zero(&self) -> bool {
+        self.as_bytes() == [0]
+    }
+
+    /// Is this integer positive, i.e. greater than zero?
+    pub fn is_positive(&self) -> bool {
+        !self.is_negative() && !self.is_zero()
+    }
+}
+
+impl<'a> DecodeValue<'a> for IntBytes<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        let bytes = ByteSlice::decode_value(decoder, length)?.as_bytes();
+        Self::new(int::decode_to_slice(bytes)?)
+    }
+}
+
+impl<'a> EncodeValue for IntBytes<'a> {
+    fn value_len(&self) -> Result<Length> {
+        Ok(self.len())
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(self.as_bytes())
+    }
+}
+
+impl<'a> From<&IntBytes<'a>> for IntBytes<'a> {
+    fn from(value: &IntBytes<'a>) -> IntBytes<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for IntBytes<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<IntBytes<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> TryFrom<IntBytes<'a>> for UIntBytes<'a> {
+    type Error = Error;
+
+    fn try_from(value: IntBytes<'a>) -> Result<UIntBytes<'a>> {
+        if value.is_negative() {
+            return Err(Tag::Integer.value_error());
+        }
+
+        UIntBytes::new(value.as_bytes())
+    }
+}
+
+impl<'a> Tagged for IntBytes<'a> {
+    const TAG: Tag = Tag::Integer;
+}
+
 #[cfg(feature = "bigint")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bigint")))]
 impl<'a, const LIMBS: usize> TryFrom<Any<'a>> for UInt<LIMBS>
@@ -157,7 +336,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::UIntBytes;
+    use super::{IntBytes, UIntBytes};
     use crate::{
         asn1::{integer::tests::*, Any},
         Decodable, Encodable, Encoder, ErrorKind, Tag,
@@ -203,6 +382,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn to_be_array() {
+        let uint = UIntBytes::from_der(I256_BYTES).unwrap();
+        assert_eq!(uint.to_be_array::<2>().unwrap(), [0x01, 0x00]);
+        assert_eq!(uint.to_be_array::<4>().unwrap(), [0, 0, 0x01, 0x00]);
+
+        let err = uint.to_be_array::<1>().err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::Integer,
+                length: None
+            }
+        );
+    }
+
+    #[test]
+    fn to_fixed_bytes() {
+        let uint = UIntBytes::from_der(I256_BYTES).unwrap();
+        let mut buffer = [0xFFu8; 4];
+        uint.to_fixed_bytes(&mut buffer).unwrap();
+        assert_eq!(buffer, [0, 0, 0x01, 0x00]);
+
+        uint.to_fixed_bytes(&mut buffer[2..]).unwrap();
+        assert_eq!(buffer, [0, 0, 0x01, 0x00]);
+
+        let err = uint.to_fixed_bytes(&mut buffer[3..]).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::Integer,
+                length: None
+            }
+        );
+        assert_eq!(buffer, [0, 0, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn from_be_array_trimmed() {
+        let array = [0, 0, 0x7F, 0xFF];
+        let uint = UIntBytes::from_be_array_trimmed(&array).unwrap();
+        assert_eq!(uint.as_bytes(), &[0x7F, 0xFF]);
+
+        let mut buf = [0u8; 16];
+        assert_eq!(uint.encode_to_slice(&mut buf).unwrap(), I32767_BYTES);
+    }
+
+    #[test]
+    fn from_le_slice() {
+        let mut buffer = [0u8; 4];
+        let uint = UIntBytes::from_le_slice(&[0xFF, 0x7F, 0, 0], &mut buffer).unwrap();
+        assert_eq!(uint.as_bytes(), &[0x7F, 0xFF]);
+
+        let mut buf = [0u8; 16];
+        assert_eq!(uint.encode_to_slice(&mut buf).unwrap(), I32767_BYTES);
+
+        let err = UIntBytes::from_le_slice(&[0xFF, 0x7F], &mut [0u8; 1])
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::Integer,
+                length: None
+            }
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_le_vec() {
+        let uint = UIntBytes::from_der(I256_BYTES).unwrap();
+        assert_eq!(uint.to_le_vec(), [0x00, 0x01]);
+
+        let uint = UIntBytes::from_der(I0_BYTES).unwrap();
+        assert_eq!(uint.to_le_vec(), [0]);
+    }
+
     #[test]
     fn reject_oversize_without_extra_zero() {
         let err = UIntBytes::try_from(Any::new(Tag::Integer, &[0x81]).unwrap())
@@ -211,4 +468,77 @@ mod tests {
 
         assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
     }
+
+    #[test]
+    fn decode_int_bytes() {
+        for &(example, bytes, negative) in &[
+            (I0_BYTES, &[0x00][..], false),
+            (I127_BYTES, &[0x7F], false),
+            (I128_BYTES, &[0x00, 0x80], false),
+            (I32767_BYTES, &[0x7F, 0xFF], false),
+            (INEG128_BYTES, &[0x80], true),
+            (INEG129_BYTES, &[0xFF, 0x7F], true),
+            (INEG32768_BYTES, &[0x80, 0x00], true),
+        ] {
+            let int = IntBytes::from_der(example).unwrap();
+            assert_eq!(int.as_bytes(), bytes);
+            assert_eq!(int.is_negative(), negative);
+
+            let mut buf = [0u8; 16];
+            assert_eq!(int.encode_to_slice(&mut buf).unwrap(), example);
+        }
+    }
+
+    #[test]
+    fn int_bytes_sign() {
+        let zero = IntBytes::from_der(I0_BYTES).unwrap();
+        assert!(zero.is_zero());
+        assert!(!zero.is_negative());
+        assert!(!zero.is_positive());
+
+        let positive = IntBytes::from_der(I128_BYTES).unwrap();
+        assert!(positive.is_positive());
+        assert!(!positive.is_zero());
+
+        let negative = IntBytes::from_der(INEG128_BYTES).unwrap();
+        assert!(negative.is_negative());
+        assert!(!negative.is_positive());
+    }
+
+    #[test]
+    fn int_bytes_new() {
+        // Redundant leading sign bytes are stripped
+        assert_eq!(IntBytes::new(&[0, 0, 0x80]).unwrap().as_bytes(), &[0, 0x80]);
+        assert_eq!(IntBytes::new(&[0, 0, 0]).unwrap().as_bytes(), &[0]);
+        assert_eq!(
+            IntBytes::new(&[0xFF, 0xFF, 0x7F]).unwrap().as_bytes(),
+            &[0xFF, 0x7F]
+        );
+        assert_eq!(IntBytes::new(&[0xFF, 0xFF]).unwrap().as_bytes(), &[0xFF]);
+
+        let err = IntBytes::new(&[]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
+    }
+
+    #[test]
+    fn int_bytes_to_uint_bytes() {
+        let int = IntBytes::from_der(I128_BYTES).unwrap();
+        assert_eq!(UIntBytes::try_from(int).unwrap().as_bytes(), &[0x80]);
+
+        let int = IntBytes::from_der(INEG128_BYTES).unwrap();
+        assert!(UIntBytes::try_from(int).is_err());
+    }
+
+    #[test]
+    fn reject_non_canonical_int_bytes() {
+        for &example in &[
+            &[0x02, 0x00][..],
+            &[0x02, 0x02, 0x00, 0x7F],
+            &[0x02, 0x02, 0xFF, 0x80],
+            &[0x02, 0x03, 0xFF, 0xFF, 0x7F],
+        ] {
+            let err = IntBytes::from_der(example).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::Noncanonical { tag: Tag::Integer });
+        }
+    }
 }
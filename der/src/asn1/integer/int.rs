@@ -1,14 +1,32 @@
 //! Support for encoding negative integers
 
 use super::is_highest_bit_set;
-use crate::{Encoder, ErrorKind, Length, Result};
+use crate::{Encoder, Length, Result, Tag};
 use core::convert::TryFrom;
 
-/// Decode an unsigned integer of the specified size.
+/// Decode a signed two's complement integer, ensuring it's minimally
+/// encoded.
+///
+/// Returns the same big endian byte slice.
+pub(super) fn decode_to_slice(bytes: &[u8]) -> Result<&[u8]> {
+    // A leading `0x00` or `0xFF` byte is redundant if the next byte has the
+    // same sign bit
+    match bytes {
+        [] => Err(Tag::Integer.non_canonical_error()),
+        [0x00, byte, ..] if *byte < 0x80 => Err(Tag::Integer.non_canonical_error()),
+        [0xFF, byte, ..] if *byte >= 0x80 => Err(Tag::Integer.non_canonical_error()),
+        _ => Ok(bytes),
+    }
+}
+
+/// Decode a negative integer of the specified size.
 ///
 /// Returns a byte array of the requested size containing a big endian integer.
 pub(super) fn decode_to_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N]> {
-    let offset = N.checked_sub(bytes.len()).ok_or(ErrorKind::Truncated)?;
+    let bytes = decode_to_slice(bytes)?;
+    let offset = N
+        .checked_sub(bytes.len())
+        .ok_or_else(|| Tag::Integer.value_error())?;
     let mut output = [0xFFu8; N];
     output[offset..].copy_from_slice(bytes);
     Ok(output)
@@ -38,3 +56,18 @@ fn strip_leading_ones(mut bytes: &[u8]) -> &[u8] {
 
     bytes
 }
+
+/// Strip the redundant leading `0x00` and `0xFF` bytes from the given big
+/// endian two's complement integer.
+pub(super) fn strip_leading_sign_bytes(mut bytes: &[u8]) -> &[u8] {
+    while let [first, second, ..] = bytes {
+        if (*first == 0x00 && *second < 0x80) || (*first == 0xFF && *second >= 0x80) {
+            bytes = &bytes[1..];
+            continue;
+        }
+
+        break;
+    }
+
+    bytes
+}
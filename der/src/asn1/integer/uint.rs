@@ -30,8 +30,11 @@ pub(super) fn decode_to_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N]> {
     let input = decode_to_slice(bytes)?;
 
     // Input has leading zeroes removed, so we need to add them back
+    let offset = N
+        .checked_sub(input.len())
+        .ok_or_else(|| Tag::Integer.value_error())?;
     let mut output = [0u8; N];
-    output[N.saturating_sub(input.len())..].copy_from_slice(input);
+    output[offset..].copy_from_slice(input);
     Ok(output)
 }
 
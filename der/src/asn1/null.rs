@@ -15,7 +15,10 @@ impl DecodeValue<'_> for Null {
         if length.is_zero() {
             Ok(Null)
         } else {
-            Err(decoder.error(ErrorKind::Length { tag: Self::TAG }))
+            Err(decoder.error(ErrorKind::Length {
+                tag: Self::TAG,
+                length: Some(length),
+            }))
         }
     }
 }
@@ -0,0 +1,181 @@
+//! ASN.1 `NumericString` support.
+
+use crate::{
+    asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result,
+    StrSlice, Tag, Tagged,
+};
+use core::{convert::TryFrom, fmt, str};
+
+/// ASN.1 `NumericString` type.
+///
+/// Supports the digits `0..9` and "` `" (i.e. space) only. Used e.g. by
+/// some EMV structures.
+///
+/// For other characters, use [`NumericString`][`crate::asn1::NumericString`]
+/// or [`Utf8String`][`crate::asn1::Utf8String`] instead.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct NumericString<'a> {
+    /// Inner value
+    inner: StrSlice<'a>,
+}
+
+impl<'a> NumericString<'a> {
+    /// Create a new ASN.1 `NumericString`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+        Self::validate(input)?;
+
+        StrSlice::from_bytes(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Validate all characters are within NumericString's allowed set.
+    fn validate(input: &[u8]) -> Result<()> {
+        if input.iter().any(|&c| !matches!(c, b'0'..=b'9' | b' ')) {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(())
+    }
+
+    /// Borrow the string as a `str`.
+    pub fn as_str(&self) -> &'a str {
+        self.inner.as_str()
+    }
+
+    /// Borrow the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<str> for NumericString<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for NumericString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for NumericString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, length)?.as_bytes())
+    }
+}
+
+impl<'a> EncodeValue for NumericString<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> From<&NumericString<'a>> for NumericString<'a> {
+    fn from(value: &NumericString<'a>) -> NumericString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<StrSlice<'a>> for NumericString<'a> {
+    type Error = Error;
+
+    fn try_from(inner: StrSlice<'a>) -> Result<NumericString<'a>> {
+        Self::validate(inner.as_bytes())?;
+        Ok(Self { inner })
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for NumericString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<NumericString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<NumericString<'a>> for Any<'a> {
+    fn from(numeric_string: NumericString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::NumericString, numeric_string.inner.into())
+    }
+}
+
+impl<'a> From<NumericString<'a>> for &'a [u8] {
+    fn from(numeric_string: NumericString<'a>) -> &'a [u8] {
+        numeric_string.as_bytes()
+    }
+}
+
+impl<'a> Tagged for NumericString<'a> {
+    const TAG: Tag = Tag::NumericString;
+}
+
+impl<'a> fmt::Display for NumericString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> fmt::Debug for NumericString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NumericString({:?})", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NumericString;
+    use crate::{Decodable, Encodable, ErrorKind, StrSlice, Tag};
+    use core::convert::TryFrom;
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() {
+        // NumericString "0123 456" as generated by `openssl asn1parse -genstr 'NUMERIC:0123 456'`
+        let example_bytes = hex!("12 08 30313233 20 343536");
+        let numeric_string = NumericString::from_der(&example_bytes).unwrap();
+        assert_eq!(numeric_string.as_str(), "0123 456");
+
+        let mut buf = [0u8; 10];
+        assert_eq!(
+            numeric_string.encode_to_slice(&mut buf).unwrap(),
+            example_bytes
+        );
+    }
+
+    #[test]
+    fn reject_invalid_chars() {
+        for s in &["12a", "1-2", "1.5", "\u{0660}"] {
+            let err = NumericString::new(s).err().unwrap();
+            assert_eq!(
+                err.kind(),
+                ErrorKind::Value {
+                    tag: Tag::NumericString
+                }
+            );
+        }
+
+        assert!(NumericString::from_der(&hex!("12 02 3141")).is_err());
+        assert!(NumericString::try_from(StrSlice::new("12 34").unwrap()).is_ok());
+    }
+}
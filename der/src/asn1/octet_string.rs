@@ -4,7 +4,7 @@ use crate::{
     asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, Length,
     Result, Tag, Tagged,
 };
-use core::convert::TryFrom;
+use core::{convert::TryFrom, slice, slice::SliceIndex};
 
 /// ASN.1 `OCTET STRING` type.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -18,7 +18,13 @@ impl<'a> OctetString<'a> {
     pub fn new(slice: &'a [u8]) -> Result<Self> {
         ByteSlice::new(slice)
             .map(|inner| Self { inner })
-            .map_err(|_| ErrorKind::Length { tag: Self::TAG }.into())
+            .map_err(|_| {
+                ErrorKind::Length {
+                    tag: Self::TAG,
+                    length: None,
+                }
+                .into()
+            })
     }
 
     /// Borrow the inner byte slice.
@@ -35,6 +41,32 @@ impl<'a> OctetString<'a> {
     pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
+
+    /// Iterate over the bytes of this `OCTET STRING`.
+    pub fn iter(&self) -> slice::Iter<'a, u8> {
+        self.as_bytes().iter()
+    }
+
+    /// Get a subslice of this `OCTET STRING` as a new `OCTET STRING` which
+    /// borrows from the same data, or `None` if `index` is out of bounds.
+    pub fn get<I>(&self, index: I) -> Option<Self>
+    where
+        I: SliceIndex<[u8], Output = [u8]>,
+    {
+        self.inner.get(index).map(|inner| Self { inner })
+    }
+
+    /// Split this `OCTET STRING` in two at the given offset.
+    ///
+    /// Returns an error if `mid` is greater than [`OctetString::len`].
+    pub fn split_at(&self, mid: Length) -> Result<(Self, Self)> {
+        let (head, tail) = self.inner.split_at(mid).ok_or(ErrorKind::Length {
+            tag: Self::TAG,
+            length: Some(mid),
+        })?;
+
+        Ok((Self { inner: head }, Self { inner: tail }))
+    }
 }
 
 impl AsRef<[u8]> for OctetString<'_> {
@@ -43,6 +75,15 @@ impl AsRef<[u8]> for OctetString<'_> {
     }
 }
 
+impl<'a> IntoIterator for OctetString<'a> {
+    type Item = &'a u8;
+    type IntoIter = slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> slice::Iter<'a, u8> {
+        self.iter()
+    }
+}
+
 impl<'a> DecodeValue<'a> for OctetString<'a> {
     fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
         Ok(Self {
@@ -90,3 +131,24 @@ impl<'a> From<OctetString<'a>> for &'a [u8] {
 impl<'a> Tagged for OctetString<'a> {
     const TAG: Tag = Tag::OctetString;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OctetString;
+    use crate::Length;
+
+    #[test]
+    fn subslice_octet_string() {
+        let os = OctetString::new(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(os.get(1..3).unwrap().as_bytes(), &[2, 3]);
+        assert_eq!(os.get(1..3).unwrap().len(), Length::from(2u8));
+        assert!(os.get(3..5).is_none());
+
+        let (head, tail) = os.split_at(Length::from(4u8)).unwrap();
+        assert_eq!(head, os);
+        assert!(tail.is_empty());
+        assert!(os.split_at(Length::from(5u8)).is_err());
+
+        assert_eq!(os.into_iter().copied().max(), Some(4));
+    }
+}
@@ -0,0 +1,229 @@
+//! ASN.1 open types, i.e. `ANY DEFINED BY` fields.
+
+use crate::{
+    asn1::{Any, ObjectIdentifier},
+    Decodable, Decoder, Encodable, Error, ErrorKind, Result, Sequence, Tag,
+};
+use core::convert::TryFrom;
+
+/// Function which decodes the value of an open type whose type is
+/// identified by a particular OID.
+pub type OpenTypeDecoder<'a, T> = fn(Option<Any<'a>>) -> Result<T>;
+
+/// Registry of functions for decoding open types, keyed by the OIDs which
+/// identify the types of their values.
+///
+/// Structures such as `AlgorithmIdentifier` or `Extension` contain an OID
+/// followed by an `ANY DEFINED BY` field whose type depends on the OID.
+/// A registry maps each supported OID to an [`OpenTypeDecoder`] for the
+/// corresponding concrete type:
+///
+/// ```
+/// use der::asn1::{Any, Null, ObjectIdentifier, OpenTypeRegistry};
+///
+/// #[derive(Debug, Eq, PartialEq)]
+/// enum DigestParams {
+///     Absent,
+///     Null(Null),
+/// }
+///
+/// const SHA256_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.1");
+///
+/// let registry = OpenTypeRegistry::new(&[(SHA256_OID, |value| match value {
+///     Some(any) => any.decode_into().map(DigestParams::Null),
+///     None => Ok(DigestParams::Absent),
+/// })]);
+///
+/// let params = registry.decode(SHA256_OID, Some(Any::from(Null))).unwrap();
+/// assert_eq!(params, DigestParams::Null(Null));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct OpenTypeRegistry<'r, 'a, T> {
+    /// OIDs and the functions decoding the values they identify.
+    entries: &'r [(ObjectIdentifier, OpenTypeDecoder<'a, T>)],
+}
+
+impl<'r, 'a, T> OpenTypeRegistry<'r, 'a, T> {
+    /// Create a new [`OpenTypeRegistry`] from the provided OIDs and decoding
+    /// functions.
+    pub const fn new(entries: &'r [(ObjectIdentifier, OpenTypeDecoder<'a, T>)]) -> Self {
+        Self { entries }
+    }
+
+    /// Get the decoding function registered for the given OID, if any.
+    pub fn get(&self, oid: ObjectIdentifier) -> Option<OpenTypeDecoder<'a, T>> {
+        self.entries
+            .iter()
+            .find(|(entry_oid, _)| *entry_oid == oid)
+            .map(|&(_, decoder)| decoder)
+    }
+
+    /// Decode the value of an open type identified by the given OID.
+    ///
+    /// Returns an [`ErrorKind::UnknownOid`] error if no decoding function is
+    /// registered for the OID.
+    pub fn decode(&self, oid: ObjectIdentifier, value: Option<Any<'a>>) -> Result<T> {
+        let decoder = self.get(oid).ok_or(ErrorKind::UnknownOid { oid })?;
+        decoder(value)
+    }
+}
+
+/// `SEQUENCE` of an `OBJECT IDENTIFIER` and an optional value whose type
+/// is identified by it.
+///
+/// ```text
+/// OidValuePair ::= SEQUENCE {
+///     oid    OBJECT IDENTIFIER,
+///     value  ANY DEFINED BY oid OPTIONAL }
+/// ```
+///
+/// The value can be decoded into a concrete type with
+/// [`OidValuePair::decode_value`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct OidValuePair<'a> {
+    /// OID identifying the type of the value.
+    pub oid: ObjectIdentifier,
+
+    /// Value whose type is identified by the OID.
+    pub value: Option<Any<'a>>,
+}
+
+impl<'a> OidValuePair<'a> {
+    /// Create a new [`OidValuePair`].
+    pub fn new(oid: ObjectIdentifier, value: Option<Any<'a>>) -> Self {
+        Self { oid, value }
+    }
+
+    /// Decode the value using the function registered for its OID in the
+    /// given [`OpenTypeRegistry`].
+    pub fn decode_value<T>(&self, registry: &OpenTypeRegistry<'_, 'a, T>) -> Result<T> {
+        registry.decode(self.oid, self.value)
+    }
+}
+
+impl<'a> Decodable<'a> for OidValuePair<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            let oid = decoder.decode()?;
+            let value = decoder.decode()?;
+            Ok(Self { oid, value })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for OidValuePair<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        f(&[&self.oid, &self.value])
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for OidValuePair<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<OidValuePair<'a>> {
+        any.tag().assert_eq(Tag::Sequence)?;
+        let mut decoder = Decoder::new(any.value());
+        let oid = decoder.decode()?;
+        let value = decoder.decode()?;
+        decoder.finish(Self { oid, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OidValuePair, OpenTypeRegistry};
+    use crate::{
+        asn1::{Any, Null, ObjectIdentifier, OctetString},
+        Decodable, Encodable, ErrorKind, Result, Tag,
+    };
+    use core::convert::TryFrom;
+    use hex_literal::hex;
+
+    /// `sha256` OID.
+    const SHA256_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.1");
+
+    /// `subjectKeyIdentifier` OID.
+    const SKI_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.14");
+
+    /// `sha256` `AlgorithmIdentifier` with `NULL` parameters.
+    const SHA256_NULL: &[u8] = &hex!("300d06096086480165030402010500");
+
+    /// `sha256` `AlgorithmIdentifier` with absent parameters.
+    const SHA256_ABSENT: &[u8] = &hex!("300b0609608648016503040201");
+
+    /// `subjectKeyIdentifier` extension value.
+    const SKI: &[u8] = &hex!("300b0603551d0e0404cafebabe");
+
+    #[derive(Debug, Eq, PartialEq)]
+    enum Value<'a> {
+        Digest(Option<Null>),
+        KeyIdentifier(OctetString<'a>),
+    }
+
+    fn decode_digest(value: Option<Any<'_>>) -> Result<Value<'_>> {
+        value.map(Any::decode_into).transpose().map(Value::Digest)
+    }
+
+    fn decode_key_identifier(value: Option<Any<'_>>) -> Result<Value<'_>> {
+        value
+            .ok_or_else(|| Tag::OctetString.value_error())?
+            .decode_into()
+            .map(Value::KeyIdentifier)
+    }
+
+    fn decode<'a>(pair: &OidValuePair<'a>) -> Result<Value<'a>> {
+        pair.decode_value(&OpenTypeRegistry::new(&[
+            (SHA256_OID, decode_digest),
+            (SKI_OID, decode_key_identifier),
+        ]))
+    }
+
+    #[test]
+    fn round_trip() {
+        for &example in &[SHA256_NULL, SHA256_ABSENT, SKI] {
+            let mut buf = [0u8; 16];
+            let pair = OidValuePair::from_der(example).unwrap();
+            assert_eq!(pair.encode_to_slice(&mut buf).unwrap(), example);
+            assert_eq!(
+                OidValuePair::try_from(Any::from_der(example).unwrap()).unwrap(),
+                pair
+            );
+        }
+    }
+
+    #[test]
+    fn decode_value() {
+        let pair = OidValuePair::from_der(SHA256_NULL).unwrap();
+        assert_eq!(pair.oid, SHA256_OID);
+        assert_eq!(decode(&pair).unwrap(), Value::Digest(Some(Null)));
+
+        let pair = OidValuePair::from_der(SHA256_ABSENT).unwrap();
+        assert_eq!(decode(&pair).unwrap(), Value::Digest(None));
+
+        let pair = OidValuePair::from_der(SKI).unwrap();
+        assert_eq!(
+            decode(&pair).unwrap(),
+            Value::KeyIdentifier(OctetString::new(&hex!("cafebabe")).unwrap())
+        );
+    }
+
+    #[test]
+    fn decode_value_errors() {
+        // Unregistered OID
+        let oid = ObjectIdentifier::new("2.5.29.15");
+        let pair = OidValuePair::new(oid, None);
+        let err = decode(&pair).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::UnknownOid { oid });
+
+        // Value of the wrong type
+        let pair = OidValuePair::new(SKI_OID, Some(Any::from(Null)));
+        assert!(decode(&pair).is_err());
+
+        // Missing value
+        let pair = OidValuePair::new(SKI_OID, None);
+        assert!(decode(&pair).is_err());
+    }
+}
@@ -1,15 +1,14 @@
 //! ASN.1 `OPTIONAL` as mapped to Rust's `Option` type
 
-use crate::{Choice, Decodable, Decoder, Encodable, Encoder, Length, Result, Tag};
-use core::convert::TryFrom;
+use crate::{Choice, Decodable, Decoder, Encodable, Encoder, Length, Result, ReverseEncoder};
 
 impl<'a, T> Decodable<'a> for Option<T>
 where
     T: Choice<'a>, // NOTE: all `Decodable + Tagged` types receive a blanket `Choice` impl
 {
     fn decode(decoder: &mut Decoder<'a>) -> Result<Option<T>> {
-        if let Some(byte) = decoder.peek() {
-            if T::can_decode(Tag::try_from(byte)?) {
+        if let Some(tag) = decoder.peek_tag()? {
+            if T::can_decode(tag) {
                 return T::decode(decoder).map(Some);
             }
         }
@@ -37,4 +36,12 @@ where
             Ok(())
         }
     }
+
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        if let Some(encodable) = self {
+            encoder.encode(encodable)
+        } else {
+            Ok(())
+        }
+    }
 }
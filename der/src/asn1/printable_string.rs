@@ -45,8 +45,15 @@ impl<'a> PrintableString<'a> {
         T: AsRef<[u8]> + ?Sized,
     {
         let input = input.as_ref();
+        Self::validate(input)?;
 
-        // Validate all characters are within PrintedString's allowed set
+        StrSlice::from_bytes(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Validate all characters are within PrintableString's allowed set.
+    fn validate(input: &[u8]) -> Result<()> {
         for &c in input.iter() {
             match c {
                 b'A'..=b'Z'
@@ -68,9 +75,7 @@ impl<'a> PrintableString<'a> {
             }
         }
 
-        StrSlice::from_bytes(input)
-            .map(|inner| Self { inner })
-            .map_err(|_| Self::TAG.value_error())
+        Ok(())
     }
 
     /// Borrow the string as a `str`.
@@ -128,6 +133,15 @@ impl<'a> From<&PrintableString<'a>> for PrintableString<'a> {
     }
 }
 
+impl<'a> TryFrom<StrSlice<'a>> for PrintableString<'a> {
+    type Error = Error;
+
+    fn try_from(inner: StrSlice<'a>) -> Result<PrintableString<'a>> {
+        Self::validate(inner.as_bytes())?;
+        Ok(Self { inner })
+    }
+}
+
 impl<'a> TryFrom<Any<'a>> for PrintableString<'a> {
     type Error = Error;
 
@@ -167,7 +181,8 @@ impl<'a> fmt::Debug for PrintableString<'a> {
 #[cfg(test)]
 mod tests {
     use super::PrintableString;
-    use crate::Decodable;
+    use crate::{Decodable, StrSlice};
+    use core::convert::TryFrom;
 
     #[test]
     fn parse_bytes() {
@@ -178,4 +193,14 @@ mod tests {
         let printable_string = PrintableString::from_der(example_bytes).unwrap();
         assert_eq!(printable_string.as_str(), "Test User 1");
     }
+
+    #[test]
+    fn from_str_slice() {
+        let s = StrSlice::new("Test User 1").unwrap();
+        let printable_string = PrintableString::try_from(s).unwrap();
+        assert_eq!(printable_string.as_str(), "Test User 1");
+
+        let s = StrSlice::new("user@example.com").unwrap();
+        assert!(PrintableString::try_from(s).is_err());
+    }
 }
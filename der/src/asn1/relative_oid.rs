@@ -0,0 +1,280 @@
+//! ASN.1 `RELATIVE-OID` support.
+
+use crate::{
+    asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result, Tag,
+    Tagged,
+};
+use core::{convert::TryFrom, fmt};
+
+#[cfg(feature = "oid")]
+use crate::{asn1::ObjectIdentifier, ErrorKind};
+
+/// ASN.1 `RELATIVE-OID` type.
+///
+/// Identifies an object relative to a known base OID, i.e. it holds the
+/// trailing arcs of an `OBJECT IDENTIFIER`, each encoded in base 128 as
+/// they would be in a full OID. With the `oid` feature enabled, it can be
+/// obtained from the suffix of an [`ObjectIdentifier`] with
+/// [`RelativeOid::from_suffix`] and appended to a base OID with
+/// [`RelativeOid::resolve`].
+///
+/// Arcs are represented as `u32`, the same as `const_oid::Arc`.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct RelativeOid<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> RelativeOid<'a> {
+    /// Create a new `RELATIVE-OID` from its BER/DER encoding.
+    ///
+    /// Returns an error if the input doesn't contain at least one arc, or if
+    /// any arc is truncated, isn't minimally encoded, or overflows a `u32`.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        let mut remaining = bytes;
+
+        if remaining.is_empty() {
+            return Err(Self::TAG.value_error());
+        }
+
+        while !remaining.is_empty() {
+            let (_, arc_len) = decode_arc(remaining).ok_or_else(|| Self::TAG.value_error())?;
+            remaining = &remaining[arc_len..];
+        }
+
+        ByteSlice::new(bytes)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Get the `RELATIVE-OID` made of the arcs of `oid` which follow `base`.
+    ///
+    /// Returns an error if `base` isn't a proper prefix of `oid`.
+    #[cfg(feature = "oid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+    pub fn from_suffix(oid: &'a ObjectIdentifier, base: &ObjectIdentifier) -> Result<Self> {
+        // The last byte of an encoded arc never has its high bit set, so a
+        // byte-wise prefix is also a prefix of arcs
+        oid.as_bytes()
+            .strip_prefix(base.as_bytes())
+            .ok_or_else(|| Self::TAG.value_error())
+            .and_then(Self::new)
+    }
+
+    /// Append the arcs of this `RELATIVE-OID` to `base`.
+    ///
+    /// Returns an error if the resulting OID exceeds
+    /// [`ObjectIdentifier::MAX_LENGTH`].
+    #[cfg(feature = "oid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+    pub fn resolve(&self, base: &ObjectIdentifier) -> Result<ObjectIdentifier> {
+        let prefix = base.as_bytes();
+        let suffix = self.as_bytes();
+
+        let mut buf = [0u8; ObjectIdentifier::MAX_LENGTH];
+        let bytes = buf
+            .get_mut(..prefix.len() + suffix.len())
+            .ok_or(ErrorKind::MalformedOid)?;
+
+        bytes[..prefix.len()].copy_from_slice(prefix);
+        bytes[prefix.len()..].copy_from_slice(suffix);
+        Ok(ObjectIdentifier::from_bytes(bytes)?)
+    }
+
+    /// Iterate over the arcs of this `RELATIVE-OID`.
+    pub fn arcs(&self) -> impl Iterator<Item = u32> + 'a {
+        let mut remaining = self.as_bytes();
+
+        core::iter::from_fn(move || {
+            let (arc, arc_len) = decode_arc(remaining)?;
+            remaining = &remaining[arc_len..];
+            Some(arc)
+        })
+    }
+
+    /// Borrow the BER/DER encoding of this `RELATIVE-OID`.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for RelativeOid<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for RelativeOid<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, length)?.as_bytes())
+    }
+}
+
+impl<'a> EncodeValue for RelativeOid<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> From<&RelativeOid<'a>> for RelativeOid<'a> {
+    fn from(value: &RelativeOid<'a>) -> RelativeOid<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for RelativeOid<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<RelativeOid<'a>> {
+        Self::new(bytes)
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for RelativeOid<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<RelativeOid<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<RelativeOid<'a>> for Any<'a> {
+    fn from(relative_oid: RelativeOid<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::RelativeOid, relative_oid.inner)
+    }
+}
+
+impl<'a> Tagged for RelativeOid<'a> {
+    const TAG: Tag = Tag::RelativeOid;
+}
+
+impl<'a> fmt::Display for RelativeOid<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arc) in self.arcs().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+
+            write!(f, "{}", arc)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Debug for RelativeOid<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RelativeOid({})", self)
+    }
+}
+
+/// Decode the base 128 encoded arc at the start of `bytes`, returning its
+/// value and the number of bytes it occupies.
+///
+/// Returns `None` if the arc is truncated, isn't minimally encoded, or
+/// overflows a `u32`.
+fn decode_arc(bytes: &[u8]) -> Option<(u32, usize)> {
+    if bytes.first() == Some(&0x80) {
+        return None;
+    }
+
+    let mut arc = 0u32;
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        if arc.leading_zeros() < 7 {
+            return None;
+        }
+
+        arc = arc << 7 | u32::from(byte & 0x7F);
+
+        if byte & 0x80 == 0 {
+            return Some((arc, i + 1));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RelativeOid;
+    use crate::{Decodable, Encodable, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() {
+        // RELATIVE-OID 8571.3.2
+        let example_bytes = hex!("0d 04 c27b 03 02");
+        let relative_oid = RelativeOid::from_der(&example_bytes).unwrap();
+        assert!(relative_oid.arcs().eq([8571, 3, 2].iter().copied()));
+
+        let mut buf = [0u8; 6];
+        assert_eq!(
+            relative_oid.encode_to_slice(&mut buf).unwrap(),
+            example_bytes
+        );
+    }
+
+    #[test]
+    fn max_arc() {
+        let relative_oid = RelativeOid::new(&hex!("8fffffff7f 00")).unwrap();
+        assert!(relative_oid.arcs().eq([u32::MAX, 0].iter().copied()));
+    }
+
+    #[test]
+    fn reject_malformed() {
+        for bytes in &[
+            &[][..],
+            &hex!("2a 86"),
+            &hex!("80 01"),
+            &hex!("908080 8000"),
+        ] {
+            let err = RelativeOid::new(bytes).err().unwrap();
+            assert_eq!(
+                err.kind(),
+                ErrorKind::Value {
+                    tag: Tag::RelativeOid
+                }
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fmt() {
+        use alloc::format;
+
+        let relative_oid = RelativeOid::new(&hex!("c27b 03 02")).unwrap();
+        assert_eq!(format!("{}", relative_oid), "8571.3.2");
+        assert_eq!(format!("{:?}", relative_oid), "RelativeOid(8571.3.2)");
+    }
+
+    #[cfg(feature = "oid")]
+    #[test]
+    fn oid_suffix() {
+        use crate::asn1::ObjectIdentifier;
+
+        let base = ObjectIdentifier::new("1.3.6.1.4.1");
+        let oid = ObjectIdentifier::new("1.3.6.1.4.1.8571.3.2");
+
+        let relative_oid = RelativeOid::from_suffix(&oid, &base).unwrap();
+        assert_eq!(relative_oid.as_bytes(), hex!("c27b 03 02"));
+        assert_eq!(relative_oid.resolve(&base).unwrap(), oid);
+
+        // The base must be a proper prefix
+        assert!(RelativeOid::from_suffix(&oid, &oid).is_err());
+        assert!(RelativeOid::from_suffix(&base, &oid).is_err());
+        assert!(RelativeOid::from_suffix(&oid, &ObjectIdentifier::new("1.3.6.1.5")).is_err());
+
+        // Resolving must not exceed the maximum OID length
+        let long = RelativeOid::new(&[0x01; ObjectIdentifier::MAX_LENGTH]).unwrap();
+        assert_eq!(
+            long.resolve(&base).err().unwrap().kind(),
+            ErrorKind::MalformedOid
+        );
+    }
+}
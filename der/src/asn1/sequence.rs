@@ -1,7 +1,9 @@
 //! The [`Sequence`] trait simplifies writing decoders/encoders which map ASN.1
 //! `SEQUENCE`s to Rust structs.
 
-use crate::{Decodable, Encodable, EncodeValue, Encoder, Length, Result, Tag, Tagged};
+use crate::{
+    Decodable, Encodable, EncodeValue, Encoder, Length, Result, ReverseEncoder, Tag, Tagged,
+};
 
 /// ASN.1 `SEQUENCE` trait.
 ///
@@ -40,6 +42,16 @@ where
             Ok(())
         })
     }
+
+    fn encode_value_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        self.fields(|fields| {
+            for &field in fields.iter().rev() {
+                encoder.encode(field)?;
+            }
+
+            Ok(())
+        })
+    }
 }
 
 impl<'a, M> Tagged for M
@@ -1,9 +1,10 @@
 //! ASN.1 `SEQUENCE OF` support.
 
 use crate::{
-    arrayvec, ArrayVec, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder,
-    ErrorKind, Length, Result, Tag, Tagged,
+    arrayvec, ArrayVec, ByteSlice, Decodable, DecodeValue, Decoder, Encodable, EncodeValue,
+    Encoder, Error, ErrorKind, Length, Result, ReverseEncoder, Tag, Tagged,
 };
+use core::{convert::TryFrom, fmt, marker::PhantomData};
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
@@ -28,6 +29,8 @@ impl<T, const N: usize> SequenceOf<T, N> {
     }
 
     /// Add an element to this [`SequenceOf`].
+    ///
+    /// Returns [`ErrorKind::Overlength`] if it already contains `N` elements.
     pub fn add(&mut self, element: T) -> Result<()> {
         self.inner.add(element)
     }
@@ -43,6 +46,16 @@ impl<T, const N: usize> SequenceOf<T, N> {
             inner: self.inner.iter(),
         }
     }
+
+    /// Get the number of elements in this [`SequenceOf`].
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Is this [`SequenceOf`] empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
 }
 
 impl<T, const N: usize> Default for SequenceOf<T, N> {
@@ -51,6 +64,31 @@ impl<T, const N: usize> Default for SequenceOf<T, N> {
     }
 }
 
+impl<T, const N: usize> From<[T; N]> for SequenceOf<T, N> {
+    fn from(array: [T; N]) -> Self {
+        Self {
+            inner: ArrayVec::from_array(array),
+        }
+    }
+}
+
+impl<T, const N: usize> TryFrom<&[T]> for SequenceOf<T, N>
+where
+    T: Clone,
+{
+    type Error = Error;
+
+    fn try_from(slice: &[T]) -> Result<Self> {
+        let mut sequence_of = Self::new();
+
+        for element in slice {
+            sequence_of.add(element.clone())?;
+        }
+
+        Ok(sequence_of)
+    }
+}
+
 impl<'a, T, const N: usize> DecodeValue<'a> for SequenceOf<T, N>
 where
     T: Decodable<'a>,
@@ -64,7 +102,10 @@ where
         }
 
         if decoder.position() != end_pos {
-            decoder.error(ErrorKind::Length { tag: Self::TAG });
+            decoder.error(ErrorKind::Length {
+                tag: Self::TAG,
+                length: None,
+            });
         }
 
         Ok(sequence_of)
@@ -135,6 +176,14 @@ where
 
         Ok(())
     }
+
+    fn encode_value_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        for elem in self.iter().rev() {
+            encoder.encode(elem)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a, T, const N: usize> Tagged for [T; N]
@@ -152,14 +201,18 @@ where
 {
     fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
         let end_pos = (decoder.position() + length)?;
-        let mut sequence_of = Self::new();
+        let mut sequence_of = Self::with_capacity(decoder.element_capacity::<T>(length));
 
         while decoder.position() < end_pos {
+            decoder.check_element_count(Self::TAG, sequence_of.len())?;
             sequence_of.push(decoder.decode()?);
         }
 
         if decoder.position() != end_pos {
-            decoder.error(ErrorKind::Length { tag: Self::TAG });
+            decoder.error(ErrorKind::Length {
+                tag: Self::TAG,
+                length: None,
+            });
         }
 
         Ok(sequence_of)
@@ -184,6 +237,14 @@ where
 
         Ok(())
     }
+
+    fn encode_value_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        for elem in self.iter().rev() {
+            encoder.encode(elem)?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -194,3 +255,225 @@ where
 {
     const TAG: Tag = Tag::Sequence;
 }
+
+/// ASN.1 `SEQUENCE OF` whose elements are decoded lazily.
+///
+/// Decoding this type only parses the header of the `SEQUENCE OF`, borrowing
+/// its contents. Elements are decoded on demand by [`LazySequenceOf::iter`],
+/// which reports the index and offset of any element which fails to decode.
+///
+/// This is useful for large `SEQUENCE OF` types (e.g. the revoked
+/// certificates of a CRL) which would otherwise need to be collected up front.
+pub struct LazySequenceOf<'a, T> {
+    /// DER-encoded elements.
+    contents: ByteSlice<'a>,
+
+    /// Element type.
+    element: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> LazySequenceOf<'a, T> {
+    /// Borrow the DER-encoded elements of this `SEQUENCE OF`.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.contents.as_bytes()
+    }
+
+    /// Iterate over the elements of this `SEQUENCE OF`, decoding them as
+    /// they're visited.
+    ///
+    /// Iteration ends after the first element which fails to decode.
+    pub fn iter(&self) -> LazySequenceOfIter<'a, T>
+    where
+        T: Decodable<'a>,
+    {
+        LazySequenceOfIter {
+            decoder: Decoder::new(self.as_bytes()),
+            index: 0,
+            failed: false,
+            element: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Clone for LazySequenceOf<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for LazySequenceOf<'a, T> {}
+
+impl<'a, T> fmt::Debug for LazySequenceOf<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazySequenceOf")
+            .field("contents", &self.contents)
+            .finish()
+    }
+}
+
+impl<'a, T> Eq for LazySequenceOf<'a, T> {}
+
+impl<'a, T> PartialEq for LazySequenceOf<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.contents == other.contents
+    }
+}
+
+impl<'a, T> DecodeValue<'a> for LazySequenceOf<'a, T> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Ok(Self {
+            contents: ByteSlice::decode_value(decoder, length)?,
+            element: PhantomData,
+        })
+    }
+}
+
+impl<'a, T> EncodeValue for LazySequenceOf<'a, T> {
+    fn value_len(&self) -> Result<Length> {
+        self.contents.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.contents.encode_value(encoder)
+    }
+}
+
+impl<'a, T> Tagged for LazySequenceOf<'a, T> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+/// Iterator over the elements of a [`LazySequenceOf`].
+#[derive(Debug)]
+pub struct LazySequenceOfIter<'a, T> {
+    /// Decoder for the remaining elements.
+    decoder: Decoder<'a>,
+
+    /// Index of the next element.
+    index: usize,
+
+    /// Has an element failed to decode?
+    failed: bool,
+
+    /// Element type.
+    element: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> Iterator for LazySequenceOfIter<'a, T>
+where
+    T: Decodable<'a>,
+{
+    type Item = core::result::Result<T, SequenceOfDecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.decoder.is_finished() {
+            return None;
+        }
+
+        let index = self.index;
+        let offset = self.decoder.position();
+        self.index += 1;
+
+        match self.decoder.decode() {
+            Ok(element) => Some(Ok(element)),
+            Err(source) => {
+                self.failed = true;
+                Some(Err(SequenceOfDecodeError {
+                    index,
+                    offset,
+                    source,
+                }))
+            }
+        }
+    }
+}
+
+/// Error decoding an element of a [`LazySequenceOf`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SequenceOfDecodeError {
+    /// Index of the element which failed to decode.
+    pub index: usize,
+
+    /// Offset of the element within the contents of the `SEQUENCE OF`.
+    pub offset: Length,
+
+    /// Error which occurred decoding the element.
+    ///
+    /// Its position is relative to the contents of the `SEQUENCE OF`.
+    pub source: Error,
+}
+
+impl fmt::Display for SequenceOfDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SEQUENCE OF element {} at offset {}: {}",
+            self.index, self.offset, self.source
+        )
+    }
+}
+
+impl From<SequenceOfDecodeError> for Error {
+    fn from(err: SequenceOfDecodeError) -> Error {
+        err.source
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SequenceOfDecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{LazySequenceOf, SequenceOf};
+    use crate::{Decodable, Encodable, ErrorKind, Length};
+    use core::convert::TryFrom;
+    use hex_literal::hex;
+
+    #[test]
+    fn sequence_of_from_array() {
+        // SEQUENCE OF INTEGER { 1, 2, 3 }
+        let bytes = hex!("3009020101020102020103");
+        let seq = SequenceOf::from([1u8, 2, 3]);
+        assert_eq!(seq.len(), 3);
+
+        let mut buf = [0u8; 11];
+        assert_eq!(seq.encode_to_slice(&mut buf).unwrap(), bytes);
+        assert_eq!(SequenceOf::<u8, 3>::from_der(&bytes).unwrap(), seq);
+        assert_eq!(<[u8; 3]>::from_der(&bytes).unwrap(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn sequence_of_try_from_slice() {
+        let seq = SequenceOf::<u8, 4>::try_from(&[1, 2, 3][..]).unwrap();
+        assert!(seq.iter().copied().eq([1, 2, 3].iter().copied()));
+        assert!(!seq.is_empty());
+
+        let err = SequenceOf::<u8, 2>::try_from(&[1, 2, 3][..]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
+
+    #[test]
+    fn lazy_sequence_of() {
+        // SEQUENCE OF INTEGER { 1, 2, 3 }
+        let bytes = hex!("3009020101020102020103");
+        let seq = LazySequenceOf::<u8>::from_der(&bytes).unwrap();
+        assert!(seq.iter().map(Result::unwrap).eq([1, 2, 3].iter().copied()));
+
+        let mut buf = [0u8; 11];
+        assert_eq!(seq.encode_to_slice(&mut buf).unwrap(), bytes);
+    }
+
+    #[test]
+    fn lazy_sequence_of_decode_error() {
+        // SEQUENCE OF INTEGER { 1, <non-canonical 2>, 3 }
+        let bytes = hex!("300a02010102020002020103");
+        let seq = LazySequenceOf::<u8>::from_der(&bytes).unwrap();
+        let mut iter = seq.iter();
+        assert_eq!(iter.next().unwrap().unwrap(), 1);
+
+        let err = iter.next().unwrap().err().unwrap();
+        assert_eq!(err.index, 1);
+        assert_eq!(err.offset, Length::new(3));
+        assert!(matches!(err.source.kind(), ErrorKind::Noncanonical { .. }));
+        assert!(iter.next().is_none());
+    }
+}
@@ -1,21 +1,27 @@
 //! ASN.1 `SET OF` support.
 
 use crate::{
-    arrayvec, ArrayVec, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder,
+    arrayvec, ArrayVec, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error,
     ErrorKind, Length, Result, Tag, Tagged,
 };
+use core::{cmp::Ordering, convert::TryFrom};
+
+#[cfg(not(feature = "alloc"))]
+use crate::encoder::Sink;
 
 #[cfg(feature = "alloc")]
 use {
-    crate::{asn1::Any, Error},
-    alloc::collections::BTreeSet,
-    core::convert::TryFrom,
+    crate::{asn1::Any, ReverseEncoder},
+    alloc::{collections::BTreeSet, vec::Vec},
 };
 
 /// ASN.1 `SET OF` backed by an array.
 ///
-/// This type implements an append-only `SET OF` type which is stack-based
-/// and does not depend on `alloc` support.
+/// This type implements a `SET OF` type which is stack-based and does not
+/// depend on `alloc` support.
+///
+/// Elements are kept in the canonical order required by DER, i.e. sorted by
+/// their encodings (X.690 Section 11.6), and duplicates are rejected.
 // TODO(tarcieri): use `ArrayVec` when/if it's merged into `core`
 // See: https://github.com/rust-lang/rfcs/pull/2990
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -39,12 +45,15 @@ where
 
     /// Add an element to this [`SetOf`].
     ///
-    /// Items MUST be added in lexicographical order according to the `Ord`
-    /// impl on `T`.
-    pub fn add(&mut self, element: T) -> Result<()> {
-        // Ensure set elements are lexicographically ordered
-        if let Some(elem) = self.inner.last() {
-            if elem >= &element {
+    /// Items MUST be added in the canonical DER order, i.e. the encoding of
+    /// each element must sort after the encoding of the previous one.
+    /// Otherwise [`ErrorKind::Ordering`] is returned.
+    pub fn add(&mut self, element: T) -> Result<()>
+    where
+        T: Encodable,
+    {
+        if let Some(last) = self.inner.last() {
+            if der_cmp(last, &element)? != Ordering::Less {
                 return Err(ErrorKind::Ordering.into());
             }
         }
@@ -52,12 +61,40 @@ where
         self.inner.add(element)
     }
 
+    /// Insert an element into this [`SetOf`] at the position given by the
+    /// canonical DER order.
+    ///
+    /// Returns [`ErrorKind::Ordering`] if the set already contains an element
+    /// with the same encoding.
+    pub fn insert(&mut self, element: T) -> Result<()>
+    where
+        T: Encodable,
+    {
+        let index = insertion_index(self.iter(), &element)?;
+        self.inner.insert(index, element)
+    }
+
+    /// Get an element of this [`SetOf`].
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.inner.get(index)
+    }
+
     /// Iterate over the elements of this [`SetOf`].
     pub fn iter(&self) -> SetOfIter<'_, T> {
         SetOfIter {
             inner: self.inner.iter(),
         }
     }
+
+    /// Get the number of elements in this [`SetOf`].
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Is this [`SetOf`] empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
 }
 
 impl<T, const N: usize> Default for SetOf<T, N>
@@ -69,20 +106,42 @@ where
     }
 }
 
+impl<T, const N: usize> TryFrom<[T; N]> for SetOf<T, N>
+where
+    T: Clone + Encodable + Ord,
+{
+    type Error = Error;
+
+    fn try_from(array: [T; N]) -> Result<Self> {
+        let mut set_of = Self::new();
+
+        for element in array {
+            set_of.insert(element)?;
+        }
+
+        Ok(set_of)
+    }
+}
+
 impl<'a, T, const N: usize> DecodeValue<'a> for SetOf<T, N>
 where
-    T: Clone + Decodable<'a> + Ord,
+    T: Clone + Decodable<'a> + Encodable + Ord,
 {
     fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
         let end_pos = (decoder.position() + length)?;
         let mut result = Self::new();
 
         while decoder.position() < end_pos {
-            result.add(decoder.decode()?)?;
+            result
+                .add(decoder.decode()?)
+                .map_err(|e| non_canonical_error(e, Self::TAG))?;
         }
 
         if decoder.position() != end_pos {
-            decoder.error(ErrorKind::Length { tag: Self::TAG });
+            decoder.error(ErrorKind::Length {
+                tag: Self::TAG,
+                length: None,
+            });
         }
 
         Ok(result)
@@ -129,6 +188,227 @@ impl<'a, T> Iterator for SetOfIter<'a, T> {
     }
 }
 
+/// ASN.1 `SET OF` backed by a [`Vec`].
+///
+/// Like [`SetOf`], elements are kept in the canonical order required by DER,
+/// i.e. sorted by their encodings (X.690 Section 11.6), and duplicates are
+/// rejected. Unlike [`BTreeSet`], whose `SET OF` encoding is ordered by the
+/// `Ord` impl on `T`, this is correct for element types whose `Ord` impl
+/// doesn't match the ordering of their encodings.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct SetOfVec<T>
+where
+    T: Clone + Ord,
+{
+    inner: Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> SetOfVec<T>
+where
+    T: Clone + Ord,
+{
+    /// Create a new [`SetOfVec`].
+    pub fn new() -> Self {
+        Self { inner: Vec::new() }
+    }
+
+    /// Add an element to this [`SetOfVec`].
+    ///
+    /// Items MUST be added in the canonical DER order, i.e. the encoding of
+    /// each element must sort after the encoding of the previous one.
+    /// Otherwise [`ErrorKind::Ordering`] is returned.
+    pub fn add(&mut self, element: T) -> Result<()>
+    where
+        T: Encodable,
+    {
+        if let Some(last) = self.inner.last() {
+            if der_cmp(last, &element)? != Ordering::Less {
+                return Err(ErrorKind::Ordering.into());
+            }
+        }
+
+        self.inner.push(element);
+        Ok(())
+    }
+
+    /// Insert an element into this [`SetOfVec`] at the position given by the
+    /// canonical DER order.
+    ///
+    /// Returns [`ErrorKind::Ordering`] if the set already contains an element
+    /// with the same encoding.
+    pub fn insert(&mut self, element: T) -> Result<()>
+    where
+        T: Encodable,
+    {
+        let index = insertion_index(self.iter(), &element)?;
+        self.inner.insert(index, element);
+        Ok(())
+    }
+
+    /// Get an element of this [`SetOfVec`].
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.inner.get(index)
+    }
+
+    /// Iterate over the elements of this [`SetOfVec`].
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.inner.iter()
+    }
+
+    /// Get the number of elements in this [`SetOfVec`].
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Is this [`SetOfVec`] empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Borrow the elements of this [`SetOfVec`] as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+
+    /// Convert this [`SetOfVec`] into its inner [`Vec`].
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> Default for SetOfVec<T>
+where
+    T: Clone + Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> TryFrom<Vec<T>> for SetOfVec<T>
+where
+    T: Clone + Encodable + Ord,
+{
+    type Error = Error;
+
+    fn try_from(vec: Vec<T>) -> Result<Self> {
+        // Encode each element once and sort by the encodings
+        let mut elements = vec
+            .into_iter()
+            .map(|elem| Ok((elem.to_vec()?, elem)))
+            .collect::<Result<Vec<_>>>()?;
+
+        elements.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        if elements.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(ErrorKind::Ordering.into());
+        }
+
+        Ok(Self {
+            inner: elements.into_iter().map(|(_, elem)| elem).collect(),
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> From<SetOfVec<T>> for Vec<T>
+where
+    T: Clone + Ord,
+{
+    fn from(set_of: SetOfVec<T>) -> Vec<T> {
+        set_of.into_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a, T> DecodeValue<'a> for SetOfVec<T>
+where
+    T: Clone + Decodable<'a> + Encodable + Ord,
+{
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        let end_pos = (decoder.position() + length)?;
+        let mut result = Self {
+            inner: Vec::with_capacity(decoder.element_capacity::<T>(length)),
+        };
+
+        while decoder.position() < end_pos {
+            decoder.check_element_count(Self::TAG, result.len())?;
+            result
+                .add(decoder.decode()?)
+                .map_err(|e| non_canonical_error(e, Self::TAG))?;
+        }
+
+        if decoder.position() != end_pos {
+            decoder.error(ErrorKind::Length {
+                tag: Self::TAG,
+                length: None,
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> EncodeValue for SetOfVec<T>
+where
+    T: Clone + Encodable + Ord,
+{
+    fn value_len(&self) -> Result<Length> {
+        self.iter()
+            .fold(Ok(Length::ZERO), |len, elem| len + elem.encoded_len()?)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        for elem in self.iter() {
+            elem.encode(encoder)?;
+        }
+
+        Ok(())
+    }
+
+    fn encode_value_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        for elem in self.iter().rev() {
+            encoder.encode(elem)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a, T> TryFrom<Any<'a>> for SetOfVec<T>
+where
+    T: Clone + Decodable<'a> + Encodable + Ord,
+{
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.decode_into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> Tagged for SetOfVec<T>
+where
+    T: Clone + Ord,
+{
+    const TAG: Tag = Tag::Set;
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl<'a, T> DecodeValue<'a> for BTreeSet<T>
@@ -141,6 +421,7 @@ where
         let mut last_value = None;
 
         while decoder.position() < end_pos {
+            decoder.check_element_count(Self::TAG, result.len() + last_value.is_some() as usize)?;
             let value = decoder.decode()?;
 
             if let Some(last) = last_value.take() {
@@ -155,7 +436,10 @@ where
         }
 
         if decoder.position() != end_pos {
-            decoder.error(ErrorKind::Length { tag: Self::TAG });
+            decoder.error(ErrorKind::Length {
+                tag: Self::TAG,
+                length: None,
+            });
         }
 
         if let Some(last) = last_value {
@@ -207,3 +491,277 @@ where
 {
     const TAG: Tag = Tag::Set;
 }
+
+/// Compare the DER encodings of two values, which determines the order of
+/// `SET OF` elements (X.690 Section 11.6).
+#[cfg(feature = "alloc")]
+pub(crate) fn der_cmp(a: &dyn Encodable, b: &dyn Encodable) -> Result<Ordering> {
+    Ok(a.to_vec()?.cmp(&b.to_vec()?))
+}
+
+/// Compare the DER encodings of two values, which determines the order of
+/// `SET OF` elements (X.690 Section 11.6).
+///
+/// Without `alloc`, `a` is encoded into fixed-size windows and `b` is
+/// compared against each window as it is encoded, stopping at the first
+/// difference. Encodings which fit in a single window are written once.
+#[cfg(not(feature = "alloc"))]
+pub(crate) fn der_cmp(a: &dyn Encodable, b: &dyn Encodable) -> Result<Ordering> {
+    let a_len = usize::try_from(a.encoded_len()?)?;
+    let b_len = usize::try_from(b.encoded_len()?)?;
+    let len = a_len.min(b_len);
+    let mut offset = 0;
+
+    while offset < len {
+        let window = EncodingWindow::new(a, offset, len)?;
+
+        match WindowCmp::compare(b, offset, window.as_bytes())? {
+            Ordering::Equal => offset += window.len,
+            ordering => return Ok(ordering.reverse()),
+        }
+    }
+
+    Ok(a_len.cmp(&b_len))
+}
+
+/// Find the index at which `element` is to be inserted into the given sorted
+/// elements, returning [`ErrorKind::Ordering`] on duplicates.
+fn insertion_index<'a, T>(elements: impl Iterator<Item = &'a T>, element: &T) -> Result<usize>
+where
+    T: 'a + Encodable,
+{
+    let mut index = 0;
+
+    for elem in elements {
+        match der_cmp(elem, element)? {
+            Ordering::Less => index += 1,
+            Ordering::Equal => return Err(ErrorKind::Ordering.into()),
+            Ordering::Greater => break,
+        }
+    }
+
+    Ok(index)
+}
+
+/// Map ordering errors encountered when decoding a `SET OF` to
+/// non-canonical encoding errors.
+fn non_canonical_error(err: Error, tag: Tag) -> Error {
+    if err.kind() == ErrorKind::Ordering {
+        tag.non_canonical_error()
+    } else {
+        err
+    }
+}
+
+/// Window of the DER encoding of a value, starting at a given offset.
+#[cfg(not(feature = "alloc"))]
+struct EncodingWindow {
+    /// Offset of the window within the encoding.
+    offset: usize,
+
+    /// Number of bytes of the encoding seen so far.
+    position: usize,
+
+    /// Bytes within the window.
+    bytes: [u8; Self::SIZE],
+
+    /// Number of bytes within the window.
+    len: usize,
+
+    /// Maximum number of bytes within the window.
+    max_len: usize,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl EncodingWindow {
+    /// Maximum size of a window.
+    const SIZE: usize = 256;
+
+    /// Encode the given value, capturing the window at the given offset
+    /// which ends at or before `end`.
+    fn new(value: &dyn Encodable, offset: usize, end: usize) -> Result<Self> {
+        let mut window = Self {
+            offset,
+            position: 0,
+            bytes: [0; Self::SIZE],
+            len: 0,
+            max_len: (end - offset).min(Self::SIZE),
+        };
+
+        // The encoder is stopped once the window is full
+        match value.encode(&mut Encoder::new_sink(&mut window)) {
+            Err(_) if window.len == window.max_len => Ok(window),
+            result => result.map(|_| window),
+        }
+    }
+
+    /// Borrow the bytes within this window.
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl Sink for EncodingWindow {
+    fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        let start = self.offset.saturating_sub(self.position).min(bytes.len());
+        let n = (bytes.len() - start).min(self.max_len - self.len);
+        self.bytes[self.len..(self.len + n)].copy_from_slice(&bytes[start..(start + n)]);
+        self.len += n;
+        self.position += bytes.len();
+
+        if self.len == self.max_len {
+            Err(ErrorKind::Failed.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// [`Sink`] which compares the DER encoding of a value against a window of
+/// another encoding as it is written.
+#[cfg(not(feature = "alloc"))]
+struct WindowCmp<'w> {
+    /// Offset of the window within the encoding.
+    offset: usize,
+
+    /// Number of bytes of the encoding seen so far.
+    position: usize,
+
+    /// Bytes of the window which have yet to be compared.
+    window: &'w [u8],
+
+    /// Result of the comparison, once it is known.
+    ordering: Option<Ordering>,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl<'w> WindowCmp<'w> {
+    /// Compare the encoding of the given value at the given offset with
+    /// `window`, which must not extend past the end of the encoding.
+    fn compare(value: &dyn Encodable, offset: usize, window: &'w [u8]) -> Result<Ordering> {
+        let mut cmp = Self {
+            offset,
+            position: 0,
+            window,
+            ordering: None,
+        };
+
+        // The encoder is stopped once the result of the comparison is known
+        match value.encode(&mut Encoder::new_sink(&mut cmp)) {
+            Err(err) if cmp.ordering.is_none() => Err(err),
+            _ => Ok(cmp.ordering.unwrap_or(Ordering::Equal)),
+        }
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl Sink for WindowCmp<'_> {
+    fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        let start = self.offset.saturating_sub(self.position).min(bytes.len());
+        let n = (bytes.len() - start).min(self.window.len());
+        self.position += bytes.len();
+
+        let ordering = bytes[start..(start + n)].cmp(&self.window[..n]);
+        self.window = &self.window[n..];
+
+        if ordering != Ordering::Equal || self.window.is_empty() {
+            self.ordering = Some(ordering);
+            Err(ErrorKind::Failed.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{der_cmp, SetOf};
+    use crate::{asn1::OctetString, Decodable, Encodable, ErrorKind, Tag};
+    use core::{cmp::Ordering, convert::TryFrom};
+    use hex_literal::hex;
+
+    #[cfg(feature = "alloc")]
+    use {super::SetOfVec, alloc::vec, alloc::vec::Vec};
+
+    #[test]
+    fn der_cmp_orders_by_encoding() {
+        // INTEGER 1 (020101) sorts before INTEGER -1 (0201ff)
+        assert_eq!(der_cmp(&1i8, &-1i8).unwrap(), Ordering::Less);
+        assert_eq!(der_cmp(&-1i8, &1i8).unwrap(), Ordering::Greater);
+        assert_eq!(der_cmp(&5i8, &5i8).unwrap(), Ordering::Equal);
+    }
+
+    #[test]
+    fn der_cmp_spans_windows() {
+        let a = [0xAAu8; 600];
+        let mut b = a;
+        b[500] = 0xAB;
+
+        let a = OctetString::new(&a).unwrap();
+        let b = OctetString::new(&b).unwrap();
+        assert_eq!(der_cmp(&a, &b).unwrap(), Ordering::Less);
+        assert_eq!(der_cmp(&b, &a).unwrap(), Ordering::Greater);
+        assert_eq!(der_cmp(&a, &a).unwrap(), Ordering::Equal);
+    }
+
+    #[test]
+    fn set_of_sorts_by_encoding() {
+        // SET OF INTEGER { 1, 2, -1 }
+        let bytes = hex!("31090201010201020201ff");
+        let set = SetOf::<i8, 3>::try_from([-1, 2, 1]).unwrap();
+        assert!(set.iter().copied().eq([1, 2, -1].iter().copied()));
+        assert_eq!(set.len(), 3);
+
+        let mut buf = [0u8; 11];
+        assert_eq!(set.encode_to_slice(&mut buf).unwrap(), bytes);
+        assert_eq!(SetOf::<i8, 3>::from_der(&bytes).unwrap(), set);
+    }
+
+    #[test]
+    fn set_of_add() {
+        let mut set = SetOf::<i8, 3>::new();
+        set.add(1).unwrap();
+        set.add(-1).unwrap();
+        assert_eq!(set.add(2).err().unwrap().kind(), ErrorKind::Ordering);
+        assert_eq!(set.add(-1).err().unwrap().kind(), ErrorKind::Ordering);
+        assert_eq!(set.insert(1).err().unwrap().kind(), ErrorKind::Ordering);
+
+        set.insert(2).unwrap();
+        assert!(set.iter().copied().eq([1, 2, -1].iter().copied()));
+        assert_eq!(set.insert(3).err().unwrap().kind(), ErrorKind::Overlength);
+    }
+
+    #[test]
+    fn set_of_rejects_unordered() {
+        // SET OF INTEGER { -1, 1 }
+        let err = SetOf::<i8, 2>::from_der(&hex!("31060201ff020101"))
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), Tag::Set.non_canonical_error().kind());
+
+        // SET OF INTEGER { 1, 1 }
+        assert!(SetOf::<i8, 2>::from_der(&hex!("3106020101020101")).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn set_of_vec() {
+        // SET OF INTEGER { 1, 2, -1 }
+        let bytes = hex!("31090201010201020201ff");
+        let set = SetOfVec::try_from(vec![2i8, -1, 1]).unwrap();
+        assert_eq!(set.as_slice(), &[1, 2, -1]);
+        assert_eq!(set.to_vec().unwrap(), bytes);
+        assert_eq!(SetOfVec::<i8>::from_der(&bytes).unwrap(), set);
+        assert_eq!(Vec::from(set), [1, 2, -1]);
+
+        let err = SetOfVec::try_from(vec![1i8, 1]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Ordering);
+
+        // SET OF INTEGER { -1, 1 }
+        let err = SetOfVec::<i8>::from_der(&hex!("31060201ff020101"))
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), Tag::Set.non_canonical_error().kind());
+    }
+}
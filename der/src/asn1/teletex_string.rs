@@ -0,0 +1,189 @@
+//! ASN.1 `TeletexString` support.
+
+use crate::{
+    asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result, Tag,
+    Tagged,
+};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Write},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// ASN.1 `TeletexString` type, also known as `T61String`.
+///
+/// Found in the subject and issuer names of legacy certificates. The T.61
+/// character set it nominally uses is a mix of 7-bit characters and
+/// combining diacritics which is rarely implemented. In practice issuers
+/// wrote ISO 8859-1 (Latin-1), which is how other implementations (e.g.
+/// OpenSSL) interpret it, so [`TeletexString::chars`] decodes each byte as
+/// the Unicode code point with the same value.
+///
+/// Any byte sequence is accepted. This type is intended for decoding
+/// existing documents: new ones should use
+/// [`Utf8String`][`crate::asn1::Utf8String`] instead.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct TeletexString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> TeletexString<'a> {
+    /// Create a new `TeletexString`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        ByteSlice::new(input.as_ref())
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Iterate over the characters of this string, interpreted as
+    /// ISO 8859-1.
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        self.as_bytes().iter().map(|&byte| char::from(byte))
+    }
+
+    /// Borrow the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for TeletexString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for TeletexString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, length)?.as_bytes())
+    }
+}
+
+impl<'a> EncodeValue for TeletexString<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> From<&TeletexString<'a>> for TeletexString<'a> {
+    fn from(value: &TeletexString<'a>) -> TeletexString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for TeletexString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<TeletexString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<TeletexString<'a>> for Any<'a> {
+    fn from(teletex_string: TeletexString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::TeletexString, teletex_string.inner)
+    }
+}
+
+impl<'a> From<TeletexString<'a>> for &'a [u8] {
+    fn from(teletex_string: TeletexString<'a>) -> &'a [u8] {
+        teletex_string.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> From<TeletexString<'a>> for String {
+    fn from(teletex_string: TeletexString<'a>) -> String {
+        teletex_string.chars().collect()
+    }
+}
+
+impl<'a> Tagged for TeletexString<'a> {
+    const TAG: Tag = Tag::TeletexString;
+}
+
+impl<'a> fmt::Display for TeletexString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|c| f.write_char(c))
+    }
+}
+
+impl<'a> fmt::Debug for TeletexString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TeletexString(\"")?;
+        self.chars()
+            .flat_map(|c| c.escape_debug())
+            .try_for_each(|c| f.write_char(c))?;
+        f.write_str("\")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TeletexString;
+    use crate::{asn1::Any, Decodable, Encodable, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() {
+        // T61String "Test CA" as generated by `openssl asn1parse -genstr 'T61STRING:Test CA'`
+        let example_bytes = hex!("14 07 54 65 73 74 20 43 41");
+        let teletex_string = TeletexString::from_der(&example_bytes).unwrap();
+        assert!(teletex_string.chars().eq("Test CA".chars()));
+        assert_eq!(teletex_string.len(), 7u8.into());
+
+        let mut buf = [0u8; 9];
+        assert_eq!(
+            teletex_string.encode_to_slice(&mut buf).unwrap(),
+            example_bytes
+        );
+    }
+
+    #[test]
+    fn latin1() {
+        let teletex_string = TeletexString::new(&hex!("4dfc6e6368656e")).unwrap();
+        assert!(teletex_string.chars().eq("München".chars()));
+    }
+
+    #[test]
+    fn any() {
+        let any = Any::from_der(&hex!("14 02 e9 e8")).unwrap();
+        assert_eq!(any.tag(), Tag::TeletexString);
+        assert_eq!(any.teletex_string().unwrap().as_bytes(), [0xe9, 0xe8]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fmt() {
+        use alloc::{format, string::String};
+
+        let teletex_string = TeletexString::new(&hex!("22 e9 22")).unwrap();
+        assert_eq!(format!("{}", teletex_string), "\"é\"");
+        assert_eq!(
+            format!("{:?}", teletex_string),
+            "TeletexString(\"\\\"é\\\"\")"
+        );
+        assert_eq!(String::from(teletex_string), "\"é\"");
+    }
+}
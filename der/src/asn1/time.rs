@@ -0,0 +1,209 @@
+//! ASN.1 `TIME` support.
+
+use crate::{
+    asn1::Any, ByteSlice, DateTime, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length,
+    Result, StrSlice, Tag, Tagged,
+};
+use core::{convert::TryFrom, fmt};
+
+/// ASN.1 `TIME` type.
+///
+/// Introduced in the 2008 revision of X.680, `TIME` values are ISO 8601
+/// strings which may represent dates, times of day, date-times, intervals,
+/// durations or recurrences, with a variety of precisions and time zones.
+/// The subtypes used in practice are [`Date`][`crate::asn1::Date`],
+/// [`TimeOfDay`][`crate::asn1::TimeOfDay`] and
+/// [`DateAndTime`][`crate::asn1::DateAndTime`].
+///
+/// Values are encoded as the characters of their ISO 8601 representation
+/// (X.690 Section 8.26). This type only validates that they are drawn from
+/// the character repertoire X.680 allows for `TIME` values, i.e. digits and
+/// any of `+,-./:CDHMPRSTWYZ`. [`Time::to_date_time`] converts UTC
+/// date-times in the `YYYY-MM-DDTHH:MM:SSZ` format.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Time<'a> {
+    /// Inner value
+    inner: StrSlice<'a>,
+}
+
+impl<'a> Time<'a> {
+    /// Create a new ASN.1 `TIME`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        if input.is_empty() || !input.iter().all(|&byte| is_time_char(byte)) {
+            return Err(Self::TAG.value_error());
+        }
+
+        StrSlice::from_bytes(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Borrow the string as a `str`.
+    pub fn as_str(&self) -> &'a str {
+        self.inner.as_str()
+    }
+
+    /// Borrow the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Convert this `TIME` into a [`DateTime`].
+    ///
+    /// Only UTC date-times with a precision of one second, i.e. of the form
+    /// `YYYY-MM-DDTHH:MM:SSZ`, are supported.
+    pub fn to_date_time(&self) -> Result<DateTime> {
+        self.as_str().parse().map_err(|_| Self::TAG.value_error())
+    }
+}
+
+impl AsRef<str> for Time<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for Time<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for Time<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, length)?.as_bytes())
+    }
+}
+
+impl<'a> EncodeValue for Time<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> From<&Time<'a>> for Time<'a> {
+    fn from(value: &Time<'a>) -> Time<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for Time<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Time<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<Time<'a>> for Any<'a> {
+    fn from(time: Time<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::Time, time.inner.into())
+    }
+}
+
+impl<'a> TryFrom<Time<'a>> for DateTime {
+    type Error = Error;
+
+    fn try_from(time: Time<'a>) -> Result<DateTime> {
+        time.to_date_time()
+    }
+}
+
+impl<'a> Tagged for Time<'a> {
+    const TAG: Tag = Tag::Time;
+}
+
+impl<'a> fmt::Display for Time<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> fmt::Debug for Time<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Time({:?})", self.as_str())
+    }
+}
+
+/// Is the given byte in the character repertoire of `TIME` values?
+fn is_time_char(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'0'..=b'9'
+            | b'+'
+            | b','
+            | b'-'
+            | b'.'
+            | b'/'
+            | b':'
+            | b'C'
+            | b'D'
+            | b'H'
+            | b'M'
+            | b'P'
+            | b'R'
+            | b'S'
+            | b'T'
+            | b'W'
+            | b'Y'
+            | b'Z'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Time;
+    use crate::{DateTime, Decodable, Encodable, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() {
+        // TIME "2012-12-21T11:12:13Z"
+        let example_bytes = hex!("0e 14 323031322d31322d32315431313a31323a31335a");
+        let time = Time::from_der(&example_bytes).unwrap();
+        assert_eq!(time.as_str(), "2012-12-21T11:12:13Z");
+        assert_eq!(
+            time.to_date_time().unwrap(),
+            DateTime::new(2012, 12, 21, 11, 12, 13).unwrap()
+        );
+
+        let mut buf = [0u8; 22];
+        assert_eq!(time.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn other_forms() {
+        for &value in &["P1Y2M10DT2H30M", "2012-W51-5", "R5/2012-12-21/P1D", "23:59"] {
+            let time = Time::new(value).unwrap();
+            assert_eq!(time.as_str(), value);
+            assert!(time.to_date_time().is_err());
+        }
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for &value in &["", "2012-12-21 11:12:13", "2012-12-21t11:12:13z"] {
+            let err = Time::new(value).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Time });
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! ASN.1 `TIME-OF-DAY` support.
+
+use crate::{
+    asn1::Any, datetime, ByteSlice, DateTime, DecodeValue, Decoder, EncodeValue, Encoder, Error,
+    Length, Result, Tag, Tagged,
+};
+use core::convert::TryFrom;
+
+/// ASN.1 `TIME-OF-DAY` type.
+///
+/// Introduced in the 2008 revision of X.680 as a subtype of `TIME`
+/// representing a local time of day with a precision of one second:
+///
+/// ```text
+/// TIME-OF-DAY ::= [UNIVERSAL 32] IMPLICIT TIME
+///     (SETTINGS "Basic=Time Time=HMS Local-or-UTC=L")
+/// ```
+///
+/// Encoded as `HHMMSS` (X.690 Section 8.26). Its universal tag number is
+/// greater than 30, so it uses a two octet tag (see [`Tag::TimeOfDay`]).
+///
+/// Values carry no time zone: it's up to the protocol to define which one
+/// applies.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct TimeOfDay {
+    /// Hour (0-23)
+    hour: u16,
+
+    /// Minutes (0-59)
+    minutes: u16,
+
+    /// Seconds (0-59)
+    seconds: u16,
+}
+
+impl TimeOfDay {
+    /// Length of an ASN.1 DER-encoded [`TimeOfDay`].
+    pub const LENGTH: Length = Length::new(6);
+
+    /// Create a new [`TimeOfDay`] from its components.
+    pub fn new(hour: u16, minutes: u16, seconds: u16) -> Result<Self> {
+        if hour > 23 || minutes > 59 || seconds > 59 {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(Self {
+            hour,
+            minutes,
+            seconds,
+        })
+    }
+
+    /// Get the time of day of a [`DateTime`].
+    pub fn from_date_time(datetime: DateTime) -> Self {
+        Self {
+            hour: datetime.hour(),
+            minutes: datetime.minutes(),
+            seconds: datetime.seconds(),
+        }
+    }
+
+    /// Get the hour (0-23).
+    pub fn hour(&self) -> u16 {
+        self.hour
+    }
+
+    /// Get the minutes (0-59).
+    pub fn minutes(&self) -> u16 {
+        self.minutes
+    }
+
+    /// Get the seconds (0-59).
+    pub fn seconds(&self) -> u16 {
+        self.seconds
+    }
+}
+
+impl DecodeValue<'_> for TimeOfDay {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        match *ByteSlice::decode_value(decoder, length)?.as_bytes() {
+            [hour1, hour2, min1, min2, sec1, sec2] => {
+                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
+                let minutes = datetime::decode_decimal(Self::TAG, min1, min2)?;
+                let seconds = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
+                Self::new(hour, minutes, seconds)
+            }
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+}
+
+impl EncodeValue for TimeOfDay {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Self::LENGTH)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        datetime::encode_decimal(encoder, Self::TAG, self.hour)?;
+        datetime::encode_decimal(encoder, Self::TAG, self.minutes)?;
+        datetime::encode_decimal(encoder, Self::TAG, self.seconds)
+    }
+}
+
+impl From<&TimeOfDay> for TimeOfDay {
+    fn from(value: &TimeOfDay) -> TimeOfDay {
+        *value
+    }
+}
+
+impl From<DateTime> for TimeOfDay {
+    fn from(datetime: DateTime) -> Self {
+        Self::from_date_time(datetime)
+    }
+}
+
+impl TryFrom<Any<'_>> for TimeOfDay {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<TimeOfDay> {
+        any.decode_into()
+    }
+}
+
+impl Tagged for TimeOfDay {
+    const TAG: Tag = Tag::TimeOfDay;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimeOfDay;
+    use crate::{DateTime, Decodable, Encodable};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        // TIME-OF-DAY "23:59:07"
+        let example_bytes = hex!("1f20 06 323335393037");
+        let time_of_day = TimeOfDay::from_der(&example_bytes).unwrap();
+        assert_eq!(
+            (
+                time_of_day.hour(),
+                time_of_day.minutes(),
+                time_of_day.seconds()
+            ),
+            (23, 59, 7)
+        );
+
+        let mut buf = [0u8; 9];
+        assert_eq!(
+            time_of_day.encode_to_slice(&mut buf).unwrap(),
+            example_bytes
+        );
+    }
+
+    #[test]
+    fn from_date_time() {
+        let datetime = DateTime::new(2012, 12, 21, 11, 12, 13).unwrap();
+        assert_eq!(
+            TimeOfDay::from(datetime),
+            TimeOfDay::new(11, 12, 13).unwrap()
+        );
+    }
+
+    #[test]
+    fn reject_invalid() {
+        assert!(TimeOfDay::new(24, 0, 0).is_err());
+        assert!(TimeOfDay::from_der(&hex!("1f20 06 323336303030")).is_err());
+        assert!(TimeOfDay::from_der(&hex!("1f20 08 32333a35393a3037")).is_err());
+    }
+}
@@ -0,0 +1,265 @@
+//! ASN.1 `UniversalString` support.
+
+use crate::{
+    asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, Length,
+    Result, Tag, Tagged,
+};
+use core::{
+    char,
+    convert::TryFrom,
+    fmt::{self, Write},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// ASN.1 `UniversalString` type.
+///
+/// Supports the full Universal Character Set, encoded as big endian 32-bit
+/// code points (UCS-4). Rarely used, but permitted as an alternative of
+/// X.509's `DirectoryString`.
+///
+/// Only the length of the input is validated on construction, so legacy
+/// documents containing values which aren't Unicode scalar values (e.g.
+/// surrogates) can still be decoded. Conversions to UTF-8 come in two
+/// flavors:
+///
+/// - strict: [`UniversalString::chars`] and `TryFrom<UniversalString> for
+///   String` fail on invalid code points.
+/// - lossy: [`UniversalString::chars_lossy`], [`UniversalString::to_string_lossy`]
+///   and the [`Display`][`fmt::Display`] impl replace them with
+///   [`char::REPLACEMENT_CHARACTER`].
+///
+/// For UTF-8, use [`Utf8String`][`crate::asn1::Utf8String`].
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct UniversalString<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> UniversalString<'a> {
+    /// Create a new `UniversalString` from its UCS-4 encoding.
+    ///
+    /// Returns an error if the length of the input isn't a multiple of 4.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        if input.len() % 4 != 0 {
+            return Err(ErrorKind::Length {
+                tag: Self::TAG,
+                length: Length::try_from(input.len()).ok(),
+            }
+            .into());
+        }
+
+        ByteSlice::new(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.length_error())
+    }
+
+    /// Iterate over the characters of this string, returning an error for
+    /// each code point which isn't a Unicode scalar value.
+    pub fn chars(&self) -> impl Iterator<Item = Result<char>> + 'a {
+        code_points(self.as_bytes())
+            .map(|code_point| char::from_u32(code_point).ok_or_else(|| Self::TAG.value_error()))
+    }
+
+    /// Iterate over the characters of this string, replacing code points
+    /// which aren't Unicode scalar values with
+    /// [`char::REPLACEMENT_CHARACTER`].
+    pub fn chars_lossy(&self) -> impl Iterator<Item = char> + 'a {
+        code_points(self.as_bytes())
+            .map(|code_point| char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Convert this string to UTF-8, replacing code points which aren't
+    /// Unicode scalar values with [`char::REPLACEMENT_CHARACTER`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_string_lossy(&self) -> String {
+        self.chars_lossy().collect()
+    }
+
+    /// Borrow the UCS-4 encoding of this string.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for UniversalString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for UniversalString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, length)?.as_bytes())
+    }
+}
+
+impl<'a> EncodeValue for UniversalString<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> From<&UniversalString<'a>> for UniversalString<'a> {
+    fn from(value: &UniversalString<'a>) -> UniversalString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for UniversalString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<UniversalString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<UniversalString<'a>> for Any<'a> {
+    fn from(universal_string: UniversalString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::UniversalString, universal_string.inner)
+    }
+}
+
+impl<'a> From<UniversalString<'a>> for &'a [u8] {
+    fn from(universal_string: UniversalString<'a>) -> &'a [u8] {
+        universal_string.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> TryFrom<UniversalString<'a>> for String {
+    type Error = Error;
+
+    fn try_from(universal_string: UniversalString<'a>) -> Result<String> {
+        universal_string.chars().collect()
+    }
+}
+
+impl<'a> Tagged for UniversalString<'a> {
+    const TAG: Tag = Tag::UniversalString;
+}
+
+impl<'a> fmt::Display for UniversalString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars_lossy().try_for_each(|c| f.write_char(c))
+    }
+}
+
+impl<'a> fmt::Debug for UniversalString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("UniversalString(\"")?;
+        self.chars_lossy()
+            .flat_map(|c| c.escape_debug())
+            .try_for_each(|c| f.write_char(c))?;
+        f.write_str("\")")
+    }
+}
+
+/// Iterate over the big endian 32-bit code points of the given bytes.
+fn code_points(bytes: &[u8]) -> impl Iterator<Item = u32> + '_ {
+    bytes
+        .chunks_exact(4)
+        .map(|quad| u32::from_be_bytes([quad[0], quad[1], quad[2], quad[3]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UniversalString;
+    use crate::{Decodable, Encodable, ErrorKind, Length, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() {
+        // UniversalString "Hi" as generated by `openssl asn1parse -genstr 'UNIVERSALSTRING:Hi'`
+        let example_bytes = hex!("1c 08 00000048 00000069");
+        let universal_string = UniversalString::from_der(&example_bytes).unwrap();
+        assert!(universal_string.chars_lossy().eq("Hi".chars()));
+        assert_eq!(universal_string.len(), 8u8.into());
+
+        let mut buf = [0u8; 10];
+        assert_eq!(
+            universal_string.encode_to_slice(&mut buf).unwrap(),
+            example_bytes
+        );
+    }
+
+    #[test]
+    fn astral_plane() {
+        let universal_string = UniversalString::new(&hex!("000020ac 0001d11e")).unwrap();
+        assert!(universal_string
+            .chars()
+            .map(Result::unwrap)
+            .eq("€𝄞".chars()));
+    }
+
+    #[test]
+    fn reject_invalid_length() {
+        let err = UniversalString::new(&hex!("000000")).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::UniversalString,
+                length: Some(Length::new(3))
+            }
+        );
+
+        assert!(UniversalString::from_der(&hex!("1c 06 00000048 0000")).is_err());
+    }
+
+    #[test]
+    fn invalid_code_points() {
+        // "A", a lone surrogate, and a value beyond U+10FFFF
+        let universal_string = UniversalString::new(&hex!("00000041 0000d800 00110000")).unwrap();
+
+        let mut chars = universal_string.chars();
+        assert_eq!(chars.next().unwrap().unwrap(), 'A');
+        assert_eq!(
+            chars.next().unwrap().err().unwrap().kind(),
+            ErrorKind::Value {
+                tag: Tag::UniversalString
+            }
+        );
+
+        assert!(universal_string
+            .chars_lossy()
+            .eq("A\u{FFFD}\u{FFFD}".chars()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string() {
+        use alloc::{format, string::String};
+        use core::convert::TryFrom;
+
+        let valid = UniversalString::new(&hex!("0000004b 000000e4 00000073 00000065")).unwrap();
+        assert_eq!(String::try_from(valid).unwrap(), "Käse");
+        assert_eq!(format!("{:?}", valid), "UniversalString(\"Käse\")");
+
+        let invalid = UniversalString::new(&hex!("0000004b 0000dc00")).unwrap();
+        assert!(String::try_from(invalid).is_err());
+        assert_eq!(invalid.to_string_lossy(), "K\u{FFFD}");
+        assert_eq!(format!("{}", invalid), "K\u{FFFD}");
+    }
+}
@@ -13,6 +13,12 @@ use core::{
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "chrono")]
+use chrono::Utc;
+
+#[cfg(feature = "time")]
+use time::{OffsetDateTime, PrimitiveDateTime};
+
 /// Maximum year that can be represented as a `UTCTime`.
 pub const MAX_YEAR: u16 = 2049;
 
@@ -168,6 +174,94 @@ impl From<UtcTime> for SystemTime {
     }
 }
 
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<chrono::DateTime<Utc>> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: chrono::DateTime<Utc>) -> Result<UtcTime> {
+        UtcTime::from_date_time(DateTime::try_from(time)?)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<&chrono::DateTime<Utc>> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: &chrono::DateTime<Utc>) -> Result<UtcTime> {
+        Self::try_from(*time)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl From<UtcTime> for chrono::DateTime<Utc> {
+    fn from(time: UtcTime) -> chrono::DateTime<Utc> {
+        time.to_date_time().into()
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<OffsetDateTime> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<UtcTime> {
+        UtcTime::from_date_time(DateTime::try_from(time)?)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<&OffsetDateTime> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: &OffsetDateTime) -> Result<UtcTime> {
+        Self::try_from(*time)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<UtcTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: UtcTime) -> Result<OffsetDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<PrimitiveDateTime> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: PrimitiveDateTime) -> Result<UtcTime> {
+        UtcTime::from_date_time(DateTime::try_from(time)?)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<&PrimitiveDateTime> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: &PrimitiveDateTime) -> Result<UtcTime> {
+        Self::try_from(*time)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<UtcTime> for PrimitiveDateTime {
+    type Error = Error;
+
+    fn try_from(time: UtcTime) -> Result<PrimitiveDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
 impl TryFrom<Any<'_>> for UtcTime {
     type Error = Error;
 
@@ -197,4 +291,42 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_crate() {
+        use chrono::{TimeZone, Utc};
+        use core::convert::TryFrom;
+
+        let utc_time = UtcTime::from_der(&hex!("17 0d 393130353036323334353430 5a")).unwrap();
+
+        let chrono_date_time = chrono::DateTime::<Utc>::from(utc_time);
+        assert_eq!(chrono_date_time.timestamp(), 673573540);
+        assert_eq!(UtcTime::try_from(chrono_date_time).unwrap(), utc_time);
+
+        // Out of range for `UTCTime`
+        let y2050 = Utc.timestamp_opt(2524608000, 0).unwrap();
+        assert!(UtcTime::try_from(y2050).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_crate() {
+        use core::convert::TryFrom;
+        use time::{OffsetDateTime, PrimitiveDateTime};
+
+        let utc_time = UtcTime::from_der(&hex!("17 0d 393130353036323334353430 5a")).unwrap();
+
+        let offset_date_time = OffsetDateTime::try_from(utc_time).unwrap();
+        assert_eq!(offset_date_time.unix_timestamp(), 673573540);
+        assert_eq!(UtcTime::try_from(offset_date_time).unwrap(), utc_time);
+
+        let primitive_date_time = PrimitiveDateTime::try_from(utc_time).unwrap();
+        assert_eq!(primitive_date_time.assume_utc(), offset_date_time);
+        assert_eq!(UtcTime::try_from(primitive_date_time).unwrap(), utc_time);
+
+        // Out of range for `UTCTime`
+        let y2050 = OffsetDateTime::from_unix_timestamp(2524608000).unwrap();
+        assert!(UtcTime::try_from(y2050).is_err());
+    }
 }
@@ -91,6 +91,12 @@ impl<'a> From<&Utf8String<'a>> for Utf8String<'a> {
     }
 }
 
+impl<'a> From<StrSlice<'a>> for Utf8String<'a> {
+    fn from(inner: StrSlice<'a>) -> Utf8String<'a> {
+        Self { inner }
+    }
+}
+
 impl<'a> TryFrom<Any<'a>> for Utf8String<'a> {
     type Error = Error;
 
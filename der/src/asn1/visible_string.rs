@@ -0,0 +1,188 @@
+//! ASN.1 `VisibleString` support.
+
+use crate::{
+    asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result,
+    StrSlice, Tag, Tagged,
+};
+use core::{convert::TryFrom, fmt, str};
+
+/// ASN.1 `VisibleString` type, also known as `ISO646String`.
+///
+/// Supports the printable characters of the ASCII character set, i.e.
+/// `0x20..=0x7E` (from "` `" to `~`), excluding control characters. Used
+/// e.g. by RFC 3161 timestamping.
+///
+/// For UTF-8, use [`Utf8String`][`crate::asn1::Utf8String`] instead. For the
+/// full ASCII character set, use [`Ia5String`][`crate::asn1::Ia5String`].
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct VisibleString<'a> {
+    /// Inner value
+    inner: StrSlice<'a>,
+}
+
+impl<'a> VisibleString<'a> {
+    /// Create a new ASN.1 `VisibleString`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+        Self::validate(input)?;
+
+        StrSlice::from_bytes(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.value_error())
+    }
+
+    /// Validate all characters are within VisibleString's allowed set.
+    fn validate(input: &[u8]) -> Result<()> {
+        if input.iter().any(|&c| !matches!(c, b' '..=b'~')) {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(())
+    }
+
+    /// Borrow the string as a `str`.
+    pub fn as_str(&self) -> &'a str {
+        self.inner.as_str()
+    }
+
+    /// Borrow the string as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of the inner byte slice.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner string empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl AsRef<str> for VisibleString<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for VisibleString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for VisibleString<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::new(ByteSlice::decode_value(decoder, length)?.as_bytes())
+    }
+}
+
+impl<'a> EncodeValue for VisibleString<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> From<&VisibleString<'a>> for VisibleString<'a> {
+    fn from(value: &VisibleString<'a>) -> VisibleString<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<StrSlice<'a>> for VisibleString<'a> {
+    type Error = Error;
+
+    fn try_from(inner: StrSlice<'a>) -> Result<VisibleString<'a>> {
+        Self::validate(inner.as_bytes())?;
+        Ok(Self { inner })
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for VisibleString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<VisibleString<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> From<VisibleString<'a>> for Any<'a> {
+    fn from(visible_string: VisibleString<'a>) -> Any<'a> {
+        Any::from_tag_and_value(Tag::VisibleString, visible_string.inner.into())
+    }
+}
+
+impl<'a> From<VisibleString<'a>> for &'a [u8] {
+    fn from(visible_string: VisibleString<'a>) -> &'a [u8] {
+        visible_string.as_bytes()
+    }
+}
+
+impl<'a> Tagged for VisibleString<'a> {
+    const TAG: Tag = Tag::VisibleString;
+}
+
+impl<'a> fmt::Display for VisibleString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> fmt::Debug for VisibleString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "VisibleString({:?})", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VisibleString;
+    use crate::{asn1::Any, Decodable, Encodable, ErrorKind, StrSlice, Tag};
+    use core::convert::TryFrom;
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() {
+        // VisibleString "a@b.c ~" as generated by `openssl asn1parse -genstr 'VISIBLE:a@b.c ~'`
+        let example_bytes = hex!("1a 07 61 40 62 2e 63 20 7e");
+        let visible_string = VisibleString::from_der(&example_bytes).unwrap();
+        assert_eq!(visible_string.as_str(), "a@b.c ~");
+
+        let mut buf = [0u8; 9];
+        assert_eq!(
+            visible_string.encode_to_slice(&mut buf).unwrap(),
+            example_bytes
+        );
+    }
+
+    #[test]
+    fn reject_invalid_chars() {
+        for s in &["tab\t", "new\nline", "del\x7f", "caf\u{e9}"] {
+            let err = VisibleString::new(s).err().unwrap();
+            assert_eq!(
+                err.kind(),
+                ErrorKind::Value {
+                    tag: Tag::VisibleString
+                }
+            );
+        }
+
+        assert!(VisibleString::try_from(StrSlice::new("user@example.com").unwrap()).is_ok());
+    }
+
+    #[test]
+    fn any() {
+        let any = Any::from_der(&hex!("1a 02 4f 4b")).unwrap();
+        assert_eq!(any.tag(), Tag::VisibleString);
+        assert_eq!(any.visible_string().unwrap().as_str(), "OK");
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     str_slice::StrSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, Length,
     Result,
 };
-use core::convert::TryFrom;
+use core::{convert::TryFrom, slice::SliceIndex};
 
 /// Byte slice newtype which respects the `Length::max()` limit.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -41,6 +41,24 @@ impl<'a> ByteSlice<'a> {
     pub fn is_empty(self) -> bool {
         self.len() == Length::ZERO
     }
+
+    /// Get a subslice of this [`ByteSlice`], or `None` if `index` is out of
+    /// bounds.
+    pub fn get<I>(self, index: I) -> Option<Self>
+    where
+        I: SliceIndex<[u8], Output = [u8]>,
+    {
+        self.inner
+            .get(index)
+            .and_then(|slice| Self::new(slice).ok())
+    }
+
+    /// Split this [`ByteSlice`] in two at the given offset, or return `None`
+    /// if `mid` is out of bounds.
+    pub fn split_at(self, mid: Length) -> Option<(Self, Self)> {
+        let mid = usize::try_from(mid).ok()?;
+        Some((self.get(..mid)?, self.get(mid..)?))
+    }
 }
 
 impl AsRef<[u8]> for ByteSlice<'_> {
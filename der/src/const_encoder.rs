@@ -0,0 +1,226 @@
+//! `const fn` DER encoder for static structures.
+
+use crate::Tag;
+
+#[cfg(feature = "oid")]
+use crate::asn1::ObjectIdentifier;
+
+/// Constant panicking assertion.
+// TODO(tarcieri): use const panic when stable.
+// See: https://github.com/rust-lang/rust/issues/51999
+macro_rules! const_assert {
+    ($bool:expr, $msg:expr) => {
+        [$msg][!$bool as usize]
+    };
+}
+
+/// Largest value length supported by [`ConstEncoder`].
+const MAX_VALUE_LEN: usize = 0xFF_FFFF;
+
+/// DER encoder which can be evaluated at compile time.
+///
+/// This is intended for emitting fixed messages (e.g. an embedded public key
+/// or `AlgorithmIdentifier`) into a `static`/`const` byte array rather than
+/// encoding them at runtime. The size of the output `N` must be known in
+/// advance and match the encoded length exactly.
+///
+/// Nested structures are built bottom-up, encoding the inner value into its
+/// own constant and then wrapping it in a header:
+///
+/// ```
+/// # #[cfg(feature = "oid")]
+/// # {
+/// use der::{asn1::ObjectIdentifier, ConstEncoder};
+///
+/// const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+///
+/// const PARAMS: [u8; 13] = ConstEncoder::new().oid(&RSA_ENCRYPTION).null().finish();
+/// static ALGORITHM_ID: [u8; 15] = ConstEncoder::new().sequence(&PARAMS).finish();
+///
+/// assert_eq!(
+///     ALGORITHM_ID,
+///     [0x30, 0x0D, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01, 0x05, 0x00]
+/// );
+/// # }
+/// ```
+///
+/// Invalid usage, such as writing past the end of the buffer or finishing
+/// before it has been completely filled, results in a compile-time error
+/// when evaluated in a `const` context and a panic otherwise.
+#[derive(Clone, Debug)]
+pub struct ConstEncoder<const N: usize> {
+    /// Output buffer.
+    bytes: [u8; N],
+
+    /// Current position within the output buffer.
+    position: usize,
+}
+
+impl<const N: usize> ConstEncoder<N> {
+    /// Create a new encoder with an empty output buffer.
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0u8; N],
+            position: 0,
+        }
+    }
+
+    /// Write raw (i.e. already DER-encoded) bytes.
+    pub const fn raw(mut self, bytes: &[u8]) -> Self {
+        const_assert!(
+            self.position + bytes.len() <= N,
+            "DER message exceeds output buffer size"
+        );
+
+        let mut i = 0;
+
+        while i < bytes.len() {
+            self.bytes[self.position] = bytes[i];
+            self.position += 1;
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Write a tag and length header for a value of the given length.
+    pub const fn header(self, tag: Tag, length: usize) -> Self {
+        const_assert!(length <= MAX_VALUE_LEN, "DER value too long");
+
+        let mut this = self.byte(tag.octet());
+
+        if let Some(number) = tag.high_tag_number() {
+            let mut index = 0;
+
+            while index < number.high_len() {
+                this = this.byte(number.high_octet(index));
+                index += 1;
+            }
+        }
+
+        match length {
+            0..=0x7F => this.byte(length as u8),
+            0x80..=0xFF => this.byte(0x81).byte(length as u8),
+            0x100..=0xFFFF => this.byte(0x82).byte((length >> 8) as u8).byte(length as u8),
+            _ => this
+                .byte(0x83)
+                .byte((length >> 16) as u8)
+                .byte((length >> 8) as u8)
+                .byte(length as u8),
+        }
+    }
+
+    /// Write a complete tag-length-value (TLV) field.
+    pub const fn tlv(self, tag: Tag, value: &[u8]) -> Self {
+        self.header(tag, value.len()).raw(value)
+    }
+
+    /// Write an ASN.1 `BOOLEAN`.
+    pub const fn boolean(self, value: bool) -> Self {
+        self.tlv(Tag::Boolean, &[if value { 0xFF } else { 0x00 }])
+    }
+
+    /// Write an ASN.1 `NULL`.
+    pub const fn null(self) -> Self {
+        self.tlv(Tag::Null, &[])
+    }
+
+    /// Write an ASN.1 `OCTET STRING`.
+    pub const fn octet_string(self, value: &[u8]) -> Self {
+        self.tlv(Tag::OctetString, value)
+    }
+
+    /// Write an ASN.1 `OBJECT IDENTIFIER`.
+    #[cfg(feature = "oid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+    pub const fn oid(self, oid: &ObjectIdentifier) -> Self {
+        let mut this = self.header(Tag::ObjectIdentifier, oid.len());
+        let bytes = oid.as_padded_bytes();
+        let mut i = 0;
+
+        while i < oid.len() {
+            this = this.byte(bytes[i]);
+            i += 1;
+        }
+
+        this
+    }
+
+    /// Write an ASN.1 `SEQUENCE` whose body is the given DER-encoded fields.
+    pub const fn sequence(self, contents: &[u8]) -> Self {
+        self.tlv(Tag::Sequence, contents)
+    }
+
+    /// Finish encoding, returning the output buffer.
+    ///
+    /// The buffer must have been completely filled.
+    pub const fn finish(self) -> [u8; N] {
+        const_assert!(
+            self.position == N,
+            "DER message is shorter than output buffer size"
+        );
+        self.bytes
+    }
+
+    /// Write a single byte.
+    const fn byte(self, byte: u8) -> Self {
+        self.raw(&[byte])
+    }
+}
+
+impl<const N: usize> Default for ConstEncoder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConstEncoder;
+    use crate::{asn1::OctetString, Encodable, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn matches_runtime_encoding() {
+        const BYTES: [u8; 10] = ConstEncoder::new()
+            .boolean(true)
+            .null()
+            .octet_string(&[1, 2, 3])
+            .finish();
+
+        let mut buf = [0u8; 10];
+        let mut encoder = crate::Encoder::new(&mut buf);
+        true.encode(&mut encoder).unwrap();
+        ().encode(&mut encoder).unwrap();
+        OctetString::new(&[1, 2, 3])
+            .unwrap()
+            .encode(&mut encoder)
+            .unwrap();
+
+        assert_eq!(&BYTES, encoder.finish().unwrap());
+    }
+
+    #[test]
+    fn long_form_length() {
+        const VALUE: [u8; 0x100] = [0xAA; 0x100];
+        const BYTES: [u8; 0x104] = ConstEncoder::new().tlv(Tag::OctetString, &VALUE).finish();
+        assert_eq!(BYTES[..4], hex!("04820100"));
+        assert_eq!(BYTES[4..], VALUE);
+    }
+
+    #[cfg(feature = "oid")]
+    #[test]
+    fn algorithm_identifier() {
+        const OID: crate::asn1::ObjectIdentifier =
+            crate::asn1::ObjectIdentifier::new("1.2.840.10045.2.1");
+        const PARAMS: [u8; 11] = ConstEncoder::new().oid(&OID).null().finish();
+        const ALGORITHM_ID: [u8; 13] = ConstEncoder::new().sequence(&PARAMS).finish();
+        assert_eq!(ALGORITHM_ID, hex!("300B06072A8648CE3D02010500"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn incomplete() {
+        ConstEncoder::<3>::new().null().finish();
+    }
+}
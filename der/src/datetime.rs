@@ -12,9 +12,15 @@ use core::{fmt, str::FromStr, time::Duration};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "time")]
-use {core::convert::TryInto, time::PrimitiveDateTime};
+use {
+    core::convert::TryInto,
+    time::{OffsetDateTime, PrimitiveDateTime},
+};
 
-#[cfg(any(feature = "std", feature = "time"))]
+#[cfg(feature = "chrono")]
+use chrono::{TimeZone, Utc};
+
+#[cfg(any(feature = "std", feature = "time", feature = "chrono"))]
 use core::convert::TryFrom;
 
 /// Minimum year allowed in [`DateTime`] values.
@@ -365,6 +371,56 @@ impl TryFrom<PrimitiveDateTime> for DateTime {
     }
 }
 
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<DateTime> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: DateTime) -> Result<OffsetDateTime> {
+        let secs = time.unix_duration().as_secs();
+        Ok(OffsetDateTime::from_unix_timestamp(secs as i64)?)
+    }
+}
+
+/// Converts to UTC, discarding any fractional seconds.
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<OffsetDateTime> for DateTime {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<DateTime> {
+        let secs = u64::try_from(time.unix_timestamp()).map_err(|_| ErrorKind::DateTime)?;
+        DateTime::from_unix_duration(Duration::from_secs(secs))
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl From<DateTime> for chrono::DateTime<Utc> {
+    fn from(time: DateTime) -> chrono::DateTime<Utc> {
+        let secs = time.unix_duration().as_secs();
+
+        // `DateTime` is limited to years 1970-9999, which is well within
+        // the range of `chrono::DateTime`
+        Utc.timestamp_opt(secs as i64, 0)
+            .single()
+            .expect("DateTime out of range for chrono")
+    }
+}
+
+/// Fails if the time is out of the range of [`DateTime`], i.e. not within
+/// the years 1970-9999. Any fractional seconds are discarded.
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<chrono::DateTime<Utc>> for DateTime {
+    type Error = Error;
+
+    fn try_from(time: chrono::DateTime<Utc>) -> Result<DateTime> {
+        let secs = u64::try_from(time.timestamp()).map_err(|_| ErrorKind::DateTime)?;
+        DateTime::from_unix_duration(Duration::from_secs(secs))
+    }
+}
+
 /// Decode 2-digit decimal value
 pub(crate) fn decode_decimal(tag: Tag, hi: u8, lo: u8) -> Result<u16> {
     if (b'0'..=b'9').contains(&hi) && (b'0'..=b'9').contains(&lo) {
@@ -420,4 +476,47 @@ mod tests {
         let datetime = DateTime::new(2001, 01, 02, 12, 13, 14).unwrap();
         assert_eq!(&datetime.to_string(), "2001-01-02T12:13:14Z");
     }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_date_time() {
+        use chrono::{TimeZone, Utc};
+        use core::convert::TryFrom;
+
+        let datetime = DateTime::new(2001, 1, 2, 12, 13, 14).unwrap();
+        let chrono_date_time = chrono::DateTime::<Utc>::from(datetime);
+        assert_eq!(chrono_date_time.timestamp(), 978437594);
+        assert_eq!(DateTime::try_from(chrono_date_time).unwrap(), datetime);
+
+        // Fractional seconds are discarded
+        let fractional = Utc.timestamp_opt(978437594, 500_000_000).unwrap();
+        assert_eq!(DateTime::try_from(fractional).unwrap(), datetime);
+
+        // Out of range
+        let before_epoch = Utc.timestamp_opt(-1, 0).unwrap();
+        assert!(DateTime::try_from(before_epoch).is_err());
+        let y10000 = Utc.timestamp_opt(253402300800, 0).unwrap();
+        assert!(DateTime::try_from(y10000).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn offset_date_time() {
+        use core::convert::TryFrom;
+        use time::{OffsetDateTime, UtcOffset};
+
+        let datetime = DateTime::new(2001, 1, 2, 12, 13, 14).unwrap();
+        let offset_date_time = OffsetDateTime::try_from(datetime).unwrap();
+        assert_eq!(offset_date_time.unix_timestamp(), 978437594);
+        assert_eq!(DateTime::try_from(offset_date_time).unwrap(), datetime);
+
+        // Normalized to UTC
+        let eastern = offset_date_time.to_offset(UtcOffset::from_hms(-5, 0, 0).unwrap());
+        assert_eq!(eastern.hour(), 7);
+        assert_eq!(DateTime::try_from(eastern).unwrap(), datetime);
+
+        // Before the Unix epoch
+        let before_epoch = OffsetDateTime::from_unix_timestamp(-1).unwrap();
+        assert!(DateTime::try_from(before_epoch).is_err());
+    }
 }
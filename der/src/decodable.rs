@@ -1,6 +1,6 @@
 //! Trait definition for [`Decodable`].
 
-use crate::{DecodeValue, Decoder, Header, Result, Tagged};
+use crate::{DecodeValue, Decoder, Result, Tagged};
 
 /// Decoding trait.
 ///
@@ -22,6 +22,16 @@ pub trait Decodable<'a>: Sized {
         let result = Self::decode(&mut decoder)?;
         decoder.finish(result)
     }
+
+    /// Parse `Self` from the provided BER-encoded byte slice.
+    ///
+    /// See [`EncodingRules::Ber`][crate::EncodingRules::Ber] for the subset
+    /// of BER which is supported.
+    fn from_ber(bytes: &'a [u8]) -> Result<Self> {
+        let mut decoder = Decoder::new_ber(bytes);
+        let result = Self::decode(&mut decoder)?;
+        decoder.finish(result)
+    }
 }
 
 impl<'a, T> Decodable<'a> for T
@@ -29,8 +39,9 @@ where
     T: DecodeValue<'a> + Tagged,
 {
     fn decode(decoder: &mut Decoder<'a>) -> Result<T> {
-        let header = Header::decode(decoder)?;
-        header.tag.assert_eq(T::TAG)?;
-        T::decode_value(decoder, header.length)
+        decoder.tlv(|decoder, header| {
+            header.tag.assert_eq(T::TAG)?;
+            T::decode_value(decoder, header.length)
+        })
     }
 }
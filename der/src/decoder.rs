@@ -1,11 +1,31 @@
 //! DER decoder.
 
 use crate::{
-    asn1::*, Choice, Decodable, DecodeValue, Error, ErrorKind, Length, Result, Tag, TagMode,
-    TagNumber, Tagged,
+    asn1::*, Choice, Decodable, DecodeValue, EncodingRules, Error, ErrorKind, Header, Length,
+    Result, Tag, TagMode, TagNumber, Tagged,
 };
 use core::convert::{TryFrom, TryInto};
 
+#[cfg(feature = "alloc")]
+use {
+    crate::Encodable,
+    alloc::vec::Vec,
+    core::{cmp, mem::size_of},
+};
+
+/// Length octet of values with an indefinite length (BER only).
+const INDEFINITE_LENGTH: u8 = 0x80;
+
+/// End-of-contents octets terminating values with an indefinite length.
+const END_OF_CONTENTS: &[u8] = &[0, 0];
+
+/// Indicator bit for constructed form encoding in identifier octets.
+const CONSTRUCTED_FLAG: u8 = 0b100000;
+
+/// Maximum nesting depth of the segments of a constructed string (BER only).
+#[cfg(feature = "alloc")]
+const MAX_SEGMENT_DEPTH: usize = 8;
+
 /// DER decoder.
 #[derive(Debug)]
 pub struct Decoder<'a> {
@@ -17,6 +37,12 @@ pub struct Decoder<'a> {
 
     /// Position within the decoded slice.
     position: Length,
+
+    /// Maximum number of elements in a decoded collection.
+    element_limit: Option<usize>,
+
+    /// Encoding rules the input is expected to follow.
+    encoding_rules: EncodingRules,
 }
 
 impl<'a> Decoder<'a> {
@@ -25,9 +51,50 @@ impl<'a> Decoder<'a> {
         Self {
             bytes: Some(bytes),
             position: Length::ZERO,
+            element_limit: None,
+            encoding_rules: EncodingRules::default(),
         }
     }
 
+    /// Create a new decoder for the given byte slice which accepts BER
+    /// (see [`EncodingRules::Ber`]).
+    pub fn new_ber(bytes: &'a [u8]) -> Self {
+        let mut decoder = Self::new(bytes);
+        decoder.encoding_rules = EncodingRules::Ber;
+        decoder
+    }
+
+    /// Get the [`EncodingRules`] this decoder accepts.
+    ///
+    /// [`EncodingRules::Der`] (the default) unless configured otherwise.
+    pub fn encoding_rules(&self) -> EncodingRules {
+        self.encoding_rules
+    }
+
+    /// Set the [`EncodingRules`] this decoder accepts, including within
+    /// nested values.
+    pub fn set_encoding_rules(&mut self, encoding_rules: EncodingRules) {
+        self.encoding_rules = encoding_rules;
+    }
+
+    /// Get the maximum number of elements allowed in a collection
+    /// (e.g. `SEQUENCE OF` or `SET OF`) decoded into a heap allocated type.
+    ///
+    /// `None` (the default) means no limit.
+    pub fn element_limit(&self) -> Option<usize> {
+        self.element_limit
+    }
+
+    /// Set the maximum number of elements allowed in a collection decoded
+    /// into a heap allocated type, including within nested values.
+    ///
+    /// Collections which exceed this limit fail with
+    /// [`ErrorKind::ElementLimit`], bounding the memory used to decode
+    /// untrusted inputs.
+    pub fn set_element_limit(&mut self, limit: Option<usize>) {
+        self.element_limit = limit;
+    }
+
     /// Decode a value which impls the [`Decodable`] trait.
     pub fn decode<T: Decodable<'a>>(&mut self) -> Result<T> {
         if self.is_failed() {
@@ -40,6 +107,27 @@ impl<'a> Decoder<'a> {
         })
     }
 
+    /// Decode a value with `IMPLICIT` tagging, i.e. its value encoded as
+    /// usual, but under the provided [`Tag`] rather than `T::TAG`.
+    ///
+    /// This is the counterpart of [`Encoder::encode_tagged`][`crate::Encoder::encode_tagged`].
+    pub fn decode_tagged<T>(&mut self, tag: Tag) -> Result<T>
+    where
+        T: DecodeValue<'a> + Tagged,
+    {
+        if tag.is_constructed() != T::TAG.is_constructed() {
+            return Err(self.error(ErrorKind::UnexpectedTag {
+                expected: None,
+                actual: tag,
+            }));
+        }
+
+        self.tlv(|decoder, header| {
+            header.tag.assert_eq(tag)?;
+            T::decode_value(decoder, header.length)
+        })
+    }
+
     /// Return an error with the given [`ErrorKind`], annotating it with
     /// context about where the error occurred.
     pub fn error(&mut self, kind: ErrorKind) -> Error {
@@ -57,7 +145,13 @@ impl<'a> Decoder<'a> {
         self.bytes.is_none()
     }
 
-    /// Get the position within the buffer.
+    /// Get the position within the buffer, i.e. the number of octets which
+    /// have been decoded so far.
+    ///
+    /// Nested decoders, such as the one passed to [`Decoder::sequence`],
+    /// share the buffer of the outer decoder, so their position is relative
+    /// to the start of the outermost message as well. This is also the
+    /// position which errors are annotated with.
     pub fn position(&self) -> Length {
         self.position
     }
@@ -69,8 +163,25 @@ impl<'a> Decoder<'a> {
             .and_then(|bytes| bytes.get(0).cloned())
     }
 
+    /// Peek at the next tag in the decoder without modifying the cursor.
+    ///
+    /// Unlike [`Decoder::peek`], this also handles tags encoded in
+    /// high-tag-number form (e.g. [`Tag::Date`]).
+    pub fn peek_tag(&self) -> Result<Option<Tag>> {
+        match self.remaining() {
+            Ok(bytes) if !bytes.is_empty() => Tag::decode(&mut Decoder::new(bytes)).map(Some),
+            _ => Ok(None),
+        }
+    }
+
     /// Finish decoding, returning the given value if there is no
-    /// remaining data, or an error otherwise
+    /// remaining data, or an error otherwise.
+    ///
+    /// Nested decoders, such as the one passed to [`Decoder::sequence`], are
+    /// finished automatically once the callback returns, so trailing data
+    /// within a nested value is an error too. This only needs to be called
+    /// on decoders created with [`Decoder::new`] or [`Decoder::new_ber`],
+    /// e.g. to decode the value of an [`Any`].
     pub fn finish<T>(self, value: T) -> Result<T> {
         if self.is_failed() {
             Err(ErrorKind::Failed.at(self.position))
@@ -93,6 +204,18 @@ impl<'a> Decoder<'a> {
         self.remaining().map(|rem| rem.is_empty()).unwrap_or(false)
     }
 
+    /// Get the number of bytes still remaining in the buffer.
+    ///
+    /// For a nested decoder, this is the number of bytes remaining in the
+    /// value it decodes. Returns an error if a fatal error has occurred.
+    pub fn remaining_len(&self) -> Result<Length> {
+        if self.is_failed() {
+            return Err(ErrorKind::Failed.at(self.position));
+        }
+
+        self.remaining()?.len().try_into()
+    }
+
     /// Attempt to decode an ASN.1 `ANY` value.
     pub fn any(&mut self) -> Result<Any<'a>> {
         self.decode()
@@ -135,6 +258,19 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `BIT STRING` into a byte vector.
+    ///
+    /// Unlike [`Decoder::bit_string`], when decoding BER this also accepts
+    /// the constructed encoding, reassembling its segments. As with
+    /// [`BitString`], every segment must have no unused bits.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn bit_string_vec(&mut self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.string_segments(Tag::BitString, Tag::BitString, &mut bytes, 0)?;
+        Ok(bytes)
+    }
+
     /// Attempt to decode an ASN.1 `CONTEXT-SPECIFIC` field with the
     /// provided [`TagNumber`].
     pub fn context_specific<T>(
@@ -152,6 +288,26 @@ impl<'a> Decoder<'a> {
         .map(|field| field.value))
     }
 
+    /// Attempt to decode an ASN.1 `BMPString`.
+    pub fn bmp_string(&mut self) -> Result<BmpString<'a>> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `DATE`.
+    pub fn date(&mut self) -> Result<Date> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `DATE-TIME`.
+    pub fn date_and_time(&mut self) -> Result<DateAndTime> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `GeneralString`.
+    pub fn general_string(&mut self) -> Result<GeneralString<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `GeneralizedTime`.
     pub fn generalized_time(&mut self) -> Result<GeneralizedTime> {
         self.decode()
@@ -167,11 +323,49 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `NumericString`.
+    pub fn numeric_string(&mut self) -> Result<NumericString<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `OCTET STRING`.
     pub fn octet_string(&mut self) -> Result<OctetString<'a>> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `OCTET STRING` into a byte vector.
+    ///
+    /// Unlike [`Decoder::octet_string`], when decoding BER this also accepts
+    /// the constructed encoding, reassembling its segments.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn octet_string_vec(&mut self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.string_segments(Tag::OctetString, Tag::OctetString, &mut bytes, 0)?;
+        Ok(bytes)
+    }
+
+    /// Attempt to decode an `IMPLICIT` ASN.1 `CONTEXT-SPECIFIC` field with
+    /// the provided [`TagNumber`] containing an `OCTET STRING` into a byte
+    /// vector, e.g. the `encryptedContent` of CMS `EnvelopedData`.
+    ///
+    /// Returns `Ok(None)` if the next field has a different tag. When
+    /// decoding BER, this also accepts the constructed encoding (see
+    /// [`Decoder::octet_string_vec`]).
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn implicit_octet_string_vec(&mut self, tag_number: TagNumber) -> Result<Option<Vec<u8>>> {
+        let tag = tag_number.context_specific(false);
+
+        if self.peek_string_form(tag).is_none() {
+            return Ok(None);
+        }
+
+        let mut bytes = Vec::new();
+        self.string_segments(tag, Tag::OctetString, &mut bytes, 0)?;
+        Ok(Some(bytes))
+    }
+
     /// Attempt to decode an ASN.1 `OBJECT IDENTIFIER`.
     #[cfg(feature = "oid")]
     #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
@@ -189,25 +383,210 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `RELATIVE-OID`.
+    pub fn relative_oid(&mut self) -> Result<RelativeOid<'a>> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `TeletexString`.
+    pub fn teletex_string(&mut self) -> Result<TeletexString<'a>> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `TIME`.
+    pub fn time(&mut self) -> Result<Time<'a>> {
+        self.decode()
+    }
+
+    /// Attempt to decode an ASN.1 `TIME-OF-DAY`.
+    pub fn time_of_day(&mut self) -> Result<TimeOfDay> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `UTCTime`.
     pub fn utc_time(&mut self) -> Result<UtcTime> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `UniversalString`.
+    pub fn universal_string(&mut self) -> Result<UniversalString<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `UTF8String`.
     pub fn utf8_string(&mut self) -> Result<Utf8String<'a>> {
         self.decode()
     }
 
+    /// Attempt to decode an ASN.1 `VisibleString`.
+    pub fn visible_string(&mut self) -> Result<VisibleString<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode an ASN.1 `SEQUENCE`, creating a new nested
     /// [`Decoder`] and calling the provided argument with it.
     pub fn sequence<F, T>(&mut self, f: F) -> Result<T>
     where
         F: FnOnce(&mut Decoder<'a>) -> Result<T>,
     {
-        Tag::try_from(self.byte()?)?.assert_eq(Tag::Sequence)?;
-        let len = Length::decode(self)?;
-        self.decode_nested(len, f)
+        Tag::decode(self)?.assert_eq(Tag::Sequence)?;
+        self.value(Tag::Sequence, |decoder, length| {
+            decoder.decode_nested(length, f)
+        })
+    }
+
+    /// Decode a tag and length, then call the provided function to decode
+    /// the value described by the resulting [`Header`].
+    ///
+    /// When decoding BER, this handles indefinite lengths (see
+    /// [`Decoder::value`]).
+    pub(crate) fn tlv<F, T>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self, Header) -> Result<T>,
+    {
+        let tag = Tag::decode(self)?;
+        self.value(tag, |decoder, length| f(decoder, Header { tag, length }))
+    }
+
+    /// Decode the length of a value with the given tag, then call the
+    /// provided function to decode the value itself.
+    ///
+    /// When decoding BER, the length of a constructed value may be
+    /// indefinite, in which case the function is called with the length of
+    /// the contents up to the end-of-contents octets, which are consumed
+    /// afterwards.
+    pub(crate) fn value<F, T>(&mut self, tag: Tag, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self, Length) -> Result<T>,
+    {
+        self.value_with_form(tag, tag.is_constructed(), f)
+    }
+
+    /// Like [`Decoder::value`], but for a value of the given form, since
+    /// there is no [`Tag`] for the constructed encoding of string types.
+    fn value_with_form<F, T>(&mut self, tag: Tag, constructed: bool, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self, Length) -> Result<T>,
+    {
+        if self.encoding_rules == EncodingRules::Ber && self.peek() == Some(INDEFINITE_LENGTH) {
+            if !constructed {
+                return Err(self.error(ErrorKind::Length { tag, length: None }));
+            }
+
+            self.byte()?;
+            let length = indefinite_length(self.remaining()?).map_err(|e| self.error(e.kind()))?;
+            let result = f(self, length)?;
+
+            return match self.bytes(END_OF_CONTENTS.len())? {
+                END_OF_CONTENTS => Ok(result),
+                _ => Err(self.error(ErrorKind::Length { tag, length: None })),
+            };
+        }
+
+        let length = Length::decode(self).map_err(|e| {
+            if e.kind() == ErrorKind::Overlength {
+                ErrorKind::Length { tag, length: None }.into()
+            } else {
+                e
+            }
+        })?;
+
+        f(self, length)
+    }
+
+    /// Decode a string with the given (primitive) tag, appending its contents
+    /// to `bytes`.
+    ///
+    /// When decoding BER, the constructed encoding is also accepted, in
+    /// which case the contents are the concatenation of those of the nested
+    /// segments, which have the universal `segment_tag` (X.690 Section
+    /// 8.7.3.2 and 8.6.4).
+    #[cfg(feature = "alloc")]
+    fn string_segments(
+        &mut self,
+        tag: Tag,
+        segment_tag: Tag,
+        bytes: &mut Vec<u8>,
+        depth: usize,
+    ) -> Result<()> {
+        let constructed = match self.peek_string_form(tag) {
+            Some(constructed) => constructed,
+            None => {
+                let actual = self
+                    .peek_tag()?
+                    .ok_or_else(|| self.error(ErrorKind::Truncated))?;
+
+                return Err(self.error(ErrorKind::UnexpectedTag {
+                    expected: Some(tag),
+                    actual,
+                }));
+            }
+        };
+
+        // The identifier has the same length in either form
+        self.bytes(tag.encoded_len()?)?;
+
+        if !constructed {
+            return self.value_with_form(tag, false, |decoder, length| {
+                let contents = decoder.bytes(length)?;
+
+                if segment_tag == Tag::BitString {
+                    // Unused bits are only supported in DER (see `BitString`)
+                    match contents.split_first() {
+                        Some((0, contents)) => bytes.extend_from_slice(contents),
+                        _ => return Err(decoder.value_error(Tag::BitString)),
+                    }
+                } else {
+                    bytes.extend_from_slice(contents);
+                }
+
+                Ok(())
+            });
+        }
+
+        if self.encoding_rules != EncodingRules::Ber {
+            return Err(self.error(tag.non_canonical_error().kind()));
+        }
+
+        if depth >= MAX_SEGMENT_DEPTH {
+            return Err(self.error(ErrorKind::Value { tag }));
+        }
+
+        self.value_with_form(tag, true, |decoder, length| {
+            decoder.decode_nested(length, |decoder| {
+                while !decoder.is_finished() {
+                    decoder.string_segments(segment_tag, segment_tag, bytes, depth + 1)?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    /// Peek at the next tag, returning whether it is the given (primitive)
+    /// string `tag` in either form, and if so, whether it is constructed.
+    #[cfg(feature = "alloc")]
+    fn peek_string_form(&self, tag: Tag) -> Option<bool> {
+        let identifier = self.peek()?;
+
+        // The constructed form of a universal string type isn't a valid
+        // `Tag`, but these always have a single identifier octet
+        let matches = if tag.is_universal() && tag.high_tag_number().is_none() {
+            identifier & !CONSTRUCTED_FLAG == tag.octet()
+        } else {
+            match self.peek_tag() {
+                Ok(Some(actual)) => {
+                    actual.class() == tag.class() && actual.number() == tag.number()
+                }
+                _ => false,
+            }
+        };
+
+        if matches {
+            Some(identifier & CONSTRUCTED_FLAG != 0)
+        } else {
+            None
+        }
     }
 
     /// Decode a single byte, updating the internal cursor.
@@ -238,16 +617,36 @@ impl<'a> Decoder<'a> {
         Ok(result)
     }
 
-    /// Get the number of bytes still remaining in the buffer.
-    pub(crate) fn remaining_len(&self) -> Result<Length> {
-        self.remaining()?.len().try_into()
+    /// Ensure a collection with the given tag which already contains `count`
+    /// elements can have another element added within the element limit.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn check_element_count(&mut self, tag: Tag, count: usize) -> Result<()> {
+        match self.element_limit {
+            Some(limit) if count >= limit => {
+                Err(self.error(ErrorKind::ElementLimit { tag, limit }))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Estimate how many elements to preallocate for a collection of type
+    /// `T` whose encoding is `length` bytes long.
+    ///
+    /// Each element takes at least 2 bytes (tag and length), and the estimate
+    /// is chosen so the preallocated memory never exceeds `length` bytes.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn element_capacity<T>(&self, length: Length) -> usize {
+        let element_size = cmp::max(2, size_of::<T>());
+        let capacity = usize::try_from(length).unwrap_or_default() / element_size;
+        self.element_limit
+            .map_or(capacity, |limit| cmp::min(capacity, limit))
     }
 
     /// Create a nested decoder which operates over the provided [`Length`].
     ///
     /// The nested decoder is passed to the provided callback function which is
     /// expected to decode a value of type `T` with it.
-    fn decode_nested<F, T>(&mut self, length: Length, f: F) -> Result<T>
+    pub(crate) fn decode_nested<F, T>(&mut self, length: Length, f: F) -> Result<T>
     where
         F: FnOnce(&mut Self) -> Result<T>,
     {
@@ -263,6 +662,8 @@ impl<'a> Decoder<'a> {
         let mut nested_decoder = Self {
             bytes: Some(bytes),
             position: start_pos,
+            element_limit: self.element_limit,
+            encoding_rules: self.encoding_rules,
         };
         self.position = end_pos;
 
@@ -281,6 +682,51 @@ impl<'a> Decoder<'a> {
     }
 }
 
+/// Find the length of the contents of a value with an indefinite length,
+/// i.e. the number of bytes until the matching end-of-contents octets.
+///
+/// Nested values may have any tag, and are skipped without being parsed
+/// beyond their identifier and length octets.
+fn indefinite_length(bytes: &[u8]) -> Result<Length> {
+    let mut scanner = Decoder::new_ber(bytes);
+    let mut depth = 0usize;
+
+    loop {
+        if scanner.remaining()?.starts_with(END_OF_CONTENTS) {
+            if depth == 0 {
+                return Ok(scanner.position);
+            }
+
+            scanner.bytes(END_OF_CONTENTS.len())?;
+            depth -= 1;
+            continue;
+        }
+
+        let identifier = scanner.byte()?;
+
+        // High tag number form (X.690 Section 8.1.2.4)
+        if identifier & 0x1F == 0x1F {
+            while scanner.byte()? & 0x80 != 0 {}
+        }
+
+        if scanner.peek() == Some(INDEFINITE_LENGTH) {
+            if identifier & CONSTRUCTED_FLAG == 0 {
+                return Err(ErrorKind::Length {
+                    tag: Tag::try_from(identifier)?,
+                    length: None,
+                }
+                .into());
+            }
+
+            scanner.byte()?;
+            depth = depth.checked_add(1).ok_or(ErrorKind::Overflow)?;
+        } else {
+            let length = Length::decode(&mut scanner)?;
+            scanner.bytes(length)?;
+        }
+    }
+}
+
 impl<'a> From<&'a [u8]> for Decoder<'a> {
     fn from(bytes: &'a [u8]) -> Decoder<'a> {
         Decoder::new(bytes)
@@ -290,7 +736,10 @@ impl<'a> From<&'a [u8]> for Decoder<'a> {
 #[cfg(test)]
 mod tests {
     use super::Decoder;
-    use crate::{Decodable, ErrorKind, Length};
+    use crate::{Decodable, ErrorKind, Length, TagNumber};
+
+    #[cfg(feature = "alloc")]
+    use crate::Tag;
 
     #[test]
     fn truncated_message() {
@@ -308,6 +757,62 @@ mod tests {
         assert_eq!(Some(Length::from(2u8)), err.position());
     }
 
+    #[test]
+    fn position_and_remaining_len() {
+        // `SEQUENCE { INTEGER 1, INTEGER 2 }` followed by `NULL`
+        let mut decoder =
+            Decoder::new(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x05, 0x00]);
+        assert_eq!(decoder.remaining_len().unwrap(), Length::new(10));
+
+        let sum = decoder
+            .sequence(|decoder| {
+                assert_eq!(decoder.position(), Length::new(2));
+                assert_eq!(decoder.remaining_len()?, Length::new(6));
+
+                let mut sum = 0u8;
+                while !decoder.is_finished() {
+                    sum += decoder.uint8()?;
+                }
+
+                assert_eq!(decoder.position(), Length::new(8));
+                Ok(sum)
+            })
+            .unwrap();
+
+        assert_eq!(sum, 3);
+        assert_eq!(decoder.position(), Length::new(8));
+        assert_eq!(decoder.remaining_len().unwrap(), Length::new(2));
+        assert!(!decoder.is_finished());
+
+        decoder.null().unwrap();
+        assert_eq!(decoder.remaining_len().unwrap(), Length::ZERO);
+        assert!(decoder.is_finished());
+
+        decoder.error(ErrorKind::Failed);
+        assert_eq!(
+            decoder.remaining_len().err().unwrap().kind(),
+            ErrorKind::Failed
+        );
+    }
+
+    #[test]
+    fn decode_tagged() {
+        let bytes = [0x41, 0x01, 0x05, 0x9F, 0x64, 0x01, 0x07];
+        let application = TagNumber::new(1).application(false);
+        let context_specific = TagNumber::new(100).context_specific(false);
+
+        let mut decoder = Decoder::new(&bytes);
+        assert!(decoder.decode_tagged::<u8>(context_specific).is_err());
+        assert!(Decoder::new(&bytes)
+            .decode_tagged::<u8>(application.number().application(true))
+            .is_err());
+
+        let mut decoder = Decoder::new(&bytes);
+        assert_eq!(decoder.decode_tagged::<u8>(application).unwrap(), 5);
+        assert_eq!(decoder.decode_tagged::<u8>(context_specific).unwrap(), 7);
+        assert!(decoder.is_finished());
+    }
+
     #[test]
     fn trailing_data() {
         let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A, 0x00]);
@@ -324,4 +829,268 @@ mod tests {
         );
         assert_eq!(Some(Length::from(3u8)), err.position());
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn element_limit() {
+        use crate::Tag;
+        use alloc::{collections::BTreeSet, vec::Vec};
+
+        // SEQUENCE OF INTEGER containing 1, 2, 3
+        const BYTES: &[u8] = &[
+            0x30, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03,
+        ];
+
+        let mut decoder = Decoder::new(BYTES);
+        decoder.set_element_limit(Some(3));
+        assert_eq!(decoder.element_limit(), Some(3));
+        assert_eq!(decoder.decode::<Vec<u8>>().unwrap(), [1, 2, 3]);
+
+        let mut decoder = Decoder::new(BYTES);
+        decoder.set_element_limit(Some(2));
+        let err = decoder.decode::<Vec<u8>>().err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::ElementLimit {
+                tag: Tag::Sequence,
+                limit: 2
+            }
+        );
+
+        // SET OF INTEGER containing 1, 2, 3
+        let mut set_bytes = BYTES.to_vec();
+        set_bytes[0] = 0x31;
+
+        let mut decoder = Decoder::new(&set_bytes);
+        decoder.set_element_limit(Some(2));
+        let err = decoder.decode::<BTreeSet<u8>>().err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::ElementLimit {
+                tag: Tag::Set,
+                limit: 2
+            }
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn element_limit_nested() {
+        use alloc::vec::Vec;
+
+        // SEQUENCE { SEQUENCE OF INTEGER containing 1, 2 }
+        const BYTES: &[u8] = &[0x30, 0x08, 0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02];
+
+        let mut decoder = Decoder::new(BYTES);
+        decoder.set_element_limit(Some(1));
+        let err = decoder
+            .sequence(|decoder| decoder.decode::<Vec<u8>>())
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            err.kind(),
+            ErrorKind::ElementLimit { limit: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn ber_indefinite_length() {
+        use crate::{asn1::ContextSpecific, EncodingRules, Tag};
+
+        // SEQUENCE (indefinite) { INTEGER 1, [0] (indefinite) { INTEGER 2 } }
+        const BYTES: &[u8] = &[
+            0x30, 0x80, 0x02, 0x01, 0x01, 0xA0, 0x80, 0x02, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let mut decoder = Decoder::new_ber(BYTES);
+        assert_eq!(decoder.encoding_rules(), EncodingRules::Ber);
+        let (a, b) = decoder
+            .sequence(|decoder| {
+                let a: u8 = decoder.decode()?;
+                let b: ContextSpecific<u8> = decoder.decode()?;
+                Ok((a, b.value))
+            })
+            .unwrap();
+        assert_eq!((a, b), (1, 2));
+        assert!(decoder.is_finished());
+
+        let any = crate::Any::from_ber(BYTES).unwrap();
+        assert_eq!(any.tag(), Tag::Sequence);
+        assert_eq!(any.value(), &BYTES[2..12]);
+
+        // Indefinite lengths are rejected by default
+        assert!(crate::Any::from_der(BYTES).is_err());
+    }
+
+    #[test]
+    fn ber_indefinite_length_skips_unknown_tags() {
+        use crate::Any;
+
+        // SEQUENCE (indefinite) { constructed OCTET STRING (indefinite) { OCTET STRING } }
+        const BYTES: &[u8] = &[
+            0x30, 0x80, 0x24, 0x80, 0x04, 0x01, 0xAA, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let any = Any::from_ber(BYTES).unwrap();
+        assert_eq!(any.value(), &BYTES[2..9]);
+    }
+
+    #[test]
+    fn ber_indefinite_length_errors() {
+        use crate::Any;
+
+        // Primitive values can't have an indefinite length
+        assert!(Any::from_ber(&[0x04, 0x80, 0x00, 0x00]).is_err());
+
+        // Missing end-of-contents octets
+        assert!(Any::from_ber(&[0x30, 0x80, 0x02, 0x01, 0x01]).is_err());
+
+        // Missing end-of-contents octets of a nested value
+        assert!(Any::from_ber(&[0x30, 0x80, 0x30, 0x80, 0x00, 0x00]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ber_constructed_octet_string() {
+        // Constructed OCTET STRING { OCTET STRING { 01 02 }, OCTET STRING { 03 } }
+        const BYTES: &[u8] = &[0x24, 0x07, 0x04, 0x02, 0x01, 0x02, 0x04, 0x01, 0x03];
+
+        let mut decoder = Decoder::new_ber(BYTES);
+        assert_eq!(decoder.octet_string_vec().unwrap(), [1, 2, 3]);
+        assert!(decoder.is_finished());
+
+        // Constructed encodings are rejected by default
+        let err = Decoder::new(BYTES).octet_string_vec().err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Noncanonical {
+                tag: Tag::OctetString
+            }
+        );
+
+        // Primitive encodings are accepted either way
+        let mut decoder = Decoder::new(&[0x04, 0x02, 0x01, 0x02]);
+        assert_eq!(decoder.octet_string_vec().unwrap(), [1, 2]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ber_constructed_octet_string_indefinite_length() {
+        // Constructed OCTET STRING (indefinite) {
+        //   OCTET STRING { 01 }
+        //   Constructed OCTET STRING { OCTET STRING { 02 03 } }
+        //   OCTET STRING { }
+        // }
+        const BYTES: &[u8] = &[
+            0x24, 0x80, 0x04, 0x01, 0x01, 0x24, 0x04, 0x04, 0x02, 0x02, 0x03, 0x04, 0x00, 0x00,
+            0x00,
+        ];
+
+        let mut decoder = Decoder::new_ber(BYTES);
+        assert_eq!(decoder.octet_string_vec().unwrap(), [1, 2, 3]);
+        assert!(decoder.is_finished());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ber_constructed_octet_string_errors() {
+        // Segments must be OCTET STRINGs
+        let mut decoder = Decoder::new_ber(&[0x24, 0x03, 0x02, 0x01, 0x01]);
+        assert!(decoder.octet_string_vec().is_err());
+
+        // Truncated segment
+        let mut decoder = Decoder::new_ber(&[0x24, 0x03, 0x04, 0x02, 0x01]);
+        assert!(decoder.octet_string_vec().is_err());
+
+        // Excessively nested segments
+        let mut bytes = [0x24u8, 0x80].repeat(9);
+        bytes.extend_from_slice(&[0x00].repeat(18));
+        let err = Decoder::new_ber(&bytes).octet_string_vec().err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Value {
+                tag: Tag::OctetString
+            }
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ber_constructed_bit_string() {
+        // Constructed BIT STRING { BIT STRING { 00 01 }, BIT STRING { 00 02 03 } }
+        const BYTES: &[u8] = &[
+            0x23, 0x09, 0x03, 0x02, 0x00, 0x01, 0x03, 0x03, 0x00, 0x02, 0x03,
+        ];
+
+        let mut decoder = Decoder::new_ber(BYTES);
+        assert_eq!(decoder.bit_string_vec().unwrap(), [1, 2, 3]);
+
+        // Unused bits aren't supported
+        let mut decoder = Decoder::new_ber(&[0x23, 0x04, 0x03, 0x02, 0x01, 0x02]);
+        assert!(decoder.bit_string_vec().is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ber_implicit_constructed_octet_string() {
+        // SEQUENCE (indefinite) {
+        //   [0] IMPLICIT constructed OCTET STRING (indefinite) {
+        //     OCTET STRING { 01 02 }, OCTET STRING { 03 }
+        //   }
+        // }
+        const BYTES: &[u8] = &[
+            0x30, 0x80, 0xA0, 0x80, 0x04, 0x02, 0x01, 0x02, 0x04, 0x01, 0x03, 0x00, 0x00, 0x00,
+            0x00,
+        ];
+
+        let mut decoder = Decoder::new_ber(BYTES);
+        let content = decoder
+            .sequence(|decoder| {
+                assert_eq!(decoder.implicit_octet_string_vec(TagNumber::new(1))?, None);
+                decoder.implicit_octet_string_vec(TagNumber::new(0))
+            })
+            .unwrap();
+        assert_eq!(content, Some([1, 2, 3].to_vec()));
+
+        // [0] IMPLICIT OCTET STRING { 01 }
+        let mut decoder = Decoder::new(&[0x80, 0x01, 0x01]);
+        let content = decoder.implicit_octet_string_vec(TagNumber::new(0));
+        assert_eq!(content.unwrap(), Some([1].to_vec()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn implicit_octet_string_vec_high_tag_number() {
+        // [31] IMPLICIT OCTET STRING { 01 }
+        const BYTES: &[u8] = &[0x9F, 0x1F, 0x01, 0x01];
+
+        let mut decoder = Decoder::new(BYTES);
+        assert_eq!(
+            decoder.implicit_octet_string_vec(TagNumber::new(40)),
+            Ok(None)
+        );
+        let content = decoder.implicit_octet_string_vec(TagNumber::new(31));
+        assert_eq!(content, Ok(Some([1].to_vec())));
+        assert!(decoder.is_finished());
+
+        // [31] IMPLICIT constructed OCTET STRING (indefinite) { OCTET STRING { 01 } }
+        const BER_BYTES: &[u8] = &[0xBF, 0x1F, 0x80, 0x04, 0x01, 0x01, 0x00, 0x00];
+
+        let mut decoder = Decoder::new_ber(BER_BYTES);
+        assert_eq!(
+            decoder.implicit_octet_string_vec(TagNumber::new(40)),
+            Ok(None)
+        );
+        let content = decoder.implicit_octet_string_vec(TagNumber::new(31));
+        assert_eq!(content, Ok(Some([1].to_vec())));
+        assert!(decoder.is_finished());
+    }
+
+    #[test]
+    fn ber_non_minimal_length() {
+        const BYTES: &[u8] = &[0x02, 0x81, 0x01, 0x2A];
+        assert_eq!(i8::from_ber(BYTES).unwrap(), 42);
+        assert!(i8::from_der(BYTES).is_err());
+    }
 }
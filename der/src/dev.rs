@@ -0,0 +1,390 @@
+//! Development-related functionality: test vector loading and a conformance
+//! runner for crates which implement ASN.1 DER-encoded formats.
+//!
+//! Two sources of test vectors are supported:
+//!
+//! - Example files (e.g. generated with `openssl`) containing a single
+//!   DER-encoded (or, with the `pem` feature, PEM-encoded) message, all of
+//!   which are expected to be valid.
+//! - [Wycheproof]-style JSON files, where each entry in `testGroups[].tests[]`
+//!   has a `tcId`, `comment`, `result` (`valid`, `acceptable`, or `invalid`),
+//!   and a hex-encoded DER message.
+//!
+//! Vectors are then checked against a message type with [`run`], which
+//! verifies valid inputs decode and re-encode to the exact same bytes, and
+//! invalid inputs are rejected.
+//!
+//! Output which must remain stable, e.g. the [`samples`], can be compared
+//! byte-for-byte against known-good golden files with [`check_golden`].
+//!
+//! [Wycheproof]: https://github.com/google/wycheproof
+
+pub mod samples;
+
+mod json;
+
+use self::json::Value;
+use crate::{Decodable, Encodable};
+use std::{
+    fmt, format, fs, io,
+    path::Path,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[cfg(feature = "pem")]
+use crate::pem;
+
+/// Environment variable which, when set, makes [`check_golden`] write golden
+/// files rather than comparing against them.
+pub const UPDATE_GOLDEN_VAR: &str = "DER_UPDATE_GOLDEN";
+
+/// Expected outcome of decoding a [`TestVector`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Expected {
+    /// Input must decode and re-encode to the same bytes.
+    Valid,
+
+    /// Input may be either accepted or rejected, but if accepted it must
+    /// re-encode to the same bytes.
+    Acceptable,
+
+    /// Input must be rejected.
+    Invalid,
+}
+
+/// Test vector containing a DER-encoded message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TestVector {
+    /// Human-readable name identifying this vector in failure reports.
+    pub name: String,
+
+    /// DER-encoded message.
+    pub der: Vec<u8>,
+
+    /// Expected outcome of decoding this message.
+    pub expected: Expected,
+}
+
+impl TestVector {
+    /// Load all example files in `dir` whose file names match `filter`.
+    ///
+    /// Files with a `.der` extension are loaded as-is, and files with a
+    /// `.pem` extension are decoded from PEM when the `pem` feature is
+    /// enabled. All other files are ignored. Examples are expected to be
+    /// [`Expected::Valid`].
+    pub fn load_examples(
+        dir: impl AsRef<Path>,
+        filter: impl Fn(&str) -> bool,
+    ) -> io::Result<Vec<Self>> {
+        let mut paths = fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        paths.sort();
+
+        let mut vectors = Vec::new();
+
+        for path in paths {
+            let name = match path.file_name().and_then(|name| name.to_str()) {
+                Some(name) if filter(name) => name.to_string(),
+                _ => continue,
+            };
+
+            let der = match path.extension().and_then(|ext| ext.to_str()) {
+                Some("der") => fs::read(&path)?,
+                #[cfg(feature = "pem")]
+                Some("pem") => {
+                    pem::decode_vec(&fs::read(&path)?)
+                        .map_err(|err| invalid_data(err.to_string()))?
+                        .1
+                }
+                _ => continue,
+            };
+
+            vectors.push(Self {
+                name,
+                der,
+                expected: Expected::Valid,
+            });
+        }
+
+        Ok(vectors)
+    }
+
+    /// Load all [`samples`] whose names match `filter`, all of which are
+    /// expected to be [`Expected::Valid`].
+    pub fn load_samples(filter: impl Fn(&str) -> bool) -> Vec<Self> {
+        samples::all()
+            .into_iter()
+            .filter(|sample| filter(sample.name))
+            .map(|sample| Self {
+                name: sample.name.to_string(),
+                der: sample.der,
+                expected: Expected::Valid,
+            })
+            .collect()
+    }
+
+    /// Load a Wycheproof-style JSON file, reading the DER-encoded message of
+    /// each test case from the hex-encoded `field`.
+    pub fn load_wycheproof(path: impl AsRef<Path>, field: &str) -> io::Result<Vec<Self>> {
+        let path = path.as_ref();
+        let source = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        Self::parse_wycheproof(source, &fs::read_to_string(path)?, field)
+    }
+
+    /// Parse Wycheproof-style JSON, reading the DER-encoded message of each
+    /// test case from the hex-encoded `field`.
+    ///
+    /// The `source` is used as a prefix for the name of each vector.
+    pub fn parse_wycheproof(source: &str, json: &str, field: &str) -> io::Result<Vec<Self>> {
+        let root = Value::parse(json).map_err(invalid_data)?;
+        let groups = root
+            .get("testGroups")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid_data("missing `testGroups`"))?;
+
+        let mut vectors = Vec::new();
+
+        for group in groups {
+            let tests = group
+                .get("tests")
+                .and_then(Value::as_array)
+                .ok_or_else(|| invalid_data("missing `tests`"))?;
+
+            for test in tests {
+                let tc_id = test
+                    .get("tcId")
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| invalid_data("missing `tcId`"))?;
+
+                let expected = match test.get("result").and_then(Value::as_str) {
+                    Some("valid") => Expected::Valid,
+                    Some("acceptable") => Expected::Acceptable,
+                    Some("invalid") => Expected::Invalid,
+                    _ => return Err(invalid_data("missing or unknown `result`")),
+                };
+
+                let der = test
+                    .get(field)
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| invalid_data("missing DER field"))
+                    .and_then(decode_hex)?;
+
+                let mut name = format!("{}#{}", source, tc_id);
+
+                if let Some(comment) = test
+                    .get("comment")
+                    .and_then(Value::as_str)
+                    .filter(|c| !c.is_empty())
+                {
+                    name.push_str(" (");
+                    name.push_str(comment);
+                    name.push(')');
+                }
+
+                vectors.push(Self {
+                    name,
+                    der,
+                    expected,
+                });
+            }
+        }
+
+        Ok(vectors)
+    }
+}
+
+/// Failed [`TestVector`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Failure {
+    /// Name of the failed vector.
+    pub name: String,
+
+    /// Description of what went wrong.
+    pub reason: String,
+}
+
+/// Result of running a set of [`TestVector`]s with [`run`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// Number of vectors with the expected outcome.
+    pub passed: usize,
+
+    /// Vectors without the expected outcome.
+    pub failures: Vec<Failure>,
+}
+
+impl Report {
+    /// Did all vectors have the expected outcome?
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Panic with a list of failures if any vector did not have the
+    /// expected outcome.
+    pub fn assert_success(&self) {
+        assert!(self.is_success(), "{}", self);
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} passed, {} failed", self.passed, self.failures.len())?;
+
+        for failure in &self.failures {
+            write!(f, "\n- {}: {}", failure.name, failure.reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Check each of the given vectors decodes as a `T` (and re-encodes to the
+/// same bytes) or is rejected according to its [`Expected`] outcome.
+pub fn run<'a, T>(vectors: &'a [TestVector]) -> Report
+where
+    T: Decodable<'a> + Encodable,
+{
+    let mut report = Report::default();
+
+    for vector in vectors {
+        let reason = match (T::from_der(&vector.der), vector.expected) {
+            (Ok(msg), Expected::Valid) | (Ok(msg), Expected::Acceptable) => match msg.to_vec() {
+                Ok(der) if der == vector.der => None,
+                Ok(_) => Some("re-encoded message differs from input".to_string()),
+                Err(err) => Some(format!("encoding failed: {}", err)),
+            },
+            (Ok(_), Expected::Invalid) => Some("invalid input was accepted".to_string()),
+            (Err(err), Expected::Valid) => Some(format!("decoding failed: {}", err)),
+            (Err(_), Expected::Acceptable) | (Err(_), Expected::Invalid) => None,
+        };
+
+        match reason {
+            Some(reason) => report.failures.push(Failure {
+                name: vector.name.clone(),
+                reason,
+            }),
+            None => report.passed += 1,
+        }
+    }
+
+    report
+}
+
+/// Check `contents` is identical to the golden file at `path`.
+///
+/// If the [`UPDATE_GOLDEN_VAR`] environment variable is set, the golden
+/// file is (re)written with `contents` instead.
+pub fn check_golden(path: impl AsRef<Path>, contents: &[u8]) -> io::Result<()> {
+    let path = path.as_ref();
+
+    if std::env::var_os(UPDATE_GOLDEN_VAR).is_some() {
+        return fs::write(path, contents);
+    }
+
+    if fs::read(path)? == contents {
+        Ok(())
+    } else {
+        Err(invalid_data(format!(
+            "{} differs from the golden file (set {} to update it)",
+            path.display(),
+            UPDATE_GOLDEN_VAR
+        )))
+    }
+}
+
+/// Decode a hexadecimal string.
+fn decode_hex(hex: &str) -> io::Result<Vec<u8>> {
+    fn nibble(c: u8) -> io::Result<u8> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(invalid_data("invalid hex character")),
+        }
+    }
+
+    let pairs = hex.as_bytes().chunks_exact(2);
+
+    if !pairs.remainder().is_empty() {
+        return Err(invalid_data("odd-length hex string"));
+    }
+
+    pairs
+        .map(|pair| Ok(nibble(pair[0])? << 4 | nibble(pair[1])?))
+        .collect()
+}
+
+/// Create an [`io::Error`] for a malformed test vector file.
+fn invalid_data<E>(err: E) -> io::Error
+where
+    E: Into<std::boxed::Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_golden, run, Expected, TestVector};
+    use crate::asn1::OctetString;
+    use std::{env, fs, io};
+
+    const WYCHEPROOF_EXAMPLE: &str = r#"{
+        "testGroups": [{
+            "tests": [
+                { "tcId": 1, "comment": "", "der": "0403010203", "result": "valid" },
+                { "tcId": 2, "comment": "long form length", "der": "048103010203", "result": "invalid" },
+                { "tcId": 3, "comment": "truncated", "der": "04030102", "result": "invalid" }
+            ]
+        }]
+    }"#;
+
+    #[test]
+    fn parse_wycheproof() {
+        let vectors = TestVector::parse_wycheproof("example", WYCHEPROOF_EXAMPLE, "der").unwrap();
+        assert_eq!(vectors.len(), 3);
+        assert_eq!(vectors[0].name, "example#1");
+        assert_eq!(vectors[0].der, [4, 3, 1, 2, 3]);
+        assert_eq!(vectors[0].expected, Expected::Valid);
+        assert_eq!(vectors[1].name, "example#2 (long form length)");
+        assert_eq!(vectors[1].expected, Expected::Invalid);
+    }
+
+    #[test]
+    fn run_octet_string() {
+        let vectors = TestVector::parse_wycheproof("example", WYCHEPROOF_EXAMPLE, "der").unwrap();
+        let report = run::<OctetString<'_>>(&vectors);
+        report.assert_success();
+        assert_eq!(report.passed, 3);
+    }
+
+    #[test]
+    fn report_failures() {
+        let mut vectors =
+            TestVector::parse_wycheproof("example", WYCHEPROOF_EXAMPLE, "der").unwrap();
+        vectors[2].expected = Expected::Valid;
+
+        let report = run::<OctetString<'_>>(&vectors);
+        assert!(!report.is_success());
+        assert_eq!(report.passed, 2);
+        assert_eq!(report.failures[0].name, "example#3 (truncated)");
+    }
+
+    #[test]
+    fn golden_mismatch() {
+        let path = env::temp_dir().join("der-golden-mismatch.der");
+        fs::write(&path, [0x05, 0x00]).unwrap();
+
+        assert!(check_golden(&path, &[0x05, 0x00]).is_ok());
+        let err = check_golden(&path, &[0x01, 0x01, 0x00]).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
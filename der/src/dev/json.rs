@@ -0,0 +1,334 @@
+//! Minimal JSON reader for Wycheproof-style test vector files.
+
+use std::{collections::BTreeMap, str, string::String, vec::Vec};
+
+/// Maximum nesting depth of arrays and objects.
+///
+/// The parser is recursive, so this bounds its stack usage. Test vector
+/// files are only nested a few levels deep.
+const MAX_DEPTH: usize = 64;
+
+/// Result type with a static error message.
+pub(super) type Result<T> = core::result::Result<T, &'static str>;
+
+/// Parsed JSON value.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(BTreeMap<String, Value>),
+}
+
+impl Value {
+    /// Parse a JSON document.
+    pub(super) fn parse(json: &str) -> Result<Self> {
+        let mut parser = Parser {
+            bytes: json.as_bytes(),
+            pos: 0,
+            depth: 0,
+        };
+
+        let value = parser.value()?;
+        parser.whitespace();
+
+        if parser.pos == parser.bytes.len() {
+            Ok(value)
+        } else {
+            Err("trailing data after JSON value")
+        }
+    }
+
+    /// Get a field of an object.
+    pub(super) fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields.get(key),
+            _ => None,
+        }
+    }
+
+    /// Get the elements of an array.
+    pub(super) fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    /// Get the contents of a string.
+    pub(super) fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Get a non-negative integer.
+    pub(super) fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Value::Number(n) if n >= 0.0 && n.fract() == 0.0 && n <= u64::MAX as f64 => {
+                Some(n as u64)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Recursive descent JSON parser.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+
+    /// Number of arrays and objects enclosing the current position.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn value(&mut self) -> Result<Value> {
+        self.whitespace();
+
+        match self.peek().ok_or("unexpected end of JSON")? {
+            b'{' => self.nested(Self::object),
+            b'[' => self.nested(Self::array),
+            b'"' => self.string().map(Value::String),
+            b't' => self.literal("true", Value::Bool(true)),
+            b'f' => self.literal("false", Value::Bool(false)),
+            b'n' => self.literal("null", Value::Null),
+            b'-' | b'0'..=b'9' => self.number(),
+            _ => Err("unexpected character in JSON"),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Value>) -> Result<Value> {
+        if self.depth == MAX_DEPTH {
+            return Err("JSON nested too deeply");
+        }
+
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn object(&mut self) -> Result<Value> {
+        let mut fields = BTreeMap::new();
+        self.expect(b'{')?;
+        self.whitespace();
+
+        if self.eat(b'}') {
+            return Ok(Value::Object(fields));
+        }
+
+        loop {
+            self.whitespace();
+            let key = self.string()?;
+            self.whitespace();
+            self.expect(b':')?;
+            fields.insert(key, self.value()?);
+            self.whitespace();
+
+            if !self.eat(b',') {
+                self.expect(b'}')?;
+                return Ok(Value::Object(fields));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value> {
+        let mut elements = Vec::new();
+        self.expect(b'[')?;
+        self.whitespace();
+
+        if self.eat(b']') {
+            return Ok(Value::Array(elements));
+        }
+
+        loop {
+            elements.push(self.value()?);
+            self.whitespace();
+
+            if !self.eat(b',') {
+                self.expect(b']')?;
+                return Ok(Value::Array(elements));
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        self.expect(b'"')?;
+        let mut out = String::new();
+
+        loop {
+            let start = self.pos;
+
+            while !matches!(self.peek(), Some(b'"') | Some(b'\\') | None) {
+                self.pos += 1;
+            }
+
+            out.push_str(
+                str::from_utf8(&self.bytes[start..self.pos]).map_err(|_| "invalid UTF-8")?,
+            );
+
+            match self.next().ok_or("unterminated JSON string")? {
+                b'"' => return Ok(out),
+                _ => {
+                    let c = match self.next().ok_or("unterminated JSON string")? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err("invalid JSON string escape"),
+                    };
+                    out.push(c);
+                }
+            }
+        }
+    }
+
+    fn unicode_escape(&mut self) -> Result<char> {
+        let high = self.hex4()?;
+
+        let code = if (0xD800..0xDC00).contains(&high) {
+            if !(self.eat(b'\\') && self.eat(b'u')) {
+                return Err("unpaired surrogate in JSON string");
+            }
+
+            let low = self.hex4()?;
+
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err("unpaired surrogate in JSON string");
+            }
+
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+
+        char::from_u32(code).ok_or("invalid unicode escape in JSON string")
+    }
+
+    fn hex4(&mut self) -> Result<u32> {
+        let digits = self
+            .bytes
+            .get(self.pos..self.pos + 4)
+            .ok_or("truncated unicode escape in JSON string")?;
+
+        self.pos += 4;
+
+        // `from_str_radix` would also accept a leading `+`
+        if !digits.iter().all(u8::is_ascii_hexdigit) {
+            return Err("invalid unicode escape in JSON string");
+        }
+
+        str::from_utf8(digits)
+            .ok()
+            .and_then(|s| u32::from_str_radix(s, 16).ok())
+            .ok_or("invalid unicode escape in JSON string")
+    }
+
+    fn number(&mut self) -> Result<Value> {
+        let start = self.pos;
+
+        while matches!(
+            self.peek(),
+            Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+        ) {
+            self.pos += 1;
+        }
+
+        str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .map(Value::Number)
+            .ok_or("invalid JSON number")
+    }
+
+    fn literal(&mut self, literal: &'static str, value: Value) -> Result<Value> {
+        if self.bytes[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(value)
+        } else {
+            Err("invalid JSON literal")
+        }
+    }
+
+    fn whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<()> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err("malformed JSON")
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.pos += 1;
+        Some(byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Value, MAX_DEPTH};
+
+    #[test]
+    fn parse() {
+        let value = Value::parse(r#" { "a": [1, true, null, "x\"é😀"], "b": {} } "#).unwrap();
+        let a = value.get("a").and_then(Value::as_array).unwrap();
+        assert_eq!(a[0].as_u64(), Some(1));
+        assert_eq!(a[1], Value::Bool(true));
+        assert_eq!(a[2], Value::Null);
+        assert_eq!(a[3].as_str(), Some("x\"é😀"));
+        assert!(value.get("b").is_some());
+
+        let escaped = Value::parse(r#""\u00e9\ud83d\ude00""#).unwrap();
+        assert_eq!(escaped.as_str(), Some("é😀"));
+    }
+
+    #[test]
+    fn reject_malformed() {
+        assert!(Value::parse(r#"{"a": 1"#).is_err());
+        assert!(Value::parse(r#"[1, 2] x"#).is_err());
+        assert!(Value::parse(r#""\ud83d""#).is_err());
+        assert!(Value::parse(r#""\u+041""#).is_err());
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let nested = "[".repeat(MAX_DEPTH) + &"]".repeat(MAX_DEPTH);
+        assert!(Value::parse(&nested).is_ok());
+
+        let too_deep = "[".repeat(MAX_DEPTH + 1) + &"]".repeat(MAX_DEPTH + 1);
+        assert_eq!(Value::parse(&too_deep), Err("JSON nested too deeply"));
+        assert_eq!(
+            Value::parse(&"[{\"a\":".repeat(100_000)),
+            Err("JSON nested too deeply")
+        );
+    }
+}
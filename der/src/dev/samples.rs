@@ -0,0 +1,409 @@
+//! Deterministically generated sample documents, useful for benchmarking
+//! decoders and encoders against representative inputs.
+//!
+//! Each generator is seeded with a fixed value, so the same document is
+//! produced on every call. The numbers involved are random rather than
+//! cryptographically valid: only the shape of the encoding is realistic.
+//!
+//! [`all`] generates a sample of each supported kind of key and certificate,
+//! which are checked byte-for-byte against golden files with
+//! [`check_golden`][`super::check_golden`], and round-tripped through the
+//! types of the crates implementing those formats.
+
+use crate::{
+    asn1::{Any, BitString, ContextSpecific, OctetString, UIntBytes},
+    Decodable, Decoder, Encodable, ErrorKind, Result, Sequence, Tag, TagMode, TagNumber,
+};
+use std::{vec, vec::Vec};
+
+/// DER encoding of the `prime256v1` named curve OID (1.2.840.10045.3.1.7).
+const P256_OID: &[u8] = &[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+
+/// DER encoding of the `id-ecPublicKey` OID (1.2.840.10045.2.1).
+const EC_PUBLIC_KEY_OID: &[u8] = &[0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+
+/// DER encoding of the `id-Ed25519` OID (1.3.101.112).
+const ED25519_OID: &[u8] = &[0x06, 0x03, 0x2b, 0x65, 0x70];
+
+/// DER encoding of the `id-at-commonName` OID (2.5.4.3).
+const COMMON_NAME_OID: &[u8] = &[0x06, 0x03, 0x55, 0x04, 0x03];
+
+/// Seed of the P-256 key samples.
+const P256_SEED: u64 = 256;
+
+/// Seed of the Ed25519 key and certificate samples.
+const ED25519_SEED: u64 = 25519;
+
+/// Sample document of a particular kind.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sample {
+    /// Name of the sample, used as the file name (sans extension) of its
+    /// golden files, e.g. `p256-priv`.
+    pub name: &'static str,
+
+    /// PEM type label of the sample, e.g. `EC PRIVATE KEY`.
+    pub label: &'static str,
+
+    /// DER encoding of the sample.
+    pub der: Vec<u8>,
+}
+
+/// Generate a sample of each supported kind of key and certificate.
+pub fn all() -> Vec<Sample> {
+    vec![
+        Sample {
+            name: "rsa2048-priv",
+            label: "RSA PRIVATE KEY",
+            der: rsa_private_key(2048),
+        },
+        Sample {
+            name: "rsa2048-pub",
+            label: "RSA PUBLIC KEY",
+            der: rsa_public_key(2048),
+        },
+        Sample {
+            name: "p256-priv",
+            label: "EC PRIVATE KEY",
+            der: p256_private_key(),
+        },
+        Sample {
+            name: "p256-pub",
+            label: "PUBLIC KEY",
+            der: p256_public_key(),
+        },
+        Sample {
+            name: "ed25519-priv",
+            label: "PRIVATE KEY",
+            der: ed25519_private_key(),
+        },
+        Sample {
+            name: "ed25519-pub",
+            label: "PUBLIC KEY",
+            der: ed25519_public_key(),
+        },
+        Sample {
+            name: "ed25519-cert",
+            label: "CERTIFICATE",
+            der: ed25519_certificate(),
+        },
+    ]
+}
+
+/// Context-specific tag of the `ECPrivateKey` `parameters` field.
+const PARAMETERS_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(0),
+};
+
+/// Context-specific tag number of the `ECPrivateKey` `publicKey` field.
+const PUBLIC_KEY_TAG: TagNumber = TagNumber::new(1);
+
+/// Generate a PKCS#1 `RSAPrivateKey`-shaped document for a modulus of the
+/// given size in bits, i.e. a `SEQUENCE` of nine `INTEGER`s.
+///
+/// Panics if `modulus_bits` is not a non-zero multiple of 16.
+pub fn rsa_private_key(modulus_bits: usize) -> Vec<u8> {
+    assert!(
+        modulus_bits != 0 && modulus_bits % 16 == 0,
+        "invalid modulus size"
+    );
+
+    let mut rng = Rng::new(modulus_bits as u64);
+    let n = modulus_bits / 8;
+    let modulus = rng.integer(n);
+    let values = [n, n / 2, n / 2, n / 2, n / 2, n / 2]
+        .iter()
+        .map(|&len| rng.integer(len))
+        .collect::<Vec<_>>();
+
+    let mut fields = vec![uint(&[0]), uint(&modulus), uint(&[1, 0, 1])];
+    fields.extend(values.iter().map(|bytes| uint(bytes)));
+    to_der(&fields)
+}
+
+/// Generate a PKCS#1 `RSAPublicKey`-shaped document matching the
+/// [`rsa_private_key`] of the same size.
+///
+/// Panics if `modulus_bits` is not a non-zero multiple of 16.
+pub fn rsa_public_key(modulus_bits: usize) -> Vec<u8> {
+    let private_key = rsa_private_key(modulus_bits);
+    let fields = Vec::<UIntBytes<'_>>::from_der(&private_key).expect("invalid sample");
+    to_der(&fields[1..3].to_vec())
+}
+
+/// Generate a 4096-bit PKCS#1 `RSAPrivateKey`-shaped document.
+pub fn rsa4096_private_key() -> Vec<u8> {
+    rsa_private_key(4096)
+}
+
+/// Generate a SEC1 `ECPrivateKey`-shaped document for the P-256 curve,
+/// including the optional `parameters` and `publicKey` fields.
+pub fn p256_private_key() -> Vec<u8> {
+    let mut rng = Rng::new(P256_SEED);
+    let private_key = rng.bytes(32);
+    let public_key = p256_point(&mut rng);
+
+    to_der(&P256PrivateKey {
+        private_key: &private_key,
+        public_key: &public_key,
+    })
+}
+
+/// Generate a `SubjectPublicKeyInfo`-shaped document for the public key of
+/// the [`p256_private_key`].
+pub fn p256_public_key() -> Vec<u8> {
+    let mut rng = Rng::new(P256_SEED);
+    rng.bytes(32);
+    let public_key = p256_point(&mut rng);
+
+    let algorithm = constructed(Tag::Sequence, &[&any(EC_PUBLIC_KEY_OID), &any(P256_OID)]);
+    constructed(Tag::Sequence, &[&any(&algorithm), &bit_string(&public_key)])
+}
+
+/// Generate a PKCS#8 `PrivateKeyInfo`-shaped document for an Ed25519 key.
+pub fn ed25519_private_key() -> Vec<u8> {
+    let mut rng = Rng::new(ED25519_SEED);
+    let private_key = to_der(&octet_string(&rng.bytes(32)));
+
+    constructed(
+        Tag::Sequence,
+        &[&0u8, &ed25519_algorithm(), &octet_string(&private_key)],
+    )
+}
+
+/// Generate a `SubjectPublicKeyInfo`-shaped document for the public key of
+/// the [`ed25519_private_key`].
+pub fn ed25519_public_key() -> Vec<u8> {
+    let mut rng = Rng::new(ED25519_SEED);
+    rng.bytes(32);
+    let public_key = rng.bytes(32);
+
+    constructed(
+        Tag::Sequence,
+        &[&ed25519_algorithm(), &bit_string(&public_key)],
+    )
+}
+
+/// Generate a self-signed X.509 v3 `Certificate`-shaped document for the
+/// [`ed25519_public_key`], with the common name `sample` and no extensions.
+pub fn ed25519_certificate() -> Vec<u8> {
+    let mut rng = Rng::new(ED25519_SEED);
+    rng.bytes(64);
+    let serial_number = rng.integer(16);
+    let signature = rng.bytes(64);
+
+    let version = ContextSpecific {
+        tag_number: TagNumber::new(0),
+        tag_mode: TagMode::Explicit,
+        value: 2u8,
+    };
+
+    let common_name = Any::new(Tag::Utf8String, b"sample").expect("invalid sample");
+    let attribute = constructed(Tag::Sequence, &[&any(COMMON_NAME_OID), &common_name]);
+    let rdn = constructed(Tag::Set, &[&any(&attribute)]);
+    let name = constructed(Tag::Sequence, &[&any(&rdn)]);
+
+    let not_before = Any::new(Tag::UtcTime, b"200101000000Z").expect("invalid sample");
+    let not_after = Any::new(Tag::UtcTime, b"491231235959Z").expect("invalid sample");
+    let validity = constructed(Tag::Sequence, &[&not_before, &not_after]);
+
+    let public_key = ed25519_public_key();
+    let tbs_certificate = constructed(
+        Tag::Sequence,
+        &[
+            &version,
+            &uint(&serial_number),
+            &ed25519_algorithm(),
+            &any(&name),
+            &any(&validity),
+            &any(&name),
+            &any(&public_key),
+        ],
+    );
+
+    constructed(
+        Tag::Sequence,
+        &[
+            &any(&tbs_certificate),
+            &ed25519_algorithm(),
+            &bit_string(&signature),
+        ],
+    )
+}
+
+/// Generate a `SEQUENCE OF INTEGER` with `len` entries of between 1 and 8
+/// bytes each.
+pub fn sequence_of_integers(len: usize) -> Vec<u8> {
+    let mut rng = Rng::new(len as u64);
+    let values = (0..len)
+        .map(|_| {
+            let size = (rng.next() % 8) as usize + 1;
+            rng.integer(size)
+        })
+        .collect::<Vec<_>>();
+
+    to_der(&values.iter().map(|bytes| uint(bytes)).collect::<Vec<_>>())
+}
+
+/// SEC1 `ECPrivateKey` for the P-256 curve, as generated by
+/// [`p256_private_key`].
+///
+/// The `version` and `parameters` fields are fixed, and `publicKey` is
+/// required.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct P256PrivateKey<'a> {
+    /// Private key data.
+    pub private_key: &'a [u8],
+
+    /// SEC1-encoded public key.
+    pub public_key: &'a [u8],
+}
+
+impl<'a> Decodable<'a> for P256PrivateKey<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            decoder.uint8()?;
+            let private_key = decoder.octet_string()?.as_bytes();
+            decoder.any()?;
+            let public_key = decoder
+                .context_specific::<BitString<'_>>(PUBLIC_KEY_TAG, TagMode::Explicit)?
+                .ok_or(ErrorKind::Truncated)?
+                .as_bytes();
+
+            Ok(Self {
+                private_key,
+                public_key,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for P256PrivateKey<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        f(&[
+            &1u8,
+            &OctetString::new(self.private_key)?,
+            &Any::new(PARAMETERS_TAG, P256_OID)?,
+            &ContextSpecific {
+                tag_number: PUBLIC_KEY_TAG,
+                tag_mode: TagMode::Explicit,
+                value: BitString::new(self.public_key)?,
+            },
+        ])
+    }
+}
+
+/// `AlgorithmIdentifier` for Ed25519, i.e. without parameters.
+fn ed25519_algorithm() -> Any<'static> {
+    Any::new(Tag::Sequence, ED25519_OID).expect("invalid sample")
+}
+
+/// Random uncompressed SEC1 encoding of a P-256 point.
+fn p256_point(rng: &mut Rng) -> Vec<u8> {
+    let mut point = rng.bytes(65);
+    point[0] = 0x04;
+    point
+}
+
+/// Parse the given DER-encoded value as an [`Any`].
+fn any(der: &[u8]) -> Any<'_> {
+    Any::from_der(der).expect("invalid sample")
+}
+
+/// Wrap the given bytes as a `BIT STRING`.
+fn bit_string(bytes: &[u8]) -> BitString<'_> {
+    BitString::new(bytes).expect("invalid sample")
+}
+
+/// Wrap the given bytes as an `OCTET STRING`.
+fn octet_string(bytes: &[u8]) -> OctetString<'_> {
+    OctetString::new(bytes).expect("invalid sample")
+}
+
+/// Serialize a constructed value with the given tag whose contents are the
+/// given fields, e.g. a `SEQUENCE`.
+fn constructed(tag: Tag, fields: &[&dyn Encodable]) -> Vec<u8> {
+    let mut value = Vec::new();
+
+    for field in fields {
+        value.extend(field.to_vec().expect("encoding error"));
+    }
+
+    to_der(&Any::new(tag, &value).expect("invalid sample"))
+}
+
+/// Wrap the given big endian bytes as an `INTEGER`.
+fn uint(bytes: &[u8]) -> UIntBytes<'_> {
+    UIntBytes::new(bytes).expect("invalid integer")
+}
+
+/// Serialize a sample document.
+fn to_der(value: &impl Encodable) -> Vec<u8> {
+    value.to_vec().expect("encoding error")
+}
+
+/// Small xorshift PRNG so samples are reproducible without extra dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed ^ 0x9e37_79b9_7f4a_7c15)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.next() as u8).collect()
+    }
+
+    /// Random big endian integer of exactly `len` bytes (i.e. with its
+    /// highest bit set).
+    fn integer(&mut self, len: usize) -> Vec<u8> {
+        let mut bytes = self.bytes(len);
+        bytes[0] |= 0x80;
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{p256_private_key, rsa4096_private_key, sequence_of_integers, P256PrivateKey};
+    use crate::{asn1::UIntBytes, Decodable, Encodable};
+    use std::vec::Vec;
+
+    #[test]
+    fn rsa4096() {
+        let der = rsa4096_private_key();
+        assert_eq!(der, rsa4096_private_key());
+
+        let fields = Vec::<UIntBytes<'_>>::from_der(&der).unwrap();
+        assert_eq!(fields.len(), 9);
+        assert_eq!(fields[1].as_bytes().len(), 512);
+        assert_eq!(fields[2].as_bytes(), [1, 0, 1]);
+    }
+
+    #[test]
+    fn p256() {
+        let der = p256_private_key();
+        assert_eq!(der.len(), 121);
+
+        let key = P256PrivateKey::from_der(&der).unwrap();
+        assert_eq!(key.private_key.len(), 32);
+        assert_eq!(key.public_key[0], 0x04);
+        assert_eq!(key.to_vec().unwrap(), der);
+    }
+
+    #[test]
+    fn sequence_of() {
+        let der = sequence_of_integers(10_000);
+        assert_eq!(Vec::<UIntBytes<'_>>::from_der(&der).unwrap().len(), 10_000);
+    }
+}
@@ -0,0 +1,219 @@
+//! Structural comparison of DER documents.
+
+use crate::{Any, Decodable, Decoder, Encodable, Header, Length, Result, Tag};
+use alloc::vec::Vec;
+
+/// Node in the tree of tag-length-value encoded values of a DER document.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DiffNode<'a> {
+    /// Offset of the node's header from the start of the document.
+    pub offset: Length,
+
+    /// Tag of the node.
+    pub tag: Tag,
+
+    /// Contents octets of the node.
+    pub value: &'a [u8],
+}
+
+impl<'a> DiffNode<'a> {
+    /// Decode the root node of a DER document.
+    fn root(bytes: &'a [u8]) -> Result<Self> {
+        let any = Any::from_der(bytes)?;
+
+        Ok(Self {
+            offset: Length::ZERO,
+            tag: any.tag(),
+            value: any.value(),
+        })
+    }
+
+    /// Decode the nodes contained in this node.
+    ///
+    /// Returns `None` if this node is primitive, or if its contents aren't a
+    /// sequence of well-formed values.
+    fn children(&self) -> Option<Vec<Self>> {
+        if !self.tag.is_constructed() {
+            return None;
+        }
+
+        let header = Header::new(self.tag, self.value.len()).ok()?;
+        let base = (self.offset + header.encoded_len().ok()?).ok()?;
+        let mut decoder = Decoder::new(self.value);
+        let mut children = Vec::new();
+
+        while !decoder.is_finished() {
+            let position = decoder.position();
+            let any = decoder.any().ok()?;
+
+            children.push(Self {
+                offset: (base + position).ok()?,
+                tag: any.tag(),
+                value: any.value(),
+            });
+        }
+
+        Some(children)
+    }
+}
+
+/// Difference between two DER documents, as found by [`diff`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Difference<'a> {
+    /// Index of the node within each of its enclosing constructed values,
+    /// starting from the outermost one. Empty for the root node.
+    pub path: Vec<usize>,
+
+    /// Node in the first document, or `None` if it only exists in the
+    /// second one.
+    pub left: Option<DiffNode<'a>>,
+
+    /// Node in the second document, or `None` if it only exists in the
+    /// first one.
+    pub right: Option<DiffNode<'a>>,
+}
+
+/// Compare the structure of two DER documents, returning the outermost
+/// nodes which differ in depth-first order.
+///
+/// Constructed values with the same tag are compared element by element,
+/// so a difference is reported at the deepest point where the documents
+/// diverge: a different tag, different contents of a primitive value, or an
+/// element present in only one of them. Constructed values whose contents
+/// aren't well-formed are compared as a whole.
+///
+/// Returns an error if either input isn't a single DER-encoded value.
+pub fn diff<'a>(a: &'a [u8], b: &'a [u8]) -> Result<Vec<Difference<'a>>> {
+    let mut differences = Vec::new();
+    let mut pending = alloc::vec![(
+        Vec::new(),
+        Some(DiffNode::root(a)?),
+        Some(DiffNode::root(b)?)
+    )];
+
+    while let Some((path, left, right)) = pending.pop() {
+        let (l, r) = match (left, right) {
+            (Some(l), Some(r)) if l.tag == r.tag => (l, r),
+            _ => {
+                differences.push(Difference { path, left, right });
+                continue;
+            }
+        };
+
+        if l.value == r.value {
+            continue;
+        }
+
+        match (l.children(), r.children()) {
+            (Some(l_children), Some(r_children)) => {
+                let len = l_children.len().max(r_children.len());
+
+                // Pushed in reverse so they're popped in order
+                for index in (0..len).rev() {
+                    let mut child_path = path.clone();
+                    child_path.push(index);
+
+                    pending.push((
+                        child_path,
+                        l_children.get(index).copied(),
+                        r_children.get(index).copied(),
+                    ));
+                }
+            }
+            _ => differences.push(Difference { path, left, right }),
+        }
+    }
+
+    Ok(differences)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, DiffNode, Difference};
+    use crate::Tag;
+    use hex_literal::hex;
+
+    // SEQUENCE { INTEGER 1, SEQUENCE { INTEGER 2, INTEGER 3 } }
+    const EXAMPLE: &[u8] = &hex!("300b 020101 3006 020102 020103");
+
+    #[test]
+    fn identical() {
+        assert!(diff(EXAMPLE, EXAMPLE).unwrap().is_empty());
+    }
+
+    #[test]
+    fn nested_value() {
+        let other = hex!("300b 020101 3006 020102 020104");
+
+        assert_eq!(
+            diff(EXAMPLE, &other).unwrap(),
+            [Difference {
+                path: alloc::vec![1, 1],
+                left: Some(DiffNode {
+                    offset: 10u8.into(),
+                    tag: Tag::Integer,
+                    value: &[3],
+                }),
+                right: Some(DiffNode {
+                    offset: 10u8.into(),
+                    tag: Tag::Integer,
+                    value: &[4],
+                }),
+            }]
+        );
+    }
+
+    #[test]
+    fn different_tag() {
+        let other = hex!("300b 020101 3106 020102 020103");
+        let differences = diff(EXAMPLE, &other).unwrap();
+
+        assert_eq!(differences.len(), 1);
+        assert_eq!(differences[0].path, [1]);
+        assert_eq!(differences[0].left.unwrap().tag, Tag::Sequence);
+        assert_eq!(differences[0].right.unwrap().tag, Tag::Set);
+    }
+
+    #[test]
+    fn missing_elements() {
+        // SEQUENCE { INTEGER 5, SEQUENCE { INTEGER 2 }, NULL }
+        let other = hex!("300a 020105 3003 020102 0500");
+        let differences = diff(EXAMPLE, &other).unwrap();
+
+        assert_eq!(differences.len(), 3);
+        assert_eq!(differences[0].path, [0]);
+        assert_eq!(differences[0].left.unwrap().value, [1]);
+        assert_eq!(differences[0].right.unwrap().value, [5]);
+
+        assert_eq!(differences[1].path, [1, 1]);
+        assert_eq!(differences[1].left.unwrap().offset, 10u8.into());
+        assert_eq!(differences[1].right, None);
+
+        assert_eq!(differences[2].path, [2]);
+        assert_eq!(differences[2].left, None);
+        assert_eq!(
+            differences[2].right,
+            Some(DiffNode {
+                offset: 10u8.into(),
+                tag: Tag::Null,
+                value: &[],
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_contents() {
+        // Constructed value whose contents are truncated
+        let a = hex!("3003 020201");
+        let b = hex!("3003 020202");
+        let differences = diff(&a, &b).unwrap();
+
+        assert_eq!(differences.len(), 1);
+        assert!(differences[0].path.is_empty());
+    }
+
+    #[test]
+    fn invalid_document() {
+        assert!(diff(EXAMPLE, &hex!("300b 020101")).is_err());
+    }
+}
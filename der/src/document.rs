@@ -7,6 +7,10 @@ use core::convert::{TryFrom, TryInto};
 #[cfg(feature = "pem")]
 use {crate::pem, alloc::string::String};
 
+mod any;
+
+pub use self::any::AnyDocument;
+
 #[cfg(feature = "std")]
 use std::{fs, path::Path};
 
@@ -61,11 +65,7 @@ pub trait Document<'a>: AsRef<[u8]> + Sized + TryFrom<Vec<u8>, Error = Error> {
         Self: pem::PemLabel,
     {
         let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
-
-        if label != Self::TYPE_LABEL {
-            return Err(pem::Error::Label.into());
-        }
-
+        Self::validate_pem_label(label)?;
         der_bytes.try_into()
     }
 
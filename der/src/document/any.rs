@@ -0,0 +1,218 @@
+//! Documents containing a single value of any type.
+
+use crate::{
+    Any, Choice, Decodable, Decoder, Document, Encodable, Encoder, Error, Length, Result, Tag,
+};
+use alloc::vec::Vec;
+use core::{convert::TryFrom, fmt};
+
+#[cfg(feature = "pem")]
+use {crate::pem, alloc::string::String};
+
+/// ASN.1 DER-encoded document containing a single value of any type.
+///
+/// This type provides owned storage for an [`Any`] with the invariant that
+/// the contained document is a single well-formed tag-length-value (TLV)
+/// encoded value. Its contents aren't otherwise validated.
+///
+/// It's intended as a container for values which an owned data model
+/// stores without parsing, e.g. unrecognized algorithm parameters: it
+/// impls [`Decodable`] and [`Encodable`], re-encoding the original bytes
+/// unchanged, and can be decoded as a more specific type later on.
+///
+/// Note that [`Decodable::from_der`] and [`Document::from_der`] are
+/// equivalent for this type.
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct AnyDocument(Vec<u8>);
+
+impl AnyDocument {
+    /// Parse the [`Any`] contained in this [`AnyDocument`].
+    pub fn any(&self) -> Any<'_> {
+        self.decode()
+    }
+
+    /// Get the tag of the contained value.
+    pub fn tag(&self) -> Tag {
+        self.any().tag()
+    }
+
+    /// Borrow the contents octets of the contained value.
+    pub fn value(&self) -> &[u8] {
+        self.any().value()
+    }
+
+    /// Attempt to decode the contained value as the given type.
+    pub fn decode_into<'a, T>(&'a self) -> Result<T>
+    where
+        T: Decodable<'a>,
+    {
+        T::from_der(self.as_ref())
+    }
+
+    /// Decode a PEM-encoded document with any type label, returning the
+    /// label along with the document.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn from_pem(s: &str) -> Result<(&str, Self)> {
+        let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
+        Ok((label, Self::try_from(der_bytes)?))
+    }
+
+    /// Encode this document as PEM with the given type label.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn to_pem(&self, label: &str, line_ending: pem::LineEnding) -> Result<String> {
+        Ok(pem::encode_string(label, line_ending, self.as_ref())?)
+    }
+}
+
+impl<'a> Document<'a> for AnyDocument {
+    type Message = Any<'a>;
+    const SENSITIVE: bool = false;
+}
+
+impl AsRef<[u8]> for AnyDocument {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl<'a> Choice<'a> for AnyDocument {
+    fn can_decode(_: Tag) -> bool {
+        true
+    }
+}
+
+impl<'a> Decodable<'a> for AnyDocument {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        Self::try_from(decoder.any()?)
+    }
+}
+
+impl Encodable for AnyDocument {
+    fn encoded_len(&self) -> Result<Length> {
+        Length::try_from(self.0.len())
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(self.as_ref())
+    }
+}
+
+impl<'a> From<&'a AnyDocument> for Any<'a> {
+    fn from(doc: &'a AnyDocument) -> Any<'a> {
+        doc.any()
+    }
+}
+
+impl TryFrom<Any<'_>> for AnyDocument {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<AnyDocument> {
+        Self::from_msg(&any)
+    }
+}
+
+impl TryFrom<&[u8]> for AnyDocument {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::try_from(bytes.to_vec())
+    }
+}
+
+impl TryFrom<Vec<u8>> for AnyDocument {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self> {
+        // Ensure document is well-formed
+        Any::from_der(bytes.as_slice())?;
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::Debug for AnyDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnyDocument").field(&self.any()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AnyDocument;
+    use crate::{asn1::Null, Decoder, Document, Encodable, ErrorKind, Tag, TagNumber};
+    use core::convert::TryFrom;
+    use hex_literal::hex;
+
+    // SEQUENCE { INTEGER 1, BOOLEAN TRUE }
+    const EXAMPLE: &[u8] = &hex!("3006 020101 0101ff");
+
+    #[test]
+    fn from_der() {
+        let doc = AnyDocument::try_from(EXAMPLE).unwrap();
+        assert_eq!(doc.as_der(), EXAMPLE);
+        assert_eq!(doc.tag(), Tag::Sequence);
+        assert_eq!(doc.value(), &EXAMPLE[2..]);
+        assert_eq!(doc.to_vec().unwrap(), EXAMPLE);
+    }
+
+    #[test]
+    fn reject_malformed() {
+        // Truncated value
+        assert!(AnyDocument::try_from(&EXAMPLE[..7]).is_err());
+
+        // Trailing data
+        let err = AnyDocument::try_from(&hex!("0500 00")[..]).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TrailingData {
+                decoded: 2u8.into(),
+                remaining: 1u8.into()
+            }
+        );
+    }
+
+    #[test]
+    fn decode_into() {
+        let doc = AnyDocument::try_from(&hex!("0500")[..]).unwrap();
+        assert_eq!(doc.decode_into::<Null>().unwrap(), Null);
+        assert!(doc.decode_into::<bool>().is_err());
+    }
+
+    #[test]
+    fn optional_field() {
+        // SEQUENCE { INTEGER 1, [0] IMPLICIT OCTET STRING }
+        let bytes = hex!("3007 020101 8002 abcd");
+        let mut decoder = Decoder::new(&bytes);
+
+        let params = decoder
+            .sequence(|decoder| {
+                decoder.decode::<u8>()?;
+                decoder.decode::<Option<AnyDocument>>()
+            })
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(params.as_der(), &bytes[5..]);
+        assert_eq!(
+            params.tag(),
+            Tag::ContextSpecific {
+                constructed: false,
+                number: TagNumber::new(0)
+            }
+        );
+    }
+
+    #[cfg(feature = "pem")]
+    #[test]
+    fn pem_passthrough() {
+        use crate::pem::LineEnding;
+
+        let pem = "-----BEGIN EXAMPLE-----\nMAYCAQEBAf8=\n-----END EXAMPLE-----\n";
+        let (label, doc) = AnyDocument::from_pem(pem).unwrap();
+        assert_eq!(label, "EXAMPLE");
+        assert_eq!(doc.as_der(), EXAMPLE);
+        assert_eq!(doc.to_pem(label, LineEnding::LF).unwrap(), pem);
+    }
+}
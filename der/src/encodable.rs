@@ -1,15 +1,15 @@
 //! Trait definition for [`Encodable`].
 
-use crate::{EncodeValue, Encoder, Header, Length, Result, Tagged};
+use crate::{EncodeValue, Encoder, ErrorKind, Header, Length, Result, ReverseEncoder, Tagged};
+use core::convert::TryFrom;
+
+#[cfg(feature = "std")]
+use std::io;
 
 #[cfg(feature = "alloc")]
 use {
-    crate::ErrorKind,
     alloc::vec::Vec,
-    core::{
-        convert::{TryFrom, TryInto},
-        iter,
-    },
+    core::{convert::TryInto, iter},
 };
 
 /// Encoding trait.
@@ -20,12 +20,93 @@ pub trait Encodable {
     /// Encode this value as ASN.1 DER using the provided [`Encoder`].
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()>;
 
+    /// Encode this value as ASN.1 DER back to front using the provided
+    /// [`ReverseEncoder`].
+    ///
+    /// The default implementation encodes it front to back into space
+    /// reserved for it using [`Encodable::encode`]. Types containing nested
+    /// fields should override it so messages containing them are encoded
+    /// in a single pass.
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        encoder.encode_forward(self)
+    }
+
     /// Encode this value to the provided byte slice, returning a sub-slice
     /// containing the encoded message.
+    ///
+    /// The message is encoded in a single pass using a [`ReverseEncoder`].
     fn encode_to_slice<'a>(&self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
-        let mut encoder = Encoder::new(buf);
+        let expected_len = usize::try_from(self.encoded_len()?)?;
+        let buf = buf.get_mut(..expected_len).ok_or(ErrorKind::Overlength)?;
+
+        let mut encoder = ReverseEncoder::new(buf);
+        encoder.encode(self)?;
+        let encoded = encoder.finish()?;
+
+        if encoded.len() != expected_len {
+            return Err(ErrorKind::Underlength {
+                expected: Length::try_from(expected_len)?,
+                actual: Length::try_from(encoded.len())?,
+            }
+            .into());
+        }
+
+        Ok(encoded)
+    }
+
+    /// Encode this value as ASN.1 DER, feeding the encoded bytes into the
+    /// provided digest as they are produced.
+    ///
+    /// Returns the number of bytes written to the digest.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    fn encode_to_digest<D>(&self, digest: &mut D) -> Result<Length>
+    where
+        Self: Sized,
+        D: digest::Update,
+    {
+        let expected_len = self.encoded_len()?;
+        let mut encoder = Encoder::new_digest(digest);
         self.encode(&mut encoder)?;
-        encoder.finish()
+        let actual_len = encoder.position();
+
+        if expected_len != actual_len {
+            return Err(ErrorKind::Underlength {
+                expected: expected_len,
+                actual: actual_len,
+            }
+            .into());
+        }
+
+        Ok(actual_len)
+    }
+
+    /// Encode this value as ASN.1 DER, writing the encoded bytes to the
+    /// provided [`io::Write`] sink as they are produced.
+    ///
+    /// Returns the number of bytes written to the sink. If encoding fails,
+    /// a partial message may have been written.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn encode_to_writer<W>(&self, writer: &mut W) -> Result<Length>
+    where
+        Self: Sized,
+        W: io::Write,
+    {
+        let expected_len = self.encoded_len()?;
+        let mut encoder = Encoder::new_writer(writer);
+        self.encode(&mut encoder)?;
+        let actual_len = encoder.position();
+
+        if expected_len != actual_len {
+            return Err(ErrorKind::Underlength {
+                expected: expected_len,
+                actual: actual_len,
+            }
+            .into());
+        }
+
+        Ok(actual_len)
     }
 
     /// Encode this message as ASN.1 DER, appending it to the provided
@@ -34,11 +115,12 @@ pub trait Encodable {
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     fn encode_to_vec(&self, buf: &mut Vec<u8>) -> Result<Length> {
         let expected_len = usize::try_from(self.encoded_len()?)?;
+        let start = buf.len();
         buf.reserve(expected_len);
         buf.extend(iter::repeat(0).take(expected_len));
 
-        let mut encoder = Encoder::new(buf);
-        self.encode(&mut encoder)?;
+        let mut encoder = ReverseEncoder::new(&mut buf[start..]);
+        encoder.encode(self)?;
         let actual_len = encoder.finish()?.len();
 
         if expected_len != actual_len {
@@ -68,7 +150,7 @@ where
 {
     /// Compute the length of this value in bytes when encoded as ASN.1 DER.
     fn encoded_len(&self) -> Result<Length> {
-        self.value_len().and_then(|len| len.for_tlv())
+        self.value_len()?.tlv_len(T::TAG)
     }
 
     /// Encode this value as ASN.1 DER using the provided [`Encoder`].
@@ -76,4 +158,10 @@ where
         Header::new(T::TAG, self.value_len()?)?.encode(encoder)?;
         self.encode_value(encoder)
     }
+
+    /// Encode this value as ASN.1 DER back to front using the provided
+    /// [`ReverseEncoder`].
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        encoder.tlv(T::TAG, |encoder| self.encode_value_reverse(encoder))
+    }
 }
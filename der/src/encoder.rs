@@ -4,15 +4,24 @@ use crate::{
     asn1::*, Encodable, EncodeValue, Error, ErrorKind, Header, Length, Result, Tag, TagMode,
     TagNumber, Tagged,
 };
-use core::convert::{TryFrom, TryInto};
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io;
 
 /// DER encoder.
 #[derive(Debug)]
 pub struct Encoder<'a> {
-    /// Buffer into which DER-encoded message is written
-    bytes: Option<&'a mut [u8]>,
+    /// Output to which the DER-encoded message is written
+    bytes: Option<Output<'a>>,
 
-    /// Total number of bytes written to buffer so far
+    /// Total number of bytes written to output so far
     position: Length,
 }
 
@@ -20,7 +29,49 @@ impl<'a> Encoder<'a> {
     /// Create a new encoder with the given byte slice as a backing buffer.
     pub fn new(bytes: &'a mut [u8]) -> Self {
         Self {
-            bytes: Some(bytes),
+            bytes: Some(Output::Buffer(bytes)),
+            position: Length::ZERO,
+        }
+    }
+
+    /// Create a new encoder which feeds the encoded message into the given
+    /// digest as it is produced, rather than writing it to a buffer.
+    ///
+    /// This allows computing e.g. a fingerprint or signature input over a
+    /// message without materializing its full DER encoding in memory.
+    ///
+    /// Note that [`Encoder::finish`] returns an empty slice for encoders
+    /// constructed this way, as no output is buffered.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    pub fn new_digest<D: digest::Update>(digest: &'a mut D) -> Self {
+        Self::new_sink(digest)
+    }
+
+    /// Create a new encoder which writes the encoded message to the given
+    /// [`io::Write`] sink (e.g. a file or socket) as it is produced, rather
+    /// than to a buffer.
+    ///
+    /// This allows serializing large messages without materializing their
+    /// full DER encoding in memory. Writes are not buffered, so consider
+    /// wrapping unbuffered sinks in an [`io::BufWriter`]. If encoding fails,
+    /// a partial message may have been written to the sink.
+    ///
+    /// Note that [`Encoder::finish`] returns an empty slice for encoders
+    /// constructed this way, as no output is buffered.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn new_writer<W: io::Write>(writer: &'a mut W) -> Self {
+        Self {
+            bytes: Some(Output::Writer(writer)),
+            position: Length::ZERO,
+        }
+    }
+
+    /// Create a new encoder which writes to the given [`Sink`].
+    pub(crate) fn new_sink(sink: &'a mut dyn Sink) -> Self {
+        Self {
+            bytes: Some(Output::Sink(sink)),
             position: Length::ZERO,
         }
     }
@@ -37,6 +88,28 @@ impl<'a> Encoder<'a> {
         })
     }
 
+    /// Encode a value with `IMPLICIT` tagging, i.e. its value encoded as
+    /// usual, but under the provided [`Tag`] rather than `T::TAG`.
+    ///
+    /// The tag may be of any class, e.g. `[APPLICATION 1] IMPLICIT INTEGER`
+    /// is encoded with `TagNumber::new(1).application(false)`. It must be
+    /// constructed if and only if `T::TAG` is, as that's determined by the
+    /// encoding of the value.
+    pub fn encode_tagged<T>(&mut self, tag: Tag, value: &T) -> Result<()>
+    where
+        T: EncodeValue + Tagged,
+    {
+        if tag.is_constructed() != T::TAG.is_constructed() {
+            return self.error(ErrorKind::UnexpectedTag {
+                expected: None,
+                actual: tag,
+            });
+        }
+
+        Header::new(tag, value.value_len()?).and_then(|header| header.encode(self))?;
+        value.encode_value(self)
+    }
+
     /// Return an error with the given [`ErrorKind`], annotating it with
     /// context about where the error occurred.
     // TODO(tarcieri): change return type to `Error`
@@ -57,6 +130,12 @@ impl<'a> Encoder<'a> {
         self.bytes.is_none()
     }
 
+    /// Get the number of bytes written so far.
+    #[cfg(any(feature = "digest", feature = "std"))]
+    pub(crate) fn position(&self) -> Length {
+        self.position
+    }
+
     /// Finish encoding to the buffer, returning a slice containing the data
     /// written to the buffer.
     pub fn finish(self) -> Result<&'a [u8]> {
@@ -64,7 +143,12 @@ impl<'a> Encoder<'a> {
         let range = ..usize::try_from(self.position)?;
 
         match self.bytes {
-            Some(bytes) => bytes.get(range).ok_or_else(|| ErrorKind::Truncated.at(pos)),
+            Some(Output::Buffer(bytes)) => {
+                bytes.get(range).ok_or_else(|| ErrorKind::Truncated.at(pos))
+            }
+            Some(Output::Sink(_)) => Ok(&[]),
+            #[cfg(feature = "std")]
+            Some(Output::Writer(_)) => Ok(&[]),
             None => Err(ErrorKind::Failed.at(pos)),
         }
     }
@@ -77,6 +161,14 @@ impl<'a> Encoder<'a> {
             .and_then(|value| self.encode(&value))
     }
 
+    /// Encode the provided value as an ASN.1 `BMPString`.
+    pub fn bmp_string(&mut self, value: impl TryInto<BmpString<'a>>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::BmpString))
+            .and_then(|value| self.encode(&value))
+    }
+
     /// Encode a `CONTEXT-SPECIFIC` field with `EXPLICIT` tagging.
     pub fn context_specific<T>(
         &mut self,
@@ -95,6 +187,30 @@ impl<'a> Encoder<'a> {
         .encode(self)
     }
 
+    /// Encode the provided value as an ASN.1 `DATE`
+    pub fn date(&mut self, value: impl TryInto<Date>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::Date))
+            .and_then(|value| self.encode(&value))
+    }
+
+    /// Encode the provided value as an ASN.1 `DATE-TIME`
+    pub fn date_and_time(&mut self, value: impl TryInto<DateAndTime>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::DateTime))
+            .and_then(|value| self.encode(&value))
+    }
+
+    /// Encode the provided value as an ASN.1 `GeneralString`
+    pub fn general_string(&mut self, value: impl TryInto<GeneralString<'a>>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::GeneralString))
+            .and_then(|value| self.encode(&value))
+    }
+
     /// Encode the provided value as an ASN.1 `GeneralizedTime`
     pub fn generalized_time(&mut self, value: impl TryInto<GeneralizedTime>) -> Result<()> {
         value
@@ -116,6 +232,14 @@ impl<'a> Encoder<'a> {
         self.encode(&Null)
     }
 
+    /// Encode the provided value as an ASN.1 `NumericString`
+    pub fn numeric_string(&mut self, value: impl TryInto<NumericString<'a>>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::NumericString))
+            .and_then(|value| self.encode(&value))
+    }
+
     /// Encode the provided value as an ASN.1 `OCTET STRING`
     pub fn octet_string(&mut self, value: impl TryInto<OctetString<'a>>) -> Result<()> {
         value
@@ -142,6 +266,14 @@ impl<'a> Encoder<'a> {
             .and_then(|value| self.encode(&value))
     }
 
+    /// Encode the provided value as an ASN.1 `RELATIVE-OID`
+    pub fn relative_oid(&mut self, value: impl TryInto<RelativeOid<'a>>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::RelativeOid))
+            .and_then(|value| self.encode(&value))
+    }
+
     /// Encode an ASN.1 `SEQUENCE` of the given length.
     ///
     /// Spawns a nested [`Encoder`] which is expected to be exactly the
@@ -152,14 +284,98 @@ impl<'a> Encoder<'a> {
     {
         Header::new(Tag::Sequence, length).and_then(|header| header.encode(self))?;
 
+        if let Some(output) = self.bytes.as_mut().and_then(Output::reborrow_stream) {
+            let mut nested_encoder = Encoder {
+                bytes: Some(output),
+                position: Length::ZERO,
+            };
+            let result = f(&mut nested_encoder);
+            let nested_len = nested_encoder.position;
+            let nested_failed = nested_encoder.is_failed();
+
+            if let Err(e) = result {
+                self.bytes.take();
+                return Err(e.nested(self.position));
+            }
+
+            if nested_failed || nested_len != length {
+                let length = if nested_failed {
+                    None
+                } else {
+                    Some(nested_len)
+                };
+
+                return self.error(ErrorKind::Length {
+                    tag: Tag::Sequence,
+                    length,
+                });
+            }
+
+            self.position = (self.position + length).or_else(|e| self.error(e.kind()))?;
+            return Ok(());
+        }
+
         let mut nested_encoder = Encoder::new(self.reserve(length)?);
         f(&mut nested_encoder)?;
 
-        if nested_encoder.finish()?.len() == length.try_into()? {
+        let nested_len = Length::try_from(nested_encoder.finish()?.len())?;
+
+        if nested_len == length {
             Ok(())
         } else {
-            self.error(ErrorKind::Length { tag: Tag::Sequence })
+            self.error(ErrorKind::Length {
+                tag: Tag::Sequence,
+                length: Some(nested_len),
+            })
+        }
+    }
+
+    /// Encode an ASN.1 `SEQUENCE` whose length is computed by buffering the
+    /// output of the provided function.
+    ///
+    /// Unlike [`Encoder::sequence`], this doesn't require knowing the length
+    /// of the nested fields in advance, at the cost of a heap allocation.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn sequence_buffered<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Encoder<'_>) -> Result<()>,
+    {
+        let mut buffer = VecSink(Vec::new());
+        let mut nested_encoder = Encoder::new_sink(&mut buffer);
+
+        if let Err(e) = f(&mut nested_encoder) {
+            self.bytes.take();
+            return Err(e.nested(self.position));
         }
+
+        let length = nested_encoder.position;
+        Header::new(Tag::Sequence, length).and_then(|header| header.encode(self))?;
+        self.bytes(&buffer.0)
+    }
+
+    /// Encode the provided value as an ASN.1 `TeletexString`
+    pub fn teletex_string(&mut self, value: impl TryInto<TeletexString<'a>>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::TeletexString))
+            .and_then(|value| self.encode(&value))
+    }
+
+    /// Encode the provided value as an ASN.1 `TIME`
+    pub fn time(&mut self, value: impl TryInto<Time<'a>>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::Time))
+            .and_then(|value| self.encode(&value))
+    }
+
+    /// Encode the provided value as an ASN.1 `TIME-OF-DAY`
+    pub fn time_of_day(&mut self, value: impl TryInto<TimeOfDay>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::TimeOfDay))
+            .and_then(|value| self.encode(&value))
     }
 
     /// Encode the provided value as an ASN.1 `UTCTime`
@@ -170,6 +386,14 @@ impl<'a> Encoder<'a> {
             .and_then(|value| self.encode(&value))
     }
 
+    /// Encode the provided value as an ASN.1 `UniversalString`
+    pub fn universal_string(&mut self, value: impl TryInto<UniversalString<'a>>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::UniversalString))
+            .and_then(|value| self.encode(&value))
+    }
+
     /// Encode the provided value as an ASN.1 `Utf8String`
     pub fn utf8_string(&mut self, value: impl TryInto<Utf8String<'a>>) -> Result<()> {
         value
@@ -178,6 +402,14 @@ impl<'a> Encoder<'a> {
             .and_then(|value| self.encode(&value))
     }
 
+    /// Encode the provided value as an ASN.1 `VisibleString`
+    pub fn visible_string(&mut self, value: impl TryInto<VisibleString<'a>>) -> Result<()> {
+        value
+            .try_into()
+            .map_err(|_| self.value_error(Tag::VisibleString))
+            .and_then(|value| self.encode(&value))
+    }
+
     /// Reserve a portion of the internal buffer, updating the internal cursor
     /// position and returning a mutable slice.
     fn reserve(&mut self, len: impl TryInto<Length>) -> Result<&mut [u8]> {
@@ -201,7 +433,10 @@ impl<'a> Encoder<'a> {
         // Unfortunately tainting the buffer on error is tricky to do when
         // potentially holding a reference to the buffer, and failure to taint
         // it would not uphold the invariant that any errors should taint it.
-        let slice = &mut self.bytes.as_mut().expect("DER encoder tainted")[range];
+        let slice = match self.bytes.as_mut().expect("DER encoder tainted") {
+            Output::Buffer(bytes) => &mut bytes[range],
+            _ => unreachable!("sinks have no buffer to reserve"),
+        };
         *position = end;
 
         Ok(slice)
@@ -209,28 +444,39 @@ impl<'a> Encoder<'a> {
 
     /// Encode a single byte into the backing buffer.
     pub(crate) fn byte(&mut self, byte: u8) -> Result<()> {
-        match self.reserve(1u8)?.first_mut() {
-            Some(b) => {
-                *b = byte;
-                Ok(())
-            }
-            None => self.error(ErrorKind::Truncated),
-        }
+        self.bytes(&[byte])
     }
 
     /// Encode the provided byte slice into the backing buffer.
     pub(crate) fn bytes(&mut self, slice: &[u8]) -> Result<()> {
+        let result = match self.bytes.as_mut() {
+            Some(Output::Sink(sink)) => Some(sink.write(slice)),
+            #[cfg(feature = "std")]
+            Some(Output::Writer(writer)) => Some(writer.write_all(slice).map_err(Error::from)),
+            _ => None,
+        };
+
+        if let Some(result) = result {
+            if let Err(e) = result {
+                return self.error(e.kind());
+            }
+
+            let len = Length::try_from(slice.len()).or_else(|_| self.error(ErrorKind::Overflow))?;
+            self.position = (self.position + len).or_else(|e| self.error(e.kind()))?;
+            return Ok(());
+        }
+
         self.reserve(slice.len())?.copy_from_slice(slice);
         Ok(())
     }
 
     /// Get the size of the buffer in bytes.
     fn buffer_len(&self) -> Result<Length> {
-        self.bytes
-            .as_ref()
-            .map(|bytes| bytes.len())
-            .ok_or_else(|| ErrorKind::Failed.at(self.position))
-            .and_then(TryInto::try_into)
+        match self.bytes.as_ref() {
+            Some(Output::Buffer(bytes)) => bytes.len().try_into(),
+            Some(_) => Ok(Length::MAX),
+            None => Err(ErrorKind::Failed.at(self.position)),
+        }
     }
 
     /// Get the number of bytes still remaining in the buffer.
@@ -244,6 +490,69 @@ impl<'a> Encoder<'a> {
     }
 }
 
+/// Output of an [`Encoder`].
+enum Output<'a> {
+    /// Fixed-size backing buffer.
+    Buffer(&'a mut [u8]),
+
+    /// Sink which consumes encoded bytes as they are produced.
+    Sink(&'a mut dyn Sink),
+
+    /// I/O sink to which encoded bytes are written as they are produced.
+    #[cfg(feature = "std")]
+    Writer(&'a mut dyn io::Write),
+}
+
+impl Output<'_> {
+    /// Reborrow a streaming output for use by a nested [`Encoder`], or
+    /// return `None` for a buffer.
+    fn reborrow_stream(&mut self) -> Option<Output<'_>> {
+        match self {
+            Output::Buffer(_) => None,
+            Output::Sink(sink) => Some(Output::Sink(&mut **sink)),
+            #[cfg(feature = "std")]
+            Output::Writer(writer) => Some(Output::Writer(&mut **writer)),
+        }
+    }
+}
+
+impl fmt::Debug for Output<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Output::Buffer(bytes) => f.debug_tuple("Buffer").field(bytes).finish(),
+            Output::Sink(_) => f.debug_tuple("Sink").finish(),
+            #[cfg(feature = "std")]
+            Output::Writer(_) => f.debug_tuple("Writer").finish(),
+        }
+    }
+}
+
+/// Object-safe consumer of encoded bytes.
+pub(crate) trait Sink {
+    /// Write the given bytes to the sink.
+    fn write(&mut self, bytes: &[u8]) -> Result<()>;
+}
+
+/// [`Sink`] which buffers encoded bytes in a [`Vec`].
+#[cfg(feature = "alloc")]
+struct VecSink(Vec<u8>);
+
+#[cfg(feature = "alloc")]
+impl Sink for VecSink {
+    fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        self.0.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<D: digest::Update> Sink for D {
+    fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        self.update(bytes);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use hex_literal::hex;
@@ -283,4 +592,127 @@ mod tests {
 
         assert_eq!(EXPECTED_BYTES, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn encode_tagged() {
+        // `[APPLICATION 1] IMPLICIT INTEGER` and
+        // `[PRIVATE 100] IMPLICIT SEQUENCE OF INTEGER`
+        const EXPECTED_BYTES: &[u8] = &hex!("410105FF6406020101020102");
+
+        let mut buf = [0u8; EXPECTED_BYTES.len()];
+        let mut encoder = Encoder::new(&mut buf);
+        encoder
+            .encode_tagged(TagNumber::new(1).application(false), &5u8)
+            .unwrap();
+        encoder
+            .encode_tagged(TagNumber::new(100).private(true), &[1u8, 2])
+            .unwrap();
+        assert_eq!(EXPECTED_BYTES, encoder.finish().unwrap());
+
+        // The tag must have the same form as the value's encoding
+        let tag = TagNumber::new(0).context_specific(true);
+        let mut encoder = Encoder::new(&mut buf);
+        assert_eq!(
+            encoder.encode_tagged(tag, &5u8).err().unwrap().kind(),
+            ErrorKind::UnexpectedTag {
+                expected: None,
+                actual: tag
+            }
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sequence_buffered() {
+        // Nested `SEQUENCE` containing a `BIT STRING` and `OCTET STRING`
+        const EXPECTED_BYTES: &[u8] = &hex!("3009030200f00403010203");
+
+        let bit_string = BitString::new(&[0xf0]).unwrap();
+        let octet_string = crate::asn1::OctetString::new(&[1, 2, 3]).unwrap();
+
+        let mut buf = [0u8; EXPECTED_BYTES.len()];
+        let mut encoder = Encoder::new(&mut buf);
+        encoder
+            .sequence_buffered(|nested| {
+                nested.encode(&bit_string)?;
+                nested.encode(&octet_string)
+            })
+            .unwrap();
+
+        assert_eq!(EXPECTED_BYTES, encoder.finish().unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sequence_buffered_overlength() {
+        let octet_string = crate::asn1::OctetString::new(&[1, 2, 3]).unwrap();
+
+        let mut buf = [0u8; 4];
+        let mut encoder = Encoder::new(&mut buf);
+        let err = encoder
+            .sequence_buffered(|nested| nested.encode(&octet_string))
+            .err()
+            .unwrap();
+
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn digest_matches_buffered_encoding() {
+        use sha2::{Digest, Sha256};
+
+        // Nested `SEQUENCE` containing a `BIT STRING` and `OCTET STRING`
+        const EXPECTED_BYTES: &[u8] = &hex!("3009030200f00403010203");
+
+        let bit_string = BitString::new(&[0xf0]).unwrap();
+        let octet_string = crate::asn1::OctetString::new(&[1, 2, 3]).unwrap();
+        let mut hasher = Sha256::new();
+        let mut encoder = Encoder::new_digest(&mut hasher);
+        encoder
+            .sequence(Length::new(9), |nested| {
+                nested.encode(&bit_string)?;
+                nested.encode(&octet_string)
+            })
+            .unwrap();
+        assert_eq!(encoder.finish().unwrap(), &[]);
+
+        assert_eq!(hasher.finalize(), Sha256::digest(EXPECTED_BYTES));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn writer_matches_buffered_encoding() {
+        // Nested `SEQUENCE` containing a `BIT STRING` and `OCTET STRING`
+        const EXPECTED_BYTES: &[u8] = &hex!("3009030200f00403010203");
+
+        let bit_string = BitString::new(&[0xf0]).unwrap();
+        let octet_string = crate::asn1::OctetString::new(&[1, 2, 3]).unwrap();
+        let mut output = std::vec::Vec::new();
+        let mut encoder = Encoder::new_writer(&mut output);
+        encoder
+            .sequence(Length::new(9), |nested| {
+                nested.encode(&bit_string)?;
+                nested.encode(&octet_string)
+            })
+            .unwrap();
+        assert_eq!(encoder.finish().unwrap(), &[]);
+
+        assert_eq!(output, EXPECTED_BYTES);
+        assert_eq!(
+            octet_string.encode_to_writer(&mut output).unwrap(),
+            Length::new(5)
+        );
+        assert_eq!(&output[EXPECTED_BYTES.len()..], &EXPECTED_BYTES[6..]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn writer_error() {
+        let mut buf = [0u8; 2];
+        let mut writer = &mut buf[..];
+        let err = 0x1234u16.encode_to_writer(&mut writer).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Io(std::io::ErrorKind::WriteZero));
+        assert_eq!(err.position(), Some(Length::new(2)));
+    }
 }
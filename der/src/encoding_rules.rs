@@ -0,0 +1,34 @@
+//! ASN.1 encoding rules.
+
+/// ASN.1 encoding rules accepted by a [`Decoder`][crate::Decoder].
+///
+/// DER is a restricted subset of BER with exactly one encoding for each
+/// value. Some producers (e.g. of CMS/PKCS#7 messages) emit BER instead,
+/// which can be opted into when decoding their output.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum EncodingRules {
+    /// Basic Encoding Rules as described in X.690 Section 8.
+    ///
+    /// In addition to DER, the following are accepted:
+    ///
+    /// - indefinite lengths of constructed values, terminated by
+    ///   end-of-contents octets (X.690 Section 8.1.3.6)
+    /// - definite lengths not encoded with the minimum number of octets
+    ///
+    /// Constructed encodings of string types, which are split into several
+    /// segments, can't be borrowed as a single slice. Those of `OCTET STRING`
+    /// and `BIT STRING` can be reassembled into a byte vector with
+    /// [`Decoder::octet_string_vec`][crate::Decoder::octet_string_vec] and
+    /// [`Decoder::bit_string_vec`][crate::Decoder::bit_string_vec]
+    /// (requires `alloc` feature), but are otherwise not supported.
+    Ber,
+
+    /// Distinguished Encoding Rules as described in X.690 Section 10.
+    Der,
+}
+
+impl Default for EncodingRules {
+    fn default() -> EncodingRules {
+        EncodingRules::Der
+    }
+}
@@ -43,6 +43,12 @@ impl Error {
         self.position
     }
 
+    /// Get a small integer identifying the class of this error, see
+    /// [`ErrorKind::kind_code`].
+    pub fn kind_code(self) -> u16 {
+        self.kind.kind_code()
+    }
+
     /// For errors occurring inside of a nested message, extend the position
     /// count by the location where the nested message occurs.
     pub fn nested(self, nested_position: Length) -> Self {
@@ -61,7 +67,7 @@ impl fmt::Display for Error {
         write!(f, "{}", self.kind)?;
 
         if let Some(pos) = self.position {
-            write!(f, " at DER byte {}", pos)?;
+            write!(f, " at DER byte {}", u64::from(pos))?;
         }
 
         Ok(())
@@ -141,6 +147,17 @@ pub enum ErrorKind {
         tag: Tag,
     },
 
+    /// Collection contains more elements than the [`Decoder`] element limit.
+    ///
+    /// [`Decoder`]: crate::Decoder
+    ElementLimit {
+        /// Tag of the collection being decoded.
+        tag: Tag,
+
+        /// Maximum number of elements allowed.
+        limit: usize,
+    },
+
     /// This error indicates a previous DER parsing operation resulted in
     /// an error and tainted the state of a `Decoder` or `Encoder`.
     ///
@@ -162,6 +179,9 @@ pub enum ErrorKind {
     Length {
         /// Tag of the value being decoded.
         tag: Tag,
+
+        /// Offending length, if known.
+        length: Option<Length>,
     },
 
     /// Message is not canonically encoded.
@@ -267,6 +287,47 @@ impl ErrorKind {
     pub fn at(self, position: Length) -> Error {
         Error::new(self, position)
     }
+
+    /// Get a small integer identifying the class of this error, i.e. its
+    /// variant, independent of any values it carries.
+    ///
+    /// This allows fuzzers and telemetry to deduplicate and aggregate errors
+    /// without matching on their [`fmt::Display`] output. Codes are stable:
+    /// a variant's code never changes, and codes of removed variants are not
+    /// reused. Variants which are only available with some crate features
+    /// have codes regardless of whether the features are enabled.
+    pub fn kind_code(self) -> u16 {
+        match self {
+            ErrorKind::DateTime => 1,
+            ErrorKind::DuplicateField { .. } => 2,
+            ErrorKind::ElementLimit { .. } => 3,
+            ErrorKind::Failed => 4,
+            #[cfg(feature = "std")]
+            ErrorKind::FileNotFound => 5,
+            #[cfg(feature = "std")]
+            ErrorKind::Io(_) => 6,
+            ErrorKind::Length { .. } => 7,
+            ErrorKind::Noncanonical { .. } => 8,
+            ErrorKind::MalformedOid => 9,
+            ErrorKind::Ordering => 10,
+            ErrorKind::Overflow => 11,
+            ErrorKind::Overlength => 12,
+            #[cfg(feature = "pem")]
+            ErrorKind::Pem(_) => 13,
+            #[cfg(feature = "std")]
+            ErrorKind::PermissionDenied => 14,
+            ErrorKind::TrailingData { .. } => 15,
+            ErrorKind::Truncated => 16,
+            ErrorKind::Underlength { .. } => 17,
+            ErrorKind::UnexpectedTag { .. } => 18,
+            #[cfg(feature = "oid")]
+            ErrorKind::UnknownOid { .. } => 19,
+            ErrorKind::UnknownTag { .. } => 20,
+            ErrorKind::UnknownTagMode => 21,
+            ErrorKind::Utf8(_) => 22,
+            ErrorKind::Value { .. } => 23,
+        }
+    }
 }
 
 impl fmt::Display for ErrorKind {
@@ -274,12 +335,23 @@ impl fmt::Display for ErrorKind {
         match self {
             ErrorKind::DateTime => write!(f, "date/time error"),
             ErrorKind::DuplicateField { tag } => write!(f, "duplicate field for {}", tag),
+            ErrorKind::ElementLimit { tag, limit } => {
+                write!(f, "{} has more than {} elements", tag, limit)
+            }
             ErrorKind::Failed => write!(f, "operation failed"),
             #[cfg(feature = "std")]
             ErrorKind::FileNotFound => f.write_str("file not found"),
             #[cfg(feature = "std")]
             ErrorKind::Io(err) => write!(f, "I/O error: {:?}", err),
-            ErrorKind::Length { tag } => write!(f, "incorrect length for {}", tag),
+            ErrorKind::Length { tag, length } => {
+                write!(f, "incorrect length for {}", tag)?;
+
+                if let Some(length) = length {
+                    write!(f, ": {}", length)?;
+                }
+
+                Ok(())
+            }
             ErrorKind::Noncanonical { tag } => {
                 write!(f, "ASN.1 {} not canonically encoded as DER", tag)
             }
@@ -294,7 +366,7 @@ impl fmt::Display for ErrorKind {
             ErrorKind::TrailingData { decoded, remaining } => {
                 write!(
                     f,
-                    "trailing data at end of DER message: decoded {} bytes, {} bytes remaining",
+                    "trailing data at end of DER message: decoded {}, {} remaining",
                     decoded, remaining
                 )
             }
@@ -326,3 +398,72 @@ impl fmt::Display for ErrorKind {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorKind;
+    use crate::{Length, Tag};
+
+    #[test]
+    fn kind_code() {
+        let kinds = [
+            ErrorKind::DateTime,
+            ErrorKind::DuplicateField { tag: Tag::Boolean },
+            ErrorKind::ElementLimit {
+                tag: Tag::Sequence,
+                limit: 1,
+            },
+            ErrorKind::Failed,
+            ErrorKind::Length {
+                tag: Tag::Sequence,
+                length: None,
+            },
+            ErrorKind::Noncanonical { tag: Tag::Integer },
+            ErrorKind::MalformedOid,
+            ErrorKind::Ordering,
+            ErrorKind::Overflow,
+            ErrorKind::Overlength,
+            ErrorKind::TrailingData {
+                decoded: Length::ONE,
+                remaining: Length::ONE,
+            },
+            ErrorKind::Truncated,
+            ErrorKind::Underlength {
+                expected: Length::ONE,
+                actual: Length::ZERO,
+            },
+            ErrorKind::UnexpectedTag {
+                expected: None,
+                actual: Tag::Null,
+            },
+            ErrorKind::UnknownTag { byte: 0xff },
+            ErrorKind::UnknownTagMode,
+            ErrorKind::Value { tag: Tag::Boolean },
+        ];
+
+        for (i, kind) in kinds.iter().enumerate() {
+            assert!(kinds[..i]
+                .iter()
+                .all(|other| other.kind_code() != kind.kind_code()));
+        }
+
+        // Codes don't depend on the values carried by the error
+        let length = |length| ErrorKind::Length {
+            tag: Tag::Integer,
+            length,
+        };
+        assert_eq!(
+            length(None).kind_code(),
+            length(Some(Length::ONE)).kind_code()
+        );
+        assert_eq!(
+            ErrorKind::Truncated.at(Length::ONE).kind_code(),
+            ErrorKind::Truncated.at(Length::ZERO).kind_code()
+        );
+
+        // Codes are stable
+        assert_eq!(ErrorKind::DateTime.kind_code(), 1);
+        assert_eq!(ErrorKind::Truncated.kind_code(), 16);
+        assert_eq!(ErrorKind::Value { tag: Tag::Null }.kind_code(), 23);
+    }
+}
@@ -21,6 +21,27 @@ impl Header {
         let length = length.try_into().map_err(|_| ErrorKind::Overflow)?;
         Ok(Self { tag, length })
     }
+
+    /// Get the length of a tag-length-value (TLV) field whose value has the
+    /// given length, i.e. including its header.
+    ///
+    /// As this is a `const fn`, it can be used to compute the size of a
+    /// buffer at compile time. The result is only meaningful for lengths
+    /// which can be represented by [`Length`], and tags encoded in a single
+    /// identifier octet (i.e. all except [`Tag::Date`], [`Tag::TimeOfDay`]
+    /// and [`Tag::DateTime`]).
+    pub const fn tlv_len(value_len: usize) -> usize {
+        let length_len = match value_len {
+            0..=0x7F => 1,
+            0x80..=0xFF => 2,
+            0x100..=0xFFFF => 3,
+            0x1_0000..=0xFF_FFFF => 4,
+            0x100_0000..=0xFFFF_FFFF => 5,
+            _ => 6,
+        };
+
+        1 + length_len + value_len
+    }
 }
 
 impl Decodable<'_> for Header {
@@ -29,7 +50,7 @@ impl Decodable<'_> for Header {
 
         let length = Length::decode(decoder).map_err(|e| {
             if e.kind() == ErrorKind::Overlength {
-                ErrorKind::Length { tag }.into()
+                ErrorKind::Length { tag, length: None }.into()
             } else {
                 e
             }
@@ -49,3 +70,21 @@ impl Encodable for Header {
         self.length.encode(encoder)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Header;
+    use crate::{Encodable, Tag};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn tlv_len() {
+        for &len in &[
+            0usize, 0x7F, 0x80, 0xFF, 0x100, 0xFFFF, 0x10000, 0xFF_FFFF, 0x100_0000, 0xFFF_FFFF,
+        ] {
+            let header = Header::new(Tag::OctetString, len).unwrap();
+            let expected = usize::try_from(header.encoded_len().unwrap()).unwrap() + len;
+            assert_eq!(Header::tlv_len(len), expected);
+        }
+    }
+}
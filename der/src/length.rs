@@ -1,20 +1,56 @@
 //! Length calculations for encoded ASN.1 DER values
 
-use crate::{Decodable, Decoder, Encodable, Encoder, Error, ErrorKind, Result};
+use crate::{Decodable, Decoder, Encodable, Encoder, EncodingRules, Error, ErrorKind, Result, Tag};
 use core::{
     convert::{TryFrom, TryInto},
     fmt,
     ops::{Add, Sub},
 };
 
-/// Maximum length as a `u32` (256 MiB).
-const MAX_U32: u32 = 0xfff_ffff;
+/// Integer type which [`Length`] is backed by.
+#[cfg(not(feature = "u64-length"))]
+type Inner = u32;
+
+/// Integer type which [`Length`] is backed by.
+#[cfg(feature = "u64-length")]
+type Inner = u64;
+
+/// Maximum length (256 MiB).
+#[cfg(not(feature = "u64-length"))]
+const MAX_INNER: Inner = 0xfff_ffff;
+
+/// Maximum length (1 TiB), i.e. the largest which can be encoded in 5 length
+/// octets following the initial octet.
+#[cfg(feature = "u64-length")]
+const MAX_INNER: Inner = 0xff_ffff_ffff;
+
+/// Largest initial octet of a long form length which is supported, i.e. the
+/// number of subsequent octets needed to encode [`MAX_INNER`].
+#[cfg(not(feature = "u64-length"))]
+const MAX_INITIAL_OCTET: u8 = 0x84;
+
+/// Largest initial octet of a long form length which is supported, i.e. the
+/// number of subsequent octets needed to encode [`MAX_INNER`].
+#[cfg(feature = "u64-length")]
+const MAX_INITIAL_OCTET: u8 = 0x85;
+
+/// Number of bytes in a kibibyte.
+const KIB: u64 = 1 << 10;
+
+/// Number of bytes in a mebibyte.
+const MIB: u64 = 1 << 20;
+
+/// Number of bytes in a gibibyte.
+const GIB: u64 = 1 << 30;
 
 /// ASN.1-encoded length.
 ///
-/// Maximum length is defined by the [`Length::MAX`] constant (256 MiB).
+/// Maximum length is defined by the [`Length::MAX`] constant: 256 MiB by
+/// default, or 1 TiB when the `u64-length` feature is enabled, which backs
+/// [`Length`] with a [`u64`] rather than a [`u32`] and allows lengths to be
+/// encoded with up to 5 octets following the initial octet.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
-pub struct Length(u32);
+pub struct Length(Inner);
 
 impl Length {
     /// Length of `0`
@@ -23,14 +59,25 @@ impl Length {
     /// Length of `1`
     pub const ONE: Self = Self(1);
 
-    /// Maximum length currently supported: 256 MiB
-    pub const MAX: Self = Self(MAX_U32);
+    /// Maximum length currently supported: 256 MiB, or 1 TiB with the
+    /// `u64-length` feature.
+    pub const MAX: Self = Self(MAX_INNER);
 
     /// Create a new [`Length`] for any value which fits inside of a [`u16`].
     ///
     /// This function is const-safe and therefore useful for [`Length`] constants.
     pub const fn new(value: u16) -> Self {
-        Length(value as u32)
+        Length(value as Inner)
+    }
+
+    /// Create a new [`Length`] from its inner value, checking it doesn't
+    /// exceed [`Length::MAX`].
+    fn from_inner(value: Inner) -> Result<Self> {
+        if value <= MAX_INNER {
+            Ok(Length(value))
+        } else {
+            Err(ErrorKind::Overflow.into())
+        }
     }
 
     /// Is this length equal to zero?
@@ -44,6 +91,21 @@ impl Length {
         Length(1) + self.encoded_len()? + self
     }
 
+    /// Get the length of the DER header (i.e. tag and length) of a value
+    /// with the given [`Tag`] if `self` is the length of the value.
+    ///
+    /// This is useful for reserving space for a header when the value is
+    /// written before the header.
+    pub fn header_len(self, tag: Tag) -> Result<Self> {
+        tag.encoded_len()? + self.encoded_len()?
+    }
+
+    /// Get the length of DER Tag-Length-Value (TLV) encoded data with the
+    /// given [`Tag`] if `self` is the length of the value.
+    pub fn tlv_len(self, tag: Tag) -> Result<Self> {
+        self.header_len(tag)? + self
+    }
+
     /// Get initial octet of the encoded length (if one is required).
     ///
     /// From X.690 Section 8.1.3.5:
@@ -57,12 +119,21 @@ impl Length {
     /// >    most significant bit;
     /// > c) the value 11111111₂ shall not be used.
     fn initial_octet(self) -> Option<u8> {
-        match self.0 {
-            0x80..=0xFF => Some(0x81),
-            0x100..=0xFFFF => Some(0x82),
-            0x10000..=MAX_U32 => Some(0x83),
-            _ => None,
+        if self.0 < 0x80 {
+            return None;
+        }
+
+        // Number of octets needed to encode the length, i.e. at most 5 as
+        // the length is no greater than `MAX_INNER`
+        let mut octets = 0;
+        let mut value = self.0;
+
+        while value != 0 {
+            octets += 1;
+            value >>= 8;
         }
+
+        Some(0x80 | octets)
     }
 }
 
@@ -73,7 +144,7 @@ impl Add for Length {
         self.0
             .checked_add(other.0)
             .ok_or_else(|| ErrorKind::Overflow.into())
-            .and_then(TryInto::try_into)
+            .and_then(Self::from_inner)
     }
 }
 
@@ -130,7 +201,7 @@ impl Sub for Length {
                 }
                 .into()
             })
-            .and_then(TryInto::try_into)
+            .and_then(Self::from_inner)
     }
 }
 
@@ -144,13 +215,13 @@ impl Sub<Length> for Result<Length> {
 
 impl From<u8> for Length {
     fn from(len: u8) -> Length {
-        Length(len as u32)
+        Length(len.into())
     }
 }
 
 impl From<u16> for Length {
     fn from(len: u16) -> Length {
-        Length(len as u32)
+        Length(len.into())
     }
 }
 
@@ -158,11 +229,15 @@ impl TryFrom<u32> for Length {
     type Error = Error;
 
     fn try_from(len: u32) -> Result<Length> {
-        if len <= Self::MAX.0 {
-            Ok(Length(len))
-        } else {
-            Err(ErrorKind::Overflow.into())
-        }
+        Self::try_from(u64::from(len))
+    }
+}
+
+impl TryFrom<u64> for Length {
+    type Error = Error;
+
+    fn try_from(len: u64) -> Result<Length> {
+        Self::from_inner(Inner::try_from(len).map_err(|_| ErrorKind::Overflow)?)
     }
 }
 
@@ -170,12 +245,32 @@ impl TryFrom<usize> for Length {
     type Error = Error;
 
     fn try_from(len: usize) -> Result<Length> {
-        u32::try_from(len)
+        u64::try_from(len)
             .map_err(|_| ErrorKind::Overflow)?
             .try_into()
     }
 }
 
+impl TryFrom<Length> for u32 {
+    type Error = Error;
+
+    fn try_from(len: Length) -> Result<u32> {
+        u32::try_from(u64::from(len)).map_err(|_| ErrorKind::Overflow.into())
+    }
+}
+
+impl From<Length> for u64 {
+    #[cfg(not(feature = "u64-length"))]
+    fn from(len: Length) -> u64 {
+        len.0.into()
+    }
+
+    #[cfg(feature = "u64-length")]
+    fn from(len: Length) -> u64 {
+        len.0
+    }
+}
+
 impl TryFrom<Length> for usize {
     type Error = Error;
 
@@ -189,29 +284,34 @@ impl Decodable<'_> for Length {
         match decoder.byte()? {
             // Note: per X.690 Section 8.1.3.6.1 the byte 0x80 encodes indefinite
             // lengths, which are not allowed in DER, so disallow that byte.
+            // When decoding BER they're handled by the `Decoder` instead, as
+            // they don't describe the length of the contents.
             len if len < 0x80 => Ok(len.into()),
             // 1-4 byte variable-sized length prefix
-            tag @ 0x81..=0x84 => {
-                let nbytes = tag.checked_sub(0x80).ok_or(ErrorKind::Overlength)? as usize;
-                debug_assert!(nbytes <= 4);
+            tag @ 0x81..=MAX_INITIAL_OCTET => {
+                let nbytes = tag.checked_sub(0x80).ok_or(ErrorKind::Overlength)?;
+                debug_assert!(nbytes <= 5);
 
-                let mut decoded_len = 0;
+                let mut decoded_len = 0u64;
                 for _ in 0..nbytes {
-                    decoded_len = (decoded_len << 8) | decoder.byte()? as u32;
+                    decoded_len = (decoded_len << 8) | u64::from(decoder.byte()?);
                 }
 
                 let length = Length::try_from(decoded_len)?;
 
                 // X.690 Section 10.1: DER lengths must be encoded with a minimum
                 // number of octets
-                if length.initial_octet() == Some(tag) {
+                if length.initial_octet() == Some(tag)
+                    || decoder.encoding_rules() == EncodingRules::Ber
+                {
                     Ok(length)
                 } else {
                     Err(ErrorKind::Overlength.into())
                 }
             }
             _ => {
-                // We specialize to a maximum 4-byte length (including initial octet)
+                // We specialize to a maximum of 4 (or 5 with `u64-length`)
+                // octets following the initial octet
                 Err(ErrorKind::Overlength.into())
             }
         }
@@ -220,43 +320,87 @@ impl Decodable<'_> for Length {
 
 impl Encodable for Length {
     fn encoded_len(&self) -> Result<Length> {
-        match self.0 {
-            0..=0x7F => Ok(Length(1)),
-            0x80..=0xFF => Ok(Length(2)),
-            0x100..=0xFFFF => Ok(Length(3)),
-            0x10000..=MAX_U32 => Ok(Length(4)),
-            _ => Err(ErrorKind::Overflow.into()),
+        match self.initial_octet() {
+            Some(initial_octet) => Ok(Length::from(1 + (initial_octet & 0x7F))),
+            None => Ok(Length::ONE),
         }
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        if let Some(tag_byte) = self.initial_octet() {
-            encoder.byte(tag_byte)?;
-
-            match self.0.to_be_bytes() {
-                [0, 0, 0, byte] => encoder.byte(byte),
-                [0, 0, bytes @ ..] => encoder.bytes(&bytes),
-                [0, bytes @ ..] => encoder.bytes(&bytes),
-                _ => Err(ErrorKind::Overlength.into()),
-            }
+        if let Some(initial_octet) = self.initial_octet() {
+            encoder.byte(initial_octet)?;
+
+            let bytes = self.0.to_be_bytes();
+            let start = bytes.len() - usize::from(initial_octet & 0x7F);
+            encoder.bytes(bytes.get(start..).ok_or(ErrorKind::Overlength)?)
         } else {
             encoder.byte(self.0 as u8)
         }
     }
 }
 
+/// Formats the length in bytes, followed by the size in KiB, MiB or GiB (to
+/// one decimal place) for lengths of 1 KiB or more, e.g. `1536 bytes (1.5 KiB)`.
 impl fmt::Display for Length {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+        match u64::from(*self) {
+            1 => f.write_str("1 byte"),
+            len if len < KIB => write!(f, "{} bytes", len),
+            len => {
+                let (unit, suffix) = if len < MIB {
+                    (KIB, "KiB")
+                } else if len < GIB {
+                    (MIB, "MiB")
+                } else {
+                    (GIB, "GiB")
+                };
+                let tenths = len * 10 / unit;
+                write!(
+                    f,
+                    "{} bytes ({}.{} {})",
+                    len,
+                    tenths / 10,
+                    tenths % 10,
+                    suffix
+                )
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Length;
-    use crate::{Decodable, Encodable, ErrorKind};
+    use crate::{Decodable, Encodable, ErrorKind, Tag};
     use core::convert::TryFrom;
 
+    #[test]
+    fn header_len() {
+        assert_eq!(
+            Length::new(0x7F).header_len(Tag::OctetString).unwrap(),
+            Length::new(2)
+        );
+        assert_eq!(
+            Length::new(0x80).header_len(Tag::OctetString).unwrap(),
+            Length::new(3)
+        );
+        assert_eq!(
+            Length::new(0x100).header_len(Tag::Sequence).unwrap(),
+            Length::new(4)
+        );
+        assert_eq!(
+            Length::try_from(0x10000u32)
+                .unwrap()
+                .header_len(Tag::Sequence)
+                .unwrap(),
+            Length::new(5)
+        );
+        assert_eq!(
+            Length::new(0x100).tlv_len(Tag::Sequence).unwrap(),
+            Length::new(0x104)
+        );
+    }
+
     #[test]
     fn decode() {
         assert_eq!(Length::ZERO, Length::from_der(&[0x00]).unwrap());
@@ -282,11 +426,24 @@ mod tests {
             Length::try_from(0x10000u32).unwrap(),
             Length::from_der(&[0x83, 0x01, 0x00, 0x00]).unwrap()
         );
+
+        assert_eq!(
+            Length::try_from(0x100_0000u32).unwrap(),
+            Length::from_der(&[0x84, 0x01, 0x00, 0x00, 0x00]).unwrap()
+        );
+
+        // Non-minimal
+        assert_eq!(
+            Length::from_der(&[0x84, 0x00, 0xFF, 0xFF, 0xFF])
+                .err()
+                .map(|err| err.kind()),
+            Some(ErrorKind::Overlength)
+        );
     }
 
     #[test]
     fn encode() {
-        let mut buffer = [0u8; 4];
+        let mut buffer = [0u8; 5];
 
         assert_eq!(&[0x00], Length::ZERO.encode_to_slice(&mut buffer).unwrap());
 
@@ -317,6 +474,54 @@ mod tests {
                 .encode_to_slice(&mut buffer)
                 .unwrap()
         );
+
+        assert_eq!(
+            &[0x84, 0x0F, 0xFF, 0xFF, 0xFF],
+            Length::try_from(0xfff_ffffu32)
+                .unwrap()
+                .encode_to_slice(&mut buffer)
+                .unwrap()
+        );
+    }
+
+    #[cfg(not(feature = "u64-length"))]
+    #[test]
+    fn reject_5_byte_lengths() {
+        assert_eq!(
+            Length::from_der(&[0x84, 0x10, 0x00, 0x00, 0x00])
+                .err()
+                .map(|err| err.kind()),
+            Some(ErrorKind::Overflow)
+        );
+        assert_eq!(
+            Length::from_der(&[0x85, 0x01, 0x00, 0x00, 0x00, 0x00])
+                .err()
+                .map(|err| err.kind()),
+            Some(ErrorKind::Overlength)
+        );
+        assert!(Length::try_from(0x1000_0000u64).is_err());
+    }
+
+    #[cfg(feature = "u64-length")]
+    #[test]
+    fn u64_lengths() {
+        let mut buffer = [0u8; 6];
+
+        for &(len, der) in &[
+            (0xffff_ffffu64, &[0x84, 0xFF, 0xFF, 0xFF, 0xFF][..]),
+            (0x1_0000_0000, &[0x85, 0x01, 0x00, 0x00, 0x00, 0x00]),
+            (0xff_ffff_ffff, &[0x85, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]),
+        ] {
+            let length = Length::try_from(len).unwrap();
+            assert_eq!(Length::from_der(der).unwrap(), length);
+            assert_eq!(length.encode_to_slice(&mut buffer).unwrap(), der);
+            assert_eq!(u64::from(length), len);
+        }
+
+        assert_eq!(Length::MAX, Length::try_from(0xff_ffff_ffffu64).unwrap());
+        assert!(Length::try_from(0x100_0000_0000u64).is_err());
+        assert!(Length::from_der(&[0x86, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00]).is_err());
+        assert!(u32::try_from(Length::try_from(0x1_0000_0000u64).unwrap()).is_err());
     }
 
     #[test]
@@ -324,6 +529,55 @@ mod tests {
         assert!(Length::from_der(&[0x80]).is_err());
     }
 
+    #[test]
+    fn conversions() {
+        assert_eq!(Length::from(0x1234u16), Length::new(0x1234));
+        assert_eq!(u32::try_from(Length::new(0x1234)).unwrap(), 0x1234);
+        assert_eq!(u64::from(Length::new(0x1234)), 0x1234);
+        assert_eq!(Length::try_from(0x1234u64).unwrap(), Length::new(0x1234));
+        assert_eq!(
+            Length::try_from(0xfff_ffffusize).unwrap(),
+            Length::try_from(0xfff_ffffu32).unwrap()
+        );
+
+        #[cfg(not(feature = "u64-length"))]
+        {
+            assert_eq!(usize::try_from(Length::MAX).unwrap(), 0xfff_ffff);
+            assert_eq!(
+                Length::try_from(0x1000_0000usize)
+                    .err()
+                    .map(|err| err.kind()),
+                Some(ErrorKind::Overflow)
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display() {
+        use alloc::string::ToString;
+
+        let examples: &[(u32, &str)] = &[
+            (0, "0 bytes"),
+            (1, "1 byte"),
+            (1023, "1023 bytes"),
+            (1024, "1024 bytes (1.0 KiB)"),
+            (1536, "1536 bytes (1.5 KiB)"),
+            (0x10_0000, "1048576 bytes (1.0 MiB)"),
+            (0xfff_ffff, "268435455 bytes (255.9 MiB)"),
+        ];
+
+        for &(len, expected) in examples {
+            assert_eq!(Length::try_from(len).unwrap().to_string(), expected);
+        }
+
+        #[cfg(feature = "u64-length")]
+        assert_eq!(
+            Length::try_from(0x1_8000_0000u64).unwrap().to_string(),
+            "6442450944 bytes (6.0 GiB)"
+        );
+    }
+
     #[test]
     fn add_overflows_when_max_length_exceeded() {
         let result = Length::MAX + Length::ONE;
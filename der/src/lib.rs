@@ -10,6 +10,11 @@
 //! The core implementation avoids any heap usage (with convenience methods
 //! that allocate gated under the off-by-default `alloc` feature).
 //!
+//! Encoding and decoding are not split into separate crate features, as
+//! Cargo features must be additive. Code paths which are never called (e.g.
+//! encoders in a decode-only firmware image) are removed by the linker, so
+//! they do not contribute to the final binary size.
+//!
 //! # Minimum Supported Rust Version
 //! This crate requires **Rust 1.55** at a minimum.
 //!
@@ -32,27 +37,51 @@
 //! - [`str`], [`String`][`alloc::string::String`]: ASN.1 `UTF8String`.
 //!   `String` requires `alloc` feature. See also [`Utf8String`].
 //! - [`BTreeSet`][`alloc::collections::BTreeSet`]: ASN.1 `SET OF`.
-//!   Requires `alloc` feature. See also [`SetOf`].
+//!   Requires `alloc` feature. See also [`SetOf`] and [`SetOfVec`], which
+//!   order elements by their encodings as DER requires.
 //! - [`Option`]: ASN.1 `OPTIONAL`.
+//! - [`OffsetDateTime`][`time::OffsetDateTime`],
+//!   [`PrimitiveDateTime`][`time::PrimitiveDateTime`]: ASN.1 `GeneralizedTime`.
+//!   Requires `time` feature.
+//! - [`chrono::DateTime<Utc>`][`chrono::DateTime`]: ASN.1 `GeneralizedTime`.
+//!   Requires `chrono` feature.
 //! - [`SystemTime`][`std::time::SystemTime`]: ASN.1 `GeneralizedTime`. Requires `std` feature.
 //! - [`Vec`][`alloc::vec::Vec`]: ASN.1 `SEQUENCE OF`. Requires `alloc` feature.
+//! - [`FlagSet`][`flagset::FlagSet`]: ASN.1 `BIT STRING` with named bits.
+//!   Requires `flagset` feature.
 //! - `[T; N]`: ASN.1 `SEQUENCE OF`. See also [`SequenceOf`].
 //!
 //! The following ASN.1 types provided by this crate also impl these traits:
 //!
 //! - [`Any`]: ASN.1 `ANY`
 //! - [`BitString`]: ASN.1 `BIT STRING`
+//! - [`BmpString`]: ASN.1 `BMPString`
+//! - [`Date`]: ASN.1 `DATE`
+//! - [`DateAndTime`]: ASN.1 `DATE-TIME`
+//! - [`EmbeddedPdv`]: ASN.1 `EMBEDDED PDV`
+//! - [`External`]: ASN.1 `EXTERNAL`
+//! - [`GeneralString`]: ASN.1 `GeneralString`
 //! - [`GeneralizedTime`]: ASN.1 `GeneralizedTime`
+//! - [`GeneralizedTimeLenient`]: ASN.1 `GeneralizedTime`, also accepting
+//!   fractional seconds and offsets from UTC
 //! - [`Ia5String`]: ASN.1 `IA5String`
+//! - [`IntBytes`]: ASN.1 signed `INTEGER` with raw access to encoded bytes
 //! - [`Null`]: ASN.1 `NULL`
+//! - [`NumericString`]: ASN.1 `NumericString` (digits and space)
 //! - [`ObjectIdentifier`]: ASN.1 `OBJECT IDENTIFIER`
 //! - [`OctetString`]: ASN.1 `OCTET STRING`
 //! - [`PrintableString`]: ASN.1 `PrintableString` (ASCII subset)
+//! - [`RelativeOid`]: ASN.1 `RELATIVE-OID`
 //! - [`SequenceOf`]: ASN.1 `SEQUENCE OF`
 //! - [`SetOf`]: ASN.1 `SET OF`
+//! - [`TeletexString`]: ASN.1 `TeletexString` (`T61String`)
+//! - [`Time`]: ASN.1 `TIME`
+//! - [`TimeOfDay`]: ASN.1 `TIME-OF-DAY`
 //! - [`UIntBytes`]: ASN.1 unsigned `INTEGER` with raw access to encoded bytes
+//! - [`UniversalString`]: ASN.1 `UniversalString`
 //! - [`UtcTime`]: ASN.1 `UTCTime`
 //! - [`Utf8String`]: ASN.1 `UTF8String`
+//! - [`VisibleString`]: ASN.1 `VisibleString` (printable ASCII)
 //!
 //! ## Example
 //! The following example implements X.509's `AlgorithmIdentifier` message type
@@ -318,18 +347,34 @@
 //! [A Warm Welcome to ASN.1 and DER]: https://letsencrypt.org/docs/a-warm-welcome-to-asn1-and-der/
 //!
 //! [`Any`]: asn1::Any
+//! [`IntBytes`]: asn1::IntBytes
 //! [`UIntBytes`]: asn1::UIntBytes
 //! [`BitString`]: asn1::BitString
+//! [`BmpString`]: asn1::BmpString
+//! [`Date`]: asn1::Date
+//! [`DateAndTime`]: asn1::DateAndTime
+//! [`EmbeddedPdv`]: asn1::EmbeddedPdv
+//! [`External`]: asn1::External
+//! [`GeneralString`]: asn1::GeneralString
 //! [`GeneralizedTime`]: asn1::GeneralizedTime
+//! [`GeneralizedTimeLenient`]: asn1::GeneralizedTimeLenient
 //! [`Ia5String`]: asn1::Ia5String
 //! [`Null`]: asn1::Null
+//! [`NumericString`]: asn1::NumericString
 //! [`ObjectIdentifier`]: asn1::ObjectIdentifier
 //! [`OctetString`]: asn1::OctetString
 //! [`PrintableString`]: asn1::PrintableString
+//! [`RelativeOid`]: asn1::RelativeOid
 //! [`SequenceOf`]: asn1::SequenceOf
 //! [`SetOf`]: asn1::SetOf
+//! [`SetOfVec`]: asn1::SetOfVec
+//! [`TeletexString`]: asn1::TeletexString
+//! [`Time`]: asn1::Time
+//! [`TimeOfDay`]: asn1::TimeOfDay
+//! [`UniversalString`]: asn1::UniversalString
 //! [`UtcTime`]: asn1::UtcTime
 //! [`Utf8String`]: asn1::Utf8String
+//! [`VisibleString`]: asn1::VisibleString
 
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -339,7 +384,12 @@
     html_root_url = "https://docs.rs/der/0.5.0-pre.1"
 )]
 #![forbid(unsafe_code, clippy::unwrap_used)]
-#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+#![warn(
+    missing_debug_implementations,
+    missing_docs,
+    rust_2018_idioms,
+    unused_qualifications
+)]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -350,37 +400,66 @@ pub mod asn1;
 
 pub(crate) mod arrayvec;
 mod byte_slice;
+mod const_encoder;
 mod datetime;
 mod decodable;
 mod decoder;
 mod encodable;
 mod encoder;
+mod encoding_rules;
 mod error;
 mod header;
 mod length;
+mod push_parser;
+mod redacted;
+mod reverse_encoder;
 mod str_slice;
 mod tag;
 mod value;
 
+#[cfg(feature = "alloc")]
+mod diff;
 #[cfg(feature = "alloc")]
 mod document;
 
+#[cfg(feature = "dev")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+pub mod dev;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod lint;
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub mod pem;
+
 pub use crate::{
     asn1::{Any, Choice, Sequence},
+    const_encoder::ConstEncoder,
     datetime::DateTime,
     decodable::Decodable,
     decoder::Decoder,
     encodable::Encodable,
     encoder::Encoder,
+    encoding_rules::EncodingRules,
     error::{Error, ErrorKind, Result},
     header::Header,
     length::Length,
+    push_parser::{PushEvent, PushParser},
+    redacted::Redacted,
+    reverse_encoder::ReverseEncoder,
+    str_slice::StrSlice,
     tag::{Class, Tag, TagMode, TagNumber, Tagged},
     value::{DecodeValue, EncodeValue},
 };
 
 #[cfg(feature = "alloc")]
-pub use document::Document;
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use diff::{diff, DiffNode, Difference};
+
+#[cfg(feature = "alloc")]
+pub use document::{AnyDocument, Document};
 
 #[cfg(feature = "bigint")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bigint")))]
@@ -390,12 +469,24 @@ pub use crypto_bigint as bigint;
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use der_derive::{Choice, Sequence};
 
-#[cfg(feature = "pem")]
-#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-pub use pem_rfc7468 as pem;
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+pub use chrono;
+
+#[cfg(feature = "digest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+pub use digest;
+
+#[cfg(feature = "flagset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flagset")))]
+pub use flagset;
+
+#[cfg(feature = "generic-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "generic-array")))]
+pub use generic_array::{self, typenum::consts};
 
 #[cfg(feature = "time")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
 pub use time;
 
-pub(crate) use crate::{arrayvec::ArrayVec, byte_slice::ByteSlice, str_slice::StrSlice};
+pub(crate) use crate::{arrayvec::ArrayVec, byte_slice::ByteSlice};
@@ -0,0 +1,574 @@
+//! Reporting of non-canonical constructs in BER/DER documents.
+//!
+//! [`lint`] walks the tree of tag-length-value encoded values of a document
+//! without decoding it as any particular ASN.1 type, and reports each
+//! construct which is valid BER but not valid DER as a [`Finding`], along
+//! with its offset from the start of the document.
+//!
+//! ```
+//! use der::lint::{self, FindingKind};
+//! use der::{Length, Tag};
+//!
+//! // `SEQUENCE` containing an `INTEGER` with a redundant leading zero
+//! let findings = lint::lint(&[0x30, 0x04, 0x02, 0x02, 0x00, 0x01])?;
+//!
+//! assert_eq!(findings.len(), 1);
+//! assert_eq!(findings[0].offset, Length::new(2));
+//! assert_eq!(findings[0].tag, Tag::Integer);
+//! assert_eq!(findings[0].kind, FindingKind::NonMinimalInteger);
+//! # Ok::<(), der::Error>(())
+//! ```
+
+use crate::{
+    asn1::{BmpString, Ia5String, NumericString, PrintableString, UniversalString, VisibleString},
+    Decodable, Decoder, ErrorKind, Length, Result, Tag,
+};
+use alloc::vec::Vec;
+use core::{convert::TryFrom, fmt};
+
+/// Bit of the identifier octet which is set for constructed encodings.
+const CONSTRUCTED_FLAG: u8 = 0b100000;
+
+/// Maximum number of subsequent length octets supported.
+const MAX_LENGTH_OCTETS: usize = 8;
+
+/// Non-canonical construct found by [`lint`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Finding {
+    /// Offset of the header of the offending value from the start of the
+    /// document.
+    pub offset: Length,
+
+    /// Tag of the offending value.
+    ///
+    /// For [`FindingKind::ConstructedString`] this is the tag of the
+    /// primitive encoding of the string type.
+    pub tag: Tag,
+
+    /// Kind of non-canonical construct.
+    pub kind: FindingKind,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) at DER byte {}",
+            self.kind,
+            self.tag,
+            u64::from(self.offset)
+        )
+    }
+}
+
+/// Kinds of non-canonical constructs reported by [`lint`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum FindingKind {
+    /// Length encoded in the long form when the short form would do, or
+    /// with leading zero octets.
+    NonMinimalLength,
+
+    /// Constructed value encoded with the indefinite length form.
+    IndefiniteLength,
+
+    /// `INTEGER` or `ENUMERATED` with redundant leading `0x00` or `0xFF`
+    /// octets.
+    NonMinimalInteger,
+
+    /// `SET` or `SET OF` element which sorts before the previous element.
+    ///
+    /// Elements are compared by their encodings, which is the order DER
+    /// requires for `SET OF`, and matches the tag order it requires for
+    /// `SET` when the elements have low tag numbers.
+    UnorderedSet,
+
+    /// String containing characters outside of the character set of its
+    /// type.
+    InvalidCharacters,
+
+    /// `BOOLEAN` whose value is neither `0x00` nor `0xFF`.
+    NonCanonicalBoolean,
+
+    /// String type encoded in the constructed form.
+    ConstructedString,
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FindingKind::NonMinimalLength => "non-minimal length",
+            FindingKind::IndefiniteLength => "indefinite length",
+            FindingKind::NonMinimalInteger => "non-minimal integer",
+            FindingKind::UnorderedSet => "unordered SET element",
+            FindingKind::InvalidCharacters => "invalid characters",
+            FindingKind::NonCanonicalBoolean => "non-canonical BOOLEAN",
+            FindingKind::ConstructedString => "constructed string",
+        })
+    }
+}
+
+/// Report the non-canonical constructs in a BER/DER document, in the order
+/// they occur in it.
+///
+/// Returns an error if the document isn't a single well-formed BER value,
+/// i.e. if a value extends beyond its parent or the end of the document,
+/// if a tag is unknown, or if a `BOOLEAN` or `INTEGER` has an invalid length.
+pub fn lint(bytes: &[u8]) -> Result<Vec<Finding>> {
+    Length::try_from(bytes.len())?;
+
+    let mut linter = Linter {
+        bytes,
+        findings: Vec::new(),
+    };
+
+    linter.run()?;
+    Ok(linter.findings)
+}
+
+/// Constructed value whose contents are being walked.
+struct Frame {
+    /// Offset of the value's header.
+    start: usize,
+
+    /// Tag of the value.
+    tag: Tag,
+
+    /// Offset of the end of the value's contents, or `None` if it has an
+    /// indefinite length.
+    end: Option<usize>,
+
+    /// Offset beyond which the value's contents may not extend.
+    limit: usize,
+
+    /// Offsets of the start and end of the previous element of a `SET`.
+    previous: Option<(usize, usize)>,
+}
+
+/// Header of a value.
+struct ValueHeader {
+    /// Tag of the value.
+    tag: Tag,
+
+    /// Is the value constructed?
+    constructed: bool,
+
+    /// Offset of the value's contents.
+    value_start: usize,
+
+    /// Length of the value's contents, or `None` if indefinite.
+    length: Option<usize>,
+}
+
+/// State of a [`lint`] run.
+struct Linter<'a> {
+    /// Document being linted.
+    bytes: &'a [u8],
+
+    /// Findings so far.
+    findings: Vec<Finding>,
+}
+
+impl<'a> Linter<'a> {
+    /// Walk the document, without recursion so deeply nested documents
+    /// can't exhaust the stack.
+    fn run(&mut self) -> Result<()> {
+        let mut stack: Vec<Frame> = Vec::new();
+        let mut pos = 0;
+
+        loop {
+            let limit = match stack.last() {
+                Some(frame) => {
+                    let finished = match frame.end {
+                        Some(end) => pos == end,
+                        None => {
+                            pos + 2 <= frame.limit && self.bytes.get(pos..pos + 2) == Some(&[0, 0])
+                        }
+                    };
+
+                    if finished {
+                        if frame.end.is_none() {
+                            pos += 2;
+                        }
+
+                        if let Some(frame) = stack.pop() {
+                            self.finish_child(&mut stack, frame.start, pos, frame.tag)?;
+                        }
+
+                        continue;
+                    }
+
+                    frame.limit
+                }
+                None if pos > 0 => break,
+                None => self.bytes.len(),
+            };
+
+            let start = pos;
+            let header = self.header(start, limit)?;
+
+            match header.length {
+                Some(length) => {
+                    let end = header
+                        .value_start
+                        .checked_add(length)
+                        .filter(|&end| end <= limit)
+                        .ok_or_else(|| ErrorKind::Truncated.at(offset(start)))?;
+
+                    if header.constructed {
+                        stack.push(Frame {
+                            start,
+                            tag: header.tag,
+                            end: Some(end),
+                            limit: end,
+                            previous: None,
+                        });
+                        pos = header.value_start;
+                    } else {
+                        let bytes = self.bytes;
+                        self.primitive(start, header.tag, &bytes[header.value_start..end])?;
+                        pos = end;
+                        self.finish_child(&mut stack, start, pos, header.tag)?;
+                    }
+                }
+                None => {
+                    stack.push(Frame {
+                        start,
+                        tag: header.tag,
+                        end: None,
+                        limit,
+                        previous: None,
+                    });
+                    pos = header.value_start;
+                }
+            }
+        }
+
+        if pos < self.bytes.len() {
+            return Err(ErrorKind::TrailingData {
+                decoded: offset(pos),
+                remaining: offset(self.bytes.len() - pos),
+            }
+            .at(offset(pos)));
+        }
+
+        Ok(())
+    }
+
+    /// Parse the header of the value at the given offset, reporting
+    /// non-canonical tags and lengths.
+    fn header(&mut self, start: usize, limit: usize) -> Result<ValueHeader> {
+        let first = *self
+            .bytes
+            .get(start)
+            .filter(|_| start < limit)
+            .ok_or_else(|| ErrorKind::Truncated.at(offset(start)))?;
+
+        let (tag, constructed, mut pos) = match constructed_string(first) {
+            Some(tag) => {
+                self.report(start, tag, FindingKind::ConstructedString);
+                (tag, true, start + 1)
+            }
+            None => {
+                let mut decoder = Decoder::new(&self.bytes[start..limit]);
+                let tag = Tag::decode(&mut decoder).map_err(|e| e.nested(offset(start)))?;
+                let tag_len = usize::try_from(decoder.position())?;
+                (tag, tag.is_constructed(), start + tag_len)
+            }
+        };
+
+        let truncated = || ErrorKind::Truncated.at(offset(start));
+        let initial = *self
+            .bytes
+            .get(pos)
+            .filter(|_| pos < limit)
+            .ok_or_else(truncated)?;
+        pos += 1;
+
+        let length = match initial {
+            0x00..=0x7F => Some(usize::from(initial)),
+            0x80 if constructed => {
+                self.report(start, tag, FindingKind::IndefiniteLength);
+                None
+            }
+            0x80 | 0xFF => {
+                return Err(ErrorKind::Length { tag, length: None }.at(offset(start)));
+            }
+            _ => {
+                let nlen = usize::from(initial & 0x7F);
+
+                if nlen > MAX_LENGTH_OCTETS {
+                    return Err(ErrorKind::Overlength.at(offset(start)));
+                }
+
+                let octets = self
+                    .bytes
+                    .get(pos..pos + nlen)
+                    .filter(|_| pos + nlen <= limit)
+                    .ok_or_else(truncated)?;
+                pos += nlen;
+
+                let length = octets
+                    .iter()
+                    .fold(0u64, |acc, &byte| acc << 8 | u64::from(byte));
+
+                if octets[0] == 0 || length < 0x80 {
+                    self.report(start, tag, FindingKind::NonMinimalLength);
+                }
+
+                Some(usize::try_from(length).map_err(|_| ErrorKind::Overlength.at(offset(start)))?)
+            }
+        };
+
+        Ok(ValueHeader {
+            tag,
+            constructed,
+            value_start: pos,
+            length,
+        })
+    }
+
+    /// Report non-canonical contents of a primitive value.
+    fn primitive(&mut self, start: usize, tag: Tag, value: &[u8]) -> Result<()> {
+        let kind = match tag {
+            Tag::Boolean => match value {
+                [0x00] | [0xFF] => None,
+                [_] => Some(FindingKind::NonCanonicalBoolean),
+                _ => return Err(tag.length_error().nested(offset(start))),
+            },
+            Tag::Integer | Tag::Enumerated => match value {
+                [] => return Err(tag.length_error().nested(offset(start))),
+                [0x00, next, ..] if *next < 0x80 => Some(FindingKind::NonMinimalInteger),
+                [0xFF, next, ..] if *next >= 0x80 => Some(FindingKind::NonMinimalInteger),
+                _ => None,
+            },
+            _ if !valid_characters(tag, value) => Some(FindingKind::InvalidCharacters),
+            _ => None,
+        };
+
+        if let Some(kind) = kind {
+            self.report(start, tag, kind);
+        }
+
+        Ok(())
+    }
+
+    /// Record the end of a value contained in the innermost frame, checking
+    /// the order of the elements of a `SET`.
+    fn finish_child(
+        &mut self,
+        stack: &mut [Frame],
+        start: usize,
+        end: usize,
+        tag: Tag,
+    ) -> Result<()> {
+        let parent = match stack.last_mut() {
+            Some(parent) => parent,
+            None => return Ok(()),
+        };
+
+        if parent.tag != Tag::Set {
+            return Ok(());
+        }
+
+        if let Some((prev_start, prev_end)) = parent.previous {
+            if self.bytes[start..end] < self.bytes[prev_start..prev_end] {
+                self.report(start, tag, FindingKind::UnorderedSet);
+            }
+        }
+
+        parent.previous = Some((start, end));
+        Ok(())
+    }
+
+    /// Record a finding.
+    fn report(&mut self, start: usize, tag: Tag, kind: FindingKind) {
+        self.findings.push(Finding {
+            offset: offset(start),
+            tag,
+            kind,
+        });
+    }
+}
+
+/// Convert an offset into the document to a [`Length`].
+///
+/// [`lint`] rejects documents whose length can't be represented, so this
+/// never saturates.
+fn offset(pos: usize) -> Length {
+    Length::try_from(pos).unwrap_or(Length::MAX)
+}
+
+/// Get the tag of the primitive encoding of a string type from the
+/// identifier octet of its constructed encoding, which [`Tag`] doesn't
+/// represent.
+fn constructed_string(byte: u8) -> Option<Tag> {
+    if byte & !0x1F != CONSTRUCTED_FLAG {
+        return None;
+    }
+
+    Tag::try_from(byte & !CONSTRUCTED_FLAG).ok().filter(|tag| {
+        matches!(
+            tag,
+            Tag::BitString
+                | Tag::OctetString
+                | Tag::ObjectDescriptor
+                | Tag::Utf8String
+                | Tag::NumericString
+                | Tag::PrintableString
+                | Tag::TeletexString
+                | Tag::Ia5String
+                | Tag::UtcTime
+                | Tag::GeneralizedTime
+                | Tag::VisibleString
+                | Tag::GeneralString
+                | Tag::UniversalString
+                | Tag::BmpString
+        )
+    })
+}
+
+/// Are the contents of a value of a string type made of characters in the
+/// character set of the type?
+///
+/// Always `true` for types which aren't restricted character string types.
+fn valid_characters(tag: Tag, value: &[u8]) -> bool {
+    match tag {
+        Tag::Utf8String => core::str::from_utf8(value).is_ok(),
+        Tag::NumericString => NumericString::new(value).is_ok(),
+        Tag::PrintableString => PrintableString::new(value).is_ok(),
+        Tag::Ia5String => Ia5String::new(value).is_ok(),
+        Tag::VisibleString => VisibleString::new(value).is_ok(),
+        Tag::BmpString => BmpString::new(value).is_ok(),
+        Tag::UniversalString => match UniversalString::new(value) {
+            Ok(string) => string.chars().all(|c| c.is_ok()),
+            Err(_) => false,
+        },
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lint, Finding, FindingKind};
+    use crate::{ErrorKind, Length, Tag};
+    use alloc::{string::ToString, vec::Vec};
+    use hex_literal::hex;
+
+    /// Lint a document, returning the offsets, tags and kinds of findings.
+    fn findings(bytes: &[u8]) -> Vec<(u64, Tag, FindingKind)> {
+        lint(bytes)
+            .unwrap()
+            .iter()
+            .map(|f| (u64::from(f.offset), f.tag, f.kind))
+            .collect()
+    }
+
+    #[test]
+    fn canonical() {
+        let der = hex!("30 12 01 01 FF 02 02 00 80 31 06 02 01 01 02 01 02 13 01 41");
+        assert!(lint(&der).unwrap().is_empty());
+    }
+
+    #[test]
+    fn non_minimal_length() {
+        assert_eq!(
+            findings(&hex!("30 81 04 04 82 00 00")),
+            [
+                (0, Tag::Sequence, FindingKind::NonMinimalLength),
+                (3, Tag::OctetString, FindingKind::NonMinimalLength),
+            ]
+        );
+    }
+
+    #[test]
+    fn indefinite_length() {
+        assert_eq!(
+            findings(&hex!("30 80 05 00 00 00")),
+            [(0, Tag::Sequence, FindingKind::IndefiniteLength)]
+        );
+
+        let err = lint(&hex!("04 80 00 00")).err().unwrap();
+        assert!(matches!(err.kind(), ErrorKind::Length { .. }));
+    }
+
+    #[test]
+    fn non_minimal_integer() {
+        assert_eq!(
+            findings(&hex!("30 0B 02 02 00 7F 02 02 FF 80 0A 01 00")),
+            [
+                (2, Tag::Integer, FindingKind::NonMinimalInteger),
+                (6, Tag::Integer, FindingKind::NonMinimalInteger),
+            ]
+        );
+    }
+
+    #[test]
+    fn unordered_set() {
+        assert_eq!(
+            findings(&hex!("31 09 02 01 02 02 01 01 02 01 03")),
+            [(5, Tag::Integer, FindingKind::UnorderedSet)]
+        );
+    }
+
+    #[test]
+    fn invalid_characters() {
+        assert_eq!(
+            findings(&hex!("30 0B 13 01 40 16 01 80 0C 01 FF 12 00")),
+            [
+                (2, Tag::PrintableString, FindingKind::InvalidCharacters),
+                (5, Tag::Ia5String, FindingKind::InvalidCharacters),
+                (8, Tag::Utf8String, FindingKind::InvalidCharacters),
+            ]
+        );
+    }
+
+    #[test]
+    fn boolean() {
+        assert_eq!(
+            findings(&hex!("01 01 01")),
+            [(0, Tag::Boolean, FindingKind::NonCanonicalBoolean)]
+        );
+    }
+
+    #[test]
+    fn constructed_string() {
+        assert_eq!(
+            findings(&hex!("24 80 04 01 01 04 01 02 00 00")),
+            [
+                (0, Tag::OctetString, FindingKind::ConstructedString),
+                (0, Tag::OctetString, FindingKind::IndefiniteLength),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let err = lint(&hex!("30 03 02 01")).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Truncated);
+
+        let err = lint(&hex!("30 03 02 02 00 00")).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Truncated);
+        assert_eq!(err.position(), Some(Length::new(2)));
+
+        let err = lint(&hex!("05 00 05 00")).err().unwrap();
+        assert!(matches!(err.kind(), ErrorKind::TrailingData { .. }));
+
+        let err = lint(&hex!("30 03 02 00 00")).err().unwrap();
+        assert_eq!(err.position(), Some(Length::new(2)));
+    }
+
+    #[test]
+    fn display() {
+        let finding = Finding {
+            offset: Length::new(4),
+            tag: Tag::Integer,
+            kind: FindingKind::NonMinimalInteger,
+        };
+
+        assert_eq!(
+            finding.to_string(),
+            "non-minimal integer (INTEGER) at DER byte 4"
+        );
+    }
+}
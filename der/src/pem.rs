@@ -0,0 +1,116 @@
+//! Privacy-Enhanced Mail (PEM) encoding support.
+//!
+//! Re-exports the [`pem_rfc7468`] crate, along with helpers for encoding
+//! [`Encodable`] types directly as PEM.
+
+pub use pem_rfc7468::*;
+
+use crate::{encoder::Sink, Encodable, Redacted};
+use alloc::string::String;
+use core::{fmt, ops::Deref};
+use zeroize::Zeroizing;
+
+/// Encode the provided value as ASN.1 DER encapsulated as a PEM document
+/// with the given type label, writing the result into the provided buffer.
+///
+/// The DER serialization is streamed into the PEM encoder as it's produced
+/// rather than being materialized in an intermediate buffer first, which
+/// roughly halves peak memory usage when encoding large documents.
+pub fn encode_from_encodable<'o>(
+    value: &impl Encodable,
+    label: &str,
+    line_ending: LineEnding,
+    buf: &'o mut [u8],
+) -> crate::Result<&'o [u8]> {
+    let mut sink = PemSink(Encoder::new(label, line_ending, buf)?);
+    let mut der_encoder = crate::Encoder::new_sink(&mut sink);
+    value.encode(&mut der_encoder)?;
+    der_encoder.finish()?;
+    Ok(sink.0.finish()?)
+}
+
+/// PEM-encoded string containing secret material, e.g. a private key.
+///
+/// The string is zeroized when dropped, and its [`fmt::Debug`] impl is
+/// redacted so the secret isn't accidentally logged.
+#[derive(Clone)]
+pub struct SecretPemString(Zeroizing<String>);
+
+impl SecretPemString {
+    /// Create a new [`SecretPemString`] from the given PEM-encoded string.
+    pub fn new(pem: String) -> Self {
+        Self(Zeroizing::new(pem))
+    }
+
+    /// Borrow the PEM-encoded string.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl AsRef<str> for SecretPemString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Deref for SecretPemString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<String> for SecretPemString {
+    fn from(pem: String) -> SecretPemString {
+        SecretPemString::new(pem)
+    }
+}
+
+impl From<Zeroizing<String>> for SecretPemString {
+    fn from(pem: Zeroizing<String>) -> SecretPemString {
+        SecretPemString(pem)
+    }
+}
+
+impl fmt::Debug for SecretPemString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SecretPemString").field(&Redacted).finish()
+    }
+}
+
+/// Adapter which feeds DER-encoded bytes into a PEM [`Encoder`].
+struct PemSink<'l, 'o>(Encoder<'l, 'o>);
+
+impl Sink for PemSink<'_, '_> {
+    fn write(&mut self, bytes: &[u8]) -> crate::Result<()> {
+        Ok(self.0.encode(bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LineEnding, SecretPemString};
+    use crate::{asn1::OctetString, Encodable};
+    use alloc::{format, string::String};
+
+    #[test]
+    fn encode_from_encodable_matches_buffered() {
+        let bytes = [0x42u8; 200];
+        let value = OctetString::new(&bytes).unwrap();
+        let expected = super::encode_string("TEST", LineEnding::LF, &value.to_vec().unwrap());
+
+        let mut buf = [0u8; 512];
+        let encoded = super::encode_from_encodable(&value, "TEST", LineEnding::LF, &mut buf);
+        assert_eq!(encoded.unwrap(), expected.unwrap().as_bytes());
+    }
+
+    #[test]
+    fn secret_pem_string() {
+        let pem = String::from("-----BEGIN TEST-----\nQg==\n-----END TEST-----\n");
+        let secret = SecretPemString::from(pem.clone());
+        assert_eq!(&*secret, pem);
+        assert_eq!(format!("{:?}", secret), "SecretPemString([REDACTED])");
+    }
+}
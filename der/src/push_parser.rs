@@ -0,0 +1,402 @@
+//! Push-based incremental parser.
+
+use crate::{Decodable, ErrorKind, Header, Length, Result, Tag};
+use core::convert::TryFrom;
+
+/// Maximum length of an encoded [`Header`]: an identifier octet, up to 5
+/// octets of high tag number, and up to 5 length octets.
+const MAX_HEADER_LEN: usize = 11;
+
+/// Event produced by a [`PushParser`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PushEvent<'i> {
+    /// The input has been exhausted, and more of it is needed to make
+    /// progress.
+    NeedMoreData,
+
+    /// Start of a tag-length-value (TLV) field with the given [`Header`].
+    ///
+    /// For constructed fields, this is followed by the events of the nested
+    /// fields. For primitive fields, it's followed by [`PushEvent::Value`]
+    /// events containing the contents of the field.
+    Start(Header),
+
+    /// Chunk of the contents of a primitive field.
+    ///
+    /// The contents of a field may be split across any number of chunks,
+    /// depending on how the input is split.
+    Value(&'i [u8]),
+
+    /// End of the field with the given [`Tag`].
+    ///
+    /// Every [`PushEvent::Start`] is matched by an [`PushEvent::End`]. The
+    /// end of a field at depth 0 (i.e. after which [`PushParser::depth`] is
+    /// 0) marks the end of a complete message.
+    End(Tag),
+}
+
+/// Push-based incremental DER parser, which is fed the encoded message in
+/// chunks as they are received (e.g. from a serial port), rather than
+/// requiring the complete message to be in memory.
+///
+/// The parser produces a stream of [`PushEvent`]s, validating that nested
+/// fields don't exceed the lengths of the fields containing them, without
+/// buffering anything other than a partially received [`Header`]. Values
+/// are not otherwise interpreted: the contents of primitive fields are
+/// returned as they are received.
+///
+/// Up to `DEPTH` nested fields may be open at a time. Parsing a message
+/// which is nested more deeply returns an [`ErrorKind::Overlength`] error.
+///
+/// Any number of consecutive messages may be parsed. Once an error is
+/// returned, the parser is failed and rejects all further input.
+///
+/// ```
+/// use der::{PushEvent, PushParser};
+///
+/// // `SEQUENCE` containing an `OCTET STRING`, received in two chunks
+/// let chunks: [&[u8]; 2] = [&[0x30, 0x05, 0x04, 0x03, 0x01], &[0x02, 0x03]];
+///
+/// let mut parser = PushParser::<4>::new();
+/// let mut value = Vec::new();
+///
+/// for mut chunk in chunks.iter().copied() {
+///     loop {
+///         match parser.push(&mut chunk)? {
+///             PushEvent::NeedMoreData => break,
+///             PushEvent::Value(bytes) => value.extend_from_slice(bytes),
+///             PushEvent::Start(_) | PushEvent::End(_) => (),
+///         }
+///     }
+/// }
+///
+/// assert!(parser.is_complete());
+/// assert_eq!(value, [1, 2, 3]);
+/// # Ok::<(), der::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct PushParser<const DEPTH: usize> {
+    /// Bytes of a partially received header.
+    header: [u8; MAX_HEADER_LEN],
+
+    /// Number of bytes of a partially received header.
+    header_len: usize,
+
+    /// Fields which have been started but not yet ended, outermost first.
+    fields: [Field; DEPTH],
+
+    /// Number of fields which have been started but not yet ended.
+    depth: usize,
+
+    /// Total number of bytes consumed so far.
+    position: Length,
+
+    /// Did parsing fail due to an error?
+    failed: bool,
+}
+
+impl<const DEPTH: usize> PushParser<DEPTH> {
+    /// Create a new [`PushParser`].
+    pub fn new() -> Self {
+        Self {
+            header: [0; MAX_HEADER_LEN],
+            header_len: 0,
+            fields: [Field {
+                tag: Tag::Null,
+                end: Length::ZERO,
+            }; DEPTH],
+            depth: 0,
+            position: Length::ZERO,
+            failed: false,
+        }
+    }
+
+    /// Parse the next event from the given input, advancing it past the
+    /// bytes which have been consumed.
+    ///
+    /// Returns [`PushEvent::NeedMoreData`] once the input is empty and there
+    /// are no further events to produce.
+    pub fn push<'i>(&mut self, input: &mut &'i [u8]) -> Result<PushEvent<'i>> {
+        if self.failed {
+            return Err(ErrorKind::Failed.at(self.position));
+        }
+
+        let result = self.next_event(input);
+        self.failed = result.is_err();
+        result
+    }
+
+    /// Get the number of fields which have been started but not yet ended.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Get the total number of bytes consumed so far.
+    pub fn position(&self) -> Length {
+        self.position
+    }
+
+    /// Is the parser at the boundary between messages, i.e. has every
+    /// field which has been started been ended?
+    ///
+    /// This is also the case before any input has been pushed.
+    pub fn is_complete(&self) -> bool {
+        !self.failed && self.depth == 0 && self.header_len == 0
+    }
+
+    /// Did parsing fail due to an error?
+    pub fn is_failed(&self) -> bool {
+        self.failed
+    }
+
+    /// Parse the next event from the given input.
+    fn next_event<'i>(&mut self, input: &mut &'i [u8]) -> Result<PushEvent<'i>> {
+        let field = self.depth.checked_sub(1).map(|index| self.fields[index]);
+
+        if let Some(field) = field {
+            if field.end == self.position {
+                self.depth -= 1;
+                return Ok(PushEvent::End(field.tag));
+            }
+
+            if !field.tag.is_constructed() {
+                if input.is_empty() {
+                    return Ok(PushEvent::NeedMoreData);
+                }
+
+                let remaining = usize::try_from((field.end - self.position)?)?;
+                let (value, rest) = input.split_at(remaining.min(input.len()));
+                *input = rest;
+                self.position = (self.position + value.len())?;
+                return Ok(PushEvent::Value(value));
+            }
+        }
+
+        let header = match self.header(input)? {
+            Some(header) => header,
+            None => return Ok(PushEvent::NeedMoreData),
+        };
+
+        let header_start = self.position;
+        self.position = (self.position + self.header_len)?;
+        self.header_len = 0;
+
+        let end = (self.position + header.length).map_err(|e| e.kind().at(header_start))?;
+
+        if matches!(field, Some(field) if end > field.end) {
+            return Err(ErrorKind::Length {
+                tag: header.tag,
+                length: Some(header.length),
+            }
+            .at(header_start));
+        }
+
+        if self.depth == DEPTH {
+            return Err(ErrorKind::Overlength.at(header_start));
+        }
+
+        self.fields[self.depth] = Field {
+            tag: header.tag,
+            end,
+        };
+        self.depth += 1;
+        Ok(PushEvent::Start(header))
+    }
+
+    /// Consume bytes from the input until a complete header has been
+    /// received, returning `None` if the input is exhausted first.
+    fn header(&mut self, input: &mut &[u8]) -> Result<Option<Header>> {
+        while let Some((&byte, rest)) = input.split_first() {
+            if self.header_len == MAX_HEADER_LEN {
+                return Err(ErrorKind::Overlength.at(self.position));
+            }
+
+            self.header[self.header_len] = byte;
+            self.header_len += 1;
+            *input = rest;
+
+            match Header::from_der(&self.header[..self.header_len]) {
+                Ok(header) => return Ok(Some(header)),
+                Err(e) if e.kind() == ErrorKind::Truncated => continue,
+                Err(e) => return Err(e.kind().at(self.position)),
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<const DEPTH: usize> Default for PushParser<DEPTH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Field which has been started but not yet ended.
+#[derive(Copy, Clone, Debug)]
+struct Field {
+    /// Tag of the field.
+    tag: Tag,
+
+    /// Position of the end of the field.
+    end: Length,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PushEvent, PushParser};
+    use crate::{ErrorKind, Header, Length, Tag, TagNumber};
+    use hex_literal::hex;
+
+    /// `SEQUENCE` containing an `INTEGER`, an empty `OCTET STRING`, and a
+    /// `[1]` containing a `NULL`.
+    const EXAMPLE: &[u8] = &hex!("300B02030100010400A1020500");
+
+    /// Push the given chunks, collecting the events.
+    fn parse<'i>(
+        parser: &mut PushParser<4>,
+        chunks: impl IntoIterator<Item = &'i [u8]>,
+        events: &mut [PushEvent<'i>],
+    ) -> usize {
+        let mut n = 0;
+
+        for mut chunk in chunks {
+            loop {
+                match parser.push(&mut chunk).unwrap() {
+                    PushEvent::NeedMoreData => break,
+                    event => {
+                        events[n] = event;
+                        n += 1;
+                    }
+                }
+            }
+        }
+
+        n
+    }
+
+    #[test]
+    fn single_chunk() {
+        let mut parser = PushParser::<4>::new();
+        let mut events = [PushEvent::NeedMoreData; 16];
+        let n = parse(&mut parser, Some(EXAMPLE), &mut events);
+
+        let context_specific = Tag::ContextSpecific {
+            constructed: true,
+            number: TagNumber::new(1),
+        };
+
+        assert_eq!(
+            events[..n],
+            [
+                PushEvent::Start(Header::new(Tag::Sequence, 11u8).unwrap()),
+                PushEvent::Start(Header::new(Tag::Integer, 3u8).unwrap()),
+                PushEvent::Value(&hex!("010001")),
+                PushEvent::End(Tag::Integer),
+                PushEvent::Start(Header::new(Tag::OctetString, 0u8).unwrap()),
+                PushEvent::End(Tag::OctetString),
+                PushEvent::Start(Header::new(context_specific, 2u8).unwrap()),
+                PushEvent::Start(Header::new(Tag::Null, 0u8).unwrap()),
+                PushEvent::End(Tag::Null),
+                PushEvent::End(context_specific),
+                PushEvent::End(Tag::Sequence),
+            ]
+        );
+
+        assert!(parser.is_complete());
+        assert_eq!(parser.position(), Length::new(13));
+    }
+
+    #[test]
+    fn byte_at_a_time() {
+        let mut parser = PushParser::<4>::new();
+        let mut events = [PushEvent::NeedMoreData; 16];
+        let n = parse(&mut parser, EXAMPLE.chunks(1), &mut events);
+
+        // The value of the `INTEGER` is split into 3 events
+        assert_eq!(n, 13);
+        assert_eq!(
+            events[2..5],
+            [
+                PushEvent::Value(&[1]),
+                PushEvent::Value(&[0]),
+                PushEvent::Value(&[1])
+            ]
+        );
+        assert!(parser.is_complete());
+
+        // Incomplete messages
+        let mut parser = PushParser::<4>::new();
+        parse(&mut parser, EXAMPLE[..1].chunks(1), &mut events);
+        assert!(!parser.is_complete());
+        parse(&mut parser, EXAMPLE[1..12].chunks(1), &mut events);
+        assert!(!parser.is_complete());
+        assert_eq!(parser.depth(), 2);
+    }
+
+    #[test]
+    fn consecutive_messages() {
+        let mut parser = PushParser::<4>::new();
+        let mut events = [PushEvent::NeedMoreData; 8];
+        let n = parse(&mut parser, Some(&hex!("05000101FF")[..]), &mut events);
+
+        assert_eq!(
+            events[..n],
+            [
+                PushEvent::Start(Header::new(Tag::Null, 0u8).unwrap()),
+                PushEvent::End(Tag::Null),
+                PushEvent::Start(Header::new(Tag::Boolean, 1u8).unwrap()),
+                PushEvent::Value(&[0xFF]),
+                PushEvent::End(Tag::Boolean),
+            ]
+        );
+    }
+
+    #[test]
+    fn reject_nested_overrun() {
+        // `OCTET STRING` longer than its containing `SEQUENCE`
+        let mut parser = PushParser::<4>::new();
+        let mut input = &hex!("300304030102")[..];
+        parser.push(&mut input).unwrap();
+
+        let err = parser.push(&mut input).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::OctetString,
+                length: Some(Length::new(3))
+            }
+        );
+        assert_eq!(err.position(), Some(Length::new(2)));
+
+        assert!(parser.is_failed());
+        assert_eq!(
+            parser.push(&mut input).err().unwrap().kind(),
+            ErrorKind::Failed
+        );
+    }
+
+    #[test]
+    fn reject_excessive_depth() {
+        let mut parser = PushParser::<1>::new();
+        let mut input = &hex!("30023000")[..];
+        parser.push(&mut input).unwrap();
+
+        let err = parser.push(&mut input).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
+
+    #[test]
+    fn reject_invalid_header() {
+        // Indefinite length
+        let mut parser = PushParser::<4>::new();
+        let mut input = &hex!("3080")[..];
+        assert!(parser.push(&mut input).is_err());
+
+        // Non-minimal length, split across chunks
+        let mut parser = PushParser::<4>::new();
+        let mut input = &hex!("0481")[..];
+        assert_eq!(parser.push(&mut input).unwrap(), PushEvent::NeedMoreData);
+        assert!(parser.push(&mut &[0x01][..]).is_err());
+    }
+}
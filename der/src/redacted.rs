@@ -0,0 +1,55 @@
+//! Redaction of secret fields in `Debug` output.
+
+use core::fmt;
+
+/// Placeholder for a secret value in [`fmt::Debug`] output.
+///
+/// Formats as `[REDACTED]`. Used by [`debug_redacted!`][crate::debug_redacted]
+/// so secrets are consistently omitted from logs.
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+pub struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+/// Implement [`fmt::Debug`] for a struct, printing the values of its public
+/// fields and [`Redacted`] in place of the values of its secret fields.
+///
+/// ```
+/// struct Key<'a> {
+///     algorithm: u8,
+///     secret: &'a [u8],
+/// }
+///
+/// der::debug_redacted!(Key<'a> { algorithm; redact secret });
+///
+/// let key = Key { algorithm: 1, secret: b"hunter2" };
+/// assert_eq!(
+///     format!("{:?}", key),
+///     "Key { algorithm: 1, secret: [REDACTED] }"
+/// );
+/// ```
+#[macro_export]
+macro_rules! debug_redacted {
+    (
+        $name:ident $(<$lt:lifetime>)? {
+            $($field:ident),* ;
+            redact $($secret:ident),* $(,)?
+        }
+    ) => {
+        impl$(<$lt>)? ::core::fmt::Debug for $name $(<$lt>)? {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($field), &self.$field))*
+                    $(.field(stringify!($secret), {
+                        let _ = &self.$secret;
+                        &$crate::Redacted
+                    }))*
+                    .finish()
+            }
+        }
+    };
+}
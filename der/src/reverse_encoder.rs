@@ -0,0 +1,291 @@
+//! Single-pass back-to-front DER encoder.
+
+use crate::{Encodable, EncodeValue, Encoder, ErrorKind, Header, Length, Result, Tag};
+use core::convert::TryFrom;
+
+/// DER encoder which writes the encoded message back to front, starting
+/// at the end of its buffer.
+///
+/// Encoding a value with [`Encoder`] requires knowing the length of each
+/// constructed field before its contents are written, so the lengths of
+/// nested fields are computed again at every level of nesting, which is
+/// quadratic in the depth of the message. Writing back to front, the
+/// contents of each field are written before its header, at which point
+/// their length is known, so a message is encoded in a single pass.
+///
+/// Types opt in by overriding [`Encodable::encode_reverse`] or
+/// [`EncodeValue::encode_value_reverse`], which is done for `SEQUENCE`s
+/// (including those which derive [`Sequence`][`crate::Sequence`]),
+/// `SEQUENCE OF`, `SET OF`, context-specific fields and `OPTIONAL` fields.
+/// Other types are encoded front to back into space reserved for them.
+///
+/// [`Encodable::encode_to_slice`] and [`Encodable::to_vec`] use this
+/// encoder, so it only needs to be used directly by types which implement
+/// [`Encodable::encode_reverse`].
+///
+/// ```
+/// use der::{asn1::OctetString, Encodable, ReverseEncoder, Tag};
+///
+/// let mut buf = [0u8; 16];
+/// let mut encoder = ReverseEncoder::new(&mut buf);
+///
+/// // `SEQUENCE` containing an `OCTET STRING`, whose header is written last
+/// encoder.tlv(Tag::Sequence, |encoder| {
+///     encoder.encode(&OctetString::new(&[0x01, 0x02, 0x03])?)
+/// })?;
+///
+/// assert_eq!(encoder.finish()?, &[0x30, 0x05, 0x04, 0x03, 0x01, 0x02, 0x03]);
+/// # Ok::<(), der::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct ReverseEncoder<'a> {
+    /// Buffer to which the DER-encoded message is written, or `None` if
+    /// encoding failed.
+    bytes: Option<&'a mut [u8]>,
+
+    /// Offset into the buffer of the start of the bytes written so far.
+    position: usize,
+}
+
+impl<'a> ReverseEncoder<'a> {
+    /// Create a new encoder which writes to the end of the given buffer.
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        let position = bytes.len();
+
+        Self {
+            bytes: Some(bytes),
+            position,
+        }
+    }
+
+    /// Encode a value which impls the [`Encodable`] trait, before the
+    /// values encoded so far.
+    pub fn encode<T: Encodable + ?Sized>(&mut self, encodable: &T) -> Result<()> {
+        if self.is_failed() {
+            self.error(ErrorKind::Failed)?;
+        }
+
+        let result = encodable.encode_reverse(self);
+
+        if result.is_err() {
+            self.bytes.take();
+        }
+
+        result
+    }
+
+    /// Encode a tag-length-value field with the given [`Tag`], whose value
+    /// is encoded back to front by the provided function.
+    ///
+    /// The length in the header is the number of bytes the function wrote.
+    pub fn tlv<F>(&mut self, tag: Tag, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut ReverseEncoder<'a>) -> Result<()>,
+    {
+        let end = self.position;
+        f(self)?;
+
+        let length = Length::try_from(end - self.position)?;
+        self.encode_forward(&Header::new(tag, length)?)
+    }
+
+    /// Encode a value front to back into space reserved for it before the
+    /// values encoded so far.
+    ///
+    /// This is how types which don't override [`Encodable::encode_reverse`]
+    /// are encoded.
+    pub fn encode_forward<T: Encodable + ?Sized>(&mut self, encodable: &T) -> Result<()> {
+        let length = encodable.encoded_len()?;
+        let mut encoder = Encoder::new(self.reserve(length)?);
+        encodable.encode(&mut encoder)?;
+        let actual_len = encoder.finish()?.len();
+        self.check_len(length, actual_len)
+    }
+
+    /// Encode the value of a field front to back into space reserved for it
+    /// before the values encoded so far.
+    ///
+    /// This is how types which don't override
+    /// [`EncodeValue::encode_value_reverse`] are encoded.
+    pub fn encode_value_forward<T: EncodeValue + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let length = value.value_len()?;
+        let mut encoder = Encoder::new(self.reserve(length)?);
+        value.encode_value(&mut encoder)?;
+        let actual_len = encoder.finish()?.len();
+        self.check_len(length, actual_len)
+    }
+
+    /// Return an error with the given [`ErrorKind`].
+    pub fn error<T>(&mut self, kind: ErrorKind) -> Result<T> {
+        self.bytes.take();
+        Err(kind.into())
+    }
+
+    /// Did the encoding operation fail due to an error?
+    pub fn is_failed(&self) -> bool {
+        self.bytes.is_none()
+    }
+
+    /// Get the number of bytes written so far.
+    pub fn encoded_len(&self) -> Result<Length> {
+        let buffer_len = self.bytes.as_ref().map(|bytes| bytes.len());
+        let buffer_len = buffer_len.ok_or(ErrorKind::Failed)?;
+        Length::try_from(buffer_len - self.position)
+    }
+
+    /// Finish encoding, returning a slice containing the data written to
+    /// the end of the buffer.
+    pub fn finish(self) -> Result<&'a [u8]> {
+        let position = self.position;
+
+        self.bytes
+            .and_then(|bytes| bytes.get(position..))
+            .ok_or_else(|| ErrorKind::Failed.into())
+    }
+
+    /// Reserve the given number of bytes before the values encoded so far,
+    /// returning a mutable slice of them.
+    fn reserve(&mut self, len: Length) -> Result<&mut [u8]> {
+        let len = usize::try_from(len)?;
+
+        let start = match self.position.checked_sub(len) {
+            Some(start) => start,
+            None => return self.error(ErrorKind::Overlength),
+        };
+
+        let bytes = match self.bytes.as_mut() {
+            Some(bytes) => bytes,
+            None => return Err(ErrorKind::Failed.into()),
+        };
+
+        let end = self.position;
+        self.position = start;
+        bytes
+            .get_mut(start..end)
+            .ok_or_else(|| ErrorKind::Overlength.into())
+    }
+
+    /// Check a value encoded front to back has the expected length.
+    fn check_len(&mut self, expected: Length, actual: usize) -> Result<()> {
+        let actual = Length::try_from(actual)?;
+
+        if actual == expected {
+            Ok(())
+        } else {
+            self.error(ErrorKind::Underlength { expected, actual })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReverseEncoder;
+    use crate::{
+        asn1::{ContextSpecific, OctetString},
+        DecodeValue, Decoder, Encodable, EncodeValue, Encoder, ErrorKind, Length, Result, Tag,
+        TagMode, TagNumber, Tagged,
+    };
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use hex_literal::hex;
+
+    /// Number of calls to [`Counted::value_len`].
+    static VALUE_LEN_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    /// `NULL` which counts how many times its length is computed.
+    struct Counted;
+
+    impl<'a> DecodeValue<'a> for Counted {
+        fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+            crate::asn1::Null::decode_value(decoder, length).map(|_| Counted)
+        }
+    }
+
+    impl EncodeValue for Counted {
+        fn value_len(&self) -> Result<Length> {
+            VALUE_LEN_CALLS.fetch_add(1, Ordering::SeqCst);
+            Ok(Length::ZERO)
+        }
+
+        fn encode_value(&self, _encoder: &mut Encoder<'_>) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Tagged for Counted {
+        const TAG: Tag = Tag::Null;
+    }
+
+    #[test]
+    fn single_pass() {
+        let nested = [[[[[[[[Counted]]]]]]]];
+
+        let mut buf = [0u8; 32];
+        let mut encoder = Encoder::new(&mut buf);
+        nested.encode(&mut encoder).unwrap();
+        let forward_calls = VALUE_LEN_CALLS.swap(0, Ordering::SeqCst);
+        let forward = encoder.finish().unwrap().to_vec();
+
+        let mut buf = [0u8; 32];
+        assert_eq!(
+            nested.encode_to_slice(&mut buf).unwrap(),
+            forward.as_slice()
+        );
+        assert_eq!(forward.len(), 18);
+        assert_eq!(&forward[16..], &[0x05, 0x00]);
+
+        // Once to size the buffer, and once to reserve space for the value
+        assert_eq!(VALUE_LEN_CALLS.load(Ordering::SeqCst), 2);
+        assert!(forward_calls > 2);
+    }
+
+    #[test]
+    fn context_specific() {
+        let value = OctetString::new(&[0x01, 0x02]).unwrap();
+        let mut buf = [0u8; 16];
+
+        for &(tag_mode, expected) in &[
+            (TagMode::Explicit, &hex!("A1 04 04 02 01 02")[..]),
+            (TagMode::Implicit, &hex!("81 02 01 02")[..]),
+        ] {
+            let field = ContextSpecific {
+                tag_number: TagNumber::new(1),
+                tag_mode,
+                value,
+            };
+
+            let mut encoder = ReverseEncoder::new(&mut buf);
+            encoder.encode(&field).unwrap();
+            assert_eq!(encoder.finish().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn optional() {
+        let fields = (Some(OctetString::new(&[0x01]).unwrap()), None::<bool>);
+        let mut buf = [0u8; 8];
+        let mut encoder = ReverseEncoder::new(&mut buf);
+
+        encoder.encode(&fields.1).unwrap();
+        encoder.encode(&fields.0).unwrap();
+        assert_eq!(encoder.encoded_len().unwrap(), Length::new(3));
+        assert_eq!(encoder.finish().unwrap(), &hex!("04 01 01"));
+    }
+
+    #[test]
+    fn overlength() {
+        let mut buf = [0u8; 4];
+        let mut encoder = ReverseEncoder::new(&mut buf);
+
+        let err = encoder
+            .encode(&OctetString::new(&[0x01, 0x02, 0x03]).unwrap())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+        assert!(encoder.is_failed());
+        assert_eq!(encoder.finish().err().unwrap().kind(), ErrorKind::Failed);
+
+        let mut buf = [0u8; 4];
+        let err = [true, false].encode_to_slice(&mut buf).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
+}
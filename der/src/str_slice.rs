@@ -1,12 +1,21 @@
 //! Common handling for types backed by `str` slices with enforcement of a
 //! library-level length limitation i.e. `Length::max()`.
 
-use crate::{ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Length, Result};
+use crate::{ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result};
 use core::{convert::TryFrom, str};
 
 /// String slice newtype which respects the [`Length::max`] limit.
+///
+/// This is the common building block of the ASN.1 string types in
+/// [`asn1`][`crate::asn1`], and can be used to define custom string types
+/// without re-validating UTF-8 or length limits. It can be converted into
+/// ASN.1 string types with [`From`]/[`TryFrom`], which only need to perform
+/// additional character set validation (if any).
+///
+/// Note that this type impls [`DecodeValue`] and [`EncodeValue`] but not
+/// [`Tagged`][`crate::Tagged`], as the tag is determined by the wrapping type.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-pub(crate) struct StrSlice<'a> {
+pub struct StrSlice<'a> {
     /// Inner value
     pub(crate) inner: &'a str,
 
@@ -50,6 +59,20 @@ impl<'a> StrSlice<'a> {
     }
 }
 
+impl<'a> TryFrom<&'a str> for StrSlice<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl<'a> From<StrSlice<'a>> for &'a str {
+    fn from(s: StrSlice<'a>) -> &'a str {
+        s.as_str()
+    }
+}
+
 impl AsRef<str> for StrSlice<'_> {
     fn as_ref(&self) -> &str {
         self.as_str()
@@ -33,6 +33,12 @@ pub trait Tagged {
 /// - Bits 8/7: [`Class`]
 /// - Bit 6: primitive (0) or constructed (1)
 /// - Bits 5-1: tag number
+///
+/// Tag numbers greater than 30 use the high-tag-number form, where bits 5-1
+/// of the leading octet are all set and the tag number follows in
+/// subsequent octets (see [`TagNumber`]). This is supported for
+/// application, context-specific and private tags, and for the universal
+/// tags of the ASN.1 `DATE`, `TIME-OF-DAY` and `DATE-TIME` types.
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum Tag {
@@ -54,9 +60,27 @@ pub enum Tag {
     /// `OBJECT IDENTIFIER` tag: `0x06`.
     ObjectIdentifier,
 
+    /// `ObjectDescriptor` tag: `0x07`.
+    ObjectDescriptor,
+
+    /// `EXTERNAL` tag: `0x08`.
+    External,
+
+    /// `ENUMERATED` tag: `0x0A`.
+    Enumerated,
+
+    /// `EMBEDDED PDV` tag: `0x0B`.
+    EmbeddedPdv,
+
     /// `UTF8String` tag: `0x0C`.
     Utf8String,
 
+    /// `RELATIVE-OID` tag: `0x0D`.
+    RelativeOid,
+
+    /// `TIME` tag: `0x0E`.
+    Time,
+
     /// `SEQUENCE` tag: `0x10`.
     Sequence,
 
@@ -69,6 +93,9 @@ pub enum Tag {
     /// `PrintableString` tag: `0x13`.
     PrintableString,
 
+    /// `TeletexString` (`T61String`) tag: `0x14`.
+    TeletexString,
+
     /// `IA5String` tag: `0x16`.
     Ia5String,
 
@@ -78,6 +105,27 @@ pub enum Tag {
     /// `GeneralizedTime` tag: `0x18`.
     GeneralizedTime,
 
+    /// `VisibleString` tag: `0x1A`.
+    VisibleString,
+
+    /// `GeneralString` tag: `0x1B`.
+    GeneralString,
+
+    /// `UniversalString` tag: `0x1C`.
+    UniversalString,
+
+    /// `BMPString` tag: `0x1E`.
+    BmpString,
+
+    /// `DATE` tag: `0x1F 0x1F`.
+    Date,
+
+    /// `TIME-OF-DAY` tag: `0x1F 0x20`.
+    TimeOfDay,
+
+    /// `DATE-TIME` tag: `0x1F 0x21`.
+    DateTime,
+
     /// Application tag.
     Application {
         /// Is this tag constructed? (vs primitive).
@@ -119,7 +167,7 @@ impl Tag {
     }
 
     /// Get the [`Class`] that corresponds to this [`Tag`].
-    pub fn class(self) -> Class {
+    pub const fn class(self) -> Class {
         match self {
             Tag::Application { .. } => Class::Application,
             Tag::ContextSpecific { .. } => Class::ContextSpecific,
@@ -128,13 +176,34 @@ impl Tag {
         }
     }
 
-    /// Get the [`TagNumber`] (lower 6-bits) for this tag.
+    /// Get the [`TagNumber`] for this tag.
     pub fn number(self) -> TagNumber {
-        TagNumber(self.octet() & TagNumber::MASK)
+        match self.high_tag_number() {
+            Some(number) => number,
+            None => TagNumber((self.octet() & TagNumber::MASK).into()),
+        }
+    }
+
+    /// Get the tag number of tags encoded in high-tag-number form, i.e. which
+    /// are followed by further identifier octets.
+    pub(crate) const fn high_tag_number(self) -> Option<TagNumber> {
+        match self {
+            Tag::Date => Some(TagNumber(0x1F)),
+            Tag::TimeOfDay => Some(TagNumber(0x20)),
+            Tag::DateTime => Some(TagNumber(0x21)),
+            Tag::Application { number, .. }
+            | Tag::ContextSpecific { number, .. }
+            | Tag::Private { number, .. }
+                if number.is_high() =>
+            {
+                Some(number)
+            }
+            _ => None,
+        }
     }
 
     /// Does this tag represent a constructed (as opposed to primitive) field?
-    pub fn is_constructed(self) -> bool {
+    pub const fn is_constructed(self) -> bool {
         self.octet() & CONSTRUCTED_FLAG != 0
     }
 
@@ -159,7 +228,11 @@ impl Tag {
     }
 
     /// Get the octet encoding for this [`Tag`].
-    pub fn octet(self) -> u8 {
+    ///
+    /// For tags encoded in high-tag-number form (e.g. [`Tag::Date`]) this is
+    /// the leading identifier octet, which doesn't identify the tag on its
+    /// own.
+    pub const fn octet(self) -> u8 {
         match self {
             Tag::Boolean => 0x01,
             Tag::Integer => 0x02,
@@ -167,14 +240,26 @@ impl Tag {
             Tag::OctetString => 0x04,
             Tag::Null => 0x05,
             Tag::ObjectIdentifier => 0x06,
+            Tag::ObjectDescriptor => 0x07,
+            Tag::External => 0x08 | CONSTRUCTED_FLAG,
+            Tag::Enumerated => 0x0A,
+            Tag::EmbeddedPdv => 0x0B | CONSTRUCTED_FLAG,
             Tag::Utf8String => 0x0C,
+            Tag::RelativeOid => 0x0D,
+            Tag::Time => 0x0E,
             Tag::Sequence => 0x10 | CONSTRUCTED_FLAG,
             Tag::Set => 0x11 | CONSTRUCTED_FLAG,
             Tag::NumericString => 0x12,
             Tag::PrintableString => 0x13,
+            Tag::TeletexString => 0x14,
             Tag::Ia5String => 0x16,
             Tag::UtcTime => 0x17,
             Tag::GeneralizedTime => 0x18,
+            Tag::VisibleString => 0x1A,
+            Tag::GeneralString => 0x1B,
+            Tag::UniversalString => 0x1C,
+            Tag::BmpString => 0x1E,
+            Tag::Date | Tag::TimeOfDay | Tag::DateTime => TagNumber::MASK,
             Tag::Application {
                 constructed,
                 number,
@@ -192,7 +277,11 @@ impl Tag {
 
     /// Create an [`Error`] for an invalid [`Length`].
     pub fn length_error(self) -> Error {
-        ErrorKind::Length { tag: self }.into()
+        ErrorKind::Length {
+            tag: self,
+            length: None,
+        }
+        .into()
     }
 
     /// Create an [`Error`] for an non-canonical value with the ASN.1 type
@@ -223,7 +312,7 @@ impl TryFrom<u8> for Tag {
 
     fn try_from(byte: u8) -> Result<Tag> {
         let constructed = byte & CONSTRUCTED_FLAG != 0;
-        let number = TagNumber::try_from(byte & TagNumber::MASK)?;
+        let number = TagNumber((byte & TagNumber::MASK).into());
 
         match byte {
             0x01 => Ok(Tag::Boolean),
@@ -232,14 +321,26 @@ impl TryFrom<u8> for Tag {
             0x04 => Ok(Tag::OctetString),
             0x05 => Ok(Tag::Null),
             0x06 => Ok(Tag::ObjectIdentifier),
+            0x07 => Ok(Tag::ObjectDescriptor),
+            0x0A => Ok(Tag::Enumerated),
             0x0C => Ok(Tag::Utf8String),
+            0x0D => Ok(Tag::RelativeOid),
+            0x0E => Ok(Tag::Time),
             0x12 => Ok(Tag::NumericString),
             0x13 => Ok(Tag::PrintableString),
+            0x14 => Ok(Tag::TeletexString),
             0x16 => Ok(Tag::Ia5String),
             0x17 => Ok(Tag::UtcTime),
             0x18 => Ok(Tag::GeneralizedTime),
-            0x30 => Ok(Tag::Sequence), // constructed
-            0x31 => Ok(Tag::Set),      // constructed
+            0x1A => Ok(Tag::VisibleString),
+            0x1B => Ok(Tag::GeneralString),
+            0x1C => Ok(Tag::UniversalString),
+            0x1E => Ok(Tag::BmpString),
+            0x28 => Ok(Tag::External),    // constructed
+            0x2B => Ok(Tag::EmbeddedPdv), // constructed
+            0x30 => Ok(Tag::Sequence),    // constructed
+            0x31 => Ok(Tag::Set),         // constructed
+            _ if number.is_high() => Err(ErrorKind::UnknownTag { byte }.into()),
             0x40..=0x7E => Ok(Tag::Application {
                 constructed,
                 number,
@@ -271,17 +372,56 @@ impl From<&Tag> for u8 {
 
 impl Decodable<'_> for Tag {
     fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
-        decoder.byte().and_then(Self::try_from)
+        let byte = decoder.byte()?;
+
+        if byte & TagNumber::MASK != TagNumber::MASK {
+            return Self::try_from(byte);
+        }
+
+        // High-tag-number form
+        let constructed = byte & CONSTRUCTED_FLAG != 0;
+        let number = TagNumber::decode_high(decoder, byte)?;
+
+        match byte & !(CONSTRUCTED_FLAG | TagNumber::MASK) {
+            0x00 if !constructed => match number.value() {
+                0x1F => Ok(Tag::Date),
+                0x20 => Ok(Tag::TimeOfDay),
+                0x21 => Ok(Tag::DateTime),
+                _ => Err(ErrorKind::UnknownTag { byte }.into()),
+            },
+            0x40 => Ok(Tag::Application {
+                constructed,
+                number,
+            }),
+            0x80 => Ok(Tag::ContextSpecific {
+                constructed,
+                number,
+            }),
+            0xC0 => Ok(Tag::Private {
+                constructed,
+                number,
+            }),
+            _ => Err(ErrorKind::UnknownTag { byte }.into()),
+        }
     }
 }
 
 impl Encodable for Tag {
     fn encoded_len(&self) -> Result<Length> {
-        Ok(Length::ONE)
+        let high_len = self.high_tag_number().map_or(0, TagNumber::high_len);
+        Length::ONE + Length::try_from(high_len)?
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        encoder.byte(self.into())
+        encoder.byte(self.into())?;
+
+        if let Some(number) = self.high_tag_number() {
+            for index in 0..number.high_len() {
+                encoder.byte(number.high_octet(index))?;
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -296,13 +436,27 @@ impl fmt::Display for Tag {
             Tag::OctetString => f.write_str("OCTET STRING"),
             Tag::Null => f.write_str("NULL"),
             Tag::ObjectIdentifier => f.write_str("OBJECT IDENTIFIER"),
+            Tag::ObjectDescriptor => f.write_str("ObjectDescriptor"),
+            Tag::External => f.write_str("EXTERNAL"),
+            Tag::Enumerated => f.write_str("ENUMERATED"),
+            Tag::EmbeddedPdv => f.write_str("EMBEDDED PDV"),
             Tag::Utf8String => f.write_str("UTF8String"),
+            Tag::RelativeOid => f.write_str("RELATIVE-OID"),
+            Tag::Time => f.write_str("TIME"),
             Tag::Set => f.write_str("SET"),
             Tag::NumericString => f.write_str("NumericString"),
             Tag::PrintableString => f.write_str("PrintableString"),
+            Tag::TeletexString => f.write_str("TeletexString"),
             Tag::Ia5String => f.write_str("IA5String"),
             Tag::UtcTime => f.write_str("UTCTime"),
             Tag::GeneralizedTime => f.write_str("GeneralizedTime"),
+            Tag::VisibleString => f.write_str("VisibleString"),
+            Tag::GeneralString => f.write_str("GeneralString"),
+            Tag::UniversalString => f.write_str("UniversalString"),
+            Tag::BmpString => f.write_str("BMPString"),
+            Tag::Date => f.write_str("DATE"),
+            Tag::TimeOfDay => f.write_str("TIME-OF-DAY"),
+            Tag::DateTime => f.write_str("DATE-TIME"),
             Tag::Sequence => f.write_str("SEQUENCE"),
             Tag::Application {
                 constructed,
@@ -334,7 +488,15 @@ impl fmt::Display for Tag {
 
 impl fmt::Debug for Tag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Tag(0x{:02x}: {})", u8::from(*self), self)
+        write!(f, "Tag(0x{:02x}", u8::from(*self))?;
+
+        if let Some(number) = self.high_tag_number() {
+            for index in 0..number.high_len() {
+                write!(f, "{:02x}", number.high_octet(index))?;
+            }
+        }
+
+        write!(f, ": {})", self)
     }
 }
 
@@ -342,6 +504,8 @@ impl fmt::Debug for Tag {
 mod tests {
     use super::TagNumber;
     use super::{Class, Tag};
+    use crate::{Decodable, Encodable, ErrorKind, Length};
+    use core::convert::TryFrom;
 
     #[test]
     fn tag_class() {
@@ -351,13 +515,27 @@ mod tests {
         assert_eq!(Tag::OctetString.class(), Class::Universal);
         assert_eq!(Tag::Null.class(), Class::Universal);
         assert_eq!(Tag::ObjectIdentifier.class(), Class::Universal);
+        assert_eq!(Tag::ObjectDescriptor.class(), Class::Universal);
+        assert_eq!(Tag::External.class(), Class::Universal);
+        assert_eq!(Tag::Enumerated.class(), Class::Universal);
+        assert_eq!(Tag::EmbeddedPdv.class(), Class::Universal);
         assert_eq!(Tag::Utf8String.class(), Class::Universal);
+        assert_eq!(Tag::RelativeOid.class(), Class::Universal);
+        assert_eq!(Tag::Time.class(), Class::Universal);
         assert_eq!(Tag::Set.class(), Class::Universal);
         assert_eq!(Tag::NumericString.class(), Class::Universal);
         assert_eq!(Tag::PrintableString.class(), Class::Universal);
+        assert_eq!(Tag::TeletexString.class(), Class::Universal);
         assert_eq!(Tag::Ia5String.class(), Class::Universal);
         assert_eq!(Tag::UtcTime.class(), Class::Universal);
         assert_eq!(Tag::GeneralizedTime.class(), Class::Universal);
+        assert_eq!(Tag::VisibleString.class(), Class::Universal);
+        assert_eq!(Tag::GeneralString.class(), Class::Universal);
+        assert_eq!(Tag::UniversalString.class(), Class::Universal);
+        assert_eq!(Tag::BmpString.class(), Class::Universal);
+        assert_eq!(Tag::Date.class(), Class::Universal);
+        assert_eq!(Tag::TimeOfDay.class(), Class::Universal);
+        assert_eq!(Tag::DateTime.class(), Class::Universal);
         assert_eq!(Tag::Sequence.class(), Class::Universal);
 
         for num in 0..=30 {
@@ -393,4 +571,69 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn high_tag_number() {
+        for &(tag, number) in &[(Tag::Date, 31), (Tag::TimeOfDay, 32), (Tag::DateTime, 33)] {
+            let mut buf = [0u8; 2];
+            let bytes = tag.encode_to_slice(&mut buf).unwrap();
+            assert_eq!(bytes, [0x1F, number]);
+            assert_eq!(Tag::from_der(bytes).unwrap(), tag);
+            assert_eq!(tag.number(), TagNumber(number.into()));
+            assert!(!tag.is_constructed());
+        }
+
+        // DURATION and other universal tags in high-tag-number form are
+        // unsupported
+        assert!(Tag::from_der(&[0x1F, 0x22]).is_err());
+        assert!(Tag::from_der(&[0x3F, 0x1F]).is_err());
+        assert!(Tag::try_from(0x1F).is_err());
+    }
+
+    #[test]
+    fn high_tag_number_classes() {
+        let examples: &[(Tag, &[u8])] = &[
+            (TagNumber::new(31).application(false), &[0x5F, 0x1F]),
+            (TagNumber::new(127).context_specific(true), &[0xBF, 0x7F]),
+            (TagNumber::new(128).private(false), &[0xDF, 0x81, 0x00]),
+            (
+                TagNumber::new(0x3FFF).application(true),
+                &[0x7F, 0xFF, 0x7F],
+            ),
+            (
+                TagNumber::new(u32::MAX).context_specific(false),
+                &[0x9F, 0x8F, 0xFF, 0xFF, 0xFF, 0x7F],
+            ),
+        ];
+
+        for &(tag, bytes) in examples {
+            let mut buf = [0u8; 8];
+            assert_eq!(
+                tag.encoded_len().unwrap(),
+                Length::try_from(bytes.len()).unwrap()
+            );
+            assert_eq!(tag.encode_to_slice(&mut buf).unwrap(), bytes);
+            assert_eq!(Tag::from_der(bytes).unwrap(), tag);
+            assert_eq!(tag.octet(), bytes[0]);
+            assert!(tag.number().is_high());
+        }
+    }
+
+    #[test]
+    fn high_tag_number_errors() {
+        // Tag numbers below 31 must use the leading octet
+        assert!(Tag::from_der(&[0x9F, 0x1E]).is_err());
+
+        // Leading zero digit
+        assert!(Tag::from_der(&[0x9F, 0x80, 0x7F]).is_err());
+
+        // Truncated
+        assert!(Tag::from_der(&[0x9F, 0x81]).is_err());
+
+        // Tag number exceeding `u32`
+        let err = Tag::from_der(&[0x9F, 0x90, 0x80, 0x80, 0x80, 0x00])
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overflow);
+    }
 }
@@ -31,9 +31,15 @@ pub enum Class {
 }
 
 impl Class {
-    /// Compute the identifier octet for a tag number of this class.
-    pub(super) fn octet(self, constructed: bool, number: TagNumber) -> u8 {
-        self as u8 | number.value() | (constructed as u8 * CONSTRUCTED_FLAG)
+    /// Compute the (leading) identifier octet for a tag number of this class.
+    pub(super) const fn octet(self, constructed: bool, number: TagNumber) -> u8 {
+        let number = if number.is_high() {
+            TagNumber::MASK
+        } else {
+            number.value() as u8
+        };
+
+        self as u8 | number | (constructed as u8 * CONSTRUCTED_FLAG)
     }
 }
 
@@ -1,42 +1,35 @@
 //! ASN.1 tag numbers
 
 use super::Tag;
-use crate::{Error, ErrorKind, Result};
-use core::{convert::TryFrom, fmt};
+use crate::{Decoder, ErrorKind, Result};
+use core::fmt;
 
-/// ASN.1 tag numbers (i.e. lower 5 bits of a [`Tag`]).
+/// ASN.1 tag numbers.
 ///
 /// From X.690 Section 8.1.2.2:
 ///
 /// > bits 5 to 1 shall encode the number of the tag as a binary integer with
 /// > bit 5 as the most significant bit.
 ///
-/// This library supports tag numbers ranging from zero to 30 (inclusive),
-/// which can be represented as a single identifier octet.
-///
-/// Section 8.1.2.4 describes how to support multi-byte tag numbers, which are
-/// encoded by using a leading tag number of 31 (`0b11111`). This library
-/// deliberately does not support this: tag numbers greater than 30 are
-/// disallowed.
+/// Tag numbers ranging from zero to 30 (inclusive) are represented in the
+/// leading identifier octet itself. Greater tag numbers are encoded in the
+/// high-tag-number form described in X.690 Section 8.1.2.4, where bits 5 to
+/// 1 of the leading octet are all set (i.e. `0b11111`) and the tag number
+/// follows in base 128, using as few octets as possible.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-pub struct TagNumber(pub(super) u8);
+pub struct TagNumber(pub(super) u32);
 
 impl TagNumber {
-    /// Maximum tag number supported (inclusive).
-    pub const MAX: u8 = 30;
+    /// Maximum tag number which can be represented in the leading
+    /// identifier octet (inclusive).
+    pub const MAX_LOW: u32 = 30;
 
     /// Mask value used to obtain the tag number from a tag octet.
     pub(super) const MASK: u8 = 0b11111;
 
     /// Create a new tag number (const-friendly).
-    ///
-    /// Panics if the tag number is greater than [`TagNumber::MAX`]. For a fallible
-    /// conversion, use [`TryFrom`] instead.
-    #[allow(clippy::no_effect)]
-    pub const fn new(byte: u8) -> Self {
-        // TODO(tarcieri): hax! use const panic when available
-        ["tag number out of range"][(byte > Self::MAX) as usize];
-        Self(byte)
+    pub const fn new(number: u32) -> Self {
+        Self(number)
     }
 
     /// Create an `APPLICATION` tag with this tag number.
@@ -64,24 +57,95 @@ impl TagNumber {
     }
 
     /// Get the inner value.
-    pub fn value(self) -> u8 {
+    pub const fn value(self) -> u32 {
         self.0
     }
-}
 
-impl TryFrom<u8> for TagNumber {
-    type Error = Error;
+    /// Is this tag number encoded in the high-tag-number form, i.e. is it
+    /// greater than [`TagNumber::MAX_LOW`]?
+    pub const fn is_high(self) -> bool {
+        self.0 > Self::MAX_LOW
+    }
+
+    /// Get the number of octets following the leading identifier octet in
+    /// the high-tag-number form, or zero if it isn't used.
+    pub(crate) const fn high_len(self) -> usize {
+        if !self.is_high() {
+            return 0;
+        }
+
+        let mut len = 1;
+        let mut number = self.0 >> 7;
 
-    fn try_from(byte: u8) -> Result<Self> {
-        match byte {
-            0..=Self::MAX => Ok(Self(byte)),
-            _ => Err(ErrorKind::UnknownTag { byte }.into()),
+        while number != 0 {
+            len += 1;
+            number >>= 7;
         }
+
+        len
+    }
+
+    /// Get the octet at the given index of the high-tag-number form, with
+    /// bit 8 set on all but the last octet.
+    pub(crate) const fn high_octet(self, index: usize) -> u8 {
+        let len = self.high_len();
+        let octet = (self.0 >> (7 * (len - index - 1))) as u8 & 0x7F;
+
+        if index + 1 < len {
+            octet | 0x80
+        } else {
+            octet
+        }
+    }
+
+    /// Decode the octets following the leading identifier octet of a tag in
+    /// the high-tag-number form.
+    ///
+    /// Leading zero digits and tag numbers which fit in the leading octet
+    /// are rejected, as X.690 requires them to be encoded in as few octets
+    /// as possible.
+    pub(super) fn decode_high(decoder: &mut Decoder<'_>, byte: u8) -> Result<Self> {
+        let mut number = 0u32;
+
+        loop {
+            let octet = decoder.byte()?;
+
+            if number == 0 && octet == 0x80 {
+                return Err(ErrorKind::UnknownTag { byte }.into());
+            }
+
+            number = number
+                .checked_mul(0x80)
+                .map(|n| n | u32::from(octet & 0x7F))
+                .ok_or(ErrorKind::Overflow)?;
+
+            if octet & 0x80 == 0 {
+                break;
+            }
+        }
+
+        if number <= Self::MAX_LOW {
+            return Err(ErrorKind::UnknownTag { byte }.into());
+        }
+
+        Ok(Self(number))
+    }
+}
+
+impl From<u8> for TagNumber {
+    fn from(number: u8) -> Self {
+        Self(number.into())
+    }
+}
+
+impl From<u32> for TagNumber {
+    fn from(number: u32) -> Self {
+        Self(number)
     }
 }
 
-impl From<TagNumber> for u8 {
-    fn from(tag_number: TagNumber) -> u8 {
+impl From<TagNumber> for u32 {
+    fn from(tag_number: TagNumber) -> u32 {
         tag_number.0
     }
 }
@@ -1,6 +1,6 @@
 //! Value traits
 
-use crate::{Decoder, Encoder, Length, Result};
+use crate::{Decoder, Encoder, Length, Result, ReverseEncoder};
 
 #[cfg(doc)]
 use crate::Tag;
@@ -22,4 +22,13 @@ pub trait EncodeValue {
     /// Encode value (sans [`Tag`]+[`Length`] header) as ASN.1 DER using the
     /// provided [`Encoder`].
     fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()>;
+
+    /// Encode value (sans [`Tag`]+[`Length`] header) as ASN.1 DER back to
+    /// front using the provided [`ReverseEncoder`].
+    ///
+    /// The default implementation encodes it front to back into space
+    /// reserved for it using [`EncodeValue::encode_value`].
+    fn encode_value_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        encoder.encode_value_forward(self)
+    }
 }
@@ -4,8 +4,11 @@
 #![cfg(feature = "derive")]
 
 use der::{
-    asn1::{GeneralizedTime, UtcTime},
-    Choice, Decodable, Encodable, Encoder,
+    asn1::{
+        Any, BitString, ContextSpecificExplicit, ContextSpecificImplicit, GeneralizedTime,
+        ObjectIdentifier, UtcTime,
+    },
+    Choice, Decodable, Encodable, Encoder, ErrorKind, Sequence, Tag, TagNumber,
 };
 use hex_literal::hex;
 use std::time::Duration;
@@ -64,3 +67,205 @@ fn encode_enum_variants() {
     general_time.encode(&mut encoder).unwrap();
     assert_eq!(GENERAL_TIMESTAMP, encoder.finish().unwrap());
 }
+
+/// Custom derive test case for the `Sequence` macro with field order checks.
+///
+/// Based on `AlgorithmIdentifier` as defined in RFC 5280:
+/// <https://tools.ietf.org/html/rfc5280#section-4.1.1.2>
+///
+/// ```text
+/// AlgorithmIdentifier  ::=  SEQUENCE  {
+///      algorithm               OBJECT IDENTIFIER,
+///      parameters              ANY DEFINED BY algorithm OPTIONAL  }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct AlgorithmIdentifier<'a> {
+    #[asn1(order = 0)]
+    pub algorithm: ObjectIdentifier,
+
+    #[asn1(order = 1)]
+    pub parameters: Option<Any<'a>>,
+}
+
+const ALGORITHM_IDENTIFIER: &[u8] = &hex!("300d06092a864886f70d0101010500");
+
+#[test]
+fn decode_ordered_sequence() {
+    let algorithm_id = AlgorithmIdentifier::from_der(ALGORITHM_IDENTIFIER).unwrap();
+    assert_eq!(
+        algorithm_id.algorithm,
+        ObjectIdentifier::new("1.2.840.113549.1.1.1")
+    );
+    assert!(algorithm_id.parameters.unwrap().is_null());
+}
+
+#[test]
+fn encode_ordered_sequence() {
+    let algorithm_id = AlgorithmIdentifier::from_der(ALGORITHM_IDENTIFIER).unwrap();
+    assert_eq!(ALGORITHM_IDENTIFIER, algorithm_id.to_vec().unwrap());
+}
+
+/// Custom derive test case for the `Sequence` macro with `CONTEXT-SPECIFIC`
+/// fields which have `DEFAULT` values.
+///
+/// Based on the trailing fields of `RSASSA-PSS-params` as defined in
+/// RFC 4055: <https://datatracker.ietf.org/doc/html/rfc4055#section-3.1>
+///
+/// ```text
+/// RSASSA-PSS-params  ::=  SEQUENCE  {
+///     ...
+///     saltLength         [2] INTEGER DEFAULT 20,
+///     trailerField       [3] INTEGER DEFAULT 1  }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct PssParams {
+    #[asn1(context_specific = "2", default = "default_salt_length")]
+    pub salt_length: u32,
+
+    #[asn1(context_specific = "3", default = "default_trailer_field")]
+    pub trailer_field: u8,
+}
+
+fn default_salt_length() -> u32 {
+    20
+}
+
+fn default_trailer_field() -> u8 {
+    1
+}
+
+/// Custom derive test case for the `Sequence` macro with `EXPLICIT` and
+/// `IMPLICIT` `CONTEXT-SPECIFIC` fields.
+///
+/// Based on `TBSCertificate` as defined in RFC 5280:
+/// <https://tools.ietf.org/html/rfc5280#section-4.1>
+///
+/// ```text
+/// TBSCertificate  ::=  SEQUENCE  {
+///      version         [0]  EXPLICIT Version DEFAULT v1,
+///      serialNumber         CertificateSerialNumber,
+///      ...
+///      issuerUniqueID  [1]  IMPLICIT UniqueIdentifier OPTIONAL,
+///      ... }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct TbsCertificate<'a> {
+    #[asn1(context_specific = "0", default = "Default::default")]
+    pub version: u8,
+
+    pub serial_number: u8,
+
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub issuer_unique_id: Option<BitString<'a>>,
+}
+
+#[test]
+fn decode_default_fields() {
+    let params = PssParams::from_der(&hex!("3000")).unwrap();
+    assert_eq!(params.salt_length, 20);
+    assert_eq!(params.trailer_field, 1);
+
+    let params = PssParams::from_der(&hex!("3005A203020120")).unwrap();
+    assert_eq!(params.salt_length, 32);
+    assert_eq!(params.trailer_field, 1);
+}
+
+#[test]
+fn encode_default_fields() {
+    let params = PssParams {
+        salt_length: 20,
+        trailer_field: 1,
+    };
+    assert_eq!(params.to_vec().unwrap(), hex!("3000"));
+
+    let params = PssParams {
+        salt_length: 32,
+        ..params
+    };
+    assert_eq!(params.to_vec().unwrap(), hex!("3005A203020120"));
+}
+
+#[test]
+fn reject_encoded_default_fields() {
+    let err = PssParams::from_der(&hex!("3005A203020114")).err().unwrap();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Noncanonical {
+            tag: Tag::ContextSpecific {
+                constructed: true,
+                number: TagNumber::new(2)
+            }
+        }
+    );
+}
+
+#[test]
+fn round_trip_context_specific_fields() {
+    for example in &[
+        &hex!("3003020101")[..],
+        &hex!("3008A003020102020101"),
+        &hex!("300CA0030201020201018102005A"),
+    ] {
+        let tbs_certificate = TbsCertificate::from_der(example).unwrap();
+        assert_eq!(tbs_certificate.serial_number, 1);
+        assert_eq!(tbs_certificate.to_vec().unwrap(), *example);
+    }
+
+    let tbs_certificate = TbsCertificate::from_der(&hex!("300CA0030201020201018102005A")).unwrap();
+    assert_eq!(tbs_certificate.version, 2);
+    assert_eq!(
+        tbs_certificate.issuer_unique_id.unwrap().as_bytes(),
+        &[0x5A]
+    );
+
+    // `version` must be omitted if it's `v1`
+    assert!(TbsCertificate::from_der(&hex!("3008A003020100020101")).is_err());
+}
+
+/// Custom derive test case for the `Sequence` macro with fields whose types
+/// carry their `CONTEXT-SPECIFIC` tags.
+///
+/// Based on the trailing fields of `TBSCertificate` as defined in RFC 5280:
+/// <https://tools.ietf.org/html/rfc5280#section-4.1>
+///
+/// ```text
+/// TBSCertificate  ::=  SEQUENCE  {
+///      ...
+///      subjectPublicKeyInfo SubjectPublicKeyInfo,
+///      issuerUniqueID  [1]  IMPLICIT UniqueIdentifier OPTIONAL,
+///      subjectUniqueID [2]  IMPLICIT UniqueIdentifier OPTIONAL,
+///      extensions      [3]  EXPLICIT Extensions OPTIONAL
+///      }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct TbsCertificateTail<'a> {
+    pub subject_public_key_info: u8,
+    pub issuer_unique_id: Option<ContextSpecificImplicit<1, BitString<'a>>>,
+    pub subject_unique_id: Option<ContextSpecificImplicit<2, BitString<'a>>>,
+    pub extensions: Option<ContextSpecificExplicit<3, u8>>,
+}
+
+#[test]
+fn round_trip_typed_context_specific_fields() {
+    for example in &[
+        &hex!("3003020101")[..],
+        &hex!("3008020101A303020102"),
+        &hex!("3007020101820200A5"),
+        &hex!("30100201018102005A820200A5A303020102"),
+    ] {
+        let tbs_certificate = TbsCertificateTail::from_der(example).unwrap();
+        assert_eq!(tbs_certificate.subject_public_key_info, 1);
+        assert_eq!(tbs_certificate.to_vec().unwrap(), *example);
+    }
+
+    let tbs_certificate = TbsCertificateTail::from_der(&hex!("3007020101820200A5")).unwrap();
+    assert_eq!(tbs_certificate.issuer_unique_id, None);
+    assert_eq!(
+        tbs_certificate.subject_unique_id.unwrap().value.as_bytes(),
+        &[0xA5]
+    );
+    assert_eq!(tbs_certificate.extensions, None);
+
+    // Fields must be in tag number order
+    assert!(TbsCertificateTail::from_der(&hex!("300C020101A303020102820200A5")).is_err());
+}
@@ -0,0 +1,32 @@
+//! Golden file tests for the deterministically generated samples
+
+#![cfg(feature = "dev")]
+
+use der::dev::{check_golden, samples};
+use std::path::PathBuf;
+
+/// Path to the golden file of the sample with the given name and extension.
+fn golden_path(name: &str, extension: &str) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "tests", "golden", name]
+        .iter()
+        .collect::<PathBuf>()
+        .with_extension(extension)
+}
+
+#[test]
+fn der_samples() {
+    for sample in samples::all() {
+        check_golden(golden_path(sample.name, "der"), &sample.der).unwrap();
+    }
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn pem_samples() {
+    use der::pem::{self, LineEnding};
+
+    for sample in samples::all() {
+        let pem = pem::encode_string(sample.label, LineEnding::LF, &sample.der).unwrap();
+        check_golden(golden_path(sample.name, "pem"), pem.as_bytes()).unwrap();
+    }
+}
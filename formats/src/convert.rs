@@ -0,0 +1,429 @@
+//! Conversions between key formats with structured errors.
+//!
+//! The functions in this module convert DER-encoded keys between PKCS#1,
+//! PKCS#8, SEC1 and SPKI. When a conversion fails they return a
+//! [`ConversionError`] which identifies the source and target [`Format`]s,
+//! the offending algorithm OID if any, and a [`Hint`] about how the input
+//! can be fixed, which applications can show to their users:
+//!
+//! ```
+//! use formats::{
+//!     convert::{self, Hint},
+//!     Format,
+//! };
+//!
+//! let ed25519_key = include_bytes!("../tests/examples/ed25519-priv-pkcs8v1.der");
+//! let err = convert::pkcs8_to_pkcs1(ed25519_key).unwrap_err();
+//!
+//! assert_eq!(err.source(), Format::Pkcs8);
+//! assert_eq!(err.target(), Format::Pkcs1);
+//! assert_eq!(err.hint(), Some(Hint::NoAlgorithmSpecificFormat));
+//! ```
+
+use crate::{identify, Format};
+use core::fmt;
+use der::{asn1::ObjectIdentifier, Decodable};
+use pkcs1::{RsaPrivateKey, RsaPublicKey};
+use pkcs8::PrivateKeyInfo;
+use sec1::{point::ModulusSize, EcPrivateKey, EncodedPoint};
+use spki::{DecodeSubjectPublicKey, SubjectPublicKeyInfo};
+
+#[cfg(feature = "alloc")]
+use {
+    core::convert::TryFrom,
+    der::{asn1::Null, Encodable},
+    pkcs8::PrivateKeyDocument,
+    sec1::EcParameters,
+    spki::AlgorithmIdentifier,
+    zeroize::Zeroizing,
+};
+
+/// `rsaEncryption` algorithm OID.
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+
+/// `id-ecPublicKey` algorithm OID.
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.2.1");
+
+/// Result type with [`ConversionError`].
+pub type Result<T> = core::result::Result<T, ConversionError>;
+
+/// Suggestions for fixing the input of a failed conversion.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Hint {
+    /// The input is an encrypted PKCS#8 key, which has to be decrypted with
+    /// its passphrase before it can be converted.
+    EncryptedKeyNeedsPassphrase,
+
+    /// The input is in a different format than the one expected.
+    InputIsFormat(Format),
+
+    /// The key's algorithm is supported by the given format instead.
+    ConvertTo(Format),
+
+    /// Keys of this algorithm are only encoded as PKCS#8 or SPKI.
+    NoAlgorithmSpecificFormat,
+
+    /// The SEC1 key doesn't name its curve, which PKCS#8 requires.
+    MissingCurve,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::EncryptedKeyNeedsPassphrase => {
+                f.write_str("the key is encrypted, decrypt it with its passphrase first")
+            }
+            Hint::InputIsFormat(format) => write!(f, "the input is a {} key", format),
+            Hint::ConvertTo(format) => write!(f, "convert the key to {} instead", format),
+            Hint::NoAlgorithmSpecificFormat => {
+                f.write_str("keys of this algorithm are only encoded as PKCS#8 or SPKI")
+            }
+            Hint::MissingCurve => f.write_str("the key doesn't name its curve"),
+        }
+    }
+}
+
+/// Errors converting a key between formats.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ConversionError {
+    /// The input isn't a DER-encoded key in the source format.
+    Decode {
+        /// Format of the input.
+        source: Format,
+
+        /// Format being converted to.
+        target: Format,
+
+        /// Decoding error.
+        error: der::Error,
+
+        /// Suggestion for fixing the input.
+        hint: Option<Hint>,
+    },
+
+    /// The key's algorithm can't be encoded in the target format.
+    Algorithm {
+        /// Format of the input.
+        source: Format,
+
+        /// Format being converted to.
+        target: Format,
+
+        /// OID of the key's algorithm.
+        oid: ObjectIdentifier,
+
+        /// Suggestion for fixing the input.
+        hint: Option<Hint>,
+    },
+
+    /// The algorithm parameters are missing, malformed or inconsistent with
+    /// the key.
+    Parameters {
+        /// Format of the input.
+        source: Format,
+
+        /// Format being converted to.
+        target: Format,
+
+        /// OID of the key's algorithm.
+        oid: ObjectIdentifier,
+
+        /// Suggestion for fixing the input.
+        hint: Option<Hint>,
+    },
+
+    /// The algorithm-specific key data is malformed.
+    KeyMalformed {
+        /// Format of the input.
+        source: Format,
+
+        /// Format being converted to.
+        target: Format,
+
+        /// OID of the key's algorithm.
+        oid: ObjectIdentifier,
+    },
+
+    /// Encoding the key in the target format failed.
+    Encode {
+        /// Format of the input.
+        source: Format,
+
+        /// Format being converted to.
+        target: Format,
+
+        /// Encoding error.
+        error: der::Error,
+    },
+}
+
+impl ConversionError {
+    /// Get the format of the input.
+    pub fn source(&self) -> Format {
+        match *self {
+            ConversionError::Decode { source, .. }
+            | ConversionError::Algorithm { source, .. }
+            | ConversionError::Parameters { source, .. }
+            | ConversionError::KeyMalformed { source, .. }
+            | ConversionError::Encode { source, .. } => source,
+        }
+    }
+
+    /// Get the format being converted to.
+    pub fn target(&self) -> Format {
+        match *self {
+            ConversionError::Decode { target, .. }
+            | ConversionError::Algorithm { target, .. }
+            | ConversionError::Parameters { target, .. }
+            | ConversionError::KeyMalformed { target, .. }
+            | ConversionError::Encode { target, .. } => target,
+        }
+    }
+
+    /// Get the OID of the key's algorithm, if the error relates to it.
+    pub fn oid(&self) -> Option<ObjectIdentifier> {
+        match *self {
+            ConversionError::Algorithm { oid, .. }
+            | ConversionError::Parameters { oid, .. }
+            | ConversionError::KeyMalformed { oid, .. } => Some(oid),
+            ConversionError::Decode { .. } | ConversionError::Encode { .. } => None,
+        }
+    }
+
+    /// Get the suggestion for fixing the input, if there is one.
+    pub fn hint(&self) -> Option<Hint> {
+        match *self {
+            ConversionError::Decode { hint, .. }
+            | ConversionError::Algorithm { hint, .. }
+            | ConversionError::Parameters { hint, .. } => hint,
+            ConversionError::KeyMalformed { .. } | ConversionError::Encode { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "can't convert {} to {}: ", self.source(), self.target())?;
+
+        match self {
+            ConversionError::Decode { source, error, .. } => {
+                write!(f, "malformed {} key: {}", source, error)?
+            }
+            ConversionError::Algorithm { oid, .. } => write!(f, "unsupported algorithm {}", oid)?,
+            ConversionError::Parameters { oid, .. } => {
+                write!(f, "invalid parameters for algorithm {}", oid)?
+            }
+            ConversionError::KeyMalformed { oid, .. } => {
+                write!(f, "malformed key data for algorithm {}", oid)?
+            }
+            ConversionError::Encode { error, .. } => write!(f, "{}", error)?,
+        }
+
+        match self.hint() {
+            Some(hint) => write!(f, " ({})", hint),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+/// Extract the PKCS#1 RSA private key from a DER-encoded PKCS#8 key.
+pub fn pkcs8_to_pkcs1(der: &[u8]) -> Result<RsaPrivateKey<'_>> {
+    let private_key_info = decode_pkcs8(der, Format::Pkcs1)?;
+    let oid = private_key_info.algorithm.oid;
+
+    private_key_info
+        .try_into_rsa()
+        .map_err(|err| pkcs8_error(err, oid, Format::Pkcs1))
+}
+
+/// Extract the SEC1 EC private key from a DER-encoded PKCS#8 key.
+///
+/// The curve is copied from the PKCS#8 algorithm parameters if the SEC1 key
+/// doesn't include it.
+pub fn pkcs8_to_sec1(der: &[u8]) -> Result<EcPrivateKey<'_>> {
+    let private_key_info = decode_pkcs8(der, Format::Sec1)?;
+    let oid = private_key_info.algorithm.oid;
+
+    private_key_info
+        .try_into_ec()
+        .map_err(|err| pkcs8_error(err, oid, Format::Sec1))
+}
+
+/// Wrap a DER-encoded PKCS#1 RSA private key in a PKCS#8 key.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn pkcs1_to_pkcs8(der: &[u8]) -> Result<PrivateKeyDocument> {
+    if let Err(err) = RsaPrivateKey::try_from(der) {
+        return Err(match err {
+            pkcs1::Error::Asn1(error) => decode_error(der, Format::Pkcs1, Format::Pkcs8, error),
+            _ => ConversionError::KeyMalformed {
+                source: Format::Pkcs1,
+                target: Format::Pkcs8,
+                oid: RSA_ENCRYPTION_OID,
+            },
+        });
+    }
+
+    let algorithm = AlgorithmIdentifier {
+        oid: RSA_ENCRYPTION_OID,
+        parameters: Some(Null.into()),
+    };
+
+    encode_pkcs8(PrivateKeyInfo::new(algorithm, der), Format::Pkcs1)
+}
+
+/// Wrap a DER-encoded SEC1 EC private key in a PKCS#8 key.
+///
+/// The curve is moved from the SEC1 key's parameters to the PKCS#8
+/// algorithm parameters, so the SEC1 key must name its curve.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn sec1_to_pkcs8(der: &[u8]) -> Result<PrivateKeyDocument> {
+    let mut private_key = EcPrivateKey::try_from(der).map_err(|err| match err {
+        sec1::Error::Asn1(error) => decode_error(der, Format::Sec1, Format::Pkcs8, error),
+        _ => ConversionError::KeyMalformed {
+            source: Format::Sec1,
+            target: Format::Pkcs8,
+            oid: EC_PUBLIC_KEY_OID,
+        },
+    })?;
+
+    let curve = private_key
+        .parameters
+        .take()
+        .and_then(EcParameters::named_curve)
+        .ok_or(ConversionError::Parameters {
+            source: Format::Sec1,
+            target: Format::Pkcs8,
+            oid: EC_PUBLIC_KEY_OID,
+            hint: Some(Hint::MissingCurve),
+        })?;
+
+    let private_key = private_key
+        .to_vec()
+        .map(Zeroizing::new)
+        .map_err(|error| ConversionError::Encode {
+            source: Format::Sec1,
+            target: Format::Pkcs8,
+            error,
+        })?;
+
+    let algorithm = AlgorithmIdentifier {
+        oid: EC_PUBLIC_KEY_OID,
+        parameters: Some((&curve).into()),
+    };
+
+    encode_pkcs8(PrivateKeyInfo::new(algorithm, &private_key), Format::Sec1)
+}
+
+/// Extract the PKCS#1 RSA public key from a DER-encoded SPKI public key.
+pub fn spki_to_pkcs1(der: &[u8]) -> Result<RsaPublicKey<'_>> {
+    decode_spki(der, Format::Pkcs1)
+}
+
+/// Extract the SEC1 encoded point from a DER-encoded SPKI public key.
+pub fn spki_to_sec1<Size: ModulusSize>(der: &[u8]) -> Result<EncodedPoint<Size>> {
+    decode_spki(der, Format::Sec1)
+}
+
+/// Decode a PKCS#8 key for conversion to `target`.
+fn decode_pkcs8(der: &[u8], target: Format) -> Result<PrivateKeyInfo<'_>> {
+    PrivateKeyInfo::from_der(der).map_err(|error| decode_error(der, Format::Pkcs8, target, error))
+}
+
+/// Encode a PKCS#8 key converted from `source`.
+#[cfg(feature = "alloc")]
+fn encode_pkcs8(
+    private_key_info: PrivateKeyInfo<'_>,
+    source: Format,
+) -> Result<PrivateKeyDocument> {
+    PrivateKeyDocument::try_from(private_key_info).map_err(|err| ConversionError::Encode {
+        source,
+        target: Format::Pkcs8,
+        error: match err {
+            pkcs8::Error::Asn1(error) => error,
+            _ => der::ErrorKind::Failed.into(),
+        },
+    })
+}
+
+/// Decode the public key `K` from a SPKI public key.
+fn decode_spki<'a, K: DecodeSubjectPublicKey<'a>>(der: &'a [u8], target: Format) -> Result<K> {
+    let spki = SubjectPublicKeyInfo::from_der(der)
+        .map_err(|error| decode_error(der, Format::Spki, target, error))?;
+    let oid = spki.algorithm.oid;
+
+    if oid != K::ALGORITHM_OID {
+        return Err(ConversionError::Algorithm {
+            source: Format::Spki,
+            target,
+            oid,
+            hint: Some(algorithm_hint(oid)),
+        });
+    }
+
+    K::decode_subject_public_key(spki.algorithm.parameters, spki.subject_public_key).map_err(|_| {
+        ConversionError::KeyMalformed {
+            source: Format::Spki,
+            target,
+            oid,
+        }
+    })
+}
+
+/// Convert an error from [`pkcs8`] converting a key with the given
+/// algorithm to `target`.
+fn pkcs8_error(err: pkcs8::Error, oid: ObjectIdentifier, target: Format) -> ConversionError {
+    match err {
+        pkcs8::Error::AlgorithmMismatch { actual, .. } => ConversionError::Algorithm {
+            source: Format::Pkcs8,
+            target,
+            oid: actual,
+            hint: Some(algorithm_hint(actual)),
+        },
+        pkcs8::Error::ParametersMalformed => ConversionError::Parameters {
+            source: Format::Pkcs8,
+            target,
+            oid,
+            hint: None,
+        },
+        _ => ConversionError::KeyMalformed {
+            source: Format::Pkcs8,
+            target,
+            oid,
+        },
+    }
+}
+
+/// Create a [`ConversionError::Decode`] with a hint based on the detected
+/// format of the input.
+fn decode_error(der: &[u8], source: Format, target: Format, error: der::Error) -> ConversionError {
+    let hint = match identify(der).ok().map(|identified| identified.format) {
+        Some(Format::EncryptedPkcs8) => Some(Hint::EncryptedKeyNeedsPassphrase),
+        Some(format) if format != source => Some(Hint::InputIsFormat(format)),
+        _ => None,
+    };
+
+    ConversionError::Decode {
+        source,
+        target,
+        error,
+        hint,
+    }
+}
+
+/// Get the hint for a key whose algorithm isn't supported by a conversion.
+fn algorithm_hint(oid: ObjectIdentifier) -> Hint {
+    if oid == RSA_ENCRYPTION_OID {
+        Hint::ConvertTo(Format::Pkcs1)
+    } else if oid == EC_PUBLIC_KEY_OID {
+        Hint::ConvertTo(Format::Sec1)
+    } else {
+        Hint::NoAlgorithmSpecificFormat
+    }
+}
@@ -0,0 +1,283 @@
+//! Identification of the format and key algorithm of encoded keys and
+//! certificates.
+
+use core::{convert::TryFrom, fmt};
+use der::{
+    asn1::{Any, ObjectIdentifier},
+    Decodable, Decoder, Tag, TagNumber,
+};
+use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
+
+#[cfg(feature = "pem")]
+use der::pem;
+
+/// `rsaEncryption` OID.
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+
+/// `id-ecPublicKey` OID.
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.2.1");
+
+/// `id-Ed25519` OID.
+const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.101.112");
+
+/// Maximum number of top-level fields inspected when identifying a message.
+const MAX_FIELDS: usize = 10;
+
+/// Key and certificate formats.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Format {
+    /// PKCS#1 `RSAPrivateKey` or `RSAPublicKey`.
+    Pkcs1,
+
+    /// SEC1 `ECPrivateKey`.
+    Sec1,
+
+    /// PKCS#8 `PrivateKeyInfo` (a.k.a. `OneAsymmetricKey`).
+    Pkcs8,
+
+    /// PKCS#8 `EncryptedPrivateKeyInfo`.
+    EncryptedPkcs8,
+
+    /// X.509 `SubjectPublicKeyInfo`.
+    Spki,
+
+    /// X.509 `Certificate`.
+    Cert,
+
+    /// PKCS#10 `CertificationRequest`.
+    Csr,
+}
+
+/// Key algorithm of an identified message.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// RSA.
+    Rsa,
+
+    /// Elliptic curve key with the given named curve OID (if specified).
+    Ec(Option<ObjectIdentifier>),
+
+    /// Ed25519.
+    Ed25519,
+
+    /// Unrecognized algorithm with the given OID.
+    Unknown(ObjectIdentifier),
+}
+
+impl Algorithm {
+    /// Get the [`Algorithm`] identified by an `AlgorithmIdentifier`.
+    fn from_algorithm_identifier(algorithm: AlgorithmIdentifier<'_>) -> Self {
+        match algorithm.oid {
+            RSA_ENCRYPTION_OID => Algorithm::Rsa,
+            EC_PUBLIC_KEY_OID => Algorithm::Ec(algorithm.parameters_oid().ok()),
+            ED25519_OID => Algorithm::Ed25519,
+            oid => Algorithm::Unknown(oid),
+        }
+    }
+}
+
+/// Result of [`identify`]ing an encoded message.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Identified {
+    /// Format of the message.
+    pub format: Format,
+
+    /// Algorithm of the key contained in the message.
+    ///
+    /// This is `None` for [`Format::EncryptedPkcs8`], as the key algorithm
+    /// can't be determined without decrypting the message.
+    pub algorithm: Option<Algorithm>,
+}
+
+/// Identify the format and key algorithm of a DER-encoded (or, with the
+/// `pem` feature, PEM-encoded) key, certificate, or certificate request.
+///
+/// Only the outer structure of the message is decoded, so a successful
+/// result does not imply the message is otherwise well-formed.
+pub fn identify(input: &[u8]) -> der::Result<Identified> {
+    #[cfg(feature = "pem")]
+    if input.starts_with(b"-----BEGIN ") {
+        let (label, der_bytes) = pem::decode_vec(input)?;
+        let identified = identify(&der_bytes)?;
+
+        return match (label, identified.format) {
+            ("RSA PRIVATE KEY" | "RSA PUBLIC KEY", Format::Pkcs1)
+            | ("EC PRIVATE KEY", Format::Sec1)
+            | ("PRIVATE KEY", Format::Pkcs8)
+            | ("ENCRYPTED PRIVATE KEY", Format::EncryptedPkcs8)
+            | ("PUBLIC KEY", Format::Spki)
+            | ("CERTIFICATE", Format::Cert)
+            | ("CERTIFICATE REQUEST" | "NEW CERTIFICATE REQUEST", Format::Csr) => Ok(identified),
+            (label, format) => {
+                let expected = format.pem_label(&der_bytes)?;
+                Err(pem::Error::unexpected_type_label(expected, label).into())
+            }
+        };
+    }
+
+    let fields = Fields::from_der(input)?;
+
+    match fields.tags() {
+        [Tag::Integer, Tag::Integer] => Ok(Identified::new(Format::Pkcs1, Algorithm::Rsa)),
+        tags if tags.len() >= 9 && tags[..9].iter().all(|&tag| tag == Tag::Integer) => {
+            Ok(Identified::new(Format::Pkcs1, Algorithm::Rsa))
+        }
+        [Tag::Integer, Tag::OctetString, ..] => {
+            // `parameters` are an explicitly tagged `[0]` field
+            let curve = match fields.tags().get(2) {
+                Some(tag) if *tag == context_specific(0) => {
+                    let params = fields.decode::<Any<'_>>(2)?;
+                    ObjectIdentifier::from_der(params.value()).ok()
+                }
+                _ => None,
+            };
+
+            Ok(Identified::new(Format::Sec1, Algorithm::Ec(curve)))
+        }
+        [Tag::Integer, Tag::Sequence, Tag::OctetString, ..] => Ok(Identified::new(
+            Format::Pkcs8,
+            Algorithm::from_algorithm_identifier(fields.decode(1)?),
+        )),
+        [Tag::Sequence, Tag::OctetString] => Ok(Identified {
+            format: Format::EncryptedPkcs8,
+            algorithm: None,
+        }),
+        [Tag::Sequence, Tag::BitString] => Ok(Identified::new(
+            Format::Spki,
+            Algorithm::from_algorithm_identifier(fields.decode(0)?),
+        )),
+        [Tag::Sequence, Tag::Sequence, Tag::BitString] => {
+            let info = Fields::from_der(fields.get(0)?)?;
+
+            // The `TBSCertificate` either begins with an explicit version, or
+            // a serial number followed by the signature `AlgorithmIdentifier`,
+            // whereas `CertificationRequestInfo` has a version followed by
+            // the subject `Name`.
+            let (format, spki_index) = match info.tags() {
+                [tag, ..] if *tag == context_specific(0) => (Format::Cert, 6),
+                [Tag::Integer, Tag::Sequence, ..]
+                    if info.decode::<AlgorithmIdentifier<'_>>(1).is_ok() =>
+                {
+                    (Format::Cert, 5)
+                }
+                _ => (Format::Csr, 2),
+            };
+
+            let spki = info.decode::<SubjectPublicKeyInfo<'_>>(spki_index)?;
+
+            Ok(Identified::new(
+                format,
+                Algorithm::from_algorithm_identifier(spki.algorithm),
+            ))
+        }
+        _ => Err(Tag::Sequence.value_error()),
+    }
+}
+
+impl Format {
+    /// Get the PEM type label of a DER-encoded message of this format.
+    #[cfg(feature = "pem")]
+    fn pem_label(self, der_bytes: &[u8]) -> der::Result<&'static str> {
+        Ok(match self {
+            Format::Pkcs1 if Fields::from_der(der_bytes)?.tags().len() == 2 => "RSA PUBLIC KEY",
+            Format::Pkcs1 => "RSA PRIVATE KEY",
+            Format::Sec1 => "EC PRIVATE KEY",
+            Format::Pkcs8 => "PRIVATE KEY",
+            Format::EncryptedPkcs8 => "ENCRYPTED PRIVATE KEY",
+            Format::Spki => "PUBLIC KEY",
+            Format::Cert => "CERTIFICATE",
+            Format::Csr => "CERTIFICATE REQUEST",
+        })
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Format::Pkcs1 => "PKCS#1",
+            Format::Sec1 => "SEC1",
+            Format::Pkcs8 => "PKCS#8",
+            Format::EncryptedPkcs8 => "encrypted PKCS#8",
+            Format::Spki => "SPKI",
+            Format::Cert => "certificate",
+            Format::Csr => "certificate request",
+        })
+    }
+}
+
+impl Identified {
+    /// Create a new [`Identified`] message with a known algorithm.
+    fn new(format: Format, algorithm: Algorithm) -> Self {
+        Self {
+            format,
+            algorithm: Some(algorithm),
+        }
+    }
+}
+
+/// Top-level fields of a DER-encoded `SEQUENCE`.
+struct Fields<'a> {
+    /// Tags of each field.
+    tags: [Tag; MAX_FIELDS],
+
+    /// DER encoding of each field.
+    fields: [&'a [u8]; MAX_FIELDS],
+
+    /// Number of fields.
+    len: usize,
+}
+
+impl<'a> Fields<'a> {
+    /// Decode the outer `SEQUENCE` of a message, collecting up to
+    /// [`MAX_FIELDS`] fields.
+    fn from_der(bytes: &'a [u8]) -> der::Result<Self> {
+        let mut result = Self {
+            tags: [Tag::Null; MAX_FIELDS],
+            fields: [&[]; MAX_FIELDS],
+            len: 0,
+        };
+
+        let contents = Any::from_der(bytes)?;
+        contents.tag().assert_eq(Tag::Sequence)?;
+
+        let contents = contents.value();
+        let mut decoder = Decoder::new(contents);
+
+        while !decoder.is_finished() && result.len < MAX_FIELDS {
+            let start = usize::try_from(decoder.position())?;
+            let tag = decoder.any()?.tag();
+            let end = usize::try_from(decoder.position())?;
+
+            result.tags[result.len] = tag;
+            result.fields[result.len] = &contents[start..end];
+            result.len += 1;
+        }
+
+        Ok(result)
+    }
+
+    /// Get the tags of the fields.
+    fn tags(&self) -> &[Tag] {
+        &self.tags[..self.len]
+    }
+
+    /// Get the DER encoding of the field at the given index.
+    fn get(&self, index: usize) -> der::Result<&'a [u8]> {
+        self.fields[..self.len]
+            .get(index)
+            .copied()
+            .ok_or_else(|| Tag::Sequence.length_error())
+    }
+
+    /// Decode the field at the given index as `T`.
+    fn decode<T: Decodable<'a>>(&self, index: usize) -> der::Result<T> {
+        T::from_der(self.get(index)?)
+    }
+}
+
+/// Get the tag for a constructed context-specific field.
+fn context_specific(number: u8) -> Tag {
+    TagNumber::from(number).context_specific(true)
+}
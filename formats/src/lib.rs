@@ -0,0 +1,72 @@
+//! Facade for the RustCrypto formats crates.
+//!
+//! This crate re-exports [`der`], [`pkcs1`], [`pkcs8`], [`sec1`], [`spki`]
+//! and (with the `x509` feature) `x509` at versions which are known to
+//! work together, so applications can depend on it alone rather than on
+//! each of them.
+//!
+//! The [`prelude`] brings the traits for decoding and encoding keys and
+//! documents into scope:
+//!
+//! ```
+//! # #[cfg(feature = "pem")]
+//! # {
+//! use formats::{prelude::*, RsaPrivateKeyDocument};
+//!
+//! let pem = include_str!("../tests/examples/rsa2048-priv.pem");
+//! let document = RsaPrivateKeyDocument::from_pkcs1_pem(pem).unwrap();
+//! assert_eq!(document.decode().modulus.as_bytes().len(), 256);
+//! # }
+//! ```
+//!
+//! The [`convert`] module converts keys between formats, returning a
+//! [`ConversionError`] with a hint for fixing the input when that fails,
+//! and [`identify`] detects the [`Format`] and key [`Algorithm`] of a key,
+//! certificate, or certificate request.
+//!
+//! # Minimum Supported Rust Version
+//! This crate requires **Rust 1.55** at a minimum.
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_root_url = "https://docs.rs/formats/0.1.0-pre"
+)]
+#![forbid(unsafe_code, clippy::unwrap_used)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod convert;
+pub mod prelude;
+
+mod identify;
+
+pub use crate::{
+    convert::ConversionError,
+    identify::{identify, Algorithm, Format, Identified},
+};
+pub use {der, pkcs1, pkcs8, sec1, spki};
+
+#[cfg(feature = "alloc")]
+pub use {
+    pkcs1::{RsaPrivateKeyDocument, RsaPublicKeyDocument},
+    pkcs8::PrivateKeyDocument,
+    sec1::EcPrivateKeyDocument,
+    spki::PublicKeyDocument,
+};
+
+#[cfg(feature = "encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+pub use pkcs8::{pkcs5, EncryptedPrivateKeyDocument};
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub use der::pem::{self, LineEnding};
+
+#[cfg(feature = "x509")]
+#[cfg_attr(docsrs, doc(cfg(feature = "x509")))]
+pub use x509;
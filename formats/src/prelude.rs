@@ -0,0 +1,24 @@
+//! Traits for decoding and encoding keys and documents.
+//!
+//! Glob importing this module (`use formats::prelude::*`) makes the
+//! `from_*_der`/`from_*_pem` constructors and `to_*_der`/`to_*_pem` methods
+//! of all the re-exported crates available.
+
+pub use der::{Decodable, Encodable};
+pub use pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+pub use pkcs8::DecodePrivateKey;
+pub use sec1::DecodeEcPrivateKey;
+pub use spki::{DecodePublicKey, DecodeSubjectPublicKey};
+
+#[cfg(feature = "alloc")]
+pub use {
+    der::Document,
+    pkcs1::{EncodeRsaPrivateKey, EncodeRsaPublicKey},
+    pkcs8::EncodePrivateKey,
+    sec1::EncodeEcPrivateKey,
+    spki::EncodePublicKey,
+};
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub use der::pem::LineEnding;
@@ -0,0 +1,175 @@
+//! Key format conversion tests
+
+use formats::{
+    convert::{self, Hint},
+    identify,
+    prelude::*,
+    sec1::consts::U32,
+    ConversionError, Format,
+};
+use hex_literal::hex;
+
+/// RSA-2048 PKCS#1 private key encoded as ASN.1 DER
+const RSA_2048_PRIV_DER: &[u8] = include_bytes!("examples/rsa2048-priv.der");
+
+/// RSA-2048 PKCS#8 private key encoded as ASN.1 DER
+const RSA_2048_PKCS8_DER: &[u8] = include_bytes!("examples/rsa2048-priv-pkcs8.der");
+
+/// RSA-2048 SPKI public key encoded as ASN.1 DER
+const RSA_2048_SPKI_DER: &[u8] = include_bytes!("examples/rsa2048-pub-spki.der");
+
+/// RSA-2048 PKCS#1 public key encoded as ASN.1 DER
+const RSA_2048_PUB_DER: &[u8] = include_bytes!("examples/rsa2048-pub.der");
+
+/// Elliptic Curve (P-256) SEC1 private key encoded as ASN.1 DER
+const EC_P256_SEC1_DER: &[u8] = include_bytes!("examples/p256-priv.der");
+
+/// Elliptic Curve (P-256) PKCS#8 private key encoded as ASN.1 DER
+const EC_P256_PKCS8_DER: &[u8] = include_bytes!("examples/p256-priv-pkcs8.der");
+
+/// Elliptic Curve (P-256) SPKI public key encoded as ASN.1 DER
+const EC_P256_SPKI_DER: &[u8] = include_bytes!("examples/p256-pub-spki.der");
+
+/// Ed25519 PKCS#8 private key encoded as ASN.1 DER
+const ED25519_PKCS8_DER: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v1.der");
+
+/// Ed25519 PKCS#8 private key encrypted with AES-256-CBC and PBKDF2-SHA256
+const ED25519_ENCPRIV_DER: &[u8] =
+    include_bytes!("examples/ed25519-encpriv-aes256-pbkdf2-sha256.der");
+
+/// `rsaEncryption` OID
+const RSA_ENCRYPTION_OID: &str = "1.2.840.113549.1.1.1";
+
+/// `id-ecPublicKey` OID
+const EC_PUBLIC_KEY_OID: &str = "1.2.840.10045.2.1";
+
+/// `id-Ed25519` OID
+const ED25519_OID: &str = "1.3.101.112";
+
+#[test]
+fn identify_format() {
+    for &(der, format) in &[
+        (RSA_2048_PRIV_DER, Format::Pkcs1),
+        (RSA_2048_PUB_DER, Format::Pkcs1),
+        (RSA_2048_PKCS8_DER, Format::Pkcs8),
+        (RSA_2048_SPKI_DER, Format::Spki),
+        (EC_P256_SEC1_DER, Format::Sec1),
+        (EC_P256_PKCS8_DER, Format::Pkcs8),
+        (EC_P256_SPKI_DER, Format::Spki),
+        (ED25519_ENCPRIV_DER, Format::EncryptedPkcs8),
+    ] {
+        assert_eq!(identify(der).unwrap().format, format);
+    }
+
+    assert!(identify(&hex!("0500")).is_err());
+}
+
+#[test]
+fn pkcs8_to_pkcs1() {
+    let private_key = convert::pkcs8_to_pkcs1(RSA_2048_PKCS8_DER).unwrap();
+    let mut buf = [0u8; 2048];
+    assert_eq!(private_key.encode_to_slice(&mut buf).unwrap(), RSA_2048_PRIV_DER);
+}
+
+#[test]
+fn pkcs8_to_sec1() {
+    let private_key = convert::pkcs8_to_sec1(EC_P256_PKCS8_DER).unwrap();
+    let mut buf = [0u8; 2048];
+    assert_eq!(private_key.encode_to_slice(&mut buf).unwrap(), EC_P256_SEC1_DER);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pkcs1_to_pkcs8() {
+    let document = convert::pkcs1_to_pkcs8(RSA_2048_PRIV_DER).unwrap();
+    assert_eq!(document.as_ref(), RSA_2048_PKCS8_DER);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn sec1_to_pkcs8() {
+    let document = convert::sec1_to_pkcs8(EC_P256_SEC1_DER).unwrap();
+    assert_eq!(document.as_ref(), EC_P256_PKCS8_DER);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn sec1_to_pkcs8_missing_curve() {
+    let mut private_key = formats::sec1::EcPrivateKey::from_der(EC_P256_SEC1_DER).unwrap();
+    private_key.parameters = None;
+    let der = private_key.to_vec().unwrap();
+
+    let err = convert::sec1_to_pkcs8(&der).unwrap_err();
+    assert!(matches!(err, ConversionError::Parameters { .. }));
+    assert_eq!(err.oid(), Some(EC_PUBLIC_KEY_OID.parse().unwrap()));
+    assert_eq!(err.hint(), Some(Hint::MissingCurve));
+}
+
+#[test]
+fn spki_to_pkcs1() {
+    let public_key = convert::spki_to_pkcs1(RSA_2048_SPKI_DER).unwrap();
+    let mut buf = [0u8; 2048];
+    assert_eq!(public_key.encode_to_slice(&mut buf).unwrap(), RSA_2048_PUB_DER);
+}
+
+#[test]
+fn spki_to_sec1() {
+    let point = convert::spki_to_sec1::<U32>(EC_P256_SPKI_DER).unwrap();
+    assert!(!point.is_compressed());
+    assert_eq!(point.as_bytes().len(), 65);
+}
+
+#[test]
+fn algorithm_mismatch() {
+    let err = convert::pkcs8_to_pkcs1(EC_P256_PKCS8_DER).unwrap_err();
+    assert!(matches!(err, ConversionError::Algorithm { .. }));
+    assert_eq!(err.source(), Format::Pkcs8);
+    assert_eq!(err.target(), Format::Pkcs1);
+    assert_eq!(err.oid(), Some(EC_PUBLIC_KEY_OID.parse().unwrap()));
+    assert_eq!(err.hint(), Some(Hint::ConvertTo(Format::Sec1)));
+
+    let err = convert::spki_to_sec1::<U32>(RSA_2048_SPKI_DER).unwrap_err();
+    assert_eq!(err.source(), Format::Spki);
+    assert_eq!(err.oid(), Some(RSA_ENCRYPTION_OID.parse().unwrap()));
+    assert_eq!(err.hint(), Some(Hint::ConvertTo(Format::Pkcs1)));
+
+    let err = convert::pkcs8_to_sec1(ED25519_PKCS8_DER).unwrap_err();
+    assert_eq!(err.oid(), Some(ED25519_OID.parse().unwrap()));
+    assert_eq!(err.hint(), Some(Hint::NoAlgorithmSpecificFormat));
+}
+
+#[test]
+fn encrypted_key() {
+    let err = convert::pkcs8_to_pkcs1(ED25519_ENCPRIV_DER).unwrap_err();
+    assert!(matches!(err, ConversionError::Decode { .. }));
+    assert_eq!(err.oid(), None);
+    assert_eq!(err.hint(), Some(Hint::EncryptedKeyNeedsPassphrase));
+}
+
+#[test]
+fn wrong_input_format() {
+    let err = convert::pkcs8_to_sec1(EC_P256_SEC1_DER).unwrap_err();
+    assert!(matches!(err, ConversionError::Decode { .. }));
+    assert_eq!(err.hint(), Some(Hint::InputIsFormat(Format::Sec1)));
+
+    let err = convert::spki_to_pkcs1(RSA_2048_PUB_DER).unwrap_err();
+    assert_eq!(err.hint(), Some(Hint::InputIsFormat(Format::Pkcs1)));
+
+    let err = convert::spki_to_pkcs1(&hex!("0500")).unwrap_err();
+    assert_eq!(err.hint(), None);
+}
+
+#[test]
+fn display() {
+    let err = convert::pkcs8_to_pkcs1(ED25519_PKCS8_DER).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "can't convert PKCS#8 to PKCS#1: unsupported algorithm 1.3.101.112 \
+         (keys of this algorithm are only encoded as PKCS#8 or SPKI)"
+    );
+
+    let err = convert::pkcs8_to_sec1(ED25519_ENCPRIV_DER).unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("(the key is encrypted, decrypt it with its passphrase first)"));
+}
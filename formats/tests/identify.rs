@@ -0,0 +1,148 @@
+//! Key and certificate format identification tests
+
+use der::asn1::ObjectIdentifier;
+use formats::{identify, Algorithm, Format, Identified};
+use hex_literal::hex;
+
+/// NIST P-256 named curve OID.
+const SECP256R1_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.3.1.7");
+
+fn assert_identified(input: &[u8], format: Format, algorithm: Option<Algorithm>) {
+    assert_eq!(identify(input).unwrap(), Identified { format, algorithm });
+}
+
+#[test]
+fn identify_pkcs1() {
+    let rsa = Some(Algorithm::Rsa);
+    assert_identified(
+        include_bytes!("examples/rsa2048-priv.der"),
+        Format::Pkcs1,
+        rsa,
+    );
+    assert_identified(
+        include_bytes!("examples/rsa2048-pub.der"),
+        Format::Pkcs1,
+        rsa,
+    );
+}
+
+#[test]
+fn identify_sec1() {
+    assert_identified(
+        include_bytes!("examples/p256-priv.der"),
+        Format::Sec1,
+        Some(Algorithm::Ec(Some(SECP256R1_OID))),
+    );
+
+    // `ECPrivateKey` whose `parameters` are `implicitCurve` (`NULL`)
+    assert_identified(
+        &hex!("300A020101040101A0020500"),
+        Format::Sec1,
+        Some(Algorithm::Ec(None)),
+    );
+}
+
+#[test]
+fn identify_pkcs8() {
+    assert_identified(
+        include_bytes!("examples/ed25519-priv-pkcs8v1.der"),
+        Format::Pkcs8,
+        Some(Algorithm::Ed25519),
+    );
+    assert_identified(
+        include_bytes!("examples/ed25519-encpriv-aes256-pbkdf2-sha256.der"),
+        Format::EncryptedPkcs8,
+        None,
+    );
+}
+
+#[test]
+fn identify_spki() {
+    assert_identified(
+        include_bytes!("examples/ed25519-pub.der"),
+        Format::Spki,
+        Some(Algorithm::Ed25519),
+    );
+    assert_identified(
+        include_bytes!("examples/p256-pub.der"),
+        Format::Spki,
+        Some(Algorithm::Ec(Some(SECP256R1_OID))),
+    );
+}
+
+#[test]
+fn identify_cert() {
+    assert_identified(
+        include_bytes!("examples/ed25519-cert.der"),
+        Format::Cert,
+        Some(Algorithm::Ed25519),
+    );
+}
+
+#[test]
+fn identify_csr() {
+    assert_identified(
+        include_bytes!("examples/ed25519-csr.der"),
+        Format::Csr,
+        Some(Algorithm::Ed25519),
+    );
+}
+
+#[test]
+fn identify_unknown() {
+    // SEQUENCE { BOOLEAN TRUE }
+    assert!(identify(&hex!("30030101FF")).is_err());
+
+    // OCTET STRING
+    assert!(identify(&hex!("0400")).is_err());
+}
+
+#[cfg(feature = "pem")]
+#[test]
+fn identify_pem() {
+    assert_identified(
+        include_bytes!("examples/rsa2048-priv.pem"),
+        Format::Pkcs1,
+        Some(Algorithm::Rsa),
+    );
+    assert_identified(
+        include_bytes!("examples/ed25519-csr.pem"),
+        Format::Csr,
+        Some(Algorithm::Ed25519),
+    );
+}
+
+#[cfg(feature = "pem")]
+#[test]
+fn identify_pem_label_mismatch() {
+    let pem = include_str!("examples/rsa2048-priv.pem").replace("RSA PRIVATE KEY", "PUBLIC KEY");
+    let err = identify(pem.as_bytes()).err().unwrap();
+
+    assert_eq!(
+        err.kind(),
+        der::ErrorKind::Pem(der::pem::Error::UnexpectedTypeLabel {
+            expected: "RSA PRIVATE KEY",
+            actual: Some("PUBLIC KEY"),
+        })
+    );
+}
+
+#[test]
+fn identify_samples() {
+    let p256 = Some(Algorithm::Ec(Some(SECP256R1_OID)));
+    let ed25519 = Some(Algorithm::Ed25519);
+
+    for sample in der::dev::samples::all() {
+        let (format, algorithm) = match sample.name {
+            "rsa2048-priv" | "rsa2048-pub" => (Format::Pkcs1, Some(Algorithm::Rsa)),
+            "p256-priv" => (Format::Sec1, p256),
+            "p256-pub" => (Format::Spki, p256),
+            "ed25519-priv" => (Format::Pkcs8, ed25519),
+            "ed25519-pub" => (Format::Spki, ed25519),
+            "ed25519-cert" => (Format::Cert, ed25519),
+            name => panic!("unexpected sample: {}", name),
+        };
+
+        assert_identified(&sample.der, format, algorithm);
+    }
+}
@@ -0,0 +1,94 @@
+//! Tests for the facade and its prelude
+
+use formats::{pkcs1::RsaPublicKey, pkcs8::PrivateKeyInfo, prelude::*, sec1::EcPrivateKey};
+
+/// RSA-2048 PKCS#1 public key encoded as ASN.1 DER
+const RSA_2048_PUB_DER: &[u8] = include_bytes!("examples/rsa2048-pub.der");
+
+/// Elliptic Curve (P-256) SEC1 private key encoded as ASN.1 DER
+const EC_P256_SEC1_DER: &[u8] = include_bytes!("examples/p256-priv.der");
+
+/// Elliptic Curve (P-256) PKCS#8 private key encoded as ASN.1 DER
+const EC_P256_PKCS8_DER: &[u8] = include_bytes!("examples/p256-priv-pkcs8.der");
+
+/// Ed25519 PKCS#8 private key encoded as ASN.1 DER
+#[cfg(feature = "alloc")]
+const ED25519_PKCS8_DER: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v1.der");
+
+/// RSA-2048 PKCS#1 private key encoded as PEM
+#[cfg(feature = "pem")]
+const RSA_2048_PRIV_PEM: &str = include_str!("examples/rsa2048-priv.pem");
+
+/// Ed25519 SPKI public key encoded as PEM
+#[cfg(feature = "pem")]
+const ED25519_PUB_PEM: &str = include_str!("examples/ed25519-pub.pem");
+
+/// Ed25519 PKCS#8 private key encrypted with AES-256-CBC and PBKDF2-SHA256
+#[cfg(feature = "encryption")]
+const ED25519_ENCPRIV_DER: &[u8] =
+    include_bytes!("examples/ed25519-encpriv-aes256-pbkdf2-sha256.der");
+
+/// Ed25519 X.509 certificate encoded as ASN.1 DER
+const ED25519_CERT_DER: &[u8] = include_bytes!("examples/ed25519-cert.der");
+
+#[test]
+fn decode_across_crates() {
+    let rsa_public_key = RsaPublicKey::from_der(RSA_2048_PUB_DER).unwrap();
+    assert_eq!(rsa_public_key.modulus.as_bytes().len(), 256);
+
+    // The PKCS#8 key wraps the SEC1 key, which only works if both crates
+    // agree on the `der` version
+    let sec1_key = EcPrivateKey::from_der(EC_P256_SEC1_DER).unwrap();
+    let pkcs8_key = PrivateKeyInfo::from_der(EC_P256_PKCS8_DER).unwrap();
+    let wrapped_key = pkcs8_key.try_into_ec().unwrap();
+    assert_eq!(wrapped_key.private_key, sec1_key.private_key);
+    assert_eq!(wrapped_key.public_key, sec1_key.public_key);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn documents() {
+    let document = formats::PrivateKeyDocument::from_pkcs8_der(ED25519_PKCS8_DER).unwrap();
+    assert_eq!(document.private_key_info().private_key.len(), 34);
+    assert_eq!(document.to_pkcs8_der().unwrap().as_ref(), ED25519_PKCS8_DER);
+
+    let document = formats::EcPrivateKeyDocument::from_sec1_der(EC_P256_SEC1_DER).unwrap();
+    assert_eq!(document.to_sec1_der().unwrap().as_der(), EC_P256_SEC1_DER);
+
+    let document = formats::RsaPublicKeyDocument::from_pkcs1_der(RSA_2048_PUB_DER).unwrap();
+    assert_eq!(document.decode().to_vec().unwrap(), RSA_2048_PUB_DER);
+    assert_eq!(document.to_pkcs1_der().unwrap().as_der(), RSA_2048_PUB_DER);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn pem_round_trip() {
+    let document = formats::RsaPrivateKeyDocument::from_pkcs1_pem(RSA_2048_PRIV_PEM).unwrap();
+    let pem = document.to_pkcs1_pem(LineEnding::LF).unwrap();
+    assert_eq!(pem.trim_end(), RSA_2048_PRIV_PEM.trim_end());
+
+    let document = formats::PublicKeyDocument::from_public_key_pem(ED25519_PUB_PEM).unwrap();
+    assert_eq!(document.spki().subject_public_key.len(), 32);
+    assert_eq!(
+        document
+            .to_public_key_pem(LineEnding::LF)
+            .unwrap()
+            .trim_end(),
+        ED25519_PUB_PEM.trim_end()
+    );
+}
+
+#[test]
+#[cfg(feature = "encryption")]
+fn encrypted_document() {
+    let document = formats::EncryptedPrivateKeyDocument::from_der(ED25519_ENCPRIV_DER).unwrap();
+    let decrypted = document.decrypt(b"hunter42").unwrap();
+    assert_eq!(decrypted.as_ref(), ED25519_PKCS8_DER);
+}
+
+#[test]
+fn identify_cert() {
+    use formats::{identify, Format};
+
+    assert_eq!(identify(ED25519_CERT_DER).unwrap().format, Format::Cert);
+}
@@ -0,0 +1,107 @@
+//! PEM bundle writer.
+
+use crate::{encode_string, grammar, LineEnding, Result};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[cfg(feature = "std")]
+use std::io;
+
+/// Writer for PEM bundles: several PEM documents concatenated together.
+///
+/// Bundles are commonly used for certificate chains, as well as "combined"
+/// PEM files containing a private key followed by its certificate chain
+/// (e.g. as consumed by HAProxy or Nginx).
+///
+/// Documents are written in the order they were added unless reordered with
+/// [`BundleWriter::sort_by_labels`], each followed by the configured
+/// separator (if any) except for the last.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BundleWriter {
+    /// Line ending to use when encoding documents.
+    line_ending: LineEnding,
+
+    /// Text written between documents.
+    separator: String,
+
+    /// Type labels and DER-encoded documents.
+    documents: Vec<(String, Vec<u8>)>,
+}
+
+impl BundleWriter {
+    /// Create a new empty [`BundleWriter`] which encodes documents with the
+    /// given line ending.
+    pub fn new(line_ending: LineEnding) -> Self {
+        Self {
+            line_ending,
+            ..Default::default()
+        }
+    }
+
+    /// Set the text written between documents, e.g. an empty line.
+    ///
+    /// By default documents are written back-to-back.
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Add a document with the given type label to the bundle.
+    pub fn push(&mut self, label: &str, der: &[u8]) -> Result<()> {
+        grammar::validate_label(label.as_bytes())?;
+        self.documents.push((label.to_string(), der.to_vec()));
+        Ok(())
+    }
+
+    /// Stably reorder the documents by the position of their label in
+    /// `labels`.
+    ///
+    /// Documents with labels which do not appear in `labels` are moved after
+    /// all of those that do, retaining their relative order.
+    pub fn sort_by_labels(&mut self, labels: &[&str]) {
+        self.documents.sort_by_key(|(label, _)| {
+            labels
+                .iter()
+                .position(|l| l == label)
+                .unwrap_or(labels.len())
+        });
+    }
+
+    /// Get the number of documents in the bundle.
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Is the bundle empty?
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Encode the bundle as a [`String`].
+    pub fn encode_string(&self) -> Result<String> {
+        let mut bundle = String::new();
+
+        for (i, (label, der)) in self.documents.iter().enumerate() {
+            if i > 0 {
+                bundle.push_str(&self.separator);
+            }
+
+            bundle.push_str(&encode_string(label, self.line_ending, der)?);
+        }
+
+        Ok(bundle)
+    }
+
+    /// Encode the bundle, writing it to the given [`io::Write`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_to<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        let bundle = self
+            .encode_string()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+        writer.write_all(bundle.as_bytes())
+    }
+}
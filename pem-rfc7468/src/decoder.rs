@@ -14,7 +14,8 @@
 use alloc::vec::Vec;
 
 use crate::{
-    grammar, Error, Result, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
+    grammar::{self, CHAR_CR, CHAR_LF},
+    Error, LineEnding, Result, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
     PRE_ENCAPSULATION_BOUNDARY,
 };
 use base64ct::{Base64, Encoding};
@@ -25,6 +26,9 @@ use core::{convert::TryFrom, str};
 /// On success, writes the decoded document into the provided buffer, returning
 /// the decoded label and the portion of the provided buffer containing the
 /// decoded message.
+///
+/// Decoding is constant-time with respect to the encapsulated data, as
+/// described in the [crate documentation](crate#constant-time-decoding).
 pub fn decode<'i, 'o>(pem: &'i [u8], buf: &'o mut [u8]) -> Result<(&'i str, &'o [u8])> {
     let encapsulation = Encapsulation::try_from(pem)?;
     let label = encapsulation.label();
@@ -34,6 +38,9 @@ pub fn decode<'i, 'o>(pem: &'i [u8], buf: &'o mut [u8]) -> Result<(&'i str, &'o
 
 /// Decode a PEM document according to RFC 7468's "Strict" grammar, returning
 /// the result as a [`Vec`] upon success.
+///
+/// Decoding is constant-time with respect to the encapsulated data, as
+/// described in the [crate documentation](crate#constant-time-decoding).
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub fn decode_vec(pem: &[u8]) -> Result<(&str, Vec<u8>)> {
@@ -59,6 +66,15 @@ pub fn decode_label(pem: &[u8]) -> Result<&str> {
     Ok(Encapsulation::try_from(pem)?.label())
 }
 
+/// Detect the [`LineEnding`] used by a PEM document.
+///
+/// This is the line ending which terminates the pre-encapsulation boundary,
+/// and can be passed to the encoder to preserve the original line endings
+/// when re-encoding a document.
+pub fn decode_line_ending(pem: &[u8]) -> Result<LineEnding> {
+    Ok(Encapsulation::try_from(pem)?.line_ending())
+}
+
 /// PEM encapsulation parser.
 ///
 /// This parser performs an initial pass over the data, locating the
@@ -71,7 +87,7 @@ pub fn decode_label(pem: &[u8]) -> Result<&str> {
 /// could potentially include extending it provide an iterator over a series
 /// of encapsulated messages.
 #[derive(Copy, Clone, Debug)]
-struct Encapsulation<'a> {
+pub(crate) struct Encapsulation<'a> {
     /// Type label extracted from the pre/post-encapsulation boundaries.
     ///
     /// From RFC 7468 Section 2:
@@ -95,6 +111,9 @@ struct Encapsulation<'a> {
     /// This data should be encoded as Base64, however this type performs no
     /// validation of it so it can be handled in constant-time.
     encapsulated_text: &'a [u8],
+
+    /// Line ending which terminates the pre-encapsulation boundary.
+    line_ending: LineEnding,
 }
 
 impl<'a> Encapsulation<'a> {
@@ -111,6 +130,17 @@ impl<'a> Encapsulation<'a> {
 
         let (label, body) = grammar::split_label(data).ok_or(Error::Label)?;
 
+        // The label can't contain a newline, so the first one found
+        // terminates the pre-encapsulation boundary
+        let line_ending = match data.iter().position(|&b| b == CHAR_CR || b == CHAR_LF) {
+            Some(pos) => match &data[pos..] {
+                [CHAR_CR, CHAR_LF, ..] => LineEnding::CRLF,
+                [CHAR_CR, ..] => LineEnding::CR,
+                _ => LineEnding::LF,
+            },
+            None => return Err(Error::Label),
+        };
+
         let mut body = match grammar::strip_trailing_eol(body).unwrap_or(body) {
             [head @ .., b'-', b'-', b'-', b'-', b'-'] => head,
             _ => return Err(Error::PreEncapsulationBoundary),
@@ -135,6 +165,7 @@ impl<'a> Encapsulation<'a> {
         Ok(Self {
             label,
             encapsulated_text,
+            line_ending,
         })
     }
 
@@ -143,6 +174,11 @@ impl<'a> Encapsulation<'a> {
         self.label
     }
 
+    /// Get the line ending which terminates the pre-encapsulation boundary.
+    pub fn line_ending(self) -> LineEnding {
+        self.line_ending
+    }
+
     /// Get an iterator over the (allegedly) Base64-encoded lines of the
     /// encapsulated text.
     pub fn encapsulated_text(self) -> Lines<'a> {
@@ -158,27 +194,27 @@ impl<'a> Encapsulation<'a> {
         let mut out_len = 0;
 
         for line in self.encapsulated_text() {
-            let line = line?;
-
-            match Base64::decode(line, &mut buf[out_len..]) {
-                Err(error) => {
-                    // in the case that we are decoding the first line
-                    // and we error, then attribute the error to an unsupported header
-                    // if a colon char is present in the line
-                    if out_len == 0 && line.iter().any(|&b| b == grammar::CHAR_COLON) {
-                        return Err(Error::HeaderDisallowed);
-                    } else {
-                        return Err(error.into());
-                    }
-                }
-                Ok(out) => out_len += out.len(),
-            }
+            out_len += decode_line(line?, out_len == 0, &mut buf[out_len..])?.len();
         }
 
         Ok(&buf[..out_len])
     }
 }
 
+/// Decode a single line of the encapsulated text into the provided buffer.
+pub(crate) fn decode_line<'o>(line: &[u8], is_first: bool, buf: &'o mut [u8]) -> Result<&'o [u8]> {
+    Base64::decode(line, buf).map_err(|error| {
+        // in the case that we are decoding the first line
+        // and we error, then attribute the error to an unsupported header
+        // if a colon char is present in the line
+        if is_first && line.iter().any(|&b| b == grammar::CHAR_COLON) {
+            Error::HeaderDisallowed
+        } else {
+            error.into()
+        }
+    })
+}
+
 impl<'a> TryFrom<&'a [u8]> for Encapsulation<'a> {
     type Error = Error;
 
@@ -188,7 +224,7 @@ impl<'a> TryFrom<&'a [u8]> for Encapsulation<'a> {
 }
 
 /// Iterator over the lines in the encapsulated text.
-struct Lines<'a> {
+pub(crate) struct Lines<'a> {
     /// true if no lines have been read
     is_start: bool,
     /// Remaining data being iterated over.
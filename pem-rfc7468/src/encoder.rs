@@ -2,50 +2,60 @@
 
 use crate::{
     grammar::{self, CHAR_CR, CHAR_LF},
-    Error, Result, BASE64_WRAP_WIDTH, ENCAPSULATION_BOUNDARY_DELIMITER,
+    Error, Result, BASE64_LINE_INPUT_LEN, BASE64_WRAP_WIDTH, ENCAPSULATION_BOUNDARY_DELIMITER,
     POST_ENCAPSULATION_BOUNDARY, PRE_ENCAPSULATION_BOUNDARY,
 };
 use base64ct::{Base64, Encoding};
+use core::{fmt, mem};
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 /// Encode a PEM document according to RFC 7468's "Strict" grammar.
-pub fn encode<'a>(
+pub fn encode<'o>(
     label: &str,
     line_ending: LineEnding,
     input: &[u8],
-    buf: &'a mut [u8],
-) -> Result<&'a [u8]> {
-    grammar::validate_label(label.as_bytes())?;
-
-    let mut buf = Buffer::new(buf, line_ending);
-    buf.write(PRE_ENCAPSULATION_BOUNDARY)?;
-    buf.write(label.as_bytes())?;
-    buf.writeln(ENCAPSULATION_BOUNDARY_DELIMITER)?;
-
-    for chunk in input.chunks((BASE64_WRAP_WIDTH * 3) / 4) {
-        buf.write_base64ln(chunk)?;
-    }
-
-    buf.write(POST_ENCAPSULATION_BOUNDARY)?;
-    buf.write(label.as_bytes())?;
-    buf.writeln(ENCAPSULATION_BOUNDARY_DELIMITER)?;
-    buf.finish()
+    buf: &'o mut [u8],
+) -> Result<&'o [u8]> {
+    let mut encoder = Encoder::new(label, line_ending, buf)?;
+    encoder.encode(input)?;
+    encoder.finish()
 }
 
 /// Get the length of a PEM encoded document with the given bytes and label.
 pub fn encoded_len(label: &str, line_ending: LineEnding, input: &[u8]) -> usize {
+    wrapped_len(
+        label,
+        BASE64_WRAP_WIDTH,
+        line_ending,
+        Base64::encoded_len(input),
+    )
+}
+
+/// Get the length of a PEM encoded document with the given label and
+/// number of Base64 characters, wrapped at the given line width.
+pub(crate) fn wrapped_len(
+    label: &str,
+    line_width: usize,
+    line_ending: LineEnding,
+    base64_len: usize,
+) -> usize {
+    let lines = match base64_len % line_width {
+        0 => base64_len / line_width,
+        _ => base64_len / line_width + 1,
+    };
+
     // TODO(tarcieri): use checked arithmetic
     PRE_ENCAPSULATION_BOUNDARY.len()
         + label.as_bytes().len()
         + ENCAPSULATION_BOUNDARY_DELIMITER.len()
         + line_ending.len()
-        + input
-            .chunks((BASE64_WRAP_WIDTH * 3) / 4)
-            .fold(0, |acc, chunk| {
-                acc + Base64::encoded_len(chunk) + line_ending.len()
-            })
+        + base64_len
+        + lines * line_ending.len()
         + POST_ENCAPSULATION_BOUNDARY.len()
         + label.as_bytes().len()
         + ENCAPSULATION_BOUNDARY_DELIMITER.len()
@@ -62,6 +72,151 @@ pub fn encode_string(label: &str, line_ending: LineEnding, input: &[u8]) -> Resu
     String::from_utf8(buf).map_err(|_| Error::CharacterEncoding)
 }
 
+/// Streaming PEM encoder.
+///
+/// Encodes a PEM document according to RFC 7468's "Strict" grammar from
+/// input which is supplied incrementally, in chunks of arbitrary size.
+/// This avoids the need to materialize the full input document in memory
+/// before encoding it.
+pub struct Encoder<'l, 'o> {
+    /// PEM type label.
+    label: &'l str,
+
+    /// Output buffer.
+    buf: Buffer<'o>,
+
+    /// Input bytes which have not yet filled an entire Base64 line.
+    ///
+    /// These may be secret (e.g. the trailing bytes of a private key), so
+    /// they're cleared once written and when the encoder is dropped.
+    pending: [u8; BASE64_LINE_INPUT_LEN],
+
+    /// Number of bytes in `pending`.
+    pending_len: usize,
+}
+
+impl<'l, 'o> Encoder<'l, 'o> {
+    /// Create a new PEM encoder which writes to the given output buffer,
+    /// emitting the pre-encapsulation boundary for the given label.
+    pub fn new(label: &'l str, line_ending: LineEnding, buf: &'o mut [u8]) -> Result<Self> {
+        Self::new_wrapped(label, BASE64_WRAP_WIDTH, line_ending, buf)
+    }
+
+    /// Create a new PEM encoder which wraps the Base64-encoded data at the
+    /// given line width rather than the 64 characters required by RFC 7468.
+    ///
+    /// This is intended for interoperability with legacy consumers (e.g.
+    /// ones expecting 76 character MIME lines). Documents with a line width
+    /// other than 64 are rejected by this crate's [`decode`][crate::decode].
+    ///
+    /// Returns [`Error::Length`] if `line_width` is zero.
+    pub fn new_wrapped(
+        label: &'l str,
+        line_width: usize,
+        line_ending: LineEnding,
+        buf: &'o mut [u8],
+    ) -> Result<Self> {
+        grammar::validate_label(label.as_bytes())?;
+
+        if line_width == 0 {
+            return Err(Error::Length);
+        }
+
+        let mut buf = Buffer::new(buf, line_width, line_ending);
+        buf.write(PRE_ENCAPSULATION_BOUNDARY)?;
+        buf.write(label.as_bytes())?;
+        buf.writeln(ENCAPSULATION_BOUNDARY_DELIMITER)?;
+
+        Ok(Self {
+            label,
+            buf,
+            pending: [0u8; BASE64_LINE_INPUT_LEN],
+            pending_len: 0,
+        })
+    }
+
+    /// Encode the provided input, which may be a partial chunk of the
+    /// document being encoded.
+    pub fn encode(&mut self, mut input: &[u8]) -> Result<()> {
+        if self.pending_len > 0 {
+            let n = core::cmp::min(BASE64_LINE_INPUT_LEN - self.pending_len, input.len());
+            let (head, rest) = input.split_at(n);
+            self.pending[self.pending_len..(self.pending_len + n)].copy_from_slice(head);
+            self.pending_len += n;
+            input = rest;
+
+            if self.pending_len < BASE64_LINE_INPUT_LEN {
+                return Ok(());
+            }
+
+            self.buf.write_base64(&self.pending)?;
+            self.pending_len = 0;
+        }
+
+        let mut lines = input.chunks_exact(BASE64_LINE_INPUT_LEN);
+
+        for line in &mut lines {
+            self.buf.write_base64(line)?;
+        }
+
+        let remainder = lines.remainder();
+        self.pending[..remainder.len()].copy_from_slice(remainder);
+        self.pending_len = remainder.len();
+        Ok(())
+    }
+
+    /// Finish encoding, emitting any remaining buffered input followed by
+    /// the post-encapsulation boundary, and returning the portion of the
+    /// output buffer which has been written to.
+    pub fn finish(mut self) -> Result<&'o [u8]> {
+        self.flush()?;
+        self.buf.end_line()?;
+        self.buf.write(POST_ENCAPSULATION_BOUNDARY)?;
+        self.buf.write(self.label.as_bytes())?;
+        self.buf.writeln(ENCAPSULATION_BOUNDARY_DELIMITER)?;
+        self.buf.finish()
+    }
+
+    /// Write any remaining buffered input, then clear it.
+    fn flush(&mut self) -> Result<()> {
+        if self.pending_len > 0 {
+            self.buf.write_base64(&self.pending[..self.pending_len])?;
+        }
+
+        self.clear_pending();
+        Ok(())
+    }
+
+    /// Clear the buffered input.
+    fn clear_pending(&mut self) {
+        #[cfg(feature = "zeroize")]
+        self.pending.zeroize();
+
+        #[cfg(not(feature = "zeroize"))]
+        {
+            self.pending = [0u8; BASE64_LINE_INPUT_LEN];
+        }
+
+        self.pending_len = 0;
+    }
+}
+
+impl Drop for Encoder<'_, '_> {
+    fn drop(&mut self) {
+        self.clear_pending();
+    }
+}
+
+impl fmt::Debug for Encoder<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Encoder")
+            .field("label", &self.label)
+            .field("line_width", &self.buf.line_width)
+            .field("line_ending", &self.buf.line_ending)
+            .finish()
+    }
+}
+
 /// Line endings.
 ///
 /// Use [`LineEnding::default`] to get an appropriate line ending for the
@@ -116,16 +271,24 @@ struct Buffer<'a> {
     /// Total number of bytes written into the buffer so far.
     position: usize,
 
+    /// Width at which Base64 is wrapped.
+    line_width: usize,
+
+    /// Number of Base64 characters written to the current line.
+    column: usize,
+
     /// Line ending to use
     line_ending: LineEnding,
 }
 
 impl<'a> Buffer<'a> {
     /// Initialize buffer.
-    pub fn new(bytes: &'a mut [u8], line_ending: LineEnding) -> Self {
+    pub fn new(bytes: &'a mut [u8], line_width: usize, line_ending: LineEnding) -> Self {
         Self {
             bytes,
             position: 0,
+            line_width,
+            column: 0,
             line_ending,
         }
     }
@@ -143,19 +306,46 @@ impl<'a> Buffer<'a> {
         self.write(self.line_ending.as_bytes())
     }
 
-    /// Write Base64-encoded data to the buffer.
+    /// Write Base64-encoded data to the buffer, wrapping lines at the
+    /// configured width.
     ///
-    /// Automatically adds a newline at the end.
-    pub fn write_base64ln(&mut self, bytes: &[u8]) -> Result<()> {
-        let reserved = self.reserve(Base64::encoded_len(bytes))?;
-        Base64::encode(bytes, reserved)?;
-        self.write(self.line_ending.as_bytes())
+    /// At most [`BASE64_LINE_INPUT_LEN`] bytes can be written at once, and
+    /// only the final call may write a number of bytes which isn't a
+    /// multiple of 3.
+    pub fn write_base64(&mut self, bytes: &[u8]) -> Result<()> {
+        let mut encoded = [0u8; BASE64_WRAP_WIDTH];
+        let mut encoded = Base64::encode(bytes, &mut encoded)?.as_bytes();
+
+        while !encoded.is_empty() {
+            let n = core::cmp::min(self.line_width - self.column, encoded.len());
+            let (line, rest) = encoded.split_at(n);
+            self.write(line)?;
+            self.column += n;
+            encoded = rest;
+
+            if self.column == self.line_width {
+                self.end_line()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Terminate the current line of Base64, if it isn't empty.
+    pub fn end_line(&mut self) -> Result<()> {
+        if self.column > 0 {
+            self.column = 0;
+            self.write(self.line_ending.as_bytes())?;
+        }
+
+        Ok(())
     }
 
     /// Finish writing to the buffer, returning the portion that has been
     /// written to.
-    pub fn finish(self) -> Result<&'a [u8]> {
-        self.bytes.get(..self.position).ok_or(Error::Length)
+    pub fn finish(&mut self) -> Result<&'a [u8]> {
+        let bytes = mem::take(&mut self.bytes);
+        bytes.get(..self.position).ok_or(Error::Length)
     }
 
     /// Reserve space in the encoding buffer, returning a mutable slice.
@@ -171,3 +361,20 @@ impl<'a> Buffer<'a> {
         Ok(reserved)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Encoder, LineEnding};
+
+    #[test]
+    fn flush_clears_pending() {
+        let mut buf = [0u8; 128];
+        let mut encoder = Encoder::new("TEST", LineEnding::LF, &mut buf).unwrap();
+        encoder.encode(&[0xAA; 5]).unwrap();
+        assert_eq!(&encoder.pending[..encoder.pending_len], &[0xAA; 5]);
+
+        encoder.flush().unwrap();
+        assert_eq!(encoder.pending_len, 0);
+        assert!(encoder.pending.iter().all(|&byte| byte == 0));
+    }
+}
@@ -2,6 +2,22 @@
 
 use core::fmt;
 
+/// Well-known PEM type labels retained by [`Error::UnexpectedTypeLabel`].
+const WELL_KNOWN_TYPE_LABELS: &[&str] = &[
+    "ATTRIBUTE CERTIFICATE",
+    "CERTIFICATE",
+    "CERTIFICATE REQUEST",
+    "CMS",
+    "EC PRIVATE KEY",
+    "ENCRYPTED PRIVATE KEY",
+    "PKCS7",
+    "PRIVATE KEY",
+    "PUBLIC KEY",
+    "RSA PRIVATE KEY",
+    "RSA PUBLIC KEY",
+    "X509 CRL",
+];
+
 /// Result type.
 pub type Result<T> = core::result::Result<T, Error>;
 
@@ -35,21 +51,78 @@ pub enum Error {
 
     /// Errors in the post-encapsulation boundary.
     PostEncapsulationBoundary,
+
+    /// Type label of the document does not match the expected one.
+    UnexpectedTypeLabel {
+        /// Type label that was expected.
+        expected: &'static str,
+
+        /// Type label that was found, if it's one of the well-known labels
+        /// listed in [`Error::unexpected_type_label`].
+        actual: Option<&'static str>,
+    },
+}
+
+impl Error {
+    /// Create an [`Error::UnexpectedTypeLabel`] for the given expected and
+    /// actual type labels.
+    ///
+    /// The actual type label is only retained if it's one of the following
+    /// well-known labels, which allows dispatching on it programmatically
+    /// (e.g. to fall back to a PKCS#8 parser for a `PRIVATE KEY` document):
+    ///
+    /// - `ATTRIBUTE CERTIFICATE`
+    /// - `CERTIFICATE`
+    /// - `CERTIFICATE REQUEST`
+    /// - `CMS`
+    /// - `EC PRIVATE KEY`
+    /// - `ENCRYPTED PRIVATE KEY`
+    /// - `PKCS7`
+    /// - `PRIVATE KEY`
+    /// - `PUBLIC KEY`
+    /// - `RSA PRIVATE KEY`
+    /// - `RSA PUBLIC KEY`
+    /// - `X509 CRL`
+    pub fn unexpected_type_label(expected: &'static str, actual: &str) -> Error {
+        Error::UnexpectedTypeLabel {
+            expected,
+            actual: WELL_KNOWN_TYPE_LABELS
+                .iter()
+                .find(|&&label| label == actual)
+                .copied(),
+        }
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            Error::Base64 => "PEM Base64 error",
-            Error::CharacterEncoding => "PEM character encoding error",
-            Error::EncapsulatedText => "PEM error in encapsulated text",
-            Error::HeaderDisallowed => "PEM headers disallowed by RFC7468",
-            Error::Label => "PEM type label invalid",
-            Error::Length => "PEM length invalid",
-            Error::Preamble => "PEM preamble contains invalid data (NUL byte)",
-            Error::PreEncapsulationBoundary => "PEM error in pre-encapsulation boundary",
-            Error::PostEncapsulationBoundary => "PEM error in post-encapsulation boundary",
-        })
+        match self {
+            Error::Base64 => f.write_str("PEM Base64 error"),
+            Error::CharacterEncoding => f.write_str("PEM character encoding error"),
+            Error::EncapsulatedText => f.write_str("PEM error in encapsulated text"),
+            Error::HeaderDisallowed => f.write_str("PEM headers disallowed by RFC7468"),
+            Error::Label => f.write_str("PEM type label invalid"),
+            Error::Length => f.write_str("PEM length invalid"),
+            Error::Preamble => f.write_str("PEM preamble contains invalid data (NUL byte)"),
+            Error::PreEncapsulationBoundary => {
+                f.write_str("PEM error in pre-encapsulation boundary")
+            }
+            Error::PostEncapsulationBoundary => {
+                f.write_str("PEM error in post-encapsulation boundary")
+            }
+            Error::UnexpectedTypeLabel {
+                expected,
+                actual: Some(actual),
+            } => write!(
+                f,
+                "PEM type label invalid: expected \"{}\", found \"{}\"",
+                expected, actual
+            ),
+            Error::UnexpectedTypeLabel {
+                expected,
+                actual: None,
+            } => write!(f, "PEM type label invalid: expected \"{}\"", expected),
+        }
     }
 }
 
@@ -46,6 +46,24 @@
 //! demonstrates how the leakage from non-constant-time PEM parsers can be used
 //! to practically extract RSA private keys from SGX enclaves.
 //!
+//! # Constant-time decoding
+//!
+//! Decoding a well-formed document with [`decode`] or `decode_vec` takes time
+//! which depends only on the lengths of its label and encapsulated text, and
+//! on its line endings, but not on the data being encoded:
+//!
+//! - The encapsulation boundaries are located by scanning inwards from either
+//!   end of the document, stopping before the encapsulated text.
+//! - The encapsulated text is split into lines at fixed offsets, and the only
+//!   bytes inspected are the line endings at those offsets. Where the
+//!   following byte is inspected to tell CR and CRLF apart, the outcome is the
+//!   same for any Base64 character.
+//! - Each line, including any padding, is decoded with [`base64ct`].
+//!
+//! This does not extend to the error paths taken for malformed documents,
+//! nor to whatever is done with the decoded data afterwards (e.g. parsing it
+//! as DER).
+//!
 //! # Minimum Supported Rust Version
 //!
 //! This crate requires **Rust 1.51** at a minimum.
@@ -76,9 +94,9 @@
 //!     ]
 //! );
 //!
-//! // Encode PEM
-//! use pem_rfc7468::LineEnding;
-//! let encoded_pem = pem_rfc7468::encode_string(type_label, LineEnding::default(), &data)?;
+//! // Encode PEM, preserving the line endings of the original document
+//! let line_ending = pem_rfc7468::decode_line_ending(example_pem.as_bytes())?;
+//! let encoded_pem = pem_rfc7468::encode_string(type_label, line_ending, &data)?;
 //! assert_eq!(&encoded_pem, example_pem);
 //! # }
 //! # Ok(())
@@ -106,19 +124,25 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+mod bundle;
 mod decoder;
 mod encoder;
 mod error;
 mod grammar;
+mod rewrap;
 
 pub use crate::{
-    decoder::{decode, decode_label},
-    encoder::{encode, encoded_len, LineEnding},
+    decoder::{decode, decode_label, decode_line_ending},
+    encoder::{encode, encoded_len, Encoder, LineEnding},
     error::{Error, Result},
+    rewrap::rewrap,
 };
 
 #[cfg(feature = "alloc")]
-pub use crate::{decoder::decode_vec, encoder::encode_string};
+pub use crate::{
+    bundle::BundleWriter, decoder::decode_vec, encoder::encode_string, rewrap::rewrap_string,
+};
 
 /// The pre-encapsulation boundary appears before the encapsulated text.
 ///
@@ -144,8 +168,21 @@ const ENCAPSULATION_BOUNDARY_DELIMITER: &[u8] = b"-----";
 /// > handle other line sizes.
 const BASE64_WRAP_WIDTH: usize = 64;
 
+/// Number of input bytes which encode to a full line of Base64.
+const BASE64_LINE_INPUT_LEN: usize = (BASE64_WRAP_WIDTH * 3) / 4;
+
 /// Marker trait for types with an associated PEM type label.
 pub trait PemLabel {
     /// Expected PEM type label for a given document, e.g. `"PRIVATE KEY"`
     const TYPE_LABEL: &'static str;
+
+    /// Validate that the given label matches [`PemLabel::TYPE_LABEL`],
+    /// returning [`Error::UnexpectedTypeLabel`] if it doesn't.
+    fn validate_pem_label(actual: &str) -> Result<()> {
+        if actual == Self::TYPE_LABEL {
+            Ok(())
+        } else {
+            Err(Error::unexpected_type_label(Self::TYPE_LABEL, actual))
+        }
+    }
 }
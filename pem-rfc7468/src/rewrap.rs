@@ -0,0 +1,66 @@
+//! Re-wrapping of PEM documents.
+
+use crate::{
+    decoder::{decode_line, Encapsulation},
+    Encoder, LineEnding, Result, BASE64_LINE_INPUT_LEN,
+};
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{encoder::wrapped_len, Error},
+    alloc::string::String,
+};
+
+/// Re-encode a PEM document with the given line width and line ending,
+/// writing the result into the provided buffer.
+///
+/// The encapsulated text is decoded and re-encoded one line at a time, so
+/// the decoded document is never materialized in memory: the only buffers
+/// needed are the input and output documents.
+///
+/// Use a `line_width` of 64 to produce a document conforming to RFC 7468's
+/// "Strict" grammar. See [`Encoder::new_wrapped`] for other line widths.
+pub fn rewrap<'o>(
+    pem: &[u8],
+    line_width: usize,
+    line_ending: LineEnding,
+    buf: &'o mut [u8],
+) -> Result<&'o [u8]> {
+    let encapsulation = Encapsulation::try_from(pem)?;
+    let mut encoder = Encoder::new_wrapped(encapsulation.label(), line_width, line_ending, buf)?;
+    let mut line_buf = [0u8; BASE64_LINE_INPUT_LEN];
+
+    for (i, line) in encapsulation.encapsulated_text().enumerate() {
+        encoder.encode(decode_line(line?, i == 0, &mut line_buf)?)?;
+    }
+
+    encoder.finish()
+}
+
+/// Re-encode a PEM document with the given line width and line ending,
+/// returning the result as a [`String`].
+///
+/// See [`rewrap`] for more information.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn rewrap_string(pem: &[u8], line_width: usize, line_ending: LineEnding) -> Result<String> {
+    if line_width == 0 {
+        return Err(Error::Length);
+    }
+
+    let encapsulation = Encapsulation::try_from(pem)?;
+    let base64_len = encapsulation
+        .encapsulated_text()
+        .map(|line| line.map(<[u8]>::len))
+        .sum::<Result<usize>>()?;
+
+    let mut buf =
+        vec![0u8; wrapped_len(encapsulation.label(), line_width, line_ending, base64_len)];
+    let len = rewrap(pem, line_width, line_ending, &mut buf)?.len();
+
+    // Padding is only re-encoded on the final line, so inputs with padded
+    // lines before it produce a shorter output than estimated
+    buf.truncate(len);
+    String::from_utf8(buf).map_err(|_| Error::CharacterEncoding)
+}
@@ -0,0 +1,70 @@
+//! PEM bundle tests
+
+#![cfg(feature = "alloc")]
+
+use pem_rfc7468::{BundleWriter, LineEnding};
+
+const PKCS1_DER: &[u8] = include_bytes!("examples/pkcs1.der");
+const PKCS1_PEM: &str = include_str!("examples/pkcs1.pem");
+const PKCS8_DER: &[u8] = include_bytes!("examples/pkcs8.der");
+const PKCS8_PEM: &str = include_str!("examples/pkcs8.pem");
+
+#[test]
+fn encode_bundle() {
+    let mut bundle = BundleWriter::new(LineEnding::LF);
+    assert!(bundle.is_empty());
+    bundle.push("RSA PRIVATE KEY", PKCS1_DER).unwrap();
+    bundle.push("PRIVATE KEY", PKCS8_DER).unwrap();
+    assert_eq!(bundle.len(), 2);
+
+    let encoded = bundle.encode_string().unwrap();
+    assert_eq!(encoded, [PKCS1_PEM, PKCS8_PEM].concat());
+}
+
+#[test]
+fn encode_bundle_with_separator() {
+    let mut bundle = BundleWriter::new(LineEnding::LF).separator("\n");
+    bundle.push("RSA PRIVATE KEY", PKCS1_DER).unwrap();
+    bundle.push("PRIVATE KEY", PKCS8_DER).unwrap();
+
+    let encoded = bundle.encode_string().unwrap();
+    assert_eq!(encoded, [PKCS1_PEM, "\n", PKCS8_PEM].concat());
+}
+
+#[test]
+fn sort_by_labels() {
+    let mut bundle = BundleWriter::new(LineEnding::LF);
+    bundle.push("CERTIFICATE", &[1]).unwrap();
+    bundle.push("RSA PRIVATE KEY", PKCS1_DER).unwrap();
+    bundle.push("CERTIFICATE", &[2]).unwrap();
+    bundle.push("PRIVATE KEY", PKCS8_DER).unwrap();
+    bundle.sort_by_labels(&["PRIVATE KEY", "RSA PRIVATE KEY"]);
+
+    let encoded = bundle.encode_string().unwrap();
+    let certs = [
+        pem_rfc7468::encode_string("CERTIFICATE", LineEnding::LF, &[1]).unwrap(),
+        pem_rfc7468::encode_string("CERTIFICATE", LineEnding::LF, &[2]).unwrap(),
+    ];
+    assert_eq!(
+        encoded,
+        [PKCS8_PEM, PKCS1_PEM, &certs[0], &certs[1]].concat()
+    );
+}
+
+#[test]
+fn reject_invalid_label() {
+    let mut bundle = BundleWriter::new(LineEnding::LF);
+    assert!(bundle.push("PRIVATE  KEY", PKCS8_DER).is_err());
+    assert!(bundle.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_to() {
+    let mut bundle = BundleWriter::new(LineEnding::LF);
+    bundle.push("PRIVATE KEY", PKCS8_DER).unwrap();
+
+    let mut out = Vec::new();
+    bundle.write_to(&mut out).unwrap();
+    assert_eq!(out, PKCS8_PEM.as_bytes());
+}
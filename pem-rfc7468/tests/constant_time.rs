@@ -0,0 +1,108 @@
+//! Timing leakage tests for PEM decoding, in the style of dudect.
+//!
+//! These are ignored by default as they are sensitive to noise, and are only
+//! meaningful in release builds:
+//!
+//! ```text
+//! cargo test --release --all-features --test constant_time -- --ignored
+//! ```
+
+#![cfg(feature = "alloc")]
+
+use pem_rfc7468::LineEnding;
+use std::time::Instant;
+
+/// Number of measurements per test.
+const SAMPLES: usize = 100_000;
+
+/// Threshold for Welch's t-statistic above which we consider there to be a
+/// timing leak. dudect considers values above 10 to be a definite leak.
+const THRESHOLD: f64 = 10.0;
+
+/// Length of the encoded messages.
+const MESSAGE_LEN: usize = 1190;
+
+/// Simple xorshift PRNG, to avoid a dependency on `rand`.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill(&mut self, bytes: &mut [u8]) {
+        for byte in bytes {
+            *byte = self.next() as u8;
+        }
+    }
+}
+
+/// Online mean and variance.
+#[derive(Default)]
+struct Stats {
+    n: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Stats {
+    fn push(&mut self, x: f64) {
+        self.n += 1.0;
+        let delta = x - self.mean;
+        self.mean += delta / self.n;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        self.m2 / (self.n - 1.0)
+    }
+}
+
+/// Welch's t-test comparing decoding times for a fixed message and random
+/// messages of the same length.
+fn t_statistic(line_ending: LineEnding) -> f64 {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    let mut message = vec![0u8; MESSAGE_LEN];
+    let fixed = pem_rfc7468::encode_string("PRIVATE KEY", line_ending, &message).unwrap();
+
+    let mut buf = vec![0u8; MESSAGE_LEN];
+    let mut classes = [Stats::default(), Stats::default()];
+
+    for _ in 0..SAMPLES {
+        let class = (rng.next() & 1) as usize;
+
+        let pem = if class == 0 {
+            fixed.clone()
+        } else {
+            rng.fill(&mut message);
+            pem_rfc7468::encode_string("PRIVATE KEY", line_ending, &message).unwrap()
+        };
+
+        let start = Instant::now();
+        let result = pem_rfc7468::decode(pem.as_bytes(), &mut buf);
+        let elapsed = start.elapsed();
+
+        assert!(result.is_ok());
+        classes[class].push(elapsed.as_nanos() as f64);
+    }
+
+    let [a, b] = classes;
+    (a.mean - b.mean) / (a.variance() / a.n + b.variance() / b.n).sqrt()
+}
+
+#[test]
+#[ignore]
+fn decode_is_constant_time() {
+    for &line_ending in &[LineEnding::LF, LineEnding::CR, LineEnding::CRLF] {
+        let t = t_statistic(line_ending);
+        assert!(
+            t.abs() < THRESHOLD,
+            "timing leak detected with {:?} line endings: t = {}",
+            line_ending,
+            t
+        );
+    }
+}
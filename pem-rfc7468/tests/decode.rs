@@ -114,3 +114,54 @@ fn ed25519_example() {
     let label = pem_rfc7468::decode_label(pem).unwrap();
     assert_eq!(label, "ED25519 CERT");
 }
+
+#[test]
+fn unexpected_type_label() {
+    use pem_rfc7468::{Error, PemLabel};
+
+    struct RsaPrivateKey;
+
+    impl PemLabel for RsaPrivateKey {
+        const TYPE_LABEL: &'static str = "RSA PRIVATE KEY";
+    }
+
+    let mut buf = [0u8; 2048];
+    let (label, _) = pem_rfc7468::decode(include_bytes!("examples/pkcs8.pem"), &mut buf).unwrap();
+    assert_eq!(
+        RsaPrivateKey::validate_pem_label(label),
+        Err(Error::UnexpectedTypeLabel {
+            expected: "RSA PRIVATE KEY",
+            actual: Some("PRIVATE KEY")
+        })
+    );
+    assert_eq!(
+        Error::unexpected_type_label("PRIVATE KEY", "FOO"),
+        Error::UnexpectedTypeLabel {
+            expected: "PRIVATE KEY",
+            actual: None
+        }
+    );
+}
+
+#[test]
+fn line_ending_example() {
+    use pem_rfc7468::LineEnding;
+
+    let pem = include_str!("examples/pkcs1.pem");
+    assert_eq!(
+        pem_rfc7468::decode_line_ending(pem.as_bytes()).unwrap(),
+        LineEnding::LF
+    );
+
+    let crlf = pem.replace('\n', "\r\n");
+    assert_eq!(
+        pem_rfc7468::decode_line_ending(crlf.as_bytes()).unwrap(),
+        LineEnding::CRLF
+    );
+
+    let cr = pem.replace('\n', "\r");
+    assert_eq!(
+        pem_rfc7468::decode_line_ending(cr.as_bytes()).unwrap(),
+        LineEnding::CR
+    );
+}
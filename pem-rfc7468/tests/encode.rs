@@ -19,3 +19,30 @@ fn pkcs8_example() {
     let encoded = pem_rfc7468::encode_string(label, LineEnding::LF, bytes).unwrap();
     assert_eq!(&encoded, include_str!("examples/pkcs8.pem"));
 }
+
+#[test]
+fn streaming_example() {
+    let label = "PRIVATE KEY";
+    let bytes = include_bytes!("examples/pkcs8.der");
+    let expected = include_str!("examples/pkcs8.pem");
+
+    for chunk_size in &[1, 7, 48, 100] {
+        let mut buf = [0u8; 1024];
+        let mut encoder = pem_rfc7468::Encoder::new(label, LineEnding::LF, &mut buf).unwrap();
+
+        for chunk in bytes.chunks(*chunk_size) {
+            encoder.encode(chunk).unwrap();
+        }
+
+        assert_eq!(encoder.finish().unwrap(), expected.as_bytes());
+    }
+}
+
+#[test]
+fn preserve_line_ending() {
+    let pem = include_str!("examples/pkcs1.pem").replace('\n', "\r\n");
+    let (label, bytes) = pem_rfc7468::decode_vec(pem.as_bytes()).unwrap();
+    let line_ending = pem_rfc7468::decode_line_ending(pem.as_bytes()).unwrap();
+    let encoded = pem_rfc7468::encode_string(label, line_ending, &bytes).unwrap();
+    assert_eq!(encoded, pem);
+}
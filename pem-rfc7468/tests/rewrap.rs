@@ -0,0 +1,96 @@
+//! PEM re-wrapping tests
+
+use pem_rfc7468::{Error, LineEnding};
+
+const PKCS1_PEM: &str = include_str!("examples/pkcs1.pem");
+#[cfg(feature = "alloc")]
+const PKCS8_PEM: &str = include_str!("examples/pkcs8.pem");
+
+#[test]
+fn rewrap_line_ending() {
+    let mut buf = [0u8; 2048];
+    let crlf = pem_rfc7468::rewrap(PKCS1_PEM.as_bytes(), 64, LineEnding::CRLF, &mut buf).unwrap();
+    assert_eq!(crlf, PKCS1_PEM.replace('\n', "\r\n").as_bytes());
+
+    let mut buf2 = [0u8; 2048];
+    let lf = pem_rfc7468::rewrap(crlf, 64, LineEnding::LF, &mut buf2).unwrap();
+    assert_eq!(lf, PKCS1_PEM.as_bytes());
+}
+
+#[test]
+fn rewrap_zero_line_width() {
+    let mut buf = [0u8; 2048];
+    assert_eq!(
+        pem_rfc7468::rewrap(PKCS1_PEM.as_bytes(), 0, LineEnding::LF, &mut buf),
+        Err(Error::Length)
+    );
+}
+
+#[test]
+fn rewrap_buffer_too_small() {
+    let mut buf = [0u8; 128];
+    assert_eq!(
+        pem_rfc7468::rewrap(PKCS1_PEM.as_bytes(), 64, LineEnding::LF, &mut buf),
+        Err(Error::Length)
+    );
+}
+
+#[test]
+fn encoder_line_width() {
+    let mut buf = [0u8; 256];
+    let mut encoder =
+        pem_rfc7468::Encoder::new_wrapped("EXAMPLE", 8, LineEnding::LF, &mut buf).unwrap();
+
+    encoder.encode(b"hello, ").unwrap();
+    encoder.encode(b"world").unwrap();
+
+    assert_eq!(
+        encoder.finish().unwrap(),
+        b"-----BEGIN EXAMPLE-----\naGVsbG8s\nIHdvcmxk\n-----END EXAMPLE-----\n"
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn rewrap_string_line_width() {
+    for &line_width in &[4, 64, 76, 1000] {
+        let rewrapped =
+            pem_rfc7468::rewrap_string(PKCS1_PEM.as_bytes(), line_width, LineEnding::LF).unwrap();
+
+        let lines = rewrapped.lines().collect::<Vec<_>>();
+        assert_eq!(lines.first(), PKCS1_PEM.lines().next().as_ref());
+        assert_eq!(lines.last(), PKCS1_PEM.lines().last().as_ref());
+
+        let body = &lines[1..lines.len() - 1];
+        assert!(body.iter().all(|line| line.len() <= line_width));
+        assert!(body[..body.len() - 1]
+            .iter()
+            .all(|line| line.len() == line_width));
+
+        assert_eq!(
+            body.concat(),
+            PKCS1_PEM
+                .lines()
+                .skip(1)
+                .take_while(|l| !l.starts_with('-'))
+                .collect::<String>()
+        );
+
+        let restored = pem_rfc7468::rewrap_string(rewrapped.as_bytes(), 64, LineEnding::LF).err();
+
+        // Only documents wrapped at 64 characters can be decoded again
+        if line_width == 64 {
+            assert_eq!(restored, None);
+            assert_eq!(rewrapped, PKCS1_PEM);
+        } else {
+            assert!(restored.is_some());
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn rewrap_string_single_line() {
+    let rewrapped = pem_rfc7468::rewrap_string(PKCS8_PEM.as_bytes(), 64, LineEnding::CR).unwrap();
+    assert_eq!(rewrapped, PKCS8_PEM.replace('\n', "\r"));
+}
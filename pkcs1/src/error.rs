@@ -5,6 +5,9 @@ use core::fmt;
 #[cfg(feature = "pem")]
 use crate::pem;
 
+#[cfg(feature = "std")]
+use std::{format, io, path::Path};
+
 /// Message to display when an `expect`-ed DER encoding error occurs
 #[cfg(feature = "alloc")]
 pub(crate) const DER_ENCODING_MSG: &str = "DER encoding error";
@@ -37,6 +40,14 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     Io,
 
+    /// A multi-prime key has an empty `otherPrimeInfos`, which must contain
+    /// at least one entry.
+    OtherPrimeInfosEmpty,
+
+    /// The primes in `otherPrimeInfos` are not in the order given by their
+    /// CRT coefficients, as required by RFC 8017.
+    OtherPrimeInfosOrder,
+
     /// PEM encoding errors.
     #[cfg(feature = "pem")]
     Pem(pem::Error),
@@ -59,6 +70,10 @@ impl fmt::Display for Error {
             Error::FileNotFound => f.write_str("file not found"),
             #[cfg(feature = "std")]
             Error::Io => f.write_str("I/O error"),
+            Error::OtherPrimeInfosEmpty => f.write_str("PKCS#1 otherPrimeInfos is empty"),
+            Error::OtherPrimeInfosOrder => {
+                f.write_str("PKCS#1 otherPrimeInfos primes are out of order")
+            }
             #[cfg(feature = "pem")]
             Error::Pem(err) => write!(f, "PKCS#1 {}", err),
             Error::Version => f.write_str("PKCS#1 version error"),
@@ -85,12 +100,72 @@ impl From<pem::Error> for Error {
 impl std::error::Error for Error {}
 
 #[cfg(feature = "std")]
-impl From<std::io::Error> for Error {
-    fn from(err: std::io::Error) -> Error {
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
         match err.kind() {
-            std::io::ErrorKind::NotFound => Error::FileNotFound,
-            std::io::ErrorKind::PermissionDenied => Error::PermissionDenied,
+            io::ErrorKind::NotFound => Error::FileNotFound,
+            io::ErrorKind::PermissionDenied => Error::PermissionDenied,
             _ => Error::Io,
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl From<Error> for io::Error {
+    fn from(err: Error) -> io::Error {
+        io::Error::new(err.io_kind(), err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error {
+    /// Get the [`io::ErrorKind`] corresponding to this error.
+    fn io_kind(self) -> io::ErrorKind {
+        match self {
+            Error::FileNotFound => io::ErrorKind::NotFound,
+            Error::PermissionDenied => io::ErrorKind::PermissionDenied,
+            Error::Io => io::ErrorKind::Other,
+            _ => io::ErrorKind::InvalidData,
+        }
+    }
+}
+
+/// Extension trait for [`Result`] for converting errors into [`io::Error`]s
+/// which identify the file being loaded.
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// use pkcs1::{DecodeRsaPrivateKey, ResultExt, RsaPrivateKeyDocument};
+///
+/// let path = "key.der";
+/// let key = RsaPrivateKeyDocument::read_pkcs1_der_file(path).with_path(path)?;
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub trait ResultExt<T> {
+    /// Convert the error into an [`io::Error`] whose message includes the
+    /// given path.
+    ///
+    /// The [`io::ErrorKind`] is [`NotFound`][io::ErrorKind::NotFound] or
+    /// [`PermissionDenied`][io::ErrorKind::PermissionDenied] if the file
+    /// couldn't be read, and [`InvalidData`][io::ErrorKind::InvalidData] if
+    /// it was read but its contents are invalid.
+    fn with_path(self, path: impl AsRef<Path>) -> io::Result<T>;
+}
+
+#[cfg(feature = "std")]
+impl<T> ResultExt<T> for Result<T> {
+    fn with_path(self, path: impl AsRef<Path>) -> io::Result<T> {
+        self.map_err(|err| {
+            io::Error::new(
+                err.io_kind(),
+                format!("{}: {}", path.as_ref().display(), err),
+            )
+        })
+    }
+}
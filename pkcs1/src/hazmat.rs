@@ -0,0 +1,278 @@
+//! Hazardous materials: raw, unvalidated PKCS#1 structures.
+//!
+//! The types in this module allow assembling and encoding RSA keys with
+//! arbitrary `INTEGER` contents, bypassing all of the validation performed
+//! by [`RsaPrivateKey`] and [`RsaPublicKey`]. This includes components which
+//! are not minimally encoded, negative, or inconsistent with each other
+//! (e.g. a wrong CRT coefficient or a `version` which doesn't match
+//! `otherPrimeInfos`).
+//!
+//! **WARNING:** these types are intended for negative testing of other
+//! implementations, e.g. checking that they reject malformed keys. Keys
+//! produced this way are generally invalid and MUST NOT be used for any
+//! cryptographic operation.
+
+use crate::{OtherPrimeInfo, RsaPrivateKey, RsaPublicKey};
+use alloc::vec::Vec;
+use der::{
+    asn1::{Any, UIntBytes},
+    Decodable, Decoder, Encodable, Sequence, Tag,
+};
+use zeroize::Zeroize;
+
+/// Raw `RSAPrivateKey` whose components are the unvalidated contents octets
+/// of each `INTEGER`.
+///
+/// Converting from an [`RsaPrivateKey`] produces the same encoding as the
+/// original key, which makes it convenient to start from a valid key and
+/// then alter a single component.
+///
+/// The private components are zeroized on drop.
+#[derive(Clone, Eq, PartialEq)]
+pub struct RawRsaPrivateKey {
+    /// `version` contents octets.
+    pub version: Vec<u8>,
+
+    /// `modulus` contents octets.
+    pub modulus: Vec<u8>,
+
+    /// `publicExponent` contents octets.
+    pub public_exponent: Vec<u8>,
+
+    /// `privateExponent` contents octets.
+    pub private_exponent: Vec<u8>,
+
+    /// `prime1` contents octets.
+    pub prime1: Vec<u8>,
+
+    /// `prime2` contents octets.
+    pub prime2: Vec<u8>,
+
+    /// `exponent1` contents octets.
+    pub exponent1: Vec<u8>,
+
+    /// `exponent2` contents octets.
+    pub exponent2: Vec<u8>,
+
+    /// `coefficient` contents octets.
+    pub coefficient: Vec<u8>,
+
+    /// `otherPrimeInfos`, which is omitted when `None`.
+    pub other_prime_infos: Option<Vec<RawOtherPrimeInfo>>,
+}
+
+der::debug_redacted!(RawRsaPrivateKey {
+    version, modulus, public_exponent;
+    redact private_exponent, prime1, prime2, exponent1, exponent2, coefficient, other_prime_infos
+});
+
+impl Zeroize for RawRsaPrivateKey {
+    fn zeroize(&mut self) {
+        self.private_exponent.zeroize();
+        self.prime1.zeroize();
+        self.prime2.zeroize();
+        self.exponent1.zeroize();
+        self.exponent2.zeroize();
+        self.coefficient.zeroize();
+        self.other_prime_infos.zeroize();
+    }
+}
+
+impl Drop for RawRsaPrivateKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl From<&RsaPrivateKey<'_>> for RawRsaPrivateKey {
+    fn from(private_key: &RsaPrivateKey<'_>) -> RawRsaPrivateKey {
+        RawRsaPrivateKey {
+            version: alloc::vec![private_key.version.into()],
+            modulus: uint_contents(private_key.modulus),
+            public_exponent: uint_contents(private_key.public_exponent),
+            private_exponent: uint_contents(private_key.private_exponent),
+            prime1: uint_contents(private_key.prime1),
+            prime2: uint_contents(private_key.prime2),
+            exponent1: uint_contents(private_key.exponent1),
+            exponent2: uint_contents(private_key.exponent2),
+            coefficient: uint_contents(private_key.coefficient),
+            other_prime_infos: private_key
+                .other_prime_infos
+                .as_ref()
+                .map(|infos| infos.iter().map(RawOtherPrimeInfo::from).collect()),
+        }
+    }
+}
+
+impl<'a> Decodable<'a> for RawRsaPrivateKey {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                version: integer(decoder)?,
+                modulus: integer(decoder)?,
+                public_exponent: integer(decoder)?,
+                private_exponent: integer(decoder)?,
+                prime1: integer(decoder)?,
+                prime2: integer(decoder)?,
+                exponent1: integer(decoder)?,
+                exponent2: integer(decoder)?,
+                coefficient: integer(decoder)?,
+                other_prime_infos: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RawRsaPrivateKey {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> der::Result<T>,
+    {
+        f(&[
+            &Any::new(Tag::Integer, &self.version)?,
+            &Any::new(Tag::Integer, &self.modulus)?,
+            &Any::new(Tag::Integer, &self.public_exponent)?,
+            &Any::new(Tag::Integer, &self.private_exponent)?,
+            &Any::new(Tag::Integer, &self.prime1)?,
+            &Any::new(Tag::Integer, &self.prime2)?,
+            &Any::new(Tag::Integer, &self.exponent1)?,
+            &Any::new(Tag::Integer, &self.exponent2)?,
+            &Any::new(Tag::Integer, &self.coefficient)?,
+            &self.other_prime_infos,
+        ])
+    }
+}
+
+/// Raw `OtherPrimeInfo` whose components are the unvalidated contents
+/// octets of each `INTEGER`.
+///
+/// All of its components are private, and zeroized on drop.
+#[derive(Clone, Eq, PartialEq)]
+pub struct RawOtherPrimeInfo {
+    /// `prime` contents octets.
+    pub prime: Vec<u8>,
+
+    /// `exponent` contents octets.
+    pub exponent: Vec<u8>,
+
+    /// `coefficient` contents octets.
+    pub coefficient: Vec<u8>,
+}
+
+der::debug_redacted!(RawOtherPrimeInfo {
+    ;
+    redact prime, exponent, coefficient
+});
+
+impl Zeroize for RawOtherPrimeInfo {
+    fn zeroize(&mut self) {
+        self.prime.zeroize();
+        self.exponent.zeroize();
+        self.coefficient.zeroize();
+    }
+}
+
+impl Drop for RawOtherPrimeInfo {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl From<&OtherPrimeInfo<'_>> for RawOtherPrimeInfo {
+    fn from(info: &OtherPrimeInfo<'_>) -> RawOtherPrimeInfo {
+        RawOtherPrimeInfo {
+            prime: uint_contents(info.prime),
+            exponent: uint_contents(info.exponent),
+            coefficient: uint_contents(info.coefficient),
+        }
+    }
+}
+
+impl<'a> Decodable<'a> for RawOtherPrimeInfo {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                prime: integer(decoder)?,
+                exponent: integer(decoder)?,
+                coefficient: integer(decoder)?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RawOtherPrimeInfo {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> der::Result<T>,
+    {
+        f(&[
+            &Any::new(Tag::Integer, &self.prime)?,
+            &Any::new(Tag::Integer, &self.exponent)?,
+            &Any::new(Tag::Integer, &self.coefficient)?,
+        ])
+    }
+}
+
+/// Raw `RSAPublicKey` whose components are the unvalidated contents octets
+/// of each `INTEGER`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RawRsaPublicKey {
+    /// `modulus` contents octets.
+    pub modulus: Vec<u8>,
+
+    /// `publicExponent` contents octets.
+    pub public_exponent: Vec<u8>,
+}
+
+impl From<&RsaPublicKey<'_>> for RawRsaPublicKey {
+    fn from(public_key: &RsaPublicKey<'_>) -> RawRsaPublicKey {
+        RawRsaPublicKey {
+            modulus: uint_contents(public_key.modulus),
+            public_exponent: uint_contents(public_key.public_exponent),
+        }
+    }
+}
+
+impl<'a> Decodable<'a> for RawRsaPublicKey {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                modulus: integer(decoder)?,
+                public_exponent: integer(decoder)?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RawRsaPublicKey {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> der::Result<T>,
+    {
+        f(&[
+            &Any::new(Tag::Integer, &self.modulus)?,
+            &Any::new(Tag::Integer, &self.public_exponent)?,
+        ])
+    }
+}
+
+/// Decode the contents octets of an `INTEGER` without validating them.
+fn integer(decoder: &mut Decoder<'_>) -> der::Result<Vec<u8>> {
+    let any = decoder.any()?;
+    any.tag().assert_eq(Tag::Integer)?;
+    Ok(any.value().to_vec())
+}
+
+/// Get the contents octets of the canonical encoding of an unsigned
+/// `INTEGER`, i.e. with a leading zero if its high bit is set.
+fn uint_contents(uint: UIntBytes<'_>) -> Vec<u8> {
+    let bytes = uint.as_bytes();
+    let mut contents = Vec::with_capacity(bytes.len() + 1);
+
+    if bytes.first().map_or(true, |byte| byte & 0x80 != 0) {
+        contents.push(0);
+    }
+
+    contents.extend_from_slice(bytes);
+    contents
+}
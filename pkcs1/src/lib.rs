@@ -34,25 +34,43 @@
     html_root_url = "https://docs.rs/pkcs1/0.2.4"
 )]
 #![forbid(unsafe_code, clippy::unwrap_used)]
-#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+#![warn(
+    missing_debug_implementations,
+    missing_docs,
+    rust_2018_idioms,
+    unused_qualifications
+)]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "hazmat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hazmat")))]
+pub mod hazmat;
+
+#[cfg(feature = "pkcs11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+pub mod pkcs11;
+
 mod error;
 mod private_key;
 mod public_key;
+mod summary;
 mod traits;
 mod version;
 
-pub use der::{self, asn1::UIntBytes};
+#[cfg(feature = "alloc")]
+mod storage;
+
+pub use der::{self, asn1::UIntBytes, consts, generic_array};
 
 pub use self::{
     error::{Error, Result},
     private_key::RsaPrivateKey,
     public_key::RsaPublicKey,
+    summary::RsaKeySummary,
     traits::{DecodeRsaPrivateKey, DecodeRsaPublicKey},
     version::Version,
 };
@@ -68,4 +86,11 @@ pub use crate::{
 
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-pub use der::pem::{self, LineEnding};
+pub use der::pem::{self, LineEnding, SecretPemString};
+
+#[cfg(feature = "spki")]
+#[cfg_attr(docsrs, doc(cfg(feature = "spki")))]
+pub use spki;
+
+#[cfg(feature = "std")]
+pub use crate::error::ResultExt;
@@ -0,0 +1,153 @@
+//! PKCS#11 object templates for RSA keys.
+//!
+//! The functions in this module map RSA keys onto the attributes which
+//! `C_CreateObject` expects when importing them into a PKCS#11 token, as
+//! described in [PKCS #11 Current Mechanisms Specification] Section 2.1.
+//! The templates contain the object class, the key type and the key
+//! components, and can be extended with further attributes such as
+//! `CKA_TOKEN`, `CKA_LABEL` or `CKA_ID` before use.
+//!
+//! [PKCS #11 Current Mechanisms Specification]: https://docs.oasis-open.org/pkcs11/pkcs11-curr/v2.40/pkcs11-curr-v2.40.html
+
+use crate::{Error, Result, RsaPrivateKey, RsaPublicKey};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use der::asn1::UIntBytes;
+use zeroize::Zeroize;
+
+/// `CKA_CLASS` attribute type.
+pub const CKA_CLASS: u64 = 0x0000;
+
+/// `CKA_KEY_TYPE` attribute type.
+pub const CKA_KEY_TYPE: u64 = 0x0100;
+
+/// `CKA_MODULUS` attribute type.
+pub const CKA_MODULUS: u64 = 0x0120;
+
+/// `CKA_PUBLIC_EXPONENT` attribute type.
+pub const CKA_PUBLIC_EXPONENT: u64 = 0x0122;
+
+/// `CKA_PRIVATE_EXPONENT` attribute type.
+pub const CKA_PRIVATE_EXPONENT: u64 = 0x0123;
+
+/// `CKA_PRIME_1` attribute type.
+pub const CKA_PRIME_1: u64 = 0x0124;
+
+/// `CKA_PRIME_2` attribute type.
+pub const CKA_PRIME_2: u64 = 0x0125;
+
+/// `CKA_EXPONENT_1` attribute type.
+pub const CKA_EXPONENT_1: u64 = 0x0126;
+
+/// `CKA_EXPONENT_2` attribute type.
+pub const CKA_EXPONENT_2: u64 = 0x0127;
+
+/// `CKA_COEFFICIENT` attribute type.
+pub const CKA_COEFFICIENT: u64 = 0x0128;
+
+/// `CKO_PUBLIC_KEY` object class.
+pub const CKO_PUBLIC_KEY: u64 = 0x0002;
+
+/// `CKO_PRIVATE_KEY` object class.
+pub const CKO_PRIVATE_KEY: u64 = 0x0003;
+
+/// `CKK_RSA` key type.
+pub const CKK_RSA: u64 = 0x0000;
+
+/// PKCS#11 attribute (`CK_ATTRIBUTE`) of an object template.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attribute {
+    /// Attribute type (`CK_ATTRIBUTE_TYPE`).
+    pub attribute_type: u64,
+
+    /// Attribute value.
+    pub value: AttributeValue,
+}
+
+impl Attribute {
+    /// Create a `CK_ULONG` attribute.
+    pub fn ulong(attribute_type: u64, value: u64) -> Self {
+        Self {
+            attribute_type,
+            value: AttributeValue::Ulong(value),
+        }
+    }
+
+    /// Create a byte array attribute.
+    pub fn bytes(attribute_type: u64, value: impl Into<Vec<u8>>) -> Self {
+        Self {
+            attribute_type,
+            value: AttributeValue::Bytes(value.into()),
+        }
+    }
+}
+
+/// Value of an [`Attribute`].
+#[derive(Clone, Eq, PartialEq)]
+pub enum AttributeValue {
+    /// `CK_ULONG` value, e.g. an object class or key type.
+    ///
+    /// PKCS#11 expects it in the native byte order and width of `CK_ULONG`,
+    /// which is 32-bit on some platforms, so it must be converted first.
+    Ulong(u64),
+
+    /// Byte array value, e.g. a big endian integer without leading zeroes.
+    ///
+    /// This is zeroized on drop as it may contain secret key material.
+    Bytes(Vec<u8>),
+}
+
+impl fmt::Debug for AttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttributeValue::Ulong(value) => f.debug_tuple("Ulong").field(value).finish(),
+            AttributeValue::Bytes(_) => f.debug_tuple("Bytes").field(&der::Redacted).finish(),
+        }
+    }
+}
+
+impl Drop for AttributeValue {
+    fn drop(&mut self) {
+        if let AttributeValue::Bytes(bytes) = self {
+            bytes.zeroize();
+        }
+    }
+}
+
+/// Get the `CKO_PRIVATE_KEY` template of an RSA private key.
+///
+/// PKCS#11 has no attributes for the additional primes of a multi-prime
+/// key, so such keys are rejected with [`Error::Version`].
+pub fn private_key_template(private_key: &RsaPrivateKey<'_>) -> Result<Vec<Attribute>> {
+    if private_key.version.is_multi() {
+        return Err(Error::Version);
+    }
+
+    Ok(vec![
+        Attribute::ulong(CKA_CLASS, CKO_PRIVATE_KEY),
+        Attribute::ulong(CKA_KEY_TYPE, CKK_RSA),
+        integer(CKA_MODULUS, private_key.modulus),
+        integer(CKA_PUBLIC_EXPONENT, private_key.public_exponent),
+        integer(CKA_PRIVATE_EXPONENT, private_key.private_exponent),
+        integer(CKA_PRIME_1, private_key.prime1),
+        integer(CKA_PRIME_2, private_key.prime2),
+        integer(CKA_EXPONENT_1, private_key.exponent1),
+        integer(CKA_EXPONENT_2, private_key.exponent2),
+        integer(CKA_COEFFICIENT, private_key.coefficient),
+    ])
+}
+
+/// Get the `CKO_PUBLIC_KEY` template of an RSA public key.
+pub fn public_key_template(public_key: &RsaPublicKey<'_>) -> Vec<Attribute> {
+    vec![
+        Attribute::ulong(CKA_CLASS, CKO_PUBLIC_KEY),
+        Attribute::ulong(CKA_KEY_TYPE, CKK_RSA),
+        integer(CKA_MODULUS, public_key.modulus),
+        integer(CKA_PUBLIC_EXPONENT, public_key.public_exponent),
+    ]
+}
+
+/// Create a big integer attribute, which has no leading zeroes.
+fn integer(attribute_type: u64, value: UIntBytes<'_>) -> Attribute {
+    Attribute::bytes(attribute_type, value.as_bytes())
+}
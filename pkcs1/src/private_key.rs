@@ -5,9 +5,9 @@ pub(crate) mod document;
 #[cfg(feature = "alloc")]
 pub(crate) mod other_prime_info;
 
-use crate::{Error, Result, RsaPublicKey, Version};
-use core::{convert::TryFrom, fmt};
-use der::{asn1::UIntBytes, Decodable, Decoder, Encodable, Sequence, Tag};
+use crate::{public_key::uint_tlv_len, Error, Result, RsaKeySummary, RsaPublicKey, Version};
+use core::convert::TryFrom;
+use der::{asn1::UIntBytes, Decodable, Decoder, Encodable, ErrorKind, Header, Sequence, Tag};
 
 #[cfg(feature = "alloc")]
 use {
@@ -15,10 +15,11 @@ use {
     crate::{EncodeRsaPrivateKey, RsaPrivateKeyDocument},
     alloc::vec::Vec,
     core::convert::TryInto,
+    der::bigint::{ArrayEncoding, UInt},
 };
 
 #[cfg(feature = "pem")]
-use {crate::LineEnding, alloc::string::String, zeroize::Zeroizing};
+use crate::{LineEnding, SecretPemString};
 
 /// PKCS#1 RSA Private Keys as defined in [RFC 8017 Appendix 1.2].
 ///
@@ -75,6 +76,38 @@ pub struct RsaPrivateKey<'a> {
 }
 
 impl<'a> RsaPrivateKey<'a> {
+    /// Get the maximum length of the DER encoding of a two-prime
+    /// [`RsaPrivateKey`] with a modulus of `BITS` bits.
+    ///
+    /// This allows sizing a buffer at compile time for the largest key an
+    /// application supports:
+    ///
+    /// ```
+    /// use pkcs1::RsaPrivateKey;
+    ///
+    /// // Large enough for any key with a modulus of up to 4096 bits
+    /// const MAX_KEY_LEN: usize = RsaPrivateKey::max_der_len::<4096>();
+    ///
+    /// let mut buf = [0u8; MAX_KEY_LEN];
+    /// # let _ = &mut buf;
+    /// ```
+    ///
+    /// The public exponent is assumed to be no larger than the modulus, and
+    /// each prime to have at most `BITS / 2` bits (rounded up), as is the
+    /// case for keys generated by common implementations. Multi-prime keys
+    /// are not covered.
+    pub const fn max_der_len<const BITS: usize>() -> usize {
+        let version = Header::tlv_len(1);
+
+        // `modulus`, `publicExponent` and `privateExponent`
+        let exponents = 3 * uint_tlv_len(BITS);
+
+        // `prime1`, `prime2`, `exponent1`, `exponent2` and `coefficient`
+        let primes = 5 * uint_tlv_len((BITS + 1) / 2);
+
+        Header::tlv_len(version + exponents + primes)
+    }
+
     /// Get the public key that corresponds to this [`RsaPrivateKey`].
     pub fn public_key(&self) -> RsaPublicKey<'a> {
         RsaPublicKey {
@@ -83,6 +116,121 @@ impl<'a> RsaPrivateKey<'a> {
         }
     }
 
+    /// Get a summary of the non-secret metadata of this key: its modulus
+    /// size, public exponent and number of primes.
+    pub fn summary(&self) -> RsaKeySummary {
+        let primes = match &self.other_prime_infos {
+            #[cfg(feature = "alloc")]
+            Some(other_prime_infos) => 2 + other_prime_infos.len(),
+            _ => 2,
+        };
+
+        RsaKeySummary::new(self.modulus, self.public_exponent, Some(primes))
+    }
+
+    /// Decode an [`RsaPrivateKey`] from ASN.1 DER, accepting `INTEGER`
+    /// components which are not minimally encoded (i.e. which have redundant
+    /// leading zeroes), as emitted by some legacy toolchains.
+    ///
+    /// Returns the key along with a flag which is `true` if any component
+    /// was normalized. Re-encoding the key always produces canonical DER.
+    ///
+    /// Note that `otherPrimeInfos` are still decoded strictly.
+    pub fn from_der_lenient(bytes: &'a [u8]) -> Result<(Self, bool)> {
+        let mut normalized = false;
+        let mut decoder = Decoder::new(bytes);
+        let key = Self::decode_with(&mut decoder, |decoder| {
+            let any = decoder.any()?;
+
+            match any.decode_into() {
+                Err(err)
+                    if err.kind() == (ErrorKind::Noncanonical { tag: Tag::Integer })
+                        && any.value().first() == Some(&0) =>
+                {
+                    normalized = true;
+                    UIntBytes::new(any.value())
+                }
+                result => result,
+            }
+        })?;
+
+        Ok((decoder.finish(key)?, normalized))
+    }
+
+    /// Decode an [`RsaPrivateKey`] using the provided function to decode its
+    /// `INTEGER` components.
+    fn decode_with(
+        decoder: &mut Decoder<'a>,
+        mut uint: impl FnMut(&mut Decoder<'a>) -> der::Result<UIntBytes<'a>>,
+    ) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            let result = Self {
+                version: decoder.decode()?,
+                modulus: uint(decoder)?,
+                public_exponent: uint(decoder)?,
+                private_exponent: uint(decoder)?,
+                prime1: uint(decoder)?,
+                prime2: uint(decoder)?,
+                exponent1: uint(decoder)?,
+                exponent2: uint(decoder)?,
+                coefficient: uint(decoder)?,
+                other_prime_infos: decoder.decode()?,
+            };
+
+            match result.check_other_prime_infos() {
+                Ok(()) => Ok(result),
+                Err(Error::OtherPrimeInfosEmpty) => {
+                    Err(decoder.error(ErrorKind::Value { tag: Tag::Sequence }))
+                }
+                // Version is set incorrectly for two-prime vs multi-prime key.
+                Err(_) => Err(decoder.error(ErrorKind::Value { tag: Tag::Integer })),
+            }
+        })
+    }
+
+    /// Check `version` and `otherPrimeInfos` are consistent: multi-prime keys
+    /// must have at least one [`OtherPrimeInfo`], and two-prime keys none.
+    pub(crate) fn check_other_prime_infos(&self) -> Result<()> {
+        if self.version.is_multi() != self.other_prime_infos.is_some() {
+            return Err(Error::Version);
+        }
+
+        #[cfg(feature = "alloc")]
+        if matches!(&self.other_prime_infos, Some(infos) if infos.is_empty()) {
+            return Err(Error::OtherPrimeInfosEmpty);
+        }
+
+        Ok(())
+    }
+
+    /// Check the primes in `otherPrimeInfos` are in the order required by
+    /// [RFC 8017 Appendix 1.2], i.e. that the CRT coefficient of each
+    /// `r_i` is the inverse of `r_1 * r_2 * ... * r_(i-1)` modulo `r_i`.
+    ///
+    /// `LIMBS` must be large enough to hold each of the primes. Returns
+    /// [`Error::OtherPrimeInfosOrder`] if the primes are out of order.
+    ///
+    /// [RFC 8017 Appendix 1.2]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.1.2
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn check_prime_order<const LIMBS: usize>(&self) -> Result<()>
+    where
+        UInt<LIMBS>: ArrayEncoding,
+    {
+        self.check_other_prime_infos()?;
+        let mut previous_primes = alloc::vec![self.prime1, self.prime2];
+
+        for info in self.other_prime_infos.iter().flatten() {
+            if !info.check_coefficient::<LIMBS>(&previous_primes)? {
+                return Err(Error::OtherPrimeInfosOrder);
+            }
+
+            previous_primes.push(info.prime);
+        }
+
+        Ok(())
+    }
+
     /// Encode this [`RsaPrivateKey`] as ASN.1 DER.
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -94,34 +242,14 @@ impl<'a> RsaPrivateKey<'a> {
     /// [`LineEnding`].
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-    pub fn to_pem(&self, line_ending: LineEnding) -> Result<Zeroizing<String>> {
+    pub fn to_pem(&self, line_ending: LineEnding) -> Result<SecretPemString> {
         RsaPrivateKeyDocument::try_from(self)?.to_pkcs1_pem(line_ending)
     }
 }
 
 impl<'a> Decodable<'a> for RsaPrivateKey<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
-        decoder.sequence(|decoder| {
-            let result = Self {
-                version: decoder.decode()?,
-                modulus: decoder.decode()?,
-                public_exponent: decoder.decode()?,
-                private_exponent: decoder.decode()?,
-                prime1: decoder.decode()?,
-                prime2: decoder.decode()?,
-                exponent1: decoder.decode()?,
-                exponent2: decoder.decode()?,
-                coefficient: decoder.decode()?,
-                other_prime_infos: decoder.decode()?,
-            };
-
-            // Ensure version is set correctly for two-prime vs multi-prime key.
-            if result.version.is_multi() != result.other_prime_infos.is_some() {
-                return Err(decoder.error(der::ErrorKind::Value { tag: Tag::Integer }));
-            }
-
-            Ok(result)
-        })
+        Self::decode_with(decoder, |decoder| decoder.decode())
     }
 }
 
@@ -166,25 +294,14 @@ impl<'a> TryFrom<&'a [u8]> for RsaPrivateKey<'a> {
     }
 }
 
-impl<'a> fmt::Debug for RsaPrivateKey<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("RsaPrivateKey")
-            .field("version", &self.version)
-            .field("modulus", &self.modulus)
-            .field("public_exponent", &self.public_exponent)
-            .field("private_exponent", &"...")
-            .field("prime1", &"...")
-            .field("prime2", &"...")
-            .field("exponent1", &"...")
-            .field("exponent2", &"...")
-            .field("coefficient", &"...")
-            .finish() // TODO: use `finish_non_exhaustive` when stable
-    }
-}
+der::debug_redacted!(RsaPrivateKey<'a> {
+    version, modulus, public_exponent;
+    redact private_exponent, prime1, prime2, exponent1, exponent2, coefficient, other_prime_infos
+});
 
 /// Placeholder struct for `OtherPrimeInfos` in the no-`alloc` case.
 #[cfg(not(feature = "alloc"))]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct OtherPrimeInfos<'a> {
     _lifetime: core::marker::PhantomData<&'a ()>,
@@ -195,7 +312,7 @@ impl<'a> Decodable<'a> for OtherPrimeInfos<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
         // Placeholder decoder that always returns an error.
         // Use `Tag::Integer` to signal an unsupported version.
-        Err(decoder.error(der::ErrorKind::Value { tag: Tag::Integer }))
+        Err(decoder.error(ErrorKind::Value { tag: Tag::Integer }))
     }
 }
 
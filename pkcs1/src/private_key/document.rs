@@ -1,6 +1,9 @@
 //! PKCS#1 RSA private key document.
 
-use crate::{DecodeRsaPrivateKey, EncodeRsaPrivateKey, Error, Result, RsaPrivateKey};
+use crate::{
+    storage::{storage, Storage},
+    DecodeRsaPrivateKey, EncodeRsaPrivateKey, Error, Result, RsaPrivateKey,
+};
 use alloc::vec::Vec;
 use core::{
     convert::{TryFrom, TryInto},
@@ -11,8 +14,7 @@ use zeroize::{Zeroize, Zeroizing};
 
 #[cfg(feature = "pem")]
 use {
-    crate::{pem, LineEnding},
-    alloc::string::String,
+    crate::{pem, LineEnding, SecretPemString},
     core::str::FromStr,
 };
 
@@ -24,9 +26,30 @@ use std::{fs, path::Path, str};
 /// This type provides storage for [`RsaPrivateKey`] encoded as ASN.1 DER
 /// with the invariant that the contained-document is "well-formed", i.e. it
 /// will parse successfully according to this crate's parsing rules.
+///
+/// When the `arc` feature is enabled, the document is stored behind a
+/// reference count, so clones are cheap and share the same allocation.
 #[derive(Clone)]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub struct RsaPrivateKeyDocument(Zeroizing<Vec<u8>>);
+pub struct RsaPrivateKeyDocument(Storage<Zeroizing<Vec<u8>>>);
+
+impl RsaPrivateKeyDocument {
+    /// Parse the [`RsaPrivateKey`] contained in this [`RsaPrivateKeyDocument`]
+    pub fn private_key(&self) -> RsaPrivateKey<'_> {
+        self.decode()
+    }
+
+    /// Decode this document, apply the provided function to the resulting
+    /// [`RsaPrivateKey`], and re-encode the result as a new document.
+    ///
+    /// The intermediate serialization is zeroized on drop.
+    pub fn map_key<'a, F>(&'a self, f: F) -> Result<Self>
+    where
+        F: FnOnce(RsaPrivateKey<'a>) -> RsaPrivateKey<'a>,
+    {
+        Ok(Self::from_msg(&f(self.decode()))?)
+    }
+}
 
 impl<'a> Document<'a> for RsaPrivateKeyDocument {
     type Message = RsaPrivateKey<'a>;
@@ -69,8 +92,8 @@ impl EncodeRsaPrivateKey for RsaPrivateKeyDocument {
 
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-    fn to_pkcs1_pem(&self, line_ending: LineEnding) -> Result<Zeroizing<String>> {
-        Ok(Zeroizing::new(self.to_pem(line_ending)?))
+    fn to_pkcs1_pem(&self, line_ending: LineEnding) -> Result<SecretPemString> {
+        Ok(SecretPemString::new(self.to_pem(line_ending)?))
     }
 
     #[cfg(feature = "std")]
@@ -89,7 +112,13 @@ impl EncodeRsaPrivateKey for RsaPrivateKeyDocument {
 
 impl AsRef<[u8]> for RsaPrivateKeyDocument {
     fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
+        self.0.as_slice()
+    }
+}
+
+impl<'a> From<&'a RsaPrivateKeyDocument> for RsaPrivateKey<'a> {
+    fn from(doc: &'a RsaPrivateKeyDocument) -> RsaPrivateKey<'a> {
+        doc.private_key()
     }
 }
 
@@ -105,6 +134,7 @@ impl TryFrom<&RsaPrivateKey<'_>> for RsaPrivateKeyDocument {
     type Error = Error;
 
     fn try_from(private_key: &RsaPrivateKey<'_>) -> Result<RsaPrivateKeyDocument> {
+        private_key.check_other_prime_infos()?;
         Ok(private_key.to_vec()?.try_into()?)
     }
 }
@@ -127,7 +157,7 @@ impl TryFrom<Vec<u8>> for RsaPrivateKeyDocument {
             return Err(err);
         }
 
-        Ok(Self(Zeroizing::new(bytes)))
+        Ok(Self(storage(Zeroizing::new(bytes))))
     }
 }
 
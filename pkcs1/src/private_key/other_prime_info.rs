@@ -1,6 +1,15 @@
 //! PKCS#1 OtherPrimeInfo support.
 
-use der::{asn1::UIntBytes, Decodable, Decoder, Encodable, Sequence};
+use crate::{Error, Result};
+use der::{
+    asn1::UIntBytes,
+    bigint::{
+        generic_array::GenericArray,
+        subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess},
+        ArrayEncoding, UInt,
+    },
+    Decodable, Decoder, Encodable, Sequence,
+};
 
 /// PKCS#1 OtherPrimeInfo as defined in [RFC 8017 Appendix 1.2].
 ///
@@ -28,6 +37,100 @@ pub struct OtherPrimeInfo<'a> {
     pub coefficient: UIntBytes<'a>,
 }
 
+der::debug_redacted!(OtherPrimeInfo<'a> {
+    ;
+    redact prime, exponent, coefficient
+});
+
+impl<'a> OtherPrimeInfo<'a> {
+    /// Get the prime factor `r_i` of `n`.
+    pub fn prime(&self) -> UIntBytes<'a> {
+        self.prime
+    }
+
+    /// Get the exponent `d_i = d mod (r_i - 1)`.
+    pub fn exponent(&self) -> UIntBytes<'a> {
+        self.exponent
+    }
+
+    /// Get the CRT coefficient `t_i = (r_1 * r_2 * ... * r_(i-1))^(-1) mod r_i`.
+    pub fn coefficient(&self) -> UIntBytes<'a> {
+        self.coefficient
+    }
+
+    /// Validate this prime info against the RSA modulus `n` and all of the
+    /// primes `r_1`, ..., `r_(i-1)` which precede it in the key (including
+    /// `prime1` and `prime2`).
+    ///
+    /// Checks that:
+    /// - `r_i` is a factor of `n`
+    /// - `d_i` and `t_i` are both less than `r_i`
+    /// - `t_i * (r_1 * r_2 * ... * r_(i-1)) = 1 mod r_i`
+    ///
+    /// `LIMBS` must be large enough to hold `n`. Returns [`Error::Crypto`] if
+    /// any of the above checks fail.
+    ///
+    /// The arithmetic on `d_i`, `t_i` and the primes is constant time, with
+    /// two exceptions: reducing `n` modulo `r_i` takes time which depends on
+    /// the bit length of `r_i`, and the overall time depends on the encoded
+    /// lengths of the integers.
+    pub fn validate_against<const LIMBS: usize>(
+        &self,
+        n: &UInt<LIMBS>,
+        previous_primes: &[UIntBytes<'_>],
+    ) -> Result<()>
+    where
+        UInt<LIMBS>: ArrayEncoding,
+    {
+        let prime = to_uint::<LIMBS>(self.prime)?;
+
+        if prime <= UInt::ONE || !bool::from(n.reduce(&prime).unwrap_or(UInt::ONE).is_zero()) {
+            return Err(Error::Crypto);
+        }
+
+        let exponent = to_uint::<LIMBS>(self.exponent)?;
+        let coefficient = to_uint::<LIMBS>(self.coefficient)?;
+
+        let valid = exponent.ct_lt(&prime)
+            & coefficient.ct_lt(&prime)
+            & self.is_coefficient_valid(&prime, previous_primes);
+
+        if bool::from(valid) {
+            Ok(())
+        } else {
+            Err(Error::Crypto)
+        }
+    }
+
+    /// Check the CRT coefficient `t_i` is the inverse of the product of the
+    /// given preceding primes modulo `r_i`.
+    ///
+    /// `LIMBS` must be large enough to hold `r_i`.
+    pub(crate) fn check_coefficient<const LIMBS: usize>(
+        &self,
+        previous_primes: &[UIntBytes<'_>],
+    ) -> Result<bool>
+    where
+        UInt<LIMBS>: ArrayEncoding,
+    {
+        let prime = to_uint::<LIMBS>(self.prime)?;
+        Ok(self.is_coefficient_valid(&prime, previous_primes).into())
+    }
+
+    /// Compute `t_i * (r_1 * r_2 * ... * r_(i-1)) = 1 mod r_i`.
+    fn is_coefficient_valid<const LIMBS: usize>(
+        &self,
+        prime: &UInt<LIMBS>,
+        previous_primes: &[UIntBytes<'_>],
+    ) -> Choice {
+        let product = previous_primes
+            .iter()
+            .fold(UInt::ONE, |acc, r| mul_mod(&acc, r.as_bytes(), prime));
+
+        mul_mod(&product, self.coefficient.as_bytes(), prime).ct_eq(&UInt::ONE)
+    }
+}
+
 impl<'a> Decodable<'a> for OtherPrimeInfo<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
         decoder.sequence(|decoder| {
@@ -48,3 +151,39 @@ impl<'a> Sequence<'a> for OtherPrimeInfo<'a> {
         f(&[&self.prime, &self.exponent, &self.coefficient])
     }
 }
+
+/// Convert the given [`UIntBytes`] into a [`UInt`], returning an error if it
+/// doesn't fit.
+fn to_uint<const LIMBS: usize>(bytes: UIntBytes<'_>) -> Result<UInt<LIMBS>>
+where
+    UInt<LIMBS>: ArrayEncoding,
+{
+    let mut array = GenericArray::default();
+    let offset = array
+        .len()
+        .checked_sub(bytes.as_bytes().len())
+        .ok_or(Error::Crypto)?;
+
+    array[offset..].copy_from_slice(bytes.as_bytes());
+    Ok(UInt::from_be_byte_array(array))
+}
+
+/// Compute `a * b mod m` where `a < m` and `b` is a big endian integer of
+/// arbitrary size, using double-and-add.
+///
+/// Runs in constant time with respect to the values of `a`, `b` and `m`, but
+/// not the length of `b`.
+fn mul_mod<const LIMBS: usize>(a: &UInt<LIMBS>, b: &[u8], m: &UInt<LIMBS>) -> UInt<LIMBS> {
+    let mut result = UInt::ZERO;
+
+    for byte in b {
+        for i in (0..8).rev() {
+            result = result.add_mod(&result, m);
+
+            let sum = result.add_mod(a, m);
+            result = UInt::conditional_select(&result, &sum, Choice::from((byte >> i) & 1));
+        }
+    }
+
+    result
+}
@@ -3,9 +3,9 @@
 #[cfg(feature = "alloc")]
 pub(crate) mod document;
 
-use crate::{Error, Result};
+use crate::{Error, Result, RsaKeySummary};
 use core::convert::TryFrom;
-use der::{asn1::UIntBytes, Decodable, Decoder, Encodable, Sequence};
+use der::{asn1::UIntBytes, Decodable, Decoder, Encodable, Header, Sequence};
 
 #[cfg(feature = "alloc")]
 use crate::RsaPublicKeyDocument;
@@ -16,6 +16,12 @@ use {
     alloc::string::String,
 };
 
+#[cfg(feature = "spki")]
+use {
+    der::asn1::{Any, Null, ObjectIdentifier},
+    spki::DecodeSubjectPublicKey,
+};
+
 /// Type label for PEM-encoded public keys.
 #[cfg(feature = "pem")]
 pub(crate) const PEM_TYPE_LABEL: &str = "RSA PUBLIC KEY";
@@ -42,6 +48,33 @@ pub struct RsaPublicKey<'a> {
 }
 
 impl<'a> RsaPublicKey<'a> {
+    /// Get the maximum length of the DER encoding of an [`RsaPublicKey`]
+    /// with a modulus of `BITS` bits.
+    ///
+    /// This allows sizing a buffer at compile time for the largest key an
+    /// application supports:
+    ///
+    /// ```
+    /// use pkcs1::RsaPublicKey;
+    ///
+    /// // Large enough for any key with a modulus of up to 4096 bits
+    /// const MAX_KEY_LEN: usize = RsaPublicKey::max_der_len::<4096>();
+    ///
+    /// let mut buf = [0u8; MAX_KEY_LEN];
+    /// # let _ = &mut buf;
+    /// ```
+    ///
+    /// The public exponent is only assumed to be no larger than the modulus.
+    pub const fn max_der_len<const BITS: usize>() -> usize {
+        Header::tlv_len(2 * uint_tlv_len(BITS))
+    }
+
+    /// Get a summary of the metadata of this key: its modulus size and
+    /// public exponent.
+    pub fn summary(&self) -> RsaKeySummary {
+        RsaKeySummary::new(self.modulus, self.public_exponent, None)
+    }
+
     /// Encode this [`RsaPublicKey`] as ASN.1 DER.
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -82,6 +115,25 @@ impl<'a> Sequence<'a> for RsaPublicKey<'a> {
     }
 }
 
+#[cfg(feature = "spki")]
+#[cfg_attr(docsrs, doc(cfg(feature = "spki")))]
+impl<'a> DecodeSubjectPublicKey<'a> for RsaPublicKey<'a> {
+    /// `rsaEncryption` OID.
+    const ALGORITHM_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+
+    /// The algorithm `parameters` must be absent or `NULL`.
+    fn decode_subject_public_key(
+        parameters: Option<Any<'a>>,
+        subject_public_key: &'a [u8],
+    ) -> der::Result<Self> {
+        if let Some(parameters) = parameters {
+            Null::try_from(parameters)?;
+        }
+
+        Self::from_der(subject_public_key)
+    }
+}
+
 impl<'a> TryFrom<&'a [u8]> for RsaPublicKey<'a> {
     type Error = Error;
 
@@ -89,3 +141,10 @@ impl<'a> TryFrom<&'a [u8]> for RsaPublicKey<'a> {
         Ok(Self::from_der(bytes)?)
     }
 }
+
+/// Get the maximum length of the DER encoding of an unsigned `INTEGER` of
+/// at most the given number of bits, including a leading zero byte if its
+/// high bit is set.
+pub(crate) const fn uint_tlv_len(bits: usize) -> usize {
+    Header::tlv_len(bits / 8 + 1)
+}
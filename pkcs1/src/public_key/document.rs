@@ -1,6 +1,10 @@
 //! PKCS#1 RSA public key document.
 
-use crate::{error, DecodeRsaPublicKey, EncodeRsaPublicKey, Error, Result, RsaPublicKey};
+use crate::{
+    error,
+    storage::{storage, Storage},
+    DecodeRsaPublicKey, EncodeRsaPublicKey, Error, Result, RsaPublicKey,
+};
 use alloc::vec::Vec;
 use core::{
     convert::{TryFrom, TryInto},
@@ -23,9 +27,28 @@ use std::path::Path;
 /// This type provides storage for [`RsaPublicKey`] encoded as ASN.1
 /// DER with the invariant that the contained-document is "well-formed", i.e.
 /// it will parse successfully according to this crate's parsing rules.
+///
+/// When the `arc` feature is enabled, the document is stored behind a
+/// reference count, so clones are cheap and share the same allocation.
 #[derive(Clone)]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub struct RsaPublicKeyDocument(Vec<u8>);
+pub struct RsaPublicKeyDocument(Storage<Vec<u8>>);
+
+impl RsaPublicKeyDocument {
+    /// Parse the [`RsaPublicKey`] contained in this [`RsaPublicKeyDocument`]
+    pub fn public_key(&self) -> RsaPublicKey<'_> {
+        self.decode()
+    }
+
+    /// Decode this document, apply the provided function to the resulting
+    /// [`RsaPublicKey`], and re-encode the result as a new document.
+    pub fn map_key<'a, F>(&'a self, f: F) -> Result<Self>
+    where
+        F: FnOnce(RsaPublicKey<'a>) -> RsaPublicKey<'a>,
+    {
+        Ok(Self::from_msg(&f(self.decode()))?)
+    }
+}
 
 impl<'a> Document<'a> for RsaPublicKeyDocument {
     type Message = RsaPublicKey<'a>;
@@ -87,7 +110,13 @@ impl EncodeRsaPublicKey for RsaPublicKeyDocument {
 
 impl AsRef<[u8]> for RsaPublicKeyDocument {
     fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
+        &self.0[..]
+    }
+}
+
+impl<'a> From<&'a RsaPublicKeyDocument> for RsaPublicKey<'a> {
+    fn from(doc: &'a RsaPublicKeyDocument) -> RsaPublicKey<'a> {
+        doc.public_key()
     }
 }
 
@@ -121,7 +150,7 @@ impl TryFrom<Vec<u8>> for RsaPublicKeyDocument {
     fn try_from(bytes: Vec<u8>) -> der::Result<Self> {
         // Ensure document is well-formed
         RsaPublicKey::from_der(bytes.as_slice())?;
-        Ok(Self(bytes))
+        Ok(Self(storage(bytes)))
     }
 }
 
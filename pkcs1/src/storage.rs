@@ -0,0 +1,21 @@
+//! Storage for DER-encoded documents.
+
+/// Storage for a DER-encoded document.
+#[cfg(not(feature = "arc"))]
+pub(crate) type Storage<T> = T;
+
+/// Storage for a DER-encoded document, shared between clones.
+#[cfg(feature = "arc")]
+pub(crate) type Storage<T> = alloc::sync::Arc<T>;
+
+/// Move a DER-encoded document into [`Storage`].
+#[cfg(not(feature = "arc"))]
+pub(crate) fn storage<T>(bytes: T) -> Storage<T> {
+    bytes
+}
+
+/// Move a DER-encoded document into [`Storage`].
+#[cfg(feature = "arc")]
+pub(crate) fn storage<T>(bytes: T) -> Storage<T> {
+    alloc::sync::Arc::new(bytes)
+}
@@ -0,0 +1,74 @@
+//! Non-secret RSA key metadata.
+
+use core::fmt;
+use der::asn1::UIntBytes;
+
+/// Summary of the non-secret metadata of an RSA key, intended for logging
+/// and display purposes.
+///
+/// It contains no secret material, even when obtained from an
+/// [`RsaPrivateKey`][`crate::RsaPrivateKey`]. Its [`fmt::Display`] impl
+/// prints e.g. `RSA-2048, e=65537, 2-prime` for a private key, or
+/// `RSA-2048, e=65537` for a public key.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RsaKeySummary {
+    /// Size of the modulus in bits.
+    pub modulus_bits: usize,
+
+    /// Public exponent, or `None` if it doesn't fit in a `u64`.
+    pub public_exponent: Option<u64>,
+
+    /// Number of prime factors of the modulus, or `None` for a public key.
+    pub primes: Option<usize>,
+}
+
+impl RsaKeySummary {
+    /// Create a new [`RsaKeySummary`] from the modulus, public exponent and
+    /// number of primes of a key.
+    pub(crate) fn new(
+        modulus: UIntBytes<'_>,
+        public_exponent: UIntBytes<'_>,
+        primes: Option<usize>,
+    ) -> Self {
+        let public_exponent = public_exponent.as_bytes();
+
+        Self {
+            modulus_bits: bit_len(modulus.as_bytes()),
+            public_exponent: if public_exponent.len() <= 8 {
+                Some(
+                    public_exponent
+                        .iter()
+                        .fold(0, |acc, &byte| acc << 8 | u64::from(byte)),
+                )
+            } else {
+                None
+            },
+            primes,
+        }
+    }
+}
+
+impl fmt::Display for RsaKeySummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RSA-{}, ", self.modulus_bits)?;
+
+        match self.public_exponent {
+            Some(e) => write!(f, "e={}", e)?,
+            None => f.write_str("e>=2^64")?,
+        }
+
+        if let Some(primes) = self.primes {
+            write!(f, ", {}-prime", primes)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Get the length in bits of a big endian integer without leading zeroes.
+fn bit_len(bytes: &[u8]) -> usize {
+    match bytes.first() {
+        Some(&msb) => bytes.len() * 8 - msb.leading_zeros() as usize,
+        None => 0,
+    }
+}
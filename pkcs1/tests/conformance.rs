@@ -0,0 +1,43 @@
+//! Conformance tests using example keys and Wycheproof-style test vectors
+
+use der::dev::{run, TestVector};
+use pkcs1::{RsaPrivateKey, RsaPublicKey};
+
+const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/examples");
+
+#[test]
+fn private_key_examples() {
+    // Multi-prime RSA keys are unsupported when the alloc feature is disabled
+    let vectors = TestVector::load_examples(EXAMPLES_DIR, |name| {
+        name.contains("-priv") && (cfg!(feature = "alloc") || !name.contains("3prime"))
+    })
+    .unwrap();
+    assert!(!vectors.is_empty());
+    run::<RsaPrivateKey<'_>>(&vectors).assert_success();
+}
+
+#[test]
+fn public_key_examples() {
+    let vectors = TestVector::load_examples(EXAMPLES_DIR, |name| name.contains("-pub")).unwrap();
+    assert!(!vectors.is_empty());
+    run::<RsaPublicKey<'_>>(&vectors).assert_success();
+}
+
+#[test]
+fn public_key_wycheproof() {
+    let path = format!("{}/rsa-pub-wycheproof.json", EXAMPLES_DIR);
+    let vectors = TestVector::load_wycheproof(path, "der").unwrap();
+    assert_eq!(vectors.len(), 7);
+    run::<RsaPublicKey<'_>>(&vectors).assert_success();
+}
+
+#[test]
+fn samples() {
+    let vectors = TestVector::load_samples(|name| name == "rsa2048-priv");
+    assert_eq!(vectors.len(), 1);
+    run::<RsaPrivateKey<'_>>(&vectors).assert_success();
+
+    let vectors = TestVector::load_samples(|name| name == "rsa2048-pub");
+    assert_eq!(vectors.len(), 1);
+    run::<RsaPublicKey<'_>>(&vectors).assert_success();
+}
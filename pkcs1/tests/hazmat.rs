@@ -0,0 +1,94 @@
+//! PKCS#1 `hazmat` tests
+
+#![cfg(feature = "hazmat")]
+
+use pkcs1::{
+    der::{Decodable, Encodable, ErrorKind, Tag},
+    hazmat::{RawRsaPrivateKey, RawRsaPublicKey},
+    RsaPrivateKey, RsaPublicKey,
+};
+use zeroize::Zeroize;
+
+/// RSA-2048 PKCS#1 private key encoded as ASN.1 DER
+const RSA_2048_PRIV_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
+
+/// RSA-2048 PKCS#1 private key with 3 primes encoded as ASN.1 DER
+const RSA_2048_MULTI_PRIME_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv-3prime.der");
+
+/// RSA-2048 PKCS#1 public key encoded as ASN.1 DER
+const RSA_2048_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pub.der");
+
+#[test]
+fn private_key_round_trip() {
+    for example in &[RSA_2048_PRIV_DER_EXAMPLE, RSA_2048_MULTI_PRIME_DER_EXAMPLE] {
+        let key = RsaPrivateKey::from_der(example).unwrap();
+        let raw = RawRsaPrivateKey::from(&key);
+        assert_eq!(raw.to_vec().unwrap(), *example);
+        assert_eq!(RawRsaPrivateKey::from_der(example).unwrap(), raw);
+    }
+}
+
+#[test]
+fn private_key_zeroize() {
+    let key = RsaPrivateKey::from_der(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    let mut raw = RawRsaPrivateKey::from(&key);
+    raw.zeroize();
+
+    assert!(raw.private_exponent.is_empty());
+    assert!(raw.prime1.is_empty() && raw.prime2.is_empty());
+    assert!(raw.exponent1.is_empty() && raw.exponent2.is_empty());
+    assert!(raw.coefficient.is_empty());
+    assert!(raw.other_prime_infos.is_none());
+    assert_eq!(raw.modulus[1..], *key.modulus.as_bytes());
+}
+
+#[test]
+fn private_key_wrong_coefficient() {
+    let key = RsaPrivateKey::from_der(RSA_2048_PRIV_DER_EXAMPLE).unwrap();
+    let mut raw = RawRsaPrivateKey::from(&key);
+    *raw.coefficient.last_mut().unwrap() ^= 1;
+
+    let der = raw.to_vec().unwrap();
+    let tampered = RsaPrivateKey::from_der(&der).unwrap();
+    assert_eq!(tampered.modulus, key.modulus);
+    assert_ne!(tampered.coefficient, key.coefficient);
+}
+
+#[test]
+fn private_key_non_minimal_integer() {
+    let key = RsaPrivateKey::from_der(RSA_2048_PRIV_DER_EXAMPLE).unwrap();
+    let mut raw = RawRsaPrivateKey::from(&key);
+    raw.public_exponent.insert(0, 0);
+
+    let der = raw.to_vec().unwrap();
+    let err = RsaPrivateKey::from_der(&der).err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::Noncanonical { tag: Tag::Integer });
+
+    let (lenient, normalized) = RsaPrivateKey::from_der_lenient(&der).unwrap();
+    assert!(normalized);
+    assert_eq!(lenient.public_exponent, key.public_exponent);
+}
+
+#[test]
+fn private_key_inconsistent_version() {
+    let key = RsaPrivateKey::from_der(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    let mut raw = RawRsaPrivateKey::from(&key);
+    raw.version = vec![0];
+
+    let der = raw.to_vec().unwrap();
+    assert!(RsaPrivateKey::from_der(&der).is_err());
+    assert_eq!(RawRsaPrivateKey::from_der(&der).unwrap(), raw);
+}
+
+#[test]
+fn public_key_round_trip() {
+    let key = RsaPublicKey::from_der(RSA_2048_PUB_DER_EXAMPLE).unwrap();
+    let mut raw = RawRsaPublicKey::from(&key);
+    assert_eq!(raw.to_vec().unwrap(), RSA_2048_PUB_DER_EXAMPLE);
+
+    // Negative modulus
+    raw.modulus.remove(0);
+    let der = raw.to_vec().unwrap();
+    assert!(RsaPublicKey::from_der(&der).is_err());
+    assert_eq!(RawRsaPublicKey::from_der(&der).unwrap(), raw);
+}
@@ -0,0 +1,42 @@
+//! `std::io::Error` conversion tests
+
+#![cfg(feature = "std")]
+
+use pkcs1::{Error, ResultExt};
+use std::io;
+
+#[test]
+fn into_io_error() {
+    let err = io::Error::from(Error::FileNotFound);
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+    let err = io::Error::from(Error::PermissionDenied);
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+    let err = io::Error::from(Error::Crypto);
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), Error::Crypto.to_string());
+}
+
+#[test]
+fn with_path() {
+    let result: pkcs1::Result<()> = Err(Error::Version);
+    let err = result.with_path("keys/rsa.der").err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "keys/rsa.der: PKCS#1 version error");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn with_path_missing_file() {
+    use pkcs1::{DecodeRsaPrivateKey, RsaPrivateKeyDocument};
+
+    let path = "tests/examples/does-not-exist.der";
+    let err = RsaPrivateKeyDocument::read_pkcs1_der_file(path)
+        .with_path(path)
+        .err()
+        .unwrap();
+
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    assert!(err.to_string().starts_with(path));
+}
@@ -0,0 +1,95 @@
+//! PKCS#1 `pkcs11` tests
+
+#![cfg(feature = "pkcs11")]
+
+use pkcs1::{
+    der::Decodable,
+    pkcs11::{self, Attribute, AttributeValue},
+    Error, RsaPrivateKey, RsaPublicKey,
+};
+
+/// RSA-2048 PKCS#1 private key encoded as ASN.1 DER
+const RSA_2048_PRIV_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
+
+/// RSA-2048 PKCS#1 private key with 3 primes encoded as ASN.1 DER
+const RSA_2048_MULTI_PRIME_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv-3prime.der");
+
+/// RSA-2048 PKCS#1 public key encoded as ASN.1 DER
+const RSA_2048_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pub.der");
+
+/// Get the bytes of the attribute with the given type.
+fn bytes(template: &[Attribute], attribute_type: u64) -> &[u8] {
+    let attribute = template
+        .iter()
+        .find(|attribute| attribute.attribute_type == attribute_type)
+        .unwrap();
+
+    match &attribute.value {
+        AttributeValue::Bytes(bytes) => bytes,
+        other => panic!("unexpected value: {:?}", other),
+    }
+}
+
+#[test]
+fn private_key_template() {
+    let key = RsaPrivateKey::from_der(RSA_2048_PRIV_DER_EXAMPLE).unwrap();
+    let template = pkcs11::private_key_template(&key).unwrap();
+    assert_eq!(template.len(), 10);
+    assert_eq!(
+        template[..2],
+        [
+            Attribute::ulong(pkcs11::CKA_CLASS, pkcs11::CKO_PRIVATE_KEY),
+            Attribute::ulong(pkcs11::CKA_KEY_TYPE, pkcs11::CKK_RSA),
+        ]
+    );
+
+    let modulus = bytes(&template, pkcs11::CKA_MODULUS);
+    assert_eq!(modulus.len(), 256);
+    assert_eq!(modulus, key.modulus.as_bytes());
+    assert_eq!(bytes(&template, pkcs11::CKA_PUBLIC_EXPONENT), [1, 0, 1]);
+
+    for &(attribute_type, value) in &[
+        (pkcs11::CKA_PRIVATE_EXPONENT, key.private_exponent),
+        (pkcs11::CKA_PRIME_1, key.prime1),
+        (pkcs11::CKA_PRIME_2, key.prime2),
+        (pkcs11::CKA_EXPONENT_1, key.exponent1),
+        (pkcs11::CKA_EXPONENT_2, key.exponent2),
+        (pkcs11::CKA_COEFFICIENT, key.coefficient),
+    ] {
+        // Big integers have no leading zero byte
+        assert_eq!(bytes(&template, attribute_type), value.as_bytes());
+        assert_ne!(bytes(&template, attribute_type)[0], 0);
+    }
+
+    // Secret values are redacted
+    let debug = format!("{:?}", template);
+    assert!(debug.contains("Bytes([REDACTED])"));
+}
+
+#[test]
+fn public_key_template() {
+    let key = RsaPublicKey::from_der(RSA_2048_PUB_DER_EXAMPLE).unwrap();
+    let template = pkcs11::public_key_template(&key);
+    assert_eq!(template.len(), 4);
+    assert_eq!(
+        template[0],
+        Attribute::ulong(pkcs11::CKA_CLASS, pkcs11::CKO_PUBLIC_KEY)
+    );
+    assert_eq!(
+        bytes(&template, pkcs11::CKA_MODULUS),
+        key.modulus.as_bytes()
+    );
+    assert_eq!(bytes(&template, pkcs11::CKA_PUBLIC_EXPONENT), [1, 0, 1]);
+
+    let private_key = RsaPrivateKey::from_der(RSA_2048_PRIV_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        pkcs11::public_key_template(&private_key.public_key()),
+        template
+    );
+}
+
+#[test]
+fn reject_multi_prime_key() {
+    let key = RsaPrivateKey::from_der(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    assert_eq!(pkcs11::private_key_template(&key), Err(Error::Version));
+}
@@ -2,10 +2,13 @@
 
 use core::convert::TryFrom;
 use hex_literal::hex;
-use pkcs1::{RsaPrivateKey, Version};
+use pkcs1::{der::Encodable, RsaKeySummary, RsaPrivateKey, UIntBytes, Version};
 
-#[cfg(feature = "pem")]
-use pkcs1::{der::Document, RsaPrivateKeyDocument};
+#[cfg(feature = "alloc")]
+use pkcs1::RsaPrivateKeyDocument;
+
+#[cfg(any(feature = "arc", feature = "pem"))]
+use pkcs1::der::Document;
 
 /// RSA-2048 PKCS#1 private key encoded as ASN.1 DER.
 ///
@@ -16,6 +19,9 @@ const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
 /// RSA-4096 PKCS#1 private key encoded as ASN.1 DER
 const RSA_4096_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa4096-priv.der");
 
+/// RSA-8192 PKCS#1 private key encoded as ASN.1 DER
+const RSA_8192_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa8192-priv.der");
+
 /// RSA-2048 PKCS#1 private key with 3 primes encoded as ASN.1 DER
 const RSA_2048_MULTI_PRIME_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv-3prime.der");
 
@@ -27,6 +33,10 @@ const RSA_2048_PEM_EXAMPLE: &str = include_str!("examples/rsa2048-priv.pem");
 #[cfg(feature = "pem")]
 const RSA_4096_PEM_EXAMPLE: &str = include_str!("examples/rsa4096-priv.pem");
 
+/// RSA-8192 PKCS#1 private key encoded as PEM
+#[cfg(feature = "pem")]
+const RSA_8192_PEM_EXAMPLE: &str = include_str!("examples/rsa8192-priv.pem");
+
 #[test]
 fn decode_rsa2048_der() {
     let key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
@@ -63,6 +73,49 @@ fn decode_rsa4096_der() {
     assert!(key.other_prime_infos.is_none());
 }
 
+#[test]
+fn decode_rsa8192_der() {
+    let key = RsaPrivateKey::try_from(RSA_8192_DER_EXAMPLE).unwrap();
+    assert_eq!(key.version, Version::TwoPrime);
+    assert_eq!(key.modulus.as_bytes().len(), 1024);
+    assert_eq!(key.public_exponent.as_bytes(), hex!("010001"));
+    assert_eq!(key.prime1.as_bytes().len(), 512);
+    assert_eq!(key.prime2.as_bytes().len(), 512);
+    assert!(key.other_prime_infos.is_none());
+
+    let mut buf = [0u8; RsaPrivateKey::max_der_len::<8192>()];
+    assert_eq!(key.encode_to_slice(&mut buf).unwrap(), RSA_8192_DER_EXAMPLE);
+}
+
+#[test]
+fn max_der_len() {
+    assert!(RSA_2048_DER_EXAMPLE.len() <= RsaPrivateKey::max_der_len::<2048>());
+    assert!(RSA_4096_DER_EXAMPLE.len() <= RsaPrivateKey::max_der_len::<4096>());
+    assert!(RSA_8192_DER_EXAMPLE.len() <= RsaPrivateKey::max_der_len::<8192>());
+
+    // Upper bound: every component has its high bit set and the public
+    // exponent is as large as the modulus
+    let modulus = [0xFF; 256];
+    let prime = [0xFF; 128];
+    let key = RsaPrivateKey {
+        version: Version::TwoPrime,
+        modulus: UIntBytes::new(&modulus).unwrap(),
+        public_exponent: UIntBytes::new(&modulus).unwrap(),
+        private_exponent: UIntBytes::new(&modulus).unwrap(),
+        prime1: UIntBytes::new(&prime).unwrap(),
+        prime2: UIntBytes::new(&prime).unwrap(),
+        exponent1: UIntBytes::new(&prime).unwrap(),
+        exponent2: UIntBytes::new(&prime).unwrap(),
+        coefficient: UIntBytes::new(&prime).unwrap(),
+        other_prime_infos: None,
+    };
+
+    assert_eq!(
+        usize::try_from(key.encoded_len().unwrap()).unwrap(),
+        RsaPrivateKey::max_der_len::<2048>()
+    );
+}
+
 #[cfg(not(feature = "alloc"))]
 #[test]
 fn decode_rsa2048_multi_prime_der() {
@@ -94,6 +147,81 @@ fn decode_rsa2048_multi_prime_der() {
     assert_eq!(other_prime_infos[0].coefficient.as_bytes(), hex!("39EA226CABFB317E41A5593B9168D1A0124993B45D9CD14A22BD1557CDCB43D28024AC26ED2C8530B53E9B93A878F428807C5282EBB811399F913017CDF2149013D80CDF73F609D6C692475EB7A123D0E93E6A60FC"));
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn validate_rsa2048_multi_prime_der() {
+    use pkcs1::der::bigint::U2048;
+
+    let key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    let n = U2048::from_be_slice(key.modulus.as_bytes());
+    let other_prime_info = &key.other_prime_infos.as_ref().unwrap()[0];
+
+    assert_eq!(other_prime_info.prime(), other_prime_info.prime);
+    assert!(other_prime_info
+        .validate_against(&n, &[key.prime1, key.prime2])
+        .is_ok());
+
+    // Omitting `prime2` yields an incorrect CRT coefficient
+    assert_eq!(
+        other_prime_info.validate_against(&n, &[key.prime1]),
+        Err(pkcs1::Error::Crypto)
+    );
+}
+
+#[test]
+fn debug_redacts_secrets() {
+    let key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let debug = format!("{:?}", key);
+
+    assert!(debug.contains(&format!("modulus: {:?}", key.modulus)));
+    assert!(debug.contains("private_exponent: [REDACTED]"));
+    assert!(debug.contains("other_prime_infos: [REDACTED]"));
+
+    for secret in &[
+        key.private_exponent,
+        key.prime1,
+        key.prime2,
+        key.exponent1,
+        key.exponent2,
+        key.coefficient,
+    ] {
+        assert!(!debug.contains(&format!("{:?}", secret)));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn debug_redacts_other_prime_infos() {
+    let key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    let other_prime_info = &key.other_prime_infos.as_ref().unwrap()[0];
+
+    assert_eq!(
+        format!("{:?}", other_prime_info),
+        "OtherPrimeInfo { prime: [REDACTED], exponent: [REDACTED], coefficient: [REDACTED] }"
+    );
+}
+
+#[test]
+fn summary() {
+    let key = RsaPrivateKey::try_from(RSA_4096_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        key.summary(),
+        RsaKeySummary {
+            modulus_bits: 4096,
+            public_exponent: Some(65537),
+            primes: Some(2)
+        }
+    );
+    assert_eq!(key.summary().to_string(), "RSA-4096, e=65537, 2-prime");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn summary_multi_prime() {
+    let key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    assert_eq!(key.summary().to_string(), "RSA-2048, e=65537, 3-prime");
+}
+
 #[cfg(feature = "pem")]
 #[test]
 fn decode_rsa_2048_pem() {
@@ -105,6 +233,13 @@ fn decode_rsa_2048_pem() {
     assert_eq!(pkcs1_doc.decode().modulus.as_bytes(), pk.modulus.as_bytes());
 }
 
+#[cfg(feature = "pem")]
+#[test]
+fn decode_rsa_8192_pem() {
+    let pkcs1_doc: RsaPrivateKeyDocument = RSA_8192_PEM_EXAMPLE.parse().unwrap();
+    assert_eq!(pkcs1_doc.as_ref(), RSA_8192_DER_EXAMPLE);
+}
+
 #[cfg(feature = "pem")]
 #[test]
 fn decode_rsa_4096_pem() {
@@ -126,3 +261,120 @@ fn private_key_to_public_key() {
     assert_eq!(public_key.modulus.as_bytes(), hex!("B6C42C515F10A6AAF282C63EDBE24243A170F3FA2633BD4833637F47CA4F6F36E03A5D29EFC3191AC80F390D874B39E30F414FCEC1FCA0ED81E547EDC2CD382C76F61C9018973DB9FA537972A7C701F6B77E0982DFC15FC01927EE5E7CD94B4F599FF07013A7C8281BDF22DCBC9AD7CABB7C4311C982F58EDB7213AD4558B332266D743AED8192D1884CADB8B14739A8DADA66DC970806D9C7AC450CB13D0D7C575FB198534FC61BC41BC0F0574E0E0130C7BBBFBDFDC9F6A6E2E3E2AFF1CBEAC89BA57884528D55CFB08327A1E8C89F4E003CF2888E933241D9D695BCBBACDC90B44E3E095FA37058EA25B13F5E295CBEAC6DE838AB8C50AF61E298975B872F"));
     assert_eq!(public_key.public_exponent.as_bytes(), hex!("010001"));
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn borrow_rsa2048_private_key_document() {
+    let doc = RsaPrivateKeyDocument::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+
+    assert_eq!(doc.private_key().modulus, key.modulus);
+    assert_eq!(
+        RsaPrivateKey::from(&doc).private_exponent,
+        key.private_exponent
+    );
+}
+
+#[cfg(feature = "pem")]
+#[test]
+fn map_rsa2048_private_key_document() {
+    let doc = RsaPrivateKeyDocument::from_der(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        doc.map_key(|key| key).unwrap().as_der(),
+        RSA_2048_DER_EXAMPLE
+    );
+
+    let exponent = [0x03];
+    let mapped = doc
+        .map_key(|mut key| {
+            key.public_exponent = pkcs1::UIntBytes::new(&exponent).unwrap();
+            key
+        })
+        .unwrap();
+
+    let key = mapped.decode();
+    assert_eq!(key.public_exponent.as_bytes(), &[0x03]);
+    assert_eq!(key.modulus.as_bytes(), doc.decode().modulus.as_bytes());
+}
+
+#[test]
+fn decode_lenient_non_minimal_integer() {
+    // Toy key where `privateExponent` is encoded with a redundant leading zero
+    const NON_MINIMAL: &[u8] =
+        &hex!("301E020100020300C35B0201030202000502010702010B020101020101020101");
+    const CANONICAL: &[u8] =
+        &hex!("301D020100020300C35B02010302010502010702010B020101020101020101");
+
+    assert!(RsaPrivateKey::try_from(NON_MINIMAL).is_err());
+
+    let (key, normalized) = RsaPrivateKey::from_der_lenient(NON_MINIMAL).unwrap();
+    assert!(normalized);
+    assert_eq!(key.private_exponent.as_bytes(), &[0x05]);
+
+    let mut buf = [0u8; 31];
+    assert_eq!(key.encode_to_slice(&mut buf).unwrap(), CANONICAL);
+
+    let (key, normalized) = RsaPrivateKey::from_der_lenient(CANONICAL).unwrap();
+    assert!(!normalized);
+    assert_eq!(key.private_exponent.as_bytes(), &[0x05]);
+}
+
+#[cfg(feature = "arc")]
+#[test]
+fn clone_shared_rsa2048_private_key_document() {
+    use pkcs1::DecodeRsaPrivateKey;
+
+    let doc = RsaPrivateKeyDocument::from_pkcs1_der(RSA_2048_DER_EXAMPLE).unwrap();
+    let shared = std::thread::spawn({
+        let doc = doc.clone();
+        move || doc
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(doc.as_der().as_ptr(), shared.as_der().as_ptr());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn reject_empty_other_prime_infos() {
+    let mut key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    key.other_prime_infos = Some(Vec::new());
+
+    assert_eq!(
+        RsaPrivateKeyDocument::try_from(&key).err(),
+        Some(pkcs1::Error::OtherPrimeInfosEmpty)
+    );
+
+    let der = key.to_vec().unwrap();
+    assert!(RsaPrivateKey::try_from(der.as_slice()).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn reject_version_mismatch() {
+    let mut key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    key.version = Version::TwoPrime;
+
+    assert_eq!(
+        RsaPrivateKeyDocument::try_from(&key).err(),
+        Some(pkcs1::Error::Version)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn check_prime_order() {
+    const LIMBS: usize = 2048 / pkcs1::der::bigint::limb::BIT_SIZE;
+
+    let mut key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    assert!(key.check_prime_order::<LIMBS>().is_ok());
+
+    let other_prime_info = &mut key.other_prime_infos.as_mut().unwrap()[0];
+    core::mem::swap(&mut key.prime2, &mut other_prime_info.prime);
+
+    assert_eq!(
+        key.check_prime_order::<LIMBS>(),
+        Err(pkcs1::Error::OtherPrimeInfosOrder)
+    );
+}
@@ -2,7 +2,7 @@
 
 use core::convert::TryFrom;
 use hex_literal::hex;
-use pkcs1::RsaPublicKey;
+use pkcs1::{der::Encodable, RsaKeySummary, RsaPublicKey, UIntBytes};
 
 #[cfg(feature = "pem")]
 use pkcs1::{der::Document, RsaPublicKeyDocument};
@@ -16,6 +16,9 @@ const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pub.der");
 /// RSA-4096 PKCS#1 public key encoded as ASN.1 DER
 const RSA_4096_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa4096-pub.der");
 
+/// RSA-8192 PKCS#1 public key encoded as ASN.1 DER
+const RSA_8192_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa8192-pub.der");
+
 /// RSA-2048 PKCS#1 public key encoded as PEM
 #[cfg(feature = "pem")]
 const RSA_2048_PEM_EXAMPLE: &str = include_str!("examples/rsa2048-pub.pem");
@@ -24,6 +27,10 @@ const RSA_2048_PEM_EXAMPLE: &str = include_str!("examples/rsa2048-pub.pem");
 #[cfg(feature = "pem")]
 const RSA_4096_PEM_EXAMPLE: &str = include_str!("examples/rsa4096-pub.pem");
 
+/// RSA-8192 PKCS#1 public key encoded as PEM
+#[cfg(feature = "pem")]
+const RSA_8192_PEM_EXAMPLE: &str = include_str!("examples/rsa8192-pub.pem");
+
 #[test]
 fn decode_rsa2048_der() {
     let key = RsaPublicKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
@@ -44,6 +51,57 @@ fn decode_rsa4096_der() {
     assert_eq!(key.public_exponent.as_bytes(), hex!("010001"));
 }
 
+#[test]
+fn decode_rsa8192_der() {
+    let key = RsaPublicKey::try_from(RSA_8192_DER_EXAMPLE).unwrap();
+    assert_eq!(key.modulus.as_bytes().len(), 1024);
+    assert_eq!(key.public_exponent.as_bytes(), hex!("010001"));
+
+    let mut buf = [0u8; RsaPublicKey::max_der_len::<8192>()];
+    assert_eq!(key.encode_to_slice(&mut buf).unwrap(), RSA_8192_DER_EXAMPLE);
+}
+
+#[test]
+fn summary() {
+    let key = RsaPublicKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        key.summary(),
+        RsaKeySummary {
+            modulus_bits: 2048,
+            public_exponent: Some(65537),
+            primes: None
+        }
+    );
+    assert_eq!(key.summary().to_string(), "RSA-2048, e=65537");
+
+    // Public exponent which doesn't fit in a `u64`
+    let key = RsaPublicKey {
+        modulus: UIntBytes::new(&[0x7F; 16]).unwrap(),
+        public_exponent: UIntBytes::new(&[0x01; 9]).unwrap(),
+    };
+    assert_eq!(key.summary().public_exponent, None);
+    assert_eq!(key.summary().to_string(), "RSA-127, e>=2^64");
+}
+
+#[test]
+fn max_der_len() {
+    assert!(RSA_2048_DER_EXAMPLE.len() <= RsaPublicKey::max_der_len::<2048>());
+    assert!(RSA_4096_DER_EXAMPLE.len() <= RsaPublicKey::max_der_len::<4096>());
+    assert!(RSA_8192_DER_EXAMPLE.len() <= RsaPublicKey::max_der_len::<8192>());
+
+    // Upper bound: the public exponent is as large as the modulus
+    let modulus = [0xFF; 512];
+    let key = RsaPublicKey {
+        modulus: UIntBytes::new(&modulus).unwrap(),
+        public_exponent: UIntBytes::new(&modulus).unwrap(),
+    };
+
+    assert_eq!(
+        usize::try_from(key.encoded_len().unwrap()).unwrap(),
+        RsaPublicKey::max_der_len::<4096>()
+    );
+}
+
 #[test]
 #[cfg(feature = "pem")]
 fn decode_rsa_2048_pem() {
@@ -55,6 +113,13 @@ fn decode_rsa_2048_pem() {
     assert_eq!(pkcs1_doc.decode().modulus.as_bytes(), pk.modulus.as_bytes());
 }
 
+#[test]
+#[cfg(feature = "pem")]
+fn decode_rsa_8192_pem() {
+    let pkcs1_doc: RsaPublicKeyDocument = RSA_8192_PEM_EXAMPLE.parse().unwrap();
+    assert_eq!(pkcs1_doc.as_ref(), RSA_8192_DER_EXAMPLE);
+}
+
 #[test]
 #[cfg(feature = "pem")]
 fn decode_rsa_4096_pem() {
@@ -65,3 +130,71 @@ fn decode_rsa_4096_pem() {
     let pk = RsaPublicKey::try_from(RSA_4096_DER_EXAMPLE).unwrap();
     assert_eq!(pkcs1_doc.decode().modulus.as_bytes(), pk.modulus.as_bytes());
 }
+
+#[test]
+#[cfg(feature = "pem")]
+fn borrow_rsa2048_public_key_document() {
+    let doc = RsaPublicKeyDocument::from_der(RSA_2048_DER_EXAMPLE).unwrap();
+    let key = RsaPublicKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+
+    assert_eq!(doc.public_key(), key);
+    assert_eq!(RsaPublicKey::from(&doc), key);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn map_rsa2048_public_key_document() {
+    let doc = RsaPublicKeyDocument::from_der(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        doc.map_key(|key| key).unwrap().as_der(),
+        RSA_2048_DER_EXAMPLE
+    );
+
+    let exponent = [0x03];
+    let mapped = doc
+        .map_key(|mut key| {
+            key.public_exponent = pkcs1::UIntBytes::new(&exponent).unwrap();
+            key
+        })
+        .unwrap();
+
+    let key = mapped.decode();
+    assert_eq!(key.public_exponent.as_bytes(), &[0x03]);
+    assert_eq!(key.modulus.as_bytes(), doc.decode().modulus.as_bytes());
+}
+
+#[test]
+#[cfg(feature = "spki")]
+fn decode_from_spki() {
+    use pkcs1::{
+        der::asn1::{Null, ObjectIdentifier},
+        spki::{AlgorithmIdentifier, SubjectPublicKeyInfo},
+    };
+
+    let mut spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: ObjectIdentifier::new("1.2.840.113549.1.1.1"),
+            parameters: Some(Null.into()),
+        },
+        subject_public_key: RSA_2048_DER_EXAMPLE,
+    };
+
+    let key = spki.decode_public_key::<RsaPublicKey<'_>>().unwrap();
+    assert_eq!(key, RsaPublicKey::try_from(RSA_2048_DER_EXAMPLE).unwrap());
+
+    // Absent parameters are accepted
+    spki.algorithm.parameters = None;
+    assert_eq!(spki.decode_public_key::<RsaPublicKey<'_>>().unwrap(), key);
+
+    // Parameters other than `NULL` are rejected
+    let oid = ObjectIdentifier::new("1.2.840.10045.3.1.7");
+    spki.algorithm.parameters = Some((&oid).into());
+    assert!(spki.decode_public_key::<RsaPublicKey<'_>>().is_err());
+
+    // Other algorithms are rejected
+    spki.algorithm = AlgorithmIdentifier {
+        oid: ObjectIdentifier::new("1.3.101.112"),
+        parameters: None,
+    };
+    assert!(spki.decode_public_key::<RsaPublicKey<'_>>().is_err());
+}
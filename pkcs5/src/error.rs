@@ -22,7 +22,8 @@ pub enum Error {
     /// Encryption Failed
     EncryptFailed,
 
-    /// Pbes1 support is limited to parsing; encryption/decryption is not supported (won't fix)
+    /// PBES1 encryption is not supported (won't fix), and decryption requires
+    /// the `pbes1` feature
     #[cfg(feature = "pbes2")]
     NoPbes1CryptSupport,
 
@@ -45,9 +46,9 @@ impl fmt::Display for Error {
             Error::DecryptFailed => f.write_str("PKCS#5 decryption failed"),
             Error::EncryptFailed => f.write_str("PKCS#5 encryption failed"),
             #[cfg(feature = "pbes2")]
-            Error::NoPbes1CryptSupport => {
-                f.write_str("PKCS#5 encryption/decryption unsupported for PBES1 (won't fix)")
-            }
+            Error::NoPbes1CryptSupport => f.write_str(
+                "PKCS#5 encryption unsupported for PBES1 (decryption requires `pbes1` feature)",
+            ),
             Error::UnsupportedAlgorithm { oid } => {
                 write!(f, "PKCS#5 algorithm {} is unsupported", oid)
             }
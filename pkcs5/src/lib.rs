@@ -32,6 +32,7 @@ mod error;
 
 pub mod pbes1;
 pub mod pbes2;
+pub mod pkcs12;
 
 pub use crate::error::{Error, Result};
 pub use der::{self, asn1::ObjectIdentifier};
@@ -57,6 +58,11 @@ pub enum EncryptionScheme<'a> {
     ///
     /// [RFC 8018 Section 6.2]: https://tools.ietf.org/html/rfc8018#section-6.2
     Pbes2(pbes2::Parameters<'a>),
+
+    /// PKCS#12 password-based encryption as defined in [RFC 7292 Appendix C].
+    ///
+    /// [RFC 7292 Appendix C]: https://tools.ietf.org/html/rfc7292#appendix-C
+    Pkcs12(pkcs12::Parameters<'a>),
 }
 
 impl<'a> EncryptionScheme<'a> {
@@ -68,7 +74,14 @@ impl<'a> EncryptionScheme<'a> {
     pub fn decrypt(&self, password: impl AsRef<[u8]>, ciphertext: &[u8]) -> Result<Vec<u8>> {
         match self {
             Self::Pbes2(params) => params.decrypt(password, ciphertext),
+            #[cfg(feature = "pbes1")]
+            Self::Pbes1(params) => params.decrypt(password, ciphertext),
+            #[cfg(not(feature = "pbes1"))]
             Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
+            #[cfg(feature = "pkcs12")]
+            Self::Pkcs12(params) => params.decrypt(password, ciphertext),
+            #[cfg(not(feature = "pkcs12"))]
+            Self::Pkcs12(params) => Err(Error::UnsupportedAlgorithm { oid: params.oid() }),
         }
     }
 
@@ -87,7 +100,14 @@ impl<'a> EncryptionScheme<'a> {
     ) -> Result<&'b [u8]> {
         match self {
             Self::Pbes2(params) => params.decrypt_in_place(password, buffer),
+            #[cfg(feature = "pbes1")]
+            Self::Pbes1(params) => params.decrypt_in_place(password, buffer),
+            #[cfg(not(feature = "pbes1"))]
             Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
+            #[cfg(feature = "pkcs12")]
+            Self::Pkcs12(params) => params.decrypt_in_place(password, buffer),
+            #[cfg(not(feature = "pkcs12"))]
+            Self::Pkcs12(params) => Err(Error::UnsupportedAlgorithm { oid: params.oid() }),
         }
     }
 
@@ -100,6 +120,7 @@ impl<'a> EncryptionScheme<'a> {
         match self {
             Self::Pbes2(params) => params.encrypt(password, plaintext),
             Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
+            Self::Pkcs12(params) => Err(Error::UnsupportedAlgorithm { oid: params.oid() }),
         }
     }
 
@@ -116,6 +137,7 @@ impl<'a> EncryptionScheme<'a> {
         match self {
             Self::Pbes2(params) => params.encrypt_in_place(password, buffer, pos),
             Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
+            Self::Pkcs12(params) => Err(Error::UnsupportedAlgorithm { oid: params.oid() }),
         }
     }
 
@@ -124,6 +146,7 @@ impl<'a> EncryptionScheme<'a> {
         match self {
             Self::Pbes1(params) => params.oid(),
             Self::Pbes2(_) => pbes2::PBES2_OID,
+            Self::Pkcs12(params) => params.oid(),
         }
     }
 
@@ -142,6 +165,14 @@ impl<'a> EncryptionScheme<'a> {
             _ => None,
         }
     }
+
+    /// Get [`pkcs12::Parameters`] if it is the selected algorithm.
+    pub fn pkcs12(&self) -> Option<&pkcs12::Parameters<'a>> {
+        match self {
+            Self::Pkcs12(params) => Some(params),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> Decodable<'a> for EncryptionScheme<'a> {
@@ -157,6 +188,7 @@ impl<'a> Encodable for EncryptionScheme<'a> {
             Self::Pbes2(pbes2) => {
                 (pbes2::PBES2_OID.encoded_len()? + pbes2.encoded_len()?)?.for_tlv()
             }
+            Self::Pkcs12(pkcs12) => pkcs12.encoded_len(),
         }
     }
 
@@ -170,6 +202,7 @@ impl<'a> Encodable for EncryptionScheme<'a> {
                     seq.encode(pbes2)
                 })
             }
+            Self::Pkcs12(pkcs12) => pkcs12.encode(encoder),
         }
     }
 }
@@ -186,12 +219,21 @@ impl<'a> From<pbes2::Parameters<'a>> for EncryptionScheme<'a> {
     }
 }
 
+impl<'a> From<pkcs12::Parameters<'a>> for EncryptionScheme<'a> {
+    fn from(params: pkcs12::Parameters<'a>) -> EncryptionScheme<'a> {
+        Self::Pkcs12(params)
+    }
+}
+
 impl<'a> TryFrom<AlgorithmIdentifier<'a>> for EncryptionScheme<'a> {
     type Error = der::Error;
 
     fn try_from(alg: AlgorithmIdentifier<'a>) -> der::Result<EncryptionScheme<'_>> {
         match alg.oid {
             pbes2::PBES2_OID => pbes2::Parameters::try_from(alg.parameters_any()?).map(Into::into),
+            oid if pkcs12::EncryptionScheme::try_from(oid).is_ok() => {
+                pkcs12::Parameters::try_from(alg).map(Into::into)
+            }
             _ => pbes1::Parameters::try_from(alg).map(Into::into),
         }
     }
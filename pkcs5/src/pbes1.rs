@@ -2,6 +2,9 @@
 //!
 //! [RFC 8018 Section 6.1]: https://tools.ietf.org/html/rfc8018#section-6.1
 
+#[cfg(feature = "pbes1")]
+mod encryption;
+
 use crate::AlgorithmIdentifier;
 use core::convert::{TryFrom, TryInto};
 use der::{
@@ -9,6 +12,12 @@ use der::{
     Decodable, Decoder, Encodable, Encoder, ErrorKind, Length, Tag, Tagged,
 };
 
+#[cfg(feature = "pbes1")]
+use crate::Result;
+
+#[cfg(all(feature = "alloc", feature = "pbes1"))]
+use alloc::vec::Vec;
+
 /// `pbeWithMD2AndDES-CBC` Object Identifier (OID).
 pub const PBE_WITH_MD2_AND_DES_CBC_OID: ObjectIdentifier =
     ObjectIdentifier::new("1.2.840.113549.1.5.1");
@@ -66,6 +75,35 @@ impl Parameters {
         self.encryption.oid()
     }
 
+    /// Attempt to decrypt the given ciphertext, allocating and returning a
+    /// byte vector containing the plaintext.
+    #[cfg(all(feature = "alloc", feature = "pbes1"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes1")))]
+    pub fn decrypt(&self, password: impl AsRef<[u8]>, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let mut buffer = ciphertext.to_vec();
+        let pt_len = self.decrypt_in_place(password, &mut buffer)?.len();
+        buffer.truncate(pt_len);
+        Ok(buffer)
+    }
+
+    /// Attempt to decrypt the given ciphertext in-place using a key derived
+    /// from the provided password and these parameters.
+    ///
+    /// Only the DES-CBC schemes using MD5 or SHA-1 are supported: the MD2 and
+    /// RC2 schemes return [`Error::UnsupportedAlgorithm`][`crate::Error`].
+    /// Encryption isn't supported, as PBES1 should only be used for
+    /// compatibility with existing documents.
+    #[cfg(feature = "pbes1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes1")))]
+    pub fn decrypt_in_place<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8]> {
+        encryption::decrypt_in_place(self, password, buffer)
+    }
+
     /// Get the inner length of the encoded sequence
     fn inner_len(&self) -> der::Result<Length> {
         let oid_len = self.encryption.oid().encoded_len()?;
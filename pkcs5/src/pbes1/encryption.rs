@@ -0,0 +1,56 @@
+//! PBES1 decryption implementation
+
+use super::{DigestAlgorithm, Parameters, SymmetricCipher};
+use crate::{Error, Result};
+use block_modes::block_padding::Pkcs7;
+use block_modes::{BlockMode, Cbc};
+use sha1::Digest;
+
+type DesCbc = Cbc<des::Des, Pkcs7>;
+
+/// Length of the key derived by PBKDF1: a DES key followed by its IV
+const DERIVED_KEY_LEN: usize = 16;
+
+/// Decrypt a message encrypted with PBES1
+pub fn decrypt_in_place<'a>(
+    params: &Parameters,
+    password: impl AsRef<[u8]>,
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8]> {
+    let es = params.encryption;
+
+    if params.iteration_count == 0 {
+        return Err(Error::AlgorithmParametersInvalid { oid: es.oid() });
+    }
+
+    let derived_key = match es.digest() {
+        DigestAlgorithm::Md2 => return Err(Error::UnsupportedAlgorithm { oid: es.oid() }),
+        DigestAlgorithm::Md5 => pbkdf1::<md5::Md5>(password.as_ref(), params),
+        DigestAlgorithm::Sha1 => pbkdf1::<sha1::Sha1>(password.as_ref(), params),
+    };
+
+    match es.cipher() {
+        SymmetricCipher::DesCbc => {
+            let (key, iv) = derived_key.split_at(DERIVED_KEY_LEN / 2);
+            let cipher = DesCbc::new_from_slices(key, iv)
+                .map_err(|_| Error::AlgorithmParametersInvalid { oid: es.oid() })?;
+            cipher.decrypt(buffer).map_err(|_| Error::DecryptFailed)
+        }
+        SymmetricCipher::Rc2Cbc => Err(Error::UnsupportedAlgorithm { oid: es.oid() }),
+    }
+}
+
+/// Derive a key and IV using PBKDF1 as defined in [RFC 8018 Section 5.1].
+///
+/// [RFC 8018 Section 5.1]: https://tools.ietf.org/html/rfc8018#section-5.1
+fn pbkdf1<D: Digest>(password: &[u8], params: &Parameters) -> [u8; DERIVED_KEY_LEN] {
+    let mut t = D::new().chain(password).chain(&params.salt).finalize();
+
+    for _ in 1..params.iteration_count {
+        t = D::digest(&t);
+    }
+
+    let mut derived_key = [0u8; DERIVED_KEY_LEN];
+    derived_key.copy_from_slice(&t[..DERIVED_KEY_LEN]);
+    derived_key
+}
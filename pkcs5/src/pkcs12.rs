@@ -0,0 +1,256 @@
+//! PKCS#12 password-based encryption schemes as defined in [RFC 7292 Appendix C].
+//!
+//! These predate PBES2, but are still used by PKCS#12 files exported by
+//! Windows and Java (e.g. `pbeWithSHAAnd40BitRC2-CBC` for certificate bags),
+//! as well as by some encrypted PKCS#8 keys.
+//!
+//! [RFC 7292 Appendix C]: https://tools.ietf.org/html/rfc7292#appendix-C
+
+#[cfg(feature = "pkcs12")]
+mod encryption;
+
+use crate::AlgorithmIdentifier;
+use core::convert::{TryFrom, TryInto};
+use der::{
+    asn1::{ObjectIdentifier, OctetString},
+    Decodable, Decoder, Encodable, Encoder, ErrorKind, Length, Tag, Tagged,
+};
+
+#[cfg(feature = "pkcs12")]
+use crate::Result;
+
+#[cfg(all(feature = "alloc", feature = "pkcs12"))]
+use alloc::vec::Vec;
+
+/// `pbeWithSHAAnd128BitRC4` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_128BIT_RC4_OID: ObjectIdentifier =
+    ObjectIdentifier::new("1.2.840.113549.1.12.1.1");
+
+/// `pbeWithSHAAnd40BitRC4` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_40BIT_RC4_OID: ObjectIdentifier =
+    ObjectIdentifier::new("1.2.840.113549.1.12.1.2");
+
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_3KEY_TRIPLE_DES_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new("1.2.840.113549.1.12.1.3");
+
+/// `pbeWithSHAAnd2-KeyTripleDES-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_2KEY_TRIPLE_DES_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new("1.2.840.113549.1.12.1.4");
+
+/// `pbeWithSHAAnd128BitRC2-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_128BIT_RC2_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new("1.2.840.113549.1.12.1.5");
+
+/// `pbeWithSHAAnd40BitRC2-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_40BIT_RC2_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new("1.2.840.113549.1.12.1.6");
+
+/// PKCS#12 password-based encryption parameters as defined in
+/// [RFC 7292 Appendix C].
+///
+/// ```text
+/// pkcs-12PbeParams ::= SEQUENCE {
+///     salt        OCTET STRING,
+///     iterations  INTEGER
+/// }
+/// ```
+///
+/// Note that this struct additionally stores an [`EncryptionScheme`] parameter
+/// parsed from the [`ObjectIdentifier`].
+///
+/// [RFC 7292 Appendix C]: https://tools.ietf.org/html/rfc7292#appendix-C
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Parameters<'a> {
+    /// Encryption scheme
+    pub encryption: EncryptionScheme,
+
+    /// Salt value
+    pub salt: &'a [u8],
+
+    /// Iteration count
+    pub iteration_count: u32,
+}
+
+impl<'a> Parameters<'a> {
+    /// Get the [`ObjectIdentifier`] (a.k.a OID) for this algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        self.encryption.oid()
+    }
+
+    /// Attempt to decrypt the given ciphertext, allocating and returning a
+    /// byte vector containing the plaintext.
+    #[cfg(all(feature = "alloc", feature = "pkcs12"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs12")))]
+    pub fn decrypt(&self, password: impl AsRef<[u8]>, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let mut buffer = ciphertext.to_vec();
+        let pt_len = self.decrypt_in_place(password, &mut buffer)?.len();
+        buffer.truncate(pt_len);
+        Ok(buffer)
+    }
+
+    /// Attempt to decrypt the given ciphertext in-place using a key derived
+    /// from the provided password and these parameters.
+    ///
+    /// The password must be UTF-8, and is converted to the `BMPString`
+    /// encoding used by the PKCS#12 key derivation function.
+    ///
+    /// The RC2 schemes are supported with the `pkcs12` feature and the
+    /// Triple-DES schemes additionally require the `3des` feature. The RC4
+    /// schemes are unsupported.
+    #[cfg(feature = "pkcs12")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs12")))]
+    pub fn decrypt_in_place<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8]> {
+        encryption::decrypt_in_place(self, password, buffer)
+    }
+
+    /// Get the inner length of the encoded sequence
+    fn inner_len(&self) -> der::Result<Length> {
+        let oid_len = self.encryption.oid().encoded_len()?;
+        let params_len = (self.salt_string()?.encoded_len()?
+            + self.iteration_count.encoded_len()?)?
+        .for_tlv()?;
+        oid_len + params_len
+    }
+
+    /// Get an [`OctetString`] wrapper for the salt
+    fn salt_string(&self) -> der::Result<OctetString<'a>> {
+        OctetString::new(self.salt)
+    }
+}
+
+impl<'a> Decodable<'a> for Parameters<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        AlgorithmIdentifier::decode(decoder)?.try_into()
+    }
+}
+
+impl<'a> Encodable for Parameters<'a> {
+    fn encoded_len(&self) -> der::Result<Length> {
+        self.inner_len()?.for_tlv()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        encoder.sequence(self.inner_len()?, |encoder| {
+            encoder.oid(self.encryption.oid())?;
+
+            let salt_string = self.salt_string()?;
+            let seq_len = (salt_string.encoded_len()? + self.iteration_count.encoded_len()?)?;
+            encoder.sequence(seq_len, |seq| {
+                seq.encode(&salt_string)?;
+                seq.encode(&self.iteration_count)
+            })
+        })
+    }
+}
+
+impl<'a> Tagged for Parameters<'a> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+impl<'a> TryFrom<AlgorithmIdentifier<'a>> for Parameters<'a> {
+    type Error = der::Error;
+
+    fn try_from(alg: AlgorithmIdentifier<'a>) -> der::Result<Self> {
+        // Ensure that we have a supported PKCS#12 algorithm identifier
+        let encryption = EncryptionScheme::try_from(alg.oid)
+            .map_err(|_| der::Tag::ObjectIdentifier.value_error())?;
+
+        alg.parameters_any()?.sequence(|params| {
+            let salt = params.octet_string()?.as_bytes();
+            let iteration_count = params.decode()?;
+
+            Ok(Self {
+                encryption,
+                salt,
+                iteration_count,
+            })
+        })
+    }
+}
+
+/// PKCS#12 password-based encryption algorithms as defined in
+/// [RFC 7292 Appendix C].
+///
+/// All of them derive their keys and IVs from the password using SHA-1 and
+/// the key derivation function in [RFC 7292 Appendix B.2].
+///
+/// [RFC 7292 Appendix C]: https://tools.ietf.org/html/rfc7292#appendix-C
+/// [RFC 7292 Appendix B.2]: https://tools.ietf.org/html/rfc7292#appendix-B.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EncryptionScheme {
+    /// `pbeWithSHAAnd128BitRC4`
+    PbeWithShaAnd128BitRc4,
+
+    /// `pbeWithSHAAnd40BitRC4`
+    PbeWithShaAnd40BitRc4,
+
+    /// `pbeWithSHAAnd3-KeyTripleDES-CBC`
+    PbeWithShaAnd3KeyTripleDesCbc,
+
+    /// `pbeWithSHAAnd2-KeyTripleDES-CBC`
+    PbeWithShaAnd2KeyTripleDesCbc,
+
+    /// `pbeWithSHAAnd128BitRC2-CBC`
+    PbeWithShaAnd128BitRc2Cbc,
+
+    /// `pbeWithSHAAnd40BitRC2-CBC`
+    PbeWithShaAnd40BitRc2Cbc,
+}
+
+impl TryFrom<ObjectIdentifier> for EncryptionScheme {
+    type Error = der::Error;
+
+    fn try_from(oid: ObjectIdentifier) -> der::Result<Self> {
+        match oid {
+            PBE_WITH_SHA_AND_128BIT_RC4_OID => Ok(Self::PbeWithShaAnd128BitRc4),
+            PBE_WITH_SHA_AND_40BIT_RC4_OID => Ok(Self::PbeWithShaAnd40BitRc4),
+            PBE_WITH_SHA_AND_3KEY_TRIPLE_DES_CBC_OID => Ok(Self::PbeWithShaAnd3KeyTripleDesCbc),
+            PBE_WITH_SHA_AND_2KEY_TRIPLE_DES_CBC_OID => Ok(Self::PbeWithShaAnd2KeyTripleDesCbc),
+            PBE_WITH_SHA_AND_128BIT_RC2_CBC_OID => Ok(Self::PbeWithShaAnd128BitRc2Cbc),
+            PBE_WITH_SHA_AND_40BIT_RC2_CBC_OID => Ok(Self::PbeWithShaAnd40BitRc2Cbc),
+            _ => Err(ErrorKind::UnknownOid { oid }.into()),
+        }
+    }
+}
+
+impl EncryptionScheme {
+    /// Get the size of a key for this algorithm in bytes.
+    pub fn key_size(self) -> usize {
+        match self {
+            Self::PbeWithShaAnd128BitRc4 => 16,
+            Self::PbeWithShaAnd40BitRc4 => 5,
+            Self::PbeWithShaAnd3KeyTripleDesCbc => 24,
+            Self::PbeWithShaAnd2KeyTripleDesCbc => 16,
+            Self::PbeWithShaAnd128BitRc2Cbc => 16,
+            Self::PbeWithShaAnd40BitRc2Cbc => 5,
+        }
+    }
+
+    /// Get the [`ObjectIdentifier`] (a.k.a OID) for this algorithm.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            Self::PbeWithShaAnd128BitRc4 => PBE_WITH_SHA_AND_128BIT_RC4_OID,
+            Self::PbeWithShaAnd40BitRc4 => PBE_WITH_SHA_AND_40BIT_RC4_OID,
+            Self::PbeWithShaAnd3KeyTripleDesCbc => PBE_WITH_SHA_AND_3KEY_TRIPLE_DES_CBC_OID,
+            Self::PbeWithShaAnd2KeyTripleDesCbc => PBE_WITH_SHA_AND_2KEY_TRIPLE_DES_CBC_OID,
+            Self::PbeWithShaAnd128BitRc2Cbc => PBE_WITH_SHA_AND_128BIT_RC2_CBC_OID,
+            Self::PbeWithShaAnd40BitRc2Cbc => PBE_WITH_SHA_AND_40BIT_RC2_CBC_OID,
+        }
+    }
+}
+
+impl Encodable for EncryptionScheme {
+    fn encoded_len(&self) -> der::Result<Length> {
+        self.oid().encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        self.oid().encode(encoder)
+    }
+}
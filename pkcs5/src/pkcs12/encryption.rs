@@ -0,0 +1,162 @@
+//! PKCS#12 password-based decryption implementation
+
+use super::{EncryptionScheme, Parameters};
+use crate::{Error, Result};
+use block_modes::block_padding::Pkcs7;
+use block_modes::{BlockMode, Cbc};
+use sha1::{Digest, Sha1};
+
+type Rc2Cbc = Cbc<rc2::Rc2, Pkcs7>;
+#[cfg(feature = "3des")]
+type DesEde2Cbc = Cbc<des::TdesEde2, Pkcs7>;
+#[cfg(feature = "3des")]
+type DesEde3Cbc = Cbc<des::TdesEde3, Pkcs7>;
+
+/// Maximum size of a derived encryption key
+const MAX_KEY_LEN: usize = 24;
+
+/// Size of the IV of the supported (64-bit block) ciphers
+const IV_LEN: usize = 8;
+
+/// SHA-1 output size (`u` in RFC 7292 Appendix B.2)
+const OUTPUT_LEN: usize = 20;
+
+/// SHA-1 block size (`v` in RFC 7292 Appendix B.2)
+const BLOCK_LEN: usize = 64;
+
+/// Key derivation function diversifier for encryption keys
+const KEY_ID: u8 = 1;
+
+/// Key derivation function diversifier for IVs
+const IV_ID: u8 = 2;
+
+/// Decrypt a message encrypted with a PKCS#12 password-based encryption scheme
+pub fn decrypt_in_place<'a>(
+    params: &Parameters<'_>,
+    password: impl AsRef<[u8]>,
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8]> {
+    let es = params.encryption;
+
+    if params.iteration_count == 0 {
+        return Err(Error::AlgorithmParametersInvalid { oid: es.oid() });
+    }
+
+    let password = core::str::from_utf8(password.as_ref()).map_err(|_| Error::DecryptFailed)?;
+
+    let mut key = [0u8; MAX_KEY_LEN];
+    let key = &mut key[..es.key_size()];
+    derive(KEY_ID, password, params, key);
+
+    let mut iv = [0u8; IV_LEN];
+    derive(IV_ID, password, params, &mut iv);
+
+    match es {
+        EncryptionScheme::PbeWithShaAnd128BitRc2Cbc
+        | EncryptionScheme::PbeWithShaAnd40BitRc2Cbc => {
+            let cipher = Rc2Cbc::new_from_slices(key, &iv)
+                .map_err(|_| Error::AlgorithmParametersInvalid { oid: es.oid() })?;
+            cipher.decrypt(buffer).map_err(|_| Error::DecryptFailed)
+        }
+        #[cfg(feature = "3des")]
+        EncryptionScheme::PbeWithShaAnd3KeyTripleDesCbc => {
+            let cipher = DesEde3Cbc::new_from_slices(key, &iv)
+                .map_err(|_| Error::AlgorithmParametersInvalid { oid: es.oid() })?;
+            cipher.decrypt(buffer).map_err(|_| Error::DecryptFailed)
+        }
+        #[cfg(feature = "3des")]
+        EncryptionScheme::PbeWithShaAnd2KeyTripleDesCbc => {
+            let cipher = DesEde2Cbc::new_from_slices(key, &iv)
+                .map_err(|_| Error::AlgorithmParametersInvalid { oid: es.oid() })?;
+            cipher.decrypt(buffer).map_err(|_| Error::DecryptFailed)
+        }
+        _ => Err(Error::UnsupportedAlgorithm { oid: es.oid() }),
+    }
+}
+
+/// Derive key material using the SHA-1 based key derivation function
+/// defined in [RFC 7292 Appendix B.2].
+///
+/// Step 6C of the algorithm adds `B + 1` to each block of `I` after each
+/// round. Rather than storing `I`, which is as long as the password, this
+/// keeps a running total of the values added so far and applies it to each
+/// block as it's recomputed.
+///
+/// [RFC 7292 Appendix B.2]: https://tools.ietf.org/html/rfc7292#appendix-B.2
+fn derive(id: u8, password: &str, params: &Parameters<'_>, out: &mut [u8]) {
+    // The password as a NUL-terminated big endian `BMPString`
+    let password_bytes = || {
+        password
+            .encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .chain([0, 0])
+    };
+
+    let salt_len = round_up(params.salt.len());
+    let password_len = round_up(password_bytes().count());
+
+    // I = S || P, with the salt and password each repeated to fill a
+    // multiple of the block size
+    let input = || {
+        params
+            .salt
+            .iter()
+            .copied()
+            .cycle()
+            .take(salt_len)
+            .chain(password_bytes().cycle().take(password_len))
+    };
+
+    let mut total = [0u8; BLOCK_LEN];
+
+    for chunk in out.chunks_mut(OUTPUT_LEN) {
+        let mut hasher = Sha1::new();
+        hasher.update(&[id; BLOCK_LEN]);
+
+        let mut input = input();
+
+        for _ in 0..(salt_len + password_len) / BLOCK_LEN {
+            let mut block = [0u8; BLOCK_LEN];
+
+            for (byte, input_byte) in block.iter_mut().zip(&mut input) {
+                *byte = input_byte;
+            }
+
+            add(&mut block, &total, 0);
+            hasher.update(&block);
+        }
+
+        let mut a = hasher.finalize();
+
+        for _ in 1..params.iteration_count {
+            a = Sha1::digest(&a);
+        }
+
+        chunk.copy_from_slice(&a[..chunk.len()]);
+
+        // B is A repeated to fill a block
+        let mut b = [0u8; BLOCK_LEN];
+
+        for (i, byte) in b.iter_mut().enumerate() {
+            *byte = a[i % OUTPUT_LEN];
+        }
+
+        add(&mut total, &b, 1);
+    }
+}
+
+/// Round the given length up to a multiple of the block size
+fn round_up(len: usize) -> usize {
+    (len + BLOCK_LEN - 1) / BLOCK_LEN * BLOCK_LEN
+}
+
+/// Add `rhs` and `carry` to `lhs` as big endian integers modulo 2^512
+fn add(lhs: &mut [u8; BLOCK_LEN], rhs: &[u8; BLOCK_LEN], carry: u16) {
+    let mut carry = carry;
+
+    for (l, r) in lhs.iter_mut().zip(rhs.iter()).rev() {
+        let sum = u16::from(*l) + u16::from(*r) + carry;
+        *l = sum as u8;
+        carry = sum >> 8;
+    }
+}
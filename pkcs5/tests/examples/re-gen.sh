@@ -51,3 +51,33 @@ do
         extract "$aes_mode" "$prf" 88
     done
 done
+
+# PBES1 (legacy); newer OpenSSL versions need `-provider legacy -provider default`
+for pbes1_alg in "PBE-MD5-DES" "PBE-SHA1-DES"
+do
+    openssl pkcs8 \
+        -topk8 \
+        -in rsa_sk.pkcs1.pem \
+        -v1 "$pbes1_alg" \
+        -iter 10 \
+        -passout "$passout" \
+        -outform DER -out "rsa_sk_${pbes1_alg}.pkcs8.der"
+
+    dd bs=1 skip=4 count=28 if="rsa_sk_${pbes1_alg}.pkcs8.der" of="pbes1_${pbes1_alg}_algid.der"
+    dd bs=1 skip=36 if="rsa_sk_${pbes1_alg}.pkcs8.der" of="pbes1_${pbes1_alg}_ciphertext.bin"
+done
+
+# PKCS#12 PBE (RFC 7292 Appendix C); also needs the legacy provider
+for pkcs12_alg in "PBE-SHA1-RC2-40" "PBE-SHA1-RC2-128" "PBE-SHA1-3DES" "PBE-SHA1-2DES"
+do
+    openssl pkcs8 \
+        -topk8 \
+        -in rsa_sk.pkcs1.pem \
+        -v1 "$pkcs12_alg" \
+        -iter 10 \
+        -passout "$passout" \
+        -outform DER -out "rsa_sk_${pkcs12_alg}.pkcs8.der"
+
+    dd bs=1 skip=4 count=29 if="rsa_sk_${pkcs12_alg}.pkcs8.der" of="pkcs12_${pkcs12_alg}_algid.der"
+    dd bs=1 skip=37 if="rsa_sk_${pkcs12_alg}.pkcs8.der" of="pkcs12_${pkcs12_alg}_ciphertext.bin"
+done
@@ -0,0 +1,71 @@
+//! PBES1 tests
+
+use core::convert::TryFrom;
+use hex_literal::hex;
+use pkcs5::pbes1;
+
+/// `pbeWithMD5AndDES-CBC` as generated by `openssl pkcs8 -v1 PBE-MD5-DES`
+const PBE_MD5_DES_ALG_ID: &[u8] = include_bytes!("examples/pbes1_PBE-MD5-DES_algid.der");
+
+/// `pbeWithSHA1AndDES-CBC` as generated by `openssl pkcs8 -v1 PBE-SHA1-DES`
+const PBE_SHA1_DES_ALG_ID: &[u8] = include_bytes!("examples/pbes1_PBE-SHA1-DES_algid.der");
+
+#[test]
+fn decode_pbe_md5_des() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBE_MD5_DES_ALG_ID).unwrap();
+    assert_eq!(scheme.oid(), pbes1::PBE_WITH_MD5_AND_DES_CBC_OID);
+
+    let params = scheme.pbes1().unwrap();
+    assert_eq!(
+        params.encryption,
+        pbes1::EncryptionScheme::PbeWithMd5AndDesCbc
+    );
+    assert_eq!(params.salt, hex!("0FC4369071D38B8F"));
+    assert_eq!(params.iteration_count, 10);
+}
+
+#[test]
+fn decode_pbe_sha1_des() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBE_SHA1_DES_ALG_ID).unwrap();
+    assert_eq!(scheme.oid(), pbes1::PBE_WITH_SHA1_AND_DES_CBC_OID);
+
+    let params = scheme.pbes1().unwrap();
+    assert_eq!(
+        params.encryption,
+        pbes1::EncryptionScheme::PbeWithSha1AndDesCbc
+    );
+    assert_eq!(params.salt, hex!("93D4FD04EC19ECD8"));
+    assert_eq!(params.iteration_count, 10);
+}
+
+#[cfg(feature = "pbes1")]
+#[test]
+fn decrypt() {
+    /// Password used to encrypt the keys.
+    const PASSWORD: &[u8] = b"hunter2"; // Bad password; don't actually use outside tests!
+
+    let sk_bytes = include_bytes!("examples/rsa_sk.pkcs8.der");
+
+    for (alg_id, ciphertext) in &[
+        (
+            PBE_MD5_DES_ALG_ID,
+            &include_bytes!("examples/pbes1_PBE-MD5-DES_ciphertext.bin")[..],
+        ),
+        (
+            PBE_SHA1_DES_ALG_ID,
+            &include_bytes!("examples/pbes1_PBE-SHA1-DES_ciphertext.bin")[..],
+        ),
+    ] {
+        let scheme = pkcs5::EncryptionScheme::try_from(*alg_id).unwrap();
+
+        let mut buffer = ciphertext.to_vec();
+        let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+        assert_eq!(plaintext, &sk_bytes[..]);
+
+        let mut buffer = ciphertext.to_vec();
+        assert_eq!(
+            scheme.decrypt_in_place(b"hunter3", &mut buffer).err(),
+            Some(pkcs5::Error::DecryptFailed)
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! PKCS#12 password-based encryption tests
+
+use core::convert::TryFrom;
+use der::Encodable;
+use hex_literal::hex;
+use pkcs5::pkcs12;
+
+/// `pbeWithSHAAnd40BitRC2-CBC` as generated by `openssl pkcs8 -v1 PBE-SHA1-RC2-40`
+const PBE_SHA1_RC2_40_ALG_ID: &[u8] = include_bytes!("examples/pkcs12_PBE-SHA1-RC2-40_algid.der");
+
+/// `pbeWithSHAAnd128BitRC2-CBC` as generated by `openssl pkcs8 -v1 PBE-SHA1-RC2-128`
+const PBE_SHA1_RC2_128_ALG_ID: &[u8] = include_bytes!("examples/pkcs12_PBE-SHA1-RC2-128_algid.der");
+
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC` as generated by `openssl pkcs8 -v1 PBE-SHA1-3DES`
+const PBE_SHA1_3DES_ALG_ID: &[u8] = include_bytes!("examples/pkcs12_PBE-SHA1-3DES_algid.der");
+
+/// `pbeWithSHAAnd2-KeyTripleDES-CBC` as generated by `openssl pkcs8 -v1 PBE-SHA1-2DES`
+const PBE_SHA1_2DES_ALG_ID: &[u8] = include_bytes!("examples/pkcs12_PBE-SHA1-2DES_algid.der");
+
+/// Password used to encrypt the keys.
+#[cfg(feature = "pkcs12")]
+const PASSWORD: &[u8] = b"hunter2"; // Bad password; don't actually use outside tests!
+
+#[test]
+fn decode_pbe_sha1_rc2_40() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBE_SHA1_RC2_40_ALG_ID).unwrap();
+    assert_eq!(scheme.oid(), pkcs12::PBE_WITH_SHA_AND_40BIT_RC2_CBC_OID);
+
+    let params = scheme.pkcs12().unwrap();
+    assert_eq!(
+        params.encryption,
+        pkcs12::EncryptionScheme::PbeWithShaAnd40BitRc2Cbc
+    );
+    assert_eq!(params.encryption.key_size(), 5);
+    assert_eq!(params.salt, &hex!("4B2FEF6059E9D082"));
+    assert_eq!(params.iteration_count, 10);
+}
+
+#[test]
+fn decode_all_schemes() {
+    for (alg_id, encryption) in &[
+        (
+            PBE_SHA1_RC2_40_ALG_ID,
+            pkcs12::EncryptionScheme::PbeWithShaAnd40BitRc2Cbc,
+        ),
+        (
+            PBE_SHA1_RC2_128_ALG_ID,
+            pkcs12::EncryptionScheme::PbeWithShaAnd128BitRc2Cbc,
+        ),
+        (
+            PBE_SHA1_3DES_ALG_ID,
+            pkcs12::EncryptionScheme::PbeWithShaAnd3KeyTripleDesCbc,
+        ),
+        (
+            PBE_SHA1_2DES_ALG_ID,
+            pkcs12::EncryptionScheme::PbeWithShaAnd2KeyTripleDesCbc,
+        ),
+    ] {
+        let scheme = pkcs5::EncryptionScheme::try_from(*alg_id).unwrap();
+        assert_eq!(scheme.pkcs12().unwrap().encryption, *encryption);
+        assert_eq!(scheme.oid(), encryption.oid());
+        assert!(scheme.pbes1().is_none());
+    }
+}
+
+#[test]
+fn encode_pbe_sha1_rc2_40() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBE_SHA1_RC2_40_ALG_ID).unwrap();
+    let mut buffer = [0u8; 1024];
+    let encoded = scheme.encode_to_slice(&mut buffer).unwrap();
+    assert_eq!(encoded, PBE_SHA1_RC2_40_ALG_ID);
+}
+
+#[cfg(feature = "pkcs12")]
+fn decrypt(alg_id: &[u8], ciphertext: &[u8]) {
+    let sk_bytes = include_bytes!("examples/rsa_sk.pkcs8.der");
+    let scheme = pkcs5::EncryptionScheme::try_from(alg_id).unwrap();
+
+    let mut buffer = ciphertext.to_vec();
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, &sk_bytes[..]);
+}
+
+#[cfg(feature = "pkcs12")]
+#[test]
+fn decrypt_pbe_sha1_rc2_40() {
+    decrypt(
+        PBE_SHA1_RC2_40_ALG_ID,
+        include_bytes!("examples/pkcs12_PBE-SHA1-RC2-40_ciphertext.bin"),
+    );
+}
+
+#[cfg(feature = "pkcs12")]
+#[test]
+fn decrypt_pbe_sha1_rc2_128() {
+    decrypt(
+        PBE_SHA1_RC2_128_ALG_ID,
+        include_bytes!("examples/pkcs12_PBE-SHA1-RC2-128_ciphertext.bin"),
+    );
+}
+
+#[cfg(all(feature = "pkcs12", feature = "3des"))]
+#[test]
+fn decrypt_pbe_sha1_3des() {
+    decrypt(
+        PBE_SHA1_3DES_ALG_ID,
+        include_bytes!("examples/pkcs12_PBE-SHA1-3DES_ciphertext.bin"),
+    );
+}
+
+#[cfg(all(feature = "pkcs12", feature = "3des"))]
+#[test]
+fn decrypt_pbe_sha1_2des() {
+    decrypt(
+        PBE_SHA1_2DES_ALG_ID,
+        include_bytes!("examples/pkcs12_PBE-SHA1-2DES_ciphertext.bin"),
+    );
+}
+
+#[cfg(feature = "pkcs12")]
+#[test]
+fn decrypt_wrong_password() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBE_SHA1_RC2_40_ALG_ID).unwrap();
+    let mut buffer = include_bytes!("examples/pkcs12_PBE-SHA1-RC2-40_ciphertext.bin").to_vec();
+    assert_eq!(
+        scheme.decrypt_in_place(b"hunter3", &mut buffer).err(),
+        Some(pkcs5::Error::DecryptFailed)
+    );
+}
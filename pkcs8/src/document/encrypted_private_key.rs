@@ -14,8 +14,7 @@ use crate::PrivateKeyDocument;
 
 #[cfg(feature = "pem")]
 use {
-    crate::{encrypted_private_key_info::PEM_TYPE_LABEL, pem, LineEnding},
-    alloc::string::String,
+    crate::{encrypted_private_key_info::PEM_TYPE_LABEL, pem, LineEnding, SecretPemString},
     core::str::FromStr,
 };
 
@@ -47,7 +46,7 @@ impl EncryptedPrivateKeyDocument {
 
     /// Parse the [`EncryptedPrivateKeyInfo`] contained in this [`EncryptedPrivateKeyDocument`].
     pub fn encrypted_private_key_info(&self) -> EncryptedPrivateKeyInfo<'_> {
-        EncryptedPrivateKeyInfo::try_from(self.0.as_ref())
+        EncryptedPrivateKeyInfo::try_from(self.0.as_slice())
             .expect("malformed EncryptedPrivateKeyDocument")
     }
 
@@ -70,7 +69,7 @@ impl EncryptedPrivateKeyDocument {
         let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
 
         if label != PEM_TYPE_LABEL {
-            return Err(pem::Error::Label.into());
+            return Err(pem::Error::unexpected_type_label(PEM_TYPE_LABEL, label).into());
         }
 
         Self::from_der(&*der_bytes)
@@ -80,9 +79,9 @@ impl EncryptedPrivateKeyDocument {
     /// PKCS#8 string with the given [`LineEnding`].
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-    pub fn to_pem(&self, line_ending: LineEnding) -> Result<Zeroizing<String>> {
+    pub fn to_pem(&self, line_ending: LineEnding) -> Result<SecretPemString> {
         pem::encode_string(PEM_TYPE_LABEL, line_ending, &self.0)
-            .map(Zeroizing::new)
+            .map(SecretPemString::new)
             .map_err(Error::Pem)
     }
 
@@ -125,6 +124,12 @@ impl AsRef<[u8]> for EncryptedPrivateKeyDocument {
     }
 }
 
+impl<'a> From<&'a EncryptedPrivateKeyDocument> for EncryptedPrivateKeyInfo<'a> {
+    fn from(doc: &'a EncryptedPrivateKeyDocument) -> EncryptedPrivateKeyInfo<'a> {
+        doc.encrypted_private_key_info()
+    }
+}
+
 impl TryFrom<EncryptedPrivateKeyInfo<'_>> for EncryptedPrivateKeyDocument {
     type Error = Error;
 
@@ -15,8 +15,7 @@ use {
 
 #[cfg(feature = "pem")]
 use {
-    crate::{pem, private_key_info::PEM_TYPE_LABEL, LineEnding},
-    alloc::string::String,
+    crate::{pem, private_key_info::PEM_TYPE_LABEL, LineEnding, SecretPemString},
     core::str::FromStr,
 };
 
@@ -38,7 +37,7 @@ pub struct PrivateKeyDocument(Zeroizing<Vec<u8>>);
 impl PrivateKeyDocument {
     /// Parse the [`PrivateKeyInfo`] contained in this [`PrivateKeyDocument`]
     pub fn private_key_info(&self) -> PrivateKeyInfo<'_> {
-        PrivateKeyInfo::try_from(self.0.as_ref()).expect("malformed PrivateKeyDocument")
+        PrivateKeyInfo::try_from(self.0.as_slice()).expect("malformed PrivateKeyDocument")
     }
 
     /// Encrypt this private key using a symmetric encryption key derived
@@ -108,7 +107,7 @@ impl DecodePrivateKey for PrivateKeyDocument {
         let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
 
         if label != PEM_TYPE_LABEL {
-            return Err(pem::Error::Label.into());
+            return Err(pem::Error::unexpected_type_label(PEM_TYPE_LABEL, label).into());
         }
 
         // Ensure document is well-formed
@@ -137,9 +136,9 @@ impl EncodePrivateKey for PrivateKeyDocument {
 
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-    fn to_pkcs8_pem(&self, line_ending: LineEnding) -> Result<Zeroizing<String>> {
+    fn to_pkcs8_pem(&self, line_ending: LineEnding) -> Result<SecretPemString> {
         let pem_doc = pem::encode_string(PEM_TYPE_LABEL, line_ending, self.as_ref())?;
-        Ok(Zeroizing::new(pem_doc))
+        Ok(SecretPemString::new(pem_doc))
     }
 
     #[cfg(feature = "std")]
@@ -163,6 +162,12 @@ impl AsRef<[u8]> for PrivateKeyDocument {
     }
 }
 
+impl<'a> From<&'a PrivateKeyDocument> for PrivateKeyInfo<'a> {
+    fn from(doc: &'a PrivateKeyDocument) -> PrivateKeyInfo<'a> {
+        doc.private_key_info()
+    }
+}
+
 impl TryFrom<PrivateKeyInfo<'_>> for PrivateKeyDocument {
     type Error = Error;
 
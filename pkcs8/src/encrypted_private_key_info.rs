@@ -12,10 +12,7 @@ use crate::{EncryptedPrivateKeyDocument, PrivateKeyDocument};
 use core::convert::TryInto;
 
 #[cfg(feature = "pem")]
-use {
-    crate::{pem, LineEnding},
-    zeroize::Zeroizing,
-};
+use crate::{pem, LineEnding, SecretPemString};
 
 /// Type label for PEM-encoded private keys.
 #[cfg(feature = "pem")]
@@ -74,9 +71,9 @@ impl<'a> EncryptedPrivateKeyInfo<'a> {
     /// the given [`LineEnding`].
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-    pub fn to_pem(&self, line_ending: LineEnding) -> Result<Zeroizing<alloc::string::String>> {
+    pub fn to_pem(&self, line_ending: LineEnding) -> Result<SecretPemString> {
         pem::encode_string(PEM_TYPE_LABEL, line_ending, self.to_der()?.as_ref())
-            .map(Zeroizing::new)
+            .map(SecretPemString::new)
             .map_err(Error::Pem)
     }
 }
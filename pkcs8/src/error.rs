@@ -1,5 +1,6 @@
 //! Error types
 
+use crate::ObjectIdentifier;
 use core::fmt;
 
 #[cfg(feature = "pem")]
@@ -12,6 +13,16 @@ pub type Result<T> = core::result::Result<T, Error>;
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Error {
+    /// The [`AlgorithmIdentifier`][`crate::AlgorithmIdentifier`] OID does
+    /// not match the key type being converted to.
+    AlgorithmMismatch {
+        /// OID of the expected algorithm.
+        expected: ObjectIdentifier,
+
+        /// OID of the algorithm actually present.
+        actual: ObjectIdentifier,
+    },
+
     /// ASN.1 DER-related errors.
     Asn1(der::Error),
 
@@ -55,6 +66,11 @@ pub enum Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Error::AlgorithmMismatch { expected, actual } => write!(
+                f,
+                "PKCS#8 algorithm mismatch: expected {}, got {}",
+                expected, actual
+            ),
             Error::Asn1(err) => write!(f, "PKCS#8 ASN.1 error: {}", err),
             Error::Crypto => f.write_str("PKCS#8 cryptographic error"),
             #[cfg(feature = "std")]
@@ -86,6 +102,26 @@ impl From<der::ErrorKind> for Error {
     }
 }
 
+#[cfg(feature = "pkcs1")]
+impl From<pkcs1::Error> for Error {
+    fn from(err: pkcs1::Error) -> Error {
+        match err {
+            pkcs1::Error::Asn1(err) => Error::Asn1(err),
+            _ => Error::KeyMalformed,
+        }
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl From<sec1::Error> for Error {
+    fn from(err: sec1::Error) -> Error {
+        match err {
+            sec1::Error::Asn1(err) => Error::Asn1(err),
+            _ => Error::KeyMalformed,
+        }
+    }
+}
+
 #[cfg(feature = "pem")]
 impl From<pem::Error> for Error {
     fn from(err: pem::Error) -> Error {
@@ -146,10 +146,18 @@ pub use {
 
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-pub use der::pem::{self, LineEnding};
+pub use der::pem::{self, LineEnding, SecretPemString};
+
+#[cfg(feature = "pkcs1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs1")))]
+pub use pkcs1;
 
 #[cfg(feature = "pkcs5")]
 pub use {crate::encrypted_private_key_info::EncryptedPrivateKeyInfo, pkcs5};
 
+#[cfg(feature = "sec1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sec1")))]
+pub use sec1;
+
 #[cfg(all(feature = "alloc", feature = "pkcs5"))]
 pub use crate::document::encrypted_private_key::EncryptedPrivateKeyDocument;
@@ -17,18 +17,29 @@ use {
 };
 
 #[cfg(feature = "pem")]
-use {
-    crate::{pem, LineEnding},
-    alloc::string::String,
-    zeroize::Zeroizing,
-};
+use crate::{pem, LineEnding, SecretPemString};
 
 #[cfg(feature = "subtle")]
 use subtle::{Choice, ConstantTimeEq};
 
+#[cfg(any(feature = "pkcs1", feature = "sec1"))]
+use crate::ObjectIdentifier;
+
 /// Context-specific tag number for the public key.
 const PUBLIC_KEY_TAG: TagNumber = TagNumber::new(1);
 
+/// `rsaEncryption` algorithm OID from [RFC 8017 Appendix C].
+///
+/// [RFC 8017 Appendix C]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-C
+#[cfg(feature = "pkcs1")]
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+
+/// `id-ecPublicKey` algorithm OID from [RFC 5480 Section 2.1.1].
+///
+/// [RFC 5480 Section 2.1.1]: https://datatracker.ietf.org/doc/html/rfc5480#section-2.1.1
+#[cfg(feature = "sec1")]
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.2.1");
+
 /// Type label for PEM-encoded private keys.
 #[cfg(feature = "pem")]
 pub(crate) const PEM_TYPE_LABEL: &str = "PRIVATE KEY";
@@ -130,6 +141,67 @@ impl<'a> PrivateKeyInfo<'a> {
         }
     }
 
+    /// Convert this [`PrivateKeyInfo`] into a PKCS#1 [`RsaPrivateKey`][`pkcs1::RsaPrivateKey`].
+    ///
+    /// Returns [`Error::AlgorithmMismatch`] if the algorithm is not
+    /// `rsaEncryption`, and [`Error::ParametersMalformed`] if the algorithm
+    /// parameters are present but not `NULL`.
+    #[cfg(feature = "pkcs1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs1")))]
+    pub fn try_into_rsa(self) -> Result<pkcs1::RsaPrivateKey<'a>> {
+        self.check_algorithm(RSA_ENCRYPTION_OID)?;
+
+        match self.algorithm.parameters {
+            Some(params) if !params.is_null() => return Err(Error::ParametersMalformed),
+            _ => (),
+        }
+
+        Ok(pkcs1::RsaPrivateKey::try_from(self.private_key)?)
+    }
+
+    /// Convert this [`PrivateKeyInfo`] into a SEC1 [`EcPrivateKey`][`sec1::EcPrivateKey`].
+    ///
+    /// The algorithm must be `id-ecPublicKey` with `namedCurve` parameters.
+    /// If the SEC1 key omits its own `parameters`, they are populated from
+    /// the [`AlgorithmIdentifier`]; if it includes them they must name the
+    /// same curve, otherwise [`Error::ParametersMalformed`] is returned.
+    #[cfg(feature = "sec1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sec1")))]
+    pub fn try_into_ec(self) -> Result<sec1::EcPrivateKey<'a>> {
+        self.check_algorithm(EC_PUBLIC_KEY_OID)?;
+
+        let curve = self
+            .algorithm
+            .parameters_oid()
+            .map_err(|_| Error::ParametersMalformed)?;
+
+        let mut private_key = sec1::EcPrivateKey::try_from(self.private_key)?;
+
+        match private_key
+            .parameters
+            .and_then(|params| params.named_curve())
+        {
+            Some(oid) if oid != curve => return Err(Error::ParametersMalformed),
+            Some(_) => (),
+            None => private_key.parameters = Some(sec1::EcParameters::NamedCurve(curve)),
+        }
+
+        Ok(private_key)
+    }
+
+    /// Ensure the `algorithm` OID matches the expected value.
+    #[cfg(any(feature = "pkcs1", feature = "sec1"))]
+    fn check_algorithm(&self, expected: ObjectIdentifier) -> Result<()> {
+        if self.algorithm.oid == expected {
+            Ok(())
+        } else {
+            Err(Error::AlgorithmMismatch {
+                expected,
+                actual: self.algorithm.oid,
+            })
+        }
+    }
+
     /// Encrypt this private key using a symmetric encryption key derived
     /// from the provided password.
     ///
@@ -155,9 +227,9 @@ impl<'a> PrivateKeyInfo<'a> {
     /// [`LineEnding`].
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-    pub fn to_pem(&self, line_ending: LineEnding) -> Result<Zeroizing<String>> {
+    pub fn to_pem(&self, line_ending: LineEnding) -> Result<SecretPemString> {
         pem::encode_string(PEM_TYPE_LABEL, line_ending, self.to_der()?.as_ref())
-            .map(Zeroizing::new)
+            .map(SecretPemString::new)
             .map_err(Error::Pem)
     }
 }
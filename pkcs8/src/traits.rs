@@ -12,10 +12,10 @@ use {
     rand_core::{CryptoRng, RngCore},
 };
 
+#[cfg(feature = "pem")]
+use crate::{LineEnding, SecretPemString};
 #[cfg(feature = "std")]
 use std::path::Path;
-#[cfg(feature = "pem")]
-use {crate::LineEnding, alloc::string::String, zeroize::Zeroizing};
 
 /// Parse a private key object from a PKCS#8 encoded document.
 pub trait DecodePrivateKey: Sized {
@@ -114,7 +114,7 @@ pub trait EncodePrivateKey {
     /// Serialize this private key as PEM-encoded PKCS#8 with the given [`LineEnding`].
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-    fn to_pkcs8_pem(&self, line_ending: LineEnding) -> Result<Zeroizing<String>> {
+    fn to_pkcs8_pem(&self, line_ending: LineEnding) -> Result<SecretPemString> {
         self.to_pkcs8_der()?.to_pkcs8_pem(line_ending)
     }
 
@@ -127,7 +127,7 @@ pub trait EncodePrivateKey {
         rng: impl CryptoRng + RngCore,
         password: impl AsRef<[u8]>,
         line_ending: LineEnding,
-    ) -> Result<Zeroizing<String>> {
+    ) -> Result<SecretPemString> {
         self.to_pkcs8_encrypted_der(rng, password)?
             .to_pem(line_ending)
     }
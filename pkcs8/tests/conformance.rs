@@ -0,0 +1,31 @@
+//! Conformance tests using example keys
+
+use der::dev::{run, TestVector};
+use pkcs8::{PrivateKeyInfo, SubjectPublicKeyInfo};
+
+const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/examples");
+
+#[test]
+fn private_key_examples() {
+    // The v2 example contains `attributes`, which are not retained when re-encoding
+    let vectors = TestVector::load_examples(EXAMPLES_DIR, |name| {
+        name.contains("-priv") && !name.contains("pkcs8v2")
+    })
+    .unwrap();
+    assert!(!vectors.is_empty());
+    run::<PrivateKeyInfo<'_>>(&vectors).assert_success();
+}
+
+#[test]
+fn public_key_examples() {
+    let vectors = TestVector::load_examples(EXAMPLES_DIR, |name| name.contains("-pub")).unwrap();
+    assert!(!vectors.is_empty());
+    run::<SubjectPublicKeyInfo<'_>>(&vectors).assert_success();
+}
+
+#[test]
+fn private_key_samples() {
+    let vectors = TestVector::load_samples(|name| name == "ed25519-priv");
+    assert_eq!(vectors.len(), 1);
+    run::<PrivateKeyInfo<'_>>(&vectors).assert_success();
+}
@@ -155,6 +155,10 @@ fn decode_ed25519_encpriv_aes256_pbkdf2_sha256_pem() {
         pkcs8_doc.encrypted_private_key_info(),
         EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap()
     );
+    assert_eq!(
+        EncryptedPrivateKeyInfo::from(&pkcs8_doc),
+        pkcs8_doc.encrypted_private_key_info()
+    );
 }
 
 #[cfg(feature = "encryption")]
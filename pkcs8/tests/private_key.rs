@@ -121,6 +121,72 @@ fn decode_x25519_der() {
     );
 }
 
+#[test]
+#[cfg(feature = "pkcs1")]
+fn try_into_rsa() {
+    let pk = PrivateKeyInfo::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let rsa_key = pk.clone().try_into_rsa().unwrap();
+    assert_eq!(rsa_key.public_exponent.as_bytes(), hex!("010001"));
+    assert!(rsa_key
+        .modulus
+        .as_bytes()
+        .starts_with(&hex!("B6C42C515F10A6AA")));
+
+    // Parameters must be `NULL` if present
+    let mut pk_with_params = pk;
+    pk_with_params.algorithm.parameters = PrivateKeyInfo::try_from(EC_P256_DER_EXAMPLE)
+        .unwrap()
+        .algorithm
+        .parameters;
+
+    assert_eq!(
+        pk_with_params.try_into_rsa().err().unwrap(),
+        pkcs8::Error::ParametersMalformed
+    );
+
+    let ec_pk = PrivateKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        ec_pk.try_into_rsa().err().unwrap(),
+        pkcs8::Error::AlgorithmMismatch {
+            expected: "1.2.840.113549.1.1.1".parse().unwrap(),
+            actual: "1.2.840.10045.2.1".parse().unwrap(),
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "sec1")]
+fn try_into_ec() {
+    let pk = PrivateKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let ec_key = pk.clone().try_into_ec().unwrap();
+    assert_eq!(
+        ec_key.private_key,
+        hex!("69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D")
+    );
+
+    // `namedCurve` is populated from the `AlgorithmIdentifier`
+    assert_eq!(
+        ec_key.parameters.and_then(|params| params.named_curve()),
+        Some("1.2.840.10045.3.1.7".parse().unwrap())
+    );
+
+    // `namedCurve` parameters are mandatory
+    let mut pk_without_params = pk;
+    pk_without_params.algorithm.parameters = None;
+    assert_eq!(
+        pk_without_params.try_into_ec().err().unwrap(),
+        pkcs8::Error::ParametersMalformed
+    );
+
+    for der in &[RSA_2048_DER_EXAMPLE, ED25519_DER_V1_EXAMPLE] {
+        let pk = PrivateKeyInfo::try_from(*der).unwrap();
+        assert!(matches!(
+            pk.try_into_ec(),
+            Err(pkcs8::Error::AlgorithmMismatch { .. })
+        ));
+    }
+}
+
 #[test]
 #[cfg(feature = "pem")]
 fn decode_ec_p256_pem() {
@@ -130,6 +196,10 @@ fn decode_ec_p256_pem() {
     // Ensure `PrivateKeyDocument` parses successfully
     let pk_info = PrivateKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
     assert_eq!(pkcs8_doc.private_key_info().algorithm, pk_info.algorithm);
+    assert_eq!(
+        PrivateKeyInfo::from(&pkcs8_doc).algorithm,
+        pk_info.algorithm
+    );
 }
 
 #[test]
@@ -0,0 +1,159 @@
+//! Hazardous materials: raw, unvalidated SEC1 structures.
+//!
+//! The types in this module allow assembling and encoding elliptic curve
+//! private keys with arbitrary contents, bypassing all of the validation
+//! performed by [`EcPrivateKey`]. This includes e.g. an unsupported
+//! `version`, a non-canonical `INTEGER` encoding of it, malformed curve
+//! parameters, or a public key with unused bits.
+//!
+//! **WARNING:** these types are intended for negative testing of other
+//! implementations, e.g. checking that they reject malformed keys. Keys
+//! produced this way are generally invalid and MUST NOT be used for any
+//! cryptographic operation.
+
+use crate::EcPrivateKey;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use der::{asn1::Any, Decodable, Decoder, Encodable, Sequence, Tag, TagNumber};
+use zeroize::Zeroize;
+
+/// Tag of the `parameters` field.
+const EC_PARAMETERS_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(0),
+};
+
+/// Tag of the `publicKey` field.
+const PUBLIC_KEY_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(1),
+};
+
+/// Raw `ECPrivateKey` whose fields are unvalidated contents octets.
+///
+/// Converting from an [`EcPrivateKey`] produces the same encoding as the
+/// original key, which makes it convenient to start from a valid key and
+/// then alter a single field.
+///
+/// The `private_key` field is zeroized on drop.
+#[derive(Clone, Eq, PartialEq)]
+pub struct RawEcPrivateKey {
+    /// `version` `INTEGER` contents octets.
+    pub version: Vec<u8>,
+
+    /// `privateKey` `OCTET STRING` contents octets.
+    pub private_key: Vec<u8>,
+
+    /// `parameters` contents octets, i.e. the DER encoding of the
+    /// `ECParameters` it explicitly tags. Omitted when `None`.
+    pub parameters: Option<Vec<u8>>,
+
+    /// `publicKey` `BIT STRING` contents octets, i.e. the number of unused
+    /// bits followed by the encoded point. Omitted when `None`.
+    pub public_key: Option<Vec<u8>>,
+}
+
+der::debug_redacted!(RawEcPrivateKey {
+    version, parameters, public_key;
+    redact private_key
+});
+
+impl Zeroize for RawEcPrivateKey {
+    fn zeroize(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+impl Drop for RawEcPrivateKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl TryFrom<&EcPrivateKey<'_>> for RawEcPrivateKey {
+    type Error = der::Error;
+
+    fn try_from(private_key: &EcPrivateKey<'_>) -> der::Result<RawEcPrivateKey> {
+        let public_key = private_key.public_key.map(|public_key| {
+            let mut contents = Vec::with_capacity(public_key.len() + 1);
+            contents.push(0);
+            contents.extend_from_slice(public_key);
+            contents
+        });
+
+        Ok(RawEcPrivateKey {
+            version: alloc::vec![1],
+            private_key: private_key.private_key.to_vec(),
+            parameters: private_key
+                .parameters
+                .map(|params| params.to_vec())
+                .transpose()?,
+            public_key,
+        })
+    }
+}
+
+impl<'a> Decodable<'a> for RawEcPrivateKey {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            let version = contents(decoder, Tag::Integer)?;
+            let private_key = contents(decoder, Tag::OctetString)?;
+
+            let parameters = if decoder.peek() == Some(EC_PARAMETERS_TAG.into()) {
+                Some(contents(decoder, EC_PARAMETERS_TAG)?)
+            } else {
+                None
+            };
+
+            let public_key = if decoder.peek() == Some(PUBLIC_KEY_TAG.into()) {
+                let bit_string = Any::from_der(decoder.any()?.value())?;
+                bit_string.tag().assert_eq(Tag::BitString)?;
+                Some(bit_string.value().to_vec())
+            } else {
+                None
+            };
+
+            Ok(Self {
+                version,
+                private_key,
+                parameters,
+                public_key,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RawEcPrivateKey {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> der::Result<T>,
+    {
+        let public_key = self
+            .public_key
+            .as_ref()
+            .map(|public_key| Any::new(Tag::BitString, public_key)?.to_vec())
+            .transpose()?;
+
+        f(&[
+            &Any::new(Tag::Integer, &self.version)?,
+            &Any::new(Tag::OctetString, &self.private_key)?,
+            &self
+                .parameters
+                .as_ref()
+                .map(|params| Any::new(EC_PARAMETERS_TAG, params))
+                .transpose()?,
+            &public_key
+                .as_ref()
+                .map(|public_key| Any::new(PUBLIC_KEY_TAG, public_key))
+                .transpose()?,
+        ])
+    }
+}
+
+/// Decode the contents octets of a value with the given tag without
+/// validating them.
+fn contents(decoder: &mut Decoder<'_>, tag: Tag) -> der::Result<Vec<u8>> {
+    let any = decoder.any()?;
+    any.tag().assert_eq(tag)?;
+    Ok(any.value().to_vec())
+}
@@ -16,7 +16,12 @@
     html_root_url = "https://docs.rs/sec1/0.2.0-pre"
 )]
 #![forbid(unsafe_code, clippy::unwrap_used)]
-#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+#![warn(
+    missing_debug_implementations,
+    missing_docs,
+    rust_2018_idioms,
+    unused_qualifications
+)]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -25,9 +30,22 @@ extern crate std;
 
 pub mod point;
 
+#[cfg(feature = "hazmat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hazmat")))]
+pub mod hazmat;
+
+#[cfg(feature = "pkcs11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+pub mod pkcs11;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+
 mod error;
 mod parameters;
 mod private_key;
+mod summary;
 mod traits;
 
 pub use der;
@@ -35,16 +53,21 @@ pub use der;
 pub use self::{
     error::{Error, Result},
     parameters::EcParameters,
-    point::EncodedPoint,
+    point::{EncodedPoint, PointEncoding},
     private_key::EcPrivateKey,
+    summary::EcKeySummary,
     traits::DecodeEcPrivateKey,
 };
 
-pub use generic_array::typenum::consts;
+pub use der::{consts, generic_array};
 
 #[cfg(feature = "alloc")]
 pub use crate::{private_key::document::EcPrivateKeyDocument, traits::EncodeEcPrivateKey};
 
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-pub use der::pem::{self, LineEnding};
+pub use der::pem::{self, LineEnding, SecretPemString};
+
+#[cfg(feature = "spki")]
+#[cfg_attr(docsrs, doc(cfg(feature = "spki")))]
+pub use spki;
@@ -17,6 +17,12 @@ use der::{
 ///   -- Any future additions to this CHOICE should be coordinated
 ///   -- with ANSI X9.
 /// ```
+///
+/// Only `namedCurve` is supported: `implicitCurve` and `specifiedCurve`
+/// parameters are rejected when decoding rather than being re-encoded
+/// differently, since signatures over structures containing explicit
+/// parameters (e.g. the TBS portion of a certificate) can only be verified
+/// if they round-trip byte-for-byte.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum EcParameters {
     /// Elliptic curve named by a particular OID.
@@ -0,0 +1,132 @@
+//! PKCS#11 object templates for elliptic curve keys.
+//!
+//! The functions in this module map elliptic curve keys onto the attributes
+//! which `C_CreateObject` expects when importing them into a PKCS#11 token,
+//! as described in [PKCS #11 Current Mechanisms Specification] Section 2.3.
+//! The templates contain the object class, the key type and the key
+//! components, and can be extended with further attributes such as
+//! `CKA_TOKEN`, `CKA_LABEL` or `CKA_ID` before use.
+//!
+//! [PKCS #11 Current Mechanisms Specification]: https://docs.oasis-open.org/pkcs11/pkcs11-curr/v2.40/pkcs11-curr-v2.40.html
+
+use crate::{EcParameters, EcPrivateKey, Result};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use der::{asn1::OctetString, Encodable, ErrorKind};
+use zeroize::Zeroize;
+
+/// `CKA_CLASS` attribute type.
+pub const CKA_CLASS: u64 = 0x0000;
+
+/// `CKA_VALUE` attribute type.
+pub const CKA_VALUE: u64 = 0x0011;
+
+/// `CKA_KEY_TYPE` attribute type.
+pub const CKA_KEY_TYPE: u64 = 0x0100;
+
+/// `CKA_EC_PARAMS` attribute type.
+pub const CKA_EC_PARAMS: u64 = 0x0180;
+
+/// `CKA_EC_POINT` attribute type.
+pub const CKA_EC_POINT: u64 = 0x0181;
+
+/// `CKO_PUBLIC_KEY` object class.
+pub const CKO_PUBLIC_KEY: u64 = 0x0002;
+
+/// `CKO_PRIVATE_KEY` object class.
+pub const CKO_PRIVATE_KEY: u64 = 0x0003;
+
+/// `CKK_EC` key type.
+pub const CKK_EC: u64 = 0x0003;
+
+/// PKCS#11 attribute (`CK_ATTRIBUTE`) of an object template.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attribute {
+    /// Attribute type (`CK_ATTRIBUTE_TYPE`).
+    pub attribute_type: u64,
+
+    /// Attribute value.
+    pub value: AttributeValue,
+}
+
+impl Attribute {
+    /// Create a `CK_ULONG` attribute.
+    pub fn ulong(attribute_type: u64, value: u64) -> Self {
+        Self {
+            attribute_type,
+            value: AttributeValue::Ulong(value),
+        }
+    }
+
+    /// Create a byte array attribute.
+    pub fn bytes(attribute_type: u64, value: impl Into<Vec<u8>>) -> Self {
+        Self {
+            attribute_type,
+            value: AttributeValue::Bytes(value.into()),
+        }
+    }
+}
+
+/// Value of an [`Attribute`].
+#[derive(Clone, Eq, PartialEq)]
+pub enum AttributeValue {
+    /// `CK_ULONG` value, e.g. an object class or key type.
+    ///
+    /// PKCS#11 expects it in the native byte order and width of `CK_ULONG`,
+    /// which is 32-bit on some platforms, so it must be converted first.
+    Ulong(u64),
+
+    /// Byte array value, e.g. a private scalar or DER encoding.
+    ///
+    /// This is zeroized on drop as it may contain secret key material.
+    Bytes(Vec<u8>),
+}
+
+impl fmt::Debug for AttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttributeValue::Ulong(value) => f.debug_tuple("Ulong").field(value).finish(),
+            AttributeValue::Bytes(_) => f.debug_tuple("Bytes").field(&der::Redacted).finish(),
+        }
+    }
+}
+
+impl Drop for AttributeValue {
+    fn drop(&mut self) {
+        if let AttributeValue::Bytes(bytes) = self {
+            bytes.zeroize();
+        }
+    }
+}
+
+/// Get the `CKO_PRIVATE_KEY` template of an elliptic curve private key.
+///
+/// `CKA_EC_PARAMS` is the DER encoding of the key's `parameters`, which
+/// must therefore be present, and `CKA_VALUE` is its private scalar.
+pub fn private_key_template(private_key: &EcPrivateKey<'_>) -> Result<Vec<Attribute>> {
+    let parameters = private_key
+        .parameters
+        .ok_or_else(|| der::Error::from(ErrorKind::Truncated))?;
+
+    Ok(vec![
+        Attribute::ulong(CKA_CLASS, CKO_PRIVATE_KEY),
+        Attribute::ulong(CKA_KEY_TYPE, CKK_EC),
+        Attribute::bytes(CKA_EC_PARAMS, parameters.to_vec()?),
+        Attribute::bytes(CKA_VALUE, private_key.private_key),
+    ])
+}
+
+/// Get the `CKO_PUBLIC_KEY` template of an elliptic curve public key, given
+/// its curve `parameters` and its `Elliptic-Curve-Point-to-Octet-String`
+/// encoded point, e.g. the `public_key` of an [`EcPrivateKey`].
+///
+/// `CKA_EC_POINT` is the point wrapped in a DER `OCTET STRING`, as required
+/// by PKCS#11.
+pub fn public_key_template(parameters: EcParameters, public_key: &[u8]) -> Result<Vec<Attribute>> {
+    Ok(vec![
+        Attribute::ulong(CKA_CLASS, CKO_PUBLIC_KEY),
+        Attribute::ulong(CKA_KEY_TYPE, CKK_EC),
+        Attribute::bytes(CKA_EC_PARAMS, parameters.to_vec()?),
+        Attribute::bytes(CKA_EC_POINT, OctetString::new(public_key)?.to_vec()?),
+    ])
+}
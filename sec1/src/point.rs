@@ -8,10 +8,11 @@
 use crate::{Error, Result};
 use core::{
     cmp::Ordering,
+    convert::TryFrom,
     fmt::{self, Debug},
     ops::Add,
 };
-use generic_array::{
+use der::generic_array::{
     typenum::U1,
     typenum::{U28, U32, U48, U66},
     ArrayLength, GenericArray,
@@ -20,6 +21,18 @@ use generic_array::{
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
+#[cfg(feature = "serde")]
+use serde_crate::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "spki")]
+use {
+    der::{
+        asn1::{Any, ObjectIdentifier},
+        ErrorKind,
+    },
+    spki::DecodeSubjectPublicKey,
+};
+
 #[cfg(feature = "subtle")]
 use subtle::{Choice, ConditionallySelectable};
 
@@ -91,6 +104,11 @@ where
             .ok_or(Error::PointEncoding)
             .and_then(Tag::from_u8)?;
 
+        // Hybrid points must be converted using `PointEncoding::encode`
+        if tag.is_hybrid() {
+            return Err(Error::PointEncoding);
+        }
+
         // Validate length
         let expected_len = tag.message_len(Size::to_usize());
 
@@ -309,6 +327,67 @@ where
     }
 }
 
+impl<Size> TryFrom<&[u8]> for EncodedPoint<Size>
+where
+    Size: ModulusSize,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes(bytes)
+    }
+}
+
+/// The algorithm `parameters` must be a `namedCurve` OID. Which curve it
+/// names isn't checked, as `EncodedPoint` is only specific to a modulus size.
+#[cfg(feature = "spki")]
+#[cfg_attr(docsrs, doc(cfg(feature = "spki")))]
+impl<'a, Size> DecodeSubjectPublicKey<'a> for EncodedPoint<Size>
+where
+    Size: ModulusSize,
+{
+    /// `id-ecPublicKey` OID.
+    const ALGORITHM_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.2.1");
+
+    fn decode_subject_public_key(
+        parameters: Option<Any<'a>>,
+        subject_public_key: &'a [u8],
+    ) -> der::Result<Self> {
+        parameters.ok_or(ErrorKind::Truncated)?.oid()?;
+        Self::from_bytes(subject_public_key).map_err(|_| der::Tag::BitString.value_error())
+    }
+}
+
+/// Serialized as a hexadecimal string by human-readable formats, and as raw
+/// bytes otherwise. See [`crate::serde`] for other representations.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<Size> Serialize for EncodedPoint<Size>
+where
+    Size: ModulusSize,
+{
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::serde::hex::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, Size> Deserialize<'de> for EncodedPoint<Size>
+where
+    Size: ModulusSize,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::serde::hex::deserialize(deserializer)
+    }
+}
+
 #[cfg(feature = "zeroize")]
 impl<Size> Zeroize for EncodedPoint<Size>
 where
@@ -370,6 +449,84 @@ impl<'a, Size: ModulusSize> Coordinates<'a, Size> {
     }
 }
 
+/// Encoding to use when serializing an elliptic curve point.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PointEncoding {
+    /// Compressed point: `0x02` or `0x03` tag byte followed by the
+    /// x-coordinate.
+    Compressed,
+
+    /// Uncompressed point: `0x04` tag byte followed by the x-coordinate and
+    /// y-coordinate.
+    Uncompressed,
+
+    /// Hybrid point: `0x06` or `0x07` tag byte (indicating the parity of the
+    /// y-coordinate) followed by the x-coordinate and y-coordinate.
+    Hybrid,
+}
+
+impl PointEncoding {
+    /// Re-encode the serialized point `input` (in any of the compressed,
+    /// uncompressed, or hybrid encodings) using this encoding, writing the
+    /// result into `out`.
+    ///
+    /// The identity point is left unchanged. Compressed points can only be
+    /// output in compressed form, since recovering the y-coordinate requires
+    /// curve arithmetic this crate does not implement.
+    ///
+    /// The output is never longer than the input.
+    pub fn encode<'o>(self, input: &[u8], out: &'o mut [u8]) -> Result<&'o [u8]> {
+        let (&tag, body) = input.split_first().ok_or(Error::PointEncoding)?;
+
+        let tag = Tag::try_from(tag)?;
+
+        let (x, y) = match tag {
+            Tag::Identity if body.is_empty() => (body, None),
+            Tag::CompressedEvenY | Tag::CompressedOddY if !body.is_empty() => (body, None),
+            Tag::Uncompressed | Tag::HybridEvenY | Tag::HybridOddY => {
+                let (x, y) = body.split_at(body.len() / 2);
+
+                if x.is_empty() || x.len() != y.len() {
+                    return Err(Error::PointEncoding);
+                }
+
+                // Hybrid tags must agree with the parity of the y-coordinate
+                if tag.is_hybrid() && Tag::compress_y(y) as u8 & 1 != tag as u8 & 1 {
+                    return Err(Error::PointEncoding);
+                }
+
+                (x, Some(y))
+            }
+            _ => return Err(Error::PointEncoding),
+        };
+
+        let (tag, y) = match (tag, y, self) {
+            (Tag::Identity, _, _) => (Tag::Identity, None),
+            (_, None, PointEncoding::Compressed) => (tag, None),
+            (_, None, _) => return Err(Error::PointEncoding),
+            (_, Some(y), PointEncoding::Compressed) => (Tag::compress_y(y), None),
+            (_, Some(y), PointEncoding::Uncompressed) => (Tag::Uncompressed, Some(y)),
+            (_, Some(y), PointEncoding::Hybrid) => (Tag::hybrid_y(y), Some(y)),
+        };
+
+        let len = 1 + x.len() + y.map(<[u8]>::len).unwrap_or_default();
+        let out = out.get_mut(..len).ok_or(Error::PointEncoding)?;
+        let (out_tag, out_body) = out.split_at_mut(1);
+        out_tag[0] = tag.into();
+
+        if !tag.is_identity() {
+            let (out_x, out_y) = out_body.split_at_mut(x.len());
+            out_x.copy_from_slice(x);
+
+            if let Some(y) = y {
+                out_y.copy_from_slice(y);
+            }
+        }
+
+        Ok(out)
+    }
+}
+
 /// Tag byte used by the `Elliptic-Curve-Point-to-Octet-String` encoding.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u8)]
@@ -388,6 +545,12 @@ pub enum Tag {
 
     /// Compact point (`0x05`)
     Compact = 5,
+
+    /// Hybrid point with even y-coordinate (`0x06`)
+    HybridEvenY = 6,
+
+    /// Hybrid point with odd y-coordinate (`0x07`)
+    HybridOddY = 7,
 }
 
 impl Tag {
@@ -399,6 +562,8 @@ impl Tag {
             3 => Ok(Tag::CompressedOddY),
             4 => Ok(Tag::Uncompressed),
             5 => Ok(Tag::Compact),
+            6 => Ok(Tag::HybridEvenY),
+            7 => Ok(Tag::HybridOddY),
             _ => Err(Error::PointEncoding),
         }
     }
@@ -413,6 +578,11 @@ impl Tag {
         matches!(self, Tag::CompressedEvenY | Tag::CompressedOddY)
     }
 
+    /// Is this point hybrid?
+    pub fn is_hybrid(self) -> bool {
+        matches!(self, Tag::HybridEvenY | Tag::HybridOddY)
+    }
+
     /// Is this point the identity point?
     pub fn is_identity(self) -> bool {
         self == Tag::Identity
@@ -427,6 +597,7 @@ impl Tag {
             Tag::CompressedEvenY | Tag::CompressedOddY => field_element_size,
             Tag::Uncompressed => field_element_size * 2,
             Tag::Compact => field_element_size,
+            Tag::HybridEvenY | Tag::HybridOddY => field_element_size * 2,
         }
     }
 
@@ -439,6 +610,23 @@ impl Tag {
             Tag::CompressedEvenY
         }
     }
+
+    /// Compute the hybrid tag for the given y-coordinate, returning a
+    /// `Tag::Hybrid*` value
+    fn hybrid_y(y: &[u8]) -> Self {
+        match Tag::compress_y(y) {
+            Tag::CompressedOddY => Tag::HybridOddY,
+            _ => Tag::HybridEvenY,
+        }
+    }
+}
+
+impl TryFrom<u8> for Tag {
+    type Error = Error;
+
+    fn try_from(byte: u8) -> Result<Self> {
+        Self::from_u8(byte)
+    }
 }
 
 impl From<Tag> for u8 {
@@ -449,8 +637,9 @@ impl From<Tag> for u8 {
 
 #[cfg(test)]
 mod tests {
-    use super::{Coordinates, Tag};
-    use generic_array::{typenum::U32, GenericArray};
+    use super::{Coordinates, PointEncoding, Tag};
+    use core::convert::TryFrom;
+    use der::generic_array::{typenum::U32, GenericArray};
     use hex_literal::hex;
 
     #[cfg(feature = "subtle")]
@@ -579,6 +768,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn tag_try_from_u8() {
+        for byte in 0..=0xFF {
+            match Tag::try_from(byte) {
+                Ok(tag) => assert_eq!(u8::from(tag), byte),
+                Err(_) => assert!(!matches!(byte, 0 | 2..=7)),
+            }
+        }
+
+        assert_eq!(Tag::try_from(0x06).unwrap(), Tag::HybridEvenY);
+        assert_eq!(Tag::try_from(0x07).unwrap(), Tag::HybridOddY);
+        assert!(Tag::HybridOddY.is_hybrid());
+        assert!(!Tag::HybridOddY.is_compressed());
+        assert_eq!(Tag::HybridEvenY.message_len(32), 65);
+    }
+
     #[test]
     fn decode_truncated_point() {
         for bytes in &[&COMPRESSED_BYTES[..], &UNCOMPRESSED_BYTES[..]] {
@@ -616,6 +821,49 @@ mod tests {
         assert_eq!(compressed_point.as_bytes(), &COMPRESSED_BYTES[..]);
     }
 
+    #[test]
+    fn point_encoding() {
+        let mut buf = [0u8; 65];
+        let hybrid = PointEncoding::Hybrid
+            .encode(&UNCOMPRESSED_BYTES, &mut buf)
+            .unwrap()
+            .to_vec();
+        assert_eq!(hybrid[0], 0x06);
+        assert_eq!(&hybrid[1..], &UNCOMPRESSED_BYTES[1..]);
+
+        for input in [&UNCOMPRESSED_BYTES[..], &hybrid, &COMPRESSED_BYTES] {
+            assert_eq!(
+                PointEncoding::Compressed.encode(input, &mut buf).unwrap(),
+                &COMPRESSED_BYTES[..]
+            );
+        }
+
+        assert_eq!(
+            PointEncoding::Uncompressed
+                .encode(&hybrid, &mut buf)
+                .unwrap(),
+            &UNCOMPRESSED_BYTES[..]
+        );
+        assert_eq!(
+            PointEncoding::Uncompressed
+                .encode(&IDENTITY_BYTES, &mut buf)
+                .unwrap(),
+            &IDENTITY_BYTES[..]
+        );
+
+        // Decompression is unsupported
+        assert!(PointEncoding::Uncompressed
+            .encode(&COMPRESSED_BYTES, &mut buf)
+            .is_err());
+
+        // Hybrid tag must match the parity of the y-coordinate
+        let mut bad_hybrid = hybrid.clone();
+        bad_hybrid[0] = 0x07;
+        assert!(PointEncoding::Compressed
+            .encode(&bad_hybrid, &mut buf)
+            .is_err());
+    }
+
     #[cfg(feature = "subtle")]
     #[test]
     fn conditional_select() {
@@ -8,11 +8,11 @@
 #[cfg(feature = "alloc")]
 pub(crate) mod document;
 
-use crate::{EcParameters, Error};
-use core::{convert::TryFrom, fmt};
+use crate::{EcKeySummary, EcParameters, Error};
+use core::convert::TryFrom;
 use der::{
-    asn1::{BitString, ContextSpecific, OctetString},
-    Decodable, Decoder, Encodable, Sequence, TagMode, TagNumber,
+    asn1::{BitString, ContextSpecific, ObjectIdentifier, OctetString},
+    Decodable, Decoder, Encodable, Header, Sequence, TagMode, TagNumber,
 };
 
 /// Type label for PEM-encoded private keys.
@@ -70,6 +70,47 @@ pub struct EcPrivateKey<'a> {
     pub public_key: Option<&'a [u8]>,
 }
 
+impl<'a> EcPrivateKey<'a> {
+    /// Get the maximum length of the DER encoding of an [`EcPrivateKey`]
+    /// for an elliptic curve whose base field elements are `BITS` bits.
+    ///
+    /// This allows sizing a buffer at compile time for the largest curve an
+    /// application supports:
+    ///
+    /// ```
+    /// use sec1::EcPrivateKey;
+    ///
+    /// // Large enough for any NIST P-521 key
+    /// const MAX_KEY_LEN: usize = EcPrivateKey::max_der_len::<521>();
+    ///
+    /// let mut buf = [0u8; MAX_KEY_LEN];
+    /// # let _ = &mut buf;
+    /// ```
+    ///
+    /// This includes the optional `parameters` (with an OID of any length)
+    /// and `publicKey` (as an uncompressed point).
+    pub const fn max_der_len<const BITS: usize>() -> usize {
+        let field_len = (BITS + 7) / 8;
+        let version = Header::tlv_len(1);
+        let private_key = Header::tlv_len(field_len);
+        let parameters = Header::tlv_len(Header::tlv_len(ObjectIdentifier::MAX_LENGTH));
+
+        // Unused bits octet, followed by an uncompressed point
+        let public_key = Header::tlv_len(Header::tlv_len(1 + 1 + 2 * field_len));
+
+        Header::tlv_len(version + private_key + parameters + public_key)
+    }
+
+    /// Get a summary of the non-secret metadata of this key: its named
+    /// curve, and whether and how its public key is included.
+    pub fn summary(&self) -> EcKeySummary {
+        EcKeySummary::new(
+            self.parameters.and_then(EcParameters::named_curve),
+            self.public_key,
+        )
+    }
+}
+
 impl<'a> Decodable<'a> for EcPrivateKey<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
         decoder.sequence(|decoder| {
@@ -127,11 +168,7 @@ impl<'a> TryFrom<&'a [u8]> for EcPrivateKey<'a> {
     }
 }
 
-impl<'a> fmt::Debug for EcPrivateKey<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("EcPrivateKey")
-            .field("parameters", &self.parameters)
-            .field("public_key", &self.public_key)
-            .finish() // TODO: use `finish_non_exhaustive` when stable
-    }
-}
+der::debug_redacted!(EcPrivateKey<'a> {
+    parameters, public_key;
+    redact private_key
+});
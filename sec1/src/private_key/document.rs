@@ -1,6 +1,8 @@
 //! SEC1 EC private key document.
 
-use crate::{DecodeEcPrivateKey, EcPrivateKey, EncodeEcPrivateKey, Error, Result};
+use crate::{
+    point::PointEncoding, DecodeEcPrivateKey, EcPrivateKey, EncodeEcPrivateKey, Error, Result,
+};
 use alloc::{borrow::ToOwned, vec::Vec};
 use core::{convert::TryFrom, fmt};
 use der::{Decodable, Encodable};
@@ -8,8 +10,7 @@ use zeroize::{Zeroize, Zeroizing};
 
 #[cfg(feature = "pem")]
 use {
-    crate::{pem, private_key::PEM_TYPE_LABEL, LineEnding},
-    alloc::string::String,
+    crate::{pem, private_key::PEM_TYPE_LABEL, LineEnding, SecretPemString},
     core::str::FromStr,
 };
 
@@ -24,19 +25,58 @@ use {
 /// This type provides storage for [`EcPrivateKey`] encoded as ASN.1 DER
 /// with the invariant that the contained-document is "well-formed", i.e. it
 /// will parse successfully according to this crate's parsing rules.
+///
+/// When the `arc` feature is enabled, the document is stored behind a
+/// reference count, so clones are cheap and share the same allocation.
 #[derive(Clone)]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub struct EcPrivateKeyDocument(Zeroizing<Vec<u8>>);
+pub struct EcPrivateKeyDocument(Storage);
+
+/// Storage for the DER-encoded document.
+#[cfg(not(feature = "arc"))]
+type Storage = Zeroizing<Vec<u8>>;
+
+/// Storage for the DER-encoded document, shared between clones.
+#[cfg(feature = "arc")]
+type Storage = alloc::sync::Arc<Zeroizing<Vec<u8>>>;
+
+/// Move the DER-encoded document into [`Storage`].
+#[cfg(not(feature = "arc"))]
+fn storage(bytes: Zeroizing<Vec<u8>>) -> Storage {
+    bytes
+}
+
+/// Move the DER-encoded document into [`Storage`].
+#[cfg(feature = "arc")]
+fn storage(bytes: Zeroizing<Vec<u8>>) -> Storage {
+    bytes.into()
+}
 
 impl EcPrivateKeyDocument {
     /// Parse the [`EcPrivateKey`] contained in this [`EcPrivateKeyDocument`]
     pub fn private_key(&self) -> EcPrivateKey<'_> {
-        EcPrivateKey::from_der(self.0.as_ref()).expect("malformed EcPrivateKeyDocument")
+        EcPrivateKey::from_der(self.0.as_slice()).expect("malformed EcPrivateKeyDocument")
     }
 
     /// Borrow the inner DER encoded bytes.
     pub fn as_der(&self) -> &[u8] {
-        self.0.as_ref()
+        self.0.as_slice()
+    }
+
+    /// Re-encode this document, serializing the public key (if present)
+    /// using the given [`PointEncoding`].
+    ///
+    /// See [`PointEncoding::encode`] for which conversions are supported.
+    pub fn with_point_encoding(&self, encoding: PointEncoding) -> Result<Self> {
+        let mut private_key = self.private_key();
+        let mut buf = Vec::new();
+
+        if let Some(public_key) = private_key.public_key {
+            buf.resize(public_key.len(), 0);
+            private_key.public_key = Some(encoding.encode(public_key, &mut buf)?);
+        }
+
+        Self::try_from(private_key)
     }
 }
 
@@ -44,7 +84,7 @@ impl DecodeEcPrivateKey for EcPrivateKeyDocument {
     fn from_sec1_der(bytes: &[u8]) -> Result<Self> {
         // Ensure document is well-formed
         EcPrivateKey::from_der(bytes)?;
-        Ok(Self(Zeroizing::new(bytes.to_owned())))
+        Ok(Self(storage(Zeroizing::new(bytes.to_owned()))))
     }
 
     #[cfg(feature = "pem")]
@@ -53,12 +93,12 @@ impl DecodeEcPrivateKey for EcPrivateKeyDocument {
         let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
 
         if label != PEM_TYPE_LABEL {
-            return Err(pem::Error::Label.into());
+            return Err(pem::Error::unexpected_type_label(PEM_TYPE_LABEL, label).into());
         }
 
         // Ensure document is well-formed
         EcPrivateKey::from_der(der_bytes.as_slice())?;
-        Ok(Self(Zeroizing::new(der_bytes)))
+        Ok(Self(storage(Zeroizing::new(der_bytes))))
     }
 
     #[cfg(feature = "std")]
@@ -82,9 +122,9 @@ impl EncodeEcPrivateKey for EcPrivateKeyDocument {
 
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-    fn to_sec1_pem(&self, line_ending: LineEnding) -> Result<Zeroizing<String>> {
+    fn to_sec1_pem(&self, line_ending: LineEnding) -> Result<SecretPemString> {
         let pem_doc = pem::encode_string(PEM_TYPE_LABEL, line_ending, self.as_der())?;
-        Ok(Zeroizing::new(pem_doc))
+        Ok(SecretPemString::new(pem_doc))
     }
 
     #[cfg(feature = "std")]
@@ -116,6 +156,12 @@ impl TryFrom<&[u8]> for EcPrivateKeyDocument {
     }
 }
 
+impl<'a> From<&'a EcPrivateKeyDocument> for EcPrivateKey<'a> {
+    fn from(doc: &'a EcPrivateKeyDocument) -> EcPrivateKey<'a> {
+        doc.private_key()
+    }
+}
+
 impl TryFrom<EcPrivateKey<'_>> for EcPrivateKeyDocument {
     type Error = Error;
 
@@ -128,7 +174,7 @@ impl TryFrom<&EcPrivateKey<'_>> for EcPrivateKeyDocument {
     type Error = Error;
 
     fn try_from(private_key: &EcPrivateKey<'_>) -> Result<Self> {
-        Ok(Self(Zeroizing::new(private_key.to_vec()?)))
+        Ok(Self(storage(Zeroizing::new(private_key.to_vec()?))))
     }
 }
 
@@ -142,7 +188,7 @@ impl TryFrom<Vec<u8>> for EcPrivateKeyDocument {
             return Err(err.into());
         }
 
-        Ok(Self(Zeroizing::new(bytes)))
+        Ok(Self(storage(Zeroizing::new(bytes))))
     }
 }
 
@@ -0,0 +1,222 @@
+//! [`serde`] adapters for choosing the textual representation of elliptic
+//! curve points and keys on a per-field basis.
+//!
+//! Use with `#[serde(with = "...")]` on any field whose type implements
+//! `AsRef<[u8]>` and `TryFrom<&[u8]>`, e.g. [`EncodedPoint`] or `[u8; 32]`:
+//!
+//! ```
+//! # use serde_crate as serde;
+//! use sec1::{consts::U32, EncodedPoint};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! # #[serde(crate = "serde_crate")]
+//! struct Config {
+//!     #[serde(with = "sec1::serde::hex")]
+//!     public_key: EncodedPoint<U32>,
+//!
+//!     #[serde(with = "sec1::serde::base64")]
+//!     secret_key: [u8; 32],
+//! }
+//! ```
+//!
+//! Human-readable formats (e.g. JSON or TOML) use the chosen string
+//! encoding, while binary formats serialize the raw bytes. Values longer
+//! than [`MAX_SIZE`] bytes are rejected when decoding from a string.
+//!
+//! [`serde`]: https://docs.rs/serde
+//! [`EncodedPoint`]: crate::EncodedPoint
+
+use core::{convert::TryFrom, fmt, marker::PhantomData};
+use serde_crate::{de, Deserializer, Serializer};
+
+/// Maximum size in bytes of a value decoded from a string: the size of an
+/// uncompressed P-521 point.
+pub const MAX_SIZE: usize = 133;
+
+/// Serialize and deserialize bytes as hexadecimal strings.
+///
+/// Uppercase is used for serialization; either case is accepted when
+/// deserializing.
+pub mod hex {
+    use super::{Encoding, MAX_SIZE};
+    use core::{convert::TryFrom, fmt};
+    use serde_crate::{Deserializer, Serializer};
+
+    /// Serialize `value` as a hexadecimal string.
+    pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]> + ?Sized,
+    {
+        super::serialize::<Hex, _>(value.as_ref(), serializer)
+    }
+
+    /// Deserialize a value from a hexadecimal string.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: for<'a> TryFrom<&'a [u8]>,
+    {
+        super::deserialize::<Hex, _, _>(deserializer)
+    }
+
+    /// Hexadecimal [`Encoding`].
+    pub(super) struct Hex;
+
+    impl Encoding for Hex {
+        const NAME: &'static str = "a hexadecimal string";
+
+        fn encode(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            bytes.iter().try_for_each(|byte| write!(f, "{:02X}", byte))
+        }
+
+        fn decode<'o>(s: &str, out: &'o mut [u8; MAX_SIZE]) -> Option<&'o [u8]> {
+            fn nibble(c: u8) -> Option<u8> {
+                match c {
+                    b'0'..=b'9' => Some(c - b'0'),
+                    b'a'..=b'f' => Some(c - b'a' + 10),
+                    b'A'..=b'F' => Some(c - b'A' + 10),
+                    _ => None,
+                }
+            }
+
+            let pairs = s.as_bytes().chunks_exact(2);
+
+            if !pairs.remainder().is_empty() || pairs.len() > MAX_SIZE {
+                return None;
+            }
+
+            let len = pairs.len();
+
+            for (byte, pair) in out.iter_mut().zip(pairs) {
+                *byte = nibble(pair[0])? << 4 | nibble(pair[1])?;
+            }
+
+            Some(&out[..len])
+        }
+    }
+}
+
+/// Serialize and deserialize bytes as padded standard Base64 strings
+/// ([RFC 4648 Section 4]), using the constant-time [`base64ct`] crate.
+///
+/// [RFC 4648 Section 4]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+/// [`base64ct`]: https://docs.rs/base64ct
+pub mod base64 {
+    use super::{Encoding, MAX_SIZE};
+    use base64ct::{Base64, Encoding as _};
+    use core::{convert::TryFrom, fmt};
+    use serde_crate::{Deserializer, Serializer};
+
+    /// Serialize `value` as a Base64 string.
+    pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]> + ?Sized,
+    {
+        super::serialize::<B64, _>(value.as_ref(), serializer)
+    }
+
+    /// Deserialize a value from a Base64 string.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: for<'a> TryFrom<&'a [u8]>,
+    {
+        super::deserialize::<B64, _, _>(deserializer)
+    }
+
+    /// Base64 [`Encoding`].
+    pub(super) struct B64;
+
+    impl Encoding for B64 {
+        const NAME: &'static str = "a Base64 string";
+
+        fn encode(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            // Chunks are a multiple of 3 bytes so only the last is padded
+            let mut buf = [0u8; 64];
+
+            for chunk in bytes.chunks(48) {
+                f.write_str(Base64::encode(chunk, &mut buf).map_err(|_| fmt::Error)?)?;
+            }
+
+            Ok(())
+        }
+
+        fn decode<'o>(s: &str, out: &'o mut [u8; MAX_SIZE]) -> Option<&'o [u8]> {
+            Base64::decode(s, out).ok()
+        }
+    }
+}
+
+/// Textual encoding of a byte string.
+trait Encoding {
+    /// Description used in error messages.
+    const NAME: &'static str;
+
+    /// Write the encoding of `bytes`.
+    fn encode(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Decode `s` into `out`, returning `None` if it is malformed or too long.
+    fn decode<'o>(s: &str, out: &'o mut [u8; MAX_SIZE]) -> Option<&'o [u8]>;
+}
+
+/// Serialize `bytes` with the encoding `E` if the format is human-readable.
+fn serialize<E: Encoding, S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    struct Display<'a, E>(&'a [u8], PhantomData<E>);
+
+    impl<E: Encoding> fmt::Display for Display<'_, E> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            E::encode(self.0, f)
+        }
+    }
+
+    if serializer.is_human_readable() {
+        serializer.collect_str(&Display::<E>(bytes, PhantomData))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Deserialize a `T` from a string with the encoding `E` if the format is
+/// human-readable, or from raw bytes otherwise.
+fn deserialize<'de, E, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    E: Encoding,
+    D: Deserializer<'de>,
+    T: for<'a> TryFrom<&'a [u8]>,
+{
+    struct Visitor<E, T>(PhantomData<(E, T)>);
+
+    impl<'de, E, T> de::Visitor<'de> for Visitor<E, T>
+    where
+        E: Encoding,
+        T: for<'a> TryFrom<&'a [u8]>,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(E::NAME)
+        }
+
+        fn visit_str<Err: de::Error>(self, s: &str) -> Result<T, Err> {
+            let mut buf = [0u8; MAX_SIZE];
+            let bytes = E::decode(s, &mut buf)
+                .ok_or_else(|| Err::invalid_value(de::Unexpected::Str(s), &self))?;
+            self.visit_bytes(bytes)
+        }
+
+        fn visit_bytes<Err: de::Error>(self, bytes: &[u8]) -> Result<T, Err> {
+            T::try_from(bytes).map_err(|_| Err::invalid_length(bytes.len(), &self))
+        }
+    }
+
+    let visitor = Visitor::<E, T>(PhantomData);
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_bytes(visitor)
+    }
+}
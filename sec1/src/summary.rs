@@ -0,0 +1,80 @@
+//! Non-secret elliptic curve key metadata.
+
+use crate::point::Tag;
+use core::fmt;
+use der::asn1::ObjectIdentifier;
+
+/// Names of well-known named curves.
+const CURVE_NAMES: &[(ObjectIdentifier, &str)] = &[
+    (ObjectIdentifier::new("1.2.840.10045.3.1.7"), "P-256"),
+    (ObjectIdentifier::new("1.3.132.0.34"), "P-384"),
+    (ObjectIdentifier::new("1.3.132.0.35"), "P-521"),
+    (ObjectIdentifier::new("1.3.132.0.10"), "secp256k1"),
+];
+
+/// Summary of the non-secret metadata of an elliptic curve private key,
+/// intended for logging and display purposes.
+///
+/// It contains no secret material. Its [`fmt::Display`] impl prints e.g.
+/// `EC P-256, public key present, compressed`, with the OID of curves
+/// other than P-256, P-384, P-521 and secp256k1.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct EcKeySummary {
+    /// Named curve, if the key's parameters are present.
+    pub curve: Option<ObjectIdentifier>,
+
+    /// Does the key include its public key?
+    pub public_key: bool,
+
+    /// Tag of the SEC1 encoding of the public key, or `None` if the key
+    /// doesn't include a public key or its tag is invalid.
+    pub point_tag: Option<Tag>,
+}
+
+impl EcKeySummary {
+    /// Create a new [`EcKeySummary`] from the curve and public key of a key.
+    pub(crate) fn new(curve: Option<ObjectIdentifier>, public_key: Option<&[u8]>) -> Self {
+        Self {
+            curve,
+            public_key: public_key.is_some(),
+            point_tag: public_key
+                .and_then(|point| point.first())
+                .and_then(|&tag| Tag::from_u8(tag).ok()),
+        }
+    }
+
+    /// Get the name of the curve, if it's a well-known named curve.
+    pub fn curve_name(&self) -> Option<&'static str> {
+        let curve = self.curve?;
+
+        CURVE_NAMES
+            .iter()
+            .find(|(oid, _)| *oid == curve)
+            .map(|&(_, name)| name)
+    }
+}
+
+impl fmt::Display for EcKeySummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.curve_name(), self.curve) {
+            (Some(name), _) => write!(f, "EC {}", name)?,
+            (None, Some(oid)) => write!(f, "EC {}", oid)?,
+            (None, None) => f.write_str("EC (unspecified curve)")?,
+        }
+
+        if !self.public_key {
+            return f.write_str(", no public key");
+        }
+
+        f.write_str(", public key present, ")?;
+
+        f.write_str(match self.point_tag {
+            Some(Tag::Identity) => "identity",
+            Some(Tag::CompressedEvenY) | Some(Tag::CompressedOddY) => "compressed",
+            Some(Tag::Uncompressed) => "uncompressed",
+            Some(Tag::Compact) => "compact",
+            Some(Tag::HybridEvenY) | Some(Tag::HybridOddY) => "hybrid",
+            None => "invalid encoding",
+        })
+    }
+}
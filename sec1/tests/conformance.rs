@@ -0,0 +1,20 @@
+//! Conformance tests using example keys
+
+use der::dev::{run, TestVector};
+use sec1::EcPrivateKey;
+
+const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/examples");
+
+#[test]
+fn private_key_examples() {
+    let vectors = TestVector::load_examples(EXAMPLES_DIR, |name| name.contains("-priv")).unwrap();
+    assert!(!vectors.is_empty());
+    run::<EcPrivateKey<'_>>(&vectors).assert_success();
+}
+
+#[test]
+fn private_key_samples() {
+    let vectors = TestVector::load_samples(|name| name == "p256-priv");
+    assert_eq!(vectors.len(), 1);
+    run::<EcPrivateKey<'_>>(&vectors).assert_success();
+}
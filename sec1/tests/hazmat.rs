@@ -0,0 +1,72 @@
+//! SEC1 `hazmat` tests
+
+#![cfg(feature = "hazmat")]
+
+use core::convert::TryFrom;
+use der::{Decodable, Encodable, Tag};
+use hex_literal::hex;
+use sec1::{hazmat::RawEcPrivateKey, EcPrivateKey};
+use zeroize::Zeroize;
+
+/// NIST P-256 SEC1 private key encoded as ASN.1 DER.
+const P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
+
+#[test]
+fn round_trip() {
+    let key = EcPrivateKey::from_der(P256_DER_EXAMPLE).unwrap();
+    let raw = RawEcPrivateKey::try_from(&key).unwrap();
+    assert_eq!(raw.version, [1]);
+    assert_eq!(
+        raw.parameters.as_deref(),
+        Some(&hex!("06082A8648CE3D030107")[..])
+    );
+    assert_eq!(raw.public_key.as_ref().unwrap()[..2], [0x00, 0x04]);
+
+    assert_eq!(raw.to_vec().unwrap(), P256_DER_EXAMPLE);
+    assert_eq!(RawEcPrivateKey::from_der(P256_DER_EXAMPLE).unwrap(), raw);
+}
+
+#[test]
+fn zeroize() {
+    let key = EcPrivateKey::from_der(P256_DER_EXAMPLE).unwrap();
+    let mut raw = RawEcPrivateKey::try_from(&key).unwrap();
+    raw.zeroize();
+
+    assert!(raw.private_key.is_empty());
+    assert_eq!(raw.version, [1]);
+    assert!(raw.public_key.is_some());
+}
+
+#[test]
+fn unsupported_version() {
+    let key = EcPrivateKey::from_der(P256_DER_EXAMPLE).unwrap();
+    let mut raw = RawEcPrivateKey::try_from(&key).unwrap();
+    raw.version = vec![2];
+
+    let der = raw.to_vec().unwrap();
+    let err = EcPrivateKey::from_der(&der).err().unwrap();
+    assert_eq!(err.kind(), Tag::Integer.value_error().kind());
+    assert_eq!(RawEcPrivateKey::from_der(&der).unwrap(), raw);
+}
+
+#[test]
+fn malformed_fields() {
+    let raw = RawEcPrivateKey {
+        version: vec![0, 1],
+        private_key: vec![],
+        parameters: Some(vec![0x05, 0x00]),
+        public_key: Some(vec![0x07, 0x04]),
+    };
+
+    let der = raw.to_vec().unwrap();
+    assert_eq!(der, hex!("301002020001 0400 A0020500 A10403020704"));
+    assert!(EcPrivateKey::from_der(&der).is_err());
+    assert_eq!(RawEcPrivateKey::from_der(&der).unwrap(), raw);
+
+    let mut raw = raw;
+    raw.parameters = None;
+    raw.public_key = None;
+    let der = raw.to_vec().unwrap();
+    assert_eq!(der, hex!("300602020001 0400"));
+    assert_eq!(RawEcPrivateKey::from_der(&der).unwrap(), raw);
+}
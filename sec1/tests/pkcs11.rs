@@ -0,0 +1,71 @@
+//! SEC1 `pkcs11` tests
+
+#![cfg(feature = "pkcs11")]
+
+use der::Decodable;
+use hex_literal::hex;
+use sec1::{
+    pkcs11::{self, Attribute, AttributeValue},
+    EcPrivateKey,
+};
+
+/// NIST P-256 SEC1 private key encoded as ASN.1 DER.
+const P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
+
+/// `CKA_EC_PARAMS` of NIST P-256: the DER-encoded `prime256v1` OID.
+const P256_EC_PARAMS: &[u8] = &hex!("06082A8648CE3D030107");
+
+/// Get the bytes of the attribute with the given type.
+fn bytes(template: &[Attribute], attribute_type: u64) -> &[u8] {
+    let attribute = template
+        .iter()
+        .find(|attribute| attribute.attribute_type == attribute_type)
+        .unwrap();
+
+    match &attribute.value {
+        AttributeValue::Bytes(bytes) => bytes,
+        other => panic!("unexpected value: {:?}", other),
+    }
+}
+
+#[test]
+fn private_key_template() {
+    let key = EcPrivateKey::from_der(P256_DER_EXAMPLE).unwrap();
+    let template = pkcs11::private_key_template(&key).unwrap();
+    assert_eq!(template.len(), 4);
+    assert_eq!(
+        template[..2],
+        [
+            Attribute::ulong(pkcs11::CKA_CLASS, pkcs11::CKO_PRIVATE_KEY),
+            Attribute::ulong(pkcs11::CKA_KEY_TYPE, pkcs11::CKK_EC),
+        ]
+    );
+    assert_eq!(bytes(&template, pkcs11::CKA_EC_PARAMS), P256_EC_PARAMS);
+    assert_eq!(bytes(&template, pkcs11::CKA_VALUE), key.private_key);
+    assert!(format!("{:?}", template).contains("Bytes([REDACTED])"));
+
+    // `parameters` are required
+    let key = EcPrivateKey {
+        parameters: None,
+        ..key
+    };
+    assert!(pkcs11::private_key_template(&key).is_err());
+}
+
+#[test]
+fn public_key_template() {
+    let key = EcPrivateKey::from_der(P256_DER_EXAMPLE).unwrap();
+    let point = key.public_key.unwrap();
+    let template = pkcs11::public_key_template(key.parameters.unwrap(), point).unwrap();
+    assert_eq!(template.len(), 4);
+    assert_eq!(
+        template[0],
+        Attribute::ulong(pkcs11::CKA_CLASS, pkcs11::CKO_PUBLIC_KEY)
+    );
+    assert_eq!(bytes(&template, pkcs11::CKA_EC_PARAMS), P256_EC_PARAMS);
+
+    // `CKA_EC_POINT` is a DER `OCTET STRING` containing the point
+    let ec_point = bytes(&template, pkcs11::CKA_EC_POINT);
+    assert_eq!(ec_point[..3], [0x04, 0x41, 0x04]);
+    assert_eq!(ec_point[2..], *point);
+}
@@ -1,11 +1,11 @@
 //! SEC1 private key tests
 
 use core::convert::TryFrom;
-use der::asn1::ObjectIdentifier;
+use der::{asn1::ObjectIdentifier, Decodable, Encodable, ErrorKind, Tag};
 use hex_literal::hex;
-use sec1::{EcParameters, EcPrivateKey};
+use sec1::{point, EcKeySummary, EcParameters, EcPrivateKey};
 
-#[cfg(feature = "pem")]
+#[cfg(any(feature = "arc", feature = "pem"))]
 use sec1::EcPrivateKeyDocument;
 
 /// NIST P-256 SEC1 private key encoded as ASN.1 DER.
@@ -14,6 +14,9 @@ use sec1::EcPrivateKeyDocument;
 /// example key in the `pkcs8` crate.
 const P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
 
+/// NIST P-521 SEC1 private key encoded as ASN.1 DER.
+const P521_DER_EXAMPLE: &[u8] = include_bytes!("examples/p521-priv.der");
+
 /// NIST P-256 SEC1 private key encoded as PEM.
 #[cfg(feature = "pem")]
 const P256_PEM_EXAMPLE: &str = include_str!("examples/p256-priv.pem");
@@ -37,6 +40,98 @@ fn decode_p256_der() {
     assert_eq!(key.public_key, Some(hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F").as_ref()));
 }
 
+#[test]
+fn decode_p521_der() {
+    let key = EcPrivateKey::try_from(P521_DER_EXAMPLE).unwrap();
+    assert_eq!(key.private_key.len(), 66);
+    assert_eq!(
+        key.parameters,
+        Some(EcParameters::NamedCurve(ObjectIdentifier::new(
+            "1.3.132.0.35"
+        )))
+    );
+    assert_eq!(key.public_key.unwrap().len(), 133);
+
+    let mut buf = [0u8; EcPrivateKey::max_der_len::<521>()];
+    assert_eq!(key.encode_to_slice(&mut buf).unwrap(), P521_DER_EXAMPLE);
+}
+
+#[test]
+fn max_der_len() {
+    assert!(P256_DER_EXAMPLE.len() <= EcPrivateKey::max_der_len::<256>());
+    assert!(P521_DER_EXAMPLE.len() <= EcPrivateKey::max_der_len::<521>());
+}
+
+#[test]
+fn reject_specified_curve() {
+    // `[0] EXPLICIT` `specifiedCurve` parameters (truncated to `version`),
+    // which must not be re-encoded as anything but the original bytes
+    let der = hex!("300D020101040101A0053003020101");
+    let err = EcPrivateKey::from_der(&der).err().unwrap();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnexpectedTag {
+            expected: Some(Tag::ObjectIdentifier),
+            actual: Tag::Sequence
+        }
+    );
+}
+
+#[test]
+fn debug_redacts_private_key() {
+    let key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
+    let debug = format!("{:?}", key);
+
+    assert!(debug.contains("private_key: [REDACTED]"));
+    assert!(debug.contains(&format!("public_key: {:?}", key.public_key)));
+    assert!(!debug.contains(&format!("{:?}", key.private_key)));
+}
+
+#[test]
+fn summary() {
+    let key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        key.summary(),
+        EcKeySummary {
+            curve: Some(ObjectIdentifier::new("1.2.840.10045.3.1.7")),
+            public_key: true,
+            point_tag: Some(point::Tag::Uncompressed)
+        }
+    );
+    assert_eq!(
+        key.summary().to_string(),
+        "EC P-256, public key present, uncompressed"
+    );
+
+    let key = EcPrivateKey::try_from(P521_DER_EXAMPLE).unwrap();
+    assert_eq!(key.summary().curve_name(), Some("P-521"));
+
+    // Compressed public key, and none at all
+    let point = hex!("021CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7F");
+    let mut key = EcPrivateKey {
+        public_key: Some(&point),
+        ..key
+    };
+    assert_eq!(
+        key.summary().to_string(),
+        "EC P-521, public key present, compressed"
+    );
+
+    key.public_key = None;
+    assert_eq!(key.summary().to_string(), "EC P-521, no public key");
+
+    // Unknown curve, and no parameters
+    key.parameters = Some(ObjectIdentifier::new("1.3.132.0.33").into());
+    assert_eq!(key.summary().curve_name(), None);
+    assert_eq!(key.summary().to_string(), "EC 1.3.132.0.33, no public key");
+
+    key.parameters = None;
+    assert_eq!(
+        key.summary().to_string(),
+        "EC (unspecified curve), no public key"
+    );
+}
+
 #[cfg(feature = "pem")]
 #[test]
 fn decode_p256_pem() {
@@ -47,3 +142,57 @@ fn decode_p256_pem() {
     let pk = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
     assert_eq!(sec1_doc.private_key().private_key, pk.private_key);
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_p256_with_point_encoding() {
+    use sec1::{EcPrivateKeyDocument, EncodeEcPrivateKey, PointEncoding};
+
+    let doc = EcPrivateKeyDocument::try_from(P256_DER_EXAMPLE).unwrap();
+
+    let compressed = doc
+        .to_sec1_der_with_point_encoding(PointEncoding::Compressed)
+        .unwrap();
+    let key = compressed.private_key();
+    assert_eq!(
+        key.public_key,
+        Some(hex!("031CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7F").as_ref())
+    );
+    assert_eq!(key.private_key, doc.private_key().private_key);
+
+    let uncompressed = doc
+        .to_sec1_der_with_point_encoding(PointEncoding::Uncompressed)
+        .unwrap();
+    assert_eq!(uncompressed.as_der(), P256_DER_EXAMPLE);
+
+    // Compressed points can't be decompressed without curve arithmetic
+    assert!(compressed
+        .with_point_encoding(PointEncoding::Uncompressed)
+        .is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn borrow_p256_document() {
+    let doc = sec1::EcPrivateKeyDocument::try_from(P256_DER_EXAMPLE).unwrap();
+    let key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
+
+    assert_eq!(EcPrivateKey::from(&doc).private_key, key.private_key);
+    assert_eq!(EcPrivateKey::from(&doc).public_key, key.public_key);
+}
+
+#[cfg(feature = "arc")]
+#[test]
+fn clone_shared_p256_document() {
+    use sec1::DecodeEcPrivateKey;
+
+    let doc = EcPrivateKeyDocument::from_sec1_der(P256_DER_EXAMPLE).unwrap();
+    let shared = std::thread::spawn({
+        let doc = doc.clone();
+        move || doc
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(doc.as_der().as_ptr(), shared.as_der().as_ptr());
+}
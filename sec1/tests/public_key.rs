@@ -0,0 +1,57 @@
+//! SEC1 public key tests
+
+#![cfg(feature = "spki")]
+
+use core::convert::TryFrom;
+use sec1::{
+    consts::U32,
+    der::asn1::{Null, ObjectIdentifier},
+    spki::{AlgorithmIdentifier, SubjectPublicKeyInfo},
+    EcPrivateKey, EncodedPoint,
+};
+
+/// NIST P-256 SEC1 private key encoded as ASN.1 DER.
+const P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
+
+/// `id-ecPublicKey` OID.
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.2.1");
+
+/// NIST P-256 named curve OID.
+const SECP256R1_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.3.1.7");
+
+#[test]
+fn decode_from_spki() {
+    let private_key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
+    let public_key = private_key.public_key.unwrap();
+
+    let mut spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: EC_PUBLIC_KEY_OID,
+            parameters: Some((&SECP256R1_OID).into()),
+        },
+        subject_public_key: public_key,
+    };
+
+    let point = spki.decode_public_key::<EncodedPoint<U32>>().unwrap();
+    assert_eq!(point.as_bytes(), public_key);
+
+    // `namedCurve` parameters are required
+    spki.algorithm.parameters = None;
+    assert!(spki.decode_public_key::<EncodedPoint<U32>>().is_err());
+
+    spki.algorithm.parameters = Some(Null.into());
+    assert!(spki.decode_public_key::<EncodedPoint<U32>>().is_err());
+}
+
+#[test]
+fn decode_from_spki_rejects_invalid_point() {
+    let spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: EC_PUBLIC_KEY_OID,
+            parameters: Some((&SECP256R1_OID).into()),
+        },
+        subject_public_key: &[0x04; 33],
+    };
+
+    assert!(spki.decode_public_key::<EncodedPoint<U32>>().is_err());
+}
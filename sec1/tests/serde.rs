@@ -0,0 +1,80 @@
+//! Serde adapter tests
+
+#![cfg(feature = "serde")]
+
+use hex_literal::hex;
+use sec1::{consts::U32, EncodedPoint};
+use serde_crate::{Deserialize, Serialize};
+
+/// Example compressed P-256 point
+const COMPRESSED_BYTES: [u8; 33] =
+    hex!("021111111111111111111111111111111111111111111111111111111111111111");
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(crate = "serde_crate")]
+struct Config {
+    point: EncodedPoint<U32>,
+
+    #[serde(with = "sec1::serde::base64")]
+    base64_point: EncodedPoint<U32>,
+
+    #[serde(with = "sec1::serde::hex")]
+    hex_key: [u8; 4],
+
+    #[serde(with = "sec1::serde::base64")]
+    base64_key: [u8; 4],
+}
+
+#[test]
+fn json_round_trip() {
+    let point = EncodedPoint::from_bytes(COMPRESSED_BYTES).unwrap();
+    let config = Config {
+        point,
+        base64_point: point,
+        hex_key: [0xDE, 0xAD, 0xBE, 0xEF],
+        base64_key: [0xDE, 0xAD, 0xBE, 0xEF],
+    };
+
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"point":"021111111111111111111111111111111111111111111111111111111111111111","#,
+            r#""base64_point":"AhERERERERERERERERERERERERERERERERERERERERER","#,
+            r#""hex_key":"DEADBEEF","base64_key":"3q2+7w=="}"#
+        )
+    );
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+}
+
+#[test]
+fn hex_accepts_lowercase() {
+    let point: EncodedPoint<U32> = serde_json::from_str(
+        r#""021111111111111111111111111111111111111111111111111111111111111111""#,
+    )
+    .unwrap();
+    assert_eq!(point.as_bytes(), COMPRESSED_BYTES);
+
+    #[derive(Deserialize)]
+    #[serde(crate = "serde_crate")]
+    struct Key(#[serde(with = "sec1::serde::hex")] [u8; 4]);
+
+    let key: Key = serde_json::from_str(r#""deadbeef""#).unwrap();
+    assert_eq!(key.0, [0xDE, 0xAD, 0xBE, 0xEF]);
+}
+
+#[test]
+fn reject_invalid() {
+    // Invalid tag byte
+    assert!(serde_json::from_str::<EncodedPoint<U32>>(r#""05""#).is_err());
+
+    // Odd length hex
+    assert!(serde_json::from_str::<EncodedPoint<U32>>(r#""0""#).is_err());
+
+    // Non-hex characters
+    assert!(serde_json::from_str::<EncodedPoint<U32>>(r#""0G""#).is_err());
+
+    // Longer than `MAX_SIZE`
+    let long = format!(r#""{}""#, "00".repeat(sec1::serde::MAX_SIZE + 1));
+    assert!(serde_json::from_str::<EncodedPoint<U32>>(&long).is_err());
+}
@@ -2,10 +2,52 @@
 
 use core::convert::{TryFrom, TryInto};
 use der::{
-    asn1::{Any, ObjectIdentifier},
-    Decodable, Decoder, Encodable, Error, ErrorKind, Result, Sequence,
+    asn1::{Any, Null, ObjectIdentifier},
+    Decodable, Decoder, Encodable, Error, ErrorKind, Result, Sequence, Tag,
 };
 
+/// Hash algorithms and RSA PKCS#1 v1.5 signature algorithms whose
+/// `parameters` are `NULL`, but which must also be accepted with absent
+/// `parameters` ([RFC 4055 Sections 2.1 and 5]).
+///
+/// [RFC 4055 Sections 2.1 and 5]: https://datatracker.ietf.org/doc/html/rfc4055#section-2.1
+const NULL_PARAMETERS_OIDS: &[ObjectIdentifier] = &[
+    // `id-sha1`
+    SHA1_OID,
+    // `id-sha224`, `id-sha256`, `id-sha384` and `id-sha512`
+    ObjectIdentifier::new("2.16.840.1.101.3.4.2.4"),
+    ObjectIdentifier::new("2.16.840.1.101.3.4.2.1"),
+    ObjectIdentifier::new("2.16.840.1.101.3.4.2.2"),
+    ObjectIdentifier::new("2.16.840.1.101.3.4.2.3"),
+    // `sha1WithRSAEncryption`
+    ObjectIdentifier::new("1.2.840.113549.1.1.5"),
+    // `sha224WithRSAEncryption`, `sha256WithRSAEncryption`,
+    // `sha384WithRSAEncryption` and `sha512WithRSAEncryption`
+    ObjectIdentifier::new("1.2.840.113549.1.1.14"),
+    ObjectIdentifier::new("1.2.840.113549.1.1.11"),
+    ObjectIdentifier::new("1.2.840.113549.1.1.12"),
+    ObjectIdentifier::new("1.2.840.113549.1.1.13"),
+];
+
+/// `id-sha1` OID.
+const SHA1_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.14.3.2.26");
+
+/// `id-RSAES-OAEP` OID.
+const RSAES_OAEP_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.7");
+
+/// `id-mgf1` OID.
+const MGF1_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.8");
+
+/// `id-pSpecified` OID.
+const P_SPECIFIED_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.9");
+
+/// `id-RSASSA-PSS` OID.
+const RSASSA_PSS_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.10");
+
+/// Contents of the DER encoding of `sha1Identifier`, which is the default
+/// hash algorithm of RSASSA-PSS and RSAES-OAEP, and of MGF1 in turn.
+const SHA1_IDENTIFIER: &[u8] = &[0x06, 0x05, 0x2B, 0x0E, 0x03, 0x02, 0x1A, 0x05, 0x00];
+
 /// X.509 `AlgorithmIdentifier` as defined in [RFC 5280 Section 4.1.1.2].
 ///
 /// ```text
@@ -73,6 +115,52 @@ impl<'a> AlgorithmIdentifier<'a> {
     pub fn parameters_oid(&self) -> Result<ObjectIdentifier> {
         self.parameters_any().and_then(TryInto::try_into)
     }
+
+    /// Do this and `other` identify the same algorithm with the same
+    /// parameters, even if the `parameters` are encoded differently?
+    ///
+    /// Implementations disagree on some encodings, so this follows the
+    /// rules of [RFC 4055] rather than comparing the `parameters` exactly:
+    ///
+    /// - `NULL` and absent `parameters` are equivalent for SHA-1, SHA-2 and
+    ///   the corresponding `sha*WithRSAEncryption` signature algorithms.
+    /// - The `parameters` of RSASSA-PSS, RSAES-OAEP and MGF1 are compared
+    ///   field by field, where absent fields are equivalent to their
+    ///   defaults and hash algorithms are compared using this method.
+    ///
+    /// The `parameters` of any other algorithm must be equal.
+    ///
+    /// [RFC 4055]: https://datatracker.ietf.org/doc/html/rfc4055
+    pub fn equivalent(&self, other: &AlgorithmIdentifier<'_>) -> bool {
+        if self.oid != other.oid {
+            return false;
+        }
+
+        let (params, other_params) = match (self.parameters, other.parameters) {
+            (Some(params), Some(other_params)) => (params, other_params),
+            (params, other_params) if NULL_PARAMETERS_OIDS.contains(&self.oid) => {
+                return params.map_or(true, |params| params.is_null())
+                    && other_params.map_or(true, |params| params.is_null());
+            }
+            (params, other_params) => return params == other_params,
+        };
+
+        let equivalent = match self.oid {
+            MGF1_OID => AlgorithmIdentifier::try_from(params).and_then(|hash| {
+                AlgorithmIdentifier::try_from(other_params).map(|other| hash.equivalent(&other))
+            }),
+            RSASSA_PSS_OID => PssParameters::try_from(params).and_then(|params| {
+                PssParameters::try_from(other_params).map(|other| params.equivalent(&other))
+            }),
+            RSAES_OAEP_OID => OaepParameters::try_from(params).and_then(|params| {
+                OaepParameters::try_from(other_params).map(|other| params.equivalent(&other))
+            }),
+            _ => Ok(false),
+        };
+
+        // Fall back to comparing malformed or unknown `parameters` exactly
+        equivalent.unwrap_or(false) || params == other_params
+    }
 }
 
 impl<'a> Decodable<'a> for AlgorithmIdentifier<'a> {
@@ -101,3 +189,145 @@ impl<'a> TryFrom<&'a [u8]> for AlgorithmIdentifier<'a> {
         Self::from_der(bytes)
     }
 }
+
+impl<'a> TryFrom<Any<'a>> for AlgorithmIdentifier<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.tag().assert_eq(Tag::Sequence)?;
+        let mut decoder = Decoder::new(any.value());
+        let oid = decoder.decode()?;
+        let parameters = decoder.decode()?;
+        decoder.finish(Self { oid, parameters })
+    }
+}
+
+/// `RSASSA-PSS-params` as defined in [RFC 4055 Section 3.1], with absent
+/// fields set to their defaults.
+///
+/// ```text
+/// RSASSA-PSS-params  ::=  SEQUENCE  {
+///     hashAlgorithm      [0] HashAlgorithm DEFAULT sha1Identifier,
+///     maskGenAlgorithm   [1] MaskGenAlgorithm DEFAULT mgf1SHA1Identifier,
+///     saltLength         [2] INTEGER DEFAULT 20,
+///     trailerField       [3] INTEGER DEFAULT 1  }
+/// ```
+///
+/// [RFC 4055 Section 3.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-3.1
+struct PssParameters<'a> {
+    hash_algorithm: AlgorithmIdentifier<'a>,
+    mask_gen_algorithm: AlgorithmIdentifier<'a>,
+    salt_length: u32,
+    trailer_field: u8,
+}
+
+impl<'a> PssParameters<'a> {
+    /// Are these parameters equivalent to `other`?
+    fn equivalent(&self, other: &PssParameters<'_>) -> bool {
+        self.hash_algorithm.equivalent(&other.hash_algorithm)
+            && self
+                .mask_gen_algorithm
+                .equivalent(&other.mask_gen_algorithm)
+            && self.salt_length == other.salt_length
+            && self.trailer_field == other.trailer_field
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for PssParameters<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.tag().assert_eq(Tag::Sequence)?;
+        let mut decoder = Decoder::new(any.value());
+        let hash_algorithm = explicit_or(&mut decoder, 0, sha1_identifier())?;
+        let mask_gen_algorithm = explicit_or(&mut decoder, 1, mgf1_sha1_identifier()?)?;
+        let salt_length = explicit_or(&mut decoder, 2, 20)?;
+        let trailer_field = explicit_or(&mut decoder, 3, 1)?;
+
+        decoder.finish(Self {
+            hash_algorithm,
+            mask_gen_algorithm,
+            salt_length,
+            trailer_field,
+        })
+    }
+}
+
+/// `RSAES-OAEP-params` as defined in [RFC 4055 Section 4.1], with absent
+/// fields set to their defaults.
+///
+/// ```text
+/// RSAES-OAEP-params  ::=  SEQUENCE  {
+///     hashFunc          [0] AlgorithmIdentifier DEFAULT sha1Identifier,
+///     maskGenFunc       [1] AlgorithmIdentifier DEFAULT mgf1SHA1Identifier,
+///     pSourceFunc       [2] AlgorithmIdentifier DEFAULT
+///                               pSpecifiedEmptyIdentifier  }
+/// ```
+///
+/// [RFC 4055 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-4.1
+struct OaepParameters<'a> {
+    hash_func: AlgorithmIdentifier<'a>,
+    mask_gen_func: AlgorithmIdentifier<'a>,
+    p_source_func: AlgorithmIdentifier<'a>,
+}
+
+impl<'a> OaepParameters<'a> {
+    /// Are these parameters equivalent to `other`?
+    fn equivalent(&self, other: &OaepParameters<'_>) -> bool {
+        self.hash_func.equivalent(&other.hash_func)
+            && self.mask_gen_func.equivalent(&other.mask_gen_func)
+            && self.p_source_func == other.p_source_func
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for OaepParameters<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.tag().assert_eq(Tag::Sequence)?;
+        let p_specified_empty = AlgorithmIdentifier {
+            oid: P_SPECIFIED_OID,
+            parameters: Some(Any::new(Tag::OctetString, &[])?),
+        };
+
+        let mut decoder = Decoder::new(any.value());
+        let hash_func = explicit_or(&mut decoder, 0, sha1_identifier())?;
+        let mask_gen_func = explicit_or(&mut decoder, 1, mgf1_sha1_identifier()?)?;
+        let p_source_func = explicit_or(&mut decoder, 2, p_specified_empty)?;
+
+        decoder.finish(Self {
+            hash_func,
+            mask_gen_func,
+            p_source_func,
+        })
+    }
+}
+
+/// Decode the `EXPLICIT` context-specific field with the given tag number,
+/// or return `default` if it's absent.
+fn explicit_or<'a, T>(decoder: &mut Decoder<'a>, tag_number: u8, default: T) -> Result<T>
+where
+    T: Decodable<'a>,
+{
+    if decoder.peek() == Some(0xA0 | tag_number) {
+        T::from_der(decoder.any()?.value())
+    } else {
+        Ok(default)
+    }
+}
+
+/// Get `sha1Identifier`.
+fn sha1_identifier() -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid: SHA1_OID,
+        parameters: Some(Null.into()),
+    }
+}
+
+/// Get `mgf1SHA1Identifier`.
+fn mgf1_sha1_identifier() -> Result<AlgorithmIdentifier<'static>> {
+    Ok(AlgorithmIdentifier {
+        oid: MGF1_OID,
+        parameters: Some(Any::new(Tag::Sequence, SHA1_IDENTIFIER)?),
+    })
+}
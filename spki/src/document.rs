@@ -55,7 +55,7 @@ impl DecodePublicKey for PublicKeyDocument {
         let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
 
         if label != PEM_TYPE_LABEL {
-            return Err(pem::Error::Label.into());
+            return Err(pem::Error::unexpected_type_label(PEM_TYPE_LABEL, label).into());
         }
 
         // Ensure document is well-formed
@@ -114,6 +114,12 @@ impl AsRef<[u8]> for PublicKeyDocument {
     }
 }
 
+impl<'a> From<&'a PublicKeyDocument> for SubjectPublicKeyInfo<'a> {
+    fn from(doc: &'a PublicKeyDocument) -> SubjectPublicKeyInfo<'a> {
+        doc.spki()
+    }
+}
+
 impl TryFrom<SubjectPublicKeyInfo<'_>> for PublicKeyDocument {
     type Error = Error;
 
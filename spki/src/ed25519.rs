@@ -0,0 +1,46 @@
+//! Ed25519 public keys.
+
+use crate::DecodeSubjectPublicKey;
+use core::convert::TryFrom;
+use der::{
+    asn1::{Any, ObjectIdentifier},
+    Result, Tag,
+};
+
+/// Raw Ed25519 public key as described in [RFC 8410 Section 4].
+///
+/// The `subjectPublicKey` of an Ed25519 [`SubjectPublicKeyInfo`] is the
+/// 32-byte public key, and the algorithm `parameters` must be absent.
+///
+/// [`SubjectPublicKeyInfo`]: crate::SubjectPublicKeyInfo
+/// [RFC 8410 Section 4]: https://datatracker.ietf.org/doc/html/rfc8410#section-4
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Ed25519PublicKey(pub [u8; Self::LEN]);
+
+impl Ed25519PublicKey {
+    /// Length of an Ed25519 public key in bytes.
+    pub const LEN: usize = 32;
+}
+
+impl<'a> DecodeSubjectPublicKey<'a> for Ed25519PublicKey {
+    const ALGORITHM_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.101.112");
+
+    fn decode_subject_public_key(
+        parameters: Option<Any<'a>>,
+        subject_public_key: &'a [u8],
+    ) -> Result<Self> {
+        if let Some(parameters) = parameters {
+            return Err(parameters.tag().unexpected_error(None));
+        }
+
+        <[u8; Self::LEN]>::try_from(subject_public_key)
+            .map(Self)
+            .map_err(|_| Tag::BitString.length_error())
+    }
+}
+
+impl AsRef<[u8]> for Ed25519PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
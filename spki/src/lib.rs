@@ -45,6 +45,7 @@ extern crate alloc;
 extern crate std;
 
 mod algorithm;
+mod ed25519;
 mod spki;
 mod traits;
 
@@ -52,7 +53,10 @@ mod traits;
 mod document;
 
 pub use crate::{
-    algorithm::AlgorithmIdentifier, spki::SubjectPublicKeyInfo, traits::DecodePublicKey,
+    algorithm::AlgorithmIdentifier,
+    ed25519::Ed25519PublicKey,
+    spki::SubjectPublicKeyInfo,
+    traits::{DecodePublicKey, DecodeSubjectPublicKey},
 };
 pub use der::{self, asn1::ObjectIdentifier};
 
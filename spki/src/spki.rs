@@ -1,6 +1,6 @@
 //! X.509 `SubjectPublicKeyInfo`
 
-use crate::AlgorithmIdentifier;
+use crate::{AlgorithmIdentifier, DecodeSubjectPublicKey};
 use core::convert::TryFrom;
 use der::{asn1::BitString, Decodable, Decoder, Encodable, Error, Result, Sequence};
 
@@ -35,6 +35,19 @@ pub struct SubjectPublicKeyInfo<'a> {
 }
 
 impl<'a> SubjectPublicKeyInfo<'a> {
+    /// Decode the public key as the algorithm-specific type `K`.
+    ///
+    /// Returns an error if the `algorithm` OID isn't
+    /// [`K::ALGORITHM_OID`][DecodeSubjectPublicKey::ALGORITHM_OID], or if `K`
+    /// rejects the algorithm `parameters` or the key itself.
+    pub fn decode_public_key<K>(&self) -> Result<K>
+    where
+        K: DecodeSubjectPublicKey<'a>,
+    {
+        self.algorithm.assert_algorithm_oid(K::ALGORITHM_OID)?;
+        K::decode_subject_public_key(self.algorithm.parameters, self.subject_public_key)
+    }
+
     /// Calculate the SHA-256 fingerprint of this [`SubjectPublicKeyInfo`].
     #[cfg(feature = "fingerprint")]
     #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
@@ -2,7 +2,10 @@
 
 use crate::SubjectPublicKeyInfo;
 use core::convert::TryFrom;
-use der::Result;
+use der::{
+    asn1::{Any, ObjectIdentifier},
+    Result,
+};
 
 #[cfg(feature = "alloc")]
 use crate::PublicKeyDocument;
@@ -62,6 +65,29 @@ pub trait DecodePublicKey: Sized {
     }
 }
 
+/// Decode a public key borrowing from the `subjectPublicKey` of a
+/// [`SubjectPublicKeyInfo`].
+///
+/// Unlike [`DecodePublicKey`], the decoded key may borrow from the input.
+/// Keys implementing this trait are decoded with
+/// [`SubjectPublicKeyInfo::decode_public_key`], which checks the algorithm
+/// OID before delegating to the implementation.
+pub trait DecodeSubjectPublicKey<'a>: Sized {
+    /// OID of the public key algorithm, i.e. the `algorithm` field in the
+    /// `AlgorithmIdentifier`.
+    const ALGORITHM_OID: ObjectIdentifier;
+
+    /// Decode the public key from the contents of the `subjectPublicKey`
+    /// `BIT STRING`.
+    ///
+    /// Implementations are responsible for validating the algorithm
+    /// `parameters`.
+    fn decode_subject_public_key(
+        parameters: Option<Any<'a>>,
+        subject_public_key: &'a [u8],
+    ) -> Result<Self>;
+}
+
 /// Serialize a public key object to a SPKI-encoded document.
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -0,0 +1,157 @@
+//! `AlgorithmIdentifier` tests.
+
+use core::convert::TryFrom;
+use hex_literal::hex;
+use spki::{
+    der::asn1::{Any, Null},
+    AlgorithmIdentifier, ObjectIdentifier,
+};
+
+/// `sha256WithRSAEncryption` with `NULL` parameters.
+const SHA256_WITH_RSA_NULL: &[u8] = &hex!("300D06092A864886F70D01010B0500");
+
+/// `sha256WithRSAEncryption` with absent parameters.
+const SHA256_WITH_RSA_ABSENT: &[u8] = &hex!("300B06092A864886F70D01010B");
+
+/// `ecdsa-with-SHA256`, whose parameters must be absent.
+const ECDSA_WITH_SHA256: &[u8] = &hex!("300A06082A8648CE3D040302");
+
+/// RSASSA-PSS with SHA-256, MGF1 with SHA-256 and a salt length of 32,
+/// whose hash algorithms have `NULL` parameters.
+const PSS_SHA256_NULL: &[u8] = &hex!(
+    "304106092A864886F70D01010A3034"
+    "A00F300D06096086480165030402010500"
+    "A11C301A06092A864886F70D010108300D06096086480165030402010500"
+    "A203020120"
+);
+
+/// [`PSS_SHA256_NULL`] whose hash algorithms have absent parameters.
+const PSS_SHA256_ABSENT: &[u8] = &hex!(
+    "303D06092A864886F70D01010A3030"
+    "A00D300B0609608648016503040201"
+    "A11A301806092A864886F70D010108300B0609608648016503040201"
+    "A203020120"
+);
+
+/// RSASSA-PSS with default parameters, i.e. SHA-1, MGF1 with SHA-1, a salt
+/// length of 20 and a trailer field of 1.
+const PSS_DEFAULT: &[u8] = &hex!("300D06092A864886F70D01010A3000");
+
+/// [`PSS_DEFAULT`] with the default parameters explicitly encoded.
+const PSS_EXPLICIT_DEFAULT: &[u8] = &hex!(
+    "303E06092A864886F70D01010A3031"
+    "A00B300906052B0E03021A0500"
+    "A118301606092A864886F70D010108300906052B0E03021A0500"
+    "A203020114"
+    "A303020101"
+);
+
+/// RSAES-OAEP with default parameters.
+const OAEP_DEFAULT: &[u8] = &hex!("300D06092A864886F70D0101073000");
+
+/// RSAES-OAEP with explicit default parameters, where SHA-1 has absent
+/// parameters.
+const OAEP_EXPLICIT_DEFAULT: &[u8] = &hex!(
+    "304106092A864886F70D0101073034"
+    "A009300706052B0E03021A"
+    "A116301406092A864886F70D010108300706052B0E03021A"
+    "A20F300D06092A864886F70D0101090400"
+);
+
+#[test]
+fn null_parameters_are_equivalent_to_absent() {
+    let null = AlgorithmIdentifier::try_from(SHA256_WITH_RSA_NULL).unwrap();
+    let absent = AlgorithmIdentifier::try_from(SHA256_WITH_RSA_ABSENT).unwrap();
+    assert_ne!(null, absent);
+    assert!(null.equivalent(&absent));
+    assert!(absent.equivalent(&null));
+    assert!(null.equivalent(&null));
+
+    let sha256 = AlgorithmIdentifier {
+        oid: ObjectIdentifier::new("2.16.840.1.101.3.4.2.1"),
+        parameters: None,
+    };
+    assert!(sha256.equivalent(&AlgorithmIdentifier {
+        parameters: Some(Null.into()),
+        ..sha256
+    }));
+
+    // Other parameters aren't
+    let other = AlgorithmIdentifier {
+        parameters: Some(Any::new(spki::der::Tag::OctetString, &[]).unwrap()),
+        ..null
+    };
+    assert!(!null.equivalent(&other));
+    assert!(!absent.equivalent(&other));
+}
+
+#[test]
+fn different_algorithms_are_not_equivalent() {
+    let rsa = AlgorithmIdentifier::try_from(SHA256_WITH_RSA_ABSENT).unwrap();
+    let ecdsa = AlgorithmIdentifier::try_from(ECDSA_WITH_SHA256).unwrap();
+    assert!(!rsa.equivalent(&ecdsa));
+    assert!(ecdsa.equivalent(&ecdsa));
+
+    // `NULL` is only equivalent to absent parameters for algorithms which
+    // define it
+    let ecdsa_null = AlgorithmIdentifier {
+        parameters: Some(Null.into()),
+        ..ecdsa
+    };
+    assert!(!ecdsa.equivalent(&ecdsa_null));
+}
+
+#[test]
+fn pss_parameters() {
+    let null = AlgorithmIdentifier::try_from(PSS_SHA256_NULL).unwrap();
+    let absent = AlgorithmIdentifier::try_from(PSS_SHA256_ABSENT).unwrap();
+    assert!(null.equivalent(&absent));
+    assert!(absent.equivalent(&null));
+
+    let default = AlgorithmIdentifier::try_from(PSS_DEFAULT).unwrap();
+    let explicit_default = AlgorithmIdentifier::try_from(PSS_EXPLICIT_DEFAULT).unwrap();
+    assert!(default.equivalent(&explicit_default));
+    assert!(explicit_default.equivalent(&default));
+    assert!(!default.equivalent(&null));
+
+    // Absent parameters, which don't restrict the use of an RSASSA-PSS key,
+    // aren't equivalent to the default parameters
+    let unrestricted = AlgorithmIdentifier {
+        parameters: None,
+        ..default
+    };
+    assert!(!unrestricted.equivalent(&default));
+    assert!(unrestricted.equivalent(&unrestricted));
+}
+
+#[test]
+fn pss_parameters_differing_salt_length() {
+    let mut bytes = PSS_SHA256_NULL.to_vec();
+    let len = bytes.len();
+    bytes[len - 1] = 0x40;
+
+    let salt_length_64 = AlgorithmIdentifier::try_from(&bytes[..]).unwrap();
+    let salt_length_32 = AlgorithmIdentifier::try_from(PSS_SHA256_NULL).unwrap();
+    assert!(!salt_length_32.equivalent(&salt_length_64));
+}
+
+#[test]
+fn oaep_parameters() {
+    let default = AlgorithmIdentifier::try_from(OAEP_DEFAULT).unwrap();
+    let explicit_default = AlgorithmIdentifier::try_from(OAEP_EXPLICIT_DEFAULT).unwrap();
+    assert!(default.equivalent(&explicit_default));
+    assert!(explicit_default.equivalent(&default));
+}
+
+#[test]
+fn malformed_parameters_are_compared_exactly() {
+    let malformed = AlgorithmIdentifier {
+        oid: ObjectIdentifier::new("1.2.840.113549.1.1.10"),
+        parameters: Some(Null.into()),
+    };
+    assert!(malformed.equivalent(&malformed));
+
+    let default = AlgorithmIdentifier::try_from(PSS_DEFAULT).unwrap();
+    assert!(!malformed.equivalent(&default));
+    assert!(!default.equivalent(&malformed));
+}
@@ -0,0 +1,20 @@
+//! Conformance tests using example keys
+
+use der::dev::{run, TestVector};
+use spki::SubjectPublicKeyInfo;
+
+const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/examples");
+
+#[test]
+fn public_key_examples() {
+    let vectors = TestVector::load_examples(EXAMPLES_DIR, |name| name.contains("-pub")).unwrap();
+    assert!(!vectors.is_empty());
+    run::<SubjectPublicKeyInfo<'_>>(&vectors).assert_success();
+}
+
+#[test]
+fn public_key_samples() {
+    let vectors = TestVector::load_samples(|name| name == "p256-pub" || name == "ed25519-pub");
+    assert_eq!(vectors.len(), 2);
+    run::<SubjectPublicKeyInfo<'_>>(&vectors).assert_success();
+}
@@ -1,17 +1,14 @@
 //! `SubjectPublicKeyInfo` tests.
 
-#[cfg(feature = "fingerprint")]
 use core::convert::TryFrom;
-#[cfg(feature = "fingerprint")]
 use hex_literal::hex;
+use spki::{der::asn1::Null, AlgorithmIdentifier, Ed25519PublicKey, SubjectPublicKeyInfo};
+
 #[cfg(feature = "alloc")]
 use spki::der::Encodable;
-#[cfg(feature = "fingerprint")]
-use spki::SubjectPublicKeyInfo;
 #[cfg(feature = "pem")]
 use spki::{EncodePublicKey, PublicKeyDocument};
 
-#[cfg(feature = "fingerprint")]
 // Taken from pkcs8/tests/public_key.rs
 /// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
 const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
@@ -82,6 +79,7 @@ fn decode_ed25519_pem() {
     // Ensure `PublicKeyDocument` parses successfully
     let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
     assert_eq!(doc.spki(), spki);
+    assert_eq!(SubjectPublicKeyInfo::from(&doc), spki);
 }
 
 #[test]
@@ -103,3 +101,45 @@ fn encode_ed25519_pem() {
 
     assert_eq!(ED25519_PEM_EXAMPLE, pk_encoded);
 }
+
+#[test]
+fn decode_ed25519_public_key() {
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+    let public_key = spki.decode_public_key::<Ed25519PublicKey>().unwrap();
+    assert_eq!(
+        public_key.as_ref(),
+        hex!("4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D")
+    );
+}
+
+#[test]
+fn decode_ed25519_public_key_rejects_parameters() {
+    let mut spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+    spki.algorithm.parameters = Some(Null.into());
+    assert!(spki.decode_public_key::<Ed25519PublicKey>().is_err());
+}
+
+#[test]
+fn decode_ed25519_public_key_rejects_invalid_length() {
+    let spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: "1.3.101.112".parse().unwrap(),
+            parameters: None,
+        },
+        subject_public_key: &[0; 31],
+    };
+    assert!(spki.decode_public_key::<Ed25519PublicKey>().is_err());
+}
+
+#[test]
+fn decode_public_key_rejects_other_algorithms() {
+    // X25519
+    let spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: "1.3.101.110".parse().unwrap(),
+            parameters: None,
+        },
+        subject_public_key: &[0; 32],
+    };
+    assert!(spki.decode_public_key::<Ed25519PublicKey>().is_err());
+}
@@ -1,8 +1,15 @@
 //! X.509 Attributes
 
+use crate::{Extensions, Set};
+use alloc::vec::{self, Vec};
+use core::{
+    convert::{TryFrom, TryInto},
+    mem, slice,
+};
 use der::{
-    asn1::{Any, ObjectIdentifier},
-    Sequence,
+    asn1::{Any, ObjectIdentifier, SetOfVec},
+    Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, ErrorKind, Length,
+    Result, Sequence, Tag, TagNumber, Tagged,
 };
 
 /// Attribute type/value pairs as defined in [RFC 5280 Section 4.1.2.4].
@@ -26,3 +33,258 @@ pub struct AttributeTypeAndValue<'a> {
     /// Value of the attribute
     pub value: Any<'a>,
 }
+
+/// `pkcs-9-at-extensionRequest` OID.
+const EXTENSION_REQUEST_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.9.14");
+
+/// Tag of the `signedAttrs` field of a CMS `SignerInfo`.
+const SIGNED_ATTRS_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(0),
+};
+
+/// X.501 `Attribute` as used in PKCS#10 certification requests
+/// ([RFC 2986 Section 4.1]).
+///
+/// ```text
+/// Attribute ::= SEQUENCE {
+///   type     AttributeType,
+///   values   SET OF AttributeValue }
+/// ```
+///
+/// [RFC 2986 Section 4.1]: https://tools.ietf.org/html/rfc2986#section-4.1
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Sequence)]
+pub struct Attribute<'a> {
+    /// OID describing the type of the attribute
+    pub oid: ObjectIdentifier,
+
+    /// Values of the attribute
+    pub values: Set<Any<'a>>,
+}
+
+impl<'a> Attribute<'a> {
+    /// Decode the [`Extensions`] requested by a PKCS#9 `extensionRequest`
+    /// attribute ([RFC 2985 Section 5.4.2]).
+    ///
+    /// The requested extensions decode to the same types as the extensions
+    /// of a certificate, so they can be copied into one as-is.
+    ///
+    /// Returns an error if this is not an `extensionRequest` attribute or it
+    /// doesn't have exactly one value.
+    ///
+    /// [RFC 2985 Section 5.4.2]: https://tools.ietf.org/html/rfc2985#section-5.4.2
+    pub fn extension_request(&self) -> Result<Extensions<'a>> {
+        if self.oid != EXTENSION_REQUEST_OID {
+            return Err(ErrorKind::UnknownOid { oid: self.oid }.into());
+        }
+
+        let mut values = self.values.iter();
+
+        match (values.next(), values.next()) {
+            (Some(value), None) => value.decode_into(),
+            _ => Err(Tag::Set.value_error()),
+        }
+    }
+
+    /// Compute the message digest input for the `signedAttrs` field of a CMS
+    /// `SignerInfo`, as described in [RFC 5652 Section 5.4].
+    ///
+    /// ```text
+    /// SignerInfo ::= SEQUENCE {
+    ///   ...
+    ///   signedAttrs [0] IMPLICIT SignedAttributes OPTIONAL,
+    ///   ... }
+    ///
+    /// SignedAttributes ::= SET SIZE (1..MAX) OF Attribute
+    /// ```
+    ///
+    /// `signed_attrs` is the `[0] IMPLICIT` field as it appears in the
+    /// `SignerInfo`. The signature is computed over the same contents
+    /// encoded with an explicit `SET OF` tag instead, so the tag is replaced
+    /// while the contents are kept byte-for-byte.
+    ///
+    /// Returns an error if `signed_attrs` isn't tagged `[0]`, or its contents
+    /// aren't a non-empty DER-encoded `SET OF Attribute`.
+    ///
+    /// [RFC 5652 Section 5.4]: https://tools.ietf.org/html/rfc5652#section-5.4
+    pub fn signed_attrs_digest_input(signed_attrs: Any<'_>) -> Result<Vec<u8>> {
+        signed_attrs.tag().assert_eq(SIGNED_ATTRS_TAG)?;
+
+        // DER sorts `SET OF` elements by their encodings, which doesn't
+        // match the ordering of `Attribute`, so check it here rather than
+        // decoding the contents as a `Set`
+        let mut decoder = Decoder::new(signed_attrs.value());
+        let mut previous: Option<&[u8]> = None;
+
+        while !decoder.is_finished() {
+            let start = decoder.position();
+            decoder.decode::<Attribute<'_>>()?;
+            let encoding =
+                &signed_attrs.value()[start.try_into()?..decoder.position().try_into()?];
+
+            if previous.map_or(false, |previous| previous > encoding) {
+                return Err(Tag::Set.non_canonical_error());
+            }
+
+            previous = Some(encoding);
+        }
+
+        if previous.is_none() {
+            return Err(Tag::Set.length_error());
+        }
+
+        Any::new(Tag::Set, signed_attrs.value())?.to_vec()
+    }
+}
+
+/// Collection of X.501 [`Attribute`]s with distinct types, e.g. the
+/// `attributes` of a PKCS#10 `CertificationRequestInfo`
+/// ([RFC 2986 Section 4.1]).
+///
+/// ```text
+/// Attributes ::= SET OF Attribute
+/// ```
+///
+/// Attributes are kept in insertion order, and adding an attribute whose
+/// OID is already present either fails ([`Attributes::insert`]) or replaces
+/// it in place ([`Attributes::replace`]). They are sorted by their
+/// encodings when encoded, as DER requires for a `SET OF`. Decoding rejects
+/// duplicate OIDs, and keeps the (canonical) order of the input.
+///
+/// [RFC 2986 Section 4.1]: https://tools.ietf.org/html/rfc2986#section-4.1
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Attributes<'a>(Vec<Attribute<'a>>);
+
+impl<'a> Attributes<'a> {
+    /// Create an empty collection of [`Attributes`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the attribute with the given OID, if present.
+    pub fn get(&self, oid: ObjectIdentifier) -> Option<&Attribute<'a>> {
+        self.0.iter().find(|attribute| attribute.oid == oid)
+    }
+
+    /// Append an attribute.
+    ///
+    /// Returns an error if an attribute with the same OID is already present.
+    pub fn insert(&mut self, attribute: Attribute<'a>) -> Result<()> {
+        if self.get(attribute.oid).is_some() {
+            return Err(Self::TAG.value_error());
+        }
+
+        self.0.push(attribute);
+        Ok(())
+    }
+
+    /// Replace the attribute with the same OID as `attribute`, keeping its
+    /// position, or append `attribute` if there is none.
+    ///
+    /// Returns the replaced attribute, if any.
+    pub fn replace(&mut self, attribute: Attribute<'a>) -> Option<Attribute<'a>> {
+        match self
+            .0
+            .iter_mut()
+            .find(|existing| existing.oid == attribute.oid)
+        {
+            Some(existing) => Some(mem::replace(existing, attribute)),
+            None => {
+                self.0.push(attribute);
+                None
+            }
+        }
+    }
+
+    /// Remove the attribute with the given OID, returning it if present.
+    pub fn remove(&mut self, oid: ObjectIdentifier) -> Option<Attribute<'a>> {
+        let index = self.0.iter().position(|attribute| attribute.oid == oid)?;
+        Some(self.0.remove(index))
+    }
+
+    /// Iterate over the attributes in order.
+    pub fn iter(&self) -> slice::Iter<'_, Attribute<'a>> {
+        self.0.iter()
+    }
+
+    /// Get the number of attributes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Are there no attributes?
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Borrow the attributes as a slice.
+    pub fn as_slice(&self) -> &[Attribute<'a>] {
+        self.0.as_slice()
+    }
+}
+
+impl<'a> DecodeValue<'a> for Attributes<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::try_from(SetOfVec::<Attribute<'a>>::decode_value(decoder, length)?.into_vec())
+    }
+}
+
+impl<'a> EncodeValue for Attributes<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        SetOfVec::try_from(self.0.clone())?.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for Attributes<'a> {
+    const TAG: Tag = Tag::Set;
+}
+
+impl<'a> TryFrom<&'a [u8]> for Attributes<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<Vec<Attribute<'a>>> for Attributes<'a> {
+    type Error = Error;
+
+    fn try_from(attributes: Vec<Attribute<'a>>) -> Result<Self> {
+        let mut result = Self(Vec::with_capacity(attributes.len()));
+
+        for attribute in attributes {
+            result.insert(attribute)?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl<'a> From<Attributes<'a>> for Vec<Attribute<'a>> {
+    fn from(attributes: Attributes<'a>) -> Vec<Attribute<'a>> {
+        attributes.0
+    }
+}
+
+impl<'a> IntoIterator for Attributes<'a> {
+    type Item = Attribute<'a>;
+    type IntoIter = vec::IntoIter<Attribute<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b Attributes<'a> {
+    type Item = &'b Attribute<'a>;
+    type IntoIter = slice::Iter<'b, Attribute<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
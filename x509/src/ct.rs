@@ -0,0 +1,137 @@
+//! Certificate Transparency (CT) precertificates as defined in [RFC 6962].
+//!
+//! A precertificate is a certificate which a CA submits to CT logs before
+//! issuing the corresponding final certificate. It carries the critical
+//! poison extension ([`POISON_OID`]) so that it can't be used as a
+//! certificate, which [`Extensions::is_precertificate`] recognizes.
+//!
+//! CT logs sign the `TBSCertificate` of the final certificate rather than
+//! the precertificate, which [`final_tbs_certificate`] reconstructs.
+//!
+//! [RFC 6962]: https://datatracker.ietf.org/doc/html/rfc6962
+//! [`Extensions::is_precertificate`]: crate::Extensions::is_precertificate
+
+use crate::{tbs_certificate::TbsCertificate, Extension, Name};
+use alloc::vec::Vec;
+use der::{asn1::ObjectIdentifier, Decodable, Encodable, ErrorKind, Result};
+
+/// Precertificate poison extension OID.
+///
+/// The extension must be critical and its value must be `NULL`.
+pub const POISON_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.4.1.11129.2.4.3");
+
+/// Signed Certificate Timestamp (SCT) list extension OID.
+pub const SCT_LIST_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.4.1.11129.2.4.2");
+
+/// Precertificate Signing Certificate extended key usage OID.
+pub const PRECERT_SIGNING_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.4.1.11129.2.4.4");
+
+/// `id-ce-authorityKeyIdentifier` OID.
+const AUTHORITY_KEY_IDENTIFIER_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.35");
+
+/// `id-ce-extKeyUsage` OID.
+const EXT_KEY_USAGE_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.37");
+
+/// Issuer of the final certificate, for precertificates signed by a
+/// Precertificate Signing Certificate ([RFC 6962 Section 3.1]).
+///
+/// Such a precertificate names the Precertificate Signing Certificate as
+/// its issuer, so the final `TBSCertificate` takes its issuer name and
+/// Authority Key Identifier extension from this type instead.
+///
+/// [RFC 6962 Section 3.1]: https://datatracker.ietf.org/doc/html/rfc6962#section-3.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FinalIssuer<'a> {
+    /// Name of the CA which issues the final certificate.
+    pub name: Name<'a>,
+
+    /// Authority Key Identifier extension identifying the key of the CA
+    /// which issues the final certificate, if any.
+    pub authority_key_identifier: Option<Extension<'a>>,
+}
+
+impl<'a> FinalIssuer<'a> {
+    /// Get the final issuer from the DER-encoded Precertificate Signing
+    /// Certificate which signed a precertificate.
+    ///
+    /// The Precertificate Signing Certificate is issued directly by the CA
+    /// which issues the final certificate, so this is its issuer name and
+    /// Authority Key Identifier extension. Returns an error if the
+    /// certificate lacks the [`PRECERT_SIGNING_OID`] extended key usage.
+    pub fn from_precert_signing_certificate(certificate: &'a [u8]) -> Result<Self> {
+        let tbs_certificate = TbsCertificate::from_certificate(certificate)?;
+
+        let extensions = tbs_certificate
+            .extensions
+            .map(|extensions| extensions.value)
+            .unwrap_or_default();
+        let key_purposes = extensions
+            .get(EXT_KEY_USAGE_OID)
+            .map(|extension| Vec::<ObjectIdentifier>::from_der(extension.extn_value.as_bytes()))
+            .transpose()?
+            .unwrap_or_default();
+
+        if !key_purposes.contains(&PRECERT_SIGNING_OID) {
+            return Err(ErrorKind::UnknownOid {
+                oid: EXT_KEY_USAGE_OID,
+            }
+            .into());
+        }
+
+        Ok(Self {
+            name: tbs_certificate.issuer,
+            authority_key_identifier: extensions.get(AUTHORITY_KEY_IDENTIFIER_OID).copied(),
+        })
+    }
+}
+
+/// Reconstruct the DER-encoded `TBSCertificate` of the final certificate
+/// from that of a precertificate, as signed by CT logs
+/// ([RFC 6962 Section 3.2]).
+///
+/// This removes the poison extension, which must be present and valid, and
+/// leaves the other extensions in their original order. If the
+/// precertificate was signed by a Precertificate Signing Certificate,
+/// `final_issuer` replaces its issuer name, and replaces its Authority Key
+/// Identifier extension or removes it if the final issuer has none.
+///
+/// [RFC 6962 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc6962#section-3.2
+pub fn final_tbs_certificate(
+    precert_tbs_certificate: &[u8],
+    final_issuer: Option<&FinalIssuer<'_>>,
+) -> Result<Vec<u8>> {
+    let mut tbs_certificate = TbsCertificate::from_der(precert_tbs_certificate)?;
+    let mut extensions = tbs_certificate
+        .extensions
+        .take()
+        .map(|extensions| extensions.value)
+        .unwrap_or_default();
+
+    if !extensions.is_precertificate()? {
+        return Err(ErrorKind::UnknownOid { oid: POISON_OID }.into());
+    }
+
+    extensions.remove(POISON_OID);
+
+    if let Some(final_issuer) = final_issuer {
+        tbs_certificate.issuer = final_issuer.name.clone();
+
+        match final_issuer.authority_key_identifier {
+            Some(authority_key_identifier) => {
+                extensions.replace(authority_key_identifier);
+            }
+            None => {
+                extensions.remove(AUTHORITY_KEY_IDENTIFIER_OID);
+            }
+        }
+    }
+
+    // `Extensions` can't be empty, so omit the field altogether
+    tbs_certificate.extensions = if extensions.is_empty() {
+        None
+    } else {
+        Some(extensions.into())
+    };
+
+    tbs_certificate.to_vec()
+}
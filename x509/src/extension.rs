@@ -0,0 +1,528 @@
+//! X.509 [`Extension`]s
+
+use crate::{
+    ct,
+    ocsp::{self, Nonce},
+    qualified::{self, QcStatements, SubjectDirectoryAttributes},
+    CertificatePolicies, PolicyConstraints, PolicyMappings,
+};
+use alloc::vec::{self, Vec};
+use core::{convert::TryFrom, mem, slice};
+use der::{
+    asn1::{Any, Ia5String, Null, ObjectIdentifier, OctetString},
+    Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, ErrorKind, Length,
+    Result, Sequence, Tag, TagNumber, Tagged,
+};
+
+/// `id-pe-authorityInfoAccess` OID.
+const AUTHORITY_INFO_ACCESS_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.1.1");
+
+/// `id-ad-ocsp` access method OID.
+const OCSP_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.48.1");
+
+/// `id-ce-cRLDistributionPoints` OID.
+const CRL_DISTRIBUTION_POINTS_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.31");
+
+/// Tag of the `uniformResourceIdentifier` alternative of `GeneralName`.
+const URI_TAG: Tag = Tag::ContextSpecific {
+    constructed: false,
+    number: TagNumber::new(6),
+};
+
+/// Tag of the `distributionPoint` field of `DistributionPoint`.
+const DISTRIBUTION_POINT_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(0),
+};
+
+/// Tag of the `fullName` alternative of `DistributionPointName`.
+const FULL_NAME_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(0),
+};
+
+/// X.509 `Extension` as defined in [RFC 5280 Section 4.1.2.9].
+///
+/// ```text
+/// Extension  ::=  SEQUENCE  {
+///      extnID      OBJECT IDENTIFIER,
+///      critical    BOOLEAN DEFAULT FALSE,
+///      extnValue   OCTET STRING
+///                  -- contains the DER encoding of an ASN.1 value
+///                  -- corresponding to the extension type identified
+///                  -- by extnID
+///      }
+/// ```
+///
+/// [RFC 5280 Section 4.1.2.9]: https://tools.ietf.org/html/rfc5280#section-4.1.2.9
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Extension<'a> {
+    /// OID identifying the extension type, i.e. the `extnID` field.
+    pub extn_id: ObjectIdentifier,
+
+    /// Must the extension be understood to process the certificate?
+    pub critical: bool,
+
+    /// DER encoding of the extension value.
+    pub extn_value: OctetString<'a>,
+}
+
+impl<'a> Decodable<'a> for Extension<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            let extn_id = decoder.decode()?;
+
+            // DER requires `DEFAULT` values to be omitted
+            let critical = match decoder.decode::<Option<bool>>()? {
+                Some(true) => true,
+                Some(false) => return Err(Tag::Boolean.non_canonical_error()),
+                None => false,
+            };
+
+            let extn_value = decoder.decode()?;
+
+            Ok(Self {
+                extn_id,
+                critical,
+                extn_value,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for Extension<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        let critical = if self.critical { Some(true) } else { None };
+        f(&[&self.extn_id, &critical, &self.extn_value])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Extension<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// X.509 `Extensions` as defined in [RFC 5280 Section 4.1].
+///
+/// ```text
+/// Extensions  ::=  SEQUENCE SIZE (1..MAX) OF Extension
+/// ```
+///
+/// RFC 5280 requires that a certificate-using system reject a certificate
+/// containing a critical extension it does not recognize.
+/// [`Extensions::unknown_critical_extensions`] and
+/// [`Extensions::check_critical`] implement this given a predicate which
+/// identifies the extensions the consumer supports.
+///
+/// RFC 5280 also requires that a certificate include at most one instance
+/// of a particular extension. This type enforces that: extensions are kept
+/// in insertion order, which is also their encoding order, and adding an
+/// extension whose OID is already present either fails
+/// ([`Extensions::insert`]) or replaces it in place
+/// ([`Extensions::replace`]). Decoding rejects duplicates as well.
+///
+/// [RFC 5280 Section 4.1]: https://tools.ietf.org/html/rfc5280#section-4.1
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Extensions<'a>(Vec<Extension<'a>>);
+
+impl<'a> Extensions<'a> {
+    /// Create an empty collection of [`Extensions`].
+    ///
+    /// Note that `Extensions` can't be encoded until at least one extension
+    /// has been added.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the extension with the given OID, if present.
+    pub fn get(&self, extn_id: ObjectIdentifier) -> Option<&Extension<'a>> {
+        self.0.iter().find(|extension| extension.extn_id == extn_id)
+    }
+
+    /// Append an extension.
+    ///
+    /// Returns an error if an extension with the same OID is already present.
+    pub fn insert(&mut self, extension: Extension<'a>) -> Result<()> {
+        if self.get(extension.extn_id).is_some() {
+            return Err(Self::TAG.value_error());
+        }
+
+        self.0.push(extension);
+        Ok(())
+    }
+
+    /// Replace the extension with the same OID as `extension`, keeping its
+    /// position, or append `extension` if there is none.
+    ///
+    /// Returns the replaced extension, if any.
+    pub fn replace(&mut self, extension: Extension<'a>) -> Option<Extension<'a>> {
+        match self
+            .0
+            .iter_mut()
+            .find(|existing| existing.extn_id == extension.extn_id)
+        {
+            Some(existing) => Some(mem::replace(existing, extension)),
+            None => {
+                self.0.push(extension);
+                None
+            }
+        }
+    }
+
+    /// Remove the extension with the given OID, returning it if present.
+    pub fn remove(&mut self, extn_id: ObjectIdentifier) -> Option<Extension<'a>> {
+        let index = self
+            .0
+            .iter()
+            .position(|extension| extension.extn_id == extn_id)?;
+
+        Some(self.0.remove(index))
+    }
+
+    /// Iterate over the extensions in order.
+    pub fn iter(&self) -> slice::Iter<'_, Extension<'a>> {
+        self.0.iter()
+    }
+
+    /// Get the number of extensions.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Are there no extensions?
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Borrow the extensions as a slice.
+    pub fn as_slice(&self) -> &[Extension<'a>] {
+        self.0.as_slice()
+    }
+
+    /// Iterate over the critical extensions for which `is_known` returns
+    /// `false`.
+    pub fn unknown_critical_extensions<F>(
+        &self,
+        is_known: F,
+    ) -> impl Iterator<Item = &Extension<'a>>
+    where
+        F: Fn(ObjectIdentifier) -> bool,
+    {
+        self.0
+            .iter()
+            .filter(move |extension| extension.critical && !is_known(extension.extn_id))
+    }
+
+    /// Iterate over the OCSP responder URIs in the Authority Information
+    /// Access extension ([RFC 5280 Section 4.2.2.1]).
+    ///
+    /// Access locations which aren't URIs are skipped. Returns an error if
+    /// the extension is malformed or contains an invalid URI, and an empty
+    /// iterator if it is absent.
+    ///
+    /// ```text
+    /// AuthorityInfoAccessSyntax  ::=
+    ///         SEQUENCE SIZE (1..MAX) OF AccessDescription
+    ///
+    /// AccessDescription  ::=  SEQUENCE {
+    ///         accessMethod          OBJECT IDENTIFIER,
+    ///         accessLocation        GeneralName  }
+    /// ```
+    ///
+    /// [RFC 5280 Section 4.2.2.1]: https://tools.ietf.org/html/rfc5280#section-4.2.2.1
+    pub fn ocsp_responders(&self) -> Result<impl Iterator<Item = &'a str>> {
+        let mut uris = Vec::new();
+
+        if let Some(extension) = self.get(AUTHORITY_INFO_ACCESS_OID) {
+            decode_sequence_of(extension.extn_value.as_bytes(), |decoder| {
+                decoder.sequence(|decoder| {
+                    let access_method: ObjectIdentifier = decoder.decode()?;
+                    let access_location = decoder.any()?;
+
+                    if access_method == OCSP_OID {
+                        uris.extend(uri(access_location)?);
+                    }
+
+                    Ok(())
+                })
+            })?;
+        }
+
+        Ok(uris.into_iter())
+    }
+
+    /// Iterate over the URIs in the full names of the CRL Distribution
+    /// Points extension ([RFC 5280 Section 4.2.1.13]).
+    ///
+    /// Names which aren't URIs, and distribution points named relative to
+    /// the CRL issuer, are skipped. Returns an error if the extension is
+    /// malformed or contains an invalid URI, and an empty iterator if it is
+    /// absent.
+    ///
+    /// ```text
+    /// CRLDistributionPoints ::= SEQUENCE SIZE (1..MAX) OF DistributionPoint
+    ///
+    /// DistributionPoint ::= SEQUENCE {
+    ///      distributionPoint       [0]     DistributionPointName OPTIONAL,
+    ///      reasons                 [1]     ReasonFlags OPTIONAL,
+    ///      cRLIssuer               [2]     GeneralNames OPTIONAL }
+    ///
+    /// DistributionPointName ::= CHOICE {
+    ///      fullName                [0]     GeneralNames,
+    ///      nameRelativeToCRLIssuer [1]     RelativeDistinguishedName }
+    /// ```
+    ///
+    /// [RFC 5280 Section 4.2.1.13]: https://tools.ietf.org/html/rfc5280#section-4.2.1.13
+    pub fn crl_distribution_urls(&self) -> Result<impl Iterator<Item = &'a str>> {
+        let mut uris = Vec::new();
+
+        if let Some(extension) = self.get(CRL_DISTRIBUTION_POINTS_OID) {
+            decode_sequence_of(extension.extn_value.as_bytes(), |decoder| {
+                decoder.sequence(|decoder| {
+                    if decoder.peek() == Some(DISTRIBUTION_POINT_TAG.into()) {
+                        // `DistributionPointName` is a `CHOICE`, hence explicitly tagged
+                        let point = decoder.any()?;
+                        let name = Any::from_der(point.value())?;
+
+                        if name.tag() == FULL_NAME_TAG {
+                            let mut names = Decoder::new(name.value());
+
+                            while !names.is_finished() {
+                                uris.extend(uri(names.any()?)?);
+                            }
+                        }
+                    }
+
+                    // Skip `reasons` and `cRLIssuer`
+                    while !decoder.is_finished() {
+                        decoder.any()?;
+                    }
+
+                    Ok(())
+                })
+            })?;
+        }
+
+        Ok(uris.into_iter())
+    }
+
+    /// Decode the Certificate Policies extension
+    /// ([RFC 5280 Section 4.2.1.4]), if present.
+    ///
+    /// [RFC 5280 Section 4.2.1.4]: https://tools.ietf.org/html/rfc5280#section-4.2.1.4
+    pub fn certificate_policies(&self) -> Result<Option<CertificatePolicies<'a>>> {
+        self.decode_extension(CertificatePolicies::OID)
+    }
+
+    /// Decode the Policy Mappings extension ([RFC 5280 Section 4.2.1.5]),
+    /// if present.
+    ///
+    /// [RFC 5280 Section 4.2.1.5]: https://tools.ietf.org/html/rfc5280#section-4.2.1.5
+    pub fn policy_mappings(&self) -> Result<Option<PolicyMappings>> {
+        self.decode_extension(PolicyMappings::OID)
+    }
+
+    /// Decode the Policy Constraints extension
+    /// ([RFC 5280 Section 4.2.1.11]), if present.
+    ///
+    /// [RFC 5280 Section 4.2.1.11]: https://tools.ietf.org/html/rfc5280#section-4.2.1.11
+    pub fn policy_constraints(&self) -> Result<Option<PolicyConstraints>> {
+        self.decode_extension(PolicyConstraints::OID)
+    }
+
+    /// Decode the Subject Directory Attributes extension
+    /// ([RFC 5280 Section 4.2.1.8]), if present.
+    ///
+    /// [RFC 5280 Section 4.2.1.8]: https://tools.ietf.org/html/rfc5280#section-4.2.1.8
+    pub fn subject_directory_attributes(&self) -> Result<Option<SubjectDirectoryAttributes<'a>>> {
+        self.decode_extension(qualified::SUBJECT_DIRECTORY_ATTRIBUTES_OID)
+    }
+
+    /// Decode the Qualified Certificate Statements extension
+    /// ([RFC 3739 Section 3.2.6]), if present.
+    ///
+    /// [RFC 3739 Section 3.2.6]: https://tools.ietf.org/html/rfc3739#section-3.2.6
+    pub fn qc_statements(&self) -> Result<Option<QcStatements<'a>>> {
+        self.decode_extension(qualified::QC_STATEMENTS_OID)
+    }
+
+    /// Decode the OCSP Nonce extension ([RFC 8954 Section 2.1]) of an OCSP
+    /// request or response, if present.
+    ///
+    /// [RFC 8954 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc8954#section-2.1
+    pub fn ocsp_nonce(&self) -> Result<Option<Nonce<'a>>> {
+        self.decode_extension(ocsp::NONCE_OID)
+    }
+
+    /// Are these the extensions of a Certificate Transparency precertificate,
+    /// i.e. do they contain the poison extension ([RFC 6962 Section 3.1])?
+    ///
+    /// Returns an error if the poison extension is present but isn't
+    /// critical or its value isn't `NULL`.
+    ///
+    /// [RFC 6962 Section 3.1]: https://datatracker.ietf.org/doc/html/rfc6962#section-3.1
+    pub fn is_precertificate(&self) -> Result<bool> {
+        match self.get(ct::POISON_OID) {
+            Some(extension) if !extension.critical => Err(Tag::Boolean.value_error()),
+            Some(extension) => Null::from_der(extension.extn_value.as_bytes()).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Ensure all critical extensions are known according to `is_known`.
+    ///
+    /// Returns an [`ErrorKind::UnknownOid`] error for the first unknown
+    /// critical extension.
+    pub fn check_critical<F>(&self, is_known: F) -> Result<()>
+    where
+        F: Fn(ObjectIdentifier) -> bool,
+    {
+        match self.unknown_critical_extensions(is_known).next() {
+            Some(extension) => Err(ErrorKind::UnknownOid {
+                oid: extension.extn_id,
+            }
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Decode the value of the extension with the given OID, if present.
+    fn decode_extension<T>(&self, extn_id: ObjectIdentifier) -> Result<Option<T>>
+    where
+        T: Decodable<'a>,
+    {
+        self.get(extn_id)
+            .map(|extension| T::from_der(extension.extn_value.as_bytes()))
+            .transpose()
+    }
+}
+
+impl<'a> DecodeValue<'a> for Extensions<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        let extensions = Vec::decode_value(decoder, length)?;
+
+        if extensions.is_empty() {
+            return Err(Self::TAG.value_error());
+        }
+
+        Self::try_from(extensions)
+    }
+}
+
+impl<'a> EncodeValue for Extensions<'a> {
+    fn value_len(&self) -> Result<Length> {
+        // `SIZE (1..MAX)`
+        if self.is_empty() {
+            return Err(Self::TAG.value_error());
+        }
+
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for Extensions<'a> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+impl<'a> TryFrom<&'a [u8]> for Extensions<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<Vec<Extension<'a>>> for Extensions<'a> {
+    type Error = Error;
+
+    fn try_from(extensions: Vec<Extension<'a>>) -> Result<Self> {
+        let mut result = Self(Vec::with_capacity(extensions.len()));
+
+        for extension in extensions {
+            result.insert(extension)?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl<'a> From<Extensions<'a>> for Vec<Extension<'a>> {
+    fn from(extensions: Extensions<'a>) -> Vec<Extension<'a>> {
+        extensions.0
+    }
+}
+
+impl<'a> IntoIterator for Extensions<'a> {
+    type Item = Extension<'a>;
+    type IntoIter = vec::IntoIter<Extension<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b Extensions<'a> {
+    type Item = &'b Extension<'a>;
+    type IntoIter = slice::Iter<'b, Extension<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Decode the DER-encoded `SEQUENCE OF` in `bytes`, calling `f` to decode
+/// each element.
+fn decode_sequence_of<'a, F>(bytes: &'a [u8], mut f: F) -> Result<()>
+where
+    F: FnMut(&mut Decoder<'a>) -> Result<()>,
+{
+    let mut decoder = Decoder::new(bytes);
+
+    decoder.sequence(|decoder| {
+        while !decoder.is_finished() {
+            f(decoder)?;
+        }
+
+        Ok(())
+    })?;
+
+    decoder.finish(())
+}
+
+/// Get the URI from a `GeneralName`, if it is a `uniformResourceIdentifier`.
+///
+/// Returns an error if the URI doesn't start with a scheme, or contains
+/// whitespace or control characters.
+fn uri(general_name: Any<'_>) -> Result<Option<&'_ str>> {
+    if general_name.tag() != URI_TAG {
+        return Ok(None);
+    }
+
+    let uri = Ia5String::new(general_name.value())?.as_str();
+    let scheme = uri.split(':').next().unwrap_or_default();
+    let scheme_is_valid = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+
+    if scheme_is_valid
+        && scheme.len() < uri.len()
+        && !uri
+            .chars()
+            .any(|c| c.is_ascii_whitespace() || c.is_ascii_control())
+    {
+        Ok(Some(uri))
+    } else {
+        Err(Tag::Ia5String.value_error())
+    }
+}
@@ -0,0 +1,16 @@
+//! Hexadecimal formatting of identifiers
+
+use core::fmt;
+
+/// Write bytes as colon-separated uppercase hexadecimal, e.g. `0A:1B:2C`.
+pub(crate) fn fmt_colon_hex(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            f.write_str(":")?;
+        }
+
+        write!(f, "{:02X}", byte)?;
+    }
+
+    Ok(())
+}
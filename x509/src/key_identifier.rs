@@ -0,0 +1,96 @@
+//! Key identifiers
+
+use core::{convert::TryFrom, fmt};
+use der::{
+    asn1::{Any, OctetString},
+    DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result, Tag, Tagged,
+};
+
+/// X.509 `KeyIdentifier` as defined in [RFC 5280 Section 4.2.1.2].
+///
+/// ```text
+/// KeyIdentifier ::= OCTET STRING
+/// ```
+///
+/// Used by the authority and subject key identifier extensions. Key
+/// identifiers must be non-empty and at most [`KeyIdentifier::MAX_LEN`]
+/// bytes long, which accommodates the output of any common hash function.
+///
+/// Displayed as colon-separated uppercase hexadecimal, e.g. `0A:1B:2C`.
+///
+/// [RFC 5280 Section 4.2.1.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct KeyIdentifier<'a>(OctetString<'a>);
+
+impl<'a> KeyIdentifier<'a> {
+    /// Maximum length of a key identifier in bytes.
+    pub const MAX_LEN: usize = 64;
+
+    /// Create a new [`KeyIdentifier`], checking the length is within bounds.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.is_empty() || bytes.len() > Self::MAX_LEN {
+            return Err(Self::TAG.length_error());
+        }
+
+        OctetString::new(bytes).map(Self)
+    }
+
+    /// Borrow the key identifier bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for KeyIdentifier<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for KeyIdentifier<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::new(OctetString::decode_value(decoder, length)?.as_bytes())
+    }
+}
+
+impl<'a> EncodeValue for KeyIdentifier<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for KeyIdentifier<'a> {
+    const TAG: Tag = Tag::OctetString;
+}
+
+impl<'a> TryFrom<Any<'a>> for KeyIdentifier<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.decode_into()
+    }
+}
+
+impl<'a> TryFrom<OctetString<'a>> for KeyIdentifier<'a> {
+    type Error = Error;
+
+    fn try_from(octet_string: OctetString<'a>) -> Result<Self> {
+        Self::new(octet_string.as_bytes())
+    }
+}
+
+impl<'a> From<KeyIdentifier<'a>> for OctetString<'a> {
+    fn from(key_identifier: KeyIdentifier<'a>) -> OctetString<'a> {
+        key_identifier.0
+    }
+}
+
+impl fmt::Display for KeyIdentifier<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::hex::fmt_colon_hex(self.as_bytes(), f)
+    }
+}
@@ -18,16 +18,51 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod ct;
+pub mod ocsp;
+pub mod pkcs7;
+pub mod qualified;
+pub mod tls;
+
+#[cfg(feature = "tpm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tpm")))]
+pub mod tpm;
+
 mod attribute;
+mod crl_number;
+mod extension;
+mod hex;
+mod key_identifier;
+mod name;
+mod policy;
+mod profile;
 mod rdn;
+mod serial_number;
+mod tbs_certificate;
 mod time;
 mod validity;
 
 pub use crate::{
-    attribute::AttributeTypeAndValue, rdn::RelativeDistinguishedName, time::Time,
+    attribute::{Attribute, AttributeTypeAndValue, Attributes},
+    crl_number::CrlNumber,
+    extension::{Extension, Extensions},
+    key_identifier::KeyIdentifier,
+    name::{CanonicalName, Name, NameBuilder},
+    policy::{
+        CertificatePolicies, DisplayText, NoticeReference, PolicyConstraints, PolicyInformation,
+        PolicyMapping, PolicyMappings, PolicyQualifierInfo, UserNotice,
+    },
+    profile::{Profile, ProfileExtensions},
+    rdn::RelativeDistinguishedName,
+    serial_number::SerialNumber,
+    time::Time,
     validity::Validity,
 };
 pub use der::{self, asn1::ObjectIdentifier};
 pub use spki::{self, AlgorithmIdentifier, SubjectPublicKeyInfo};
 
+#[cfg(feature = "tpm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tpm")))]
+pub use {pkcs1, sec1};
+
 use alloc::collections::BTreeSet as Set;
@@ -0,0 +1,338 @@
+//! X.501 [`Name`] as used in X.509 certificates
+
+use crate::{AttributeTypeAndValue, RelativeDistinguishedName, Set};
+use alloc::{string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    hash::{Hash, Hasher},
+    str,
+};
+use der::{
+    asn1::{Any, Ia5String, ObjectIdentifier, PrintableString, Utf8String},
+    Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, ErrorKind, Header,
+    Length, Result, Tag, Tagged,
+};
+
+/// `id-at-commonName` OID.
+const COMMON_NAME_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.4.3");
+
+/// `id-at-serialNumber` OID.
+const SERIAL_NUMBER_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.4.5");
+
+/// `id-at-countryName` OID.
+const COUNTRY_NAME_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.4.6");
+
+/// `id-at-organizationName` OID.
+const ORGANIZATION_NAME_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.4.10");
+
+/// `id-at-organizationalUnitName` OID.
+const ORGANIZATIONAL_UNIT_NAME_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.4.11");
+
+/// `id-emailAddress` OID.
+const EMAIL_ADDRESS_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.9.1");
+
+/// Upper bound on the length of names and serial numbers (`ub-common-name`,
+/// `ub-organization-name`, etc.) from [RFC 5280 Appendix A.1].
+///
+/// [RFC 5280 Appendix A.1]: https://tools.ietf.org/html/rfc5280#appendix-A.1
+const UB_NAME: usize = 64;
+
+/// Upper bound on the length of email addresses (`ub-emailaddress-length`)
+/// from [RFC 5280 Appendix A.1].
+///
+/// [RFC 5280 Appendix A.1]: https://tools.ietf.org/html/rfc5280#appendix-A.1
+const UB_EMAIL_ADDRESS: usize = 255;
+
+/// X.501 `Name` as defined in [RFC 5280 Section 4.1.2.4].
+///
+/// ```text
+/// Name ::= CHOICE { -- only one possibility for now --
+///   rdnSequence  RDNSequence }
+///
+/// RDNSequence ::= SEQUENCE OF RelativeDistinguishedName
+/// ```
+///
+/// As `rdnSequence` is the only alternative, this type is encoded as the
+/// `RDNSequence` itself.
+///
+/// [RFC 5280 Section 4.1.2.4]: https://tools.ietf.org/html/rfc5280#section-4.1.2.4
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Name<'a>(pub Vec<RelativeDistinguishedName<'a>>);
+
+impl<'a> Name<'a> {
+    /// Compute the [`CanonicalName`] of this name.
+    pub fn canonicalize(&self) -> Result<CanonicalName> {
+        let mut rdns = Vec::new();
+
+        for rdn in &self.0 {
+            let mut attributes = rdn
+                .0
+                .iter()
+                .map(canonicalize_attribute)
+                .collect::<Result<Vec<_>>>()?;
+
+            // DER `SET OF` elements are sorted by their encoding
+            attributes.sort();
+            rdns.extend_from_slice(&tlv(Tag::Set, &attributes.concat())?);
+        }
+
+        let der = tlv(Tag::Sequence, &rdns)?;
+        let hash = fnv1a(&der);
+        Ok(CanonicalName { der, hash })
+    }
+}
+
+/// Builder for a [`Name`] from typed components.
+///
+/// Each setter validates its value and appends it as a single-valued
+/// [`RelativeDistinguishedName`], so the resulting name lists components
+/// in the order they were added.
+///
+/// `DirectoryString` values (common name, organization and organizational
+/// unit) are encoded as a `PrintableString` when possible, and as a
+/// `UTF8String` otherwise, as recommended by [RFC 5280 Section 4.1.2.4].
+///
+/// ```
+/// use x509::NameBuilder;
+///
+/// let name = NameBuilder::new()
+///     .country("US")?
+///     .organization("Example, Inc.")?
+///     .common_name("example.com")?
+///     .build();
+///
+/// assert_eq!(name.0.len(), 3);
+/// # Ok::<(), x509::der::Error>(())
+/// ```
+///
+/// [RFC 5280 Section 4.1.2.4]: https://tools.ietf.org/html/rfc5280#section-4.1.2.4
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NameBuilder<'a> {
+    rdns: Vec<RelativeDistinguishedName<'a>>,
+}
+
+impl<'a> NameBuilder<'a> {
+    /// Create a new, empty [`NameBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a common name (`CN`).
+    pub fn common_name(self, value: &'a str) -> Result<Self> {
+        self.directory_string(COMMON_NAME_OID, value)
+    }
+
+    /// Add a two-letter ISO 3166 country code (`C`), e.g. `US`.
+    pub fn country(self, code: &'a str) -> Result<Self> {
+        if code.len() != 2 || !code.bytes().all(|b| b.is_ascii_uppercase()) {
+            return Err(Tag::PrintableString.value_error());
+        }
+
+        self.push(COUNTRY_NAME_OID, PrintableString::new(code)?.into())
+    }
+
+    /// Add an email address (PKCS#9 `emailAddress`).
+    pub fn email_address(self, value: &'a str) -> Result<Self> {
+        check_length(Tag::Ia5String, value, UB_EMAIL_ADDRESS)?;
+        self.push(EMAIL_ADDRESS_OID, Ia5String::new(value)?.into())
+    }
+
+    /// Add an organization (`O`).
+    pub fn organization(self, value: &'a str) -> Result<Self> {
+        self.directory_string(ORGANIZATION_NAME_OID, value)
+    }
+
+    /// Add an organizational unit (`OU`).
+    pub fn organizational_unit(self, value: &'a str) -> Result<Self> {
+        self.directory_string(ORGANIZATIONAL_UNIT_NAME_OID, value)
+    }
+
+    /// Add a serial number, which must only contain `PrintableString`
+    /// characters.
+    ///
+    /// This is the subject's serial number, not a certificate serial number.
+    pub fn serial_number(self, value: &'a str) -> Result<Self> {
+        check_length(Tag::PrintableString, value, UB_NAME)?;
+        self.push(SERIAL_NUMBER_OID, PrintableString::new(value)?.into())
+    }
+
+    /// Finish building the [`Name`].
+    pub fn build(self) -> Name<'a> {
+        Name(self.rdns)
+    }
+
+    /// Add a `DirectoryString` attribute.
+    fn directory_string(self, oid: ObjectIdentifier, value: &'a str) -> Result<Self> {
+        check_length(Tag::Utf8String, value, UB_NAME)?;
+
+        let value = match PrintableString::new(value) {
+            Ok(printable) => printable.into(),
+            Err(_) => Utf8String::new(value)?.into(),
+        };
+
+        self.push(oid, value)
+    }
+
+    /// Append a single-valued RDN with the given attribute.
+    fn push(mut self, oid: ObjectIdentifier, value: Any<'a>) -> Result<Self> {
+        let mut attributes = Set::new();
+        attributes.insert(AttributeTypeAndValue { oid, value });
+        self.rdns.push(RelativeDistinguishedName(attributes));
+        Ok(self)
+    }
+}
+
+impl<'a> DecodeValue<'a> for Name<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Vec::decode_value(decoder, length).map(Self)
+    }
+}
+
+impl<'a> EncodeValue for Name<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for Name<'a> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+impl<'a> TryFrom<&'a [u8]> for Name<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// Canonical form of a [`Name`], suitable for comparing and indexing names
+/// (e.g. looking up certificates by issuer).
+///
+/// The canonical form is the DER encoding of the name after each attribute
+/// with a string value has been converted to a `UTF8String`, had its
+/// leading and trailing whitespace removed, internal whitespace collapsed to
+/// a single space, and been converted to lowercase. This is a simplification
+/// of the string preparation rules in [RFC 4518].
+///
+/// A hash of the canonical form is computed once up front, making
+/// [`CanonicalName`] cheap to use as a key in hash-based collections.
+///
+/// [RFC 4518]: https://datatracker.ietf.org/doc/html/rfc4518
+#[derive(Clone, Debug)]
+pub struct CanonicalName {
+    /// DER encoding of the canonicalized name.
+    der: Vec<u8>,
+
+    /// Hash of `der`.
+    hash: u64,
+}
+
+impl CanonicalName {
+    /// Borrow the DER encoding of the canonicalized name.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// Get the precomputed 64-bit hash of the canonicalized name.
+    ///
+    /// This is the FNV-1a hash of [`CanonicalName::as_bytes`], and is stable
+    /// across platforms and releases.
+    pub fn hash_value(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl AsRef<[u8]> for CanonicalName {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Eq for CanonicalName {}
+
+impl PartialEq for CanonicalName {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.der == other.der
+    }
+}
+
+impl Hash for CanonicalName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl Ord for CanonicalName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.der.cmp(&other.der)
+    }
+}
+
+impl PartialOrd for CanonicalName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Ensure `value` is non-empty and at most `max` characters long.
+fn check_length(tag: Tag, value: &str, max: usize) -> Result<()> {
+    let len = value.chars().count();
+
+    if len == 0 || len > max {
+        Err(ErrorKind::Length { tag, length: None }.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Encode the canonical form of an attribute.
+fn canonicalize_attribute(attribute: &AttributeTypeAndValue<'_>) -> Result<Vec<u8>> {
+    match attribute.value.tag() {
+        Tag::Ia5String | Tag::NumericString | Tag::PrintableString | Tag::Utf8String => {
+            let folded = fold_string(str::from_utf8(attribute.value.value())?);
+
+            AttributeTypeAndValue {
+                oid: attribute.oid,
+                value: Utf8String::new(&folded)?.into(),
+            }
+            .to_vec()
+        }
+        _ => attribute.to_vec(),
+    }
+}
+
+/// Remove leading and trailing whitespace, collapse internal whitespace to a
+/// single space, and convert to lowercase.
+fn fold_string(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+
+    for word in s.split_whitespace() {
+        if !folded.is_empty() {
+            folded.push(' ');
+        }
+
+        folded.extend(word.chars().flat_map(char::to_lowercase));
+    }
+
+    folded
+}
+
+/// Encode a TLV with the given tag and value.
+fn tlv(tag: Tag, value: &[u8]) -> Result<Vec<u8>> {
+    let mut der = Header::new(tag, value.len())?.to_vec()?;
+    der.extend_from_slice(value);
+    Ok(der)
+}
+
+/// Compute the 64-bit FNV-1a hash of the given bytes.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -0,0 +1,1088 @@
+//! Online Certificate Status Protocol (OCSP) HTTP transport formats as
+//! defined in [RFC 6960 Appendix A], and decoding of OCSP responses.
+//!
+//! This module contains only the format logic needed to submit OCSP requests
+//! and fetch CRLs over HTTP. Performing the actual network requests is left
+//! to the caller.
+//!
+//! Requests are built as an [`OcspRequest`] listing a [`Request`] for the
+//! [`CertId`] of each certificate whose status is wanted, and serialized
+//! with [`OcspRequest::to_http_get_url`] or [`OcspRequest::to_http_post_body`].
+//!
+//! Responses are decoded with [`OcspResponse`], whose
+//! [`BasicOcspResponse`] can be searched for the [`SingleResponse`] of a
+//! [`CertId`] and checked to echo the [`Nonce`] of the request. Verifying
+//! the signature of the response is also left to the caller.
+//!
+//! [RFC 6960 Appendix A]: https://datatracker.ietf.org/doc/html/rfc6960#appendix-A
+
+use crate::{Extensions, Name, SerialNumber};
+use alloc::{string::String, vec::Vec};
+use base64ct::{Base64, Encoding};
+use core::convert::{TryFrom, TryInto};
+use der::{
+    asn1::{Any, ContextSpecific, GeneralizedTime, ObjectIdentifier, OctetString},
+    Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, ErrorKind, Length,
+    Result, Sequence, Tag, TagMode, TagNumber, Tagged,
+};
+use spki::AlgorithmIdentifier;
+
+#[cfg(feature = "digest")]
+use {crate::tbs_certificate::TbsCertificate, digest::Digest};
+
+/// `id-pkix-ocsp-basic` response type OID.
+pub const BASIC_RESPONSE_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.48.1.1");
+
+/// `id-pkix-ocsp-nonce` extension OID.
+pub const NONCE_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.48.1.2");
+
+/// Tag of the `responseBytes` field of `OCSPResponse` and of the `certs`
+/// field of `BasicOCSPResponse`.
+const EXPLICIT_0_TAG: u8 = 0xA0;
+
+/// Tag of the `optionalSignature` field of `OCSPRequest`.
+const OPTIONAL_SIGNATURE_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(0),
+};
+
+/// Tag of the `requestorName` field of `TBSRequest`.
+const REQUESTOR_NAME_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(1),
+};
+
+/// Tag of the `byName` alternative of `ResponderID`.
+const BY_NAME_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(1),
+};
+
+/// Tag of the `byKey` alternative of `ResponderID`.
+const BY_KEY_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(2),
+};
+
+/// Tag of the `good` alternative of `CertStatus`.
+const GOOD_TAG: Tag = Tag::ContextSpecific {
+    constructed: false,
+    number: TagNumber::new(0),
+};
+
+/// Tag of the `revoked` alternative of `CertStatus`.
+const REVOKED_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(1),
+};
+
+/// Tag of the `unknown` alternative of `CertStatus`.
+const UNKNOWN_TAG: Tag = Tag::ContextSpecific {
+    constructed: false,
+    number: TagNumber::new(2),
+};
+
+/// HTTP `Content-Type` of a DER-encoded OCSP request.
+pub const OCSP_REQUEST_CONTENT_TYPE: &str = "application/ocsp-request";
+
+/// HTTP `Content-Type` of a DER-encoded OCSP response.
+pub const OCSP_RESPONSE_CONTENT_TYPE: &str = "application/ocsp-response";
+
+/// HTTP `Content-Type` of a DER-encoded CRL as defined in [RFC 2585 Section 4.2].
+///
+/// [RFC 2585 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc2585#section-4.2
+pub const CRL_CONTENT_TYPE: &str = "application/pkix-crl";
+
+/// Maximum size of a DER-encoded OCSP request which may be submitted using
+/// the HTTP `GET` method.
+///
+/// From RFC 6960 Appendix A.1:
+/// > Requests that are smaller than 255 bytes MAY be submitted using GET.
+pub const MAX_GET_REQUEST_LEN: usize = 255;
+
+/// Construct the URL for submitting the given OCSP request to a responder
+/// using the HTTP `GET` method.
+///
+/// From RFC 6960 Appendix A.1:
+///
+/// ```text
+/// GET {url}/{url-encoding of base64 encoding of the DER encoding of
+///     the OCSPRequest}
+/// ```
+///
+/// Requests whose DER encoding is [`MAX_GET_REQUEST_LEN`] bytes or larger
+/// should be submitted using `POST` instead (see [`http_post_body`]).
+pub fn http_get_url(responder_url: &str, request: &impl Encodable) -> Result<String> {
+    let der = request.to_vec()?;
+    let encoded = Base64::encode_string(&der);
+
+    let mut url = String::with_capacity(responder_url.len() + 1 + encoded.len() * 3);
+    url.push_str(responder_url);
+
+    if !url.ends_with('/') {
+        url.push('/');
+    }
+
+    for c in encoded.chars() {
+        match c {
+            '+' => url.push_str("%2B"),
+            '/' => url.push_str("%2F"),
+            '=' => url.push_str("%3D"),
+            _ => url.push(c),
+        }
+    }
+
+    Ok(url)
+}
+
+/// Serialize the given OCSP request as the body of an HTTP `POST` request.
+///
+/// From RFC 6960 Appendix A.1:
+/// > An OCSP request using the POST method is constructed as follows: The
+/// > Content-Type header has the value "application/ocsp-request", while
+/// > the body of the message is the binary value of the DER encoding of the
+/// > OCSPRequest.
+///
+/// The `Content-Type` header should be set to [`OCSP_REQUEST_CONTENT_TYPE`].
+pub fn http_post_body(request: &impl Encodable) -> Result<Vec<u8>> {
+    request.to_vec()
+}
+
+/// OCSP request as defined in [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// OCSPRequest     ::=     SEQUENCE {
+///     tbsRequest                  TBSRequest,
+///     optionalSignature   [0]     EXPLICIT Signature OPTIONAL }
+///
+/// Signature       ::=     SEQUENCE {
+///     signatureAlgorithm      AlgorithmIdentifier,
+///     signature               BIT STRING,
+///     certs               [0] EXPLICIT SEQUENCE OF Certificate OPTIONAL}
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OcspRequest<'a> {
+    /// Request data, which the signature covers
+    pub tbs_request: TbsRequest<'a>,
+
+    /// `Signature` over the DER encoding of `tbs_request`, if the request
+    /// is signed. Computing and verifying it is left to the caller.
+    pub optional_signature: Option<Any<'a>>,
+}
+
+impl<'a> OcspRequest<'a> {
+    /// Construct the URL for submitting this request to a responder using
+    /// the HTTP `GET` method (see [`http_get_url`]).
+    pub fn to_http_get_url(&self, responder_url: &str) -> Result<String> {
+        http_get_url(responder_url, self)
+    }
+
+    /// Serialize this request as the body of an HTTP `POST` request (see
+    /// [`http_post_body`]).
+    pub fn to_http_post_body(&self) -> Result<Vec<u8>> {
+        http_post_body(self)
+    }
+}
+
+impl<'a> Decodable<'a> for OcspRequest<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                tbs_request: decoder.decode()?,
+                optional_signature: decode_explicit_any(decoder, OPTIONAL_SIGNATURE_TAG)?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for OcspRequest<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        let signature = self
+            .optional_signature
+            .map(|any| any.to_vec())
+            .transpose()?;
+        let signature = signature
+            .as_deref()
+            .map(|der| Any::new(OPTIONAL_SIGNATURE_TAG, der))
+            .transpose()?;
+
+        f(&[&self.tbs_request, &signature])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for OcspRequest<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// Data of an [`OcspRequest`] as defined in [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// TBSRequest      ::=     SEQUENCE {
+///     version             [0]     EXPLICIT Version DEFAULT v1,
+///     requestorName       [1]     EXPLICIT GeneralName OPTIONAL,
+///     requestList                 SEQUENCE OF Request,
+///     requestExtensions   [2]     EXPLICIT Extensions OPTIONAL }
+/// ```
+///
+/// Only `v1` is defined, so other versions are rejected when decoding, and
+/// the version is omitted when encoding.
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TbsRequest<'a> {
+    /// `GeneralName` of the requestor, which signed requests must include
+    pub requestor_name: Option<Any<'a>>,
+
+    /// Requests for the statuses of individual certificates
+    pub request_list: Vec<Request<'a>>,
+
+    /// Request extensions, e.g. the [`Nonce`]
+    pub request_extensions: Option<Extensions<'a>>,
+}
+
+impl<'a> Decodable<'a> for TbsRequest<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            // `v1` is encoded as `0`, but it's the default and thus omitted
+            if let Some(version) =
+                decoder.context_specific::<u8>(TagNumber::new(0), TagMode::Explicit)?
+            {
+                if version != 0 {
+                    return Err(Tag::Integer.value_error());
+                }
+            }
+
+            Ok(Self {
+                requestor_name: decode_explicit_any(decoder, REQUESTOR_NAME_TAG)?,
+                request_list: decoder.decode()?,
+                request_extensions: decoder
+                    .context_specific(TagNumber::new(2), TagMode::Explicit)?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for TbsRequest<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        let requestor_name = self.requestor_name.map(|any| any.to_vec()).transpose()?;
+        let requestor_name = requestor_name
+            .as_deref()
+            .map(|der| Any::new(REQUESTOR_NAME_TAG, der))
+            .transpose()?;
+
+        f(&[
+            &requestor_name,
+            &self.request_list,
+            &explicit(TagNumber::new(2), &self.request_extensions),
+        ])
+    }
+}
+
+/// Request for the status of a single certificate as defined in
+/// [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// Request         ::=     SEQUENCE {
+///     reqCert                     CertID,
+///     singleRequestExtensions     [0] EXPLICIT Extensions OPTIONAL }
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Request<'a> {
+    /// Identifier of the certificate
+    pub req_cert: CertId<'a>,
+
+    /// Single request extensions
+    pub single_request_extensions: Option<Extensions<'a>>,
+}
+
+impl<'a> From<CertId<'a>> for Request<'a> {
+    fn from(req_cert: CertId<'a>) -> Self {
+        Self {
+            req_cert,
+            single_request_extensions: None,
+        }
+    }
+}
+
+impl<'a> Decodable<'a> for Request<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                req_cert: decoder.decode()?,
+                single_request_extensions: decoder
+                    .context_specific(TagNumber::new(0), TagMode::Explicit)?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for Request<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        f(&[
+            &self.req_cert,
+            &explicit(TagNumber::new(0), &self.single_request_extensions),
+        ])
+    }
+}
+
+/// OCSP `CertID` as defined in [RFC 6960 Section 4.1.1], identifying the
+/// certificate whose status is being requested.
+///
+/// ```text
+/// CertID          ::=     SEQUENCE {
+///     hashAlgorithm       AlgorithmIdentifier,
+///     issuerNameHash      OCTET STRING, -- Hash of issuer's DN
+///     issuerKeyHash       OCTET STRING, -- Hash of issuer's public key
+///     serialNumber        CertificateSerialNumber }
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertId<'a> {
+    /// Hash algorithm used to compute `issuer_name_hash` and `issuer_key_hash`
+    pub hash_algorithm: AlgorithmIdentifier<'a>,
+
+    /// Hash of the DER encoding of the issuer's distinguished name
+    pub issuer_name_hash: Vec<u8>,
+
+    /// Hash of the value of the issuer's `subjectPublicKey` `BIT STRING`
+    pub issuer_key_hash: Vec<u8>,
+
+    /// Serial number of the certificate
+    pub serial_number: SerialNumber<'a>,
+}
+
+impl<'a> CertId<'a> {
+    /// Compute the [`CertId`] of `subject_cert` from it and the certificate
+    /// of its issuer, both DER-encoded, using the digest `D`.
+    ///
+    /// `hash_algorithm` identifies `D` to the responder.
+    ///
+    /// The name hash covers the complete DER encoding (including tag and
+    /// length) of the issuer certificate's `subject`, while the key hash
+    /// covers only the contents of its `subjectPublicKey` `BIT STRING`
+    /// (excluding tag, length and the unused bits count).
+    ///
+    /// Returns an error if either certificate is malformed, or if the
+    /// `issuer` of `subject_cert` doesn't match the `subject` of
+    /// `issuer_cert`.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    pub fn from_cert_pair<D: Digest>(
+        hash_algorithm: AlgorithmIdentifier<'a>,
+        issuer_cert: &[u8],
+        subject_cert: &'a [u8],
+    ) -> Result<Self> {
+        let issuer = TbsCertificate::from_certificate(issuer_cert)?;
+        let subject = TbsCertificate::from_certificate(subject_cert)?;
+        let issuer_name = issuer.subject.to_vec()?;
+
+        if subject.issuer.to_vec()? != issuer_name {
+            return Err(Tag::Sequence.value_error());
+        }
+
+        Ok(Self {
+            hash_algorithm,
+            issuer_name_hash: D::digest(&issuer_name).to_vec(),
+            issuer_key_hash: D::digest(issuer.subject_public_key_info.subject_public_key).to_vec(),
+            serial_number: subject.serial_number.try_into()?,
+        })
+    }
+
+    /// Does this [`CertId`] identify the same certificate as `other`?
+    ///
+    /// The hash algorithms are compared using
+    /// [`AlgorithmIdentifier::equivalent`], as responders may omit `NULL`
+    /// parameters which the request included, or vice versa.
+    pub fn matches(&self, other: &CertId<'_>) -> bool {
+        self.hash_algorithm.equivalent(&other.hash_algorithm)
+            && self.issuer_name_hash == other.issuer_name_hash
+            && self.issuer_key_hash == other.issuer_key_hash
+            && self.serial_number.as_bytes() == other.serial_number.as_bytes()
+    }
+}
+
+impl<'a> Decodable<'a> for CertId<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                hash_algorithm: decoder.decode()?,
+                issuer_name_hash: decoder.octet_string()?.as_bytes().to_vec(),
+                issuer_key_hash: decoder.octet_string()?.as_bytes().to_vec(),
+                serial_number: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for CertId<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        f(&[
+            &self.hash_algorithm,
+            &OctetString::new(&self.issuer_name_hash)?,
+            &OctetString::new(&self.issuer_key_hash)?,
+            &self.serial_number,
+        ])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for CertId<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// Status of an [`OcspResponse`] as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// OCSPResponseStatus ::= ENUMERATED {
+///     successful            (0),  -- Response has valid confirmations
+///     malformedRequest      (1),  -- Illegal confirmation request
+///     internalError         (2),  -- Internal error in issuer
+///     tryLater              (3),  -- Try again later
+///                                 -- (4) is not used
+///     sigRequired           (5),  -- Must sign the request
+///     unauthorized          (6)   -- Request unauthorized
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum OcspResponseStatus {
+    /// Response has valid confirmations
+    Successful = 0,
+
+    /// Illegal confirmation request
+    MalformedRequest = 1,
+
+    /// Internal error in issuer
+    InternalError = 2,
+
+    /// Try again later
+    TryLater = 3,
+
+    /// Must sign the request
+    SigRequired = 5,
+
+    /// Request unauthorized
+    Unauthorized = 6,
+}
+
+impl TryFrom<u8> for OcspResponseStatus {
+    type Error = Error;
+
+    fn try_from(status: u8) -> Result<Self> {
+        match status {
+            0 => Ok(OcspResponseStatus::Successful),
+            1 => Ok(OcspResponseStatus::MalformedRequest),
+            2 => Ok(OcspResponseStatus::InternalError),
+            3 => Ok(OcspResponseStatus::TryLater),
+            5 => Ok(OcspResponseStatus::SigRequired),
+            6 => Ok(OcspResponseStatus::Unauthorized),
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+}
+
+impl<'a> DecodeValue<'a> for OcspResponseStatus {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        // `ENUMERATED` values are encoded like `INTEGER`s
+        u8::decode_value(decoder, length)?.try_into()
+    }
+}
+
+impl Tagged for OcspResponseStatus {
+    const TAG: Tag = Tag::Enumerated;
+}
+
+/// OCSP response as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// OCSPResponse ::= SEQUENCE {
+///    responseStatus         OCSPResponseStatus,
+///    responseBytes          [0] EXPLICIT ResponseBytes OPTIONAL }
+///
+/// ResponseBytes ::=       SEQUENCE {
+///     responseType   OBJECT IDENTIFIER,
+///     response       OCTET STRING }
+/// ```
+///
+/// `responseBytes` is present if and only if the status is
+/// [`OcspResponseStatus::Successful`], which is checked when decoding.
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OcspResponse<'a> {
+    /// Status of the response
+    pub response_status: OcspResponseStatus,
+
+    /// Type and DER encoding of the response, if successful
+    pub response_bytes: Option<(ObjectIdentifier, &'a [u8])>,
+}
+
+impl<'a> OcspResponse<'a> {
+    /// Decode the [`BasicOcspResponse`] contained in this response.
+    ///
+    /// Returns an error if the response wasn't successful, or an
+    /// [`ErrorKind::UnknownOid`] error if it isn't of the
+    /// [`BASIC_RESPONSE_OID`] type.
+    pub fn basic_response(&self) -> Result<BasicOcspResponse<'a>> {
+        match self.response_bytes {
+            Some((BASIC_RESPONSE_OID, response)) => BasicOcspResponse::from_der(response),
+            Some((oid, _)) => Err(ErrorKind::UnknownOid { oid }.into()),
+            None => Err(OcspResponseStatus::TAG.value_error()),
+        }
+    }
+}
+
+impl<'a> Decodable<'a> for OcspResponse<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            let response_status = decoder.decode()?;
+            let mut response_bytes = None;
+
+            if decoder.peek() == Some(EXPLICIT_0_TAG) {
+                let field = decoder.any()?;
+                let mut decoder = Decoder::new(field.value());
+                let (response_type, response) = decoder
+                    .sequence(|decoder| Ok((decoder.oid()?, decoder.octet_string()?.as_bytes())))?;
+                response_bytes = Some(decoder.finish((response_type, response))?);
+            }
+
+            if response_bytes.is_some() != (response_status == OcspResponseStatus::Successful) {
+                return Err(OcspResponseStatus::TAG.value_error());
+            }
+
+            Ok(Self {
+                response_status,
+                response_bytes,
+            })
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for OcspResponse<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// Basic OCSP response as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// BasicOCSPResponse       ::= SEQUENCE {
+///    tbsResponseData      ResponseData,
+///    signatureAlgorithm   AlgorithmIdentifier,
+///    signature            BIT STRING,
+///    certs            [0] EXPLICIT SEQUENCE OF Certificate OPTIONAL }
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BasicOcspResponse<'a> {
+    /// Signed response data
+    pub tbs_response_data: ResponseData<'a>,
+
+    /// DER encoding of `tbs_response_data`, which the signature covers
+    pub tbs_response_data_der: &'a [u8],
+
+    /// Signature algorithm
+    pub signature_algorithm: AlgorithmIdentifier<'a>,
+
+    /// Signature over `tbs_response_data_der`
+    pub signature: &'a [u8],
+
+    /// DER-encoded certificates which help to verify the signature, e.g.
+    /// that of a delegated responder
+    pub certs: Vec<&'a [u8]>,
+}
+
+impl<'a> BasicOcspResponse<'a> {
+    /// Find the [`SingleResponse`] for the certificate identified by
+    /// `cert_id`, as compared by [`CertId::matches`].
+    pub fn find_single_response(&self, cert_id: &CertId<'_>) -> Option<&SingleResponse<'a>> {
+        self.tbs_response_data
+            .responses
+            .iter()
+            .find(|response| response.cert_id.matches(cert_id))
+    }
+
+    /// Get the [`Nonce`] of the response extensions, if present.
+    pub fn nonce(&self) -> Result<Option<Nonce<'a>>> {
+        match &self.tbs_response_data.response_extensions {
+            Some(extensions) => extensions.ocsp_nonce(),
+            None => Ok(None),
+        }
+    }
+
+    /// Does this response echo the [`Nonce`] sent in the request?
+    ///
+    /// Returns `false` if the response has no nonce, e.g. because the
+    /// responder ignored it or returned a pre-produced response, in which
+    /// case the caller may still accept a sufficiently recent response
+    /// ([RFC 8954 Section 2.1]).
+    ///
+    /// [RFC 8954 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc8954#section-2.1
+    pub fn matches_nonce(&self, request_nonce: &Nonce<'_>) -> Result<bool> {
+        Ok(self
+            .nonce()?
+            .map_or(false, |nonce| nonce.as_bytes() == request_nonce.as_bytes()))
+    }
+}
+
+impl<'a> Decodable<'a> for BasicOcspResponse<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        // Decode the fields from the contents of the `SEQUENCE` so that the
+        // DER encoding of `tbsResponseData` can be sliced out of them
+        let sequence = decoder.any()?;
+        sequence.tag().assert_eq(Tag::Sequence)?;
+        let bytes = sequence.value();
+
+        let mut decoder = Decoder::new(bytes);
+        let tbs_response_data = decoder.decode()?;
+        let tbs_response_data_der = &bytes[..usize::try_from(decoder.position())?];
+        let signature_algorithm = decoder.decode()?;
+        let signature = decoder.bit_string()?.as_bytes();
+        let mut certs = Vec::new();
+
+        if decoder.peek() == Some(EXPLICIT_0_TAG) {
+            let field = decoder.any()?;
+            let sequence = Any::from_der(field.value())?;
+            sequence.tag().assert_eq(Tag::Sequence)?;
+            let mut certs_decoder = Decoder::new(sequence.value());
+
+            while !certs_decoder.is_finished() {
+                let start = usize::try_from(certs_decoder.position())?;
+                certs_decoder.any()?.tag().assert_eq(Tag::Sequence)?;
+                let end = usize::try_from(certs_decoder.position())?;
+                certs.push(&sequence.value()[start..end]);
+            }
+        }
+
+        decoder.finish(Self {
+            tbs_response_data,
+            tbs_response_data_der,
+            signature_algorithm,
+            signature,
+            certs,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for BasicOcspResponse<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// Data of a [`BasicOcspResponse`] as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// ResponseData ::= SEQUENCE {
+///    version              [0] EXPLICIT Version DEFAULT v1,
+///    responderID              ResponderID,
+///    producedAt               GeneralizedTime,
+///    responses                SEQUENCE OF SingleResponse,
+///    responseExtensions   [1] EXPLICIT Extensions OPTIONAL }
+/// ```
+///
+/// Only `v1` is defined, so other versions are rejected when decoding.
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResponseData<'a> {
+    /// Identifier of the responder which signed the response
+    pub responder_id: ResponderId<'a>,
+
+    /// Time at which the response was signed
+    pub produced_at: GeneralizedTime,
+
+    /// Statuses of the requested certificates
+    pub responses: Vec<SingleResponse<'a>>,
+
+    /// Response extensions, e.g. the [`Nonce`]
+    pub response_extensions: Option<Extensions<'a>>,
+}
+
+impl<'a> Decodable<'a> for ResponseData<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            // `v1` is encoded as `0`, but it's the default and thus omitted
+            if let Some(version) =
+                decoder.context_specific::<u8>(TagNumber::new(0), TagMode::Explicit)?
+            {
+                if version != 0 {
+                    return Err(Tag::Integer.value_error());
+                }
+            }
+
+            Ok(Self {
+                responder_id: decoder.decode()?,
+                produced_at: decoder.generalized_time()?,
+                responses: decoder.decode()?,
+                response_extensions: decoder
+                    .context_specific(TagNumber::new(1), TagMode::Explicit)?,
+            })
+        })
+    }
+}
+
+/// Identifier of an OCSP responder as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// ResponderID ::= CHOICE {
+///    byName               [1] Name,
+///    byKey                [2] KeyHash }
+///
+/// KeyHash ::= OCTET STRING -- SHA-1 hash of responder's public key
+///                          -- (excluding the tag and length fields)
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResponderId<'a> {
+    /// Subject name of the responder's certificate
+    ByName(Name<'a>),
+
+    /// SHA-1 hash of the value of the responder's `subjectPublicKey`
+    /// `BIT STRING`
+    ByKey(Vec<u8>),
+}
+
+impl<'a> ResponderId<'a> {
+    /// Does this identify the responder with the given DER-encoded
+    /// certificate?
+    ///
+    /// Names are compared by their DER encodings, and key hashes are
+    /// computed using the digest `D`, which should be SHA-1.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    pub fn matches_certificate<D: Digest>(&self, certificate: &[u8]) -> Result<bool> {
+        let certificate = TbsCertificate::from_certificate(certificate)?;
+
+        Ok(match self {
+            ResponderId::ByName(name) => name.to_vec()? == certificate.subject.to_vec()?,
+            ResponderId::ByKey(key_hash) => {
+                let subject_public_key = certificate.subject_public_key_info.subject_public_key;
+                key_hash[..] == D::digest(subject_public_key)[..]
+            }
+        })
+    }
+}
+
+impl<'a> Decodable<'a> for ResponderId<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        let field = decoder.any()?;
+
+        match field.tag() {
+            BY_NAME_TAG => Name::from_der(field.value()).map(ResponderId::ByName),
+            BY_KEY_TAG => OctetString::from_der(field.value())
+                .map(|key_hash| ResponderId::ByKey(key_hash.as_bytes().to_vec())),
+            tag => Err(tag.unexpected_error(None)),
+        }
+    }
+}
+
+/// Status of a single certificate as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// SingleResponse ::= SEQUENCE {
+///    certID                       CertID,
+///    certStatus                   CertStatus,
+///    thisUpdate                   GeneralizedTime,
+///    nextUpdate         [0]       EXPLICIT GeneralizedTime OPTIONAL,
+///    singleExtensions   [1]       EXPLICIT Extensions OPTIONAL }
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SingleResponse<'a> {
+    /// Identifier of the certificate
+    pub cert_id: CertId<'a>,
+
+    /// Status of the certificate
+    pub cert_status: CertStatus,
+
+    /// Time at which the status was known to be correct
+    pub this_update: GeneralizedTime,
+
+    /// Time at or before which newer information will be available, if
+    /// known
+    pub next_update: Option<GeneralizedTime>,
+
+    /// Single response extensions
+    pub single_extensions: Option<Extensions<'a>>,
+}
+
+impl<'a> Decodable<'a> for SingleResponse<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                cert_id: decoder.decode()?,
+                cert_status: decoder.decode()?,
+                this_update: decoder.generalized_time()?,
+                next_update: decoder.context_specific(TagNumber::new(0), TagMode::Explicit)?,
+                single_extensions: decoder
+                    .context_specific(TagNumber::new(1), TagMode::Explicit)?,
+            })
+        })
+    }
+}
+
+/// Revocation status of a certificate as defined in
+/// [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// CertStatus ::= CHOICE {
+///     good        [0]     IMPLICIT NULL,
+///     revoked     [1]     IMPLICIT RevokedInfo,
+///     unknown     [2]     IMPLICIT UnknownInfo }
+///
+/// RevokedInfo ::= SEQUENCE {
+///     revocationTime              GeneralizedTime,
+///     revocationReason    [0]     EXPLICIT CRLReason OPTIONAL }
+///
+/// UnknownInfo ::= NULL
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CertStatus {
+    /// The certificate isn't revoked
+    Good,
+
+    /// The certificate is revoked
+    Revoked {
+        /// Time at which the certificate was revoked
+        revocation_time: GeneralizedTime,
+
+        /// Reason for the revocation, if given
+        revocation_reason: Option<CrlReason>,
+    },
+
+    /// The responder doesn't know about the certificate
+    Unknown,
+}
+
+impl<'a> Decodable<'a> for CertStatus {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        let field = decoder.any()?;
+
+        match field.tag() {
+            GOOD_TAG | UNKNOWN_TAG if !field.value().is_empty() => Err(field.tag().length_error()),
+            GOOD_TAG => Ok(CertStatus::Good),
+            UNKNOWN_TAG => Ok(CertStatus::Unknown),
+            REVOKED_TAG => {
+                let mut decoder = Decoder::new(field.value());
+                let revocation_time = decoder.generalized_time()?;
+                let revocation_reason =
+                    decoder.context_specific(TagNumber::new(0), TagMode::Explicit)?;
+
+                decoder.finish(CertStatus::Revoked {
+                    revocation_time,
+                    revocation_reason,
+                })
+            }
+            tag => Err(tag.unexpected_error(None)),
+        }
+    }
+}
+
+/// Reason for revoking a certificate as defined in [RFC 5280 Section 5.3.1].
+///
+/// ```text
+/// CRLReason ::= ENUMERATED {
+///      unspecified             (0),
+///      keyCompromise           (1),
+///      cACompromise            (2),
+///      affiliationChanged      (3),
+///      superseded              (4),
+///      cessationOfOperation    (5),
+///      certificateHold         (6),
+///           -- value 7 is not used
+///      removeFromCRL           (8),
+///      privilegeWithdrawn      (9),
+///      aACompromise           (10) }
+/// ```
+///
+/// [RFC 5280 Section 5.3.1]: https://tools.ietf.org/html/rfc5280#section-5.3.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum CrlReason {
+    /// `unspecified`
+    Unspecified = 0,
+
+    /// `keyCompromise`
+    KeyCompromise = 1,
+
+    /// `cACompromise`
+    CaCompromise = 2,
+
+    /// `affiliationChanged`
+    AffiliationChanged = 3,
+
+    /// `superseded`
+    Superseded = 4,
+
+    /// `cessationOfOperation`
+    CessationOfOperation = 5,
+
+    /// `certificateHold`
+    CertificateHold = 6,
+
+    /// `removeFromCRL`
+    RemoveFromCrl = 8,
+
+    /// `privilegeWithdrawn`
+    PrivilegeWithdrawn = 9,
+
+    /// `aACompromise`
+    AaCompromise = 10,
+}
+
+impl TryFrom<u8> for CrlReason {
+    type Error = Error;
+
+    fn try_from(reason: u8) -> Result<Self> {
+        match reason {
+            0 => Ok(CrlReason::Unspecified),
+            1 => Ok(CrlReason::KeyCompromise),
+            2 => Ok(CrlReason::CaCompromise),
+            3 => Ok(CrlReason::AffiliationChanged),
+            4 => Ok(CrlReason::Superseded),
+            5 => Ok(CrlReason::CessationOfOperation),
+            6 => Ok(CrlReason::CertificateHold),
+            8 => Ok(CrlReason::RemoveFromCrl),
+            9 => Ok(CrlReason::PrivilegeWithdrawn),
+            10 => Ok(CrlReason::AaCompromise),
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+}
+
+impl<'a> DecodeValue<'a> for CrlReason {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        // `ENUMERATED` values are encoded like `INTEGER`s
+        u8::decode_value(decoder, length)?.try_into()
+    }
+}
+
+impl Tagged for CrlReason {
+    const TAG: Tag = Tag::Enumerated;
+}
+
+/// OCSP nonce as defined in [RFC 8954 Section 2.1], which binds a response
+/// to the request containing the same nonce in order to prevent replay
+/// attacks.
+///
+/// ```text
+/// Nonce ::= OCTET STRING(SIZE(1..32))
+/// ```
+///
+/// The nonce is the value of the [`NONCE_OID`] extension of both the
+/// request and the response. The extension value of a request is the DER
+/// encoding of the [`Nonce`], and that of a response is decoded with
+/// [`Extensions::ocsp_nonce`].
+///
+/// [RFC 8954 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc8954#section-2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Nonce<'a> {
+    /// Inner value
+    inner: OctetString<'a>,
+}
+
+impl<'a> Nonce<'a> {
+    /// Maximum length of a nonce in bytes.
+    pub const MAX_LEN: usize = 32;
+
+    /// Create a new [`Nonce`] from the given bytes, which should be
+    /// generated by a cryptographically secure random number generator.
+    ///
+    /// Returns an error if the nonce is empty or longer than
+    /// [`Nonce::MAX_LEN`].
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.is_empty() || bytes.len() > Self::MAX_LEN {
+            return Err(Self::TAG.length_error());
+        }
+
+        OctetString::new(bytes).map(|inner| Self { inner })
+    }
+
+    /// Borrow the inner byte slice.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for Nonce<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::new(OctetString::decode_value(decoder, length)?.as_bytes())
+    }
+}
+
+impl<'a> EncodeValue for Nonce<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.inner.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.inner.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for Nonce<'a> {
+    const TAG: Tag = Tag::OctetString;
+}
+
+impl<'a> TryFrom<Any<'a>> for Nonce<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Nonce<'a>> {
+        any.decode_into()
+    }
+}
+
+/// Decode an `EXPLICIT` context-specific field with the given tag whose
+/// contents are kept as [`Any`], if present.
+fn decode_explicit_any<'a>(decoder: &mut Decoder<'a>, tag: Tag) -> Result<Option<Any<'a>>> {
+    if decoder.peek() == Some(tag.into()) {
+        Any::from_der(decoder.any()?.value()).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Wrap an `OPTIONAL` field in an `EXPLICIT` context-specific tag with the
+/// given tag number for encoding.
+fn explicit<T: Clone>(tag_number: TagNumber, field: &Option<T>) -> Option<ContextSpecific<T>> {
+    field.clone().map(|value| ContextSpecific {
+        tag_number,
+        tag_mode: TagMode::Explicit,
+        value,
+    })
+}
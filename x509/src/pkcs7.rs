@@ -0,0 +1,238 @@
+//! PKCS#7/CMS `SignedData` in the degenerate "certificates-only" form used
+//! by `.p7b` certificate chain files, as described in
+//! [RFC 5652 Section 5] and [RFC 2315 Section 9.1].
+//!
+//! [RFC 5652 Section 5]: https://datatracker.ietf.org/doc/html/rfc5652#section-5
+//! [RFC 2315 Section 9.1]: https://datatracker.ietf.org/doc/html/rfc2315#section-9.1
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use der::{
+    asn1::{Any, ContextSpecific, ObjectIdentifier},
+    Decodable, Decoder, Encodable, Error, ErrorKind, Result, Sequence, Tag, TagMode, TagNumber,
+};
+
+/// `id-signedData` OID.
+const SIGNED_DATA_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.7.2");
+
+/// `id-data` OID.
+const DATA_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.7.1");
+
+/// Tag of the `content` field of `ContentInfo`.
+const CONTENT_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(0),
+};
+
+/// Tag of the `certificates` field of `SignedData`.
+const CERTIFICATES_TAG: Tag = Tag::ContextSpecific {
+    constructed: true,
+    number: TagNumber::new(0),
+};
+
+/// `SignedData` version used by the certificates-only form.
+const VERSION: u8 = 1;
+
+/// PKCS#7/CMS `SignedData` containing only certificates.
+///
+/// ```text
+/// SignedData ::= SEQUENCE {
+///   version CMSVersion,
+///   digestAlgorithms DigestAlgorithmIdentifiers,
+///   encapContentInfo EncapsulatedContentInfo,
+///   certificates [0] IMPLICIT CertificateSet OPTIONAL,
+///   crls [1] IMPLICIT RevocationInfoChoices OPTIONAL,
+///   signerInfos SignerInfos }
+/// ```
+///
+/// In this form `digestAlgorithms` and `signerInfos` are empty and the
+/// encapsulated content is absent, so the structure merely carries a list of
+/// certificates. `SignedData` with signers or CRLs is rejected when decoding.
+///
+/// Certificates are kept in their original order as DER-encoded bytes.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SignedData<'a> {
+    /// DER-encoded certificates
+    certificates: Vec<&'a [u8]>,
+}
+
+impl<'a> SignedData<'a> {
+    /// Create a certificates-only [`SignedData`] from the given DER-encoded
+    /// certificates.
+    ///
+    /// Returns an error if any of them isn't a single DER-encoded `SEQUENCE`.
+    pub fn certs_only<I>(certificates: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        let certificates = certificates
+            .into_iter()
+            .map(|certificate| {
+                Any::from_der(certificate)?.tag().assert_eq(Tag::Sequence)?;
+                Ok(certificate)
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { certificates })
+    }
+
+    /// Decode a [`SignedData`] wrapped in a `ContentInfo`, as found in
+    /// `.p7b` files.
+    ///
+    /// ```text
+    /// ContentInfo ::= SEQUENCE {
+    ///   contentType ContentType,
+    ///   content [0] EXPLICIT ANY DEFINED BY contentType }
+    /// ```
+    pub fn from_content_info_der(bytes: &'a [u8]) -> Result<Self> {
+        ContentInfo::from_der(bytes).map(|content_info| content_info.content)
+    }
+
+    /// Encode this [`SignedData`] wrapped in a `ContentInfo`, as found in
+    /// `.p7b` files.
+    pub fn to_content_info_der(&self) -> Result<Vec<u8>> {
+        ContentInfo {
+            content: self.clone(),
+        }
+        .to_vec()
+    }
+
+    /// Iterate over the DER-encoded certificates.
+    pub fn certificates(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        self.certificates.iter().copied()
+    }
+}
+
+impl<'a> Decodable<'a> for SignedData<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            if decoder.uint8()? != VERSION {
+                return Err(Tag::Integer.value_error());
+            }
+
+            let digest_algorithms = decoder.any()?;
+            digest_algorithms.tag().assert_eq(Tag::Set)?;
+
+            if !digest_algorithms.value().is_empty() {
+                return Err(Tag::Set.value_error());
+            }
+
+            let encap_content_info: EncapsulatedContentInfo = decoder.decode()?;
+
+            if encap_content_info.e_content_type != DATA_OID {
+                return Err(ErrorKind::UnknownOid {
+                    oid: encap_content_info.e_content_type,
+                }
+                .into());
+            }
+
+            let mut certificates = Vec::new();
+
+            if decoder.peek() == Some(CERTIFICATES_TAG.into()) {
+                let set = decoder.any()?;
+                let mut certs = Decoder::new(set.value());
+
+                while !certs.is_finished() {
+                    let start = usize::try_from(certs.position())?;
+                    certs.any()?.tag().assert_eq(Tag::Sequence)?;
+                    let end = usize::try_from(certs.position())?;
+                    certificates.push(&set.value()[start..end]);
+                }
+            }
+
+            let signer_infos = decoder.any()?;
+            signer_infos.tag().assert_eq(Tag::Set)?;
+
+            if !signer_infos.value().is_empty() {
+                return Err(Tag::Set.value_error());
+            }
+
+            Ok(Self { certificates })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for SignedData<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        let certs = self.certificates.concat();
+        let certificates = if self.certificates.is_empty() {
+            None
+        } else {
+            Some(Any::new(CERTIFICATES_TAG, &certs)?)
+        };
+        let empty_set = Any::new(Tag::Set, &[])?;
+
+        f(&[
+            &VERSION,
+            &empty_set,
+            &EncapsulatedContentInfo {
+                e_content_type: DATA_OID,
+            },
+            &certificates,
+            &empty_set,
+        ])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SignedData<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// `EncapsulatedContentInfo` without content.
+///
+/// ```text
+/// EncapsulatedContentInfo ::= SEQUENCE {
+///   eContentType ContentType,
+///   eContent [0] EXPLICIT OCTET STRING OPTIONAL }
+/// ```
+#[derive(Sequence)]
+struct EncapsulatedContentInfo {
+    e_content_type: ObjectIdentifier,
+}
+
+/// `ContentInfo` containing [`SignedData`].
+struct ContentInfo<'a> {
+    content: SignedData<'a>,
+}
+
+impl<'a> Decodable<'a> for ContentInfo<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            let content_type: ObjectIdentifier = decoder.decode()?;
+
+            if content_type != SIGNED_DATA_OID {
+                return Err(ErrorKind::UnknownOid { oid: content_type }.into());
+            }
+
+            let content = decoder.any()?;
+            content.tag().assert_eq(CONTENT_TAG)?;
+
+            Ok(Self {
+                content: SignedData::from_der(content.value())?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for ContentInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        f(&[
+            &SIGNED_DATA_OID,
+            &ContextSpecific {
+                tag_number: TagNumber::new(0),
+                tag_mode: TagMode::Explicit,
+                value: self.content.clone(),
+            },
+        ])
+    }
+}
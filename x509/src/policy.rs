@@ -0,0 +1,561 @@
+//! Certificate policy extensions
+
+use alloc::vec::Vec;
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt, slice,
+};
+use der::{
+    asn1::{
+        Any, BmpString, ContextSpecific, Ia5String, ObjectIdentifier, Utf8String, VisibleString,
+    },
+    Choice, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, Length,
+    Result, Sequence, Tag, TagMode, TagNumber, Tagged,
+};
+
+/// X.509 `CertificatePolicies` extension as defined in
+/// [RFC 5280 Section 4.2.1.4].
+///
+/// ```text
+/// certificatePolicies ::= SEQUENCE SIZE (1..MAX) OF PolicyInformation
+/// ```
+///
+/// RFC 5280 requires that a policy OID appear at most once, which is
+/// enforced when decoding.
+///
+/// [RFC 5280 Section 4.2.1.4]: https://tools.ietf.org/html/rfc5280#section-4.2.1.4
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificatePolicies<'a>(Vec<PolicyInformation<'a>>);
+
+impl<'a> CertificatePolicies<'a> {
+    /// `id-ce-certificatePolicies` OID.
+    pub const OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.32");
+
+    /// Get the information for the policy with the given OID, if present.
+    pub fn get(&self, policy_identifier: ObjectIdentifier) -> Option<&PolicyInformation<'a>> {
+        self.0
+            .iter()
+            .find(|info| info.policy_identifier == policy_identifier)
+    }
+
+    /// Iterate over the policies in order.
+    pub fn iter(&self) -> slice::Iter<'_, PolicyInformation<'a>> {
+        self.0.iter()
+    }
+
+    /// Borrow the policies as a slice.
+    pub fn as_slice(&self) -> &[PolicyInformation<'a>] {
+        self.0.as_slice()
+    }
+}
+
+impl<'a> DecodeValue<'a> for CertificatePolicies<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::try_from(Vec::decode_value(decoder, length)?)
+    }
+}
+
+impl<'a> EncodeValue for CertificatePolicies<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for CertificatePolicies<'a> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+impl<'a> TryFrom<&'a [u8]> for CertificatePolicies<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<Vec<PolicyInformation<'a>>> for CertificatePolicies<'a> {
+    type Error = Error;
+
+    fn try_from(policies: Vec<PolicyInformation<'a>>) -> Result<Self> {
+        let has_duplicates = policies.iter().enumerate().any(|(i, info)| {
+            policies[..i]
+                .iter()
+                .any(|other| other.policy_identifier == info.policy_identifier)
+        });
+
+        if policies.is_empty() || has_duplicates {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(Self(policies))
+    }
+}
+
+impl<'a> From<CertificatePolicies<'a>> for Vec<PolicyInformation<'a>> {
+    fn from(policies: CertificatePolicies<'a>) -> Vec<PolicyInformation<'a>> {
+        policies.0
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b CertificatePolicies<'a> {
+    type Item = &'b PolicyInformation<'a>;
+    type IntoIter = slice::Iter<'b, PolicyInformation<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// X.509 `PolicyInformation` as defined in [RFC 5280 Section 4.2.1.4].
+///
+/// ```text
+/// PolicyInformation ::= SEQUENCE {
+///      policyIdentifier   CertPolicyId,
+///      policyQualifiers   SEQUENCE SIZE (1..MAX) OF
+///                              PolicyQualifierInfo OPTIONAL }
+///
+/// CertPolicyId ::= OBJECT IDENTIFIER
+/// ```
+///
+/// An absent `policyQualifiers` field is represented by an empty
+/// [`PolicyInformation::policy_qualifiers`].
+///
+/// [RFC 5280 Section 4.2.1.4]: https://tools.ietf.org/html/rfc5280#section-4.2.1.4
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyInformation<'a> {
+    /// OID identifying the policy.
+    pub policy_identifier: ObjectIdentifier,
+
+    /// Qualifiers for the policy.
+    pub policy_qualifiers: Vec<PolicyQualifierInfo<'a>>,
+}
+
+impl<'a> PolicyInformation<'a> {
+    /// `anyPolicy` OID, which stands for all policies.
+    pub const ANY_POLICY: ObjectIdentifier = ObjectIdentifier::new("2.5.29.32.0");
+}
+
+impl<'a> Decodable<'a> for PolicyInformation<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            let policy_identifier = decoder.decode()?;
+
+            // `SIZE (1..MAX)`
+            let policy_qualifiers = match decoder.decode::<Option<Vec<_>>>()? {
+                Some(qualifiers) if qualifiers.is_empty() => {
+                    return Err(Tag::Sequence.value_error())
+                }
+                qualifiers => qualifiers.unwrap_or_default(),
+            };
+
+            Ok(Self {
+                policy_identifier,
+                policy_qualifiers,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for PolicyInformation<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        if self.policy_qualifiers.is_empty() {
+            f(&[&self.policy_identifier])
+        } else {
+            f(&[&self.policy_identifier, &self.policy_qualifiers])
+        }
+    }
+}
+
+/// X.509 `PolicyQualifierInfo` as defined in [RFC 5280 Section 4.2.1.4].
+///
+/// ```text
+/// PolicyQualifierInfo ::= SEQUENCE {
+///      policyQualifierId  PolicyQualifierId,
+///      qualifier          ANY DEFINED BY policyQualifierId }
+///
+/// PolicyQualifierId ::= OBJECT IDENTIFIER ( id-qt-cps | id-qt-unotice )
+/// ```
+///
+/// [RFC 5280 Section 4.2.1.4]: https://tools.ietf.org/html/rfc5280#section-4.2.1.4
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct PolicyQualifierInfo<'a> {
+    /// OID identifying the type of the qualifier.
+    pub policy_qualifier_id: ObjectIdentifier,
+
+    /// Value of the qualifier.
+    pub qualifier: Any<'a>,
+}
+
+impl<'a> PolicyQualifierInfo<'a> {
+    /// `id-qt-cps` OID.
+    pub const CPS_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.2.1");
+
+    /// `id-qt-unotice` OID.
+    pub const USER_NOTICE_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.2.2");
+
+    /// Get the URI of the Certification Practice Statement if this is a CPS
+    /// pointer qualifier.
+    ///
+    /// ```text
+    /// CPSuri ::= IA5String
+    /// ```
+    pub fn cps_uri(&self) -> Result<Option<&'a str>> {
+        if self.policy_qualifier_id != Self::CPS_OID {
+            return Ok(None);
+        }
+
+        self.qualifier.ia5_string().map(|uri| Some(uri.as_str()))
+    }
+
+    /// Decode the [`UserNotice`] if this is a user notice qualifier.
+    pub fn user_notice(&self) -> Result<Option<UserNotice<'a>>> {
+        if self.policy_qualifier_id != Self::USER_NOTICE_OID {
+            return Ok(None);
+        }
+
+        self.qualifier
+            .sequence(|decoder| {
+                Ok(UserNotice {
+                    notice_ref: decoder.decode()?,
+                    explicit_text: decoder.decode()?,
+                })
+            })
+            .map(Some)
+    }
+}
+
+/// X.509 `UserNotice` policy qualifier as defined in
+/// [RFC 5280 Section 4.2.1.4].
+///
+/// ```text
+/// UserNotice ::= SEQUENCE {
+///      noticeRef        NoticeReference OPTIONAL,
+///      explicitText     DisplayText OPTIONAL }
+/// ```
+///
+/// [RFC 5280 Section 4.2.1.4]: https://tools.ietf.org/html/rfc5280#section-4.2.1.4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct UserNotice<'a> {
+    /// Reference to a notice published by an organization.
+    pub notice_ref: Option<NoticeReference<'a>>,
+
+    /// Text of the notice.
+    pub explicit_text: Option<DisplayText<'a>>,
+}
+
+/// X.509 `NoticeReference` as defined in [RFC 5280 Section 4.2.1.4].
+///
+/// ```text
+/// NoticeReference ::= SEQUENCE {
+///      organization     DisplayText,
+///      noticeNumbers    SEQUENCE OF INTEGER }
+/// ```
+///
+/// [RFC 5280 Section 4.2.1.4]: https://tools.ietf.org/html/rfc5280#section-4.2.1.4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct NoticeReference<'a> {
+    /// Organization which published the notice.
+    pub organization: DisplayText<'a>,
+
+    /// Numbers identifying notices published by the organization.
+    pub notice_numbers: Vec<u32>,
+}
+
+/// X.509 `DisplayText` as defined in [RFC 5280 Section 4.2.1.4].
+///
+/// ```text
+/// DisplayText ::= CHOICE {
+///      ia5String        IA5String      (SIZE (1..200)),
+///      visibleString    VisibleString  (SIZE (1..200)),
+///      bmpString        BMPString      (SIZE (1..200)),
+///      utf8String       UTF8String     (SIZE (1..200)) }
+/// ```
+///
+/// The size constraints are not enforced, as RFC 5280 notes that some
+/// non-conforming CAs issue longer text.
+///
+/// [RFC 5280 Section 4.2.1.4]: https://tools.ietf.org/html/rfc5280#section-4.2.1.4
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DisplayText<'a> {
+    /// `IA5String` text.
+    Ia5String(Ia5String<'a>),
+
+    /// `VisibleString` text.
+    VisibleString(VisibleString<'a>),
+
+    /// `BMPString` text.
+    BmpString(BmpString<'a>),
+
+    /// `UTF8String` text, which RFC 5280 recommends for new certificates.
+    Utf8String(Utf8String<'a>),
+}
+
+impl<'a> Choice<'a> for DisplayText<'a> {
+    fn can_decode(tag: Tag) -> bool {
+        matches!(
+            tag,
+            Tag::Ia5String | Tag::VisibleString | Tag::BmpString | Tag::Utf8String
+        )
+    }
+}
+
+impl<'a> Decodable<'a> for DisplayText<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        let any = decoder.any()?;
+
+        match any.tag() {
+            Tag::Ia5String => any.try_into().map(Self::Ia5String),
+            Tag::VisibleString => any.try_into().map(Self::VisibleString),
+            Tag::BmpString => any.try_into().map(Self::BmpString),
+            Tag::Utf8String => any.try_into().map(Self::Utf8String),
+            tag => Err(tag.unexpected_error(None)),
+        }
+    }
+}
+
+impl<'a> Encodable for DisplayText<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        match self {
+            Self::Ia5String(text) => text.encoded_len(),
+            Self::VisibleString(text) => text.encoded_len(),
+            Self::BmpString(text) => text.encoded_len(),
+            Self::Utf8String(text) => text.encoded_len(),
+        }
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        match self {
+            Self::Ia5String(text) => text.encode(encoder),
+            Self::VisibleString(text) => text.encode(encoder),
+            Self::BmpString(text) => text.encode(encoder),
+            Self::Utf8String(text) => text.encode(encoder),
+        }
+    }
+}
+
+impl fmt::Display for DisplayText<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ia5String(text) => text.fmt(f),
+            Self::VisibleString(text) => text.fmt(f),
+            Self::BmpString(text) => text.fmt(f),
+            Self::Utf8String(text) => text.fmt(f),
+        }
+    }
+}
+
+/// X.509 `PolicyMappings` extension as defined in
+/// [RFC 5280 Section 4.2.1.5].
+///
+/// ```text
+/// PolicyMappings ::= SEQUENCE SIZE (1..MAX) OF SEQUENCE {
+///      issuerDomainPolicy      CertPolicyId,
+///      subjectDomainPolicy     CertPolicyId }
+/// ```
+///
+/// Note that RFC 5280 forbids mapping to or from
+/// [`PolicyInformation::ANY_POLICY`]. This is left to path validation,
+/// which must reject such certificates.
+///
+/// [RFC 5280 Section 4.2.1.5]: https://tools.ietf.org/html/rfc5280#section-4.2.1.5
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyMappings(Vec<PolicyMapping>);
+
+impl PolicyMappings {
+    /// `id-ce-policyMappings` OID.
+    pub const OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.33");
+
+    /// Iterate over the subject domain policies the given issuer domain
+    /// policy maps to.
+    pub fn subject_domain_policies(
+        &self,
+        issuer_domain_policy: ObjectIdentifier,
+    ) -> impl Iterator<Item = ObjectIdentifier> + '_ {
+        self.0
+            .iter()
+            .filter(move |mapping| mapping.issuer_domain_policy == issuer_domain_policy)
+            .map(|mapping| mapping.subject_domain_policy)
+    }
+
+    /// Iterate over the mappings in order.
+    pub fn iter(&self) -> slice::Iter<'_, PolicyMapping> {
+        self.0.iter()
+    }
+
+    /// Borrow the mappings as a slice.
+    pub fn as_slice(&self) -> &[PolicyMapping] {
+        self.0.as_slice()
+    }
+}
+
+impl<'a> DecodeValue<'a> for PolicyMappings {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::try_from(Vec::decode_value(decoder, length)?)
+    }
+}
+
+impl EncodeValue for PolicyMappings {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl Tagged for PolicyMappings {
+    const TAG: Tag = Tag::Sequence;
+}
+
+impl<'a> TryFrom<&'a [u8]> for PolicyMappings {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl TryFrom<Vec<PolicyMapping>> for PolicyMappings {
+    type Error = Error;
+
+    fn try_from(mappings: Vec<PolicyMapping>) -> Result<Self> {
+        // `SIZE (1..MAX)`
+        if mappings.is_empty() {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(Self(mappings))
+    }
+}
+
+impl From<PolicyMappings> for Vec<PolicyMapping> {
+    fn from(mappings: PolicyMappings) -> Vec<PolicyMapping> {
+        mappings.0
+    }
+}
+
+impl<'a> IntoIterator for &'a PolicyMappings {
+    type Item = &'a PolicyMapping;
+    type IntoIter = slice::Iter<'a, PolicyMapping>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Mapping of an issuer domain policy to a subject domain policy, i.e. an
+/// element of [`PolicyMappings`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Sequence)]
+pub struct PolicyMapping {
+    /// Policy OID in the issuer's domain.
+    pub issuer_domain_policy: ObjectIdentifier,
+
+    /// Equivalent policy OID in the subject's domain.
+    pub subject_domain_policy: ObjectIdentifier,
+}
+
+/// X.509 `PolicyConstraints` extension as defined in
+/// [RFC 5280 Section 4.2.1.11].
+///
+/// ```text
+/// PolicyConstraints ::= SEQUENCE {
+///      requireExplicitPolicy           [0] SkipCerts OPTIONAL,
+///      inhibitPolicyMapping            [1] SkipCerts OPTIONAL }
+///
+/// SkipCerts ::= INTEGER (0..MAX)
+/// ```
+///
+/// RFC 5280 forbids an empty sequence, so decoding and encoding fail unless
+/// at least one field is present.
+///
+/// [RFC 5280 Section 4.2.1.11]: https://tools.ietf.org/html/rfc5280#section-4.2.1.11
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct PolicyConstraints {
+    /// Number of additional certificates in the path before an explicit
+    /// policy is required.
+    pub require_explicit_policy: Option<u32>,
+
+    /// Number of additional certificates in the path before policy mapping
+    /// is no longer permitted.
+    pub inhibit_policy_mapping: Option<u32>,
+}
+
+impl PolicyConstraints {
+    /// `id-ce-policyConstraints` OID.
+    pub const OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.36");
+
+    /// Tag number of the `requireExplicitPolicy` field.
+    const REQUIRE_EXPLICIT_POLICY_TAG: TagNumber = TagNumber::new(0);
+
+    /// Tag number of the `inhibitPolicyMapping` field.
+    const INHIBIT_POLICY_MAPPING_TAG: TagNumber = TagNumber::new(1);
+
+    /// Ensure at least one field is present.
+    fn check(&self) -> Result<()> {
+        if self.require_explicit_policy.is_none() && self.inhibit_policy_mapping.is_none() {
+            return Err(Tag::Sequence.value_error());
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Decodable<'a> for PolicyConstraints {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            let constraints = Self {
+                require_explicit_policy: decoder
+                    .context_specific(Self::REQUIRE_EXPLICIT_POLICY_TAG, TagMode::Implicit)?,
+                inhibit_policy_mapping: decoder
+                    .context_specific(Self::INHIBIT_POLICY_MAPPING_TAG, TagMode::Implicit)?,
+            };
+
+            constraints.check()?;
+            Ok(constraints)
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for PolicyConstraints {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        self.check()?;
+
+        let field = |tag_number, value: Option<u32>| {
+            value.map(|value| ContextSpecific {
+                tag_number,
+                tag_mode: TagMode::Implicit,
+                value,
+            })
+        };
+
+        f(&[
+            &field(
+                Self::REQUIRE_EXPLICIT_POLICY_TAG,
+                self.require_explicit_policy,
+            ),
+            &field(
+                Self::INHIBIT_POLICY_MAPPING_TAG,
+                self.inhibit_policy_mapping,
+            ),
+        ])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PolicyConstraints {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
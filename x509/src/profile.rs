@@ -0,0 +1,254 @@
+//! Certificate extension profiles
+
+use crate::{Extension, Extensions, KeyIdentifier};
+use alloc::vec::Vec;
+use der::{
+    asn1::{ContextSpecificImplicit, ObjectIdentifier, OctetString},
+    Encodable, Result, Sequence,
+};
+
+#[cfg(feature = "digest")]
+use {digest::Digest, spki::SubjectPublicKeyInfo};
+
+/// `id-ce-subjectKeyIdentifier` OID.
+const SUBJECT_KEY_IDENTIFIER_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.14");
+
+/// `id-ce-keyUsage` OID.
+const KEY_USAGE_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.15");
+
+/// `id-ce-basicConstraints` OID.
+const BASIC_CONSTRAINTS_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.19");
+
+/// `id-ce-authorityKeyIdentifier` OID.
+const AUTHORITY_KEY_IDENTIFIER_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.35");
+
+/// `id-ce-extKeyUsage` OID.
+const EXT_KEY_USAGE_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.37");
+
+/// `BasicConstraints` of an end-entity certificate, i.e. `cA` `FALSE`.
+const END_ENTITY_BASIC_CONSTRAINTS: &[u8] = &[0x30, 0x00];
+
+/// `BasicConstraints` of a CA certificate, i.e. `cA` `TRUE` without a path
+/// length constraint.
+const CA_BASIC_CONSTRAINTS: &[u8] = &[0x30, 0x03, 0x01, 0x01, 0xFF];
+
+/// `KeyUsage` with `digitalSignature` set.
+const DIGITAL_SIGNATURE_KEY_USAGE: &[u8] = &[0x03, 0x02, 0x07, 0x80];
+
+/// `KeyUsage` with `digitalSignature` and `keyEncipherment` set.
+const TLS_SERVER_KEY_USAGE: &[u8] = &[0x03, 0x02, 0x05, 0xA0];
+
+/// `KeyUsage` with `keyCertSign` and `cRLSign` set.
+const CA_KEY_USAGE: &[u8] = &[0x03, 0x02, 0x01, 0x06];
+
+/// `ExtKeyUsageSyntax` with `id-kp-serverAuth`.
+const SERVER_AUTH_EXT_KEY_USAGE: &[u8] = &[
+    0x30, 0x0A, 0x06, 0x08, 0x2B, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x01,
+];
+
+/// `ExtKeyUsageSyntax` with `id-kp-clientAuth`.
+const CLIENT_AUTH_EXT_KEY_USAGE: &[u8] = &[
+    0x30, 0x0A, 0x06, 0x08, 0x2B, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x02,
+];
+
+/// `ExtKeyUsageSyntax` with `id-kp-codeSigning`.
+const CODE_SIGNING_EXT_KEY_USAGE: &[u8] = &[
+    0x30, 0x0A, 0x06, 0x08, 0x2B, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x03,
+];
+
+/// Preset certificate profiles with policy-compliant extensions.
+///
+/// Each profile includes the following extensions ([RFC 5280 Section 4.2.1]),
+/// in this order:
+///
+/// | Profile       | Basic constraints       | Key usage (critical)                    | Extended key usage |
+/// |---------------|-------------------------|-----------------------------------------|--------------------|
+/// | `TlsServer`   | `cA` `FALSE` (critical) | `digitalSignature`, `keyEncipherment`   | `serverAuth`       |
+/// | `TlsClient`   | `cA` `FALSE` (critical) | `digitalSignature`                      | `clientAuth`       |
+/// | `Ca`          | `cA` `TRUE` (critical)  | `keyCertSign`, `cRLSign`                | (none)             |
+/// | `CodeSigning` | `cA` `FALSE` (critical) | `digitalSignature`                      | `codeSigning`      |
+///
+/// Use [`ProfileExtensions`] to add subject and authority key identifiers.
+///
+/// [RFC 5280 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Profile {
+    /// TLS server (web server) certificate.
+    TlsServer,
+
+    /// TLS client certificate.
+    TlsClient,
+
+    /// Certificate authority which issues certificates and CRLs.
+    Ca,
+
+    /// Code signing certificate.
+    CodeSigning,
+}
+
+impl Profile {
+    /// Get the extensions of this profile.
+    pub fn extensions(self) -> Result<Extensions<'static>> {
+        let (basic_constraints, key_usage, ext_key_usage) = match self {
+            Profile::TlsServer => (
+                END_ENTITY_BASIC_CONSTRAINTS,
+                TLS_SERVER_KEY_USAGE,
+                Some(SERVER_AUTH_EXT_KEY_USAGE),
+            ),
+            Profile::TlsClient => (
+                END_ENTITY_BASIC_CONSTRAINTS,
+                DIGITAL_SIGNATURE_KEY_USAGE,
+                Some(CLIENT_AUTH_EXT_KEY_USAGE),
+            ),
+            Profile::Ca => (CA_BASIC_CONSTRAINTS, CA_KEY_USAGE, None),
+            Profile::CodeSigning => (
+                END_ENTITY_BASIC_CONSTRAINTS,
+                DIGITAL_SIGNATURE_KEY_USAGE,
+                Some(CODE_SIGNING_EXT_KEY_USAGE),
+            ),
+        };
+
+        let mut extensions = Extensions::new();
+        extensions.insert(extension(BASIC_CONSTRAINTS_OID, true, basic_constraints)?)?;
+        extensions.insert(extension(KEY_USAGE_OID, true, key_usage)?)?;
+
+        if let Some(ext_key_usage) = ext_key_usage {
+            extensions.insert(extension(EXT_KEY_USAGE_OID, false, ext_key_usage)?)?;
+        }
+
+        Ok(extensions)
+    }
+}
+
+/// Extensions of a [`Profile`] along with the subject and authority key
+/// identifiers of a certificate.
+///
+/// ```
+/// use x509::{KeyIdentifier, Profile, ProfileExtensions};
+///
+/// let key_id = KeyIdentifier::new(&[0x01, 0x02, 0x03])?;
+/// let profile = ProfileExtensions::new(Profile::Ca)
+///     .subject_key_identifier(key_id)?
+///     .authority_key_identifier(key_id)?;
+///
+/// assert_eq!(profile.to_extensions()?.len(), 4);
+/// # Ok::<(), x509::der::Error>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfileExtensions {
+    /// Profile providing the other extensions.
+    profile: Profile,
+
+    /// DER-encoded value of the subject key identifier extension.
+    subject_key_identifier: Option<Vec<u8>>,
+
+    /// DER-encoded value of the authority key identifier extension.
+    authority_key_identifier: Option<Vec<u8>>,
+}
+
+impl ProfileExtensions {
+    /// Create new [`ProfileExtensions`] for the given [`Profile`], without
+    /// key identifiers.
+    pub fn new(profile: Profile) -> Self {
+        Self {
+            profile,
+            subject_key_identifier: None,
+            authority_key_identifier: None,
+        }
+    }
+
+    /// Get the [`Profile`].
+    pub fn profile(&self) -> Profile {
+        self.profile
+    }
+
+    /// Set the subject key identifier, i.e. the identifier of the
+    /// certificate's public key.
+    pub fn subject_key_identifier(mut self, key_id: KeyIdentifier<'_>) -> Result<Self> {
+        self.subject_key_identifier = Some(key_id.to_vec()?);
+        Ok(self)
+    }
+
+    /// Set the authority key identifier, i.e. the subject key identifier of
+    /// the issuer's certificate.
+    ///
+    /// For self-signed certificates this is the same as the subject key
+    /// identifier.
+    pub fn authority_key_identifier(mut self, key_id: KeyIdentifier<'_>) -> Result<Self> {
+        let authority_key_identifier = AuthorityKeyIdentifier {
+            key_identifier: key_id.into(),
+        };
+
+        self.authority_key_identifier = Some(authority_key_identifier.to_vec()?);
+        Ok(self)
+    }
+
+    /// Set the subject key identifier to the digest `D` of the value of the
+    /// certificate's `subjectPublicKey` `BIT STRING`.
+    ///
+    /// With SHA-1 this is method (1) of [RFC 5280 Section 4.2.1.2].
+    ///
+    /// [RFC 5280 Section 4.2.1.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.2
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    pub fn subject_public_key<D: Digest>(self, spki: &SubjectPublicKeyInfo<'_>) -> Result<Self> {
+        let key_id = D::digest(spki.subject_public_key);
+        self.subject_key_identifier(KeyIdentifier::new(&key_id)?)
+    }
+
+    /// Set the authority key identifier to the digest `D` of the value of
+    /// the issuer's `subjectPublicKey` `BIT STRING`.
+    ///
+    /// This matches the issuer's subject key identifier if it was computed
+    /// the same way, e.g. with [`ProfileExtensions::subject_public_key`].
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    pub fn issuer_public_key<D: Digest>(self, spki: &SubjectPublicKeyInfo<'_>) -> Result<Self> {
+        let key_id = D::digest(spki.subject_public_key);
+        self.authority_key_identifier(KeyIdentifier::new(&key_id)?)
+    }
+
+    /// Get the extensions of the [`Profile`], followed by the subject and
+    /// authority key identifier extensions if they're set.
+    pub fn to_extensions(&self) -> Result<Extensions<'_>> {
+        let mut extensions = self.profile.extensions()?;
+
+        if let Some(value) = &self.subject_key_identifier {
+            extensions.insert(extension(SUBJECT_KEY_IDENTIFIER_OID, false, value)?)?;
+        }
+
+        if let Some(value) = &self.authority_key_identifier {
+            extensions.insert(extension(AUTHORITY_KEY_IDENTIFIER_OID, false, value)?)?;
+        }
+
+        Ok(extensions)
+    }
+}
+
+impl From<Profile> for ProfileExtensions {
+    fn from(profile: Profile) -> ProfileExtensions {
+        ProfileExtensions::new(profile)
+    }
+}
+
+/// `AuthorityKeyIdentifier` with only the `keyIdentifier` field.
+///
+/// ```text
+/// AuthorityKeyIdentifier ::= SEQUENCE {
+///      keyIdentifier             [0] KeyIdentifier           OPTIONAL,
+///      authorityCertIssuer       [1] GeneralNames            OPTIONAL,
+///      authorityCertSerialNumber [2] CertificateSerialNumber OPTIONAL  }
+/// ```
+#[derive(Sequence)]
+struct AuthorityKeyIdentifier<'a> {
+    key_identifier: ContextSpecificImplicit<0, KeyIdentifier<'a>>,
+}
+
+/// Create an [`Extension`] with the given DER-encoded value.
+fn extension(extn_id: ObjectIdentifier, critical: bool, value: &[u8]) -> Result<Extension<'_>> {
+    Ok(Extension {
+        extn_id,
+        critical,
+        extn_value: OctetString::new(value)?,
+    })
+}
@@ -0,0 +1,788 @@
+//! Qualified certificate extensions as defined in [RFC 3739] and
+//! [ETSI EN 319 412-5].
+//!
+//! Qualified certificates, e.g. those issued under the EU eIDAS regulation,
+//! describe their subject with the [`SubjectDirectoryAttributes`] extension
+//! and make statements about the certificate with the [`QcStatements`]
+//! extension.
+//!
+//! The information carried by a statement depends on its type. Types
+//! implementing [`QcStatementInfo`] associate a statement ID with the type
+//! of its information, which is how [`QcStatements::info`] decodes it.
+//! This module implements it for the statements defined by RFC 3739 and
+//! ETSI EN 319 412-5, and it may be implemented for other statements
+//! outside this crate.
+//!
+//! [RFC 3739]: https://datatracker.ietf.org/doc/html/rfc3739
+//! [ETSI EN 319 412-5]: https://www.etsi.org/deliver/etsi_en/319400_319499/31941205/02.03.01_60/en_31941205v020301p.pdf
+
+use crate::Attribute;
+use alloc::vec::Vec;
+use core::convert::{TryFrom, TryInto};
+use der::{
+    asn1::{Any, GeneralizedTime, Ia5String, ObjectIdentifier, PrintableString},
+    Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error, Length, Result,
+    Sequence, Tag, Tagged,
+};
+
+/// `id-pe-qcStatements` extension OID.
+pub const QC_STATEMENTS_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.1.3");
+
+/// `id-ce-subjectDirectoryAttributes` extension OID.
+pub const SUBJECT_DIRECTORY_ATTRIBUTES_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.9");
+
+/// `id-pda-dateOfBirth` attribute OID.
+pub const DATE_OF_BIRTH_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.9.1");
+
+/// `id-pda-placeOfBirth` attribute OID.
+pub const PLACE_OF_BIRTH_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.9.2");
+
+/// `id-pda-gender` attribute OID.
+pub const GENDER_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.9.3");
+
+/// `id-pda-countryOfCitizenship` attribute OID.
+pub const COUNTRY_OF_CITIZENSHIP_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.9.4");
+
+/// `id-pda-countryOfResidence` attribute OID.
+pub const COUNTRY_OF_RESIDENCE_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.9.5");
+
+/// `id-qcs-pkixQCSyntax-v2` statement OID, whose information is
+/// [`SemanticsInformation`].
+pub const PKIX_QC_SYNTAX_V2_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.5.5.7.11.2");
+
+/// `id-etsi-qcs-QcCompliance` statement OID, which has no information.
+///
+/// Claims that the certificate is an EU qualified certificate.
+pub const QC_COMPLIANCE_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.1862.1.1");
+
+/// `id-etsi-qcs-QcLimitValue` statement OID, whose information is
+/// [`QcLimitValue`].
+pub const QC_LIMIT_VALUE_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.1862.1.2");
+
+/// `id-etsi-qcs-QcRetentionPeriod` statement OID, whose information is
+/// [`QcRetentionPeriod`].
+pub const QC_RETENTION_PERIOD_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.1862.1.3");
+
+/// `id-etsi-qcs-QcSSCD` statement OID, which has no information.
+///
+/// Claims that the private key resides in a qualified signature or seal
+/// creation device.
+pub const QC_SSCD_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.1862.1.4");
+
+/// `id-etsi-qcs-QcPDS` statement OID, whose information is [`QcPds`].
+pub const QC_PDS_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.1862.1.5");
+
+/// `id-etsi-qcs-QcType` statement OID, whose information is [`QcType`].
+pub const QC_TYPE_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.1862.1.6");
+
+/// `id-etsi-qct-esign` qualified certificate type OID, for certificates for
+/// electronic signatures.
+pub const QCT_ESIGN_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.1862.1.6.1");
+
+/// `id-etsi-qct-eseal` qualified certificate type OID, for certificates for
+/// electronic seals.
+pub const QCT_ESEAL_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.1862.1.6.2");
+
+/// `id-etsi-qct-web` qualified certificate type OID, for certificates for
+/// website authentication.
+pub const QCT_WEB_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.1862.1.6.3");
+
+/// `id-etsi-qcs-QcCClegislation` statement OID, whose information is
+/// [`QcCcLegislation`].
+pub const QC_CC_LEGISLATION_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.1862.1.7");
+
+/// X.509 `SubjectDirectoryAttributes` extension as defined in
+/// [RFC 5280 Section 4.2.1.8].
+///
+/// ```text
+/// SubjectDirectoryAttributes ::= SEQUENCE SIZE (1..MAX) OF Attribute
+/// ```
+///
+/// [RFC 3739 Section 3.2.2] defines the personal data attributes commonly
+/// found in qualified certificates, some of which have typed accessors.
+///
+/// [RFC 5280 Section 4.2.1.8]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.8
+/// [RFC 3739 Section 3.2.2]: https://datatracker.ietf.org/doc/html/rfc3739#section-3.2.2
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubjectDirectoryAttributes<'a>(Vec<Attribute<'a>>);
+
+impl<'a> SubjectDirectoryAttributes<'a> {
+    /// Get the first attribute with the given OID, if present.
+    pub fn get(&self, oid: ObjectIdentifier) -> Option<&Attribute<'a>> {
+        self.0.iter().find(|attribute| attribute.oid == oid)
+    }
+
+    /// Iterate over the attributes in order.
+    pub fn iter(&self) -> core::slice::Iter<'_, Attribute<'a>> {
+        self.0.iter()
+    }
+
+    /// Borrow the attributes as a slice.
+    pub fn as_slice(&self) -> &[Attribute<'a>] {
+        self.0.as_slice()
+    }
+
+    /// Get the subject's date of birth, if present.
+    ///
+    /// RFC 3739 requires the time to be midnight, which isn't enforced.
+    pub fn date_of_birth(&self) -> Result<Option<GeneralizedTime>> {
+        self.get(DATE_OF_BIRTH_OID)
+            .map(|attribute| single_value(attribute)?.generalized_time())
+            .transpose()
+    }
+
+    /// Iterate over the ISO 3166 codes of the countries of which the
+    /// subject is a citizen.
+    pub fn countries_of_citizenship(&self) -> Result<impl Iterator<Item = &'a str>> {
+        let mut countries = Vec::new();
+
+        for attribute in self.iter() {
+            if attribute.oid == COUNTRY_OF_CITIZENSHIP_OID {
+                countries.push(single_value(attribute).and_then(country_code)?);
+            }
+        }
+
+        Ok(countries.into_iter())
+    }
+}
+
+impl<'a> DecodeValue<'a> for SubjectDirectoryAttributes<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::try_from(Vec::decode_value(decoder, length)?)
+    }
+}
+
+impl<'a> EncodeValue for SubjectDirectoryAttributes<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for SubjectDirectoryAttributes<'a> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+impl<'a> TryFrom<&'a [u8]> for SubjectDirectoryAttributes<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<Vec<Attribute<'a>>> for SubjectDirectoryAttributes<'a> {
+    type Error = Error;
+
+    fn try_from(attributes: Vec<Attribute<'a>>) -> Result<Self> {
+        // `SIZE (1..MAX)`
+        if attributes.is_empty() {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(Self(attributes))
+    }
+}
+
+impl<'a> From<SubjectDirectoryAttributes<'a>> for Vec<Attribute<'a>> {
+    fn from(attributes: SubjectDirectoryAttributes<'a>) -> Vec<Attribute<'a>> {
+        attributes.0
+    }
+}
+
+/// X.509 `QCStatements` extension as defined in [RFC 3739 Section 3.2.6].
+///
+/// ```text
+/// QCStatements ::= SEQUENCE OF QCStatement
+/// ```
+///
+/// [RFC 3739 Section 3.2.6]: https://datatracker.ietf.org/doc/html/rfc3739#section-3.2.6
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QcStatements<'a>(Vec<QcStatement<'a>>);
+
+impl<'a> QcStatements<'a> {
+    /// Get the first statement with the given ID, if present.
+    pub fn get(&self, statement_id: ObjectIdentifier) -> Option<&QcStatement<'a>> {
+        self.0
+            .iter()
+            .find(|statement| statement.statement_id == statement_id)
+    }
+
+    /// Is a statement with the given ID present?
+    ///
+    /// This is sufficient for statements without information, such as
+    /// [`QC_COMPLIANCE_OID`] and [`QC_SSCD_OID`].
+    pub fn contains(&self, statement_id: ObjectIdentifier) -> bool {
+        self.get(statement_id).is_some()
+    }
+
+    /// Decode the information of the first statement of type `T`, if
+    /// present.
+    pub fn info<T>(&self) -> Result<Option<T>>
+    where
+        T: QcStatementInfo<'a>,
+    {
+        match self.get(T::STATEMENT_ID) {
+            Some(statement) => statement.info(),
+            None => Ok(None),
+        }
+    }
+
+    /// Iterate over the statements in order.
+    pub fn iter(&self) -> core::slice::Iter<'_, QcStatement<'a>> {
+        self.0.iter()
+    }
+
+    /// Borrow the statements as a slice.
+    pub fn as_slice(&self) -> &[QcStatement<'a>] {
+        self.0.as_slice()
+    }
+}
+
+impl<'a> DecodeValue<'a> for QcStatements<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Vec::decode_value(decoder, length).map(Self)
+    }
+}
+
+impl<'a> EncodeValue for QcStatements<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for QcStatements<'a> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+impl<'a> TryFrom<&'a [u8]> for QcStatements<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> From<Vec<QcStatement<'a>>> for QcStatements<'a> {
+    fn from(statements: Vec<QcStatement<'a>>) -> QcStatements<'a> {
+        Self(statements)
+    }
+}
+
+impl<'a> From<QcStatements<'a>> for Vec<QcStatement<'a>> {
+    fn from(statements: QcStatements<'a>) -> Vec<QcStatement<'a>> {
+        statements.0
+    }
+}
+
+/// `QCStatement` as defined in [RFC 3739 Section 3.2.6].
+///
+/// ```text
+/// QCStatement ::= SEQUENCE {
+///     statementId   QC-STATEMENT.&id({SupportedStatements}),
+///     statementInfo QC-STATEMENT.&Type
+///                   ({SupportedStatements}{@statementId}) OPTIONAL }
+/// ```
+///
+/// [RFC 3739 Section 3.2.6]: https://datatracker.ietf.org/doc/html/rfc3739#section-3.2.6
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct QcStatement<'a> {
+    /// OID identifying the type of the statement.
+    pub statement_id: ObjectIdentifier,
+
+    /// Information for the statement, whose type depends on the
+    /// `statement_id`.
+    pub statement_info: Option<Any<'a>>,
+}
+
+impl<'a> QcStatement<'a> {
+    /// Decode the information of this statement if it is of type `T`.
+    ///
+    /// Returns an error if the statement is of type `T` but its information
+    /// is absent or malformed.
+    pub fn info<T>(&self) -> Result<Option<T>>
+    where
+        T: QcStatementInfo<'a>,
+    {
+        if self.statement_id != T::STATEMENT_ID {
+            return Ok(None);
+        }
+
+        self.statement_info
+            .ok_or_else(|| Self::TAG.value_error())?
+            .try_into()
+            .map(Some)
+    }
+}
+
+/// Information of a particular type of [`QcStatement`].
+///
+/// Implement this trait to decode statements not supported by this crate
+/// with [`QcStatements::info`].
+pub trait QcStatementInfo<'a>: TryFrom<Any<'a>, Error = Error> + Encodable {
+    /// OID identifying statements with this information.
+    const STATEMENT_ID: ObjectIdentifier;
+}
+
+/// Information of the `id-qcs-pkixQCSyntax-v2` statement as defined in
+/// [RFC 3739 Section 3.2.6.1].
+///
+/// ```text
+/// SemanticsInformation ::= SEQUENCE {
+///     semanticsIdentifier         OBJECT IDENTIFIER OPTIONAL,
+///     nameRegistrationAuthorities NameRegistrationAuthorities OPTIONAL
+///     } -- At least one field shall be present
+///
+/// NameRegistrationAuthorities ::= SEQUENCE SIZE (1..MAX) OF GeneralName
+/// ```
+///
+/// [RFC 3739 Section 3.2.6.1]: https://datatracker.ietf.org/doc/html/rfc3739#section-3.2.6.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SemanticsInformation<'a> {
+    /// OID identifying the semantics of the subject's name, e.g.
+    /// `id-etsi-qcs-semanticsId-Natural`.
+    pub semantics_identifier: Option<ObjectIdentifier>,
+
+    /// `SEQUENCE OF GeneralName` identifying the name registration
+    /// authorities.
+    pub name_registration_authorities: Option<Any<'a>>,
+}
+
+impl<'a> SemanticsInformation<'a> {
+    /// `id-etsi-qcs-semanticsId-Natural` OID, for natural person
+    /// identifiers as defined in ETSI EN 319 412-1.
+    pub const NATURAL_PERSON_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.194121.1.1");
+
+    /// `id-etsi-qcs-semanticsId-Legal` OID, for legal person identifiers as
+    /// defined in ETSI EN 319 412-1.
+    pub const LEGAL_PERSON_OID: ObjectIdentifier = ObjectIdentifier::new("0.4.0.194121.1.2");
+}
+
+impl<'a> Decodable<'a> for SemanticsInformation<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.any()?.try_into()
+    }
+}
+
+impl<'a> Sequence<'a> for SemanticsInformation<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        f(&[
+            &self.semantics_identifier,
+            &self.name_registration_authorities,
+        ])
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for SemanticsInformation<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.sequence(|decoder| {
+            let semantics_identifier: Option<ObjectIdentifier> = decoder.decode()?;
+
+            let name_registration_authorities = decoder
+                .decode::<Option<Any<'a>>>()?
+                .map(|names| names.tag().assert_eq(Tag::Sequence).map(|_| names))
+                .transpose()?;
+
+            if semantics_identifier.is_none() && name_registration_authorities.is_none() {
+                return Err(Tag::Sequence.value_error());
+            }
+
+            Ok(Self {
+                semantics_identifier,
+                name_registration_authorities,
+            })
+        })
+    }
+}
+
+impl<'a> QcStatementInfo<'a> for SemanticsInformation<'a> {
+    const STATEMENT_ID: ObjectIdentifier = PKIX_QC_SYNTAX_V2_OID;
+}
+
+/// Information of the `QcLimitValue` statement as defined in
+/// ETSI EN 319 412-5 Section 4.3.2: the limit on the value of transactions
+/// the certificate may be used for.
+///
+/// ```text
+/// QcEuLimitValue ::= MonetaryValue
+///
+/// MonetaryValue ::= SEQUENCE {
+///     currency Iso4217CurrencyCode,
+///     amount   INTEGER,
+///     exponent INTEGER }
+///     -- value = amount * 10^exponent
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct QcLimitValue<'a> {
+    /// Currency of the limit.
+    pub currency: Currency<'a>,
+
+    /// Amount, which is multiplied by 10 to the power of the exponent.
+    pub amount: i64,
+
+    /// Decimal exponent of the amount.
+    pub exponent: i32,
+}
+
+impl<'a> Decodable<'a> for QcLimitValue<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.any()?.try_into()
+    }
+}
+
+impl<'a> Sequence<'a> for QcLimitValue<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        f(&[&self.currency, &self.amount, &self.exponent])
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for QcLimitValue<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.sequence(|decoder| {
+            Ok(Self {
+                currency: decoder.any()?.try_into()?,
+                amount: decoder.decode()?,
+                exponent: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> QcStatementInfo<'a> for QcLimitValue<'a> {
+    const STATEMENT_ID: ObjectIdentifier = QC_LIMIT_VALUE_OID;
+}
+
+/// ISO 4217 currency code.
+///
+/// ```text
+/// Iso4217CurrencyCode ::= CHOICE {
+///     alphabetic PrintableString (SIZE (3)), -- Recommended
+///     numeric    INTEGER (1..999) }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Currency<'a> {
+    /// Alphabetic code, e.g. `EUR`.
+    Alphabetic(PrintableString<'a>),
+
+    /// Numeric code, e.g. `978`.
+    Numeric(u16),
+}
+
+impl<'a> TryFrom<Any<'a>> for Currency<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        match any.tag() {
+            Tag::PrintableString => {
+                let code = any.printable_string()?;
+
+                if code.as_str().len() != 3 {
+                    return Err(Tag::PrintableString.value_error());
+                }
+
+                Ok(Self::Alphabetic(code))
+            }
+            Tag::Integer => match any.decode_into()? {
+                code @ 1..=999 => Ok(Self::Numeric(code)),
+                _ => Err(Tag::Integer.value_error()),
+            },
+            tag => Err(tag.unexpected_error(None)),
+        }
+    }
+}
+
+impl<'a> Encodable for Currency<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        match self {
+            Self::Alphabetic(code) => code.encoded_len(),
+            Self::Numeric(code) => code.encoded_len(),
+        }
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        match self {
+            Self::Alphabetic(code) => code.encode(encoder),
+            Self::Numeric(code) => code.encode(encoder),
+        }
+    }
+}
+
+/// Information of the `QcRetentionPeriod` statement as defined in
+/// ETSI EN 319 412-5 Section 4.3.3: the number of years after the
+/// certificate expires that registration information is kept.
+///
+/// ```text
+/// QcEuRetentionPeriod ::= INTEGER
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct QcRetentionPeriod(pub u32);
+
+impl<'a> DecodeValue<'a> for QcRetentionPeriod {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        u32::decode_value(decoder, length).map(Self)
+    }
+}
+
+impl EncodeValue for QcRetentionPeriod {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl Tagged for QcRetentionPeriod {
+    const TAG: Tag = Tag::Integer;
+}
+
+impl<'a> TryFrom<Any<'a>> for QcRetentionPeriod {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.decode_into()
+    }
+}
+
+impl<'a> QcStatementInfo<'a> for QcRetentionPeriod {
+    const STATEMENT_ID: ObjectIdentifier = QC_RETENTION_PERIOD_OID;
+}
+
+/// Information of the `QcPDS` statement as defined in ETSI EN 319 412-5
+/// Section 4.3.4: the locations of PKI Disclosure Statements.
+///
+/// ```text
+/// QcEuPDS ::= PdsLocations
+///
+/// PdsLocations ::= SEQUENCE SIZE (1..MAX) OF PdsLocation
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QcPds<'a>(Vec<PdsLocation<'a>>);
+
+impl<'a> QcPds<'a> {
+    /// Iterate over the locations in order.
+    pub fn iter(&self) -> core::slice::Iter<'_, PdsLocation<'a>> {
+        self.0.iter()
+    }
+
+    /// Borrow the locations as a slice.
+    pub fn as_slice(&self) -> &[PdsLocation<'a>] {
+        self.0.as_slice()
+    }
+}
+
+impl<'a> DecodeValue<'a> for QcPds<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::try_from(Vec::decode_value(decoder, length)?)
+    }
+}
+
+impl<'a> EncodeValue for QcPds<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for QcPds<'a> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+impl<'a> TryFrom<Any<'a>> for QcPds<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.decode_into()
+    }
+}
+
+impl<'a> TryFrom<Vec<PdsLocation<'a>>> for QcPds<'a> {
+    type Error = Error;
+
+    fn try_from(locations: Vec<PdsLocation<'a>>) -> Result<Self> {
+        // `SIZE (1..MAX)`
+        if locations.is_empty() {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(Self(locations))
+    }
+}
+
+impl<'a> QcStatementInfo<'a> for QcPds<'a> {
+    const STATEMENT_ID: ObjectIdentifier = QC_PDS_OID;
+}
+
+/// Location of a PKI Disclosure Statement.
+///
+/// ```text
+/// PdsLocation ::= SEQUENCE {
+///     url      IA5String,
+///     language PrintableString (SIZE(2)) } -- ISO 639-1 language code
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct PdsLocation<'a> {
+    /// URL of the statement.
+    pub url: Ia5String<'a>,
+
+    /// ISO 639-1 code of the language of the statement, e.g. `en`.
+    pub language: PrintableString<'a>,
+}
+
+/// Information of the `QcType` statement as defined in ETSI EN 319 412-5
+/// Section 4.2.3: the types of the certificate, e.g. [`QCT_ESIGN_OID`].
+///
+/// ```text
+/// QcType ::= SEQUENCE OF OBJECT IDENTIFIER
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QcType(Vec<ObjectIdentifier>);
+
+impl QcType {
+    /// Is the certificate of the given type?
+    pub fn contains(&self, qc_type: ObjectIdentifier) -> bool {
+        self.0.contains(&qc_type)
+    }
+
+    /// Borrow the types as a slice.
+    pub fn as_slice(&self) -> &[ObjectIdentifier] {
+        self.0.as_slice()
+    }
+}
+
+impl<'a> DecodeValue<'a> for QcType {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Vec::decode_value(decoder, length).map(Self)
+    }
+}
+
+impl EncodeValue for QcType {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl Tagged for QcType {
+    const TAG: Tag = Tag::Sequence;
+}
+
+impl<'a> TryFrom<Any<'a>> for QcType {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.decode_into()
+    }
+}
+
+impl From<Vec<ObjectIdentifier>> for QcType {
+    fn from(types: Vec<ObjectIdentifier>) -> QcType {
+        Self(types)
+    }
+}
+
+impl<'a> QcStatementInfo<'a> for QcType {
+    const STATEMENT_ID: ObjectIdentifier = QC_TYPE_OID;
+}
+
+/// Information of the `QcCClegislation` statement as defined in
+/// ETSI EN 319 412-5 Section 4.2.4: the countries under whose legislation a
+/// non-EU qualified certificate is issued.
+///
+/// ```text
+/// QcCClegislation ::= SEQUENCE OF CountryName
+///
+/// CountryName ::= PrintableString (SIZE (2)) -- ISO 3166 country code
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QcCcLegislation<'a>(Vec<PrintableString<'a>>);
+
+impl<'a> QcCcLegislation<'a> {
+    /// Iterate over the ISO 3166 country codes.
+    pub fn countries(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.0.iter().map(|country| country.as_str())
+    }
+}
+
+impl<'a> DecodeValue<'a> for QcCcLegislation<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        let countries = Vec::<PrintableString<'a>>::decode_value(decoder, length)?;
+
+        if countries.iter().any(|country| country.as_str().len() != 2) {
+            return Err(Tag::PrintableString.value_error());
+        }
+
+        Ok(Self(countries))
+    }
+}
+
+impl<'a> EncodeValue for QcCcLegislation<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for QcCcLegislation<'a> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+impl<'a> TryFrom<Any<'a>> for QcCcLegislation<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.decode_into()
+    }
+}
+
+impl<'a> QcStatementInfo<'a> for QcCcLegislation<'a> {
+    const STATEMENT_ID: ObjectIdentifier = QC_CC_LEGISLATION_OID;
+}
+
+/// Get the value of an attribute which must have exactly one value.
+fn single_value<'a>(attribute: &Attribute<'a>) -> Result<Any<'a>> {
+    let mut values = attribute.values.iter();
+
+    match (values.next(), values.next()) {
+        (Some(value), None) => Ok(*value),
+        _ => Err(Tag::Set.value_error()),
+    }
+}
+
+/// Get an ISO 3166 country code, which is a two character `PrintableString`.
+fn country_code(value: Any<'_>) -> Result<&'_ str> {
+    let code = value.printable_string()?.as_str();
+
+    if code.len() != 2 {
+        return Err(Tag::PrintableString.value_error());
+    }
+
+    Ok(code)
+}
@@ -1,7 +1,41 @@
 //! Relative Distinguished Names
 
 use crate::{AttributeTypeAndValue, Set};
+use der::{DecodeValue, Decoder, EncodeValue, Encoder, Length, Result, Tag, Tagged};
 
-/// Relative Distinguished Name
+/// Relative Distinguished Name as defined in [RFC 5280 Section 4.1.2.4].
+///
+/// ```text
+/// RelativeDistinguishedName ::=
+///   SET SIZE (1..MAX) OF AttributeTypeAndValue
+/// ```
+///
+/// [RFC 5280 Section 4.1.2.4]: https://tools.ietf.org/html/rfc5280#section-4.1.2.4
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
-pub struct RelativeDistinguishedName<'a>(Set<AttributeTypeAndValue<'a>>);
+pub struct RelativeDistinguishedName<'a>(pub Set<AttributeTypeAndValue<'a>>);
+
+impl<'a> DecodeValue<'a> for RelativeDistinguishedName<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        let attributes = Set::decode_value(decoder, length)?;
+
+        if attributes.is_empty() {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(Self(attributes))
+    }
+}
+
+impl<'a> EncodeValue for RelativeDistinguishedName<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for RelativeDistinguishedName<'a> {
+    const TAG: Tag = Tag::Set;
+}
@@ -0,0 +1,95 @@
+//! Certificate serial numbers
+
+use core::{convert::TryFrom, fmt};
+use der::{
+    asn1::{Any, UIntBytes},
+    DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result, Tag, Tagged,
+};
+
+/// X.509 `CertificateSerialNumber` as defined in [RFC 5280 Section 4.1.2.2].
+///
+/// ```text
+/// CertificateSerialNumber ::= INTEGER
+/// ```
+///
+/// Serial numbers must be positive, and their DER encoding must be at most
+/// [`SerialNumber::MAX_LEN`] bytes long.
+///
+/// Displayed as colon-separated uppercase hexadecimal, e.g. `0A:1B:2C`.
+///
+/// [RFC 5280 Section 4.1.2.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SerialNumber<'a>(UIntBytes<'a>);
+
+impl<'a> SerialNumber<'a> {
+    /// Maximum length of the encoded serial number in bytes.
+    pub const MAX_LEN: usize = 20;
+
+    /// Create a new [`SerialNumber`] from big endian bytes, checking it is
+    /// positive and within the length limit.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        Self::try_from(UIntBytes::new(bytes)?)
+    }
+
+    /// Borrow the big endian bytes of the serial number, with leading zeroes
+    /// stripped.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for SerialNumber<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        Self::try_from(UIntBytes::decode_value(decoder, length)?)
+    }
+}
+
+impl<'a> EncodeValue for SerialNumber<'a> {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<'a> Tagged for SerialNumber<'a> {
+    const TAG: Tag = Tag::Integer;
+}
+
+impl<'a> TryFrom<Any<'a>> for SerialNumber<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.decode_into()
+    }
+}
+
+impl<'a> TryFrom<UIntBytes<'a>> for SerialNumber<'a> {
+    type Error = Error;
+
+    fn try_from(uint: UIntBytes<'a>) -> Result<Self> {
+        if uint.as_bytes() == [0] {
+            return Err(Self::TAG.value_error());
+        }
+
+        if usize::try_from(uint.value_len()?)? > Self::MAX_LEN {
+            return Err(Self::TAG.length_error());
+        }
+
+        Ok(Self(uint))
+    }
+}
+
+impl<'a> From<SerialNumber<'a>> for UIntBytes<'a> {
+    fn from(serial_number: SerialNumber<'a>) -> UIntBytes<'a> {
+        serial_number.0
+    }
+}
+
+impl fmt::Display for SerialNumber<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::hex::fmt_colon_hex(self.as_bytes(), f)
+    }
+}
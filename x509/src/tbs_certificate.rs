@@ -0,0 +1,135 @@
+//! Partial `TBSCertificate` parser shared by the [`ct`][crate::ct] and
+//! [`ocsp`][crate::ocsp] modules.
+
+use crate::{Extensions, Name};
+use der::{
+    asn1::{Any, ContextSpecificExplicit},
+    Decodable, Decoder, Encodable, Result, Sequence, Tag,
+};
+use spki::SubjectPublicKeyInfo;
+
+/// Tag of the `version` field of `TBSCertificate`.
+const VERSION_TAG: u8 = 0xA0;
+
+/// Tag of the `issuerUniqueID` field of `TBSCertificate`.
+const ISSUER_UNIQUE_ID_TAG: u8 = 0x81;
+
+/// Tag of the `subjectUniqueID` field of `TBSCertificate`.
+const SUBJECT_UNIQUE_ID_TAG: u8 = 0x82;
+
+/// `TBSCertificate` as defined in [RFC 5280 Section 4.1].
+///
+/// Fields which aren't needed are kept as [`Any`] so that they're
+/// re-encoded exactly as they were decoded.
+///
+/// ```text
+/// TBSCertificate  ::=  SEQUENCE  {
+///      version         [0]  EXPLICIT Version DEFAULT v1,
+///      serialNumber         CertificateSerialNumber,
+///      signature            AlgorithmIdentifier,
+///      issuer               Name,
+///      validity             Validity,
+///      subject              Name,
+///      subjectPublicKeyInfo SubjectPublicKeyInfo,
+///      issuerUniqueID  [1]  IMPLICIT UniqueIdentifier OPTIONAL,
+///      subjectUniqueID [2]  IMPLICIT UniqueIdentifier OPTIONAL,
+///      extensions      [3]  EXPLICIT Extensions OPTIONAL
+///      }
+/// ```
+///
+/// [RFC 5280 Section 4.1]: https://tools.ietf.org/html/rfc5280#section-4.1
+pub(crate) struct TbsCertificate<'a> {
+    pub(crate) version: Option<Any<'a>>,
+    pub(crate) serial_number: Any<'a>,
+    pub(crate) signature: Any<'a>,
+    pub(crate) issuer: Name<'a>,
+    pub(crate) validity: Any<'a>,
+    pub(crate) subject: Any<'a>,
+    pub(crate) subject_public_key_info: SubjectPublicKeyInfo<'a>,
+    pub(crate) issuer_unique_id: Option<Any<'a>>,
+    pub(crate) subject_unique_id: Option<Any<'a>>,
+    pub(crate) extensions: Option<ContextSpecificExplicit<3, Extensions<'a>>>,
+}
+
+impl<'a> TbsCertificate<'a> {
+    /// Decode the `TBSCertificate` of the given DER-encoded certificate.
+    ///
+    /// ```text
+    /// Certificate  ::=  SEQUENCE  {
+    ///      tbsCertificate       TBSCertificate,
+    ///      signatureAlgorithm   AlgorithmIdentifier,
+    ///      signatureValue       BIT STRING  }
+    /// ```
+    pub(crate) fn from_certificate(certificate: &'a [u8]) -> Result<Self> {
+        let mut decoder = Decoder::new(certificate);
+        let tbs_certificate = decoder.sequence(|decoder| {
+            let tbs_certificate = decoder.decode()?;
+
+            // Skip `signatureAlgorithm` and `signatureValue`
+            decoder.any()?;
+            decoder.any()?;
+            Ok(tbs_certificate)
+        })?;
+        decoder.finish(tbs_certificate)
+    }
+}
+
+impl<'a> Decodable<'a> for TbsCertificate<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            let version = optional_field(decoder, VERSION_TAG)?;
+            let serial_number = decoder.any()?;
+            let signature = decoder.any()?;
+            let issuer = decoder.decode()?;
+            let validity = decoder.any()?;
+            let subject = decoder.any()?;
+            let subject_public_key_info = decoder.decode()?;
+            serial_number.tag().assert_eq(Tag::Integer)?;
+            signature.tag().assert_eq(Tag::Sequence)?;
+            validity.tag().assert_eq(Tag::Sequence)?;
+            subject.tag().assert_eq(Tag::Sequence)?;
+
+            Ok(Self {
+                version,
+                serial_number,
+                signature,
+                issuer,
+                validity,
+                subject,
+                subject_public_key_info,
+                issuer_unique_id: optional_field(decoder, ISSUER_UNIQUE_ID_TAG)?,
+                subject_unique_id: optional_field(decoder, SUBJECT_UNIQUE_ID_TAG)?,
+                extensions: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for TbsCertificate<'a> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        f(&[
+            &self.version,
+            &self.serial_number,
+            &self.signature,
+            &self.issuer,
+            &self.validity,
+            &self.subject,
+            &self.subject_public_key_info,
+            &self.issuer_unique_id,
+            &self.subject_unique_id,
+            &self.extensions,
+        ])
+    }
+}
+
+/// Decode the next field if it has the given tag.
+fn optional_field<'a>(decoder: &mut Decoder<'a>, tag: u8) -> Result<Option<Any<'a>>> {
+    if decoder.peek() == Some(tag) {
+        decoder.any().map(Some)
+    } else {
+        Ok(None)
+    }
+}
@@ -1,14 +1,17 @@
 //! Validity [`Time`] as defined in RFC 5280
 
-use core::time::Duration;
+use core::{cmp::Ordering, convert::TryFrom, time::Duration};
 use der::{
     asn1::{GeneralizedTime, UtcTime},
-    Choice,
+    Choice, DateTime, Error, Result,
 };
 
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "time")]
+use der::time::{OffsetDateTime, PrimitiveDateTime};
+
 /// Validity [`Time`] as defined in [RFC 5280 Section 4.1.2.5].
 ///
 /// Schema definition from [RFC 5280 Appendix A]:
@@ -19,6 +22,15 @@ use std::time::SystemTime;
 ///      generalTime    GeneralizedTime }
 /// ```
 ///
+/// RFC 5280 requires dates through the year 2049 to be encoded as `UTCTime`,
+/// and dates in 2050 or later as `GeneralizedTime`. The constructors on this
+/// type select the encoding accordingly; see [`Time::to_canonical`] for
+/// normalizing a decoded value.
+///
+/// [`Time`] values are ordered chronologically. Values representing the same
+/// instant with different encodings are not equal, and order
+/// [`Time::UtcTime`] before [`Time::GeneralTime`].
+///
 /// [RFC 5280 Section 4.1.2.5]: https://tools.ietf.org/html/rfc5280#section-4.1.2.5
 /// [RFC 5280 Appendix A]: https://tools.ietf.org/html/rfc5280#page-117
 #[derive(Choice, Copy, Clone, Debug, Eq, PartialEq)]
@@ -33,6 +45,28 @@ pub enum Time {
 }
 
 impl Time {
+    /// Create a [`Time`] from a [`DateTime`], using `UTCTime` if the year is
+    /// 2049 or earlier and `GeneralizedTime` otherwise.
+    pub fn from_date_time(datetime: DateTime) -> Self {
+        UtcTime::from_date_time(datetime)
+            .map(Time::UtcTime)
+            .unwrap_or_else(|_| Time::GeneralTime(GeneralizedTime::from_date_time(datetime)))
+    }
+
+    /// Convert to [`DateTime`].
+    pub fn to_date_time(&self) -> DateTime {
+        match self {
+            Time::UtcTime(t) => t.to_date_time(),
+            Time::GeneralTime(t) => t.to_date_time(),
+        }
+    }
+
+    /// Create a [`Time`] given a [`Duration`] since `UNIX_EPOCH`, selecting
+    /// the encoding as described in [`Time::from_date_time`].
+    pub fn from_unix_duration(unix_duration: Duration) -> Result<Self> {
+        DateTime::from_unix_duration(unix_duration).map(Self::from_date_time)
+    }
+
     /// Get duration since `UNIX_EPOCH`.
     pub fn to_unix_duration(self) -> Duration {
         match self {
@@ -41,6 +75,14 @@ impl Time {
         }
     }
 
+    /// Create a [`Time`] from [`SystemTime`], selecting the encoding as
+    /// described in [`Time::from_date_time`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_system_time(time: SystemTime) -> Result<Self> {
+        DateTime::from_system_time(time).map(Self::from_date_time)
+    }
+
     /// Convert to [`SystemTime`].
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -50,4 +92,107 @@ impl Time {
             Time::GeneralTime(t) => t.to_system_time(),
         }
     }
+
+    /// Convert to [`GeneralizedTime`], which can represent any [`Time`].
+    pub fn to_generalized_time(self) -> GeneralizedTime {
+        GeneralizedTime::from_date_time(self.to_date_time())
+    }
+
+    /// Re-encode this [`Time`] as required by RFC 5280, i.e. as `UTCTime` if
+    /// the year is 2049 or earlier and `GeneralizedTime` otherwise.
+    pub fn to_canonical(self) -> Self {
+        Self::from_date_time(self.to_date_time())
+    }
+
+    /// Is this [`Time`] encoded as required by RFC 5280?
+    pub fn is_canonical(&self) -> bool {
+        *self == self.to_canonical()
+    }
+}
+
+impl Ord for Time {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let variant = |time: &Time| match time {
+            Time::UtcTime(_) => 0,
+            Time::GeneralTime(_) => 1,
+        };
+
+        self.to_date_time()
+            .cmp(&other.to_date_time())
+            .then_with(|| variant(self).cmp(&variant(other)))
+    }
+}
+
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<DateTime> for Time {
+    fn from(datetime: DateTime) -> Time {
+        Time::from_date_time(datetime)
+    }
+}
+
+impl From<Time> for DateTime {
+    fn from(time: Time) -> DateTime {
+        time.to_date_time()
+    }
+}
+
+impl From<Time> for GeneralizedTime {
+    fn from(time: Time) -> GeneralizedTime {
+        time.to_generalized_time()
+    }
+}
+
+impl TryFrom<Time> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: Time) -> Result<UtcTime> {
+        UtcTime::from_date_time(time.to_date_time())
+    }
+}
+
+/// Selects the encoding as described in [`Time::from_date_time`].
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<OffsetDateTime> for Time {
+    type Error = Error;
+
+    fn try_from(time: OffsetDateTime) -> Result<Time> {
+        DateTime::try_from(time).map(Self::from_date_time)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<Time> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(time: Time) -> Result<OffsetDateTime> {
+        OffsetDateTime::try_from(time.to_date_time())
+    }
+}
+
+/// Selects the encoding as described in [`Time::from_date_time`].
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<PrimitiveDateTime> for Time {
+    type Error = Error;
+
+    fn try_from(time: PrimitiveDateTime) -> Result<Time> {
+        DateTime::try_from(time).map(Self::from_date_time)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<Time> for PrimitiveDateTime {
+    type Error = Error;
+
+    fn try_from(time: Time) -> Result<PrimitiveDateTime> {
+        PrimitiveDateTime::try_from(time.to_date_time())
+    }
 }
@@ -0,0 +1,209 @@
+//! TLS `Certificate` message `certificate_list` encoding, as described in
+//! [RFC 5246 Section 7.4.2] (TLS 1.2) and [RFC 8446 Section 4.4.2] (TLS 1.3).
+//!
+//! This allows assembling and splitting the certificate chain carried in a
+//! TLS handshake without depending on a TLS implementation.
+//!
+//! [RFC 5246 Section 7.4.2]: https://datatracker.ietf.org/doc/html/rfc5246#section-7.4.2
+//! [RFC 8446 Section 4.4.2]: https://datatracker.ietf.org/doc/html/rfc8446#section-4.4.2
+
+use alloc::vec::Vec;
+use core::convert::{TryFrom, TryInto};
+use der::{asn1::Any, Decodable, Error, ErrorKind, Length, Result, Tag};
+
+/// Maximum value of a 24-bit length prefix.
+const MAX_U24: usize = 0xFF_FFFF;
+
+/// Chain of DER-encoded certificates as carried in the `certificate_list`
+/// of a TLS `Certificate` handshake message.
+///
+/// In TLS 1.2 the list is a 24-bit length-prefixed concatenation of 24-bit
+/// length-prefixed certificates:
+///
+/// ```text
+/// opaque ASN.1Cert<1..2^24-1>;
+/// ASN.1Cert certificate_list<0..2^24-1>;
+/// ```
+///
+/// In TLS 1.3 each certificate is additionally followed by extensions:
+///
+/// ```text
+/// struct {
+///     opaque cert_data<1..2^24-1>;
+///     Extension extensions<0..2^16-1>;
+/// } CertificateEntry;
+///
+/// CertificateEntry certificate_list<0..2^24-1>;
+/// ```
+///
+/// Certificates are kept in their original order, i.e. the end-entity
+/// certificate first, as DER-encoded bytes.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CertificateList<'a> {
+    /// DER-encoded certificates
+    certificates: Vec<&'a [u8]>,
+}
+
+impl<'a> CertificateList<'a> {
+    /// Create a [`CertificateList`] from the given DER-encoded certificates.
+    ///
+    /// Returns an error if any of them isn't a single DER-encoded `SEQUENCE`.
+    pub fn new<I>(certificates: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        let certificates = certificates
+            .into_iter()
+            .map(|certificate| {
+                Any::from_der(certificate)?.tag().assert_eq(Tag::Sequence)?;
+                Ok(certificate)
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { certificates })
+    }
+
+    /// Decode a TLS 1.2 `certificate_list`, including its length prefix.
+    pub fn from_tls12(bytes: &'a [u8]) -> Result<Self> {
+        Self::decode_with(bytes, |_| Ok(()))
+    }
+
+    /// Decode a TLS 1.3 `certificate_list`, including its length prefix.
+    ///
+    /// The extensions of each `CertificateEntry` are checked to be
+    /// well-formed, but are otherwise ignored.
+    pub fn from_tls13(bytes: &'a [u8]) -> Result<Self> {
+        Self::decode_with(bytes, |reader| {
+            let len = reader.uint(2)?;
+            reader.bytes(len).map(|_| ())
+        })
+    }
+
+    /// Encode this [`CertificateList`] as a TLS 1.2 `certificate_list`,
+    /// including its length prefix.
+    pub fn to_tls12(&self) -> Result<Vec<u8>> {
+        self.encode_with(&[])
+    }
+
+    /// Encode this [`CertificateList`] as a TLS 1.3 `certificate_list`,
+    /// including its length prefix.
+    ///
+    /// Each `CertificateEntry` is encoded with empty extensions.
+    pub fn to_tls13(&self) -> Result<Vec<u8>> {
+        self.encode_with(&[0, 0])
+    }
+
+    /// Iterate over the DER-encoded certificates.
+    pub fn certificates(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        self.certificates.iter().copied()
+    }
+
+    /// Decode a `certificate_list`, calling `f` to consume whatever follows
+    /// each certificate.
+    fn decode_with<F>(bytes: &'a [u8], mut f: F) -> Result<Self>
+    where
+        F: FnMut(&mut Reader<'a>) -> Result<()>,
+    {
+        let mut reader = Reader { bytes, position: 0 };
+        let len = reader.uint(3)?;
+        let mut entries = Reader {
+            position: reader.position,
+            bytes: reader.bytes(len)?,
+        };
+        reader.finish()?;
+
+        let mut certificates = Vec::new();
+
+        while !entries.is_finished() {
+            let len = entries.uint(3)?;
+            certificates.push(entries.bytes(len)?);
+            f(&mut entries)?;
+        }
+
+        Self::new(certificates)
+    }
+
+    /// Encode a `certificate_list`, appending `suffix` after each
+    /// certificate.
+    fn encode_with(&self, suffix: &[u8]) -> Result<Vec<u8>> {
+        let mut entries = Vec::new();
+
+        for certificate in &self.certificates {
+            write_u24(&mut entries, certificate.len())?;
+            entries.extend_from_slice(certificate);
+            entries.extend_from_slice(suffix);
+        }
+
+        let mut bytes = Vec::with_capacity(entries.len() + 3);
+        write_u24(&mut bytes, entries.len())?;
+        bytes.extend_from_slice(&entries);
+        Ok(bytes)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for CertificateList<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_tls12(bytes)
+    }
+}
+
+/// Append a 24-bit big endian length prefix.
+fn write_u24(bytes: &mut Vec<u8>, len: usize) -> Result<()> {
+    if len > MAX_U24 {
+        return Err(ErrorKind::Overlength.into());
+    }
+
+    bytes.extend_from_slice(&(len as u32).to_be_bytes()[1..]);
+    Ok(())
+}
+
+/// Reader for length-prefixed TLS vectors.
+struct Reader<'a> {
+    /// Remaining input.
+    bytes: &'a [u8],
+
+    /// Number of bytes read so far.
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Read a big endian unsigned integer of the given size in bytes.
+    fn uint(&mut self, size: usize) -> Result<usize> {
+        Ok(self
+            .bytes(size)?
+            .iter()
+            .fold(0, |acc, &byte| (acc << 8) | usize::from(byte)))
+    }
+
+    /// Read the given number of bytes.
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.bytes.len() {
+            return Err(ErrorKind::Truncated.at(self.position.try_into()?));
+        }
+
+        let (bytes, remaining) = self.bytes.split_at(len);
+        self.bytes = remaining;
+        self.position += len;
+        Ok(bytes)
+    }
+
+    /// Have all of the bytes been read?
+    fn is_finished(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Ensure all of the bytes have been read.
+    fn finish(self) -> Result<()> {
+        if self.is_finished() {
+            Ok(())
+        } else {
+            Err(ErrorKind::TrailingData {
+                decoded: Length::try_from(self.position)?,
+                remaining: Length::try_from(self.bytes.len())?,
+            }
+            .into())
+        }
+    }
+}
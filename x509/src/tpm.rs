@@ -0,0 +1,538 @@
+//! TPM 2.0 public areas (`TPM2B_PUBLIC`) as defined in
+//! [TPM 2.0 Library Part 2: Structures] Section 12.2.
+//!
+//! A TPM returns the public part of a key it holds, e.g. an endorsement or
+//! attestation key, as a `TPMT_PUBLIC` structure in its own big endian wire
+//! format. [`PublicArea`] parses those of RSA and ECC keys and converts them
+//! into [`RsaPublicKey`], [`EncodedPoint`] and `SubjectPublicKeyInfo`, as
+//! required to certify them or verify their signatures.
+//!
+//! [TPM 2.0 Library Part 2: Structures]: https://trustedcomputinggroup.org/resource/tpm-library-specification/
+
+use alloc::vec::Vec;
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+use der::{
+    asn1::{Any, Null, ObjectIdentifier, UIntBytes},
+    Encodable,
+};
+use pkcs1::RsaPublicKey;
+use sec1::{
+    generic_array::GenericArray,
+    point::{EncodedPoint, ModulusSize},
+};
+use spki::{AlgorithmIdentifier, EncodePublicKey, PublicKeyDocument, SubjectPublicKeyInfo};
+
+/// `rsaEncryption` OID.
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+
+/// `id-ecPublicKey` OID.
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.2.1");
+
+/// `TPM_ALG_RSA` algorithm ID.
+const ALG_RSA: u16 = 0x0001;
+
+/// `TPM_ALG_NULL` algorithm ID.
+const ALG_NULL: u16 = 0x0010;
+
+/// `TPM_ALG_RSAES` algorithm ID, the only RSA scheme without a hash.
+const ALG_RSAES: u16 = 0x0015;
+
+/// `TPM_ALG_ECDAA` algorithm ID, the only ECC scheme with a counter.
+const ALG_ECDAA: u16 = 0x001A;
+
+/// `TPM_ALG_ECC` algorithm ID.
+const ALG_ECC: u16 = 0x0023;
+
+/// RSA signature and encryption schemes whose details are a hash algorithm:
+/// `TPM_ALG_RSASSA`, `TPM_ALG_RSAPSS` and `TPM_ALG_OAEP`.
+const RSA_HASH_SCHEMES: &[u16] = &[0x0014, 0x0016, 0x0017];
+
+/// ECC schemes whose details are a hash algorithm: `TPM_ALG_ECDSA`,
+/// `TPM_ALG_ECDH`, `TPM_ALG_SM2`, `TPM_ALG_ECSCHNORR` and `TPM_ALG_ECMQV`.
+const ECC_HASH_SCHEMES: &[u16] = &[0x0018, 0x0019, 0x001B, 0x001C, 0x001D];
+
+/// Key derivation schemes, whose details are a hash algorithm:
+/// `TPM_ALG_MGF1`, `TPM_ALG_KDF1_SP800_56A`, `TPM_ALG_KDF2` and
+/// `TPM_ALG_KDF1_SP800_108`.
+const KDF_SCHEMES: &[u16] = &[0x0007, 0x0020, 0x0021, 0x0022];
+
+/// RSA public exponent used when the `exponent` field is zero.
+const DEFAULT_RSA_EXPONENT: u32 = 65537;
+
+/// Result type.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// The key is malformed, e.g. the size of the RSA modulus doesn't match
+    /// `keyBits`.
+    InvalidKey,
+
+    /// Data remains after the public area.
+    TrailingData,
+
+    /// The public area is truncated.
+    Truncated,
+
+    /// Unsupported key type or scheme (`TPM_ALG_ID`).
+    UnsupportedAlgorithm(u16),
+
+    /// Unsupported elliptic curve (`TPM_ECC_CURVE`).
+    UnsupportedCurve(u16),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "TPM public area ASN.1 error: {}", err),
+            Error::InvalidKey => f.write_str("TPM public area contains an invalid key"),
+            Error::TrailingData => f.write_str("TPM public area has trailing data"),
+            Error::Truncated => f.write_str("TPM public area is truncated"),
+            Error::UnsupportedAlgorithm(alg) => {
+                write!(f, "unsupported TPM algorithm: 0x{:04X}", alg)
+            }
+            Error::UnsupportedCurve(curve) => write!(f, "unsupported TPM curve: 0x{:04X}", curve),
+        }
+    }
+}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Public area of a TPM key (`TPMT_PUBLIC`).
+///
+/// ```text
+/// typedef struct {
+///     TPMI_ALG_PUBLIC    type;
+///     TPMI_ALG_HASH      nameAlg;
+///     TPMA_OBJECT        objectAttributes;
+///     TPM2B_DIGEST       authPolicy;
+///     TPMU_PUBLIC_PARMS  parameters;
+///     TPMU_PUBLIC_ID     unique;
+/// } TPMT_PUBLIC;
+/// ```
+///
+/// Only RSA and ECC keys are supported. Their `parameters` (symmetric
+/// algorithm, scheme and key derivation function) are checked to be well
+/// formed, but are not retained.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicArea<'a> {
+    /// Hash algorithm used to compute the name of the key (`TPM_ALG_ID`).
+    pub name_alg: u16,
+
+    /// Attributes of the key (`TPMA_OBJECT`).
+    pub object_attributes: u32,
+
+    /// Policy which must be satisfied to use the key, if any.
+    pub auth_policy: &'a [u8],
+
+    /// Public key.
+    pub key: PublicKey<'a>,
+}
+
+impl<'a> PublicArea<'a> {
+    /// Parse a size-prefixed `TPM2B_PUBLIC` structure, as returned by
+    /// e.g. `TPM2_ReadPublic` or `TPM2_CreatePrimary`.
+    pub fn from_tpm2b_public(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes);
+        let public_area = reader.sized()?;
+        reader.finish(())?;
+        Self::from_bytes(public_area)
+    }
+
+    /// Parse a `TPMT_PUBLIC` structure.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes);
+        let key_type = reader.u16()?;
+        let name_alg = reader.u16()?;
+        let object_attributes = reader.u32()?;
+        let auth_policy = reader.sized()?;
+
+        // `TPMT_SYM_DEF_OBJECT`: `keyBits` and `mode` unless `TPM_ALG_NULL`
+        if reader.u16()? != ALG_NULL {
+            reader.u16()?;
+            reader.u16()?;
+        }
+
+        let key = match key_type {
+            ALG_RSA => PublicKey::Rsa(RsaPublic::read(&mut reader)?),
+            ALG_ECC => PublicKey::Ecc(EccPublic::read(&mut reader)?),
+            _ => return Err(Error::UnsupportedAlgorithm(key_type)),
+        };
+
+        reader.finish(Self {
+            name_alg,
+            object_attributes,
+            auth_policy,
+            key,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PublicArea<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_bytes(bytes)
+    }
+}
+
+/// Public key contained in a [`PublicArea`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PublicKey<'a> {
+    /// RSA public key (`TPM_ALG_RSA`).
+    Rsa(RsaPublic<'a>),
+
+    /// ECC public key (`TPM_ALG_ECC`).
+    Ecc(EccPublic<'a>),
+}
+
+impl<'a> EncodePublicKey for PublicKey<'a> {
+    fn to_public_key_der(&self) -> der::Result<PublicKeyDocument> {
+        match self {
+            PublicKey::Rsa(key) => key.to_public_key_der(),
+            PublicKey::Ecc(key) => key.to_public_key_der(),
+        }
+    }
+}
+
+/// RSA public key: `TPMS_RSA_PARMS` and `TPM2B_PUBLIC_KEY_RSA`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RsaPublic<'a> {
+    /// Size of the modulus in bits.
+    key_bits: u16,
+
+    /// Big endian public exponent.
+    exponent: [u8; 4],
+
+    /// Big endian modulus.
+    modulus: &'a [u8],
+}
+
+impl<'a> RsaPublic<'a> {
+    /// Get the size of the modulus in bits.
+    pub fn key_bits(&self) -> u16 {
+        self.key_bits
+    }
+
+    /// Get the public exponent.
+    ///
+    /// This is 65537 if the public area specifies the default exponent.
+    pub fn exponent(&self) -> u32 {
+        u32::from_be_bytes(self.exponent)
+    }
+
+    /// Get the big endian modulus.
+    pub fn modulus(&self) -> &'a [u8] {
+        self.modulus
+    }
+
+    /// Convert this key into a PKCS#1 [`RsaPublicKey`].
+    pub fn to_rsa_public_key(&self) -> Result<RsaPublicKey<'_>> {
+        Ok(self.rsa_public_key()?)
+    }
+
+    /// Convert this key into a PKCS#1 [`RsaPublicKey`].
+    fn rsa_public_key(&self) -> der::Result<RsaPublicKey<'_>> {
+        Ok(RsaPublicKey {
+            modulus: UIntBytes::new(self.modulus)?,
+            public_exponent: UIntBytes::new(&self.exponent)?,
+        })
+    }
+
+    /// Read the RSA parameters and modulus.
+    fn read(reader: &mut Reader<'a>) -> Result<Self> {
+        // `TPMT_RSA_SCHEME`
+        match reader.u16()? {
+            ALG_NULL | ALG_RSAES => (),
+            scheme if RSA_HASH_SCHEMES.contains(&scheme) => {
+                reader.u16()?;
+            }
+            scheme => return Err(Error::UnsupportedAlgorithm(scheme)),
+        }
+
+        let key_bits = reader.u16()?;
+        let exponent = match reader.u32()? {
+            0 => DEFAULT_RSA_EXPONENT,
+            exponent => exponent,
+        };
+        let modulus = reader.sized()?;
+
+        if key_bits == 0 || usize::from(key_bits) != modulus.len() * 8 || modulus[0] == 0 {
+            return Err(Error::InvalidKey);
+        }
+
+        Ok(Self {
+            key_bits,
+            exponent: exponent.to_be_bytes(),
+            modulus,
+        })
+    }
+}
+
+impl<'a> EncodePublicKey for RsaPublic<'a> {
+    fn to_public_key_der(&self) -> der::Result<PublicKeyDocument> {
+        SubjectPublicKeyInfo {
+            algorithm: AlgorithmIdentifier {
+                oid: RSA_ENCRYPTION_OID,
+                parameters: Some(Null.into()),
+            },
+            subject_public_key: &self.rsa_public_key()?.to_vec()?,
+        }
+        .try_into()
+    }
+}
+
+/// ECC public key: `TPMS_ECC_PARMS` and `TPMS_ECC_POINT`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EccPublic<'a> {
+    /// Curve of the key.
+    curve: EccCurve,
+
+    /// Big endian `x` coordinate.
+    x: &'a [u8],
+
+    /// Big endian `y` coordinate.
+    y: &'a [u8],
+}
+
+impl<'a> EccPublic<'a> {
+    /// Get the curve of the key.
+    pub fn curve(&self) -> EccCurve {
+        self.curve
+    }
+
+    /// Get the big endian `x` coordinate.
+    ///
+    /// The TPM may omit leading zeroes, so it can be shorter than
+    /// [`EccCurve::field_size`].
+    pub fn x(&self) -> &'a [u8] {
+        self.x
+    }
+
+    /// Get the big endian `y` coordinate.
+    ///
+    /// The TPM may omit leading zeroes, so it can be shorter than
+    /// [`EccCurve::field_size`].
+    pub fn y(&self) -> &'a [u8] {
+        self.y
+    }
+
+    /// Convert this key into an uncompressed SEC1 [`EncodedPoint`].
+    ///
+    /// Returns [`Error::UnsupportedCurve`] if `Size` isn't the field size
+    /// of the curve.
+    pub fn to_encoded_point<Size: ModulusSize>(&self) -> Result<EncodedPoint<Size>> {
+        let mut x = GenericArray::<u8, Size>::default();
+        let mut y = GenericArray::<u8, Size>::default();
+
+        if x.len() != self.curve.field_size() {
+            return Err(Error::UnsupportedCurve(self.curve.into()));
+        }
+
+        let field_size = self.curve.field_size();
+        x[(field_size - self.x.len())..].copy_from_slice(self.x);
+        y[(field_size - self.y.len())..].copy_from_slice(self.y);
+        Ok(EncodedPoint::from_affine_coordinates(&x, &y, false))
+    }
+
+    /// Read the ECC parameters and point.
+    fn read(reader: &mut Reader<'a>) -> Result<Self> {
+        // `TPMT_ECC_SCHEME`
+        match reader.u16()? {
+            ALG_NULL => (),
+            ALG_ECDAA => {
+                reader.u16()?;
+                reader.u16()?;
+            }
+            scheme if ECC_HASH_SCHEMES.contains(&scheme) => {
+                reader.u16()?;
+            }
+            scheme => return Err(Error::UnsupportedAlgorithm(scheme)),
+        }
+
+        let curve = EccCurve::try_from(reader.u16()?)?;
+
+        // `TPMT_KDF_SCHEME`
+        match reader.u16()? {
+            ALG_NULL => (),
+            scheme if KDF_SCHEMES.contains(&scheme) => {
+                reader.u16()?;
+            }
+            scheme => return Err(Error::UnsupportedAlgorithm(scheme)),
+        }
+
+        let x = reader.sized()?;
+        let y = reader.sized()?;
+
+        for coordinate in &[x, y] {
+            if coordinate.is_empty() || coordinate.len() > curve.field_size() {
+                return Err(Error::InvalidKey);
+            }
+        }
+
+        Ok(Self { curve, x, y })
+    }
+}
+
+impl<'a> EncodePublicKey for EccPublic<'a> {
+    fn to_public_key_der(&self) -> der::Result<PublicKeyDocument> {
+        let field_size = self.curve.field_size();
+        let mut point = Vec::with_capacity(1 + 2 * field_size);
+        point.push(0x04);
+
+        for coordinate in &[self.x, self.y] {
+            point.resize(point.len() + field_size - coordinate.len(), 0);
+            point.extend_from_slice(coordinate);
+        }
+
+        let curve_oid = self.curve.oid();
+
+        SubjectPublicKeyInfo {
+            algorithm: AlgorithmIdentifier {
+                oid: EC_PUBLIC_KEY_OID,
+                parameters: Some(Any::from(&curve_oid)),
+            },
+            subject_public_key: &point,
+        }
+        .try_into()
+    }
+}
+
+/// Elliptic curves (`TPM_ECC_CURVE`) with a standard `namedCurve` OID.
+///
+/// Barreto-Naehrig curves, which some TPMs support for ECDAA, have no such
+/// OID and are therefore not supported.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum EccCurve {
+    /// `TPM_ECC_NIST_P192`
+    NistP192 = 0x0001,
+
+    /// `TPM_ECC_NIST_P224`
+    NistP224 = 0x0002,
+
+    /// `TPM_ECC_NIST_P256`
+    NistP256 = 0x0003,
+
+    /// `TPM_ECC_NIST_P384`
+    NistP384 = 0x0004,
+
+    /// `TPM_ECC_NIST_P521`
+    NistP521 = 0x0005,
+
+    /// `TPM_ECC_SM2_P256`
+    Sm2P256 = 0x0020,
+}
+
+impl EccCurve {
+    /// Get the size of a field element of this curve in bytes.
+    pub fn field_size(self) -> usize {
+        match self {
+            EccCurve::NistP192 => 24,
+            EccCurve::NistP224 => 28,
+            EccCurve::NistP256 | EccCurve::Sm2P256 => 32,
+            EccCurve::NistP384 => 48,
+            EccCurve::NistP521 => 66,
+        }
+    }
+
+    /// Get the `namedCurve` OID of this curve.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            EccCurve::NistP192 => ObjectIdentifier::new("1.2.840.10045.3.1.1"),
+            EccCurve::NistP224 => ObjectIdentifier::new("1.3.132.0.33"),
+            EccCurve::NistP256 => ObjectIdentifier::new("1.2.840.10045.3.1.7"),
+            EccCurve::NistP384 => ObjectIdentifier::new("1.3.132.0.34"),
+            EccCurve::NistP521 => ObjectIdentifier::new("1.3.132.0.35"),
+            EccCurve::Sm2P256 => ObjectIdentifier::new("1.2.156.10197.1.301"),
+        }
+    }
+}
+
+impl TryFrom<u16> for EccCurve {
+    type Error = Error;
+
+    fn try_from(curve: u16) -> Result<Self> {
+        match curve {
+            0x0001 => Ok(EccCurve::NistP192),
+            0x0002 => Ok(EccCurve::NistP224),
+            0x0003 => Ok(EccCurve::NistP256),
+            0x0004 => Ok(EccCurve::NistP384),
+            0x0005 => Ok(EccCurve::NistP521),
+            0x0020 => Ok(EccCurve::Sm2P256),
+            _ => Err(Error::UnsupportedCurve(curve)),
+        }
+    }
+}
+
+impl From<EccCurve> for u16 {
+    fn from(curve: EccCurve) -> u16 {
+        curve as u16
+    }
+}
+
+/// Reader for the big endian TPM wire format.
+struct Reader<'a> {
+    /// Remaining bytes.
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Create a new reader for the given bytes.
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Read the given number of bytes.
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.bytes.len() {
+            return Err(Error::Truncated);
+        }
+
+        let (bytes, remaining) = self.bytes.split_at(len);
+        self.bytes = remaining;
+        Ok(bytes)
+    }
+
+    /// Read a `UINT16`.
+    fn u16(&mut self) -> Result<u16> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// Read a `UINT32`.
+    fn u32(&mut self) -> Result<u32> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Read a `TPM2B` structure, i.e. a `UINT16` size followed by as many
+    /// bytes.
+    fn sized(&mut self) -> Result<&'a [u8]> {
+        let len = self.u16()?;
+        self.bytes(len.into())
+    }
+
+    /// Finish reading, returning the given value if no bytes remain.
+    fn finish<T>(self, value: T) -> Result<T> {
+        if self.bytes.is_empty() {
+            Ok(value)
+        } else {
+            Err(Error::TrailingData)
+        }
+    }
+}
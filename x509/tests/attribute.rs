@@ -0,0 +1,141 @@
+//! Attribute tests
+
+use core::convert::TryFrom;
+use der::{
+    asn1::{Any, ObjectIdentifier},
+    Decodable, Encodable, ErrorKind, Tag,
+};
+use hex_literal::hex;
+use x509::{Attribute, Attributes};
+
+/// `pkcs-9-at-contentType` OID.
+const CONTENT_TYPE_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.9.3");
+
+/// `pkcs-9-at-messageDigest` OID.
+const MESSAGE_DIGEST_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.9.4");
+
+// [0] {
+//   SEQUENCE { messageDigest, SET { OCTET STRING 0x01020304 } }
+//   SEQUENCE { contentType, SET { data } }
+// }
+const SIGNED_ATTRS_DER: &[u8] = &hex!(
+    "A02F"
+    "301306092A864886F70D0109043106040401020304"
+    "301806092A864886F70D010903310B06092A864886F70D010701"
+);
+
+#[test]
+fn signed_attrs_digest_input() {
+    let signed_attrs = Any::from_der(SIGNED_ATTRS_DER).unwrap();
+    let digest_input = Attribute::signed_attrs_digest_input(signed_attrs).unwrap();
+    assert_eq!(digest_input[0], 0x31);
+    assert_eq!(&digest_input[1..], &SIGNED_ATTRS_DER[1..]);
+}
+
+#[test]
+fn signed_attrs_digest_input_rejects_set() {
+    // Already re-tagged as an explicit SET OF
+    let mut der = SIGNED_ATTRS_DER.to_vec();
+    der[0] = 0x31;
+    let signed_attrs = Any::from_der(&der).unwrap();
+    assert!(Attribute::signed_attrs_digest_input(signed_attrs).is_err());
+}
+
+#[test]
+fn signed_attrs_digest_input_rejects_non_canonical() {
+    // [0] { contentType, messageDigest } is not sorted as DER requires
+    let der = hex!(
+        "A02F"
+        "301806092A864886F70D010903310B06092A864886F70D010701"
+        "301306092A864886F70D0109043106040401020304"
+    );
+    let signed_attrs = Any::from_der(&der).unwrap();
+    assert!(Attribute::signed_attrs_digest_input(signed_attrs).is_err());
+}
+
+#[test]
+fn signed_attrs_digest_input_rejects_empty() {
+    let signed_attrs = Any::from_der(&hex!("A000")).unwrap();
+    assert!(Attribute::signed_attrs_digest_input(signed_attrs).is_err());
+}
+
+// SET {
+//   SEQUENCE { messageDigest, SET { OCTET STRING 0x01020304 } }
+//   SEQUENCE { contentType, SET { data } }
+// }
+const ATTRIBUTES_DER: &[u8] = &hex!(
+    "312F"
+    "301306092A864886F70D0109043106040401020304"
+    "301806092A864886F70D010903310B06092A864886F70D010701"
+);
+
+#[test]
+fn decode_attributes() {
+    let attributes = Attributes::from_der(ATTRIBUTES_DER).unwrap();
+    assert_eq!(attributes.len(), 2);
+    assert_eq!(attributes.as_slice()[0].oid, MESSAGE_DIGEST_OID);
+    assert!(attributes.get(CONTENT_TYPE_OID).is_some());
+    assert_eq!(attributes.to_vec().unwrap(), ATTRIBUTES_DER);
+
+    assert!(Attributes::from_der(&hex!("3100")).unwrap().is_empty());
+}
+
+#[test]
+fn reject_duplicate_attributes() {
+    // SET { messageDigest 0x01020304, messageDigest 0x01020305 }
+    let der = hex!(
+        "312A"
+        "301306092A864886F70D0109043106040401020304"
+        "301306092A864886F70D0109043106040401020305"
+    );
+    let err = Attributes::from_der(&der).err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Set });
+}
+
+#[test]
+fn reject_non_canonical_attributes() {
+    let der = hex!(
+        "312F"
+        "301806092A864886F70D010903310B06092A864886F70D010701"
+        "301306092A864886F70D0109043106040401020304"
+    );
+    assert!(Attributes::from_der(&der).is_err());
+}
+
+#[test]
+fn build_attributes() {
+    let decoded = Attributes::from_der(ATTRIBUTES_DER).unwrap();
+    let content_type = decoded.get(CONTENT_TYPE_OID).unwrap().clone();
+    let message_digest = decoded.get(MESSAGE_DIGEST_OID).unwrap().clone();
+
+    // Insertion order is kept, but the encoding is sorted
+    let mut attributes = Attributes::new();
+    attributes.insert(content_type.clone()).unwrap();
+    attributes.insert(message_digest.clone()).unwrap();
+
+    let oids = attributes
+        .iter()
+        .map(|attribute| attribute.oid)
+        .collect::<Vec<_>>();
+    assert_eq!(oids, [CONTENT_TYPE_OID, MESSAGE_DIGEST_OID]);
+    assert_eq!(attributes.to_vec().unwrap(), ATTRIBUTES_DER);
+
+    // Duplicates are rejected
+    assert!(attributes.insert(content_type.clone()).is_err());
+    assert_eq!(attributes.len(), 2);
+
+    // Replacing keeps the position of the existing attribute
+    let mut empty = content_type.clone();
+    empty.values.clear();
+    assert_eq!(
+        attributes.replace(empty.clone()),
+        Some(content_type.clone())
+    );
+    assert_eq!(attributes.as_slice()[0], empty);
+
+    assert_eq!(attributes.remove(CONTENT_TYPE_OID), Some(empty));
+    assert_eq!(attributes.remove(CONTENT_TYPE_OID), None);
+    assert_eq!(Vec::from(attributes), [message_digest.clone()]);
+
+    assert!(Attributes::try_from(vec![message_digest.clone(), message_digest]).is_err());
+}
@@ -0,0 +1,111 @@
+//! Certificate Transparency precertificate tests
+
+use der::{Decodable, Decoder, Encodable};
+use hex_literal::hex;
+use x509::{
+    ct::{self, FinalIssuer},
+    Extensions, Name,
+};
+
+/// Certificate for `example.com` issued by `CN=Example CA`.
+const CERT: &[u8] = include_bytes!("examples/ct-cert.der");
+
+/// Precertificate for [`CERT`] issued by `CN=Example CA`.
+const PRECERT: &[u8] = include_bytes!("examples/ct-precert.der");
+
+/// Precertificate for [`CERT`] issued by [`PRECERT_SIGNING_CERT`].
+const PRECERT_BY_SIGNING_CERT: &[u8] = include_bytes!("examples/ct-precert-by-signing-cert.der");
+
+/// Precertificate Signing Certificate issued by `CN=Example CA`.
+const PRECERT_SIGNING_CERT: &[u8] = include_bytes!("examples/ct-precert-signing-cert.der");
+
+/// `CN=Example CA`
+const CA_NAME: &[u8] = &hex!("30153113301106035504030C0A4578616D706C65204341");
+
+/// Extract the DER-encoded `TBSCertificate` of a certificate.
+fn tbs_certificate(certificate: &[u8]) -> Vec<u8> {
+    let mut decoder = Decoder::new(certificate);
+    let tbs_certificate = decoder.sequence(|decoder| {
+        let tbs_certificate = decoder.any()?;
+        decoder.any()?;
+        decoder.any()?;
+        Ok(tbs_certificate)
+    });
+    tbs_certificate.unwrap().to_vec().unwrap()
+}
+
+/// Extract the extensions of a DER-encoded `TBSCertificate`.
+fn extensions(tbs_certificate: &[u8]) -> Extensions<'_> {
+    let mut decoder = Decoder::new(tbs_certificate);
+    let extensions = decoder.sequence(|decoder| {
+        while decoder.peek() != Some(0xA3) {
+            decoder.any()?;
+        }
+
+        let extensions = decoder.any()?;
+        Extensions::from_der(extensions.value())
+    });
+    extensions.unwrap()
+}
+
+#[test]
+fn recognize_precertificate() {
+    assert!(extensions(&tbs_certificate(PRECERT))
+        .is_precertificate()
+        .unwrap());
+    assert!(!extensions(&tbs_certificate(CERT))
+        .is_precertificate()
+        .unwrap());
+}
+
+#[test]
+fn reject_malformed_poison() {
+    // SEQUENCE { SEQUENCE { ctPrecertificatePoison, OCTET STRING { NULL } } }
+    let non_critical =
+        Extensions::from_der(&hex!("30123010060A2B06010401D6790204030402" "0500")).unwrap();
+    assert!(non_critical.is_precertificate().is_err());
+
+    // SEQUENCE { SEQUENCE { ctPrecertificatePoison, TRUE, OCTET STRING { INTEGER 0 } } }
+    let not_null =
+        Extensions::from_der(&hex!("30163014060A2B06010401D6790204030101FF0403" "020100")).unwrap();
+    assert!(not_null.is_precertificate().is_err());
+}
+
+#[test]
+fn final_tbs_certificate() {
+    let final_tbs = ct::final_tbs_certificate(&tbs_certificate(PRECERT), None).unwrap();
+    assert_eq!(final_tbs, tbs_certificate(CERT));
+
+    // Only precertificates can be transformed
+    assert!(ct::final_tbs_certificate(&tbs_certificate(CERT), None).is_err());
+}
+
+#[test]
+fn final_tbs_certificate_from_precert_signing_certificate() {
+    let final_issuer = FinalIssuer::from_precert_signing_certificate(PRECERT_SIGNING_CERT).unwrap();
+    assert_eq!(final_issuer.name, Name::from_der(CA_NAME).unwrap());
+
+    let precert_tbs = tbs_certificate(PRECERT_BY_SIGNING_CERT);
+    let final_tbs = ct::final_tbs_certificate(&precert_tbs, Some(&final_issuer)).unwrap();
+    assert_eq!(final_tbs, tbs_certificate(CERT));
+
+    // The issuer must be replaced
+    assert_ne!(
+        ct::final_tbs_certificate(&precert_tbs, None).unwrap(),
+        tbs_certificate(CERT)
+    );
+
+    // Without an Authority Key Identifier, the precertificate's is removed
+    let final_issuer = FinalIssuer {
+        name: Name::from_der(CA_NAME).unwrap(),
+        authority_key_identifier: None,
+    };
+    let final_tbs = ct::final_tbs_certificate(&precert_tbs, Some(&final_issuer)).unwrap();
+    assert_eq!(extensions(&final_tbs).len(), 3);
+}
+
+#[test]
+fn reject_non_precert_signing_certificate() {
+    // Lacks the Precertificate Signing Certificate extended key usage
+    assert!(FinalIssuer::from_precert_signing_certificate(CERT).is_err());
+}
@@ -0,0 +1,219 @@
+//! Extension tests
+
+use core::convert::TryFrom;
+use der::{asn1::ObjectIdentifier, Decodable, Encodable, ErrorKind, Tag};
+use hex_literal::hex;
+use x509::{Attribute, Extensions};
+
+/// `id-ce-basicConstraints` OID.
+const BASIC_CONSTRAINTS_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.19");
+
+/// `id-ce-keyUsage` OID.
+const KEY_USAGE_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.15");
+
+/// `id-ce-subjectKeyIdentifier` OID.
+const SUBJECT_KEY_IDENTIFIER_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.29.14");
+
+// SEQUENCE {
+//   SEQUENCE { basicConstraints, TRUE, OCTET STRING { SEQUENCE { TRUE } } }
+//   SEQUENCE { keyUsage, TRUE, OCTET STRING { BIT STRING 0x06 } }
+//   SEQUENCE { subjectKeyIdentifier, OCTET STRING { OCTET STRING 0x0102030405060708 } }
+// }
+const EXTENSIONS_DER: &[u8] = &hex!(
+    "3034"
+    "300F0603551D130101FF040530030101FF"
+    "300E0603551D0F0101FF040403020106"
+    "30110603551D0E040A04080102030405060708"
+);
+
+#[test]
+fn decode_extensions() {
+    let extensions = Extensions::from_der(EXTENSIONS_DER).unwrap();
+    assert_eq!(extensions.len(), 3);
+    assert!(extensions.get(BASIC_CONSTRAINTS_OID).unwrap().critical);
+    assert!(!extensions.get(SUBJECT_KEY_IDENTIFIER_OID).unwrap().critical);
+    assert_eq!(extensions.to_vec().unwrap(), EXTENSIONS_DER);
+}
+
+#[test]
+fn unknown_critical_extensions() {
+    let extensions = Extensions::from_der(EXTENSIONS_DER).unwrap();
+
+    let unknown = extensions
+        .unknown_critical_extensions(|oid| oid == BASIC_CONSTRAINTS_OID)
+        .map(|extension| extension.extn_id)
+        .collect::<Vec<_>>();
+    assert_eq!(unknown, [KEY_USAGE_OID]);
+
+    // Unknown non-critical extensions are ignored
+    assert!(extensions
+        .check_critical(|oid| oid == BASIC_CONSTRAINTS_OID || oid == KEY_USAGE_OID)
+        .is_ok());
+
+    let err = extensions.check_critical(|_| false).err().unwrap();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnknownOid {
+            oid: BASIC_CONSTRAINTS_OID
+        }
+    );
+}
+
+#[test]
+fn reject_explicit_default_critical() {
+    // SEQUENCE { SEQUENCE { subjectKeyIdentifier, FALSE, OCTET STRING { ... } } }
+    let der = hex!("30143012" "0603551D0E" "010100" "04080406010203040506");
+    assert!(Extensions::from_der(&der).is_err());
+}
+
+#[test]
+fn reject_empty_extensions() {
+    assert!(Extensions::from_der(&hex!("3000")).is_err());
+}
+
+#[test]
+fn reject_duplicate_extensions() {
+    // SEQUENCE { basicConstraints, basicConstraints }
+    let der = hex!(
+        "3022"
+        "300F0603551D130101FF040530030101FF"
+        "300F0603551D130101FF040530030101FF"
+    );
+    let err = Extensions::from_der(&der).err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Sequence });
+}
+
+#[test]
+fn build_extensions() {
+    let decoded = Extensions::from_der(EXTENSIONS_DER).unwrap();
+
+    let mut extensions = Extensions::new();
+    assert!(extensions.to_vec().is_err());
+
+    for extension in &decoded {
+        extensions.insert(*extension).unwrap();
+    }
+
+    assert_eq!(extensions, decoded);
+    assert_eq!(extensions.to_vec().unwrap(), EXTENSIONS_DER);
+
+    // Duplicates are rejected
+    let key_usage = *extensions.get(KEY_USAGE_OID).unwrap();
+    assert!(extensions.insert(key_usage).is_err());
+    assert_eq!(extensions.len(), 3);
+
+    // Replacing keeps the position of the existing extension
+    let mut non_critical = key_usage;
+    non_critical.critical = false;
+    assert_eq!(extensions.replace(non_critical), Some(key_usage));
+    assert_eq!(extensions.as_slice()[1], non_critical);
+
+    assert_eq!(extensions.remove(KEY_USAGE_OID), Some(non_critical));
+    assert_eq!(extensions.remove(KEY_USAGE_OID), None);
+    assert!(extensions.replace(key_usage).is_none());
+
+    let oids = extensions
+        .iter()
+        .map(|extension| extension.extn_id)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        oids,
+        [
+            BASIC_CONSTRAINTS_OID,
+            SUBJECT_KEY_IDENTIFIER_OID,
+            KEY_USAGE_OID
+        ]
+    );
+
+    assert!(Extensions::try_from(vec![key_usage, key_usage]).is_err());
+    assert_eq!(
+        Vec::from(Extensions::try_from(vec![key_usage]).unwrap()),
+        [key_usage]
+    );
+}
+
+#[test]
+fn extension_request() {
+    // SEQUENCE { extensionRequest, SET { Extensions } }
+    let mut der = Vec::from(hex!("304306092A864886F70D01090E3136"));
+    der.extend_from_slice(EXTENSIONS_DER);
+
+    let attribute = Attribute::from_der(&der).unwrap();
+    assert_eq!(
+        attribute.extension_request().unwrap(),
+        Extensions::from_der(EXTENSIONS_DER).unwrap()
+    );
+    assert_eq!(attribute.to_vec().unwrap(), der);
+
+    // Other attribute types are rejected
+    let mut attribute = attribute;
+    attribute.oid = ObjectIdentifier::new("1.2.840.113549.1.9.7");
+    assert!(attribute.extension_request().is_err());
+}
+
+// SEQUENCE {
+//   SEQUENCE { authorityInfoAccess, OCTET STRING { SEQUENCE {
+//     SEQUENCE { ocsp, [6] "http://ocsp.example.com" }
+//     SEQUENCE { caIssuers, [6] "http://ca.example.com/ca.crt" }
+//     SEQUENCE { ocsp, [2] "ocsp.example.com" }
+//   } } }
+// }
+const AUTHORITY_INFO_ACCESS_DER: &[u8] = &hex!(
+    "307D307B06082B06010505070101046F306D"
+    "302306082B060105050730018617687474703A2F2F6F6373702E6578616D706C652E636F6D"
+    "302806082B06010505073002861C687474703A2F2F63612E6578616D706C652E636F6D2F63612E637274"
+    "301C06082B0601050507300182106F6373702E6578616D706C652E636F6D"
+);
+
+// SEQUENCE {
+//   SEQUENCE { cRLDistributionPoints, OCTET STRING { SEQUENCE {
+//     SEQUENCE { [0] { [0] {
+//       [6] "http://crl.example.com/ca.crl"
+//       [6] "ldap://ldap.example.com/cn=CA"
+//     } } }
+//     SEQUENCE { [0] { [1] {} } [1] 0x0640 }
+//     SEQUENCE { [2] { [6] "http://x" } }
+//   } } }
+// }
+const CRL_DISTRIBUTION_POINTS_DER: &[u8] = &hex!(
+    "306730650603551D1F045E305C"
+    "3042A040A03E"
+    "861D687474703A2F2F63726C2E6578616D706C652E636F6D2F63612E63726C"
+    "861D6C6461703A2F2F6C6461702E6578616D706C652E636F6D2F636E3D4341"
+    "3008A002A10081020640"
+    "300CA20A8608687474703A2F2F78"
+);
+
+#[test]
+fn ocsp_responders() {
+    let extensions = Extensions::from_der(AUTHORITY_INFO_ACCESS_DER).unwrap();
+    let responders: Vec<_> = extensions.ocsp_responders().unwrap().collect();
+    assert_eq!(responders, ["http://ocsp.example.com"]);
+    assert_eq!(extensions.crl_distribution_urls().unwrap().count(), 0);
+}
+
+#[test]
+fn crl_distribution_urls() {
+    let extensions = Extensions::from_der(CRL_DISTRIBUTION_POINTS_DER).unwrap();
+    let urls: Vec<_> = extensions.crl_distribution_urls().unwrap().collect();
+    assert_eq!(
+        urls,
+        [
+            "http://crl.example.com/ca.crl",
+            "ldap://ldap.example.com/cn=CA"
+        ]
+    );
+    assert_eq!(extensions.ocsp_responders().unwrap().count(), 0);
+}
+
+#[test]
+fn reject_invalid_ocsp_responder() {
+    // SEQUENCE { ocsp, [6] "ocsp.example.com" }
+    let extensions = Extensions::from_der(&hex!(
+        "302E302C06082B060105050701010420301E"
+        "301C06082B0601050507300186106F6373702E6578616D706C652E636F6D"
+    ))
+    .unwrap();
+
+    assert!(extensions.ocsp_responders().is_err());
+}
@@ -0,0 +1,48 @@
+//! Key identifier, serial number, and CRL number tests
+
+use der::{Decodable, Encodable};
+use hex_literal::hex;
+use x509::{CrlNumber, KeyIdentifier, SerialNumber};
+
+#[test]
+fn key_identifier() {
+    let der = hex!("0403010A2B");
+    let key_id = KeyIdentifier::from_der(&der).unwrap();
+    assert_eq!(key_id.as_bytes(), [0x01, 0x0A, 0x2B]);
+    assert_eq!(key_id.to_string(), "01:0A:2B");
+    assert_eq!(key_id.to_vec().unwrap(), der);
+
+    assert!(KeyIdentifier::from_der(&hex!("0400")).is_err());
+    assert!(KeyIdentifier::new(&[0; KeyIdentifier::MAX_LEN]).is_ok());
+    assert!(KeyIdentifier::new(&[0; KeyIdentifier::MAX_LEN + 1]).is_err());
+}
+
+#[test]
+fn serial_number() {
+    let der = hex!("020300FF01");
+    let serial = SerialNumber::from_der(&der).unwrap();
+    assert_eq!(serial.as_bytes(), [0xFF, 0x01]);
+    assert_eq!(serial.to_string(), "FF:01");
+    assert_eq!(serial.to_vec().unwrap(), der);
+
+    // Zero
+    assert!(SerialNumber::from_der(&hex!("020100")).is_err());
+    // Negative
+    assert!(SerialNumber::from_der(&hex!("0201FF")).is_err());
+
+    // 20 octets including the leading zero
+    assert!(SerialNumber::new(&[0x7F; 20]).is_ok());
+    assert!(SerialNumber::new(&[0xFF; 19]).is_ok());
+    assert!(SerialNumber::new(&[0xFF; 20]).is_err());
+}
+
+#[test]
+fn crl_number() {
+    let crl_number = CrlNumber::from_der(&hex!("020100")).unwrap();
+    assert_eq!(crl_number.as_bytes(), [0]);
+    assert_eq!(crl_number.to_string(), "00");
+
+    assert!(CrlNumber::from_der(&hex!("0201FF")).is_err());
+    assert!(CrlNumber::new(&[0x7F; 20]).is_ok());
+    assert!(CrlNumber::new(&[0x7F; 21]).is_err());
+}
@@ -0,0 +1,160 @@
+//! X.501 `Name` tests
+
+use der::{
+    asn1::{Any, ObjectIdentifier},
+    Decodable, Encodable, Tag,
+};
+use std::collections::{BTreeSet, HashSet};
+use x509::{AttributeTypeAndValue, Name, NameBuilder, RelativeDistinguishedName};
+
+/// `id-at-commonName` OID.
+const CN_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.4.3");
+
+/// `id-at-organizationName` OID.
+const O_OID: ObjectIdentifier = ObjectIdentifier::new("2.5.4.10");
+
+/// Encode a name with a single RDN for each of the given attributes.
+fn name_der(attributes: &[(ObjectIdentifier, Tag, &str)]) -> Vec<u8> {
+    let rdns = attributes
+        .iter()
+        .map(|&(oid, tag, value)| {
+            let mut set = BTreeSet::new();
+            set.insert(AttributeTypeAndValue {
+                oid,
+                value: Any::new(tag, value.as_bytes()).unwrap(),
+            });
+            RelativeDistinguishedName(set)
+        })
+        .collect();
+
+    Name(rdns).to_vec().unwrap()
+}
+
+#[test]
+fn decode_name() {
+    let der = name_der(&[
+        (O_OID, Tag::PrintableString, "Example"),
+        (CN_OID, Tag::Utf8String, "example.com"),
+    ]);
+
+    let name = Name::from_der(&der).unwrap();
+    assert_eq!(name.0.len(), 2);
+    assert_eq!(name.to_vec().unwrap(), der);
+}
+
+#[test]
+fn decode_name_with_teletex_string() {
+    // Legacy `O=Müller GmbH` encoded as a Latin-1 `TeletexString`
+    let der = [
+        0x30, 0x16, 0x31, 0x14, 0x30, 0x12, 0x06, 0x03, 0x55, 0x04, 0x0a, 0x14, 0x0b, 0x4d, 0xfc,
+        0x6c, 0x6c, 0x65, 0x72, 0x20, 0x47, 0x6d, 0x62, 0x48,
+    ];
+
+    let name = Name::from_der(&der).unwrap();
+    let attribute = name.0[0].0.iter().next().unwrap();
+    assert_eq!(attribute.oid, O_OID);
+    assert_eq!(
+        attribute.value.teletex_string().unwrap().to_string(),
+        "Müller GmbH"
+    );
+    assert_eq!(name.to_vec().unwrap(), der);
+}
+
+#[test]
+fn reject_empty_rdn() {
+    // SEQUENCE { SET {} }
+    assert!(Name::from_der(&[0x30, 0x02, 0x31, 0x00]).is_err());
+}
+
+#[test]
+fn canonicalize_folds_case_and_whitespace() {
+    let a = name_der(&[
+        (O_OID, Tag::PrintableString, "Example  Corp"),
+        (CN_OID, Tag::Ia5String, " WWW.Example.COM "),
+    ]);
+    let b = name_der(&[
+        (O_OID, Tag::Utf8String, "example corp"),
+        (CN_OID, Tag::Utf8String, "www.example.com"),
+    ]);
+
+    let a = Name::from_der(&a).unwrap().canonicalize().unwrap();
+    let b = Name::from_der(&b).unwrap().canonicalize().unwrap();
+    assert_eq!(a, b);
+    assert_eq!(a.hash_value(), b.hash_value());
+    assert_eq!(a.as_bytes(), b.as_bytes());
+
+    // The canonical form is itself a valid `Name`
+    assert!(Name::from_der(a.as_bytes()).is_ok());
+
+    let mut index = HashSet::new();
+    index.insert(a);
+    assert!(index.contains(&b));
+}
+
+#[test]
+fn canonicalize_distinguishes_names() {
+    let a = name_der(&[(CN_OID, Tag::Utf8String, "example.com")]);
+    let b = name_der(&[(CN_OID, Tag::Utf8String, "example.org")]);
+    let c = name_der(&[(O_OID, Tag::Utf8String, "example.com")]);
+
+    let a = Name::from_der(&a).unwrap().canonicalize().unwrap();
+    let b = Name::from_der(&b).unwrap().canonicalize().unwrap();
+    let c = Name::from_der(&c).unwrap().canonicalize().unwrap();
+    assert_ne!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn build_name() {
+    let name = NameBuilder::new()
+        .country("NO")
+        .unwrap()
+        .organization("Example AS")
+        .unwrap()
+        .organizational_unit("Utvikling")
+        .unwrap()
+        .common_name("Bjørn")
+        .unwrap()
+        .serial_number("12345")
+        .unwrap()
+        .email_address("bjorn@example.com")
+        .unwrap()
+        .build();
+
+    let expected = name_der(&[
+        (ObjectIdentifier::new("2.5.4.6"), Tag::PrintableString, "NO"),
+        (O_OID, Tag::PrintableString, "Example AS"),
+        (
+            ObjectIdentifier::new("2.5.4.11"),
+            Tag::PrintableString,
+            "Utvikling",
+        ),
+        (CN_OID, Tag::Utf8String, "Bjørn"),
+        (
+            ObjectIdentifier::new("2.5.4.5"),
+            Tag::PrintableString,
+            "12345",
+        ),
+        (
+            ObjectIdentifier::new("1.2.840.113549.1.9.1"),
+            Tag::Ia5String,
+            "bjorn@example.com",
+        ),
+    ]);
+
+    assert_eq!(name.to_vec().unwrap(), expected);
+    assert_eq!(Name::from_der(&expected).unwrap(), name);
+}
+
+#[test]
+fn build_name_rejects_invalid_components() {
+    assert!(NameBuilder::new().country("us").is_err());
+    assert!(NameBuilder::new().country("USA").is_err());
+    assert!(NameBuilder::new().common_name("").is_err());
+    assert!(NameBuilder::new().common_name(&"x".repeat(65)).is_err());
+    assert!(NameBuilder::new().common_name(&"ø".repeat(64)).is_ok());
+    assert!(NameBuilder::new().serial_number("1234*").is_err());
+    assert!(NameBuilder::new()
+        .email_address("bjørn@example.com")
+        .is_err());
+}
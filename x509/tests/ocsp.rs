@@ -0,0 +1,416 @@
+//! OCSP HTTP transport and response tests
+
+use base64ct::{Base64, Encoding};
+use core::convert::TryFrom;
+use der::{
+    asn1::{Any, GeneralizedTime, Null, ObjectIdentifier, OctetString},
+    Decodable, Encodable,
+};
+use hex_literal::hex;
+use x509::{
+    ocsp::{
+        self, BasicOcspResponse, CertId, CertStatus, CrlReason, Nonce, OcspRequest, OcspResponse,
+        OcspResponseStatus, Request, ResponderId, TbsRequest,
+    },
+    AlgorithmIdentifier, Extension, Extensions, Name, SerialNumber,
+};
+
+/// `CertID` of `ed25519-leaf-cert.der` using SHA-256, as computed by
+/// `openssl ocsp -issuer ed25519-ca-cert.der -sha256 -cert ed25519-leaf-cert.der`
+const CERT_ID_DER: &[u8] = &hex!(
+    "3057300D06096086480165030402010500"
+    "042011A9AA1835DBD1BC2CB5A83E297598C362B4F5501F3B43CC4FCD41E0F9904807"
+    "0420889024D660179BB7B9ED0FDF908D9CF660AEDE764B6827E6F72E75464294B955"
+    "02021234"
+);
+
+/// Request of `openssl ocsp -sha256 -issuer ocsp-ca-cert.der` for
+/// `ocsp-leaf-cert.der` and `ocsp-revoked-cert.der`, carrying the nonce
+/// [`OCSP_REQUEST_NONCE`].
+const REQUEST_DER: &[u8] = include_bytes!("examples/ocsp-request.der");
+
+/// Nonce of [`REQUEST_DER`].
+const OCSP_REQUEST_NONCE: [u8; 16] = hex!("8D1FD67BCDB865FF488630AFFD062F05");
+
+/// Response of `openssl ocsp -index` for `ocsp-leaf-cert.der` (good) and
+/// `ocsp-revoked-cert.der` (revoked) using SHA-256 `CertID`s, signed by
+/// `ocsp-ca-cert.der` with the nonce [`REQUEST_NONCE`].
+const RESPONSE_DER: &[u8] = include_bytes!("examples/ocsp-response.der");
+
+/// Response for `ocsp-leaf-cert.der` alone, identifying the responder by
+/// key and carrying neither a nonce nor certificates.
+const RESPONSE_BY_KEY_DER: &[u8] = include_bytes!("examples/ocsp-response-by-key.der");
+
+/// Nonce of the request which [`RESPONSE_DER`] answers.
+const REQUEST_NONCE: [u8; 16] = hex!("D62BAA209E243DF06018EFC746CE59EF");
+
+/// SHA-256 `issuerNameHash` of `ocsp-ca-cert.der`.
+const ISSUER_NAME_HASH: [u8; 32] =
+    hex!("DD353A42BA5A3D256DAE935774F75D6FB21211D6C2216800991AD5BC8355CD03");
+
+/// SHA-256 `issuerKeyHash` of `ocsp-ca-cert.der`.
+const ISSUER_KEY_HASH: [u8; 32] =
+    hex!("96ABEE624F7278AD4834BAA7A515D9262F643727E789C9E95D152DC5D8AEA9D1");
+
+/// `Name` of `ocsp-ca-cert.der`: `CN=OCSP Test CA`.
+const CA_NAME_DER: &[u8] = &hex!("30173115301306035504030C0C4F4353502054657374204341");
+
+/// SHA-1 hash of the public key of `ocsp-ca-cert.der`.
+const CA_KEY_HASH: [u8; 20] = hex!("66643BB362B05D77FB21514E082368E3598AFEA8");
+
+/// SHA-256 OID.
+const SHA256_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.1");
+
+#[test]
+fn http_get_url_escapes_base64() {
+    let request = OctetString::new(&[0xfb, 0xff]).unwrap();
+    let url = ocsp::http_get_url("http://ocsp.example.com", &request).unwrap();
+    assert_eq!(url, "http://ocsp.example.com/BAL7%2Fw%3D%3D");
+
+    let url = ocsp::http_get_url("http://ocsp.example.com/", &request).unwrap();
+    assert_eq!(url, "http://ocsp.example.com/BAL7%2Fw%3D%3D");
+}
+
+#[test]
+fn http_post_body_is_der() {
+    let request = OctetString::new(&[0xfb, 0xff]).unwrap();
+    let body = ocsp::http_post_body(&request).unwrap();
+    assert_eq!(body, &[0x04, 0x02, 0xfb, 0xff]);
+}
+
+#[test]
+fn decode_ocsp_request() {
+    let request = OcspRequest::from_der(REQUEST_DER).unwrap();
+    assert_eq!(request.optional_signature, None);
+
+    let tbs_request = &request.tbs_request;
+    assert_eq!(tbs_request.requestor_name, None);
+    assert_eq!(
+        tbs_request.request_list,
+        [
+            Request::from(ocsp_cert_id(&[0x10, 0x01])),
+            Request::from(ocsp_cert_id(&[0x10, 0x02]))
+        ]
+    );
+    assert_eq!(
+        tbs_request
+            .request_extensions
+            .as_ref()
+            .unwrap()
+            .ocsp_nonce()
+            .unwrap(),
+        Some(Nonce::new(&OCSP_REQUEST_NONCE).unwrap())
+    );
+    assert_eq!(request.to_vec().unwrap(), REQUEST_DER);
+}
+
+#[test]
+fn ocsp_request_http_transport() {
+    let nonce = Nonce::new(&OCSP_REQUEST_NONCE).unwrap().to_vec().unwrap();
+    let mut request_extensions = Extensions::new();
+    request_extensions
+        .insert(Extension {
+            extn_id: ocsp::NONCE_OID,
+            critical: false,
+            extn_value: OctetString::new(&nonce).unwrap(),
+        })
+        .unwrap();
+
+    let request = OcspRequest {
+        tbs_request: TbsRequest {
+            requestor_name: None,
+            request_list: vec![
+                ocsp_cert_id(&[0x10, 0x01]).into(),
+                ocsp_cert_id(&[0x10, 0x02]).into(),
+            ],
+            request_extensions: Some(request_extensions),
+        },
+        optional_signature: None,
+    };
+
+    assert_eq!(request.to_http_post_body().unwrap(), REQUEST_DER);
+    assert!(REQUEST_DER.len() < ocsp::MAX_GET_REQUEST_LEN);
+
+    let url = request.to_http_get_url("http://ocsp.example.com").unwrap();
+    let encoded = url
+        .strip_prefix("http://ocsp.example.com/")
+        .unwrap()
+        .replace("%2B", "+")
+        .replace("%2F", "/")
+        .replace("%3D", "=");
+    assert_eq!(Base64::decode_vec(&encoded).unwrap(), REQUEST_DER);
+}
+
+#[test]
+fn signed_ocsp_request_round_trip() {
+    // `dNSName` of `ocsp.example.com`
+    let requestor_name = hex!("82106F6373702E6578616D706C652E636F6D");
+
+    // `Signature` with an Ed25519 `signatureAlgorithm` and no certificates
+    let signature = hex!("300A300506032B6570030100");
+
+    let request = OcspRequest {
+        tbs_request: TbsRequest {
+            requestor_name: Some(Any::from_der(&requestor_name).unwrap()),
+            request_list: vec![ocsp_cert_id(&[0x10, 0x01]).into()],
+            request_extensions: None,
+        },
+        optional_signature: Some(Any::from_der(&signature).unwrap()),
+    };
+
+    let der = request.to_vec().unwrap();
+    assert_eq!(OcspRequest::from_der(&der).unwrap(), request);
+    assert!(der.ends_with(&[&hex!("A00C")[..], &signature].concat()));
+}
+
+#[test]
+fn reject_unsupported_ocsp_request_version() {
+    // `TBSRequest` of `v2`, which isn't defined
+    let mut der = REQUEST_DER.to_vec();
+    der.splice(6..6, hex!("A003020101").iter().copied());
+    der[2] += 5;
+    der[5] += 5;
+    assert!(OcspRequest::from_der(&der).is_err());
+
+    // `v1` may be decoded even though DER requires it to be omitted
+    der[10] = 0;
+    assert!(OcspRequest::from_der(&der).is_ok());
+}
+
+#[test]
+fn decode_cert_id() {
+    let cert_id = CertId::from_der(CERT_ID_DER).unwrap();
+    assert_eq!(cert_id.issuer_name_hash.len(), 32);
+    assert_eq!(cert_id.issuer_key_hash.len(), 32);
+    assert_eq!(cert_id.serial_number.as_bytes(), &[0x12, 0x34]);
+    assert_eq!(cert_id.to_vec().unwrap(), CERT_ID_DER);
+}
+
+#[test]
+#[cfg(feature = "digest")]
+fn cert_id_from_cert_pair() {
+    let hash_algorithm = AlgorithmIdentifier {
+        oid: SHA256_OID,
+        parameters: Some(Null.into()),
+    };
+
+    let cert_id = CertId::from_cert_pair::<sha2::Sha256>(
+        hash_algorithm,
+        include_bytes!("examples/ed25519-ca-cert.der"),
+        include_bytes!("examples/ed25519-leaf-cert.der"),
+    )
+    .unwrap();
+
+    assert_eq!(cert_id.to_vec().unwrap(), CERT_ID_DER);
+}
+
+#[test]
+#[cfg(feature = "digest")]
+fn cert_id_from_mismatched_cert_pair() {
+    let hash_algorithm = AlgorithmIdentifier {
+        oid: SHA256_OID,
+        parameters: None,
+    };
+
+    // The leaf certificate wasn't issued by itself
+    let leaf_cert = include_bytes!("examples/ed25519-leaf-cert.der");
+    assert!(CertId::from_cert_pair::<sha2::Sha256>(hash_algorithm, leaf_cert, leaf_cert).is_err());
+}
+
+#[test]
+fn decode_ocsp_response() {
+    let response = OcspResponse::from_der(RESPONSE_DER).unwrap();
+    assert_eq!(response.response_status, OcspResponseStatus::Successful);
+
+    let basic_response = response.basic_response().unwrap();
+    assert_eq!(basic_response.tbs_response_data_der, &RESPONSE_DER[34..404]);
+    assert_eq!(
+        basic_response.signature_algorithm.oid,
+        ObjectIdentifier::new("1.2.840.10045.4.3.2")
+    );
+    assert_eq!(basic_response.signature.len(), 72);
+    assert_eq!(
+        basic_response.certs,
+        [&include_bytes!("examples/ocsp-ca-cert.der")[..]]
+    );
+
+    let response_data = &basic_response.tbs_response_data;
+    assert_eq!(
+        response_data.responder_id,
+        ResponderId::ByName(Name::from_der(CA_NAME_DER).unwrap())
+    );
+    assert_eq!(
+        response_data.produced_at,
+        GeneralizedTime::from_der(&hex!("180F32303236313031363133313233325A")).unwrap()
+    );
+    assert_eq!(response_data.responses.len(), 2);
+}
+
+#[test]
+fn find_single_response() {
+    let basic_response = OcspResponse::from_der(RESPONSE_DER)
+        .unwrap()
+        .basic_response()
+        .unwrap();
+
+    let good = basic_response
+        .find_single_response(&ocsp_cert_id(&[0x10, 0x01]))
+        .unwrap();
+    assert_eq!(good.cert_status, CertStatus::Good);
+    assert_eq!(
+        good.next_update,
+        Some(GeneralizedTime::from_der(&hex!("180F32303236313032333133313233325A")).unwrap())
+    );
+
+    let revoked = basic_response
+        .find_single_response(&ocsp_cert_id(&[0x10, 0x02]))
+        .unwrap();
+    assert_eq!(
+        revoked.cert_status,
+        CertStatus::Revoked {
+            revocation_time: GeneralizedTime::from_der(&hex!("180F32303234303130313030303030305A"))
+                .unwrap(),
+            revocation_reason: Some(CrlReason::KeyCompromise),
+        }
+    );
+
+    assert!(basic_response
+        .find_single_response(&ocsp_cert_id(&[0x10, 0x03]))
+        .is_none());
+
+    // `NULL` parameters of the hash algorithm may be omitted
+    let mut cert_id = ocsp_cert_id(&[0x10, 0x01]);
+    cert_id.hash_algorithm.parameters = None;
+    assert_eq!(basic_response.find_single_response(&cert_id), Some(good));
+
+    cert_id.issuer_key_hash[0] ^= 1;
+    assert!(basic_response.find_single_response(&cert_id).is_none());
+}
+
+#[test]
+fn match_nonce() {
+    let basic_response = BasicOcspResponse::try_from(
+        OcspResponse::from_der(RESPONSE_DER)
+            .unwrap()
+            .response_bytes
+            .unwrap()
+            .1,
+    )
+    .unwrap();
+
+    let nonce = Nonce::new(&REQUEST_NONCE).unwrap();
+    assert_eq!(basic_response.nonce().unwrap(), Some(nonce));
+    assert!(basic_response.matches_nonce(&nonce).unwrap());
+    assert!(!basic_response
+        .matches_nonce(&Nonce::new(&REQUEST_NONCE[1..]).unwrap())
+        .unwrap());
+
+    // The extension value of a request is the DER encoding of the nonce
+    assert_eq!(
+        nonce.to_vec().unwrap(),
+        [&hex!("0410")[..], &REQUEST_NONCE].concat()
+    );
+}
+
+#[test]
+fn decode_response_by_key() {
+    let basic_response = OcspResponse::from_der(RESPONSE_BY_KEY_DER)
+        .unwrap()
+        .basic_response()
+        .unwrap();
+
+    let response_data = &basic_response.tbs_response_data;
+    assert_eq!(
+        response_data.responder_id,
+        ResponderId::ByKey(CA_KEY_HASH.into())
+    );
+    assert!(response_data.response_extensions.is_none());
+    assert!(basic_response.certs.is_empty());
+
+    let single_response = basic_response
+        .find_single_response(&ocsp_cert_id(&[0x10, 0x01]))
+        .unwrap();
+    assert_eq!(single_response.cert_status, CertStatus::Good);
+    assert_eq!(single_response.next_update, None);
+
+    // Responses without a nonce don't match any
+    let nonce = Nonce::new(&REQUEST_NONCE).unwrap();
+    assert_eq!(basic_response.nonce().unwrap(), None);
+    assert!(!basic_response.matches_nonce(&nonce).unwrap());
+}
+
+#[test]
+#[cfg(feature = "digest")]
+fn find_single_response_from_cert_pair() {
+    let ca_cert = include_bytes!("examples/ocsp-ca-cert.der");
+    let leaf_cert = include_bytes!("examples/ocsp-leaf-cert.der");
+    let hash_algorithm = AlgorithmIdentifier {
+        oid: SHA256_OID,
+        parameters: Some(Null.into()),
+    };
+
+    let cert_id =
+        CertId::from_cert_pair::<sha2::Sha256>(hash_algorithm, ca_cert, leaf_cert).unwrap();
+    let basic_response = OcspResponse::from_der(RESPONSE_DER)
+        .unwrap()
+        .basic_response()
+        .unwrap();
+
+    let single_response = basic_response.find_single_response(&cert_id).unwrap();
+    assert_eq!(single_response.cert_status, CertStatus::Good);
+
+    let responder_id = &basic_response.tbs_response_data.responder_id;
+    assert!(responder_id
+        .matches_certificate::<sha2::Sha256>(ca_cert)
+        .unwrap());
+    assert!(!responder_id
+        .matches_certificate::<sha2::Sha256>(leaf_cert)
+        .unwrap());
+
+    // Key hashes are SHA-1, so they don't match using SHA-256
+    let responder_id = ResponderId::ByKey(CA_KEY_HASH.into());
+    assert!(!responder_id
+        .matches_certificate::<sha2::Sha256>(ca_cert)
+        .unwrap());
+}
+
+#[test]
+fn reject_unsuccessful_responses() {
+    let response = OcspResponse::from_der(&hex!("30030A0103")).unwrap();
+    assert_eq!(response.response_status, OcspResponseStatus::TryLater);
+    assert!(response.response_bytes.is_none());
+    assert!(response.basic_response().is_err());
+
+    // Successful responses must have `responseBytes`
+    assert!(OcspResponse::from_der(&hex!("30030A0100")).is_err());
+
+    // Status 4 isn't used
+    assert!(OcspResponse::from_der(&hex!("30030A0104")).is_err());
+
+    // Unsuccessful responses must not have `responseBytes`
+    let mut bytes = RESPONSE_DER.to_vec();
+    bytes[6] = 0x02;
+    assert!(OcspResponse::from_der(&bytes).is_err());
+}
+
+#[test]
+fn reject_invalid_nonces() {
+    assert!(Nonce::new(&[]).is_err());
+    assert!(Nonce::new(&[0; 32]).is_ok());
+    assert!(Nonce::new(&[0; 33]).is_err());
+    assert!(Nonce::from_der(&hex!("0400")).is_err());
+}
+
+/// Build the SHA-256 `CertID` of the certificate issued by
+/// `ocsp-ca-cert.der` with the given serial number.
+fn ocsp_cert_id(serial_number: &[u8]) -> CertId<'_> {
+    CertId {
+        hash_algorithm: AlgorithmIdentifier {
+            oid: SHA256_OID,
+            parameters: Some(Null.into()),
+        },
+        issuer_name_hash: ISSUER_NAME_HASH.into(),
+        issuer_key_hash: ISSUER_KEY_HASH.into(),
+        serial_number: SerialNumber::new(serial_number).unwrap(),
+    }
+}
@@ -0,0 +1,54 @@
+//! PKCS#7 certificates-only `SignedData` tests
+
+use der::{Decodable, Encodable};
+use hex_literal::hex;
+use x509::pkcs7::SignedData;
+
+/// Certificates-only `SignedData` generated with
+/// `openssl crl2pkcs7 -nocrl -certfile ed25519-ca-cert.der -certfile ed25519-leaf-cert.der`
+const CHAIN_P7B: &[u8] = include_bytes!("examples/ed25519-chain.p7b");
+
+const CA_CERT: &[u8] = include_bytes!("examples/ed25519-ca-cert.der");
+const LEAF_CERT: &[u8] = include_bytes!("examples/ed25519-leaf-cert.der");
+
+#[test]
+fn decode_certs_only() {
+    let signed_data = SignedData::from_content_info_der(CHAIN_P7B).unwrap();
+    let certificates: Vec<_> = signed_data.certificates().collect();
+    assert_eq!(certificates, [CA_CERT, LEAF_CERT]);
+}
+
+#[test]
+fn encode_certs_only() {
+    let signed_data = SignedData::certs_only(vec![CA_CERT, LEAF_CERT]).unwrap();
+    assert_eq!(signed_data.to_content_info_der().unwrap(), CHAIN_P7B);
+
+    // `SignedData` itself is the `[0]` content of the `ContentInfo`
+    let der = signed_data.to_vec().unwrap();
+    assert_eq!(der, &CHAIN_P7B[19..]);
+    assert_eq!(SignedData::from_der(&der).unwrap(), signed_data);
+}
+
+#[test]
+fn encode_empty() {
+    // SEQUENCE { 1, SET {}, SEQUENCE { data }, SET {} }
+    let der = hex!("30140201013100300B06092A864886F70D0107013100");
+    let signed_data = SignedData::certs_only(None).unwrap();
+    assert_eq!(signed_data.to_vec().unwrap(), der);
+    assert_eq!(
+        SignedData::from_der(&der).unwrap().certificates().count(),
+        0
+    );
+}
+
+#[test]
+fn reject_invalid_certificate() {
+    assert!(SignedData::certs_only(Some(&hex!("0400")[..])).is_err());
+}
+
+#[test]
+fn reject_signer_infos() {
+    // SEQUENCE { 1, SET {}, SEQUENCE { data }, SET { SEQUENCE {} } }
+    let der = hex!("30160201013100300B06092A864886F70D01070131023000");
+    assert!(SignedData::from_der(&der).is_err());
+}
@@ -0,0 +1,187 @@
+//! Certificate policy extension tests
+
+use core::convert::TryFrom;
+use der::{asn1::ObjectIdentifier, Decodable, Encodable};
+use hex_literal::hex;
+use x509::{
+    CertificatePolicies, DisplayText, Extensions, PolicyConstraints, PolicyInformation,
+    PolicyMapping, PolicyMappings, PolicyQualifierInfo,
+};
+
+/// Policy OID used in the test certificate.
+const TEST_POLICY_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.2.1.48.1");
+
+/// Extensions of a certificate generated by `openssl req -x509` with:
+///
+/// ```text
+/// certificatePolicies=critical,@pol1,2.5.29.32.0
+/// policyMappings=2.16.840.1.101.3.2.1.48.1:1.2.3.4
+/// policyConstraints=critical,requireExplicitPolicy:0,inhibitPolicyMapping:2
+///
+/// [pol1]
+/// policyIdentifier=2.16.840.1.101.3.2.1.48.1
+/// CPS.1="http://example.com/cps"
+/// userNotice.1=@notice
+///
+/// [notice]
+/// explicitText="Notice"
+/// organization="Example Org"
+/// noticeNumbers=1,2
+/// ```
+const EXTENSIONS_DER: &[u8] = &hex!(
+    "3081C8"
+    "30750603551D200101FF046B"
+    "3069305F060A608648016503020130013051"
+    "302206082B060105050702011616687474703A2F2F6578616D706C652E636F6D2F637073"
+    "302B06082B06010505070202301F30151A0B4578616D706C65204F726730060201010201021A064E6F74696365"
+    "30060604551D2000"
+    "301C0603551D21041530133011060A6086480165030201300106032A0304"
+    "30120603551D240101FF04083006800100810102"
+    "301D0603551D0E041604143A42B8902E6C8D5147E04E3E51567FC2F65412EA"
+);
+
+#[test]
+fn certificate_policies() {
+    let extensions = Extensions::from_der(EXTENSIONS_DER).unwrap();
+    let policies = extensions.certificate_policies().unwrap().unwrap();
+    assert_eq!(policies.iter().count(), 2);
+
+    let any_policy = policies.get(PolicyInformation::ANY_POLICY).unwrap();
+    assert!(any_policy.policy_qualifiers.is_empty());
+
+    let info = policies.get(TEST_POLICY_OID).unwrap();
+    assert_eq!(info.policy_qualifiers.len(), 2);
+
+    let cps = &info.policy_qualifiers[0];
+    assert_eq!(cps.policy_qualifier_id, PolicyQualifierInfo::CPS_OID);
+    assert_eq!(cps.cps_uri().unwrap(), Some("http://example.com/cps"));
+    assert_eq!(cps.user_notice().unwrap(), None);
+
+    let notice = info.policy_qualifiers[1].user_notice().unwrap().unwrap();
+    assert_eq!(info.policy_qualifiers[1].cps_uri().unwrap(), None);
+    assert_eq!(notice.explicit_text.unwrap().to_string(), "Notice");
+
+    let notice_ref = notice.notice_ref.unwrap();
+    assert_eq!(notice_ref.organization.to_string(), "Example Org");
+    assert!(matches!(
+        notice_ref.organization,
+        DisplayText::VisibleString(_)
+    ));
+    assert_eq!(notice_ref.notice_numbers, [1, 2]);
+
+    let extension = extensions.get(CertificatePolicies::OID).unwrap();
+    assert_eq!(policies.to_vec().unwrap(), extension.extn_value.as_bytes());
+}
+
+#[test]
+fn policy_mappings() {
+    let extensions = Extensions::from_der(EXTENSIONS_DER).unwrap();
+    let mappings = extensions.policy_mappings().unwrap().unwrap();
+    assert_eq!(
+        mappings.as_slice(),
+        [PolicyMapping {
+            issuer_domain_policy: TEST_POLICY_OID,
+            subject_domain_policy: ObjectIdentifier::new("1.2.3.4"),
+        }]
+    );
+
+    let subject_policies: Vec<_> = mappings.subject_domain_policies(TEST_POLICY_OID).collect();
+    assert_eq!(subject_policies, [ObjectIdentifier::new("1.2.3.4")]);
+
+    let extension = extensions.get(PolicyMappings::OID).unwrap();
+    assert_eq!(mappings.to_vec().unwrap(), extension.extn_value.as_bytes());
+}
+
+#[test]
+fn policy_constraints() {
+    let extensions = Extensions::from_der(EXTENSIONS_DER).unwrap();
+    let constraints = extensions.policy_constraints().unwrap().unwrap();
+    assert_eq!(
+        constraints,
+        PolicyConstraints {
+            require_explicit_policy: Some(0),
+            inhibit_policy_mapping: Some(2),
+        }
+    );
+
+    let extension = extensions.get(PolicyConstraints::OID).unwrap();
+    assert_eq!(
+        constraints.to_vec().unwrap(),
+        extension.extn_value.as_bytes()
+    );
+
+    // Either field may be omitted
+    let inhibit_only = PolicyConstraints {
+        require_explicit_policy: None,
+        inhibit_policy_mapping: Some(2),
+    };
+    let der = hex!("3003810102");
+    assert_eq!(inhibit_only.to_vec().unwrap(), der);
+    assert_eq!(PolicyConstraints::from_der(&der).unwrap(), inhibit_only);
+}
+
+#[test]
+fn absent_policy_extensions() {
+    // SEQUENCE { SEQUENCE { basicConstraints, TRUE, OCTET STRING { SEQUENCE { TRUE } } } }
+    let extensions = Extensions::from_der(&hex!("3011300F0603551D130101FF040530030101FF")).unwrap();
+    assert_eq!(extensions.certificate_policies().unwrap(), None);
+    assert_eq!(extensions.policy_mappings().unwrap(), None);
+    assert_eq!(extensions.policy_constraints().unwrap(), None);
+}
+
+#[test]
+fn user_notice_string_types() {
+    // SEQUENCE { id-qt-unotice, SEQUENCE { BMPString "Hi" } }
+    let qualifier =
+        PolicyQualifierInfo::from_der(&hex!("301206082B060105050702023006" "1E0400480069"))
+            .unwrap();
+    let text = qualifier.user_notice().unwrap().unwrap().explicit_text;
+    assert!(matches!(text, Some(DisplayText::BmpString(_))));
+    assert_eq!(text.unwrap().to_string(), "Hi");
+
+    // SEQUENCE { id-qt-unotice, SEQUENCE { UTF8String "Hi" } }
+    let qualifier =
+        PolicyQualifierInfo::from_der(&hex!("301006082B060105050702023004" "0C024869")).unwrap();
+    let text = qualifier.user_notice().unwrap().unwrap().explicit_text;
+    assert!(matches!(text, Some(DisplayText::Utf8String(_))));
+
+    // Other string types are rejected
+    let qualifier =
+        PolicyQualifierInfo::from_der(&hex!("301006082B060105050702023004" "13024869")).unwrap();
+    assert!(qualifier.user_notice().is_err());
+
+    // Malformed `BMPString`
+    let qualifier =
+        PolicyQualifierInfo::from_der(&hex!("301106082B060105050702023005" "1E03004800")).unwrap();
+    assert!(qualifier.user_notice().is_err());
+}
+
+#[test]
+fn reject_invalid_certificate_policies() {
+    // Empty `SEQUENCE OF`
+    assert!(CertificatePolicies::from_der(&hex!("3000")).is_err());
+
+    // Empty `policyQualifiers`
+    assert!(CertificatePolicies::from_der(&hex!("300A30080604551D20003000")).is_err());
+
+    // Duplicate policy OID
+    assert!(CertificatePolicies::from_der(&hex!(
+        "3010" "30060604551D2000" "30060604551D2000"
+    ))
+    .is_err());
+
+    let any_policy = PolicyInformation {
+        policy_identifier: PolicyInformation::ANY_POLICY,
+        policy_qualifiers: Vec::new(),
+    };
+    assert!(CertificatePolicies::try_from(vec![any_policy.clone(), any_policy]).is_err());
+}
+
+#[test]
+fn reject_empty_policy_mappings_and_constraints() {
+    assert!(PolicyMappings::from_der(&hex!("3000")).is_err());
+    assert!(PolicyMappings::try_from(Vec::new()).is_err());
+
+    assert!(PolicyConstraints::from_der(&hex!("3000")).is_err());
+    assert!(PolicyConstraints::default().to_vec().is_err());
+}
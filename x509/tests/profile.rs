@@ -0,0 +1,105 @@
+//! Certificate extension profile tests
+
+use der::{Decodable, Encodable};
+use hex_literal::hex;
+use x509::{Extensions, KeyIdentifier, ObjectIdentifier, Profile, ProfileExtensions};
+
+/// `Extensions` of the TLS server profile.
+const TLS_SERVER_EXTENSIONS: &[u8] = &hex!(
+    "3033"
+    "300C0603551D130101FF04023000"
+    "300E0603551D0F0101FF0404030205A0"
+    "30130603551D25040C300A06082B06010505070301"
+);
+
+/// `Extensions` of the CA profile, with subject and authority key
+/// identifiers of `01:02:03`.
+const CA_EXTENSIONS: &[u8] = &hex!(
+    "303F"
+    "300F0603551D130101FF040530030101FF"
+    "300E0603551D0F0101FF040403020106"
+    "300C0603551D0E04050403010203"
+    "300E0603551D23040730058003010203"
+);
+
+#[test]
+fn tls_server() {
+    let extensions = Profile::TlsServer.extensions().unwrap();
+    assert_eq!(extensions.to_vec().unwrap(), TLS_SERVER_EXTENSIONS);
+    assert_eq!(
+        Extensions::from_der(TLS_SERVER_EXTENSIONS).unwrap(),
+        extensions
+    );
+}
+
+#[test]
+fn end_entity_profiles() {
+    for &(profile, key_purpose) in &[
+        (Profile::TlsServer, "1.3.6.1.5.5.7.3.1"),
+        (Profile::TlsClient, "1.3.6.1.5.5.7.3.2"),
+        (Profile::CodeSigning, "1.3.6.1.5.5.7.3.3"),
+    ] {
+        let extensions = profile.extensions().unwrap();
+        assert_eq!(extensions.len(), 3);
+
+        let basic_constraints = extensions.get(ObjectIdentifier::new("2.5.29.19")).unwrap();
+        assert!(basic_constraints.critical);
+        assert_eq!(basic_constraints.extn_value.as_bytes(), hex!("3000"));
+
+        let key_usage = extensions.get(ObjectIdentifier::new("2.5.29.15")).unwrap();
+        assert!(key_usage.critical);
+
+        let ext_key_usage = extensions.get(ObjectIdentifier::new("2.5.29.37")).unwrap();
+        assert!(!ext_key_usage.critical);
+        assert_eq!(
+            Vec::<ObjectIdentifier>::from_der(ext_key_usage.extn_value.as_bytes()).unwrap(),
+            [ObjectIdentifier::new(key_purpose)]
+        );
+    }
+}
+
+#[test]
+fn ca_with_key_identifiers() {
+    let key_id = KeyIdentifier::new(&hex!("010203")).unwrap();
+    let profile = ProfileExtensions::new(Profile::Ca)
+        .subject_key_identifier(key_id)
+        .unwrap()
+        .authority_key_identifier(key_id)
+        .unwrap();
+
+    let extensions = profile.to_extensions().unwrap();
+    assert_eq!(extensions.to_vec().unwrap(), CA_EXTENSIONS);
+    assert_eq!(extensions.get(ObjectIdentifier::new("2.5.29.37")), None);
+
+    let profile = ProfileExtensions::from(Profile::Ca);
+    assert_eq!(profile.profile(), Profile::Ca);
+    assert_eq!(
+        profile.to_extensions().unwrap(),
+        Profile::Ca.extensions().unwrap()
+    );
+}
+
+#[test]
+#[cfg(feature = "digest")]
+fn key_identifiers_from_public_keys() {
+    use sha2::{Digest, Sha256};
+    use x509::SubjectPublicKeyInfo;
+
+    let spki = SubjectPublicKeyInfo::from_der(include_bytes!("examples/ed25519-pub.der")).unwrap();
+    let key_id = Sha256::digest(spki.subject_public_key);
+
+    let computed = ProfileExtensions::new(Profile::TlsClient)
+        .subject_public_key::<Sha256>(&spki)
+        .unwrap()
+        .issuer_public_key::<Sha256>(&spki)
+        .unwrap();
+
+    let expected = ProfileExtensions::new(Profile::TlsClient)
+        .subject_key_identifier(KeyIdentifier::new(&key_id).unwrap())
+        .unwrap()
+        .authority_key_identifier(KeyIdentifier::new(&key_id).unwrap())
+        .unwrap();
+
+    assert_eq!(computed, expected);
+    assert_eq!(computed.to_extensions().unwrap().len(), 5);
+}
@@ -0,0 +1,219 @@
+//! Qualified certificate extension tests
+
+use core::convert::TryFrom;
+use der::{
+    asn1::{Any, ObjectIdentifier, Utf8String},
+    Decodable, Encodable, Error, Result,
+};
+use hex_literal::hex;
+use x509::{
+    qualified::{
+        self, Currency, QcCcLegislation, QcLimitValue, QcPds, QcRetentionPeriod, QcStatementInfo,
+        QcStatements, QcType, SemanticsInformation, SubjectDirectoryAttributes,
+    },
+    Extensions,
+};
+
+// SEQUENCE {
+//   SEQUENCE { qcStatements, OCTET STRING { SEQUENCE {
+//     SEQUENCE { QcCompliance }
+//     SEQUENCE { QcSSCD }
+//     SEQUENCE { QcType, SEQUENCE { esign } }
+//     SEQUENCE { QcPDS, SEQUENCE { SEQUENCE { "https://example.com/pds", "en" } } }
+//     SEQUENCE { QcRetentionPeriod, 15 }
+//     SEQUENCE { QcLimitValue, SEQUENCE { "EUR", 1000, 2 } }
+//     SEQUENCE { pkixQCSyntax-v2, SEQUENCE { semanticsId-Natural } }
+//     SEQUENCE { QcCClegislation, SEQUENCE { "CH" } }
+//     SEQUENCE { 1.2.3.4, UTF8String "custom" }
+//   } } }
+//   SEQUENCE { subjectDirectoryAttributes, OCTET STRING { SEQUENCE {
+//     SEQUENCE { dateOfBirth, SET { 19800101000000Z } }
+//     SEQUENCE { gender, SET { "F" } }
+//     SEQUENCE { countryOfCitizenship, SET { "DE" } }
+//     SEQUENCE { countryOfCitizenship, SET { "FR" } }
+//   } } }
+// }
+const EXTENSIONS_DER: &[u8] = &hex!(
+    "30820121"
+    "3081BF06082B060105050701030481B2"
+    "3081AF"
+    "3008060604008E460101"
+    "3008060604008E460104"
+    "3013060604008E4601063009060704008E46010601"
+    "3029060604008E460105301F301D161768747470733A2F2F6578616D706C652E636F6D2F7064731302656E"
+    "300B060604008E46010302010F"
+    "3016060604008E460102300C1303455552020203E8020102"
+    "301506082B06010505070B023009060704008BEC490101"
+    "300E060604008E460107300413024348"
+    "300D06032A03040C06637573746F6D"
+    "305D0603551D090456"
+    "3054"
+    "301D06082B060105050709013111180F31393830303130313030303030305A"
+    "300F06082B060105050709033103130146"
+    "301006082B06010505070904310413024445"
+    "301006082B06010505070904310413024652"
+);
+
+/// Statement defined outside of the `x509` crate.
+#[derive(Debug, Eq, PartialEq)]
+struct CustomStatement<'a>(Utf8String<'a>);
+
+impl<'a> TryFrom<Any<'a>> for CustomStatement<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.utf8_string().map(Self)
+    }
+}
+
+impl<'a> Encodable for CustomStatement<'a> {
+    fn encoded_len(&self) -> Result<der::Length> {
+        self.0.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut der::Encoder<'_>) -> Result<()> {
+        self.0.encode(encoder)
+    }
+}
+
+impl<'a> QcStatementInfo<'a> for CustomStatement<'a> {
+    const STATEMENT_ID: ObjectIdentifier = ObjectIdentifier::new("1.2.3.4");
+}
+
+fn qc_statements() -> QcStatements<'static> {
+    let extensions = Extensions::from_der(EXTENSIONS_DER).unwrap();
+    extensions.qc_statements().unwrap().unwrap()
+}
+
+#[test]
+fn statements_without_info() {
+    let statements = qc_statements();
+    assert_eq!(statements.as_slice().len(), 9);
+    assert!(statements.contains(qualified::QC_COMPLIANCE_OID));
+    assert!(statements.contains(qualified::QC_SSCD_OID));
+
+    let compliance = statements.get(qualified::QC_COMPLIANCE_OID).unwrap();
+    assert_eq!(compliance.statement_info, None);
+}
+
+#[test]
+fn etsi_statements() {
+    let statements = qc_statements();
+
+    let qc_type = statements.info::<QcType>().unwrap().unwrap();
+    assert!(qc_type.contains(qualified::QCT_ESIGN_OID));
+    assert!(!qc_type.contains(qualified::QCT_WEB_OID));
+
+    let pds = statements.info::<QcPds<'_>>().unwrap().unwrap();
+    let location = pds.as_slice()[0];
+    assert_eq!(location.url.as_str(), "https://example.com/pds");
+    assert_eq!(location.language.as_str(), "en");
+
+    let retention_period = statements.info::<QcRetentionPeriod>().unwrap();
+    assert_eq!(retention_period, Some(QcRetentionPeriod(15)));
+
+    let limit = statements.info::<QcLimitValue<'_>>().unwrap().unwrap();
+    assert!(matches!(limit.currency, Currency::Alphabetic(code) if code.as_str() == "EUR"));
+    assert_eq!(limit.amount, 1000);
+    assert_eq!(limit.exponent, 2);
+
+    let legislation = statements.info::<QcCcLegislation<'_>>().unwrap().unwrap();
+    assert_eq!(legislation.countries().collect::<Vec<_>>(), ["CH"]);
+}
+
+#[test]
+fn semantics_information() {
+    let semantics = qc_statements()
+        .info::<SemanticsInformation<'_>>()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        semantics.semantics_identifier,
+        Some(SemanticsInformation::NATURAL_PERSON_OID)
+    );
+    assert_eq!(semantics.name_registration_authorities, None);
+    assert_eq!(semantics.to_vec().unwrap(), hex!("3009060704008BEC490101"));
+
+    // At least one field must be present
+    let empty = Any::from_der(&hex!("3000")).unwrap();
+    assert!(SemanticsInformation::try_from(empty).is_err());
+}
+
+#[test]
+fn custom_statement() {
+    let statements = qc_statements();
+    let custom = statements.info::<CustomStatement<'_>>().unwrap().unwrap();
+    assert_eq!(custom.0.as_str(), "custom");
+
+    // Statements of a different type are ignored
+    let compliance = statements.get(qualified::QC_COMPLIANCE_OID).unwrap();
+    assert_eq!(compliance.info::<CustomStatement<'_>>().unwrap(), None);
+}
+
+#[test]
+fn reject_malformed_statement_info() {
+    // SEQUENCE { SEQUENCE { QcRetentionPeriod } }
+    let statements = QcStatements::from_der(&hex!("300A3008060604008E460103")).unwrap();
+    assert!(statements.info::<QcRetentionPeriod>().is_err());
+
+    // SEQUENCE { SEQUENCE { QcLimitValue, SEQUENCE { "EURO", 1000, 2 } } }
+    let statements = QcStatements::from_der(&hex!(
+        "30193017060604008E460102300D13044555524F020203E8020102"
+    ))
+    .unwrap();
+    assert!(statements.info::<QcLimitValue<'_>>().is_err());
+
+    // SEQUENCE { SEQUENCE { QcCClegislation, SEQUENCE { "CHE" } } }
+    let statements =
+        QcStatements::from_der(&hex!("3011300F060604008E46010730051303434845")).unwrap();
+    assert!(statements.info::<QcCcLegislation<'_>>().is_err());
+}
+
+#[test]
+fn encode_qc_statements() {
+    let extensions = Extensions::from_der(EXTENSIONS_DER).unwrap();
+    let extension = extensions.get(qualified::QC_STATEMENTS_OID).unwrap();
+    assert_eq!(
+        qc_statements().to_vec().unwrap(),
+        extension.extn_value.as_bytes()
+    );
+
+    let limit = QcLimitValue {
+        currency: Currency::Numeric(978),
+        amount: 5,
+        exponent: 3,
+    };
+    let der = limit.to_vec().unwrap();
+    assert_eq!(der, hex!("300A020203D2020105020103"));
+    assert_eq!(
+        QcLimitValue::try_from(Any::from_der(&der).unwrap()).unwrap(),
+        limit
+    );
+}
+
+#[test]
+fn subject_directory_attributes() {
+    let extensions = Extensions::from_der(EXTENSIONS_DER).unwrap();
+    let attributes = extensions.subject_directory_attributes().unwrap().unwrap();
+    assert_eq!(attributes.as_slice().len(), 4);
+
+    let date_of_birth = attributes.date_of_birth().unwrap().unwrap();
+    assert_eq!(date_of_birth.to_unix_duration().as_secs(), 315532800);
+
+    let countries: Vec<_> = attributes.countries_of_citizenship().unwrap().collect();
+    assert_eq!(countries, ["DE", "FR"]);
+
+    let gender = attributes.get(qualified::GENDER_OID).unwrap();
+    assert_eq!(gender.values.len(), 1);
+
+    let extension = extensions
+        .get(qualified::SUBJECT_DIRECTORY_ATTRIBUTES_OID)
+        .unwrap();
+    assert_eq!(
+        attributes.to_vec().unwrap(),
+        extension.extn_value.as_bytes()
+    );
+
+    assert!(SubjectDirectoryAttributes::from_der(&hex!("3000")).is_err());
+}
@@ -0,0 +1,86 @@
+//! Time tests
+
+use core::convert::TryFrom;
+use der::{
+    asn1::{GeneralizedTime, UtcTime},
+    DateTime, Decodable, Encodable,
+};
+use hex_literal::hex;
+use x509::Time;
+
+fn date(year: u16) -> DateTime {
+    DateTime::new(year, 1, 1, 0, 0, 0).unwrap()
+}
+
+#[test]
+fn encoding_selection() {
+    assert!(matches!(Time::from(date(2049)), Time::UtcTime(_)));
+    assert!(matches!(Time::from(date(2050)), Time::GeneralTime(_)));
+
+    let time = Time::from_unix_duration(date(2049).unix_duration()).unwrap();
+    assert_eq!(
+        time.to_vec().unwrap(),
+        hex!("170D3439303130313030303030305A")
+    );
+
+    let time = Time::from_unix_duration(date(2050).unix_duration()).unwrap();
+    assert_eq!(
+        time.to_vec().unwrap(),
+        hex!("180F32303530303130313030303030305A")
+    );
+}
+
+#[test]
+fn canonical() {
+    // GeneralizedTime 2010-01-01 08:30:00 UTC
+    let time = Time::from_der(&hex!("180F32303130303130313038333030305A")).unwrap();
+    assert!(!time.is_canonical());
+
+    let canonical = time.to_canonical();
+    assert!(canonical.is_canonical());
+    assert!(matches!(canonical, Time::UtcTime(_)));
+    assert_eq!(canonical.to_unix_duration(), time.to_unix_duration());
+    assert_eq!(canonical.to_generalized_time(), GeneralizedTime::from(time));
+}
+
+#[test]
+fn conversions() {
+    let time = Time::from(date(2050));
+    assert!(UtcTime::try_from(time).is_err());
+    assert_eq!(DateTime::from(time), date(2050));
+
+    let time = Time::from(date(2030));
+    assert_eq!(UtcTime::try_from(time).unwrap().to_date_time(), date(2030));
+}
+
+#[test]
+fn ordering() {
+    let utc = Time::from(date(2030));
+    let general = Time::GeneralTime(GeneralizedTime::from_date_time(date(2030)));
+    let later = Time::from(date(2050));
+
+    assert!(utc < general);
+    assert!(general < later);
+    assert!(utc < later);
+    assert_ne!(utc, general);
+    assert_eq!(utc.max(later), later);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_crate() {
+    use der::time::{OffsetDateTime, PrimitiveDateTime};
+
+    let offset_date_time = OffsetDateTime::try_from(date(2049)).unwrap();
+    let time = Time::try_from(offset_date_time).unwrap();
+    assert!(matches!(time, Time::UtcTime(_)));
+    assert_eq!(OffsetDateTime::try_from(time).unwrap(), offset_date_time);
+
+    let primitive_date_time = PrimitiveDateTime::try_from(date(2050)).unwrap();
+    let time = Time::try_from(primitive_date_time).unwrap();
+    assert!(matches!(time, Time::GeneralTime(_)));
+    assert_eq!(
+        PrimitiveDateTime::try_from(time).unwrap(),
+        primitive_date_time
+    );
+}
@@ -0,0 +1,94 @@
+//! TLS `certificate_list` tests
+
+use der::ErrorKind;
+use hex_literal::hex;
+use x509::tls::CertificateList;
+
+const CA_CERT: &[u8] = include_bytes!("examples/ed25519-ca-cert.der");
+const LEAF_CERT: &[u8] = include_bytes!("examples/ed25519-leaf-cert.der");
+
+#[test]
+fn tls12_round_trip() {
+    let list = CertificateList::new(vec![LEAF_CERT, CA_CERT]).unwrap();
+    let bytes = list.to_tls12().unwrap();
+
+    assert_eq!(bytes.len(), 3 + 3 + LEAF_CERT.len() + 3 + CA_CERT.len());
+    assert_eq!(bytes[..6], hex!("00026C000122"));
+    assert_eq!(&bytes[6..296], LEAF_CERT);
+    assert_eq!(bytes[296..299], hex!("000144"));
+    assert_eq!(&bytes[299..], CA_CERT);
+
+    let decoded = CertificateList::from_tls12(&bytes).unwrap();
+    assert_eq!(decoded, list);
+    assert!(decoded
+        .certificates()
+        .eq([LEAF_CERT, CA_CERT].iter().copied()));
+}
+
+#[test]
+fn tls13_round_trip() {
+    let list = CertificateList::new(vec![LEAF_CERT, CA_CERT]).unwrap();
+    let bytes = list.to_tls13().unwrap();
+
+    assert_eq!(
+        bytes.len(),
+        3 + 3 + LEAF_CERT.len() + 2 + 3 + CA_CERT.len() + 2
+    );
+    assert_eq!(bytes[..6], hex!("000270000122"));
+    assert_eq!(&bytes[6..296], LEAF_CERT);
+    assert_eq!(bytes[296..301], hex!("0000000144"));
+    assert_eq!(&bytes[301..625], CA_CERT);
+    assert_eq!(bytes[625..], hex!("0000"));
+
+    assert_eq!(CertificateList::from_tls13(&bytes).unwrap(), list);
+}
+
+#[test]
+fn tls13_skips_extensions() {
+    // `status_request` extension carrying an (empty) OCSP response
+    let mut bytes = vec![0x00, 0x01, 0x2B, 0x00, 0x01, 0x22];
+    bytes.extend_from_slice(LEAF_CERT);
+    bytes.extend_from_slice(&hex!("0004 0005 0000"));
+
+    let list = CertificateList::from_tls13(&bytes).unwrap();
+    assert!(list.certificates().eq([LEAF_CERT].iter().copied()));
+}
+
+#[test]
+fn empty() {
+    let list = CertificateList::new(None).unwrap();
+    assert_eq!(list.to_tls12().unwrap(), hex!("000000"));
+    assert_eq!(list.to_tls13().unwrap(), hex!("000000"));
+    assert_eq!(CertificateList::from_tls12(&hex!("000000")).unwrap(), list);
+}
+
+#[test]
+fn decode_errors() {
+    // Truncated outer length
+    let err = CertificateList::from_tls12(&hex!("0000")).err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::Truncated);
+
+    // Certificate length exceeds the list
+    let err = CertificateList::from_tls12(&hex!("00000400000530"))
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), ErrorKind::Truncated);
+
+    // Trailing data after the list
+    let err = CertificateList::from_tls12(&hex!("000005000002300000"))
+        .err()
+        .unwrap();
+    assert!(matches!(err.kind(), ErrorKind::TrailingData { .. }));
+
+    // Certificate which isn't a `SEQUENCE`
+    let err = CertificateList::from_tls12(&hex!("0000050000020400"))
+        .err()
+        .unwrap();
+    assert!(matches!(err.kind(), ErrorKind::UnexpectedTag { .. }));
+
+    // TLS 1.3 entry missing its extensions
+    let err = CertificateList::from_tls13(&hex!("0000050000023000"))
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), ErrorKind::Truncated);
+}
@@ -0,0 +1,132 @@
+//! TPM public area tests
+
+#![cfg(feature = "tpm")]
+
+use der::{asn1::ObjectIdentifier, Decodable, Encodable};
+use hex_literal::hex;
+use spki::{EncodePublicKey, SubjectPublicKeyInfo};
+use x509::{
+    sec1::consts::{U32, U48},
+    tpm::{EccCurve, Error, PublicArea, PublicKey},
+};
+
+/// `TPM2B_PUBLIC` of an RSA 2048 endorsement key created from the default
+/// EK template, with the modulus of `rsa2048-pub.der`.
+const RSA_EK: &[u8] = include_bytes!("examples/tpm-rsa2048-ek.pub");
+
+/// `TPM2B_PUBLIC` of an ECDSA P-256 signing key with the point of
+/// `p256-pub.der`.
+const P256_ECDSA: &[u8] = include_bytes!("examples/tpm-p256-ecdsa.pub");
+
+/// PKCS#1 `RSAPublicKey` with the modulus of the EK.
+const RSA_PUB: &[u8] = include_bytes!("examples/rsa2048-pub.der");
+
+/// `SubjectPublicKeyInfo` with the point of the ECDSA key.
+const P256_PUB: &[u8] = include_bytes!("examples/p256-pub.der");
+
+/// Policy of the default EK template (`PolicySecret(TPM_RH_ENDORSEMENT)`).
+const EK_POLICY: [u8; 32] =
+    hex!("837197674484B3F81A90CC8D46A5D724FD52D76E06520B64F2A1DA1B331469AA");
+
+#[test]
+fn rsa_endorsement_key() {
+    let public_area = PublicArea::from_tpm2b_public(RSA_EK).unwrap();
+    assert_eq!(public_area.name_alg, 0x000B);
+    assert_eq!(public_area.object_attributes, 0x000300B2);
+    assert_eq!(public_area.auth_policy, EK_POLICY);
+
+    let key = match &public_area.key {
+        PublicKey::Rsa(key) => key,
+        other => panic!("unexpected key: {:?}", other),
+    };
+    assert_eq!(key.key_bits(), 2048);
+    assert_eq!(key.exponent(), 65537);
+    assert_eq!(key.modulus().len(), 256);
+    assert_eq!(key.to_rsa_public_key().unwrap().to_vec().unwrap(), RSA_PUB);
+
+    let document = public_area.key.to_public_key_der().unwrap();
+    let spki = document.spki();
+    assert_eq!(
+        spki.algorithm.oid,
+        ObjectIdentifier::new("1.2.840.113549.1.1.1")
+    );
+    assert_eq!(spki.subject_public_key, RSA_PUB);
+}
+
+#[test]
+fn ecc_signing_key() {
+    let public_area = PublicArea::from_tpm2b_public(P256_ECDSA).unwrap();
+    assert_eq!(public_area.name_alg, 0x000B);
+    assert_eq!(public_area.object_attributes, 0x00040072);
+    assert!(public_area.auth_policy.is_empty());
+
+    let key = match &public_area.key {
+        PublicKey::Ecc(key) => key,
+        other => panic!("unexpected key: {:?}", other),
+    };
+    assert_eq!(key.curve(), EccCurve::NistP256);
+    assert_eq!(key.x().len(), 32);
+    assert_eq!(key.y().len(), 32);
+
+    let spki = SubjectPublicKeyInfo::from_der(P256_PUB).unwrap();
+    let point = key.to_encoded_point::<U32>().unwrap();
+    assert_eq!(point.as_bytes(), spki.subject_public_key);
+    assert_eq!(
+        key.to_encoded_point::<U48>(),
+        Err(Error::UnsupportedCurve(0x0003))
+    );
+
+    let document = public_area.key.to_public_key_der().unwrap();
+    assert_eq!(document.as_ref(), P256_PUB);
+}
+
+#[test]
+fn ecc_short_coordinates() {
+    // Leading zeroes of the coordinates may be omitted
+    let mut bytes = P256_ECDSA[2..22].to_vec();
+    bytes.extend_from_slice(&hex!("0001010001FF"));
+
+    let public_area = PublicArea::from_bytes(&bytes).unwrap();
+    let key = match public_area.key {
+        PublicKey::Ecc(key) => key,
+        other => panic!("unexpected key: {:?}", other),
+    };
+
+    let point = key.to_encoded_point::<U32>().unwrap();
+    assert_eq!(point.x().unwrap()[..31], [0; 31]);
+    assert_eq!(point.x().unwrap()[31], 0x01);
+    assert_eq!(point.y().unwrap()[31], 0xFF);
+}
+
+#[test]
+fn reject_malformed_public_areas() {
+    assert_eq!(
+        PublicArea::from_tpm2b_public(&RSA_EK[..RSA_EK.len() - 1]),
+        Err(Error::Truncated)
+    );
+
+    let mut trailing = P256_ECDSA[2..].to_vec();
+    trailing.push(0);
+    assert_eq!(PublicArea::from_bytes(&trailing), Err(Error::TrailingData));
+
+    // `TPM_ALG_KEYEDHASH` key
+    let mut keyed_hash = P256_ECDSA[2..].to_vec();
+    keyed_hash[..2].copy_from_slice(&hex!("0008"));
+    assert_eq!(
+        PublicArea::from_bytes(&keyed_hash),
+        Err(Error::UnsupportedAlgorithm(0x0008))
+    );
+
+    // `TPM_ECC_BN_P256` curve
+    let mut bn_curve = P256_ECDSA[2..].to_vec();
+    bn_curve[16..18].copy_from_slice(&hex!("0010"));
+    assert_eq!(
+        PublicArea::from_bytes(&bn_curve),
+        Err(Error::UnsupportedCurve(0x0010))
+    );
+
+    // `keyBits` of 1024 with a 2048-bit modulus
+    let mut key_bits = RSA_EK[2..].to_vec();
+    key_bits[50..52].copy_from_slice(&hex!("0400"));
+    assert_eq!(PublicArea::from_bytes(&key_bits), Err(Error::InvalidKey));
+}